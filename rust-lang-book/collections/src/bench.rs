@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+pub use std::hint::black_box;
+
+//* A tiny timing helper for the benchmark binaries in src/bin
+//* Build them with optimizations, e.g. "cargo run --release --bin map_bench"
+//* Each measurement runs once to warm up and then repeats until at least 'MIN_TIME' has passed
+
+const MIN_TIME: Duration = Duration::from_millis(200);
+
+pub struct Measurement {
+    pub name: String,
+    pub runs: u32,
+    pub per_run: Duration,
+}
+
+//* Runs 'f' repeatedly and returns the average time of a single run
+pub fn measure<R, F: FnMut() -> R>(name: &str, mut f: F) -> Measurement {
+    black_box(f());
+
+    let start = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || start.elapsed() < MIN_TIME {
+        black_box(f());
+        runs += 1;
+    }
+    Measurement { name: name.to_string(), runs, per_run: start.elapsed() / runs }
+}
+
+//* 'n' keys in a scrambled but reproducible order, for the benchmarks of maps and vectors
+pub fn scrambled_keys(n: u64) -> Vec<u64> {
+    (0..n).map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 16).collect()
+}
+
+//* Prints a group of measurements relative to the first one
+pub fn report(title: &str, measurements: &[Measurement]) {
+    println!("{}", title);
+    let baseline = measurements.first().map(|m| m.per_run.as_secs_f64()).unwrap_or(1.0);
+    for m in measurements {
+        println!(
+            "    {:<28} {:>12} {:>8.2}x  ({} runs)",
+            m.name,
+            format_duration(m.per_run),
+            m.per_run.as_secs_f64() / baseline,
+            m.runs
+        );
+    }
+}
+
+fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    } else {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    }
+}
@@ -0,0 +1,671 @@
+use std::ops::Range;
+
+mod tables;
+
+use tables::{BIDI_CLASS, BRACKETS, MIRRORED};
+
+//* Bidirectional text (UAX #9, the Unicode Bidirectional Algorithm)
+//* Strings store text in logical order, the order it is typed and read in: "السلام عليكم" starts with 'ا'
+//* Terminals print the characters from left to right, so right-to-left words come out backwards,
+//* and a right-to-left word inside a left-to-right sentence (or the other way round) must be moved as a whole
+//*
+//* The algorithm assigns every character an embedding level: even levels are left-to-right, odd ones right-to-left
+//*     "Hello שלום 123!"  ->  levels 0 0 0 0 0 0 1 1 1 1 1 2 2 2 0
+//* (the space before "123" lies between two right-to-left characters, numbers count as such for neutrals)
+//* and then reverses every run of characters from the highest level down to the lowest odd level
+//*
+//* Not covered: line breaking (a paragraph is displayed as one line) and the terminal's own reordering,
+//* terminals that already apply the algorithm must be given the logical order instead
+
+//* The names are the short names from the Unicode standard
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    //* strong: left-to-right, right-to-left, Arabic letter
+    L,
+    R,
+    AL,
+    //* weak: European number and its separator/terminator, Arabic number, common separator, nonspacing mark, boundary neutral
+    EN,
+    ES,
+    ET,
+    AN,
+    CS,
+    NSM,
+    BN,
+    //* neutral: paragraph separator, segment separator, whitespace, other
+    B,
+    S,
+    WS,
+    ON,
+    //* explicit formatting: embeddings, overrides and their pop, isolates and their pop
+    LRE,
+    LRO,
+    RLE,
+    RLO,
+    PDF,
+    LRI,
+    RLI,
+    FSI,
+    PDI,
+}
+
+use BidiClass::*;
+
+pub fn bidi_class(c: char) -> BidiClass {
+    let i = BIDI_CLASS.partition_point(|&(_, last, _)| last < c);
+    match BIDI_CLASS.get(i) {
+        Some(&(first, _, class)) if first <= c => class,
+        _ => L,
+    }
+}
+
+//* Drawn as its mirror image in right-to-left text: '(' becomes ')'
+pub fn mirrored(c: char) -> Option<char> {
+    MIRRORED.binary_search_by_key(&c, |&(from, _)| from).ok().map(|i| MIRRORED[i].1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Direction {
+    pub fn of_level(level: u8) -> Direction {
+        if level.is_multiple_of(2) {
+            Direction::LeftToRight
+        } else {
+            Direction::RightToLeft
+        }
+    }
+
+    fn strong_class(self) -> BidiClass {
+        match self {
+            Direction::LeftToRight => L,
+            Direction::RightToLeft => R,
+        }
+    }
+}
+
+//* The direction of the first strong character (rules P2 and P3), None when there is none
+//* Characters inside isolates (between e.g. U+2067 RLI and U+2069 PDI) are skipped
+pub fn paragraph_direction(text: &str) -> Option<Direction> {
+    let classes: Vec<BidiClass> = text.chars().map(bidi_class).collect();
+    first_strong(&classes)
+}
+
+fn first_strong(classes: &[BidiClass]) -> Option<Direction> {
+    let mut isolates = 0;
+    for &class in classes {
+        match class {
+            L if isolates == 0 => return Some(Direction::LeftToRight),
+            R | AL if isolates == 0 => return Some(Direction::RightToLeft),
+            LRI | RLI | FSI => isolates += 1,
+            PDI if isolates > 0 => isolates -= 1,
+            B => break,
+            _ => {}
+        }
+    }
+    None
+}
+
+//* Splits after every paragraph separator ('\n', '\r\n', U+2029...), each paragraph gets its own direction
+pub fn paragraphs(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = rest.len();
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if bidi_class(c) == B {
+                //* '\r\n' is a single separator
+                let next_is_lf = c == '\r' && chars.peek().map(|&(_, next)| next) == Some('\n');
+                end = i + c.len_utf8() + if next_is_lf { 1 } else { 0 };
+                break;
+            }
+        }
+        let (paragraph, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(paragraph)
+    })
+}
+
+//* Converts text from logical to display order, paragraph by paragraph, each in the direction of its first strong character
+pub fn display(text: &str) -> String {
+    paragraphs(text).map(|paragraph| Paragraph::new(paragraph, None).display()).collect()
+}
+
+//* A consecutive piece of text with the same embedding level
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run<'a> {
+    //* byte range in the paragraph
+    pub range: Range<usize>,
+    pub level: u8,
+    pub text: &'a str,
+}
+
+impl Run<'_> {
+    pub fn direction(&self) -> Direction {
+        Direction::of_level(self.level)
+    }
+}
+
+//* The resolved levels of one paragraph
+#[derive(Debug, Clone)]
+pub struct Paragraph<'a> {
+    text: &'a str,
+    level: u8,
+    offsets: Vec<usize>,
+    chars: Vec<char>,
+    classes: Vec<BidiClass>,
+    levels: Vec<u8>,
+    //* the levels after rule L1 for displaying the paragraph as a single line
+    line_levels: Vec<u8>,
+}
+
+const MAX_DEPTH: u8 = 125;
+
+impl<'a> Paragraph<'a> {
+    //* 'direction' overrides the direction found from the first strong character
+    pub fn new(text: &'a str, direction: Option<Direction>) -> Paragraph<'a> {
+        let offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        let chars: Vec<char> = text.chars().collect();
+        let classes: Vec<BidiClass> = chars.iter().map(|&c| bidi_class(c)).collect();
+        let level = match direction.or_else(|| first_strong(&classes)) {
+            Some(Direction::RightToLeft) => 1,
+            _ => 0,
+        };
+
+        let mut paragraph = Paragraph { text, level, offsets, chars, classes, levels: Vec::new(), line_levels: Vec::new() };
+        paragraph.resolve();
+        paragraph.line_levels = paragraph.line_levels(0..paragraph.chars.len());
+        paragraph
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn direction(&self) -> Direction {
+        Direction::of_level(self.level)
+    }
+
+    //* The embedding level of every char (not byte) before the line rules are applied
+    pub fn levels(&self) -> &[u8] {
+        &self.levels
+    }
+
+    //* The levels after rule L1, the ones runs(), visual_order() and display() use
+    pub fn display_levels(&self) -> &[u8] {
+        &self.line_levels
+    }
+
+    //* Runs in logical order
+    pub fn runs(&self) -> Vec<Run<'a>> {
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for i in 1..=self.chars.len() {
+            if i == self.chars.len() || self.line_levels[i] != self.line_levels[start] {
+                runs.push(self.run(start..i));
+                start = i;
+            }
+        }
+        runs
+    }
+
+    //* Runs in display order, from left to right
+    pub fn visual_runs(&self) -> Vec<Run<'a>> {
+        let order = self.visual_order();
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for i in 1..=order.len() {
+            let continues = i < order.len()
+                && self.line_levels[order[i]] == self.line_levels[order[start]]
+                && (order[i] == order[i - 1] + 1 || order[i] + 1 == order[i - 1]);
+            if !continues {
+                let (first, last) = (order[start].min(order[i - 1]), order[start].max(order[i - 1]));
+                runs.push(self.run(first..last + 1));
+                start = i;
+            }
+        }
+        runs
+    }
+
+    //* Char indices in display order (rule L2)
+    pub fn visual_order(&self) -> Vec<usize> {
+        let levels = &self.line_levels;
+        let mut order: Vec<usize> = (0..levels.len()).collect();
+        let highest = levels.iter().copied().max().unwrap_or(0);
+        let lowest_odd = levels.iter().copied().filter(|level| level % 2 == 1).min().unwrap_or(highest + 1);
+        for level in (lowest_odd..=highest).rev() {
+            let mut i = 0;
+            while i < order.len() {
+                if levels[order[i]] < level {
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < order.len() && levels[order[i]] >= level {
+                    i += 1;
+                }
+                order[start..i].reverse();
+            }
+        }
+        order
+    }
+
+    //* The text in display order, ready to be printed left to right
+    //* Brackets in right-to-left runs are mirrored, explicit formatting characters are dropped
+    //* and combining marks stay behind their base character
+    pub fn display(&self) -> String {
+        let mut out = String::with_capacity(self.text.len());
+        let mut marks = Vec::new();
+        for i in self.visual_order() {
+            let c = self.chars[i];
+            match self.classes[i] {
+                LRE | RLE | LRO | RLO | PDF | LRI | RLI | FSI | PDI => continue,
+                NSM if self.line_levels[i] % 2 == 1 => {
+                    marks.push(c);
+                    continue;
+                }
+                _ => {}
+            }
+            if self.line_levels[i] % 2 == 1 {
+                out.push(mirrored(c).unwrap_or(c));
+            } else {
+                out.push(c);
+            }
+            out.extend(marks.drain(..).rev());
+        }
+        out.extend(marks.drain(..).rev());
+        out
+    }
+
+    fn run(&self, chars: Range<usize>) -> Run<'a> {
+        let start = self.offsets[chars.start];
+        let end = self.offsets.get(chars.end).copied().unwrap_or(self.text.len());
+        Run { range: start..end, level: self.line_levels[chars.start], text: &self.text[start..end] }
+    }
+
+    //* Rule L1: separators, and whitespace before them or at the end of the line, go back to the paragraph level
+    fn line_levels(&self, line: Range<usize>) -> Vec<u8> {
+        let mut levels = self.levels[line.clone()].to_vec();
+        let mut trailing = true;
+        for i in line.clone().rev() {
+            let class = self.classes[i];
+            let whitespace = matches!(class, WS | LRI | RLI | FSI | PDI) || removed_by_x9(class);
+            if matches!(class, S | B) {
+                levels[i - line.start] = self.level;
+                trailing = true;
+            } else if whitespace && trailing {
+                levels[i - line.start] = self.level;
+            } else {
+                trailing = false;
+            }
+        }
+        levels
+    }
+
+    fn resolve(&mut self) {
+        let len = self.chars.len();
+        let mut types = self.classes.clone();
+        let mut levels = vec![self.level; len];
+
+        //* BD9: the PDI closing each isolate initiator
+        let mut matching_pdi: Vec<Option<usize>> = vec![None; len];
+        let mut has_initiator = vec![false; len];
+        let mut open = Vec::new();
+        for (i, class) in self.classes.iter().enumerate() {
+            match class {
+                LRI | RLI | FSI => open.push(i),
+                PDI => {
+                    if let Some(initiator) = open.pop() {
+                        matching_pdi[initiator] = Some(i);
+                        has_initiator[i] = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.explicit_levels(&mut types, &mut levels, &matching_pdi);
+
+        //* sos and eos are found from the explicit levels, the resolved ones must not leak into the next sequence
+        let explicit = levels.clone();
+        for sequence in self.isolating_run_sequences(&explicit, &matching_pdi, &has_initiator) {
+            self.resolve_sequence(&sequence, &explicit, &mut types, &mut levels, &matching_pdi);
+        }
+
+        //* Characters removed by X9 take the level of the character before them
+        for i in 0..len {
+            if removed_by_x9(self.classes[i]) {
+                levels[i] = if i == 0 { self.level } else { levels[i - 1] };
+            }
+        }
+        self.levels = levels;
+    }
+
+    //* Rules X1 to X8
+    fn explicit_levels(&self, types: &mut [BidiClass], levels: &mut [u8], matching_pdi: &[Option<usize>]) {
+        struct Status {
+            level: u8,
+            overridden: Option<BidiClass>,
+            isolate: bool,
+        }
+
+        let mut stack = vec![Status { level: self.level, overridden: None, isolate: false }];
+        let mut overflow_isolates = 0;
+        let mut overflow_embeddings = 0;
+        let mut valid_isolates = 0;
+
+        for i in 0..self.classes.len() {
+            let current = stack.last().unwrap();
+            let (current_level, current_override) = (current.level, current.overridden);
+            match self.classes[i] {
+                class @ (RLE | LRE | RLO | LRO) => {
+                    levels[i] = current_level;
+                    let level = if matches!(class, RLE | RLO) { (current_level + 1) | 1 } else { (current_level + 2) & !1 };
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        let overridden = match class {
+                            RLO => Some(R),
+                            LRO => Some(L),
+                            _ => None,
+                        };
+                        stack.push(Status { level, overridden, isolate: false });
+                    } else if overflow_isolates == 0 {
+                        overflow_embeddings += 1;
+                    }
+                }
+                class @ (RLI | LRI | FSI) => {
+                    levels[i] = current_level;
+                    if let Some(overridden) = current_override {
+                        types[i] = overridden;
+                    }
+                    let rtl = match class {
+                        RLI => true,
+                        LRI => false,
+                        _ => {
+                            let end = matching_pdi[i].unwrap_or(self.classes.len());
+                            first_strong(&self.classes[i + 1..end]) == Some(Direction::RightToLeft)
+                        }
+                    };
+                    let level = if rtl { (current_level + 1) | 1 } else { (current_level + 2) & !1 };
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        valid_isolates += 1;
+                        stack.push(Status { level, overridden: None, isolate: true });
+                    } else {
+                        overflow_isolates += 1;
+                    }
+                }
+                PDI => {
+                    if overflow_isolates > 0 {
+                        overflow_isolates -= 1;
+                    } else if valid_isolates > 0 {
+                        overflow_embeddings = 0;
+                        while !stack.last().unwrap().isolate {
+                            stack.pop();
+                        }
+                        stack.pop();
+                        valid_isolates -= 1;
+                    }
+                    let current = stack.last().unwrap();
+                    levels[i] = current.level;
+                    if let Some(overridden) = current.overridden {
+                        types[i] = overridden;
+                    }
+                }
+                PDF => {
+                    levels[i] = current_level;
+                    if overflow_isolates == 0 && overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if overflow_isolates == 0 && !stack.last().unwrap().isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+                B => levels[i] = self.level,
+                BN => levels[i] = current_level,
+                _ => {
+                    levels[i] = current_level;
+                    if let Some(overridden) = current_override {
+                        types[i] = overridden;
+                    }
+                }
+            }
+        }
+    }
+
+    //* BD13: level runs joined across isolates, e.g. the text before and after an isolate form one sequence
+    fn isolating_run_sequences(&self, levels: &[u8], matching_pdi: &[Option<usize>], has_initiator: &[bool]) -> Vec<Vec<usize>> {
+        let mut runs: Vec<Vec<usize>> = Vec::new();
+        let mut run_of = vec![usize::MAX; levels.len()];
+        let mut last_level = None;
+        for i in (0..levels.len()).filter(|&i| !removed_by_x9(self.classes[i])) {
+            if last_level != Some(levels[i]) {
+                runs.push(Vec::new());
+                last_level = Some(levels[i]);
+            }
+            runs.last_mut().unwrap().push(i);
+            run_of[i] = runs.len() - 1;
+        }
+
+        let mut sequences = Vec::new();
+        for run in &runs {
+            let first = run[0];
+            if self.classes[first] == PDI && has_initiator[first] {
+                continue;
+            }
+            let mut sequence = run.clone();
+            while let Some(pdi) = matching_pdi[*sequence.last().unwrap()] {
+                sequence.extend(&runs[run_of[pdi]]);
+            }
+            sequences.push(sequence);
+        }
+        sequences
+    }
+
+    //* Rules W1 to W7, N0 to N2, I1 and I2 for one isolating run sequence
+    fn resolve_sequence(
+        &self,
+        sequence: &[usize],
+        explicit: &[u8],
+        types: &mut [BidiClass],
+        levels: &mut [u8],
+        matching_pdi: &[Option<usize>],
+    ) {
+        let level = explicit[sequence[0]];
+        let first = sequence[0];
+        let last = *sequence.last().unwrap();
+
+        let before = (0..first).rev().find(|&i| !removed_by_x9(self.classes[i])).map_or(self.level, |i| explicit[i]);
+        let after = if is_isolate_initiator(self.classes[last]) && matching_pdi[last].is_none() {
+            self.level
+        } else {
+            (last + 1..explicit.len()).find(|&i| !removed_by_x9(self.classes[i])).map_or(self.level, |i| explicit[i])
+        };
+        let sos = Direction::of_level(level.max(before)).strong_class();
+        let eos = Direction::of_level(level.max(after)).strong_class();
+        let embedding = Direction::of_level(level).strong_class();
+
+        let mut t: Vec<BidiClass> = sequence.iter().map(|&i| types[i]).collect();
+        let n = t.len();
+
+        //* W1: nonspacing marks take the type of the character before them
+        for k in 0..n {
+            if t[k] == NSM {
+                t[k] = if k == 0 {
+                    sos
+                } else if matches!(t[k - 1], LRI | RLI | FSI | PDI) {
+                    ON
+                } else {
+                    t[k - 1]
+                };
+            }
+        }
+        //* W2: European numbers after Arabic letters are Arabic numbers,  W3: Arabic letters are R
+        let mut strong = sos;
+        for class in t.iter_mut() {
+            match *class {
+                L | R | AL => strong = *class,
+                EN if strong == AL => *class = AN,
+                _ => {}
+            }
+        }
+        for class in t.iter_mut().filter(|class| **class == AL) {
+            *class = R;
+        }
+        //* W4: a single separator between two numbers of the same kind joins them
+        for k in 1..n.saturating_sub(1) {
+            t[k] = match (t[k - 1], t[k], t[k + 1]) {
+                (EN, ES, EN) | (EN, CS, EN) => EN,
+                (AN, CS, AN) => AN,
+                (_, class, _) => class,
+            };
+        }
+        //* W5: terminators next to European numbers become part of them
+        let mut k = 0;
+        while k < n {
+            if t[k] != ET {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < n && t[k] == ET {
+                k += 1;
+            }
+            if (start > 0 && t[start - 1] == EN) || (k < n && t[k] == EN) {
+                t[start..k].iter_mut().for_each(|class| *class = EN);
+            }
+        }
+        //* W6: remaining separators and terminators are neutral
+        for class in t.iter_mut().filter(|class| matches!(class, ES | ET | CS)) {
+            *class = ON;
+        }
+        //* W7: European numbers in left-to-right context are L
+        let mut strong = sos;
+        for class in t.iter_mut() {
+            match *class {
+                L | R => strong = *class,
+                EN if strong == L => *class = L,
+                _ => {}
+            }
+        }
+
+        self.resolve_brackets(sequence, &mut t, sos, embedding);
+
+        //* N1, N2: neutrals between two strong types of the same direction take that direction, otherwise the embedding direction
+        let direction_of = |class: BidiClass| match class {
+            L => Some(L),
+            R | EN | AN => Some(R),
+            _ => None,
+        };
+        let mut k = 0;
+        while k < n {
+            if !is_neutral(t[k]) {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < n && is_neutral(t[k]) {
+                k += 1;
+            }
+            let leading = if start == 0 { Some(sos) } else { direction_of(t[start - 1]) };
+            let trailing = if k == n { Some(eos) } else { direction_of(t[k]) };
+            let resolved = if leading == trailing { leading.unwrap_or(embedding) } else { embedding };
+            t[start..k].iter_mut().for_each(|class| *class = resolved);
+        }
+
+        //* I1, I2
+        for (k, &i) in sequence.iter().enumerate() {
+            levels[i] = match (level % 2, t[k]) {
+                (0, R) => level + 1,
+                (0, AN) | (0, EN) => level + 2,
+                (1, L) | (1, EN) | (1, AN) => level + 1,
+                _ => level,
+            };
+        }
+    }
+
+    //* N0: a pair of brackets takes the direction of the text inside, so "(שלום)" doesn't end up as ")שלום("
+    fn resolve_brackets(&self, sequence: &[usize], t: &mut [BidiClass], sos: BidiClass, embedding: BidiClass) {
+        //* BD16: at most 63 nested brackets are tracked
+        let mut pairs = Vec::new();
+        let mut open: Vec<(char, usize)> = Vec::new();
+        for (k, &i) in sequence.iter().enumerate() {
+            if t[k] != ON {
+                continue;
+            }
+            let Some((paired, opening)) = bracket(self.chars[i]) else { continue };
+            if opening {
+                if open.len() == 63 {
+                    break;
+                }
+                open.push((paired, k));
+            } else if let Some(position) = open.iter().rposition(|&(closing, _)| closing == canonical_bracket(self.chars[i])) {
+                pairs.push((open[position].1, k));
+                open.truncate(position);
+            }
+        }
+        pairs.sort_unstable();
+
+        let strong = |class: BidiClass| match class {
+            L => Some(L),
+            R | AL | EN | AN => Some(R),
+            _ => None,
+        };
+        for (opening, closing) in pairs {
+            let inside: Vec<BidiClass> = t[opening + 1..closing].iter().filter_map(|&class| strong(class)).collect();
+            let resolved = if inside.contains(&embedding) {
+                embedding
+            } else if let Some(&opposite) = inside.first() {
+                let context = t[..opening].iter().rev().find_map(|&class| strong(class)).unwrap_or(sos);
+                if context == opposite {
+                    opposite
+                } else {
+                    embedding
+                }
+            } else {
+                continue;
+            };
+            for position in [opening, closing] {
+                t[position] = resolved;
+                for k in position + 1..sequence.len() {
+                    if self.classes[sequence[k]] != NSM {
+                        break;
+                    }
+                    t[k] = resolved;
+                }
+            }
+        }
+    }
+}
+
+fn removed_by_x9(class: BidiClass) -> bool {
+    matches!(class, RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(class, LRI | RLI | FSI)
+}
+
+fn is_neutral(class: BidiClass) -> bool {
+    matches!(class, B | S | WS | ON | LRI | RLI | FSI | PDI)
+}
+
+//* (paired bracket, is opening), the paired bracket of an opening one is returned in its canonical form
+fn bracket(c: char) -> Option<(char, bool)> {
+    let i = BRACKETS.binary_search_by_key(&c, |&(from, _, _)| from).ok()?;
+    let (_, paired, opening) = BRACKETS[i];
+    Some((canonical_bracket(paired), opening))
+}
+
+//* U+2329 and U+232A are canonically equivalent to U+3008 and U+3009
+fn canonical_bracket(c: char) -> char {
+    match c {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        c => c,
+    }
+}
@@ -0,0 +1,1296 @@
+//* Unicode bidi data, sorted by code point
+//* Bidi classes from UnicodeData.txt (Unicode 15.1) with the defaults of DerivedBidiClass.txt for unassigned code points,
+//* brackets from BidiBrackets.txt and mirrored glyphs from BidiMirroring.txt
+
+use super::BidiClass::{self, *};
+
+//* (first, last, class), code points that are missing are L
+pub(super) static BIDI_CLASS: [(char, char, BidiClass); 723] = [
+    ('\u{0}', '\u{8}', BN),
+    ('\u{9}', '\u{9}', S),
+    ('\u{A}', '\u{A}', B),
+    ('\u{B}', '\u{B}', S),
+    ('\u{C}', '\u{C}', WS),
+    ('\u{D}', '\u{D}', B),
+    ('\u{E}', '\u{1B}', BN),
+    ('\u{1C}', '\u{1E}', B),
+    ('\u{1F}', '\u{1F}', S),
+    ('\u{20}', '\u{20}', WS),
+    ('!', '"', ON),
+    ('#', '%', ET),
+    ('&', '*', ON),
+    ('+', '+', ES),
+    (',', ',', CS),
+    ('-', '-', ES),
+    ('.', '/', CS),
+    ('0', '9', EN),
+    (':', ':', CS),
+    (';', '@', ON),
+    ('[', '`', ON),
+    ('{', '~', ON),
+    ('\u{7F}', '\u{84}', BN),
+    ('\u{85}', '\u{85}', B),
+    ('\u{86}', '\u{9F}', BN),
+    ('\u{A0}', '\u{A0}', CS),
+    ('\u{A1}', '\u{A1}', ON),
+    ('\u{A2}', '\u{A5}', ET),
+    ('\u{A6}', '\u{A9}', ON),
+    ('\u{AB}', '\u{AC}', ON),
+    ('\u{AD}', '\u{AD}', BN),
+    ('\u{AE}', '\u{AF}', ON),
+    ('\u{B0}', '\u{B1}', ET),
+    ('\u{B2}', '\u{B3}', EN),
+    ('\u{B4}', '\u{B4}', ON),
+    ('\u{B6}', '\u{B8}', ON),
+    ('\u{B9}', '\u{B9}', EN),
+    ('\u{BB}', '\u{BF}', ON),
+    ('\u{D7}', '\u{D7}', ON),
+    ('\u{F7}', '\u{F7}', ON),
+    ('\u{2B9}', '\u{2BA}', ON),
+    ('\u{2C2}', '\u{2CF}', ON),
+    ('\u{2D2}', '\u{2DF}', ON),
+    ('\u{2E5}', '\u{2ED}', ON),
+    ('\u{2EF}', '\u{2FF}', ON),
+    ('\u{300}', '\u{36F}', NSM),
+    ('\u{374}', '\u{375}', ON),
+    ('\u{37E}', '\u{37E}', ON),
+    ('\u{384}', '\u{385}', ON),
+    ('\u{387}', '\u{387}', ON),
+    ('\u{3F6}', '\u{3F6}', ON),
+    ('\u{483}', '\u{489}', NSM),
+    ('\u{58A}', '\u{58A}', ON),
+    ('\u{58D}', '\u{58E}', ON),
+    ('\u{58F}', '\u{58F}', ET),
+    ('\u{590}', '\u{590}', R),
+    ('\u{591}', '\u{5BD}', NSM),
+    ('\u{5BE}', '\u{5BE}', R),
+    ('\u{5BF}', '\u{5BF}', NSM),
+    ('\u{5C0}', '\u{5C0}', R),
+    ('\u{5C1}', '\u{5C2}', NSM),
+    ('\u{5C3}', '\u{5C3}', R),
+    ('\u{5C4}', '\u{5C5}', NSM),
+    ('\u{5C6}', '\u{5C6}', R),
+    ('\u{5C7}', '\u{5C7}', NSM),
+    ('\u{5C8}', '\u{5FF}', R),
+    ('\u{600}', '\u{605}', AN),
+    ('\u{606}', '\u{607}', ON),
+    ('\u{608}', '\u{608}', AL),
+    ('\u{609}', '\u{60A}', ET),
+    ('\u{60B}', '\u{60B}', AL),
+    ('\u{60C}', '\u{60C}', CS),
+    ('\u{60D}', '\u{60D}', AL),
+    ('\u{60E}', '\u{60F}', ON),
+    ('\u{610}', '\u{61A}', NSM),
+    ('\u{61B}', '\u{64A}', AL),
+    ('\u{64B}', '\u{65F}', NSM),
+    ('\u{660}', '\u{669}', AN),
+    ('\u{66A}', '\u{66A}', ET),
+    ('\u{66B}', '\u{66C}', AN),
+    ('\u{66D}', '\u{66F}', AL),
+    ('\u{670}', '\u{670}', NSM),
+    ('\u{671}', '\u{6D5}', AL),
+    ('\u{6D6}', '\u{6DC}', NSM),
+    ('\u{6DD}', '\u{6DD}', AN),
+    ('\u{6DE}', '\u{6DE}', ON),
+    ('\u{6DF}', '\u{6E4}', NSM),
+    ('\u{6E5}', '\u{6E6}', AL),
+    ('\u{6E7}', '\u{6E8}', NSM),
+    ('\u{6E9}', '\u{6E9}', ON),
+    ('\u{6EA}', '\u{6ED}', NSM),
+    ('\u{6EE}', '\u{6EF}', AL),
+    ('\u{6F0}', '\u{6F9}', EN),
+    ('\u{6FA}', '\u{710}', AL),
+    ('\u{711}', '\u{711}', NSM),
+    ('\u{712}', '\u{72F}', AL),
+    ('\u{730}', '\u{74A}', NSM),
+    ('\u{74B}', '\u{7A5}', AL),
+    ('\u{7A6}', '\u{7B0}', NSM),
+    ('\u{7B1}', '\u{7BF}', AL),
+    ('\u{7C0}', '\u{7EA}', R),
+    ('\u{7EB}', '\u{7F3}', NSM),
+    ('\u{7F4}', '\u{7F5}', R),
+    ('\u{7F6}', '\u{7F9}', ON),
+    ('\u{7FA}', '\u{7FC}', R),
+    ('\u{7FD}', '\u{7FD}', NSM),
+    ('\u{7FE}', '\u{815}', R),
+    ('\u{816}', '\u{819}', NSM),
+    ('\u{81A}', '\u{81A}', R),
+    ('\u{81B}', '\u{823}', NSM),
+    ('\u{824}', '\u{824}', R),
+    ('\u{825}', '\u{827}', NSM),
+    ('\u{828}', '\u{828}', R),
+    ('\u{829}', '\u{82D}', NSM),
+    ('\u{82E}', '\u{858}', R),
+    ('\u{859}', '\u{85B}', NSM),
+    ('\u{85C}', '\u{85F}', R),
+    ('\u{860}', '\u{88F}', AL),
+    ('\u{890}', '\u{891}', AN),
+    ('\u{892}', '\u{897}', AL),
+    ('\u{898}', '\u{89F}', NSM),
+    ('\u{8A0}', '\u{8C9}', AL),
+    ('\u{8CA}', '\u{8E1}', NSM),
+    ('\u{8E2}', '\u{8E2}', AN),
+    ('\u{8E3}', '\u{902}', NSM),
+    ('\u{93A}', '\u{93A}', NSM),
+    ('\u{93C}', '\u{93C}', NSM),
+    ('\u{941}', '\u{948}', NSM),
+    ('\u{94D}', '\u{94D}', NSM),
+    ('\u{951}', '\u{957}', NSM),
+    ('\u{962}', '\u{963}', NSM),
+    ('\u{981}', '\u{981}', NSM),
+    ('\u{9BC}', '\u{9BC}', NSM),
+    ('\u{9C1}', '\u{9C4}', NSM),
+    ('\u{9CD}', '\u{9CD}', NSM),
+    ('\u{9E2}', '\u{9E3}', NSM),
+    ('\u{9F2}', '\u{9F3}', ET),
+    ('\u{9FB}', '\u{9FB}', ET),
+    ('\u{9FE}', '\u{9FE}', NSM),
+    ('\u{A01}', '\u{A02}', NSM),
+    ('\u{A3C}', '\u{A3C}', NSM),
+    ('\u{A41}', '\u{A42}', NSM),
+    ('\u{A47}', '\u{A48}', NSM),
+    ('\u{A4B}', '\u{A4D}', NSM),
+    ('\u{A51}', '\u{A51}', NSM),
+    ('\u{A70}', '\u{A71}', NSM),
+    ('\u{A75}', '\u{A75}', NSM),
+    ('\u{A81}', '\u{A82}', NSM),
+    ('\u{ABC}', '\u{ABC}', NSM),
+    ('\u{AC1}', '\u{AC5}', NSM),
+    ('\u{AC7}', '\u{AC8}', NSM),
+    ('\u{ACD}', '\u{ACD}', NSM),
+    ('\u{AE2}', '\u{AE3}', NSM),
+    ('\u{AF1}', '\u{AF1}', ET),
+    ('\u{AFA}', '\u{AFF}', NSM),
+    ('\u{B01}', '\u{B01}', NSM),
+    ('\u{B3C}', '\u{B3C}', NSM),
+    ('\u{B3F}', '\u{B3F}', NSM),
+    ('\u{B41}', '\u{B44}', NSM),
+    ('\u{B4D}', '\u{B4D}', NSM),
+    ('\u{B55}', '\u{B56}', NSM),
+    ('\u{B62}', '\u{B63}', NSM),
+    ('\u{B82}', '\u{B82}', NSM),
+    ('\u{BC0}', '\u{BC0}', NSM),
+    ('\u{BCD}', '\u{BCD}', NSM),
+    ('\u{BF3}', '\u{BF8}', ON),
+    ('\u{BF9}', '\u{BF9}', ET),
+    ('\u{BFA}', '\u{BFA}', ON),
+    ('\u{C00}', '\u{C00}', NSM),
+    ('\u{C04}', '\u{C04}', NSM),
+    ('\u{C3C}', '\u{C3C}', NSM),
+    ('\u{C3E}', '\u{C40}', NSM),
+    ('\u{C46}', '\u{C48}', NSM),
+    ('\u{C4A}', '\u{C4D}', NSM),
+    ('\u{C55}', '\u{C56}', NSM),
+    ('\u{C62}', '\u{C63}', NSM),
+    ('\u{C78}', '\u{C7E}', ON),
+    ('\u{C81}', '\u{C81}', NSM),
+    ('\u{CBC}', '\u{CBC}', NSM),
+    ('\u{CCC}', '\u{CCD}', NSM),
+    ('\u{CE2}', '\u{CE3}', NSM),
+    ('\u{D00}', '\u{D01}', NSM),
+    ('\u{D3B}', '\u{D3C}', NSM),
+    ('\u{D41}', '\u{D44}', NSM),
+    ('\u{D4D}', '\u{D4D}', NSM),
+    ('\u{D62}', '\u{D63}', NSM),
+    ('\u{D81}', '\u{D81}', NSM),
+    ('\u{DCA}', '\u{DCA}', NSM),
+    ('\u{DD2}', '\u{DD4}', NSM),
+    ('\u{DD6}', '\u{DD6}', NSM),
+    ('\u{E31}', '\u{E31}', NSM),
+    ('\u{E34}', '\u{E3A}', NSM),
+    ('\u{E3F}', '\u{E3F}', ET),
+    ('\u{E47}', '\u{E4E}', NSM),
+    ('\u{EB1}', '\u{EB1}', NSM),
+    ('\u{EB4}', '\u{EBC}', NSM),
+    ('\u{EC8}', '\u{ECE}', NSM),
+    ('\u{F18}', '\u{F19}', NSM),
+    ('\u{F35}', '\u{F35}', NSM),
+    ('\u{F37}', '\u{F37}', NSM),
+    ('\u{F39}', '\u{F39}', NSM),
+    ('\u{F3A}', '\u{F3D}', ON),
+    ('\u{F71}', '\u{F7E}', NSM),
+    ('\u{F80}', '\u{F84}', NSM),
+    ('\u{F86}', '\u{F87}', NSM),
+    ('\u{F8D}', '\u{F97}', NSM),
+    ('\u{F99}', '\u{FBC}', NSM),
+    ('\u{FC6}', '\u{FC6}', NSM),
+    ('\u{102D}', '\u{1030}', NSM),
+    ('\u{1032}', '\u{1037}', NSM),
+    ('\u{1039}', '\u{103A}', NSM),
+    ('\u{103D}', '\u{103E}', NSM),
+    ('\u{1058}', '\u{1059}', NSM),
+    ('\u{105E}', '\u{1060}', NSM),
+    ('\u{1071}', '\u{1074}', NSM),
+    ('\u{1082}', '\u{1082}', NSM),
+    ('\u{1085}', '\u{1086}', NSM),
+    ('\u{108D}', '\u{108D}', NSM),
+    ('\u{109D}', '\u{109D}', NSM),
+    ('\u{135D}', '\u{135F}', NSM),
+    ('\u{1390}', '\u{1399}', ON),
+    ('\u{1400}', '\u{1400}', ON),
+    ('\u{1680}', '\u{1680}', WS),
+    ('\u{169B}', '\u{169C}', ON),
+    ('\u{1712}', '\u{1714}', NSM),
+    ('\u{1732}', '\u{1733}', NSM),
+    ('\u{1752}', '\u{1753}', NSM),
+    ('\u{1772}', '\u{1773}', NSM),
+    ('\u{17B4}', '\u{17B5}', NSM),
+    ('\u{17B7}', '\u{17BD}', NSM),
+    ('\u{17C6}', '\u{17C6}', NSM),
+    ('\u{17C9}', '\u{17D3}', NSM),
+    ('\u{17DB}', '\u{17DB}', ET),
+    ('\u{17DD}', '\u{17DD}', NSM),
+    ('\u{17F0}', '\u{17F9}', ON),
+    ('\u{1800}', '\u{180A}', ON),
+    ('\u{180B}', '\u{180D}', NSM),
+    ('\u{180E}', '\u{180E}', BN),
+    ('\u{180F}', '\u{180F}', NSM),
+    ('\u{1885}', '\u{1886}', NSM),
+    ('\u{18A9}', '\u{18A9}', NSM),
+    ('\u{1920}', '\u{1922}', NSM),
+    ('\u{1927}', '\u{1928}', NSM),
+    ('\u{1932}', '\u{1932}', NSM),
+    ('\u{1939}', '\u{193B}', NSM),
+    ('\u{1940}', '\u{1940}', ON),
+    ('\u{1944}', '\u{1945}', ON),
+    ('\u{19DE}', '\u{19FF}', ON),
+    ('\u{1A17}', '\u{1A18}', NSM),
+    ('\u{1A1B}', '\u{1A1B}', NSM),
+    ('\u{1A56}', '\u{1A56}', NSM),
+    ('\u{1A58}', '\u{1A5E}', NSM),
+    ('\u{1A60}', '\u{1A60}', NSM),
+    ('\u{1A62}', '\u{1A62}', NSM),
+    ('\u{1A65}', '\u{1A6C}', NSM),
+    ('\u{1A73}', '\u{1A7C}', NSM),
+    ('\u{1A7F}', '\u{1A7F}', NSM),
+    ('\u{1AB0}', '\u{1ACE}', NSM),
+    ('\u{1B00}', '\u{1B03}', NSM),
+    ('\u{1B34}', '\u{1B34}', NSM),
+    ('\u{1B36}', '\u{1B3A}', NSM),
+    ('\u{1B3C}', '\u{1B3C}', NSM),
+    ('\u{1B42}', '\u{1B42}', NSM),
+    ('\u{1B6B}', '\u{1B73}', NSM),
+    ('\u{1B80}', '\u{1B81}', NSM),
+    ('\u{1BA2}', '\u{1BA5}', NSM),
+    ('\u{1BA8}', '\u{1BA9}', NSM),
+    ('\u{1BAB}', '\u{1BAD}', NSM),
+    ('\u{1BE6}', '\u{1BE6}', NSM),
+    ('\u{1BE8}', '\u{1BE9}', NSM),
+    ('\u{1BED}', '\u{1BED}', NSM),
+    ('\u{1BEF}', '\u{1BF1}', NSM),
+    ('\u{1C2C}', '\u{1C33}', NSM),
+    ('\u{1C36}', '\u{1C37}', NSM),
+    ('\u{1CD0}', '\u{1CD2}', NSM),
+    ('\u{1CD4}', '\u{1CE0}', NSM),
+    ('\u{1CE2}', '\u{1CE8}', NSM),
+    ('\u{1CED}', '\u{1CED}', NSM),
+    ('\u{1CF4}', '\u{1CF4}', NSM),
+    ('\u{1CF8}', '\u{1CF9}', NSM),
+    ('\u{1DC0}', '\u{1DFF}', NSM),
+    ('\u{1FBD}', '\u{1FBD}', ON),
+    ('\u{1FBF}', '\u{1FC1}', ON),
+    ('\u{1FCD}', '\u{1FCF}', ON),
+    ('\u{1FDD}', '\u{1FDF}', ON),
+    ('\u{1FED}', '\u{1FEF}', ON),
+    ('\u{1FFD}', '\u{1FFE}', ON),
+    ('\u{2000}', '\u{200A}', WS),
+    ('\u{200B}', '\u{200D}', BN),
+    ('\u{200F}', '\u{200F}', R),
+    ('\u{2010}', '\u{2027}', ON),
+    ('\u{2028}', '\u{2028}', WS),
+    ('\u{2029}', '\u{2029}', B),
+    ('\u{202A}', '\u{202A}', LRE),
+    ('\u{202B}', '\u{202B}', RLE),
+    ('\u{202C}', '\u{202C}', PDF),
+    ('\u{202D}', '\u{202D}', LRO),
+    ('\u{202E}', '\u{202E}', RLO),
+    ('\u{202F}', '\u{202F}', CS),
+    ('\u{2030}', '\u{2034}', ET),
+    ('\u{2035}', '\u{2043}', ON),
+    ('\u{2044}', '\u{2044}', CS),
+    ('\u{2045}', '\u{205E}', ON),
+    ('\u{205F}', '\u{205F}', WS),
+    ('\u{2060}', '\u{2065}', BN),
+    ('\u{2066}', '\u{2066}', LRI),
+    ('\u{2067}', '\u{2067}', RLI),
+    ('\u{2068}', '\u{2068}', FSI),
+    ('\u{2069}', '\u{2069}', PDI),
+    ('\u{206A}', '\u{206F}', BN),
+    ('\u{2070}', '\u{2070}', EN),
+    ('\u{2074}', '\u{2079}', EN),
+    ('\u{207A}', '\u{207B}', ES),
+    ('\u{207C}', '\u{207E}', ON),
+    ('\u{2080}', '\u{2089}', EN),
+    ('\u{208A}', '\u{208B}', ES),
+    ('\u{208C}', '\u{208E}', ON),
+    ('\u{20A0}', '\u{20CF}', ET),
+    ('\u{20D0}', '\u{20F0}', NSM),
+    ('\u{2100}', '\u{2101}', ON),
+    ('\u{2103}', '\u{2106}', ON),
+    ('\u{2108}', '\u{2109}', ON),
+    ('\u{2114}', '\u{2114}', ON),
+    ('\u{2116}', '\u{2118}', ON),
+    ('\u{211E}', '\u{2123}', ON),
+    ('\u{2125}', '\u{2125}', ON),
+    ('\u{2127}', '\u{2127}', ON),
+    ('\u{2129}', '\u{2129}', ON),
+    ('\u{212E}', '\u{212E}', ET),
+    ('\u{213A}', '\u{213B}', ON),
+    ('\u{2140}', '\u{2144}', ON),
+    ('\u{214A}', '\u{214D}', ON),
+    ('\u{2150}', '\u{215F}', ON),
+    ('\u{2189}', '\u{218B}', ON),
+    ('\u{2190}', '\u{2211}', ON),
+    ('\u{2212}', '\u{2212}', ES),
+    ('\u{2213}', '\u{2213}', ET),
+    ('\u{2214}', '\u{2335}', ON),
+    ('\u{237B}', '\u{2394}', ON),
+    ('\u{2396}', '\u{2426}', ON),
+    ('\u{2440}', '\u{244A}', ON),
+    ('\u{2460}', '\u{2487}', ON),
+    ('\u{2488}', '\u{249B}', EN),
+    ('\u{24EA}', '\u{26AB}', ON),
+    ('\u{26AD}', '\u{27FF}', ON),
+    ('\u{2900}', '\u{2B73}', ON),
+    ('\u{2B76}', '\u{2B95}', ON),
+    ('\u{2B97}', '\u{2BFF}', ON),
+    ('\u{2CE5}', '\u{2CEA}', ON),
+    ('\u{2CEF}', '\u{2CF1}', NSM),
+    ('\u{2CF9}', '\u{2CFF}', ON),
+    ('\u{2D7F}', '\u{2D7F}', NSM),
+    ('\u{2DE0}', '\u{2DFF}', NSM),
+    ('\u{2E00}', '\u{2E5D}', ON),
+    ('\u{2E80}', '\u{2E99}', ON),
+    ('\u{2E9B}', '\u{2EF3}', ON),
+    ('\u{2F00}', '\u{2FD5}', ON),
+    ('\u{2FF0}', '\u{2FFF}', ON),
+    ('\u{3000}', '\u{3000}', WS),
+    ('\u{3001}', '\u{3004}', ON),
+    ('\u{3008}', '\u{3020}', ON),
+    ('\u{302A}', '\u{302D}', NSM),
+    ('\u{3030}', '\u{3030}', ON),
+    ('\u{3036}', '\u{3037}', ON),
+    ('\u{303D}', '\u{303F}', ON),
+    ('\u{3099}', '\u{309A}', NSM),
+    ('\u{309B}', '\u{309C}', ON),
+    ('\u{30A0}', '\u{30A0}', ON),
+    ('\u{30FB}', '\u{30FB}', ON),
+    ('\u{31C0}', '\u{31E3}', ON),
+    ('\u{31EF}', '\u{31EF}', ON),
+    ('\u{321D}', '\u{321E}', ON),
+    ('\u{3250}', '\u{325F}', ON),
+    ('\u{327C}', '\u{327E}', ON),
+    ('\u{32B1}', '\u{32BF}', ON),
+    ('\u{32CC}', '\u{32CF}', ON),
+    ('\u{3377}', '\u{337A}', ON),
+    ('\u{33DE}', '\u{33DF}', ON),
+    ('\u{33FF}', '\u{33FF}', ON),
+    ('\u{4DC0}', '\u{4DFF}', ON),
+    ('\u{A490}', '\u{A4C6}', ON),
+    ('\u{A60D}', '\u{A60F}', ON),
+    ('\u{A66F}', '\u{A672}', NSM),
+    ('\u{A673}', '\u{A673}', ON),
+    ('\u{A674}', '\u{A67D}', NSM),
+    ('\u{A67E}', '\u{A67F}', ON),
+    ('\u{A69E}', '\u{A69F}', NSM),
+    ('\u{A6F0}', '\u{A6F1}', NSM),
+    ('\u{A700}', '\u{A721}', ON),
+    ('\u{A788}', '\u{A788}', ON),
+    ('\u{A802}', '\u{A802}', NSM),
+    ('\u{A806}', '\u{A806}', NSM),
+    ('\u{A80B}', '\u{A80B}', NSM),
+    ('\u{A825}', '\u{A826}', NSM),
+    ('\u{A828}', '\u{A82B}', ON),
+    ('\u{A82C}', '\u{A82C}', NSM),
+    ('\u{A838}', '\u{A839}', ET),
+    ('\u{A874}', '\u{A877}', ON),
+    ('\u{A8C4}', '\u{A8C5}', NSM),
+    ('\u{A8E0}', '\u{A8F1}', NSM),
+    ('\u{A8FF}', '\u{A8FF}', NSM),
+    ('\u{A926}', '\u{A92D}', NSM),
+    ('\u{A947}', '\u{A951}', NSM),
+    ('\u{A980}', '\u{A982}', NSM),
+    ('\u{A9B3}', '\u{A9B3}', NSM),
+    ('\u{A9B6}', '\u{A9B9}', NSM),
+    ('\u{A9BC}', '\u{A9BD}', NSM),
+    ('\u{A9E5}', '\u{A9E5}', NSM),
+    ('\u{AA29}', '\u{AA2E}', NSM),
+    ('\u{AA31}', '\u{AA32}', NSM),
+    ('\u{AA35}', '\u{AA36}', NSM),
+    ('\u{AA43}', '\u{AA43}', NSM),
+    ('\u{AA4C}', '\u{AA4C}', NSM),
+    ('\u{AA7C}', '\u{AA7C}', NSM),
+    ('\u{AAB0}', '\u{AAB0}', NSM),
+    ('\u{AAB2}', '\u{AAB4}', NSM),
+    ('\u{AAB7}', '\u{AAB8}', NSM),
+    ('\u{AABE}', '\u{AABF}', NSM),
+    ('\u{AAC1}', '\u{AAC1}', NSM),
+    ('\u{AAEC}', '\u{AAED}', NSM),
+    ('\u{AAF6}', '\u{AAF6}', NSM),
+    ('\u{AB6A}', '\u{AB6B}', ON),
+    ('\u{ABE5}', '\u{ABE5}', NSM),
+    ('\u{ABE8}', '\u{ABE8}', NSM),
+    ('\u{ABED}', '\u{ABED}', NSM),
+    ('\u{FB1D}', '\u{FB1D}', R),
+    ('\u{FB1E}', '\u{FB1E}', NSM),
+    ('\u{FB1F}', '\u{FB28}', R),
+    ('\u{FB29}', '\u{FB29}', ES),
+    ('\u{FB2A}', '\u{FB4F}', R),
+    ('\u{FB50}', '\u{FD3D}', AL),
+    ('\u{FD3E}', '\u{FD4F}', ON),
+    ('\u{FD50}', '\u{FDCE}', AL),
+    ('\u{FDCF}', '\u{FDCF}', ON),
+    ('\u{FDD0}', '\u{FDEF}', BN),
+    ('\u{FDF0}', '\u{FDFC}', AL),
+    ('\u{FDFD}', '\u{FDFF}', ON),
+    ('\u{FE00}', '\u{FE0F}', NSM),
+    ('\u{FE10}', '\u{FE19}', ON),
+    ('\u{FE20}', '\u{FE2F}', NSM),
+    ('\u{FE30}', '\u{FE4F}', ON),
+    ('\u{FE50}', '\u{FE50}', CS),
+    ('\u{FE51}', '\u{FE51}', ON),
+    ('\u{FE52}', '\u{FE52}', CS),
+    ('\u{FE54}', '\u{FE54}', ON),
+    ('\u{FE55}', '\u{FE55}', CS),
+    ('\u{FE56}', '\u{FE5E}', ON),
+    ('\u{FE5F}', '\u{FE5F}', ET),
+    ('\u{FE60}', '\u{FE61}', ON),
+    ('\u{FE62}', '\u{FE63}', ES),
+    ('\u{FE64}', '\u{FE66}', ON),
+    ('\u{FE68}', '\u{FE68}', ON),
+    ('\u{FE69}', '\u{FE6A}', ET),
+    ('\u{FE6B}', '\u{FE6B}', ON),
+    ('\u{FE70}', '\u{FEFE}', AL),
+    ('\u{FEFF}', '\u{FEFF}', BN),
+    ('\u{FF01}', '\u{FF02}', ON),
+    ('\u{FF03}', '\u{FF05}', ET),
+    ('\u{FF06}', '\u{FF0A}', ON),
+    ('\u{FF0B}', '\u{FF0B}', ES),
+    ('\u{FF0C}', '\u{FF0C}', CS),
+    ('\u{FF0D}', '\u{FF0D}', ES),
+    ('\u{FF0E}', '\u{FF0F}', CS),
+    ('\u{FF10}', '\u{FF19}', EN),
+    ('\u{FF1A}', '\u{FF1A}', CS),
+    ('\u{FF1B}', '\u{FF20}', ON),
+    ('\u{FF3B}', '\u{FF40}', ON),
+    ('\u{FF5B}', '\u{FF65}', ON),
+    ('\u{FFE0}', '\u{FFE1}', ET),
+    ('\u{FFE2}', '\u{FFE4}', ON),
+    ('\u{FFE5}', '\u{FFE6}', ET),
+    ('\u{FFE8}', '\u{FFEE}', ON),
+    ('\u{FFF0}', '\u{FFF8}', BN),
+    ('\u{FFF9}', '\u{FFFD}', ON),
+    ('\u{FFFE}', '\u{FFFF}', BN),
+    ('\u{10101}', '\u{10101}', ON),
+    ('\u{10140}', '\u{1018C}', ON),
+    ('\u{10190}', '\u{1019C}', ON),
+    ('\u{101A0}', '\u{101A0}', ON),
+    ('\u{101FD}', '\u{101FD}', NSM),
+    ('\u{102E0}', '\u{102E0}', NSM),
+    ('\u{102E1}', '\u{102FB}', EN),
+    ('\u{10376}', '\u{1037A}', NSM),
+    ('\u{10800}', '\u{1091E}', R),
+    ('\u{1091F}', '\u{1091F}', ON),
+    ('\u{10920}', '\u{10A00}', R),
+    ('\u{10A01}', '\u{10A03}', NSM),
+    ('\u{10A04}', '\u{10A04}', R),
+    ('\u{10A05}', '\u{10A06}', NSM),
+    ('\u{10A07}', '\u{10A0B}', R),
+    ('\u{10A0C}', '\u{10A0F}', NSM),
+    ('\u{10A10}', '\u{10A37}', R),
+    ('\u{10A38}', '\u{10A3A}', NSM),
+    ('\u{10A3B}', '\u{10A3E}', R),
+    ('\u{10A3F}', '\u{10A3F}', NSM),
+    ('\u{10A40}', '\u{10AE4}', R),
+    ('\u{10AE5}', '\u{10AE6}', NSM),
+    ('\u{10AE7}', '\u{10B38}', R),
+    ('\u{10B39}', '\u{10B3F}', ON),
+    ('\u{10B40}', '\u{10CFF}', R),
+    ('\u{10D00}', '\u{10D23}', AL),
+    ('\u{10D24}', '\u{10D27}', NSM),
+    ('\u{10D28}', '\u{10D2F}', AL),
+    ('\u{10D30}', '\u{10D39}', AN),
+    ('\u{10D3A}', '\u{10D3F}', AL),
+    ('\u{10D40}', '\u{10E5F}', R),
+    ('\u{10E60}', '\u{10E7E}', AN),
+    ('\u{10E7F}', '\u{10EAA}', R),
+    ('\u{10EAB}', '\u{10EAC}', NSM),
+    ('\u{10EAD}', '\u{10EBF}', R),
+    ('\u{10EC0}', '\u{10EFC}', AL),
+    ('\u{10EFD}', '\u{10EFF}', NSM),
+    ('\u{10F00}', '\u{10F2F}', R),
+    ('\u{10F30}', '\u{10F45}', AL),
+    ('\u{10F46}', '\u{10F50}', NSM),
+    ('\u{10F51}', '\u{10F6F}', AL),
+    ('\u{10F70}', '\u{10F81}', R),
+    ('\u{10F82}', '\u{10F85}', NSM),
+    ('\u{10F86}', '\u{10FFF}', R),
+    ('\u{11001}', '\u{11001}', NSM),
+    ('\u{11038}', '\u{11046}', NSM),
+    ('\u{11052}', '\u{11065}', ON),
+    ('\u{11070}', '\u{11070}', NSM),
+    ('\u{11073}', '\u{11074}', NSM),
+    ('\u{1107F}', '\u{11081}', NSM),
+    ('\u{110B3}', '\u{110B6}', NSM),
+    ('\u{110B9}', '\u{110BA}', NSM),
+    ('\u{110C2}', '\u{110C2}', NSM),
+    ('\u{11100}', '\u{11102}', NSM),
+    ('\u{11127}', '\u{1112B}', NSM),
+    ('\u{1112D}', '\u{11134}', NSM),
+    ('\u{11173}', '\u{11173}', NSM),
+    ('\u{11180}', '\u{11181}', NSM),
+    ('\u{111B6}', '\u{111BE}', NSM),
+    ('\u{111C9}', '\u{111CC}', NSM),
+    ('\u{111CF}', '\u{111CF}', NSM),
+    ('\u{1122F}', '\u{11231}', NSM),
+    ('\u{11234}', '\u{11234}', NSM),
+    ('\u{11236}', '\u{11237}', NSM),
+    ('\u{1123E}', '\u{1123E}', NSM),
+    ('\u{11241}', '\u{11241}', NSM),
+    ('\u{112DF}', '\u{112DF}', NSM),
+    ('\u{112E3}', '\u{112EA}', NSM),
+    ('\u{11300}', '\u{11301}', NSM),
+    ('\u{1133B}', '\u{1133C}', NSM),
+    ('\u{11340}', '\u{11340}', NSM),
+    ('\u{11366}', '\u{1136C}', NSM),
+    ('\u{11370}', '\u{11374}', NSM),
+    ('\u{11438}', '\u{1143F}', NSM),
+    ('\u{11442}', '\u{11444}', NSM),
+    ('\u{11446}', '\u{11446}', NSM),
+    ('\u{1145E}', '\u{1145E}', NSM),
+    ('\u{114B3}', '\u{114B8}', NSM),
+    ('\u{114BA}', '\u{114BA}', NSM),
+    ('\u{114BF}', '\u{114C0}', NSM),
+    ('\u{114C2}', '\u{114C3}', NSM),
+    ('\u{115B2}', '\u{115B5}', NSM),
+    ('\u{115BC}', '\u{115BD}', NSM),
+    ('\u{115BF}', '\u{115C0}', NSM),
+    ('\u{115DC}', '\u{115DD}', NSM),
+    ('\u{11633}', '\u{1163A}', NSM),
+    ('\u{1163D}', '\u{1163D}', NSM),
+    ('\u{1163F}', '\u{11640}', NSM),
+    ('\u{11660}', '\u{1166C}', ON),
+    ('\u{116AB}', '\u{116AB}', NSM),
+    ('\u{116AD}', '\u{116AD}', NSM),
+    ('\u{116B0}', '\u{116B5}', NSM),
+    ('\u{116B7}', '\u{116B7}', NSM),
+    ('\u{1171D}', '\u{1171F}', NSM),
+    ('\u{11722}', '\u{11725}', NSM),
+    ('\u{11727}', '\u{1172B}', NSM),
+    ('\u{1182F}', '\u{11837}', NSM),
+    ('\u{11839}', '\u{1183A}', NSM),
+    ('\u{1193B}', '\u{1193C}', NSM),
+    ('\u{1193E}', '\u{1193E}', NSM),
+    ('\u{11943}', '\u{11943}', NSM),
+    ('\u{119D4}', '\u{119D7}', NSM),
+    ('\u{119DA}', '\u{119DB}', NSM),
+    ('\u{119E0}', '\u{119E0}', NSM),
+    ('\u{11A01}', '\u{11A06}', NSM),
+    ('\u{11A09}', '\u{11A0A}', NSM),
+    ('\u{11A33}', '\u{11A38}', NSM),
+    ('\u{11A3B}', '\u{11A3E}', NSM),
+    ('\u{11A47}', '\u{11A47}', NSM),
+    ('\u{11A51}', '\u{11A56}', NSM),
+    ('\u{11A59}', '\u{11A5B}', NSM),
+    ('\u{11A8A}', '\u{11A96}', NSM),
+    ('\u{11A98}', '\u{11A99}', NSM),
+    ('\u{11C30}', '\u{11C36}', NSM),
+    ('\u{11C38}', '\u{11C3D}', NSM),
+    ('\u{11C92}', '\u{11CA7}', NSM),
+    ('\u{11CAA}', '\u{11CB0}', NSM),
+    ('\u{11CB2}', '\u{11CB3}', NSM),
+    ('\u{11CB5}', '\u{11CB6}', NSM),
+    ('\u{11D31}', '\u{11D36}', NSM),
+    ('\u{11D3A}', '\u{11D3A}', NSM),
+    ('\u{11D3C}', '\u{11D3D}', NSM),
+    ('\u{11D3F}', '\u{11D45}', NSM),
+    ('\u{11D47}', '\u{11D47}', NSM),
+    ('\u{11D90}', '\u{11D91}', NSM),
+    ('\u{11D95}', '\u{11D95}', NSM),
+    ('\u{11D97}', '\u{11D97}', NSM),
+    ('\u{11EF3}', '\u{11EF4}', NSM),
+    ('\u{11F00}', '\u{11F01}', NSM),
+    ('\u{11F36}', '\u{11F3A}', NSM),
+    ('\u{11F40}', '\u{11F40}', NSM),
+    ('\u{11F42}', '\u{11F42}', NSM),
+    ('\u{11FD5}', '\u{11FDC}', ON),
+    ('\u{11FDD}', '\u{11FE0}', ET),
+    ('\u{11FE1}', '\u{11FF1}', ON),
+    ('\u{13440}', '\u{13440}', NSM),
+    ('\u{13447}', '\u{13455}', NSM),
+    ('\u{16AF0}', '\u{16AF4}', NSM),
+    ('\u{16B30}', '\u{16B36}', NSM),
+    ('\u{16F4F}', '\u{16F4F}', NSM),
+    ('\u{16F8F}', '\u{16F92}', NSM),
+    ('\u{16FE2}', '\u{16FE2}', ON),
+    ('\u{16FE4}', '\u{16FE4}', NSM),
+    ('\u{1BC9D}', '\u{1BC9E}', NSM),
+    ('\u{1BCA0}', '\u{1BCA3}', BN),
+    ('\u{1CF00}', '\u{1CF2D}', NSM),
+    ('\u{1CF30}', '\u{1CF46}', NSM),
+    ('\u{1D167}', '\u{1D169}', NSM),
+    ('\u{1D173}', '\u{1D17A}', BN),
+    ('\u{1D17B}', '\u{1D182}', NSM),
+    ('\u{1D185}', '\u{1D18B}', NSM),
+    ('\u{1D1AA}', '\u{1D1AD}', NSM),
+    ('\u{1D1E9}', '\u{1D1EA}', ON),
+    ('\u{1D200}', '\u{1D241}', ON),
+    ('\u{1D242}', '\u{1D244}', NSM),
+    ('\u{1D245}', '\u{1D245}', ON),
+    ('\u{1D300}', '\u{1D356}', ON),
+    ('\u{1D6DB}', '\u{1D6DB}', ON),
+    ('\u{1D715}', '\u{1D715}', ON),
+    ('\u{1D74F}', '\u{1D74F}', ON),
+    ('\u{1D789}', '\u{1D789}', ON),
+    ('\u{1D7C3}', '\u{1D7C3}', ON),
+    ('\u{1D7CE}', '\u{1D7FF}', EN),
+    ('\u{1DA00}', '\u{1DA36}', NSM),
+    ('\u{1DA3B}', '\u{1DA6C}', NSM),
+    ('\u{1DA75}', '\u{1DA75}', NSM),
+    ('\u{1DA84}', '\u{1DA84}', NSM),
+    ('\u{1DA9B}', '\u{1DA9F}', NSM),
+    ('\u{1DAA1}', '\u{1DAAF}', NSM),
+    ('\u{1E000}', '\u{1E006}', NSM),
+    ('\u{1E008}', '\u{1E018}', NSM),
+    ('\u{1E01B}', '\u{1E021}', NSM),
+    ('\u{1E023}', '\u{1E024}', NSM),
+    ('\u{1E026}', '\u{1E02A}', NSM),
+    ('\u{1E08F}', '\u{1E08F}', NSM),
+    ('\u{1E130}', '\u{1E136}', NSM),
+    ('\u{1E2AE}', '\u{1E2AE}', NSM),
+    ('\u{1E2EC}', '\u{1E2EF}', NSM),
+    ('\u{1E2FF}', '\u{1E2FF}', ET),
+    ('\u{1E4EC}', '\u{1E4EF}', NSM),
+    ('\u{1E800}', '\u{1E8CF}', R),
+    ('\u{1E8D0}', '\u{1E8D6}', NSM),
+    ('\u{1E8D7}', '\u{1E943}', R),
+    ('\u{1E944}', '\u{1E94A}', NSM),
+    ('\u{1E94B}', '\u{1EC6F}', R),
+    ('\u{1EC70}', '\u{1ECBF}', AL),
+    ('\u{1ECC0}', '\u{1ECFF}', R),
+    ('\u{1ED00}', '\u{1ED4F}', AL),
+    ('\u{1ED50}', '\u{1EDFF}', R),
+    ('\u{1EE00}', '\u{1EEEF}', AL),
+    ('\u{1EEF0}', '\u{1EEF1}', ON),
+    ('\u{1EEF2}', '\u{1EEFF}', AL),
+    ('\u{1EF00}', '\u{1EFFF}', R),
+    ('\u{1F000}', '\u{1F02B}', ON),
+    ('\u{1F030}', '\u{1F093}', ON),
+    ('\u{1F0A0}', '\u{1F0AE}', ON),
+    ('\u{1F0B1}', '\u{1F0BF}', ON),
+    ('\u{1F0C1}', '\u{1F0CF}', ON),
+    ('\u{1F0D1}', '\u{1F0F5}', ON),
+    ('\u{1F100}', '\u{1F10A}', EN),
+    ('\u{1F10B}', '\u{1F10F}', ON),
+    ('\u{1F12F}', '\u{1F12F}', ON),
+    ('\u{1F16A}', '\u{1F16F}', ON),
+    ('\u{1F1AD}', '\u{1F1AD}', ON),
+    ('\u{1F260}', '\u{1F265}', ON),
+    ('\u{1F300}', '\u{1F6D7}', ON),
+    ('\u{1F6DC}', '\u{1F6EC}', ON),
+    ('\u{1F6F0}', '\u{1F6FC}', ON),
+    ('\u{1F700}', '\u{1F776}', ON),
+    ('\u{1F77B}', '\u{1F7D9}', ON),
+    ('\u{1F7E0}', '\u{1F7EB}', ON),
+    ('\u{1F7F0}', '\u{1F7F0}', ON),
+    ('\u{1F800}', '\u{1F80B}', ON),
+    ('\u{1F810}', '\u{1F847}', ON),
+    ('\u{1F850}', '\u{1F859}', ON),
+    ('\u{1F860}', '\u{1F887}', ON),
+    ('\u{1F890}', '\u{1F8AD}', ON),
+    ('\u{1F8B0}', '\u{1F8B1}', ON),
+    ('\u{1F900}', '\u{1FA53}', ON),
+    ('\u{1FA60}', '\u{1FA6D}', ON),
+    ('\u{1FA70}', '\u{1FA7C}', ON),
+    ('\u{1FA80}', '\u{1FA88}', ON),
+    ('\u{1FA90}', '\u{1FABD}', ON),
+    ('\u{1FABF}', '\u{1FAC5}', ON),
+    ('\u{1FACE}', '\u{1FADB}', ON),
+    ('\u{1FAE0}', '\u{1FAE8}', ON),
+    ('\u{1FAF0}', '\u{1FAF8}', ON),
+    ('\u{1FB00}', '\u{1FB92}', ON),
+    ('\u{1FB94}', '\u{1FBCA}', ON),
+    ('\u{1FBF0}', '\u{1FBF9}', EN),
+    ('\u{1FFFE}', '\u{1FFFF}', BN),
+    ('\u{2FFFE}', '\u{2FFFF}', BN),
+    ('\u{3FFFE}', '\u{3FFFF}', BN),
+    ('\u{4FFFE}', '\u{4FFFF}', BN),
+    ('\u{5FFFE}', '\u{5FFFF}', BN),
+    ('\u{6FFFE}', '\u{6FFFF}', BN),
+    ('\u{7FFFE}', '\u{7FFFF}', BN),
+    ('\u{8FFFE}', '\u{8FFFF}', BN),
+    ('\u{9FFFE}', '\u{9FFFF}', BN),
+    ('\u{AFFFE}', '\u{AFFFF}', BN),
+    ('\u{BFFFE}', '\u{BFFFF}', BN),
+    ('\u{CFFFE}', '\u{CFFFF}', BN),
+    ('\u{DFFFE}', '\u{E00FF}', BN),
+    ('\u{E0100}', '\u{E01EF}', NSM),
+    ('\u{E01F0}', '\u{E0FFF}', BN),
+    ('\u{EFFFE}', '\u{EFFFF}', BN),
+    ('\u{FFFFE}', '\u{FFFFF}', BN),
+    ('\u{10FFFE}', '\u{10FFFF}', BN),
+];
+
+//* (bracket, paired bracket, is opening)
+pub(super) static BRACKETS: [(char, char, bool); 128] = [
+    ('(', ')', true),
+    (')', '(', false),
+    ('[', ']', true),
+    (']', '[', false),
+    ('{', '}', true),
+    ('}', '{', false),
+    ('\u{F3A}', '\u{F3B}', true),
+    ('\u{F3B}', '\u{F3A}', false),
+    ('\u{F3C}', '\u{F3D}', true),
+    ('\u{F3D}', '\u{F3C}', false),
+    ('\u{169B}', '\u{169C}', true),
+    ('\u{169C}', '\u{169B}', false),
+    ('\u{2045}', '\u{2046}', true),
+    ('\u{2046}', '\u{2045}', false),
+    ('\u{207D}', '\u{207E}', true),
+    ('\u{207E}', '\u{207D}', false),
+    ('\u{208D}', '\u{208E}', true),
+    ('\u{208E}', '\u{208D}', false),
+    ('\u{2308}', '\u{2309}', true),
+    ('\u{2309}', '\u{2308}', false),
+    ('\u{230A}', '\u{230B}', true),
+    ('\u{230B}', '\u{230A}', false),
+    ('\u{2329}', '\u{232A}', true),
+    ('\u{232A}', '\u{2329}', false),
+    ('\u{2768}', '\u{2769}', true),
+    ('\u{2769}', '\u{2768}', false),
+    ('\u{276A}', '\u{276B}', true),
+    ('\u{276B}', '\u{276A}', false),
+    ('\u{276C}', '\u{276D}', true),
+    ('\u{276D}', '\u{276C}', false),
+    ('\u{276E}', '\u{276F}', true),
+    ('\u{276F}', '\u{276E}', false),
+    ('\u{2770}', '\u{2771}', true),
+    ('\u{2771}', '\u{2770}', false),
+    ('\u{2772}', '\u{2773}', true),
+    ('\u{2773}', '\u{2772}', false),
+    ('\u{2774}', '\u{2775}', true),
+    ('\u{2775}', '\u{2774}', false),
+    ('\u{27C5}', '\u{27C6}', true),
+    ('\u{27C6}', '\u{27C5}', false),
+    ('\u{27E6}', '\u{27E7}', true),
+    ('\u{27E7}', '\u{27E6}', false),
+    ('\u{27E8}', '\u{27E9}', true),
+    ('\u{27E9}', '\u{27E8}', false),
+    ('\u{27EA}', '\u{27EB}', true),
+    ('\u{27EB}', '\u{27EA}', false),
+    ('\u{27EC}', '\u{27ED}', true),
+    ('\u{27ED}', '\u{27EC}', false),
+    ('\u{27EE}', '\u{27EF}', true),
+    ('\u{27EF}', '\u{27EE}', false),
+    ('\u{2983}', '\u{2984}', true),
+    ('\u{2984}', '\u{2983}', false),
+    ('\u{2985}', '\u{2986}', true),
+    ('\u{2986}', '\u{2985}', false),
+    ('\u{2987}', '\u{2988}', true),
+    ('\u{2988}', '\u{2987}', false),
+    ('\u{2989}', '\u{298A}', true),
+    ('\u{298A}', '\u{2989}', false),
+    ('\u{298B}', '\u{298C}', true),
+    ('\u{298C}', '\u{298B}', false),
+    ('\u{298D}', '\u{2990}', true),
+    ('\u{298E}', '\u{298F}', false),
+    ('\u{298F}', '\u{298E}', true),
+    ('\u{2990}', '\u{298D}', false),
+    ('\u{2991}', '\u{2992}', true),
+    ('\u{2992}', '\u{2991}', false),
+    ('\u{2993}', '\u{2994}', true),
+    ('\u{2994}', '\u{2993}', false),
+    ('\u{2995}', '\u{2996}', true),
+    ('\u{2996}', '\u{2995}', false),
+    ('\u{2997}', '\u{2998}', true),
+    ('\u{2998}', '\u{2997}', false),
+    ('\u{29D8}', '\u{29D9}', true),
+    ('\u{29D9}', '\u{29D8}', false),
+    ('\u{29DA}', '\u{29DB}', true),
+    ('\u{29DB}', '\u{29DA}', false),
+    ('\u{29FC}', '\u{29FD}', true),
+    ('\u{29FD}', '\u{29FC}', false),
+    ('\u{2E22}', '\u{2E23}', true),
+    ('\u{2E23}', '\u{2E22}', false),
+    ('\u{2E24}', '\u{2E25}', true),
+    ('\u{2E25}', '\u{2E24}', false),
+    ('\u{2E26}', '\u{2E27}', true),
+    ('\u{2E27}', '\u{2E26}', false),
+    ('\u{2E28}', '\u{2E29}', true),
+    ('\u{2E29}', '\u{2E28}', false),
+    ('\u{2E55}', '\u{2E56}', true),
+    ('\u{2E56}', '\u{2E55}', false),
+    ('\u{2E57}', '\u{2E58}', true),
+    ('\u{2E58}', '\u{2E57}', false),
+    ('\u{2E59}', '\u{2E5A}', true),
+    ('\u{2E5A}', '\u{2E59}', false),
+    ('\u{2E5B}', '\u{2E5C}', true),
+    ('\u{2E5C}', '\u{2E5B}', false),
+    ('\u{3008}', '\u{3009}', true),
+    ('\u{3009}', '\u{3008}', false),
+    ('\u{300A}', '\u{300B}', true),
+    ('\u{300B}', '\u{300A}', false),
+    ('\u{300C}', '\u{300D}', true),
+    ('\u{300D}', '\u{300C}', false),
+    ('\u{300E}', '\u{300F}', true),
+    ('\u{300F}', '\u{300E}', false),
+    ('\u{3010}', '\u{3011}', true),
+    ('\u{3011}', '\u{3010}', false),
+    ('\u{3014}', '\u{3015}', true),
+    ('\u{3015}', '\u{3014}', false),
+    ('\u{3016}', '\u{3017}', true),
+    ('\u{3017}', '\u{3016}', false),
+    ('\u{3018}', '\u{3019}', true),
+    ('\u{3019}', '\u{3018}', false),
+    ('\u{301A}', '\u{301B}', true),
+    ('\u{301B}', '\u{301A}', false),
+    ('\u{FE59}', '\u{FE5A}', true),
+    ('\u{FE5A}', '\u{FE59}', false),
+    ('\u{FE5B}', '\u{FE5C}', true),
+    ('\u{FE5C}', '\u{FE5B}', false),
+    ('\u{FE5D}', '\u{FE5E}', true),
+    ('\u{FE5E}', '\u{FE5D}', false),
+    ('\u{FF08}', '\u{FF09}', true),
+    ('\u{FF09}', '\u{FF08}', false),
+    ('\u{FF3B}', '\u{FF3D}', true),
+    ('\u{FF3D}', '\u{FF3B}', false),
+    ('\u{FF5B}', '\u{FF5D}', true),
+    ('\u{FF5D}', '\u{FF5B}', false),
+    ('\u{FF5F}', '\u{FF60}', true),
+    ('\u{FF60}', '\u{FF5F}', false),
+    ('\u{FF62}', '\u{FF63}', true),
+    ('\u{FF63}', '\u{FF62}', false),
+];
+
+//* (character, mirrored glyph) for characters drawn mirrored in right-to-left text
+pub(super) static MIRRORED: [(char, char); 428] = [
+    ('(', ')'),
+    (')', '('),
+    ('<', '>'),
+    ('>', '<'),
+    ('[', ']'),
+    (']', '['),
+    ('{', '}'),
+    ('}', '{'),
+    ('\u{AB}', '\u{BB}'),
+    ('\u{BB}', '\u{AB}'),
+    ('\u{F3A}', '\u{F3B}'),
+    ('\u{F3B}', '\u{F3A}'),
+    ('\u{F3C}', '\u{F3D}'),
+    ('\u{F3D}', '\u{F3C}'),
+    ('\u{169B}', '\u{169C}'),
+    ('\u{169C}', '\u{169B}'),
+    ('\u{2039}', '\u{203A}'),
+    ('\u{203A}', '\u{2039}'),
+    ('\u{2045}', '\u{2046}'),
+    ('\u{2046}', '\u{2045}'),
+    ('\u{207D}', '\u{207E}'),
+    ('\u{207E}', '\u{207D}'),
+    ('\u{208D}', '\u{208E}'),
+    ('\u{208E}', '\u{208D}'),
+    ('\u{2208}', '\u{220B}'),
+    ('\u{2209}', '\u{220C}'),
+    ('\u{220A}', '\u{220D}'),
+    ('\u{220B}', '\u{2208}'),
+    ('\u{220C}', '\u{2209}'),
+    ('\u{220D}', '\u{220A}'),
+    ('\u{2215}', '\u{29F5}'),
+    ('\u{221F}', '\u{2BFE}'),
+    ('\u{2220}', '\u{29A3}'),
+    ('\u{2221}', '\u{299B}'),
+    ('\u{2222}', '\u{29A0}'),
+    ('\u{2224}', '\u{2AEE}'),
+    ('\u{223C}', '\u{223D}'),
+    ('\u{223D}', '\u{223C}'),
+    ('\u{2243}', '\u{22CD}'),
+    ('\u{2245}', '\u{224C}'),
+    ('\u{224C}', '\u{2245}'),
+    ('\u{2252}', '\u{2253}'),
+    ('\u{2253}', '\u{2252}'),
+    ('\u{2254}', '\u{2255}'),
+    ('\u{2255}', '\u{2254}'),
+    ('\u{2264}', '\u{2265}'),
+    ('\u{2265}', '\u{2264}'),
+    ('\u{2266}', '\u{2267}'),
+    ('\u{2267}', '\u{2266}'),
+    ('\u{2268}', '\u{2269}'),
+    ('\u{2269}', '\u{2268}'),
+    ('\u{226A}', '\u{226B}'),
+    ('\u{226B}', '\u{226A}'),
+    ('\u{226E}', '\u{226F}'),
+    ('\u{226F}', '\u{226E}'),
+    ('\u{2270}', '\u{2271}'),
+    ('\u{2271}', '\u{2270}'),
+    ('\u{2272}', '\u{2273}'),
+    ('\u{2273}', '\u{2272}'),
+    ('\u{2274}', '\u{2275}'),
+    ('\u{2275}', '\u{2274}'),
+    ('\u{2276}', '\u{2277}'),
+    ('\u{2277}', '\u{2276}'),
+    ('\u{2278}', '\u{2279}'),
+    ('\u{2279}', '\u{2278}'),
+    ('\u{227A}', '\u{227B}'),
+    ('\u{227B}', '\u{227A}'),
+    ('\u{227C}', '\u{227D}'),
+    ('\u{227D}', '\u{227C}'),
+    ('\u{227E}', '\u{227F}'),
+    ('\u{227F}', '\u{227E}'),
+    ('\u{2280}', '\u{2281}'),
+    ('\u{2281}', '\u{2280}'),
+    ('\u{2282}', '\u{2283}'),
+    ('\u{2283}', '\u{2282}'),
+    ('\u{2284}', '\u{2285}'),
+    ('\u{2285}', '\u{2284}'),
+    ('\u{2286}', '\u{2287}'),
+    ('\u{2287}', '\u{2286}'),
+    ('\u{2288}', '\u{2289}'),
+    ('\u{2289}', '\u{2288}'),
+    ('\u{228A}', '\u{228B}'),
+    ('\u{228B}', '\u{228A}'),
+    ('\u{228F}', '\u{2290}'),
+    ('\u{2290}', '\u{228F}'),
+    ('\u{2291}', '\u{2292}'),
+    ('\u{2292}', '\u{2291}'),
+    ('\u{2298}', '\u{29B8}'),
+    ('\u{22A2}', '\u{22A3}'),
+    ('\u{22A3}', '\u{22A2}'),
+    ('\u{22A6}', '\u{2ADE}'),
+    ('\u{22A8}', '\u{2AE4}'),
+    ('\u{22A9}', '\u{2AE3}'),
+    ('\u{22AB}', '\u{2AE5}'),
+    ('\u{22B0}', '\u{22B1}'),
+    ('\u{22B1}', '\u{22B0}'),
+    ('\u{22B2}', '\u{22B3}'),
+    ('\u{22B3}', '\u{22B2}'),
+    ('\u{22B4}', '\u{22B5}'),
+    ('\u{22B5}', '\u{22B4}'),
+    ('\u{22B6}', '\u{22B7}'),
+    ('\u{22B7}', '\u{22B6}'),
+    ('\u{22B8}', '\u{27DC}'),
+    ('\u{22C9}', '\u{22CA}'),
+    ('\u{22CA}', '\u{22C9}'),
+    ('\u{22CB}', '\u{22CC}'),
+    ('\u{22CC}', '\u{22CB}'),
+    ('\u{22CD}', '\u{2243}'),
+    ('\u{22D0}', '\u{22D1}'),
+    ('\u{22D1}', '\u{22D0}'),
+    ('\u{22D6}', '\u{22D7}'),
+    ('\u{22D7}', '\u{22D6}'),
+    ('\u{22D8}', '\u{22D9}'),
+    ('\u{22D9}', '\u{22D8}'),
+    ('\u{22DA}', '\u{22DB}'),
+    ('\u{22DB}', '\u{22DA}'),
+    ('\u{22DC}', '\u{22DD}'),
+    ('\u{22DD}', '\u{22DC}'),
+    ('\u{22DE}', '\u{22DF}'),
+    ('\u{22DF}', '\u{22DE}'),
+    ('\u{22E0}', '\u{22E1}'),
+    ('\u{22E1}', '\u{22E0}'),
+    ('\u{22E2}', '\u{22E3}'),
+    ('\u{22E3}', '\u{22E2}'),
+    ('\u{22E4}', '\u{22E5}'),
+    ('\u{22E5}', '\u{22E4}'),
+    ('\u{22E6}', '\u{22E7}'),
+    ('\u{22E7}', '\u{22E6}'),
+    ('\u{22E8}', '\u{22E9}'),
+    ('\u{22E9}', '\u{22E8}'),
+    ('\u{22EA}', '\u{22EB}'),
+    ('\u{22EB}', '\u{22EA}'),
+    ('\u{22EC}', '\u{22ED}'),
+    ('\u{22ED}', '\u{22EC}'),
+    ('\u{22F0}', '\u{22F1}'),
+    ('\u{22F1}', '\u{22F0}'),
+    ('\u{22F2}', '\u{22FA}'),
+    ('\u{22F3}', '\u{22FB}'),
+    ('\u{22F4}', '\u{22FC}'),
+    ('\u{22F6}', '\u{22FD}'),
+    ('\u{22F7}', '\u{22FE}'),
+    ('\u{22FA}', '\u{22F2}'),
+    ('\u{22FB}', '\u{22F3}'),
+    ('\u{22FC}', '\u{22F4}'),
+    ('\u{22FD}', '\u{22F6}'),
+    ('\u{22FE}', '\u{22F7}'),
+    ('\u{2308}', '\u{2309}'),
+    ('\u{2309}', '\u{2308}'),
+    ('\u{230A}', '\u{230B}'),
+    ('\u{230B}', '\u{230A}'),
+    ('\u{2329}', '\u{232A}'),
+    ('\u{232A}', '\u{2329}'),
+    ('\u{2768}', '\u{2769}'),
+    ('\u{2769}', '\u{2768}'),
+    ('\u{276A}', '\u{276B}'),
+    ('\u{276B}', '\u{276A}'),
+    ('\u{276C}', '\u{276D}'),
+    ('\u{276D}', '\u{276C}'),
+    ('\u{276E}', '\u{276F}'),
+    ('\u{276F}', '\u{276E}'),
+    ('\u{2770}', '\u{2771}'),
+    ('\u{2771}', '\u{2770}'),
+    ('\u{2772}', '\u{2773}'),
+    ('\u{2773}', '\u{2772}'),
+    ('\u{2774}', '\u{2775}'),
+    ('\u{2775}', '\u{2774}'),
+    ('\u{27C3}', '\u{27C4}'),
+    ('\u{27C4}', '\u{27C3}'),
+    ('\u{27C5}', '\u{27C6}'),
+    ('\u{27C6}', '\u{27C5}'),
+    ('\u{27C8}', '\u{27C9}'),
+    ('\u{27C9}', '\u{27C8}'),
+    ('\u{27CB}', '\u{27CD}'),
+    ('\u{27CD}', '\u{27CB}'),
+    ('\u{27D5}', '\u{27D6}'),
+    ('\u{27D6}', '\u{27D5}'),
+    ('\u{27DC}', '\u{22B8}'),
+    ('\u{27DD}', '\u{27DE}'),
+    ('\u{27DE}', '\u{27DD}'),
+    ('\u{27E2}', '\u{27E3}'),
+    ('\u{27E3}', '\u{27E2}'),
+    ('\u{27E4}', '\u{27E5}'),
+    ('\u{27E5}', '\u{27E4}'),
+    ('\u{27E6}', '\u{27E7}'),
+    ('\u{27E7}', '\u{27E6}'),
+    ('\u{27E8}', '\u{27E9}'),
+    ('\u{27E9}', '\u{27E8}'),
+    ('\u{27EA}', '\u{27EB}'),
+    ('\u{27EB}', '\u{27EA}'),
+    ('\u{27EC}', '\u{27ED}'),
+    ('\u{27ED}', '\u{27EC}'),
+    ('\u{27EE}', '\u{27EF}'),
+    ('\u{27EF}', '\u{27EE}'),
+    ('\u{2983}', '\u{2984}'),
+    ('\u{2984}', '\u{2983}'),
+    ('\u{2985}', '\u{2986}'),
+    ('\u{2986}', '\u{2985}'),
+    ('\u{2987}', '\u{2988}'),
+    ('\u{2988}', '\u{2987}'),
+    ('\u{2989}', '\u{298A}'),
+    ('\u{298A}', '\u{2989}'),
+    ('\u{298B}', '\u{298C}'),
+    ('\u{298C}', '\u{298B}'),
+    ('\u{298D}', '\u{2990}'),
+    ('\u{298E}', '\u{298F}'),
+    ('\u{298F}', '\u{298E}'),
+    ('\u{2990}', '\u{298D}'),
+    ('\u{2991}', '\u{2992}'),
+    ('\u{2992}', '\u{2991}'),
+    ('\u{2993}', '\u{2994}'),
+    ('\u{2994}', '\u{2993}'),
+    ('\u{2995}', '\u{2996}'),
+    ('\u{2996}', '\u{2995}'),
+    ('\u{2997}', '\u{2998}'),
+    ('\u{2998}', '\u{2997}'),
+    ('\u{299B}', '\u{2221}'),
+    ('\u{29A0}', '\u{2222}'),
+    ('\u{29A3}', '\u{2220}'),
+    ('\u{29A4}', '\u{29A5}'),
+    ('\u{29A5}', '\u{29A4}'),
+    ('\u{29A8}', '\u{29A9}'),
+    ('\u{29A9}', '\u{29A8}'),
+    ('\u{29AA}', '\u{29AB}'),
+    ('\u{29AB}', '\u{29AA}'),
+    ('\u{29AC}', '\u{29AD}'),
+    ('\u{29AD}', '\u{29AC}'),
+    ('\u{29AE}', '\u{29AF}'),
+    ('\u{29AF}', '\u{29AE}'),
+    ('\u{29B8}', '\u{2298}'),
+    ('\u{29C0}', '\u{29C1}'),
+    ('\u{29C1}', '\u{29C0}'),
+    ('\u{29C4}', '\u{29C5}'),
+    ('\u{29C5}', '\u{29C4}'),
+    ('\u{29CF}', '\u{29D0}'),
+    ('\u{29D0}', '\u{29CF}'),
+    ('\u{29D1}', '\u{29D2}'),
+    ('\u{29D2}', '\u{29D1}'),
+    ('\u{29D4}', '\u{29D5}'),
+    ('\u{29D5}', '\u{29D4}'),
+    ('\u{29D8}', '\u{29D9}'),
+    ('\u{29D9}', '\u{29D8}'),
+    ('\u{29DA}', '\u{29DB}'),
+    ('\u{29DB}', '\u{29DA}'),
+    ('\u{29E8}', '\u{29E9}'),
+    ('\u{29E9}', '\u{29E8}'),
+    ('\u{29F5}', '\u{2215}'),
+    ('\u{29F8}', '\u{29F9}'),
+    ('\u{29F9}', '\u{29F8}'),
+    ('\u{29FC}', '\u{29FD}'),
+    ('\u{29FD}', '\u{29FC}'),
+    ('\u{2A2B}', '\u{2A2C}'),
+    ('\u{2A2C}', '\u{2A2B}'),
+    ('\u{2A2D}', '\u{2A2E}'),
+    ('\u{2A2E}', '\u{2A2D}'),
+    ('\u{2A34}', '\u{2A35}'),
+    ('\u{2A35}', '\u{2A34}'),
+    ('\u{2A3C}', '\u{2A3D}'),
+    ('\u{2A3D}', '\u{2A3C}'),
+    ('\u{2A64}', '\u{2A65}'),
+    ('\u{2A65}', '\u{2A64}'),
+    ('\u{2A79}', '\u{2A7A}'),
+    ('\u{2A7A}', '\u{2A79}'),
+    ('\u{2A7B}', '\u{2A7C}'),
+    ('\u{2A7C}', '\u{2A7B}'),
+    ('\u{2A7D}', '\u{2A7E}'),
+    ('\u{2A7E}', '\u{2A7D}'),
+    ('\u{2A7F}', '\u{2A80}'),
+    ('\u{2A80}', '\u{2A7F}'),
+    ('\u{2A81}', '\u{2A82}'),
+    ('\u{2A82}', '\u{2A81}'),
+    ('\u{2A83}', '\u{2A84}'),
+    ('\u{2A84}', '\u{2A83}'),
+    ('\u{2A85}', '\u{2A86}'),
+    ('\u{2A86}', '\u{2A85}'),
+    ('\u{2A87}', '\u{2A88}'),
+    ('\u{2A88}', '\u{2A87}'),
+    ('\u{2A89}', '\u{2A8A}'),
+    ('\u{2A8A}', '\u{2A89}'),
+    ('\u{2A8B}', '\u{2A8C}'),
+    ('\u{2A8C}', '\u{2A8B}'),
+    ('\u{2A8D}', '\u{2A8E}'),
+    ('\u{2A8E}', '\u{2A8D}'),
+    ('\u{2A8F}', '\u{2A90}'),
+    ('\u{2A90}', '\u{2A8F}'),
+    ('\u{2A91}', '\u{2A92}'),
+    ('\u{2A92}', '\u{2A91}'),
+    ('\u{2A93}', '\u{2A94}'),
+    ('\u{2A94}', '\u{2A93}'),
+    ('\u{2A95}', '\u{2A96}'),
+    ('\u{2A96}', '\u{2A95}'),
+    ('\u{2A97}', '\u{2A98}'),
+    ('\u{2A98}', '\u{2A97}'),
+    ('\u{2A99}', '\u{2A9A}'),
+    ('\u{2A9A}', '\u{2A99}'),
+    ('\u{2A9B}', '\u{2A9C}'),
+    ('\u{2A9C}', '\u{2A9B}'),
+    ('\u{2A9D}', '\u{2A9E}'),
+    ('\u{2A9E}', '\u{2A9D}'),
+    ('\u{2A9F}', '\u{2AA0}'),
+    ('\u{2AA0}', '\u{2A9F}'),
+    ('\u{2AA1}', '\u{2AA2}'),
+    ('\u{2AA2}', '\u{2AA1}'),
+    ('\u{2AA6}', '\u{2AA7}'),
+    ('\u{2AA7}', '\u{2AA6}'),
+    ('\u{2AA8}', '\u{2AA9}'),
+    ('\u{2AA9}', '\u{2AA8}'),
+    ('\u{2AAA}', '\u{2AAB}'),
+    ('\u{2AAB}', '\u{2AAA}'),
+    ('\u{2AAC}', '\u{2AAD}'),
+    ('\u{2AAD}', '\u{2AAC}'),
+    ('\u{2AAF}', '\u{2AB0}'),
+    ('\u{2AB0}', '\u{2AAF}'),
+    ('\u{2AB1}', '\u{2AB2}'),
+    ('\u{2AB2}', '\u{2AB1}'),
+    ('\u{2AB3}', '\u{2AB4}'),
+    ('\u{2AB4}', '\u{2AB3}'),
+    ('\u{2AB5}', '\u{2AB6}'),
+    ('\u{2AB6}', '\u{2AB5}'),
+    ('\u{2AB7}', '\u{2AB8}'),
+    ('\u{2AB8}', '\u{2AB7}'),
+    ('\u{2AB9}', '\u{2ABA}'),
+    ('\u{2ABA}', '\u{2AB9}'),
+    ('\u{2ABB}', '\u{2ABC}'),
+    ('\u{2ABC}', '\u{2ABB}'),
+    ('\u{2ABD}', '\u{2ABE}'),
+    ('\u{2ABE}', '\u{2ABD}'),
+    ('\u{2ABF}', '\u{2AC0}'),
+    ('\u{2AC0}', '\u{2ABF}'),
+    ('\u{2AC1}', '\u{2AC2}'),
+    ('\u{2AC2}', '\u{2AC1}'),
+    ('\u{2AC3}', '\u{2AC4}'),
+    ('\u{2AC4}', '\u{2AC3}'),
+    ('\u{2AC5}', '\u{2AC6}'),
+    ('\u{2AC6}', '\u{2AC5}'),
+    ('\u{2AC7}', '\u{2AC8}'),
+    ('\u{2AC8}', '\u{2AC7}'),
+    ('\u{2AC9}', '\u{2ACA}'),
+    ('\u{2ACA}', '\u{2AC9}'),
+    ('\u{2ACB}', '\u{2ACC}'),
+    ('\u{2ACC}', '\u{2ACB}'),
+    ('\u{2ACD}', '\u{2ACE}'),
+    ('\u{2ACE}', '\u{2ACD}'),
+    ('\u{2ACF}', '\u{2AD0}'),
+    ('\u{2AD0}', '\u{2ACF}'),
+    ('\u{2AD1}', '\u{2AD2}'),
+    ('\u{2AD2}', '\u{2AD1}'),
+    ('\u{2AD3}', '\u{2AD4}'),
+    ('\u{2AD4}', '\u{2AD3}'),
+    ('\u{2AD5}', '\u{2AD6}'),
+    ('\u{2AD6}', '\u{2AD5}'),
+    ('\u{2ADE}', '\u{22A6}'),
+    ('\u{2AE3}', '\u{22A9}'),
+    ('\u{2AE4}', '\u{22A8}'),
+    ('\u{2AE5}', '\u{22AB}'),
+    ('\u{2AEC}', '\u{2AED}'),
+    ('\u{2AED}', '\u{2AEC}'),
+    ('\u{2AEE}', '\u{2224}'),
+    ('\u{2AF7}', '\u{2AF8}'),
+    ('\u{2AF8}', '\u{2AF7}'),
+    ('\u{2AF9}', '\u{2AFA}'),
+    ('\u{2AFA}', '\u{2AF9}'),
+    ('\u{2BFE}', '\u{221F}'),
+    ('\u{2E02}', '\u{2E03}'),
+    ('\u{2E03}', '\u{2E02}'),
+    ('\u{2E04}', '\u{2E05}'),
+    ('\u{2E05}', '\u{2E04}'),
+    ('\u{2E09}', '\u{2E0A}'),
+    ('\u{2E0A}', '\u{2E09}'),
+    ('\u{2E0C}', '\u{2E0D}'),
+    ('\u{2E0D}', '\u{2E0C}'),
+    ('\u{2E1C}', '\u{2E1D}'),
+    ('\u{2E1D}', '\u{2E1C}'),
+    ('\u{2E20}', '\u{2E21}'),
+    ('\u{2E21}', '\u{2E20}'),
+    ('\u{2E22}', '\u{2E23}'),
+    ('\u{2E23}', '\u{2E22}'),
+    ('\u{2E24}', '\u{2E25}'),
+    ('\u{2E25}', '\u{2E24}'),
+    ('\u{2E26}', '\u{2E27}'),
+    ('\u{2E27}', '\u{2E26}'),
+    ('\u{2E28}', '\u{2E29}'),
+    ('\u{2E29}', '\u{2E28}'),
+    ('\u{2E55}', '\u{2E56}'),
+    ('\u{2E56}', '\u{2E55}'),
+    ('\u{2E57}', '\u{2E58}'),
+    ('\u{2E58}', '\u{2E57}'),
+    ('\u{2E59}', '\u{2E5A}'),
+    ('\u{2E5A}', '\u{2E59}'),
+    ('\u{2E5B}', '\u{2E5C}'),
+    ('\u{2E5C}', '\u{2E5B}'),
+    ('\u{3008}', '\u{3009}'),
+    ('\u{3009}', '\u{3008}'),
+    ('\u{300A}', '\u{300B}'),
+    ('\u{300B}', '\u{300A}'),
+    ('\u{300C}', '\u{300D}'),
+    ('\u{300D}', '\u{300C}'),
+    ('\u{300E}', '\u{300F}'),
+    ('\u{300F}', '\u{300E}'),
+    ('\u{3010}', '\u{3011}'),
+    ('\u{3011}', '\u{3010}'),
+    ('\u{3014}', '\u{3015}'),
+    ('\u{3015}', '\u{3014}'),
+    ('\u{3016}', '\u{3017}'),
+    ('\u{3017}', '\u{3016}'),
+    ('\u{3018}', '\u{3019}'),
+    ('\u{3019}', '\u{3018}'),
+    ('\u{301A}', '\u{301B}'),
+    ('\u{301B}', '\u{301A}'),
+    ('\u{FE59}', '\u{FE5A}'),
+    ('\u{FE5A}', '\u{FE59}'),
+    ('\u{FE5B}', '\u{FE5C}'),
+    ('\u{FE5C}', '\u{FE5B}'),
+    ('\u{FE5D}', '\u{FE5E}'),
+    ('\u{FE5E}', '\u{FE5D}'),
+    ('\u{FE64}', '\u{FE65}'),
+    ('\u{FE65}', '\u{FE64}'),
+    ('\u{FF08}', '\u{FF09}'),
+    ('\u{FF09}', '\u{FF08}'),
+    ('\u{FF1C}', '\u{FF1E}'),
+    ('\u{FF1E}', '\u{FF1C}'),
+    ('\u{FF3B}', '\u{FF3D}'),
+    ('\u{FF3D}', '\u{FF3B}'),
+    ('\u{FF5B}', '\u{FF5D}'),
+    ('\u{FF5D}', '\u{FF5B}'),
+    ('\u{FF5F}', '\u{FF60}'),
+    ('\u{FF60}', '\u{FF5F}'),
+    ('\u{FF62}', '\u{FF63}'),
+    ('\u{FF63}', '\u{FF62}'),
+];
@@ -0,0 +1,237 @@
+use std::borrow::Borrow;
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+//* A one-to-one map, e.g. country ids to country names and back
+//* Every left value is paired with exactly one right value and vice versa, so both sides work as keys
+//* Two HashMaps, one for each direction, each side keeps a clone of the other side's value
+//* Inserting a pair removes the pairs that had the same left or the same right value, see Overwritten
+pub struct BiMap<L, R> {
+    left_to_right: HashMap<L, R>,
+    right_to_left: HashMap<R, L>,
+}
+
+//* The pairs that insert() had to remove to keep both sides unique
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    Neither,
+    //* the same pair was in the map already
+    Pair(L, R),
+    //* the pair with the same left value
+    Left(L, R),
+    //* the pair with the same right value
+    Right(L, R),
+    //* the pair with the same left value and the pair with the same right value
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> BiMap<L, R> {
+    pub fn new() -> BiMap<L, R> {
+        BiMap { left_to_right: HashMap::new(), right_to_left: HashMap::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> BiMap<L, R> {
+        BiMap { left_to_right: HashMap::with_capacity(capacity), right_to_left: HashMap::with_capacity(capacity) }
+    }
+
+    //* The number of pairs
+    pub fn len(&self) -> usize {
+        self.left_to_right.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left_to_right.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.left_to_right.clear();
+        self.right_to_left.clear();
+    }
+
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        Iter { inner: self.left_to_right.iter() }
+    }
+
+    pub fn left_values(&self) -> impl ExactSizeIterator<Item = &L> {
+        self.left_to_right.keys()
+    }
+
+    pub fn right_values(&self) -> impl ExactSizeIterator<Item = &R> {
+        self.right_to_left.keys()
+    }
+}
+
+impl<L: Hash + Eq, R: Hash + Eq> BiMap<L, R> {
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.left_to_right.get(left)
+    }
+
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.right_to_left.get(right)
+    }
+
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.left_to_right.contains_key(left)
+    }
+
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.right_to_left.contains_key(right)
+    }
+
+    //* Removes the pair with this left value
+    pub fn remove_by_left<Q>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (left, right) = self.left_to_right.remove_entry(left)?;
+        self.right_to_left.remove(&right);
+        Some((left, right))
+    }
+
+    //* Removes the pair with this right value
+    pub fn remove_by_right<Q>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (right, left) = self.right_to_left.remove_entry(right)?;
+        self.left_to_right.remove(&left);
+        Some((left, right))
+    }
+
+    pub fn retain<F: FnMut(&L, &R) -> bool>(&mut self, mut keep: F) {
+        let right_to_left = &mut self.right_to_left;
+        self.left_to_right.retain(|left, right| {
+            let kept = keep(left, right);
+            if !kept {
+                right_to_left.remove(right);
+            }
+            kept
+        });
+    }
+}
+
+impl<L: Hash + Eq + Clone, R: Hash + Eq + Clone> BiMap<L, R> {
+    //* Pairs 'left' with 'right', whatever either of them was paired with before is removed
+    pub fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+        let overwritten = match (self.remove_by_left(&left), self.remove_by_right(&right)) {
+            (None, None) => Overwritten::Neither,
+            (Some((l, r)), None) if r == right => Overwritten::Pair(l, r),
+            (Some((l, r)), None) => Overwritten::Left(l, r),
+            (None, Some((l, r))) => Overwritten::Right(l, r),
+            (Some(by_left), Some(by_right)) => Overwritten::Both(by_left, by_right),
+        };
+        self.left_to_right.insert(left.clone(), right.clone());
+        self.right_to_left.insert(right, left);
+        overwritten
+    }
+
+    //* Only inserts if neither value is paired yet, otherwise hands the pair back
+    pub fn insert_no_overwrite(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.contains_left(&left) || self.contains_right(&right) {
+            return Err((left, right));
+        }
+        self.left_to_right.insert(left.clone(), right.clone());
+        self.right_to_left.insert(right, left);
+        Ok(())
+    }
+}
+
+pub struct Iter<'a, L, R> {
+    inner: hash_map::Iter<'a, L, R>,
+}
+
+impl<'a, L, R> Iterator for Iter<'a, L, R> {
+    type Item = (&'a L, &'a R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, L, R> ExactSizeIterator for Iter<'a, L, R> {}
+
+impl<L, R> IntoIterator for BiMap<L, R> {
+    type Item = (L, R);
+    type IntoIter = hash_map::IntoIter<L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.left_to_right.into_iter()
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a BiMap<L, R> {
+    type Item = (&'a L, &'a R);
+    type IntoIter = Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+//* Later pairs win, like repeated inserts
+impl<L: Hash + Eq + Clone, R: Hash + Eq + Clone> FromIterator<(L, R)> for BiMap<L, R> {
+    fn from_iter<I: IntoIterator<Item = (L, R)>>(iter: I) -> Self {
+        let mut map = BiMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<L: Hash + Eq + Clone, R: Hash + Eq + Clone> Extend<(L, R)> for BiMap<L, R> {
+    fn extend<I: IntoIterator<Item = (L, R)>>(&mut self, iter: I) {
+        for (left, right) in iter {
+            self.insert(left, right);
+        }
+    }
+}
+
+impl<L, R> Default for BiMap<L, R> {
+    fn default() -> Self {
+        BiMap::new()
+    }
+}
+
+impl<L: Clone, R: Clone> Clone for BiMap<L, R> {
+    fn clone(&self) -> Self {
+        BiMap { left_to_right: self.left_to_right.clone(), right_to_left: self.right_to_left.clone() }
+    }
+}
+
+impl<L: Hash + Eq, R: PartialEq> PartialEq for BiMap<L, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.left_to_right == other.left_to_right
+    }
+}
+
+impl<L: Hash + Eq, R: Eq> Eq for BiMap<L, R> {}
+
+impl<L: fmt::Debug, R: fmt::Debug> fmt::Debug for BiMap<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use collections::bench::{black_box, measure, report};
+use collections::cache::{LfuCache, LruCache, TtlCache};
+
+//* Compares the caches on a skewed workload, where a few keys are asked for far more often than the rest
+//* Usage: cargo run --release --bin cache_bench -- [lookups] [capacity]
+fn main() {
+    let mut args = std::env::args().skip(1).map(|s| s.parse::<usize>().ok());
+    let lookups = args.next().flatten().unwrap_or(100_000);
+    let capacity = args.next().flatten().unwrap_or(1_000);
+    let universe = capacity as f64 * 20.0;
+
+    //* Reproducible pseudo random numbers (xorshift), cubed so that small keys come up much more often
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let keys: Vec<u64> = (0..lookups)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let uniform = (state >> 11) as f64 / (1u64 << 53) as f64;
+            (uniform.powi(3) * universe) as u64
+        })
+        .collect();
+
+    //* Stands in for the lookup that is worth caching
+    fn compute(key: u64) -> u64 {
+        (0..16).fold(key, |acc, i| acc.rotate_left(5) ^ i)
+    }
+
+    println!("{} lookups of {} distinct keys, capacity {}\n", lookups, universe as u64, capacity);
+
+    report(
+        "get_or_insert_with",
+        &[
+            measure("HashMap (unbounded)", || {
+                let mut map = HashMap::new();
+                keys.iter().map(|&k| *map.entry(k).or_insert_with(|| compute(k))).sum::<u64>()
+            }),
+            measure("LruCache", || {
+                let mut cache = LruCache::new(capacity);
+                keys.iter().map(|&k| *cache.get_or_insert_with(k, || compute(k))).sum::<u64>()
+            }),
+            measure("LfuCache", || {
+                let mut cache = LfuCache::new(capacity);
+                keys.iter().map(|&k| *cache.get_or_insert_with(k, || compute(k))).sum::<u64>()
+            }),
+            measure("TtlCache", || {
+                let mut cache = TtlCache::new(capacity, Duration::from_secs(60));
+                keys.iter().map(|&k| *cache.get_or_insert_with(k, || compute(k))).sum::<u64>()
+            }),
+        ],
+    );
+
+    let mut lru = LruCache::new(capacity);
+    let mut lfu = LfuCache::new(capacity);
+    let mut ttl = TtlCache::new(capacity, Duration::from_secs(60));
+    for &k in &keys {
+        black_box(lru.get_or_insert_with(k, || compute(k)));
+        black_box(lfu.get_or_insert_with(k, || compute(k)));
+        black_box(ttl.get_or_insert_with(k, || compute(k)));
+    }
+    println!("\nhit ratios");
+    println!("    {:<28} {}", "LruCache", lru.stats());
+    println!("    {:<28} {}", "LfuCache", lfu.stats());
+    println!("    {:<28} {}", "TtlCache", ttl.stats());
+}
@@ -0,0 +1,55 @@
+use std::collections::{BTreeMap, HashMap};
+
+use collections::bench::{black_box, measure, report, scrambled_keys};
+use collections::fnv::DeterministicHashMap;
+use collections::ordered_map::OrderedMap;
+use collections::sorted_map::SortedMap;
+
+//* Compares building, looking up and iterating the map types
+//* Usage: cargo run --release --bin map_bench -- [size]
+fn main() {
+    let size: u64 = std::env::args().nth(1).and_then(|s| s.parse().ok()).unwrap_or(10_000);
+
+    let keys = scrambled_keys(size);
+
+    let hash: HashMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+    let fnv: DeterministicHashMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+    let ordered: OrderedMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+    let btree: BTreeMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+    let sorted: SortedMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+
+    println!("{} entries\n", size);
+
+    report(
+        "insert",
+        &[
+            measure("HashMap", || keys.iter().map(|&k| (k, k)).collect::<HashMap<_, _>>()),
+            measure("HashMap (FNV)", || keys.iter().map(|&k| (k, k)).collect::<DeterministicHashMap<_, _>>()),
+            measure("OrderedMap", || keys.iter().map(|&k| (k, k)).collect::<OrderedMap<_, _>>()),
+            measure("BTreeMap", || keys.iter().map(|&k| (k, k)).collect::<BTreeMap<_, _>>()),
+            measure("SortedMap", || keys.iter().map(|&k| (k, k)).collect::<SortedMap<_, _>>()),
+        ],
+    );
+
+    report(
+        "lookup every key",
+        &[
+            measure("HashMap", || keys.iter().filter_map(|k| hash.get(k)).sum::<u64>()),
+            measure("HashMap (FNV)", || keys.iter().filter_map(|k| fnv.get(k)).sum::<u64>()),
+            measure("OrderedMap", || keys.iter().filter_map(|k| ordered.get(k)).sum::<u64>()),
+            measure("BTreeMap", || keys.iter().filter_map(|k| btree.get(k)).sum::<u64>()),
+            measure("SortedMap", || keys.iter().filter_map(|k| sorted.get(k)).sum::<u64>()),
+        ],
+    );
+
+    report(
+        "iterate",
+        &[
+            measure("HashMap", || black_box(&hash).values().sum::<u64>()),
+            measure("HashMap (FNV)", || black_box(&fnv).values().sum::<u64>()),
+            measure("OrderedMap", || black_box(&ordered).values().sum::<u64>()),
+            measure("BTreeMap", || black_box(&btree).values().sum::<u64>()),
+            measure("SortedMap", || black_box(&sorted).values().sum::<u64>()),
+        ],
+    );
+}
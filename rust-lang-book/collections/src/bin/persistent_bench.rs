@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use collections::bench::{black_box, measure, report, scrambled_keys};
+use collections::persistent::{PMap, PVec};
+
+//* Compares keeping every version of a collection by cloning Vec/HashMap with the persistent types
+//* Usage: cargo run --release --bin persistent_bench -- [size]
+fn main() {
+    let size: u64 = std::env::args().nth(1).and_then(|s| s.parse().ok()).unwrap_or(2_000);
+
+    let keys = scrambled_keys(size);
+
+    let vec: Vec<u64> = keys.clone();
+    let pvec: PVec<u64> = keys.iter().copied().collect();
+    let hash: HashMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+    let pmap: PMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+
+    println!("{} elements\n", size);
+
+    report(
+        "push, keeping every version",
+        &[
+            measure("Vec (clone)", || {
+                let mut versions = vec![Vec::new()];
+                for &k in &keys {
+                    let mut next = versions[versions.len() - 1].clone();
+                    next.push(k);
+                    versions.push(next);
+                }
+                versions
+            }),
+            measure("PVec", || {
+                let mut versions = vec![PVec::new()];
+                for &k in &keys {
+                    let next = versions[versions.len() - 1].push(k);
+                    versions.push(next);
+                }
+                versions
+            }),
+        ],
+    );
+
+    report(
+        "change one element of a snapshot",
+        &[
+            measure("Vec (clone)", || {
+                let mut next = black_box(&vec).clone();
+                next[size as usize / 2] = 0;
+                next
+            }),
+            measure("PVec", || black_box(&pvec).set(size as usize / 2, 0)),
+        ],
+    );
+
+    report(
+        "index every element",
+        &[
+            measure("Vec", || (0..vec.len()).map(|i| vec[i]).sum::<u64>()),
+            measure("PVec", || (0..pvec.len()).map(|i| pvec[i]).sum::<u64>()),
+        ],
+    );
+
+    report(
+        "iterate",
+        &[
+            measure("Vec", || black_box(&vec).iter().sum::<u64>()),
+            measure("PVec", || black_box(&pvec).iter().sum::<u64>()),
+        ],
+    );
+
+    report(
+        "insert, keeping every version",
+        &[
+            measure("HashMap (clone)", || {
+                let mut versions = vec![HashMap::new()];
+                for &k in &keys {
+                    let mut next = versions[versions.len() - 1].clone();
+                    next.insert(k, k);
+                    versions.push(next);
+                }
+                versions
+            }),
+            measure("PMap", || {
+                let mut versions = vec![PMap::new()];
+                for &k in &keys {
+                    let next = versions[versions.len() - 1].insert(k, k);
+                    versions.push(next);
+                }
+                versions
+            }),
+        ],
+    );
+
+    report(
+        "change one key of a snapshot",
+        &[
+            measure("HashMap (clone)", || {
+                let mut next = black_box(&hash).clone();
+                next.insert(keys[0], 0);
+                next
+            }),
+            measure("PMap", || black_box(&pmap).insert(keys[0], 0)),
+        ],
+    );
+
+    report(
+        "lookup every key",
+        &[
+            measure("HashMap", || keys.iter().filter_map(|k| hash.get(k)).sum::<u64>()),
+            measure("PMap", || keys.iter().filter_map(|k| pmap.get(k)).sum::<u64>()),
+        ],
+    );
+}
@@ -0,0 +1,66 @@
+use collections::bench::{black_box, measure, report};
+use collections::small::{SmallString, SmallVec};
+
+//* Compares Vec and String with SmallVec and SmallString for many short collections
+//* Usage: cargo run --release --bin small_bench -- [count]
+fn main() {
+    let count: u32 = std::env::args().nth(1).and_then(|s| s.parse().ok()).unwrap_or(10_000);
+
+    let words: Vec<String> = (0..count).map(|i| format!("word{}", i % 1000)).collect();
+    let long_words: Vec<String> = (0..count).map(|i| format!("a rather long word number {}", i)).collect();
+
+    println!("{} collections\n", count);
+
+    report(
+        "build vectors of 3 elements",
+        &[
+            measure("Vec", || (0..count).map(|i| black_box(vec![i, i + 1, i + 2])).map(|v| v[2]).sum::<u32>()),
+            measure("SmallVec<[u32; 4]>", || {
+                (0..count)
+                    .map(|i| black_box([i, i + 1, i + 2].iter().copied().collect::<SmallVec<[u32; 4]>>()))
+                    .map(|v| v[2])
+                    .sum::<u32>()
+            }),
+        ],
+    );
+
+    report(
+        "build vectors of 16 elements (spilled)",
+        &[
+            measure("Vec", || (0..count).map(|i| black_box((i..i + 16).collect::<Vec<_>>())).map(|v| v[15]).sum::<u32>()),
+            measure("SmallVec<[u32; 4]>", || {
+                (0..count).map(|i| black_box((i..i + 16).collect::<SmallVec<[u32; 4]>>())).map(|v| v[15]).sum::<u32>()
+            }),
+        ],
+    );
+
+    report(
+        "copy short words",
+        &[
+            measure("String", || words.iter().map(|w| black_box(String::from(w.as_str())).len()).sum::<usize>()),
+            measure("SmallString<[u8; 16]>", || {
+                words.iter().map(|w| black_box(SmallString::<[u8; 16]>::from(w.as_str())).len()).sum::<usize>()
+            }),
+        ],
+    );
+
+    report(
+        "copy long words (spilled)",
+        &[
+            measure("String", || long_words.iter().map(|w| black_box(String::from(w.as_str())).len()).sum::<usize>()),
+            measure("SmallString<[u8; 16]>", || {
+                long_words.iter().map(|w| black_box(SmallString::<[u8; 16]>::from(w.as_str())).len()).sum::<usize>()
+            }),
+        ],
+    );
+
+    let vecs: Vec<Vec<u32>> = (0..count).map(|i| vec![i, i + 1, i + 2]).collect();
+    let smalls: Vec<SmallVec<[u32; 4]>> = (0..count).map(|i| SmallVec::from([i, i + 1, i + 2, i + 3])).collect();
+    report(
+        "sum all elements",
+        &[
+            measure("Vec<Vec<u32>>", || black_box(&vecs).iter().map(|v| v.iter().sum::<u32>()).sum::<u32>()),
+            measure("Vec<SmallVec<[u32; 4]>>", || black_box(&smalls).iter().map(|v| v.iter().sum::<u32>()).sum::<u32>()),
+        ],
+    );
+}
@@ -0,0 +1,100 @@
+use std::io::{self, Read};
+use std::process;
+
+use collections::text_stats::{Options, TextStats};
+
+const USAGE: &str = "Usage: text_stats [OPTIONS] [FILE]...
+
+Reads the files, or stdin when there are none or the file is '-', and prints word statistics
+
+Options:
+    -k, --top K             number of words and n-grams to list (default 10)
+    -n, --ngram N           length of the counted n-grams, 0 turns them off (default 2)
+    -c, --keep-case         count 'The' and 'the' as different words
+    -s, --stop-words LIST   leave out stop words: 'english' or a file with one word per line
+    -h, --help              print this message";
+
+//* Usage: cargo run --bin text_stats -- --stop-words english -k 5 README.md
+fn main() {
+    let mut options = Options::default();
+    let mut top = 10;
+    let mut files = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "-k" | "--top" => top = number(&arg, args.next()),
+            "-n" | "--ngram" => options.ngram = number(&arg, args.next()),
+            "-c" | "--keep-case" => options.fold_case = false,
+            "-s" | "--stop-words" => {
+                let list = args.next().unwrap_or_else(|| usage_error(&format!("{} needs a value", arg)));
+                let words = if list == "english" {
+                    Ok(Options::english_stop_words())
+                } else {
+                    Options::load_stop_words(&list)
+                };
+                options.stop_words.extend(words.unwrap_or_else(|err| fail(&list, err)));
+            }
+            flag if flag.starts_with('-') && flag != "-" => usage_error(&format!("unknown option {}", flag)),
+            _ => files.push(arg),
+        }
+    }
+    if files.is_empty() {
+        files.push("-".to_string());
+    }
+
+    let mut stats = TextStats::new(options);
+    for file in &files {
+        if file == "-" {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).unwrap_or_else(|err| fail("stdin", err));
+            stats.add_text(&text);
+        } else {
+            stats.add_file(file).unwrap_or_else(|err| fail(file, err));
+        }
+    }
+
+    println!("lines          {}", stats.lines());
+    println!("characters     {}", stats.chars());
+    println!("words          {}", stats.total_words());
+    if stats.skipped_words() > 0 {
+        println!("stop words     {}", stats.skipped_words());
+    }
+    println!("unique words   {}", stats.unique_words());
+    println!("unique ratio   {:.3}", stats.unique_ratio());
+
+    print_top("top words", &stats.top_words(top));
+    print_top("top n-grams", &stats.top_ngrams(top));
+}
+
+fn print_top(title: &str, entries: &[(&str, usize)]) {
+    if entries.is_empty() {
+        return;
+    }
+    println!("\n{}", title);
+    let width = entries.iter().map(|(word, _)| word.chars().count()).max().unwrap_or(0);
+    for (word, count) in entries {
+        println!("    {:<width$}  {:>6}", word, count, width = width);
+    }
+}
+
+fn number(flag: &str, value: Option<String>) -> usize {
+    match value.map(|v| v.parse()) {
+        Some(Ok(n)) => n,
+        _ => usage_error(&format!("{} needs a number", flag)),
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn fail(source: &str, err: io::Error) -> ! {
+    eprintln!("error: {}: {}", source, err);
+    process::exit(1);
+}
@@ -0,0 +1,124 @@
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+mod lfu;
+mod list;
+mod lru;
+mod ttl;
+
+pub use lfu::LfuCache;
+pub use lru::LruCache;
+pub use ttl::TtlCache;
+
+//* Bounded caches for memoising lookups like text_handle.get(&1)
+//* All of them are a HashMap from the key to a node of an intrusive linked list (see cache/list.rs),
+//* the list keeps the order in which entries are evicted, so lookups, puts and evictions are O(1)
+//* The exception is removing an entry from an LfuCache with pop, pop_lfu or resize,
+//* it searches the new lowest use count and is O(number of distinct use counts), see cache/lfu.rs
+//*     LruCache    evicts the least recently used entry
+//*     LfuCache    evicts the least frequently used entry, the least recently used one among equally frequent ones
+//*     TtlCache    entries expire a fixed time after they were put, the oldest one is evicted when it is full
+//*
+//* get() counts as a use and updates the statistics, peek() does neither
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    //* entries that were dropped to make room for new ones
+    pub evictions: u64,
+    //* entries that were dropped because their time was up (TtlCache only)
+    pub expirations: u64,
+}
+
+impl Stats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    //* Between 0.0 and 1.0, 0.0 before the first lookup
+    pub fn hit_ratio(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+
+    pub(crate) fn record(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit ratio), {} evictions",
+            self.hits,
+            self.misses,
+            self.hit_ratio() * 100.0,
+            self.evictions
+        )?;
+        if self.expirations > 0 {
+            write!(f, ", {} expirations", self.expirations)?;
+        }
+        Ok(())
+    }
+}
+
+//* What an insertion pushed out of the cache
+enum Displaced<K, V> {
+    Nothing,
+    Replaced(K, V),
+    Evicted(K, V),
+}
+
+//* Where TtlCache gets the current time from, tests use a ManualClock instead of waiting
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+//* A clock that only moves when it is told to, clones share the same time
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    start: Instant,
+    elapsed: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock { start: Instant::now(), elapsed: Rc::new(Cell::new(Duration::ZERO)) }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed.set(self.elapsed.get() + duration);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> ManualClock {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+}
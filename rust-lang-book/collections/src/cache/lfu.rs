@@ -0,0 +1,266 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use super::list::{Chain, Slab};
+use super::{Displaced, Stats};
+
+//* Least frequently used: one list per use count, each ordered from the most to the least recently used entry
+//* A use moves the entry from the list of count n to the front of the list of count n + 1,
+//* the entry to evict is the tail of the list with the lowest count
+//* Updating an entry with put counts as a use, new entries start with a count of 1
+//* Use counts only grow by one and a new entry has the lowest possible one, so only removing an entry
+//* can leave the lowest count unknown, pop and pop_lfu then scan the distinct counts for it
+pub struct LfuCache<K, V> {
+    map: HashMap<K, usize>,
+    slab: Slab<Node<K, V>>,
+    //* use count -> entries used that often
+    frequencies: HashMap<u64, Chain>,
+    lowest: u64,
+    capacity: usize,
+    stats: Stats,
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    uses: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    //* Panics if the capacity is 0
+    pub fn new(capacity: usize) -> LfuCache<K, V> {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        LfuCache {
+            map: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            frequencies: HashMap::new(),
+            lowest: 0,
+            capacity,
+            stats: Stats::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::default();
+    }
+
+    //* Counts as a use of the entry
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.get(key).copied();
+        self.stats.record(index.is_some());
+        let index = index?;
+        self.touch(index);
+        Some(&mut self.slab.get_mut(index).value)
+    }
+
+    //* Looks without counting as a use
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&index| &self.slab.get(index).value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    //* How often the entry was used, None if it isn't cached
+    pub fn uses<Q>(&self, key: &Q) -> Option<u64>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&index| self.slab.get(index).uses)
+    }
+
+    //* Returns the old value of the key, evicts the least frequently used entry when the cache is full
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert(key, value) {
+            Displaced::Replaced(_, old) => Some(old),
+            _ => None,
+        }
+    }
+
+    //* Like put, but returns the entry that was replaced or evicted
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)> {
+        match self.insert(key, value) {
+            Displaced::Replaced(key, value) | Displaced::Evicted(key, value) => Some((key, value)),
+            Displaced::Nothing => None,
+        }
+    }
+
+    //* Returns the cached value or computes, caches and returns it
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, make: F) -> &mut V {
+        match self.try_get_or_insert_with(key, || Ok::<V, std::convert::Infallible>(make())) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    //* Nothing is cached when 'make' fails
+    pub fn try_get_or_insert_with<E, F: FnOnce() -> Result<V, E>>(&mut self, key: K, make: F) -> Result<&mut V, E> {
+        let index = match self.map.get(&key) {
+            Some(&index) => {
+                self.stats.record(true);
+                self.touch(index);
+                index
+            }
+            None => {
+                self.stats.record(false);
+                let value = make()?;
+                self.insert(key.clone(), value);
+                self.map[&key]
+            }
+        };
+        Ok(&mut self.slab.get_mut(index).value)
+    }
+
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        let uses = self.slab.get(index).uses;
+        self.unlink(index, uses);
+        self.update_lowest();
+        Some(self.slab.remove(index).value)
+    }
+
+    //* Removes the least frequently used entry
+    pub fn pop_lfu(&mut self) -> Option<(K, V)> {
+        let popped = self.remove_lowest();
+        self.update_lowest();
+        popped
+    }
+
+    pub fn resize(&mut self, capacity: usize) {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        while self.map.len() > capacity {
+            self.pop_lfu();
+            self.stats.evictions += 1;
+        }
+        self.capacity = capacity;
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slab.clear();
+        self.frequencies.clear();
+        self.lowest = 0;
+    }
+
+    //* From the most to the least frequently used entry
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut counts: Vec<u64> = self.frequencies.keys().copied().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts
+            .into_iter()
+            .flat_map(move |count| self.slab.iter(&self.frequencies[&count]))
+            .map(|node| (&node.key, &node.value))
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Displaced<K, V> {
+        if let Some(&index) = self.map.get(&key) {
+            self.touch(index);
+            let node = self.slab.get_mut(index);
+            let old_key = std::mem::replace(&mut node.key, key);
+            let old_value = std::mem::replace(&mut node.value, value);
+            return Displaced::Replaced(old_key, old_value);
+        }
+        //* No need to search the lowest count after evicting, the new entry has the lowest possible one
+        let evicted = if self.map.len() == self.capacity { self.remove_lowest() } else { None };
+        if evicted.is_some() {
+            self.stats.evictions += 1;
+        }
+        let index = self.slab.insert(Node { key: key.clone(), value, uses: 1 });
+        self.slab.push_front(self.frequencies.entry(1).or_default(), index);
+        self.map.insert(key, index);
+        self.lowest = 1;
+        match evicted {
+            Some((key, value)) => Displaced::Evicted(key, value),
+            None => Displaced::Nothing,
+        }
+    }
+
+    fn remove_lowest(&mut self) -> Option<(K, V)> {
+        if self.map.is_empty() {
+            return None;
+        }
+        let lowest = self.lowest;
+        let index = self.frequencies[&lowest].tail;
+        self.unlink(index, lowest);
+        let node = self.slab.remove(index);
+        self.map.remove(&node.key);
+        Some((node.key, node.value))
+    }
+
+    //* Moves the entry one list up
+    fn touch(&mut self, index: usize) {
+        let uses = self.slab.get(index).uses;
+        self.unlink(index, uses);
+        self.slab.get_mut(index).uses = uses + 1;
+        self.slab.push_front(self.frequencies.entry(uses + 1).or_default(), index);
+        if self.lowest == uses && !self.frequencies.contains_key(&uses) {
+            self.lowest = uses + 1;
+        }
+    }
+
+    //* Takes the entry out of its list, lists that become empty are dropped
+    fn unlink(&mut self, index: usize, uses: u64) {
+        let chain = self.frequencies.get_mut(&uses).expect("list of the entry's use count");
+        self.slab.unlink(chain, index);
+        if chain.len == 0 {
+            self.frequencies.remove(&uses);
+        }
+    }
+
+    //* After removing an entry the lowest count has to be searched, but only among the distinct counts
+    fn update_lowest(&mut self) {
+        if !self.frequencies.contains_key(&self.lowest) {
+            self.lowest = self.frequencies.keys().copied().min().unwrap_or(0);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + fmt::Debug, V: fmt::Debug> fmt::Debug for LfuCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
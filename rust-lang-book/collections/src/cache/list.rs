@@ -0,0 +1,118 @@
+//* An intrusive doubly linked list: the links live inside the nodes, and the nodes live in a Vec
+//* Indices instead of pointers keep it safe, a HashMap from the key to the index finds a node in O(1)
+//* One Slab can hold several lists (the LFU cache keeps one per frequency), a Chain is the head and tail of one of them
+
+pub(super) const NIL: usize = usize::MAX;
+
+struct Node<T> {
+    value: Option<T>,
+    prev: usize,
+    next: usize,
+}
+
+pub(super) struct Slab<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Chain {
+    pub(super) head: usize,
+    pub(super) tail: usize,
+    pub(super) len: usize,
+}
+
+impl Default for Chain {
+    fn default() -> Chain {
+        Chain { head: NIL, tail: NIL, len: 0 }
+    }
+}
+
+impl<T> Slab<T> {
+    pub(super) fn with_capacity(capacity: usize) -> Slab<T> {
+        Slab { nodes: Vec::with_capacity(capacity), free: Vec::new() }
+    }
+
+    //* The new node isn't linked into any chain yet
+    pub(super) fn insert(&mut self, value: T) -> usize {
+        let node = Node { value: Some(value), prev: NIL, next: NIL };
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    //* The node must have been unlinked before
+    pub(super) fn remove(&mut self, index: usize) -> T {
+        self.free.push(index);
+        self.nodes[index].value.take().expect("node is occupied")
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+    }
+
+    pub(super) fn get(&self, index: usize) -> &T {
+        self.nodes[index].value.as_ref().expect("node is occupied")
+    }
+
+    pub(super) fn get_mut(&mut self, index: usize) -> &mut T {
+        self.nodes[index].value.as_mut().expect("node is occupied")
+    }
+
+    pub(super) fn push_front(&mut self, chain: &mut Chain, index: usize) {
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = chain.head;
+        if chain.head != NIL {
+            self.nodes[chain.head].prev = index;
+        } else {
+            chain.tail = index;
+        }
+        chain.head = index;
+        chain.len += 1;
+    }
+
+    pub(super) fn unlink(&mut self, chain: &mut Chain, index: usize) {
+        let Node { prev, next, .. } = self.nodes[index];
+        if prev != NIL {
+            self.nodes[prev].next = next;
+        } else {
+            chain.head = next;
+        }
+        if next != NIL {
+            self.nodes[next].prev = prev;
+        } else {
+            chain.tail = prev;
+        }
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = NIL;
+        chain.len -= 1;
+    }
+
+    pub(super) fn move_to_front(&mut self, chain: &mut Chain, index: usize) {
+        if chain.head != index {
+            self.unlink(chain, index);
+            self.push_front(chain, index);
+        }
+    }
+
+    //* From head to tail
+    pub(super) fn iter<'a>(&'a self, chain: &Chain) -> impl Iterator<Item = &'a T> + 'a {
+        let mut index = chain.head;
+        std::iter::from_fn(move || {
+            if index == NIL {
+                return None;
+            }
+            let node = &self.nodes[index];
+            index = node.next;
+            node.value.as_ref()
+        })
+    }
+}
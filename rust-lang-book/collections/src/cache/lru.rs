@@ -0,0 +1,202 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use super::list::{Chain, Slab};
+use super::{Displaced, Stats};
+
+//* Least recently used: the list is ordered from the most to the least recently used entry
+pub struct LruCache<K, V> {
+    map: HashMap<K, usize>,
+    slab: Slab<(K, V)>,
+    order: Chain,
+    capacity: usize,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    //* Panics if the capacity is 0
+    pub fn new(capacity: usize) -> LruCache<K, V> {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        LruCache {
+            map: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            order: Chain::default(),
+            capacity,
+            stats: Stats::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::default();
+    }
+
+    //* Marks the entry as most recently used
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.get(key).copied();
+        self.stats.record(index.is_some());
+        let index = index?;
+        self.slab.move_to_front(&mut self.order, index);
+        Some(&mut self.slab.get_mut(index).1)
+    }
+
+    //* Looks without counting as a use
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&index| &self.slab.get(index).1)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    //* Returns the old value of the key, evicts the least recently used entry when the cache is full
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert(key, value) {
+            Displaced::Replaced(_, old) => Some(old),
+            _ => None,
+        }
+    }
+
+    //* Like put, but returns the entry that was replaced or evicted
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)> {
+        match self.insert(key, value) {
+            Displaced::Replaced(key, value) | Displaced::Evicted(key, value) => Some((key, value)),
+            Displaced::Nothing => None,
+        }
+    }
+
+    //* The entry API of a cache: returns the cached value or computes, caches and returns it
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, make: F) -> &mut V {
+        match self.try_get_or_insert_with(key, || Ok::<V, std::convert::Infallible>(make())) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    //* Nothing is cached when 'make' fails
+    pub fn try_get_or_insert_with<E, F: FnOnce() -> Result<V, E>>(&mut self, key: K, make: F) -> Result<&mut V, E> {
+        let index = match self.map.get(&key) {
+            Some(&index) => {
+                self.stats.record(true);
+                self.slab.move_to_front(&mut self.order, index);
+                index
+            }
+            None => {
+                self.stats.record(false);
+                let value = make()?;
+                self.insert(key.clone(), value);
+                self.map[&key]
+            }
+        };
+        Ok(&mut self.slab.get_mut(index).1)
+    }
+
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.slab.unlink(&mut self.order, index);
+        Some(self.slab.remove(index).1)
+    }
+
+    //* Removes the least recently used entry
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let index = self.order.tail;
+        if self.map.is_empty() {
+            return None;
+        }
+        self.slab.unlink(&mut self.order, index);
+        let (key, value) = self.slab.remove(index);
+        self.map.remove(&key);
+        Some((key, value))
+    }
+
+    //* Evicts the least recently used entries when the cache shrinks
+    pub fn resize(&mut self, capacity: usize) {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        while self.map.len() > capacity {
+            self.evict();
+        }
+        self.capacity = capacity;
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slab.clear();
+        self.order = Chain::default();
+    }
+
+    //* From the most to the least recently used entry
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slab.iter(&self.order).map(|(key, value)| (key, value))
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Displaced<K, V> {
+        if let Some(&index) = self.map.get(&key) {
+            self.slab.move_to_front(&mut self.order, index);
+            let (key, value) = std::mem::replace(self.slab.get_mut(index), (key, value));
+            return Displaced::Replaced(key, value);
+        }
+        let evicted = if self.map.len() == self.capacity { self.evict() } else { None };
+        let index = self.slab.insert((key.clone(), value));
+        self.slab.push_front(&mut self.order, index);
+        self.map.insert(key, index);
+        match evicted {
+            Some((key, value)) => Displaced::Evicted(key, value),
+            None => Displaced::Nothing,
+        }
+    }
+
+    fn evict(&mut self) -> Option<(K, V)> {
+        let evicted = self.pop_lru();
+        if evicted.is_some() {
+            self.stats.evictions += 1;
+        }
+        evicted
+    }
+}
+
+impl<K: Hash + Eq + Clone + fmt::Debug, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,260 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use super::list::{Chain, Slab, NIL};
+use super::{Clock, Displaced, Stats, SystemClock};
+
+//* Time to live: every entry expires 'ttl' after it was put
+//* All entries live equally long, so the list in put order is also the list in expiry order:
+//* expired entries are always at its tail and the oldest entry is the one evicted when the cache is full
+//* Expired entries are removed lazily, by get and put, or all at once by purge_expired
+pub struct TtlCache<K, V, C = SystemClock> {
+    map: HashMap<K, usize>,
+    slab: Slab<Node<K, V>>,
+    order: Chain,
+    capacity: usize,
+    ttl: Duration,
+    clock: C,
+    stats: Stats,
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    expires: Instant,
+}
+
+impl<K: Hash + Eq + Clone, V> TtlCache<K, V> {
+    //* Panics if the capacity is 0
+    pub fn new(capacity: usize, ttl: Duration) -> TtlCache<K, V> {
+        TtlCache::with_clock(capacity, ttl, SystemClock)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, C: Clock> TtlCache<K, V, C> {
+    pub fn with_clock(capacity: usize, ttl: Duration, clock: C) -> TtlCache<K, V, C> {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        TtlCache {
+            map: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            order: Chain::default(),
+            capacity,
+            ttl,
+            clock,
+            stats: Stats::default(),
+        }
+    }
+
+    //* Includes expired entries that weren't removed yet
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::default();
+    }
+
+    //* An expired entry is removed and counts as a miss
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.live_index(key);
+        self.stats.record(index.is_some());
+        Some(&mut self.slab.get_mut(index?).value)
+    }
+
+    //* Looks without updating the statistics or removing expired entries
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = self.slab.get(*self.map.get(key)?);
+        if node.expires > self.clock.now() {
+            Some(&node.value)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.peek(key).is_some()
+    }
+
+    //* How long the entry has left, None if it isn't cached or already expired
+    pub fn remaining<Q>(&self, key: &Q) -> Option<Duration>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = self.slab.get(*self.map.get(key)?);
+        node.expires.checked_duration_since(self.clock.now()).filter(|left| !left.is_zero())
+    }
+
+    //* Returns the old value of the key, putting an existing key starts its time again
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert(key, value) {
+            Displaced::Replaced(_, old) => Some(old),
+            _ => None,
+        }
+    }
+
+    //* Like put, but returns the entry that was replaced or evicted, expired entries that were removed are not returned
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)> {
+        match self.insert(key, value) {
+            Displaced::Replaced(key, value) | Displaced::Evicted(key, value) => Some((key, value)),
+            Displaced::Nothing => None,
+        }
+    }
+
+    //* Returns the cached value or computes, caches and returns it
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, make: F) -> &mut V {
+        match self.try_get_or_insert_with(key, || Ok::<V, std::convert::Infallible>(make())) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    //* Nothing is cached when 'make' fails
+    pub fn try_get_or_insert_with<E, F: FnOnce() -> Result<V, E>>(&mut self, key: K, make: F) -> Result<&mut V, E> {
+        let index = match self.live_index(&key) {
+            Some(index) => {
+                self.stats.record(true);
+                index
+            }
+            None => {
+                self.stats.record(false);
+                let value = make()?;
+                self.insert(key.clone(), value);
+                self.map[&key]
+            }
+        };
+        Ok(&mut self.slab.get_mut(index).value)
+    }
+
+    //* Returns the value even if it has expired
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.slab.unlink(&mut self.order, index);
+        Some(self.slab.remove(index).value)
+    }
+
+    //* Removes all expired entries and returns how many there were
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let mut purged = 0;
+        while self.order.tail != NIL && self.slab.get(self.order.tail).expires <= now {
+            self.remove_oldest();
+            purged += 1;
+        }
+        self.stats.expirations += purged as u64;
+        purged
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slab.clear();
+        self.order = Chain::default();
+    }
+
+    //* The entries that haven't expired, from the newest to the oldest
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let now = self.clock.now();
+        self.slab.iter(&self.order).take_while(move |node| node.expires > now).map(|node| (&node.key, &node.value))
+    }
+
+    //* The index of the entry if it hasn't expired, an expired one is removed
+    fn live_index<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = *self.map.get(key)?;
+        if self.slab.get(index).expires > self.clock.now() {
+            return Some(index);
+        }
+        self.map.remove(key);
+        self.slab.unlink(&mut self.order, index);
+        self.slab.remove(index);
+        self.stats.expirations += 1;
+        None
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Displaced<K, V> {
+        let expires = self.clock.now() + self.ttl;
+        if let Some(&index) = self.map.get(&key) {
+            self.slab.move_to_front(&mut self.order, index);
+            let node = self.slab.get_mut(index);
+            node.expires = expires;
+            let old_key = std::mem::replace(&mut node.key, key);
+            let old_value = std::mem::replace(&mut node.value, value);
+            return Displaced::Replaced(old_key, old_value);
+        }
+        let mut evicted = None;
+        if self.map.len() == self.capacity && self.purge_expired() == 0 {
+            evicted = self.remove_oldest();
+            self.stats.evictions += 1;
+        }
+        let index = self.slab.insert(Node { key: key.clone(), value, expires });
+        self.slab.push_front(&mut self.order, index);
+        self.map.insert(key, index);
+        match evicted {
+            Some((key, value)) => Displaced::Evicted(key, value),
+            None => Displaced::Nothing,
+        }
+    }
+
+    fn remove_oldest(&mut self) -> Option<(K, V)> {
+        if self.map.is_empty() {
+            return None;
+        }
+        let index = self.order.tail;
+        self.slab.unlink(&mut self.order, index);
+        let node = self.slab.remove(index);
+        self.map.remove(&node.key);
+        Some((node.key, node.value))
+    }
+}
+
+impl<K: Hash + Eq + Clone + fmt::Debug, V: fmt::Debug, C: Clock> fmt::Debug for TtlCache<K, V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+mod data;
+
+use data::COUNTRIES;
+
+//* A bundled ISO 3166-1 country table, so that maps like {1: "germany", 2: "france"} don't have to be written by hand
+//* Countries are looked up by any of their codes or by name, names are compared case-insensitively
+//* and without accents, so "cote d'ivoire" finds "Côte d'Ivoire"
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Continent {
+    pub const ALL: [Continent; 7] = [
+        Continent::Africa,
+        Continent::Antarctica,
+        Continent::Asia,
+        Continent::Europe,
+        Continent::NorthAmerica,
+        Continent::Oceania,
+        Continent::SouthAmerica,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Continent::Africa => "Africa",
+            Continent::Antarctica => "Antarctica",
+            Continent::Asia => "Asia",
+            Continent::Europe => "Europe",
+            Continent::NorthAmerica => "North America",
+            Continent::Oceania => "Oceania",
+            Continent::SouthAmerica => "South America",
+        }
+    }
+}
+
+impl fmt::Display for Continent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Country {
+    alpha2: &'static str,
+    alpha3: &'static str,
+    numeric: u16,
+    name: &'static str,
+    official_name: Option<&'static str>,
+    capital: Option<&'static str>,
+    continent: Continent,
+    aliases: &'static [&'static str],
+}
+
+//* A typed handle for a country, wraps the ISO numeric code
+//* Use it as the key of id-to-name maps instead of a bare i32
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CountryId(u16);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCountry(pub String);
+
+impl fmt::Display for UnknownCountry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown country '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCountry {}
+
+impl Country {
+    pub fn all() -> &'static [Country] {
+        &COUNTRIES
+    }
+
+    pub fn id(&self) -> CountryId {
+        CountryId(self.numeric)
+    }
+
+    pub fn alpha2(&self) -> &'static str {
+        self.alpha2
+    }
+
+    pub fn alpha3(&self) -> &'static str {
+        self.alpha3
+    }
+
+    pub fn numeric(&self) -> u16 {
+        self.numeric
+    }
+
+    //* The short name, e.g. "Bolivia" rather than "Bolivia, Plurinational State of"
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn official_name(&self) -> &'static str {
+        self.official_name.unwrap_or(self.name)
+    }
+
+    pub fn capital(&self) -> Option<&'static str> {
+        self.capital
+    }
+
+    pub fn continent(&self) -> Continent {
+        self.continent
+    }
+
+    //* Other names the country is known by, e.g. "UK" or "Great Britain"
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    pub fn by_alpha2(code: &str) -> Option<&'static Country> {
+        let code = code.to_ascii_uppercase();
+        COUNTRIES
+            .binary_search_by(|c| c.alpha2.cmp(code.as_str()))
+            .ok()
+            .map(|i| &COUNTRIES[i])
+    }
+
+    pub fn by_alpha3(code: &str) -> Option<&'static Country> {
+        COUNTRIES.iter().find(|c| c.alpha3.eq_ignore_ascii_case(code))
+    }
+
+    pub fn by_numeric(numeric: u16) -> Option<&'static Country> {
+        COUNTRIES.iter().find(|c| c.numeric == numeric)
+    }
+
+    //* Any of the three codes: "DE", "DEU" or "276" (leading zeros are optional)
+    pub fn by_code(code: &str) -> Option<&'static Country> {
+        let code = code.trim();
+        if !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()) {
+            return code.parse().ok().and_then(Country::by_numeric);
+        }
+        match code.len() {
+            2 => Country::by_alpha2(code),
+            3 => Country::by_alpha3(code),
+            _ => None,
+        }
+    }
+
+    //* Exact match on the name, official name or an alias, ignoring case and accents
+    pub fn by_name(name: &str) -> Option<&'static Country> {
+        name_index().get(&fold(name)).map(|&i| &COUNTRIES[i])
+    }
+
+    //* Tries the codes first, then the names
+    pub fn lookup(query: &str) -> Option<&'static Country> {
+        Country::by_code(query).or_else(|| Country::by_name(query))
+    }
+
+    pub fn in_continent(continent: Continent) -> impl Iterator<Item = &'static Country> {
+        COUNTRIES.iter().filter(move |c| c.continent == continent)
+    }
+
+    //* Fuzzy search over all names, best matches first
+    //* Ranked by: exact match, name starts with the query, a word starts with the query,
+    //* name contains the query, and finally small typos (edit distance of up to a third of the query)
+    pub fn search(query: &str) -> Vec<&'static Country> {
+        let query = fold(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut ranked: Vec<(usize, &'static Country)> = COUNTRIES
+            .iter()
+            .filter_map(|country| {
+                country
+                    .names()
+                    .filter_map(|name| match_rank(&query, &fold(name)))
+                    .min()
+                    .map(|rank| (rank, country))
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(b.1.name)));
+        ranked.into_iter().map(|(_, country)| country).collect()
+    }
+
+    fn names(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.name)
+            .chain(self.official_name)
+            .chain(self.aliases.iter().copied())
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name)
+    }
+}
+
+impl CountryId {
+    pub fn from_numeric(numeric: u16) -> Option<CountryId> {
+        Country::by_numeric(numeric).map(Country::id)
+    }
+
+    pub fn country(&self) -> &'static Country {
+        //* CountryIds are only handed out for existing countries
+        Country::by_numeric(self.0).expect("CountryId of a known country")
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.country().name
+    }
+
+    pub fn numeric(&self) -> u16 {
+        self.0
+    }
+}
+
+//* Printed as the alpha-2 code, e.g. "DE"
+impl fmt::Display for CountryId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.country().alpha2)
+    }
+}
+
+//* Accepts any code or name, see Country::lookup
+impl FromStr for CountryId {
+    type Err = UnknownCountry;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Country::lookup(s).map(Country::id).ok_or_else(|| UnknownCountry(s.to_string()))
+    }
+}
+
+fn name_index() -> &'static HashMap<String, usize> {
+    static INDEX: OnceLock<HashMap<String, usize>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index = HashMap::new();
+        for (i, country) in COUNTRIES.iter().enumerate() {
+            for name in country.names() {
+                index.entry(fold(name)).or_insert(i);
+            }
+        }
+        index
+    })
+}
+
+//* Lower case without accents, punctuation becomes a single space: "Korea, Republic of" -> "korea republic of"
+fn fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let c = strip_accent(c);
+        if c.is_alphanumeric() {
+            folded.push(c);
+        } else if c == '\'' || c == '\u{2019}' || c == '\u{2bb}' {
+            //* "N'Djamena", "Côte d'Ivoire" are written without a gap
+        } else if !folded.is_empty() && !folded.ends_with(' ') {
+            folded.push(' ');
+        }
+    }
+    folded.truncate(folded.trim_end().len());
+    folded
+}
+
+fn strip_accent(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => 'i',
+        'ł' | 'ľ' | 'ĺ' => 'l',
+        'ñ' | 'ń' | 'ň' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+        'ř' | 'ŕ' => 'r',
+        'ś' | 'š' | 'ş' | 'ș' => 's',
+        'ť' | 'ţ' | 'ț' => 't',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+fn match_rank(query: &str, name: &str) -> Option<usize> {
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(query) {
+        return Some(1);
+    }
+    if name.split(' ').any(|word| word.starts_with(query)) {
+        return Some(2);
+    }
+    if name.contains(query) {
+        return Some(3);
+    }
+
+    //* Typos are compared against the whole name and against each word
+    let allowed = (query.chars().count() / 3).max(1);
+    std::iter::once(name)
+        .chain(name.split(' '))
+        .map(|candidate| edit_distance(query, candidate))
+        .min()
+        .filter(|&distance| distance <= allowed)
+        .map(|distance| 3 + distance)
+}
+
+//* Levenshtein distance over chars, keeping a single row of the table
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,258 @@
+//* ISO 3166-1 countries, sorted by alpha-2 code
+//* Codes, names and official names follow the iso-codes project (iso_3166-1.json), capitals and continents were added by hand
+//* Territories without a capital (e.g. Antarctica) have none, countries spanning two continents are listed under one
+
+use super::Continent::*;
+use super::Country;
+
+pub(super) static COUNTRIES: [Country; 249] = [
+    Country { alpha2: "AD", alpha3: "AND", numeric: 20, name: "Andorra", official_name: Some("Principality of Andorra"), capital: Some("Andorra la Vella"), continent: Europe, aliases: &[] },
+    Country { alpha2: "AE", alpha3: "ARE", numeric: 784, name: "United Arab Emirates", official_name: None, capital: Some("Abu Dhabi"), continent: Asia, aliases: &["UAE", "Emirates"] },
+    Country { alpha2: "AF", alpha3: "AFG", numeric: 4, name: "Afghanistan", official_name: Some("Islamic Republic of Afghanistan"), capital: Some("Kabul"), continent: Asia, aliases: &[] },
+    Country { alpha2: "AG", alpha3: "ATG", numeric: 28, name: "Antigua and Barbuda", official_name: None, capital: Some("Saint John's"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "AI", alpha3: "AIA", numeric: 660, name: "Anguilla", official_name: None, capital: Some("The Valley"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "AL", alpha3: "ALB", numeric: 8, name: "Albania", official_name: Some("Republic of Albania"), capital: Some("Tirana"), continent: Europe, aliases: &[] },
+    Country { alpha2: "AM", alpha3: "ARM", numeric: 51, name: "Armenia", official_name: Some("Republic of Armenia"), capital: Some("Yerevan"), continent: Asia, aliases: &[] },
+    Country { alpha2: "AO", alpha3: "AGO", numeric: 24, name: "Angola", official_name: Some("Republic of Angola"), capital: Some("Luanda"), continent: Africa, aliases: &[] },
+    Country { alpha2: "AQ", alpha3: "ATA", numeric: 10, name: "Antarctica", official_name: None, capital: None, continent: Antarctica, aliases: &[] },
+    Country { alpha2: "AR", alpha3: "ARG", numeric: 32, name: "Argentina", official_name: Some("Argentine Republic"), capital: Some("Buenos Aires"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "AS", alpha3: "ASM", numeric: 16, name: "American Samoa", official_name: None, capital: Some("Pago Pago"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "AT", alpha3: "AUT", numeric: 40, name: "Austria", official_name: Some("Republic of Austria"), capital: Some("Vienna"), continent: Europe, aliases: &[] },
+    Country { alpha2: "AU", alpha3: "AUS", numeric: 36, name: "Australia", official_name: None, capital: Some("Canberra"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "AW", alpha3: "ABW", numeric: 533, name: "Aruba", official_name: None, capital: Some("Oranjestad"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "AX", alpha3: "ALA", numeric: 248, name: "Åland Islands", official_name: None, capital: Some("Mariehamn"), continent: Europe, aliases: &[] },
+    Country { alpha2: "AZ", alpha3: "AZE", numeric: 31, name: "Azerbaijan", official_name: Some("Republic of Azerbaijan"), capital: Some("Baku"), continent: Asia, aliases: &[] },
+    Country { alpha2: "BA", alpha3: "BIH", numeric: 70, name: "Bosnia and Herzegovina", official_name: Some("Republic of Bosnia and Herzegovina"), capital: Some("Sarajevo"), continent: Europe, aliases: &[] },
+    Country { alpha2: "BB", alpha3: "BRB", numeric: 52, name: "Barbados", official_name: None, capital: Some("Bridgetown"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "BD", alpha3: "BGD", numeric: 50, name: "Bangladesh", official_name: Some("People's Republic of Bangladesh"), capital: Some("Dhaka"), continent: Asia, aliases: &[] },
+    Country { alpha2: "BE", alpha3: "BEL", numeric: 56, name: "Belgium", official_name: Some("Kingdom of Belgium"), capital: Some("Brussels"), continent: Europe, aliases: &[] },
+    Country { alpha2: "BF", alpha3: "BFA", numeric: 854, name: "Burkina Faso", official_name: None, capital: Some("Ouagadougou"), continent: Africa, aliases: &[] },
+    Country { alpha2: "BG", alpha3: "BGR", numeric: 100, name: "Bulgaria", official_name: Some("Republic of Bulgaria"), capital: Some("Sofia"), continent: Europe, aliases: &[] },
+    Country { alpha2: "BH", alpha3: "BHR", numeric: 48, name: "Bahrain", official_name: Some("Kingdom of Bahrain"), capital: Some("Manama"), continent: Asia, aliases: &[] },
+    Country { alpha2: "BI", alpha3: "BDI", numeric: 108, name: "Burundi", official_name: Some("Republic of Burundi"), capital: Some("Gitega"), continent: Africa, aliases: &[] },
+    Country { alpha2: "BJ", alpha3: "BEN", numeric: 204, name: "Benin", official_name: Some("Republic of Benin"), capital: Some("Porto-Novo"), continent: Africa, aliases: &[] },
+    Country { alpha2: "BL", alpha3: "BLM", numeric: 652, name: "Saint Barthélemy", official_name: None, capital: Some("Gustavia"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "BM", alpha3: "BMU", numeric: 60, name: "Bermuda", official_name: None, capital: Some("Hamilton"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "BN", alpha3: "BRN", numeric: 96, name: "Brunei Darussalam", official_name: None, capital: Some("Bandar Seri Begawan"), continent: Asia, aliases: &["Brunei"] },
+    Country { alpha2: "BO", alpha3: "BOL", numeric: 68, name: "Bolivia", official_name: Some("Plurinational State of Bolivia"), capital: Some("Sucre"), continent: SouthAmerica, aliases: &["Bolivia, Plurinational State of"] },
+    Country { alpha2: "BQ", alpha3: "BES", numeric: 535, name: "Bonaire, Sint Eustatius and Saba", official_name: Some("Bonaire, Sint Eustatius and Saba"), capital: Some("Kralendijk"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "BR", alpha3: "BRA", numeric: 76, name: "Brazil", official_name: Some("Federative Republic of Brazil"), capital: Some("Brasília"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "BS", alpha3: "BHS", numeric: 44, name: "Bahamas", official_name: Some("Commonwealth of the Bahamas"), capital: Some("Nassau"), continent: NorthAmerica, aliases: &["The Bahamas"] },
+    Country { alpha2: "BT", alpha3: "BTN", numeric: 64, name: "Bhutan", official_name: Some("Kingdom of Bhutan"), capital: Some("Thimphu"), continent: Asia, aliases: &[] },
+    Country { alpha2: "BV", alpha3: "BVT", numeric: 74, name: "Bouvet Island", official_name: None, capital: None, continent: Antarctica, aliases: &[] },
+    Country { alpha2: "BW", alpha3: "BWA", numeric: 72, name: "Botswana", official_name: Some("Republic of Botswana"), capital: Some("Gaborone"), continent: Africa, aliases: &[] },
+    Country { alpha2: "BY", alpha3: "BLR", numeric: 112, name: "Belarus", official_name: Some("Republic of Belarus"), capital: Some("Minsk"), continent: Europe, aliases: &[] },
+    Country { alpha2: "BZ", alpha3: "BLZ", numeric: 84, name: "Belize", official_name: None, capital: Some("Belmopan"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "CA", alpha3: "CAN", numeric: 124, name: "Canada", official_name: None, capital: Some("Ottawa"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "CC", alpha3: "CCK", numeric: 166, name: "Cocos (Keeling) Islands", official_name: None, capital: Some("West Island"), continent: Asia, aliases: &["Cocos Islands", "Keeling Islands"] },
+    Country { alpha2: "CD", alpha3: "COD", numeric: 180, name: "Congo, The Democratic Republic of the", official_name: None, capital: Some("Kinshasa"), continent: Africa, aliases: &["DR Congo", "Congo-Kinshasa", "Democratic Republic of the Congo"] },
+    Country { alpha2: "CF", alpha3: "CAF", numeric: 140, name: "Central African Republic", official_name: None, capital: Some("Bangui"), continent: Africa, aliases: &[] },
+    Country { alpha2: "CG", alpha3: "COG", numeric: 178, name: "Congo", official_name: Some("Republic of the Congo"), capital: Some("Brazzaville"), continent: Africa, aliases: &["Congo-Brazzaville", "Republic of the Congo"] },
+    Country { alpha2: "CH", alpha3: "CHE", numeric: 756, name: "Switzerland", official_name: Some("Swiss Confederation"), capital: Some("Bern"), continent: Europe, aliases: &[] },
+    Country { alpha2: "CI", alpha3: "CIV", numeric: 384, name: "Côte d'Ivoire", official_name: Some("Republic of Côte d'Ivoire"), capital: Some("Yamoussoukro"), continent: Africa, aliases: &["Ivory Coast"] },
+    Country { alpha2: "CK", alpha3: "COK", numeric: 184, name: "Cook Islands", official_name: None, capital: Some("Avarua"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "CL", alpha3: "CHL", numeric: 152, name: "Chile", official_name: Some("Republic of Chile"), capital: Some("Santiago"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "CM", alpha3: "CMR", numeric: 120, name: "Cameroon", official_name: Some("Republic of Cameroon"), capital: Some("Yaoundé"), continent: Africa, aliases: &[] },
+    Country { alpha2: "CN", alpha3: "CHN", numeric: 156, name: "China", official_name: Some("People's Republic of China"), capital: Some("Beijing"), continent: Asia, aliases: &[] },
+    Country { alpha2: "CO", alpha3: "COL", numeric: 170, name: "Colombia", official_name: Some("Republic of Colombia"), capital: Some("Bogotá"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "CR", alpha3: "CRI", numeric: 188, name: "Costa Rica", official_name: Some("Republic of Costa Rica"), capital: Some("San José"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "CU", alpha3: "CUB", numeric: 192, name: "Cuba", official_name: Some("Republic of Cuba"), capital: Some("Havana"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "CV", alpha3: "CPV", numeric: 132, name: "Cabo Verde", official_name: Some("Republic of Cabo Verde"), capital: Some("Praia"), continent: Africa, aliases: &["Cape Verde"] },
+    Country { alpha2: "CW", alpha3: "CUW", numeric: 531, name: "Curaçao", official_name: Some("Curaçao"), capital: Some("Willemstad"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "CX", alpha3: "CXR", numeric: 162, name: "Christmas Island", official_name: None, capital: Some("Flying Fish Cove"), continent: Asia, aliases: &[] },
+    Country { alpha2: "CY", alpha3: "CYP", numeric: 196, name: "Cyprus", official_name: Some("Republic of Cyprus"), capital: Some("Nicosia"), continent: Europe, aliases: &[] },
+    Country { alpha2: "CZ", alpha3: "CZE", numeric: 203, name: "Czechia", official_name: Some("Czech Republic"), capital: Some("Prague"), continent: Europe, aliases: &["Czech Republic"] },
+    Country { alpha2: "DE", alpha3: "DEU", numeric: 276, name: "Germany", official_name: Some("Federal Republic of Germany"), capital: Some("Berlin"), continent: Europe, aliases: &[] },
+    Country { alpha2: "DJ", alpha3: "DJI", numeric: 262, name: "Djibouti", official_name: Some("Republic of Djibouti"), capital: Some("Djibouti"), continent: Africa, aliases: &[] },
+    Country { alpha2: "DK", alpha3: "DNK", numeric: 208, name: "Denmark", official_name: Some("Kingdom of Denmark"), capital: Some("Copenhagen"), continent: Europe, aliases: &[] },
+    Country { alpha2: "DM", alpha3: "DMA", numeric: 212, name: "Dominica", official_name: Some("Commonwealth of Dominica"), capital: Some("Roseau"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "DO", alpha3: "DOM", numeric: 214, name: "Dominican Republic", official_name: None, capital: Some("Santo Domingo"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "DZ", alpha3: "DZA", numeric: 12, name: "Algeria", official_name: Some("People's Democratic Republic of Algeria"), capital: Some("Algiers"), continent: Africa, aliases: &[] },
+    Country { alpha2: "EC", alpha3: "ECU", numeric: 218, name: "Ecuador", official_name: Some("Republic of Ecuador"), capital: Some("Quito"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "EE", alpha3: "EST", numeric: 233, name: "Estonia", official_name: Some("Republic of Estonia"), capital: Some("Tallinn"), continent: Europe, aliases: &[] },
+    Country { alpha2: "EG", alpha3: "EGY", numeric: 818, name: "Egypt", official_name: Some("Arab Republic of Egypt"), capital: Some("Cairo"), continent: Africa, aliases: &[] },
+    Country { alpha2: "EH", alpha3: "ESH", numeric: 732, name: "Western Sahara", official_name: None, capital: Some("Laayoune"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ER", alpha3: "ERI", numeric: 232, name: "Eritrea", official_name: Some("the State of Eritrea"), capital: Some("Asmara"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ES", alpha3: "ESP", numeric: 724, name: "Spain", official_name: Some("Kingdom of Spain"), capital: Some("Madrid"), continent: Europe, aliases: &[] },
+    Country { alpha2: "ET", alpha3: "ETH", numeric: 231, name: "Ethiopia", official_name: Some("Federal Democratic Republic of Ethiopia"), capital: Some("Addis Ababa"), continent: Africa, aliases: &[] },
+    Country { alpha2: "FI", alpha3: "FIN", numeric: 246, name: "Finland", official_name: Some("Republic of Finland"), capital: Some("Helsinki"), continent: Europe, aliases: &[] },
+    Country { alpha2: "FJ", alpha3: "FJI", numeric: 242, name: "Fiji", official_name: Some("Republic of Fiji"), capital: Some("Suva"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "FK", alpha3: "FLK", numeric: 238, name: "Falkland Islands (Malvinas)", official_name: None, capital: Some("Stanley"), continent: SouthAmerica, aliases: &["Falkland Islands"] },
+    Country { alpha2: "FM", alpha3: "FSM", numeric: 583, name: "Micronesia, Federated States of", official_name: Some("Federated States of Micronesia"), capital: Some("Palikir"), continent: Oceania, aliases: &["Micronesia"] },
+    Country { alpha2: "FO", alpha3: "FRO", numeric: 234, name: "Faroe Islands", official_name: None, capital: Some("Tórshavn"), continent: Europe, aliases: &[] },
+    Country { alpha2: "FR", alpha3: "FRA", numeric: 250, name: "France", official_name: Some("French Republic"), capital: Some("Paris"), continent: Europe, aliases: &[] },
+    Country { alpha2: "GA", alpha3: "GAB", numeric: 266, name: "Gabon", official_name: Some("Gabonese Republic"), capital: Some("Libreville"), continent: Africa, aliases: &[] },
+    Country { alpha2: "GB", alpha3: "GBR", numeric: 826, name: "United Kingdom", official_name: Some("United Kingdom of Great Britain and Northern Ireland"), capital: Some("London"), continent: Europe, aliases: &["UK", "Great Britain", "Britain", "England"] },
+    Country { alpha2: "GD", alpha3: "GRD", numeric: 308, name: "Grenada", official_name: None, capital: Some("Saint George's"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "GE", alpha3: "GEO", numeric: 268, name: "Georgia", official_name: None, capital: Some("Tbilisi"), continent: Asia, aliases: &[] },
+    Country { alpha2: "GF", alpha3: "GUF", numeric: 254, name: "French Guiana", official_name: None, capital: Some("Cayenne"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "GG", alpha3: "GGY", numeric: 831, name: "Guernsey", official_name: None, capital: Some("Saint Peter Port"), continent: Europe, aliases: &[] },
+    Country { alpha2: "GH", alpha3: "GHA", numeric: 288, name: "Ghana", official_name: Some("Republic of Ghana"), capital: Some("Accra"), continent: Africa, aliases: &[] },
+    Country { alpha2: "GI", alpha3: "GIB", numeric: 292, name: "Gibraltar", official_name: None, capital: Some("Gibraltar"), continent: Europe, aliases: &[] },
+    Country { alpha2: "GL", alpha3: "GRL", numeric: 304, name: "Greenland", official_name: None, capital: Some("Nuuk"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "GM", alpha3: "GMB", numeric: 270, name: "Gambia", official_name: Some("Republic of the Gambia"), capital: Some("Banjul"), continent: Africa, aliases: &["The Gambia"] },
+    Country { alpha2: "GN", alpha3: "GIN", numeric: 324, name: "Guinea", official_name: Some("Republic of Guinea"), capital: Some("Conakry"), continent: Africa, aliases: &[] },
+    Country { alpha2: "GP", alpha3: "GLP", numeric: 312, name: "Guadeloupe", official_name: None, capital: Some("Basse-Terre"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "GQ", alpha3: "GNQ", numeric: 226, name: "Equatorial Guinea", official_name: Some("Republic of Equatorial Guinea"), capital: Some("Malabo"), continent: Africa, aliases: &[] },
+    Country { alpha2: "GR", alpha3: "GRC", numeric: 300, name: "Greece", official_name: Some("Hellenic Republic"), capital: Some("Athens"), continent: Europe, aliases: &[] },
+    Country { alpha2: "GS", alpha3: "SGS", numeric: 239, name: "South Georgia and the South Sandwich Islands", official_name: None, capital: Some("King Edward Point"), continent: Antarctica, aliases: &[] },
+    Country { alpha2: "GT", alpha3: "GTM", numeric: 320, name: "Guatemala", official_name: Some("Republic of Guatemala"), capital: Some("Guatemala City"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "GU", alpha3: "GUM", numeric: 316, name: "Guam", official_name: None, capital: Some("Hagåtña"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "GW", alpha3: "GNB", numeric: 624, name: "Guinea-Bissau", official_name: Some("Republic of Guinea-Bissau"), capital: Some("Bissau"), continent: Africa, aliases: &[] },
+    Country { alpha2: "GY", alpha3: "GUY", numeric: 328, name: "Guyana", official_name: Some("Republic of Guyana"), capital: Some("Georgetown"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "HK", alpha3: "HKG", numeric: 344, name: "Hong Kong", official_name: Some("Hong Kong Special Administrative Region of China"), capital: Some("Hong Kong"), continent: Asia, aliases: &[] },
+    Country { alpha2: "HM", alpha3: "HMD", numeric: 334, name: "Heard Island and McDonald Islands", official_name: None, capital: None, continent: Antarctica, aliases: &[] },
+    Country { alpha2: "HN", alpha3: "HND", numeric: 340, name: "Honduras", official_name: Some("Republic of Honduras"), capital: Some("Tegucigalpa"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "HR", alpha3: "HRV", numeric: 191, name: "Croatia", official_name: Some("Republic of Croatia"), capital: Some("Zagreb"), continent: Europe, aliases: &[] },
+    Country { alpha2: "HT", alpha3: "HTI", numeric: 332, name: "Haiti", official_name: Some("Republic of Haiti"), capital: Some("Port-au-Prince"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "HU", alpha3: "HUN", numeric: 348, name: "Hungary", official_name: Some("Hungary"), capital: Some("Budapest"), continent: Europe, aliases: &[] },
+    Country { alpha2: "ID", alpha3: "IDN", numeric: 360, name: "Indonesia", official_name: Some("Republic of Indonesia"), capital: Some("Jakarta"), continent: Asia, aliases: &[] },
+    Country { alpha2: "IE", alpha3: "IRL", numeric: 372, name: "Ireland", official_name: None, capital: Some("Dublin"), continent: Europe, aliases: &[] },
+    Country { alpha2: "IL", alpha3: "ISR", numeric: 376, name: "Israel", official_name: Some("State of Israel"), capital: Some("Jerusalem"), continent: Asia, aliases: &[] },
+    Country { alpha2: "IM", alpha3: "IMN", numeric: 833, name: "Isle of Man", official_name: None, capital: Some("Douglas"), continent: Europe, aliases: &[] },
+    Country { alpha2: "IN", alpha3: "IND", numeric: 356, name: "India", official_name: Some("Republic of India"), capital: Some("New Delhi"), continent: Asia, aliases: &[] },
+    Country { alpha2: "IO", alpha3: "IOT", numeric: 86, name: "British Indian Ocean Territory", official_name: None, capital: Some("Diego Garcia"), continent: Asia, aliases: &[] },
+    Country { alpha2: "IQ", alpha3: "IRQ", numeric: 368, name: "Iraq", official_name: Some("Republic of Iraq"), capital: Some("Baghdad"), continent: Asia, aliases: &[] },
+    Country { alpha2: "IR", alpha3: "IRN", numeric: 364, name: "Iran", official_name: Some("Islamic Republic of Iran"), capital: Some("Tehran"), continent: Asia, aliases: &["Iran, Islamic Republic of"] },
+    Country { alpha2: "IS", alpha3: "ISL", numeric: 352, name: "Iceland", official_name: Some("Republic of Iceland"), capital: Some("Reykjavík"), continent: Europe, aliases: &[] },
+    Country { alpha2: "IT", alpha3: "ITA", numeric: 380, name: "Italy", official_name: Some("Italian Republic"), capital: Some("Rome"), continent: Europe, aliases: &[] },
+    Country { alpha2: "JE", alpha3: "JEY", numeric: 832, name: "Jersey", official_name: None, capital: Some("Saint Helier"), continent: Europe, aliases: &[] },
+    Country { alpha2: "JM", alpha3: "JAM", numeric: 388, name: "Jamaica", official_name: None, capital: Some("Kingston"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "JO", alpha3: "JOR", numeric: 400, name: "Jordan", official_name: Some("Hashemite Kingdom of Jordan"), capital: Some("Amman"), continent: Asia, aliases: &[] },
+    Country { alpha2: "JP", alpha3: "JPN", numeric: 392, name: "Japan", official_name: None, capital: Some("Tokyo"), continent: Asia, aliases: &[] },
+    Country { alpha2: "KE", alpha3: "KEN", numeric: 404, name: "Kenya", official_name: Some("Republic of Kenya"), capital: Some("Nairobi"), continent: Africa, aliases: &[] },
+    Country { alpha2: "KG", alpha3: "KGZ", numeric: 417, name: "Kyrgyzstan", official_name: Some("Kyrgyz Republic"), capital: Some("Bishkek"), continent: Asia, aliases: &[] },
+    Country { alpha2: "KH", alpha3: "KHM", numeric: 116, name: "Cambodia", official_name: Some("Kingdom of Cambodia"), capital: Some("Phnom Penh"), continent: Asia, aliases: &[] },
+    Country { alpha2: "KI", alpha3: "KIR", numeric: 296, name: "Kiribati", official_name: Some("Republic of Kiribati"), capital: Some("South Tarawa"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "KM", alpha3: "COM", numeric: 174, name: "Comoros", official_name: Some("Union of the Comoros"), capital: Some("Moroni"), continent: Africa, aliases: &[] },
+    Country { alpha2: "KN", alpha3: "KNA", numeric: 659, name: "Saint Kitts and Nevis", official_name: None, capital: Some("Basseterre"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "KP", alpha3: "PRK", numeric: 408, name: "North Korea", official_name: Some("Democratic People's Republic of Korea"), capital: Some("Pyongyang"), continent: Asia, aliases: &["Korea, Democratic People's Republic of"] },
+    Country { alpha2: "KR", alpha3: "KOR", numeric: 410, name: "South Korea", official_name: None, capital: Some("Seoul"), continent: Asia, aliases: &["Korea, Republic of", "Korea"] },
+    Country { alpha2: "KW", alpha3: "KWT", numeric: 414, name: "Kuwait", official_name: Some("State of Kuwait"), capital: Some("Kuwait City"), continent: Asia, aliases: &[] },
+    Country { alpha2: "KY", alpha3: "CYM", numeric: 136, name: "Cayman Islands", official_name: None, capital: Some("George Town"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "KZ", alpha3: "KAZ", numeric: 398, name: "Kazakhstan", official_name: Some("Republic of Kazakhstan"), capital: Some("Astana"), continent: Asia, aliases: &[] },
+    Country { alpha2: "LA", alpha3: "LAO", numeric: 418, name: "Laos", official_name: None, capital: Some("Vientiane"), continent: Asia, aliases: &["Lao People's Democratic Republic"] },
+    Country { alpha2: "LB", alpha3: "LBN", numeric: 422, name: "Lebanon", official_name: Some("Lebanese Republic"), capital: Some("Beirut"), continent: Asia, aliases: &[] },
+    Country { alpha2: "LC", alpha3: "LCA", numeric: 662, name: "Saint Lucia", official_name: None, capital: Some("Castries"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "LI", alpha3: "LIE", numeric: 438, name: "Liechtenstein", official_name: Some("Principality of Liechtenstein"), capital: Some("Vaduz"), continent: Europe, aliases: &[] },
+    Country { alpha2: "LK", alpha3: "LKA", numeric: 144, name: "Sri Lanka", official_name: Some("Democratic Socialist Republic of Sri Lanka"), capital: Some("Sri Jayawardenepura Kotte"), continent: Asia, aliases: &[] },
+    Country { alpha2: "LR", alpha3: "LBR", numeric: 430, name: "Liberia", official_name: Some("Republic of Liberia"), capital: Some("Monrovia"), continent: Africa, aliases: &[] },
+    Country { alpha2: "LS", alpha3: "LSO", numeric: 426, name: "Lesotho", official_name: Some("Kingdom of Lesotho"), capital: Some("Maseru"), continent: Africa, aliases: &[] },
+    Country { alpha2: "LT", alpha3: "LTU", numeric: 440, name: "Lithuania", official_name: Some("Republic of Lithuania"), capital: Some("Vilnius"), continent: Europe, aliases: &[] },
+    Country { alpha2: "LU", alpha3: "LUX", numeric: 442, name: "Luxembourg", official_name: Some("Grand Duchy of Luxembourg"), capital: Some("Luxembourg"), continent: Europe, aliases: &[] },
+    Country { alpha2: "LV", alpha3: "LVA", numeric: 428, name: "Latvia", official_name: Some("Republic of Latvia"), capital: Some("Riga"), continent: Europe, aliases: &[] },
+    Country { alpha2: "LY", alpha3: "LBY", numeric: 434, name: "Libya", official_name: Some("Libya"), capital: Some("Tripoli"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MA", alpha3: "MAR", numeric: 504, name: "Morocco", official_name: Some("Kingdom of Morocco"), capital: Some("Rabat"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MC", alpha3: "MCO", numeric: 492, name: "Monaco", official_name: Some("Principality of Monaco"), capital: Some("Monaco"), continent: Europe, aliases: &[] },
+    Country { alpha2: "MD", alpha3: "MDA", numeric: 498, name: "Moldova", official_name: Some("Republic of Moldova"), capital: Some("Chișinău"), continent: Europe, aliases: &["Moldova, Republic of"] },
+    Country { alpha2: "ME", alpha3: "MNE", numeric: 499, name: "Montenegro", official_name: Some("Montenegro"), capital: Some("Podgorica"), continent: Europe, aliases: &[] },
+    Country { alpha2: "MF", alpha3: "MAF", numeric: 663, name: "Saint Martin (French part)", official_name: None, capital: Some("Marigot"), continent: NorthAmerica, aliases: &["Saint Martin"] },
+    Country { alpha2: "MG", alpha3: "MDG", numeric: 450, name: "Madagascar", official_name: Some("Republic of Madagascar"), capital: Some("Antananarivo"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MH", alpha3: "MHL", numeric: 584, name: "Marshall Islands", official_name: Some("Republic of the Marshall Islands"), capital: Some("Majuro"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "MK", alpha3: "MKD", numeric: 807, name: "North Macedonia", official_name: Some("Republic of North Macedonia"), capital: Some("Skopje"), continent: Europe, aliases: &["Macedonia"] },
+    Country { alpha2: "ML", alpha3: "MLI", numeric: 466, name: "Mali", official_name: Some("Republic of Mali"), capital: Some("Bamako"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MM", alpha3: "MMR", numeric: 104, name: "Myanmar", official_name: Some("Republic of Myanmar"), capital: Some("Naypyidaw"), continent: Asia, aliases: &["Burma"] },
+    Country { alpha2: "MN", alpha3: "MNG", numeric: 496, name: "Mongolia", official_name: None, capital: Some("Ulaanbaatar"), continent: Asia, aliases: &[] },
+    Country { alpha2: "MO", alpha3: "MAC", numeric: 446, name: "Macao", official_name: Some("Macao Special Administrative Region of China"), capital: Some("Macao"), continent: Asia, aliases: &[] },
+    Country { alpha2: "MP", alpha3: "MNP", numeric: 580, name: "Northern Mariana Islands", official_name: Some("Commonwealth of the Northern Mariana Islands"), capital: Some("Saipan"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "MQ", alpha3: "MTQ", numeric: 474, name: "Martinique", official_name: None, capital: Some("Fort-de-France"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "MR", alpha3: "MRT", numeric: 478, name: "Mauritania", official_name: Some("Islamic Republic of Mauritania"), capital: Some("Nouakchott"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MS", alpha3: "MSR", numeric: 500, name: "Montserrat", official_name: None, capital: Some("Brades"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "MT", alpha3: "MLT", numeric: 470, name: "Malta", official_name: Some("Republic of Malta"), capital: Some("Valletta"), continent: Europe, aliases: &[] },
+    Country { alpha2: "MU", alpha3: "MUS", numeric: 480, name: "Mauritius", official_name: Some("Republic of Mauritius"), capital: Some("Port Louis"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MV", alpha3: "MDV", numeric: 462, name: "Maldives", official_name: Some("Republic of Maldives"), capital: Some("Malé"), continent: Asia, aliases: &[] },
+    Country { alpha2: "MW", alpha3: "MWI", numeric: 454, name: "Malawi", official_name: Some("Republic of Malawi"), capital: Some("Lilongwe"), continent: Africa, aliases: &[] },
+    Country { alpha2: "MX", alpha3: "MEX", numeric: 484, name: "Mexico", official_name: Some("United Mexican States"), capital: Some("Mexico City"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "MY", alpha3: "MYS", numeric: 458, name: "Malaysia", official_name: None, capital: Some("Kuala Lumpur"), continent: Asia, aliases: &[] },
+    Country { alpha2: "MZ", alpha3: "MOZ", numeric: 508, name: "Mozambique", official_name: Some("Republic of Mozambique"), capital: Some("Maputo"), continent: Africa, aliases: &[] },
+    Country { alpha2: "NA", alpha3: "NAM", numeric: 516, name: "Namibia", official_name: Some("Republic of Namibia"), capital: Some("Windhoek"), continent: Africa, aliases: &[] },
+    Country { alpha2: "NC", alpha3: "NCL", numeric: 540, name: "New Caledonia", official_name: None, capital: Some("Nouméa"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "NE", alpha3: "NER", numeric: 562, name: "Niger", official_name: Some("Republic of the Niger"), capital: Some("Niamey"), continent: Africa, aliases: &[] },
+    Country { alpha2: "NF", alpha3: "NFK", numeric: 574, name: "Norfolk Island", official_name: None, capital: Some("Kingston"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "NG", alpha3: "NGA", numeric: 566, name: "Nigeria", official_name: Some("Federal Republic of Nigeria"), capital: Some("Abuja"), continent: Africa, aliases: &[] },
+    Country { alpha2: "NI", alpha3: "NIC", numeric: 558, name: "Nicaragua", official_name: Some("Republic of Nicaragua"), capital: Some("Managua"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "NL", alpha3: "NLD", numeric: 528, name: "Netherlands", official_name: Some("Kingdom of the Netherlands"), capital: Some("Amsterdam"), continent: Europe, aliases: &["Holland", "The Netherlands"] },
+    Country { alpha2: "NO", alpha3: "NOR", numeric: 578, name: "Norway", official_name: Some("Kingdom of Norway"), capital: Some("Oslo"), continent: Europe, aliases: &[] },
+    Country { alpha2: "NP", alpha3: "NPL", numeric: 524, name: "Nepal", official_name: Some("Federal Democratic Republic of Nepal"), capital: Some("Kathmandu"), continent: Asia, aliases: &[] },
+    Country { alpha2: "NR", alpha3: "NRU", numeric: 520, name: "Nauru", official_name: Some("Republic of Nauru"), capital: Some("Yaren"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "NU", alpha3: "NIU", numeric: 570, name: "Niue", official_name: Some("Niue"), capital: Some("Alofi"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "NZ", alpha3: "NZL", numeric: 554, name: "New Zealand", official_name: None, capital: Some("Wellington"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "OM", alpha3: "OMN", numeric: 512, name: "Oman", official_name: Some("Sultanate of Oman"), capital: Some("Muscat"), continent: Asia, aliases: &[] },
+    Country { alpha2: "PA", alpha3: "PAN", numeric: 591, name: "Panama", official_name: Some("Republic of Panama"), capital: Some("Panama City"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "PE", alpha3: "PER", numeric: 604, name: "Peru", official_name: Some("Republic of Peru"), capital: Some("Lima"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "PF", alpha3: "PYF", numeric: 258, name: "French Polynesia", official_name: None, capital: Some("Papeete"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "PG", alpha3: "PNG", numeric: 598, name: "Papua New Guinea", official_name: Some("Independent State of Papua New Guinea"), capital: Some("Port Moresby"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "PH", alpha3: "PHL", numeric: 608, name: "Philippines", official_name: Some("Republic of the Philippines"), capital: Some("Manila"), continent: Asia, aliases: &[] },
+    Country { alpha2: "PK", alpha3: "PAK", numeric: 586, name: "Pakistan", official_name: Some("Islamic Republic of Pakistan"), capital: Some("Islamabad"), continent: Asia, aliases: &[] },
+    Country { alpha2: "PL", alpha3: "POL", numeric: 616, name: "Poland", official_name: Some("Republic of Poland"), capital: Some("Warsaw"), continent: Europe, aliases: &[] },
+    Country { alpha2: "PM", alpha3: "SPM", numeric: 666, name: "Saint Pierre and Miquelon", official_name: None, capital: Some("Saint-Pierre"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "PN", alpha3: "PCN", numeric: 612, name: "Pitcairn", official_name: None, capital: Some("Adamstown"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "PR", alpha3: "PRI", numeric: 630, name: "Puerto Rico", official_name: None, capital: Some("San Juan"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "PS", alpha3: "PSE", numeric: 275, name: "Palestine, State of", official_name: Some("the State of Palestine"), capital: Some("Ramallah"), continent: Asia, aliases: &["Palestine"] },
+    Country { alpha2: "PT", alpha3: "PRT", numeric: 620, name: "Portugal", official_name: Some("Portuguese Republic"), capital: Some("Lisbon"), continent: Europe, aliases: &[] },
+    Country { alpha2: "PW", alpha3: "PLW", numeric: 585, name: "Palau", official_name: Some("Republic of Palau"), capital: Some("Ngerulmud"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "PY", alpha3: "PRY", numeric: 600, name: "Paraguay", official_name: Some("Republic of Paraguay"), capital: Some("Asunción"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "QA", alpha3: "QAT", numeric: 634, name: "Qatar", official_name: Some("State of Qatar"), capital: Some("Doha"), continent: Asia, aliases: &[] },
+    Country { alpha2: "RE", alpha3: "REU", numeric: 638, name: "Réunion", official_name: None, capital: Some("Saint-Denis"), continent: Africa, aliases: &[] },
+    Country { alpha2: "RO", alpha3: "ROU", numeric: 642, name: "Romania", official_name: None, capital: Some("Bucharest"), continent: Europe, aliases: &[] },
+    Country { alpha2: "RS", alpha3: "SRB", numeric: 688, name: "Serbia", official_name: Some("Republic of Serbia"), capital: Some("Belgrade"), continent: Europe, aliases: &[] },
+    Country { alpha2: "RU", alpha3: "RUS", numeric: 643, name: "Russian Federation", official_name: None, capital: Some("Moscow"), continent: Europe, aliases: &["Russia"] },
+    Country { alpha2: "RW", alpha3: "RWA", numeric: 646, name: "Rwanda", official_name: Some("Rwandese Republic"), capital: Some("Kigali"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SA", alpha3: "SAU", numeric: 682, name: "Saudi Arabia", official_name: Some("Kingdom of Saudi Arabia"), capital: Some("Riyadh"), continent: Asia, aliases: &[] },
+    Country { alpha2: "SB", alpha3: "SLB", numeric: 90, name: "Solomon Islands", official_name: None, capital: Some("Honiara"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "SC", alpha3: "SYC", numeric: 690, name: "Seychelles", official_name: Some("Republic of Seychelles"), capital: Some("Victoria"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SD", alpha3: "SDN", numeric: 729, name: "Sudan", official_name: Some("Republic of the Sudan"), capital: Some("Khartoum"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SE", alpha3: "SWE", numeric: 752, name: "Sweden", official_name: Some("Kingdom of Sweden"), capital: Some("Stockholm"), continent: Europe, aliases: &[] },
+    Country { alpha2: "SG", alpha3: "SGP", numeric: 702, name: "Singapore", official_name: Some("Republic of Singapore"), capital: Some("Singapore"), continent: Asia, aliases: &[] },
+    Country { alpha2: "SH", alpha3: "SHN", numeric: 654, name: "Saint Helena, Ascension and Tristan da Cunha", official_name: None, capital: Some("Jamestown"), continent: Africa, aliases: &["Saint Helena"] },
+    Country { alpha2: "SI", alpha3: "SVN", numeric: 705, name: "Slovenia", official_name: Some("Republic of Slovenia"), capital: Some("Ljubljana"), continent: Europe, aliases: &[] },
+    Country { alpha2: "SJ", alpha3: "SJM", numeric: 744, name: "Svalbard and Jan Mayen", official_name: None, capital: Some("Longyearbyen"), continent: Europe, aliases: &[] },
+    Country { alpha2: "SK", alpha3: "SVK", numeric: 703, name: "Slovakia", official_name: Some("Slovak Republic"), capital: Some("Bratislava"), continent: Europe, aliases: &[] },
+    Country { alpha2: "SL", alpha3: "SLE", numeric: 694, name: "Sierra Leone", official_name: Some("Republic of Sierra Leone"), capital: Some("Freetown"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SM", alpha3: "SMR", numeric: 674, name: "San Marino", official_name: Some("Republic of San Marino"), capital: Some("San Marino"), continent: Europe, aliases: &[] },
+    Country { alpha2: "SN", alpha3: "SEN", numeric: 686, name: "Senegal", official_name: Some("Republic of Senegal"), capital: Some("Dakar"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SO", alpha3: "SOM", numeric: 706, name: "Somalia", official_name: Some("Federal Republic of Somalia"), capital: Some("Mogadishu"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SR", alpha3: "SUR", numeric: 740, name: "Suriname", official_name: Some("Republic of Suriname"), capital: Some("Paramaribo"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "SS", alpha3: "SSD", numeric: 728, name: "South Sudan", official_name: Some("Republic of South Sudan"), capital: Some("Juba"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ST", alpha3: "STP", numeric: 678, name: "Sao Tome and Principe", official_name: Some("Democratic Republic of Sao Tome and Principe"), capital: Some("São Tomé"), continent: Africa, aliases: &[] },
+    Country { alpha2: "SV", alpha3: "SLV", numeric: 222, name: "El Salvador", official_name: Some("Republic of El Salvador"), capital: Some("San Salvador"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "SX", alpha3: "SXM", numeric: 534, name: "Sint Maarten (Dutch part)", official_name: Some("Sint Maarten (Dutch part)"), capital: Some("Philipsburg"), continent: NorthAmerica, aliases: &["Sint Maarten"] },
+    Country { alpha2: "SY", alpha3: "SYR", numeric: 760, name: "Syria", official_name: None, capital: Some("Damascus"), continent: Asia, aliases: &["Syrian Arab Republic"] },
+    Country { alpha2: "SZ", alpha3: "SWZ", numeric: 748, name: "Eswatini", official_name: Some("Kingdom of Eswatini"), capital: Some("Mbabane"), continent: Africa, aliases: &["Swaziland"] },
+    Country { alpha2: "TC", alpha3: "TCA", numeric: 796, name: "Turks and Caicos Islands", official_name: None, capital: Some("Cockburn Town"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "TD", alpha3: "TCD", numeric: 148, name: "Chad", official_name: Some("Republic of Chad"), capital: Some("N'Djamena"), continent: Africa, aliases: &[] },
+    Country { alpha2: "TF", alpha3: "ATF", numeric: 260, name: "French Southern Territories", official_name: None, capital: Some("Port-aux-Français"), continent: Antarctica, aliases: &[] },
+    Country { alpha2: "TG", alpha3: "TGO", numeric: 768, name: "Togo", official_name: Some("Togolese Republic"), capital: Some("Lomé"), continent: Africa, aliases: &[] },
+    Country { alpha2: "TH", alpha3: "THA", numeric: 764, name: "Thailand", official_name: Some("Kingdom of Thailand"), capital: Some("Bangkok"), continent: Asia, aliases: &[] },
+    Country { alpha2: "TJ", alpha3: "TJK", numeric: 762, name: "Tajikistan", official_name: Some("Republic of Tajikistan"), capital: Some("Dushanbe"), continent: Asia, aliases: &[] },
+    Country { alpha2: "TK", alpha3: "TKL", numeric: 772, name: "Tokelau", official_name: None, capital: Some("Nukunonu"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "TL", alpha3: "TLS", numeric: 626, name: "Timor-Leste", official_name: Some("Democratic Republic of Timor-Leste"), capital: Some("Dili"), continent: Asia, aliases: &["East Timor"] },
+    Country { alpha2: "TM", alpha3: "TKM", numeric: 795, name: "Turkmenistan", official_name: None, capital: Some("Ashgabat"), continent: Asia, aliases: &[] },
+    Country { alpha2: "TN", alpha3: "TUN", numeric: 788, name: "Tunisia", official_name: Some("Republic of Tunisia"), capital: Some("Tunis"), continent: Africa, aliases: &[] },
+    Country { alpha2: "TO", alpha3: "TON", numeric: 776, name: "Tonga", official_name: Some("Kingdom of Tonga"), capital: Some("Nukuʻalofa"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "TR", alpha3: "TUR", numeric: 792, name: "Türkiye", official_name: Some("Republic of Türkiye"), capital: Some("Ankara"), continent: Asia, aliases: &["Turkey"] },
+    Country { alpha2: "TT", alpha3: "TTO", numeric: 780, name: "Trinidad and Tobago", official_name: Some("Republic of Trinidad and Tobago"), capital: Some("Port of Spain"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "TV", alpha3: "TUV", numeric: 798, name: "Tuvalu", official_name: None, capital: Some("Funafuti"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "TW", alpha3: "TWN", numeric: 158, name: "Taiwan", official_name: Some("Taiwan, Province of China"), capital: Some("Taipei"), continent: Asia, aliases: &["Taiwan, Province of China"] },
+    Country { alpha2: "TZ", alpha3: "TZA", numeric: 834, name: "Tanzania", official_name: Some("United Republic of Tanzania"), capital: Some("Dodoma"), continent: Africa, aliases: &["Tanzania, United Republic of"] },
+    Country { alpha2: "UA", alpha3: "UKR", numeric: 804, name: "Ukraine", official_name: None, capital: Some("Kyiv"), continent: Europe, aliases: &[] },
+    Country { alpha2: "UG", alpha3: "UGA", numeric: 800, name: "Uganda", official_name: Some("Republic of Uganda"), capital: Some("Kampala"), continent: Africa, aliases: &[] },
+    Country { alpha2: "UM", alpha3: "UMI", numeric: 581, name: "United States Minor Outlying Islands", official_name: None, capital: None, continent: Oceania, aliases: &[] },
+    Country { alpha2: "US", alpha3: "USA", numeric: 840, name: "United States", official_name: Some("United States of America"), capital: Some("Washington, D.C."), continent: NorthAmerica, aliases: &["USA", "United States of America", "America"] },
+    Country { alpha2: "UY", alpha3: "URY", numeric: 858, name: "Uruguay", official_name: Some("Eastern Republic of Uruguay"), capital: Some("Montevideo"), continent: SouthAmerica, aliases: &[] },
+    Country { alpha2: "UZ", alpha3: "UZB", numeric: 860, name: "Uzbekistan", official_name: Some("Republic of Uzbekistan"), capital: Some("Tashkent"), continent: Asia, aliases: &[] },
+    Country { alpha2: "VA", alpha3: "VAT", numeric: 336, name: "Holy See (Vatican City State)", official_name: None, capital: Some("Vatican City"), continent: Europe, aliases: &["Vatican", "Vatican City", "Holy See"] },
+    Country { alpha2: "VC", alpha3: "VCT", numeric: 670, name: "Saint Vincent and the Grenadines", official_name: None, capital: Some("Kingstown"), continent: NorthAmerica, aliases: &[] },
+    Country { alpha2: "VE", alpha3: "VEN", numeric: 862, name: "Venezuela", official_name: Some("Bolivarian Republic of Venezuela"), capital: Some("Caracas"), continent: SouthAmerica, aliases: &["Venezuela, Bolivarian Republic of"] },
+    Country { alpha2: "VG", alpha3: "VGB", numeric: 92, name: "Virgin Islands, British", official_name: Some("British Virgin Islands"), capital: Some("Road Town"), continent: NorthAmerica, aliases: &["British Virgin Islands"] },
+    Country { alpha2: "VI", alpha3: "VIR", numeric: 850, name: "Virgin Islands, U.S.", official_name: Some("Virgin Islands of the United States"), capital: Some("Charlotte Amalie"), continent: NorthAmerica, aliases: &["US Virgin Islands"] },
+    Country { alpha2: "VN", alpha3: "VNM", numeric: 704, name: "Vietnam", official_name: Some("Socialist Republic of Viet Nam"), capital: Some("Hanoi"), continent: Asia, aliases: &["Viet Nam"] },
+    Country { alpha2: "VU", alpha3: "VUT", numeric: 548, name: "Vanuatu", official_name: Some("Republic of Vanuatu"), capital: Some("Port Vila"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "WF", alpha3: "WLF", numeric: 876, name: "Wallis and Futuna", official_name: None, capital: Some("Mata-Utu"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "WS", alpha3: "WSM", numeric: 882, name: "Samoa", official_name: Some("Independent State of Samoa"), capital: Some("Apia"), continent: Oceania, aliases: &[] },
+    Country { alpha2: "YE", alpha3: "YEM", numeric: 887, name: "Yemen", official_name: Some("Republic of Yemen"), capital: Some("Sana'a"), continent: Asia, aliases: &[] },
+    Country { alpha2: "YT", alpha3: "MYT", numeric: 175, name: "Mayotte", official_name: None, capital: Some("Mamoudzou"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ZA", alpha3: "ZAF", numeric: 710, name: "South Africa", official_name: Some("Republic of South Africa"), capital: Some("Pretoria"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ZM", alpha3: "ZMB", numeric: 894, name: "Zambia", official_name: Some("Republic of Zambia"), capital: Some("Lusaka"), continent: Africa, aliases: &[] },
+    Country { alpha2: "ZW", alpha3: "ZWE", numeric: 716, name: "Zimbabwe", official_name: Some("Republic of Zimbabwe"), capital: Some("Harare"), continent: Africa, aliases: &[] },
+];
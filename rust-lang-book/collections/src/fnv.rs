@@ -0,0 +1,38 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+//* HashMap's default hasher (SipHash with a random key) makes the iteration order change from run to run
+//* That protects against hash flooding attacks, but it also makes printed maps useless for snapshot tests
+//* FNV-1a has no random key: the same insertions always give the same order
+//* It is also faster for short keys, but must not be used for keys controlled by an attacker
+//* The order is still arbitrary, use OrderedMap or SortedMap when the order itself matters
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl Default for FnvHasher {
+    fn default() -> FnvHasher {
+        FnvHasher(OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+//* Create them with ::default(), e.g. 'let map: DeterministicHashMap<i32, String> = DeterministicHashMap::default();'
+pub type DeterministicHashMap<K, V> = HashMap<K, V, FnvBuildHasher>;
+pub type DeterministicHashSet<T> = HashSet<T, FnvBuildHasher>;
@@ -0,0 +1,14 @@
+pub mod bench;
+pub mod bidi;
+pub mod bimap;
+pub mod cache;
+pub mod country;
+pub mod fnv;
+pub mod multimap;
+pub mod normalize;
+pub mod ordered_map;
+pub mod persistent;
+pub mod small;
+pub mod sorted_map;
+pub mod text_stats;
+pub mod value;
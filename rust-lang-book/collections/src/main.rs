@@ -176,4 +176,296 @@ fn main() {
     }
 
 
+    //* Maps like text_handle above are usually about real countries, writing them by hand is error-prone
+    //* src/country.rs bundles the ISO 3166 country table, the i32 ids become a typed CountryId
+    {
+        use collections::country::{Continent, Country, CountryId};
+
+        let germany = Country::lookup("germany").unwrap();
+        println!("{} ({}, {}, {})", germany.name(), germany.alpha2(), germany.alpha3(), germany.numeric());
+        println!("capital {:?} in {:?}", germany.capital(), germany.continent());
+
+        //* Codes and names are looked up regardless of case and accents
+        for query in &["DE", "deu", "276", "GERMANY", "Federal Republic of Germany", "usa", "cote d'ivoire"] {
+            println!("{:?} finds {:?}", query, Country::lookup(query).map(Country::name));
+        }
+
+        //* The fuzzy search forgives typos and partial names
+        println!("Untied Kingdom finds {:?}", Country::search("Untied Kingdom").first().map(|c| c.name()));
+        let guineas: Vec<&str> = Country::search("guinea").iter().map(|c| c.name()).collect();
+        println!("guinea finds {:?}", guineas);
+
+        let text_handle: HashMap<CountryId, String> = ["germany", "france", "united kingdom"]
+            .iter()
+            .map(|name| {
+                let id: CountryId = name.parse().unwrap();
+                (id, id.country().capital().unwrap_or_default().to_string())
+            })
+            .collect();
+        let france: CountryId = "FR".parse().unwrap();
+        println!("{} is the capital of {}", text_handle[&france], france.name());
+
+        println!("{} countries in South America", Country::in_continent(Continent::SouthAmerica).count());
+    }
+
+
+    //* Printing text_handle above gives a different order on every run, HashMap's order is arbitrary
+    //* src/ordered_map.rs keeps the insertion order, src/sorted_map.rs sorts by key
+    //* Both offer the same entry API, and src/fnv.rs makes plain HashMaps at least reproducible
+    //* "cargo run --release --bin map_bench" compares them
+    {
+        use collections::fnv::DeterministicHashMap;
+        use collections::ordered_map::OrderedMap;
+        use collections::sorted_map::SortedMap;
+
+        let mut ordered = OrderedMap::new();
+        ordered.insert(3, "united kingdom".to_string());
+        ordered.insert(1, "germany".to_string());
+        ordered.insert(2, "france".to_string());
+        ordered.insert(1, "uganda".to_string()); // keeps its place
+        ordered.entry(2).or_insert("spain".to_string());
+        ordered.entry(4).or_insert("usa".to_string());
+        ordered.entry(3).and_modify(|val| val.push_str(" of great britain"));
+        println!("{:?}", ordered); // in insertion order, key 1 kept its place when "uganda" replaced "germany"
+        println!("second entry: {:?}", ordered.get_index(1));
+
+        //* shift_remove keeps the order, swap_remove moves the last entry into the gap
+        let mut shifted = ordered.clone();
+        shifted.shift_remove(&3);
+        println!("shift_remove: {:?}", shifted.keys().collect::<Vec<_>>());
+        ordered.swap_remove(&3);
+        println!("swap_remove: {:?}", ordered.keys().collect::<Vec<_>>());
+
+        let mut sorted: SortedMap<i32, String> = SortedMap::new();
+        sorted.insert(30, "united kingdom".to_string());
+        sorted.insert(10, "germany".to_string());
+        sorted.entry(20).or_insert("france".to_string());
+        sorted.entry(10).and_modify(|val| val.make_ascii_uppercase());
+        println!("{:?}", sorted); // in key order
+        println!("at or below 25: {:?}, at or above 25: {:?}", sorted.floor(&25), sorted.ceiling(&25));
+        println!("30 is at position {}", sorted.rank(&30));
+
+        //* Word counting works the same on all of them
+        let text = "the quick brown fox jumps over the lazy dog the end";
+        let mut counts: DeterministicHashMap<&str, u32> = DeterministicHashMap::default();
+        let mut first_seen: OrderedMap<&str, u32> = OrderedMap::new();
+        for word in text.split_whitespace() {
+            *counts.entry(word).or_insert(0) += 1;
+            *first_seen.entry(word).or_default() += 1;
+        }
+        println!("\"the\" appears {} times", counts["the"]);
+        println!("{:?}", first_seen);
+    }
+
+    //* A dynamic Value - the enum T from above, but with accessors, arithmetic, ordering and a literal syntax
+    {
+        use collections::value::Value;
+
+        let row = [Value::Int(20), Value::Float(23.0), Value::Text("Hello".to_string())];
+        println!("{:?} {:?}", row[0].as_int(), row[1].as_float());
+        println!("{}", row[2].as_int().unwrap_err()); // expected int, found text
+
+        //* Int + Float coerces to Float, Text + Text concatenates, mixing them is an error
+        println!("{:?}", &row[0] + &row[1]); // Ok(Float(43.0))
+        println!("{:?}", &row[2] + &Value::from(", world"));
+        println!("{}", (&row[0] + &row[2]).unwrap_err()); // cannot apply '+' to int and text
+        //* Overflowing ints and divisions that don't come out even turn into floats
+        println!("{:?} {:?}", &Value::Int(i64::MAX) + &Value::Int(1), &Value::Int(7) / &Value::Int(2));
+
+        //* Every value can be compared with every other one: null < bool < numbers < text < list < map
+        let mut mixed = vec![Value::from("b"), Value::Float(2.5), Value::Null, Value::Int(3), Value::Bool(true), Value::Int(-1)];
+        mixed.sort();
+        println!("{}", Value::List(mixed)); // [null, true, -1, 2.5, 3, "b"]
+        println!("1 == 1.0: {}", Value::Int(1) == Value::Float(1.0));
+
+        //* A config-like literal parses back and forth
+        let config: Value = r#"{name: "server", port: 8080, ratio: 0.75, tags: ["a", 'b'], "debug mode": false}"#.parse().unwrap();
+        println!("port {:?}", config.get("port").and_then(|p| p.as_int().ok()));
+        println!("{}", config); // to_literal() gives text that parses back into the same value
+        println!("{}", "[1, 2,, 3]".parse::<Value>().unwrap_err());
+
+        //* Loosely typed CSV rows: every cell is inferred
+        let csv = "name,age,height\nAlice,30,1.68\nBob,,1.80";
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        let records: Vec<Value> = lines
+            .map(|line| Value::Map(header.iter().map(|h| h.to_string()).zip(line.split(',').map(Value::infer)).collect()))
+            .collect();
+        println!("ages {:?} and {:?}", records[0].get("age"), records[1].get("age")); // the empty cell is null
+        let total_height = records.iter().try_fold(Value::Int(0), |sum, r| &sum + r.get("height").unwrap()).unwrap();
+        println!("{:?} -> total height {}", records[0].get("name"), total_height);
+    }
+
+    //* Word statistics - the entry counting from above on real text, see src/bin/text_stats.rs for the command line tool
+    {
+        use collections::text_stats::{tokenize, Options, TextStats};
+
+        //* Combining marks stay with their letters, Japanese is split where the script changes
+        for text in ["नमस्ते, Dobrý den!", "こんにちは世界", "don't 'quote'"] {
+            println!("{}", tokenize(text).collect::<Vec<_>>().join(" | "));
+        }
+
+        let options = Options { stop_words: Options::english_stop_words(), ..Options::default() };
+        let mut stats = TextStats::new(options);
+        stats.add_text("The quick brown fox jumps over the lazy dog. The quick brown fox sleeps.");
+        println!("\"Quick\" {} times, {} stop words skipped", stats.count("Quick"), stats.skipped_words());
+        println!("top words {:?}, top n-gram {:?}", stats.top_words(2), stats.top_ngrams(1));
+        println!("{} words, {} unique ({:.2})", stats.total_words(), stats.unique_words(), stats.unique_ratio());
+    }
+
+    //* Unicode normalization - == compares bytes, so the same greeting can be "different" depending on how it was typed
+    {
+        use collections::normalize::{canonically_equivalent, case_fold, caseless_eq, nfc, nfd, nfkc};
+
+        let typed = "Dobry\u{301} den"; // 'y' followed by a combining acute accent
+        println!("== {}, after NFC == {}", typed == "Dobrý den", nfc(typed) == "Dobrý den");
+        println!("canonically equivalent: {}", canonically_equivalent(typed, "Dobrý den"));
+        println!("{} bytes in NFD, {} in NFC", nfd(typed).len(), nfc(typed).len());
+
+        //* Hangul syllables decompose into their letters
+        println!("안녕 has {} chars in NFD", nfd("안녕").chars().count()); // 6
+        println!("ﬁ ① in NFKC: {}", nfkc("ﬁ ①"));
+
+        //* Case folding for comparisons, to_lowercase is for display
+        println!("{}", case_fold("ЗДРАВСТВУЙТЕ"));
+        let lowercase = "Straße".to_lowercase() == "STRASSE".to_lowercase();
+        println!("STRASSE and Straße: to_lowercase {}, caseless_eq {}", lowercase, caseless_eq("STRASSE", "Straße"));
+    }
+
+    //* Bidirectional text - "السلام عليكم" and "שָׁלוֹם" are stored in reading order but terminals print left to right
+    {
+        use collections::bidi::{display, paragraph_direction, Paragraph};
+
+        for text in ["السلام عليكم", "Hello", "123"] {
+            println!("{} {:?}", text, paragraph_direction(text)); // digits alone have no direction
+        }
+
+        //* The Hebrew word is reversed as a whole, its points stay on their letters, the number keeps its order
+        let mixed = "Shalom is שלום (2 words?)";
+        let paragraph = Paragraph::new(mixed, None);
+        let runs: Vec<&str> = paragraph.runs().iter().map(|run| run.text).collect();
+        //* The number after the Hebrew word gets a level of its own (2), that keeps its digits left to right
+        println!("{:?}", runs);
+        println!("{}", display(mixed));
+        println!("{}", display("שָׁלוֹם"));
+
+        //* In a right-to-left paragraph the English words move to the left and the brackets are mirrored
+        let arabic = "السلام (Hello) عليكم";
+        for run in Paragraph::new(arabic, None).visual_runs() {
+            println!("{:?} {:?}", run.direction(), run.text);
+        }
+        println!("{}", display(arabic));
+    }
+
+    //* Caches - text_handle.get(&1) is cheap, but when the value is expensive to compute it is worth keeping a bounded number around
+    {
+        use collections::cache::{LfuCache, LruCache, ManualClock, TtlCache};
+        use std::time::Duration;
+
+        let mut capitals = LruCache::new(2);
+        capitals.put(1, "Berlin");
+        capitals.put(2, "Paris");
+        println!("{:?}", capitals.get(&1));
+        //* 2 is now the least recently used entry, so it makes room for 3
+        println!("evicted {:?}", capitals.push(3, "London"));
+        capitals.get_or_insert_with(4, || "Madrid");
+        println!("{:?}: {}", capitals, capitals.stats());
+
+        //* An LfuCache keeps the entries that are asked for most often, even if not recently
+        let mut lookups = LfuCache::new(2);
+        for id in [1, 1, 1, 2, 3, 1] {
+            lookups.get_or_insert_with(id, || id * 100);
+        }
+        println!("1 used {:?} times, 2 is gone: {}", lookups.uses(&1), !lookups.contains_key(&2));
+
+        //* A TtlCache forgets entries after a while, a ManualClock makes that testable without waiting
+        let clock = ManualClock::new();
+        let mut weather = TtlCache::with_clock(10, Duration::from_secs(60), clock.clone());
+        weather.put("Berlin", "sunny");
+        clock.advance(Duration::from_secs(61));
+        println!("Berlin after a minute: {:?}", weather.get(&"Berlin"));
+        println!("{}", weather.stats());
+    }
+
+
+    //* Persistent collections - with a PVec the chapter's "let _first = &v[0]; v.push(4);" works,
+    //* push returns a new version and the old one (and borrows of it) stay valid
+    {
+        use collections::persistent::{PMap, PVec};
+
+        let v: PVec<i32> = vec![1, 2, 3].into_iter().collect();
+        let first = &v[0];
+        let w = v.push(4);
+        println!("first is still {}, v is {:?} and w is {:?}", first, v, w);
+        println!("w with a new first element: {:?}", w.set(0, 10));
+        println!("w without its last element is v again: {}", w.pop() == Some(v.clone()));
+
+        //* Snapshots of a map are cheap, every version shares most of its nodes with the previous one
+        let before = PMap::new().insert(1, "germany".to_string()).insert(2, "france".to_string());
+        let after = before.insert(1, "uganda".to_string()).remove(&2);
+        println!("{:?} -> {:?}", before, after);
+    }
+
+
+    //* BiMap and MultiMap - text_handle only goes from id to country, and every id has a single country
+    {
+        use collections::bimap::{BiMap, Overwritten};
+        use collections::country::{Continent, Country};
+        use collections::multimap::MultiMap;
+
+        //* A BiMap looks up both ways, ids and countries are unique
+        let ids = vec![1, 2, 3];
+        let countries = vec!["germany".to_string(), "france".to_string(), "united kingdom".to_string()];
+        let mut text_handle: BiMap<i32, String> = ids.into_iter().zip(countries).collect();
+        println!("1 is {:?}, france is {:?}", text_handle.get_by_left(&1), text_handle.get_by_right("france"));
+
+        //* Giving france a new id drops its old one, giving id 1 to another country drops germany
+        if let Overwritten::Right(id, country) = text_handle.insert(4, "france".to_string()) {
+            println!("{} lost id {}", country, id);
+        }
+        println!("{:?}", text_handle.insert(1, "spain".to_string())); // Left(1, "germany")
+        println!("{:?}", text_handle);
+
+        //* A MultiMap keeps every value of a key
+        let by_continent: MultiMap<Continent, &str> =
+            Country::all().iter().map(|country| (country.continent(), country.name())).collect();
+        println!("{} continents, {} countries", by_continent.len(), by_continent.total_len());
+        let oceania = &by_continent[&Continent::Oceania];
+        println!("{} countries in Oceania, the first is {}", oceania.len(), oceania[0]);
+
+        let mut visits: MultiMap<&str, &str> = MultiMap::new();
+        visits.insert("germany", "Berlin");
+        visits.insert("germany", "Munich");
+        visits.entry("france").or_insert("Paris");
+        visits.entry("germany").push("Hamburg");
+        println!("visited in germany: {:?}", visits.get_all("germany"));
+        //* Removing the last value removes the key as well
+        visits.remove_value("france", &"Paris");
+        println!("{:?}", visits);
+    }
+
+
+    //* SmallVec and SmallString - vec![1, 2, 3] and String::from("Hello") allocate even though they are tiny
+    {
+        use collections::small::{SmallString, SmallVec};
+
+        //* Up to 4 elements live inline, on the stack here
+        let mut v: SmallVec<[i32; 4]> = SmallVec::from_vec(vec![1, 2, 3]);
+        v.push(4);
+        println!("{:?} on the heap: {}", v, v.spilled());
+        //* The fifth one moves everything to the heap, nothing else changes
+        v.push(5);
+        println!("{:?} on the heap: {}", v, v.spilled());
+        v.sort_by(|a, b| b.cmp(a)); // slice methods through Deref
+        println!("{:?}", v);
+
+        let mut s: SmallString<[u8; 16]> = SmallString::from("Hello");
+        s.push_str(", world");
+        println!("{} ({} bytes, on the heap: {})", s.to_uppercase(), s.len(), s.spilled()); // str methods through Deref
+        s.push_str(" and everyone else");
+        println!("{} ({} bytes, on the heap: {})", s, s.len(), s.spilled());
+    }
+
+
+
 }
@@ -0,0 +1,380 @@
+use std::borrow::Borrow;
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::Index;
+
+//* A map where a key can have several values, e.g. a continent and its countries
+//* A HashMap from the key to a Vec of its values, in the order they were inserted
+//* A key without values isn't kept: removing the last value of a key removes the key
+//*
+//* len() counts keys, total_len() counts values
+//* iter() yields one (key, value) pair per value, iter_all() one (key, values) pair per key
+pub struct MultiMap<K, V> {
+    map: HashMap<K, Vec<V>>,
+    total_len: usize,
+}
+
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    inner: hash_map::OccupiedEntry<'a, K, Vec<V>>,
+    total_len: &'a mut usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    inner: hash_map::VacantEntry<'a, K, Vec<V>>,
+    total_len: &'a mut usize,
+}
+
+impl<K, V> MultiMap<K, V> {
+    pub fn new() -> MultiMap<K, V> {
+        MultiMap { map: HashMap::new(), total_len: 0 }
+    }
+
+    pub fn with_capacity(capacity: usize) -> MultiMap<K, V> {
+        MultiMap { map: HashMap::with_capacity(capacity), total_len: 0 }
+    }
+
+    //* The number of keys
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    //* The number of values of all keys
+    pub fn total_len(&self) -> usize {
+        self.total_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.total_len = 0;
+    }
+
+    //* Every value with its key, the values of a key in insertion order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { outer: self.map.iter(), inner: None, remaining: self.total_len }
+    }
+
+    //* Every key with all of its values
+    pub fn iter_all(&self) -> impl ExactSizeIterator<Item = (&K, &[V])> {
+        self.map.iter().map(|(key, values)| (key, values.as_slice()))
+    }
+
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> {
+        self.map.keys()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.map.values().flatten()
+    }
+}
+
+impl<K: Hash + Eq, V> MultiMap<K, V> {
+    //* Adds 'value' after the values the key has already
+    pub fn insert(&mut self, key: K, value: V) {
+        self.map.entry(key).or_default().push(value);
+        self.total_len += 1;
+    }
+
+    pub fn insert_many<I: IntoIterator<Item = V>>(&mut self, key: K, values: I) {
+        let mut values = values.into_iter().peekable();
+        if values.peek().is_none() {
+            return;
+        }
+        let existing = self.map.entry(key).or_default();
+        let before = existing.len();
+        existing.extend(values);
+        self.total_len += existing.len() - before;
+    }
+
+    //* The first value of the key
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).and_then(|values| values.first())
+    }
+
+    //* All values of the key, empty if there are none
+    pub fn get_all<Q>(&self, key: &Q) -> &[V]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    //* A slice and not the Vec, so that a key can't be left without values
+    pub fn get_all_mut<Q>(&mut self, key: &Q) -> Option<&mut [V]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_mut(key).map(Vec::as_mut_slice)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    pub fn contains<Q>(&self, key: &Q, value: &V) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        V: PartialEq,
+    {
+        self.get_all(key).contains(value)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let total_len = &mut self.total_len;
+        match self.map.entry(key) {
+            hash_map::Entry::Occupied(inner) => Entry::Occupied(OccupiedEntry { inner, total_len }),
+            hash_map::Entry::Vacant(inner) => Entry::Vacant(VacantEntry { inner, total_len }),
+        }
+    }
+
+    //* Removes the key with all of its values
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Vec<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let values = self.map.remove(key)?;
+        self.total_len -= values.len();
+        Some(values)
+    }
+
+    //* Removes the first value of the key that equals 'value'
+    pub fn remove_value<Q>(&mut self, key: &Q, value: &V) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        V: PartialEq,
+    {
+        let values = self.map.get_mut(key)?;
+        let position = values.iter().position(|v| v == value)?;
+        let removed = values.remove(position);
+        if values.is_empty() {
+            self.map.remove(key);
+        }
+        self.total_len -= 1;
+        Some(removed)
+    }
+
+    //* Keeps the values for which 'keep' returns true, keys that are left without values are removed
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, mut keep: F) {
+        let total_len = &mut self.total_len;
+        self.map.retain(|key, values| {
+            let before = values.len();
+            values.retain(|value| keep(key, value));
+            *total_len -= before - values.len();
+            !values.is_empty()
+        });
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    //* The first value, 'default' becomes the only value if the key has none
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => &mut entry.into_mut()[0],
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut [V])>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    //* Adds a value whether the key has values already or not, returns all of them
+    pub fn push(self, value: V) -> &'a mut [V] {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.push(value);
+                entry.into_mut()
+            }
+            Entry::Vacant(entry) => std::slice::from_mut(entry.insert(value)),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.inner.key()
+    }
+
+    pub fn get(&self) -> &[V] {
+        self.inner.get()
+    }
+
+    pub fn get_mut(&mut self) -> &mut [V] {
+        self.inner.get_mut()
+    }
+
+    pub fn into_mut(self) -> &'a mut [V] {
+        self.inner.into_mut()
+    }
+
+    pub fn push(&mut self, value: V) {
+        self.inner.get_mut().push(value);
+        *self.total_len += 1;
+    }
+
+    //* Removes the key with all of its values
+    pub fn remove(self) -> Vec<V> {
+        let values = self.inner.remove();
+        *self.total_len -= values.len();
+        values
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.inner.key()
+    }
+
+    pub fn into_key(self) -> K {
+        self.inner.into_key()
+    }
+
+    //* The value becomes the key's only value
+    pub fn insert(self, value: V) -> &'a mut V {
+        *self.total_len += 1;
+        &mut self.inner.insert(vec![value])[0]
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    outer: hash_map::Iter<'a, K, Vec<V>>,
+    //* the key whose values are being yielded and the rest of them
+    inner: Option<(&'a K, std::slice::Iter<'a, V>)>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.inner {
+                if let Some(value) = values.next() {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+            }
+            let (key, values) = self.outer.next()?;
+            self.inner = Some((key, values.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+//* One (key, values) pair per key
+impl<K, V> IntoIterator for MultiMap<K, V> {
+    type Item = (K, Vec<V>);
+    type IntoIter = hash_map::IntoIter<K, Vec<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a MultiMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+//* Pairs with the same key are grouped, their values keep their order
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for MultiMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = MultiMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V> Extend<(K, V)> for MultiMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, Q> Index<&Q> for MultiMap<K, V>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+{
+    type Output = [V];
+
+    fn index(&self, key: &Q) -> &[V] {
+        self.map.get(key).expect("key not found in MultiMap")
+    }
+}
+
+impl<K, V> Default for MultiMap<K, V> {
+    fn default() -> Self {
+        MultiMap::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for MultiMap<K, V> {
+    fn clone(&self) -> Self {
+        MultiMap { map: self.map.clone(), total_len: self.total_len }
+    }
+}
+
+//* Equal if both have the same keys with the same values in the same order
+impl<K: Hash + Eq, V: PartialEq> PartialEq for MultiMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Hash + Eq, V: Eq> Eq for MultiMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for MultiMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter_all()).finish()
+    }
+}
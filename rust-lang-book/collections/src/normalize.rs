@@ -0,0 +1,221 @@
+use std::fmt;
+
+mod tables;
+
+use tables::{CASE_FOLDING, COMBINING_CLASS, COMPOSITION, DECOMPOSITION};
+
+//* Unicode normalization (UAX #15) and case folding
+//* Comparing Strings compares their bytes, but the same text can be encoded in more than one way:
+//*     "Dobrý" as 'y' + U+0301 COMBINING ACUTE ACCENT  or as the single U+00FD 'ý'
+//* The normalization forms pick one encoding, so equal-looking texts compare equal afterwards
+//*     NFD   decomposes everything:                                "ý" -> "y\u{301}"
+//*     NFC   decomposes and then composes again where possible:   "y\u{301}" -> "ý"
+//*     NFKD, NFKC  also replace compatibility characters:          "ﬁ" -> "fi", "①" -> "1"
+//* Inside a run of combining marks the marks are sorted by their combining class, so the order
+//* the marks were typed in doesn't matter for marks that don't interact (Hebrew points, Vietnamese accents...)
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl Form {
+    pub const ALL: [Form; 4] = [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd];
+
+    pub fn normalize(self, s: &str) -> String {
+        //* ASCII never changes
+        if s.is_ascii() {
+            return s.to_string();
+        }
+        let compatibility = matches!(self, Form::Nfkc | Form::Nfkd);
+        let mut chars = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            decompose(c, compatibility, &mut chars);
+        }
+        reorder(&mut chars);
+        if matches!(self, Form::Nfc | Form::Nfkc) {
+            compose(&mut chars);
+        }
+        chars.into_iter().collect()
+    }
+
+    pub fn is_normalized(self, s: &str) -> bool {
+        s.is_ascii() || self.normalize(s) == s
+    }
+}
+
+impl fmt::Display for Form {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Form::Nfc => "NFC",
+            Form::Nfd => "NFD",
+            Form::Nfkc => "NFKC",
+            Form::Nfkd => "NFKD",
+        })
+    }
+}
+
+pub fn nfc(s: &str) -> String {
+    Form::Nfc.normalize(s)
+}
+
+pub fn nfd(s: &str) -> String {
+    Form::Nfd.normalize(s)
+}
+
+pub fn nfkc(s: &str) -> String {
+    Form::Nfkc.normalize(s)
+}
+
+pub fn nfkd(s: &str) -> String {
+    Form::Nfkd.normalize(s)
+}
+
+//* Full case folding: like to_lowercase, but meant for comparing instead of displaying
+//*     "Straße" -> "strasse", "ΣΊΣΥΦΟΣ" and "σίσυφος" -> "σίσυφοσ"
+//* It doesn't depend on the language, the Turkish dotted and dotless i are not special cased
+pub fn case_fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii() {
+            folded.push(c.to_ascii_lowercase());
+            continue;
+        }
+        match CASE_FOLDING.binary_search_by_key(&c, |&(from, _)| from) {
+            Ok(i) => folded.extend(CASE_FOLDING[i].1),
+            Err(_) => folded.push(c),
+        }
+    }
+    folded
+}
+
+//* Same text, possibly encoded differently: compares the NFD forms
+pub fn canonically_equivalent(a: &str, b: &str) -> bool {
+    a == b || nfd(a) == nfd(b)
+}
+
+//* Canonical caseless match (Unicode D145): equal after folding the case and ignoring the encoding
+//* NFD has to run before folding too, because some characters only fold correctly when decomposed (e.g. U+0345)
+pub fn caseless_eq(a: &str, b: &str) -> bool {
+    let key = |s: &str| nfd(&case_fold(&nfd(s)));
+    a == b || key(a) == key(b)
+}
+
+//* Compatibility caseless match (Unicode D146): also ignores compatibility differences, "Ｒｕｓｔ ①" matches "rust 1"
+pub fn compatibility_caseless_eq(a: &str, b: &str) -> bool {
+    let key = |s: &str| nfkd(&case_fold(&nfkd(&case_fold(&nfd(s)))));
+    a == b || key(a) == key(b)
+}
+
+pub fn combining_class(c: char) -> u8 {
+    if (c as u32) < 0x300 {
+        return 0;
+    }
+    let i = COMBINING_CLASS.partition_point(|&(_, last, _)| last < c);
+    match COMBINING_CLASS.get(i) {
+        Some(&(first, _, class)) if first <= c => class,
+        _ => 0,
+    }
+}
+
+//* Hangul syllables are built from their jamo arithmetically (Unicode chapter 3.12)
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn decompose(c: char, compatibility: bool, out: &mut Vec<char>) {
+    if (c as u32) < 0xA0 {
+        out.push(c);
+        return;
+    }
+
+    let s_index = (c as u32).wrapping_sub(S_BASE);
+    if s_index < S_COUNT {
+        let jamo = |code: u32| char::from_u32(code).unwrap();
+        out.push(jamo(L_BASE + s_index / N_COUNT));
+        out.push(jamo(V_BASE + (s_index % N_COUNT) / T_COUNT));
+        if !s_index.is_multiple_of(T_COUNT) {
+            out.push(jamo(T_BASE + s_index % T_COUNT));
+        }
+        return;
+    }
+
+    match DECOMPOSITION.binary_search_by_key(&c, |&(from, _, _)| from) {
+        Ok(i) if compatibility || !DECOMPOSITION[i].1 => {
+            for &part in DECOMPOSITION[i].2 {
+                decompose(part, compatibility, out);
+            }
+        }
+        _ => out.push(c),
+    }
+}
+
+//* Canonical ordering: a stable sort of every run of non-starters by combining class
+fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len() && combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        if end - start > 1 {
+            chars[start..end].sort_by_key(|&c| combining_class(c));
+        }
+        start = end;
+    }
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let l_index = (first as u32).wrapping_sub(L_BASE);
+    let v_index = (second as u32).wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    let s_index = (first as u32).wrapping_sub(S_BASE);
+    let t_index = (second as u32).wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index.is_multiple_of(T_COUNT) && t_index > 0 && t_index < T_COUNT {
+        return char::from_u32(first as u32 + t_index);
+    }
+    COMPOSITION.binary_search_by(|&(a, b, _)| (a, b).cmp(&(first, second))).ok().map(|i| COMPOSITION[i].2)
+}
+
+//* Canonical composition: every character is combined with the last starter before it,
+//* unless a character in between has the same or a higher combining class (it is "blocked")
+fn compose(chars: &mut Vec<char>) {
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0;
+    let mut write = 0;
+    for read in 0..chars.len() {
+        let c = chars[read];
+        let class = combining_class(c);
+        if let Some(s) = starter {
+            let blocked = write > s + 1 && (last_class == 0 || last_class >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(chars[s], c) {
+                    chars[s] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(write);
+        }
+        last_class = class;
+        chars[write] = c;
+        write += 1;
+    }
+    chars.truncate(write);
+}
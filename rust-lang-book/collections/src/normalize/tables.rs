@@ -0,0 +1,8735 @@
+//* Unicode 15.1 character data for the normalization forms and case folding, sorted by code point
+//* Generated from the Unicode Character Database (UnicodeData.txt, CompositionExclusions.txt and CaseFolding.txt),
+//* Hangul syllables are left out because they are decomposed and composed algorithmically
+
+//* (first, last, canonical combining class), code points that are missing have class 0
+pub(super) static COMBINING_CLASS: [(char, char, u8); 388] = [
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31A}', '\u{31A}', 232),
+    ('\u{31B}', '\u{31B}', 216),
+    ('\u{31C}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33C}', 220),
+    ('\u{33D}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34A}', '\u{34C}', 230),
+    ('\u{34D}', '\u{34E}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35A}', 220),
+    ('\u{35B}', '\u{35B}', 230),
+    ('\u{35C}', '\u{35C}', 233),
+    ('\u{35D}', '\u{35E}', 234),
+    ('\u{35F}', '\u{35F}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36F}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59A}', '\u{59A}', 222),
+    ('\u{59B}', '\u{59B}', 220),
+    ('\u{59C}', '\u{5A1}', 230),
+    ('\u{5A2}', '\u{5A7}', 220),
+    ('\u{5A8}', '\u{5A9}', 230),
+    ('\u{5AA}', '\u{5AA}', 220),
+    ('\u{5AB}', '\u{5AC}', 230),
+    ('\u{5AD}', '\u{5AD}', 222),
+    ('\u{5AE}', '\u{5AE}', 228),
+    ('\u{5AF}', '\u{5AF}', 230),
+    ('\u{5B0}', '\u{5B0}', 10),
+    ('\u{5B1}', '\u{5B1}', 11),
+    ('\u{5B2}', '\u{5B2}', 12),
+    ('\u{5B3}', '\u{5B3}', 13),
+    ('\u{5B4}', '\u{5B4}', 14),
+    ('\u{5B5}', '\u{5B5}', 15),
+    ('\u{5B6}', '\u{5B6}', 16),
+    ('\u{5B7}', '\u{5B7}', 17),
+    ('\u{5B8}', '\u{5B8}', 18),
+    ('\u{5B9}', '\u{5BA}', 19),
+    ('\u{5BB}', '\u{5BB}', 20),
+    ('\u{5BC}', '\u{5BC}', 21),
+    ('\u{5BD}', '\u{5BD}', 22),
+    ('\u{5BF}', '\u{5BF}', 23),
+    ('\u{5C1}', '\u{5C1}', 24),
+    ('\u{5C2}', '\u{5C2}', 25),
+    ('\u{5C4}', '\u{5C4}', 230),
+    ('\u{5C5}', '\u{5C5}', 220),
+    ('\u{5C7}', '\u{5C7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61A}', '\u{61A}', 32),
+    ('\u{64B}', '\u{64B}', 27),
+    ('\u{64C}', '\u{64C}', 28),
+    ('\u{64D}', '\u{64D}', 29),
+    ('\u{64E}', '\u{64E}', 30),
+    ('\u{64F}', '\u{64F}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65B}', 230),
+    ('\u{65C}', '\u{65C}', 220),
+    ('\u{65D}', '\u{65E}', 230),
+    ('\u{65F}', '\u{65F}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6D6}', '\u{6DC}', 230),
+    ('\u{6DF}', '\u{6E2}', 230),
+    ('\u{6E3}', '\u{6E3}', 220),
+    ('\u{6E4}', '\u{6E4}', 230),
+    ('\u{6E7}', '\u{6E8}', 230),
+    ('\u{6EA}', '\u{6EA}', 220),
+    ('\u{6EB}', '\u{6EC}', 230),
+    ('\u{6ED}', '\u{6ED}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73A}', '\u{73A}', 230),
+    ('\u{73B}', '\u{73C}', 220),
+    ('\u{73D}', '\u{73D}', 230),
+    ('\u{73E}', '\u{73E}', 220),
+    ('\u{73F}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74A}', 230),
+    ('\u{7EB}', '\u{7F1}', 230),
+    ('\u{7F2}', '\u{7F2}', 220),
+    ('\u{7F3}', '\u{7F3}', 230),
+    ('\u{7FD}', '\u{7FD}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81B}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82D}', 230),
+    ('\u{859}', '\u{85B}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89B}', 220),
+    ('\u{89C}', '\u{89F}', 230),
+    ('\u{8CA}', '\u{8CE}', 230),
+    ('\u{8CF}', '\u{8D3}', 220),
+    ('\u{8D4}', '\u{8E1}', 230),
+    ('\u{8E3}', '\u{8E3}', 220),
+    ('\u{8E4}', '\u{8E5}', 230),
+    ('\u{8E6}', '\u{8E6}', 220),
+    ('\u{8E7}', '\u{8E8}', 230),
+    ('\u{8E9}', '\u{8E9}', 220),
+    ('\u{8EA}', '\u{8EC}', 230),
+    ('\u{8ED}', '\u{8EF}', 220),
+    ('\u{8F0}', '\u{8F0}', 27),
+    ('\u{8F1}', '\u{8F1}', 28),
+    ('\u{8F2}', '\u{8F2}', 29),
+    ('\u{8F3}', '\u{8F5}', 230),
+    ('\u{8F6}', '\u{8F6}', 220),
+    ('\u{8F7}', '\u{8F8}', 230),
+    ('\u{8F9}', '\u{8FA}', 220),
+    ('\u{8FB}', '\u{8FF}', 230),
+    ('\u{93C}', '\u{93C}', 7),
+    ('\u{94D}', '\u{94D}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9BC}', '\u{9BC}', 7),
+    ('\u{9CD}', '\u{9CD}', 9),
+    ('\u{9FE}', '\u{9FE}', 230),
+    ('\u{A3C}', '\u{A3C}', 7),
+    ('\u{A4D}', '\u{A4D}', 9),
+    ('\u{ABC}', '\u{ABC}', 7),
+    ('\u{ACD}', '\u{ACD}', 9),
+    ('\u{B3C}', '\u{B3C}', 7),
+    ('\u{B4D}', '\u{B4D}', 9),
+    ('\u{BCD}', '\u{BCD}', 9),
+    ('\u{C3C}', '\u{C3C}', 7),
+    ('\u{C4D}', '\u{C4D}', 9),
+    ('\u{C55}', '\u{C55}', 84),
+    ('\u{C56}', '\u{C56}', 91),
+    ('\u{CBC}', '\u{CBC}', 7),
+    ('\u{CCD}', '\u{CCD}', 9),
+    ('\u{D3B}', '\u{D3C}', 9),
+    ('\u{D4D}', '\u{D4D}', 9),
+    ('\u{DCA}', '\u{DCA}', 9),
+    ('\u{E38}', '\u{E39}', 103),
+    ('\u{E3A}', '\u{E3A}', 9),
+    ('\u{E48}', '\u{E4B}', 107),
+    ('\u{EB8}', '\u{EB9}', 118),
+    ('\u{EBA}', '\u{EBA}', 9),
+    ('\u{EC8}', '\u{ECB}', 122),
+    ('\u{F18}', '\u{F19}', 220),
+    ('\u{F35}', '\u{F35}', 220),
+    ('\u{F37}', '\u{F37}', 220),
+    ('\u{F39}', '\u{F39}', 216),
+    ('\u{F71}', '\u{F71}', 129),
+    ('\u{F72}', '\u{F72}', 130),
+    ('\u{F74}', '\u{F74}', 132),
+    ('\u{F7A}', '\u{F7D}', 130),
+    ('\u{F80}', '\u{F80}', 130),
+    ('\u{F82}', '\u{F83}', 230),
+    ('\u{F84}', '\u{F84}', 9),
+    ('\u{F86}', '\u{F87}', 230),
+    ('\u{FC6}', '\u{FC6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103A}', 9),
+    ('\u{108D}', '\u{108D}', 220),
+    ('\u{135D}', '\u{135F}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17D2}', '\u{17D2}', 9),
+    ('\u{17DD}', '\u{17DD}', 230),
+    ('\u{18A9}', '\u{18A9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193A}', '\u{193A}', 230),
+    ('\u{193B}', '\u{193B}', 220),
+    ('\u{1A17}', '\u{1A17}', 230),
+    ('\u{1A18}', '\u{1A18}', 220),
+    ('\u{1A60}', '\u{1A60}', 9),
+    ('\u{1A75}', '\u{1A7C}', 230),
+    ('\u{1A7F}', '\u{1A7F}', 220),
+    ('\u{1AB0}', '\u{1AB4}', 230),
+    ('\u{1AB5}', '\u{1ABA}', 220),
+    ('\u{1ABB}', '\u{1ABC}', 230),
+    ('\u{1ABD}', '\u{1ABD}', 220),
+    ('\u{1ABF}', '\u{1AC0}', 220),
+    ('\u{1AC1}', '\u{1AC2}', 230),
+    ('\u{1AC3}', '\u{1AC4}', 220),
+    ('\u{1AC5}', '\u{1AC9}', 230),
+    ('\u{1ACA}', '\u{1ACA}', 220),
+    ('\u{1ACB}', '\u{1ACE}', 230),
+    ('\u{1B34}', '\u{1B34}', 7),
+    ('\u{1B44}', '\u{1B44}', 9),
+    ('\u{1B6B}', '\u{1B6B}', 230),
+    ('\u{1B6C}', '\u{1B6C}', 220),
+    ('\u{1B6D}', '\u{1B73}', 230),
+    ('\u{1BAA}', '\u{1BAB}', 9),
+    ('\u{1BE6}', '\u{1BE6}', 7),
+    ('\u{1BF2}', '\u{1BF3}', 9),
+    ('\u{1C37}', '\u{1C37}', 7),
+    ('\u{1CD0}', '\u{1CD2}', 230),
+    ('\u{1CD4}', '\u{1CD4}', 1),
+    ('\u{1CD5}', '\u{1CD9}', 220),
+    ('\u{1CDA}', '\u{1CDB}', 230),
+    ('\u{1CDC}', '\u{1CDF}', 220),
+    ('\u{1CE0}', '\u{1CE0}', 230),
+    ('\u{1CE2}', '\u{1CE8}', 1),
+    ('\u{1CED}', '\u{1CED}', 220),
+    ('\u{1CF4}', '\u{1CF4}', 230),
+    ('\u{1CF8}', '\u{1CF9}', 230),
+    ('\u{1DC0}', '\u{1DC1}', 230),
+    ('\u{1DC2}', '\u{1DC2}', 220),
+    ('\u{1DC3}', '\u{1DC9}', 230),
+    ('\u{1DCA}', '\u{1DCA}', 220),
+    ('\u{1DCB}', '\u{1DCC}', 230),
+    ('\u{1DCD}', '\u{1DCD}', 234),
+    ('\u{1DCE}', '\u{1DCE}', 214),
+    ('\u{1DCF}', '\u{1DCF}', 220),
+    ('\u{1DD0}', '\u{1DD0}', 202),
+    ('\u{1DD1}', '\u{1DF5}', 230),
+    ('\u{1DF6}', '\u{1DF6}', 232),
+    ('\u{1DF7}', '\u{1DF8}', 228),
+    ('\u{1DF9}', '\u{1DF9}', 220),
+    ('\u{1DFA}', '\u{1DFA}', 218),
+    ('\u{1DFB}', '\u{1DFB}', 230),
+    ('\u{1DFC}', '\u{1DFC}', 233),
+    ('\u{1DFD}', '\u{1DFD}', 220),
+    ('\u{1DFE}', '\u{1DFE}', 230),
+    ('\u{1DFF}', '\u{1DFF}', 220),
+    ('\u{20D0}', '\u{20D1}', 230),
+    ('\u{20D2}', '\u{20D3}', 1),
+    ('\u{20D4}', '\u{20D7}', 230),
+    ('\u{20D8}', '\u{20DA}', 1),
+    ('\u{20DB}', '\u{20DC}', 230),
+    ('\u{20E1}', '\u{20E1}', 230),
+    ('\u{20E5}', '\u{20E6}', 1),
+    ('\u{20E7}', '\u{20E7}', 230),
+    ('\u{20E8}', '\u{20E8}', 220),
+    ('\u{20E9}', '\u{20E9}', 230),
+    ('\u{20EA}', '\u{20EB}', 1),
+    ('\u{20EC}', '\u{20EF}', 220),
+    ('\u{20F0}', '\u{20F0}', 230),
+    ('\u{2CEF}', '\u{2CF1}', 230),
+    ('\u{2D7F}', '\u{2D7F}', 9),
+    ('\u{2DE0}', '\u{2DFF}', 230),
+    ('\u{302A}', '\u{302A}', 218),
+    ('\u{302B}', '\u{302B}', 228),
+    ('\u{302C}', '\u{302C}', 232),
+    ('\u{302D}', '\u{302D}', 222),
+    ('\u{302E}', '\u{302F}', 224),
+    ('\u{3099}', '\u{309A}', 8),
+    ('\u{A66F}', '\u{A66F}', 230),
+    ('\u{A674}', '\u{A67D}', 230),
+    ('\u{A69E}', '\u{A69F}', 230),
+    ('\u{A6F0}', '\u{A6F1}', 230),
+    ('\u{A806}', '\u{A806}', 9),
+    ('\u{A82C}', '\u{A82C}', 9),
+    ('\u{A8C4}', '\u{A8C4}', 9),
+    ('\u{A8E0}', '\u{A8F1}', 230),
+    ('\u{A92B}', '\u{A92D}', 220),
+    ('\u{A953}', '\u{A953}', 9),
+    ('\u{A9B3}', '\u{A9B3}', 7),
+    ('\u{A9C0}', '\u{A9C0}', 9),
+    ('\u{AAB0}', '\u{AAB0}', 230),
+    ('\u{AAB2}', '\u{AAB3}', 230),
+    ('\u{AAB4}', '\u{AAB4}', 220),
+    ('\u{AAB7}', '\u{AAB8}', 230),
+    ('\u{AABE}', '\u{AABF}', 230),
+    ('\u{AAC1}', '\u{AAC1}', 230),
+    ('\u{AAF6}', '\u{AAF6}', 9),
+    ('\u{ABED}', '\u{ABED}', 9),
+    ('\u{FB1E}', '\u{FB1E}', 26),
+    ('\u{FE20}', '\u{FE26}', 230),
+    ('\u{FE27}', '\u{FE2D}', 220),
+    ('\u{FE2E}', '\u{FE2F}', 230),
+    ('\u{101FD}', '\u{101FD}', 220),
+    ('\u{102E0}', '\u{102E0}', 220),
+    ('\u{10376}', '\u{1037A}', 230),
+    ('\u{10A0D}', '\u{10A0D}', 220),
+    ('\u{10A0F}', '\u{10A0F}', 230),
+    ('\u{10A38}', '\u{10A38}', 230),
+    ('\u{10A39}', '\u{10A39}', 1),
+    ('\u{10A3A}', '\u{10A3A}', 220),
+    ('\u{10A3F}', '\u{10A3F}', 9),
+    ('\u{10AE5}', '\u{10AE5}', 230),
+    ('\u{10AE6}', '\u{10AE6}', 220),
+    ('\u{10D24}', '\u{10D27}', 230),
+    ('\u{10EAB}', '\u{10EAC}', 230),
+    ('\u{10EFD}', '\u{10EFF}', 220),
+    ('\u{10F46}', '\u{10F47}', 220),
+    ('\u{10F48}', '\u{10F4A}', 230),
+    ('\u{10F4B}', '\u{10F4B}', 220),
+    ('\u{10F4C}', '\u{10F4C}', 230),
+    ('\u{10F4D}', '\u{10F50}', 220),
+    ('\u{10F82}', '\u{10F82}', 230),
+    ('\u{10F83}', '\u{10F83}', 220),
+    ('\u{10F84}', '\u{10F84}', 230),
+    ('\u{10F85}', '\u{10F85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107F}', '\u{1107F}', 9),
+    ('\u{110B9}', '\u{110B9}', 9),
+    ('\u{110BA}', '\u{110BA}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111C0}', '\u{111C0}', 9),
+    ('\u{111CA}', '\u{111CA}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112E9}', '\u{112E9}', 7),
+    ('\u{112EA}', '\u{112EA}', 9),
+    ('\u{1133B}', '\u{1133C}', 7),
+    ('\u{1134D}', '\u{1134D}', 9),
+    ('\u{11366}', '\u{1136C}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145E}', '\u{1145E}', 230),
+    ('\u{114C2}', '\u{114C2}', 9),
+    ('\u{114C3}', '\u{114C3}', 7),
+    ('\u{115BF}', '\u{115BF}', 9),
+    ('\u{115C0}', '\u{115C0}', 7),
+    ('\u{1163F}', '\u{1163F}', 9),
+    ('\u{116B6}', '\u{116B6}', 9),
+    ('\u{116B7}', '\u{116B7}', 7),
+    ('\u{1172B}', '\u{1172B}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183A}', '\u{1183A}', 7),
+    ('\u{1193D}', '\u{1193E}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119E0}', '\u{119E0}', 9),
+    ('\u{11A34}', '\u{11A34}', 9),
+    ('\u{11A47}', '\u{11A47}', 9),
+    ('\u{11A99}', '\u{11A99}', 9),
+    ('\u{11C3F}', '\u{11C3F}', 9),
+    ('\u{11D42}', '\u{11D42}', 7),
+    ('\u{11D44}', '\u{11D45}', 9),
+    ('\u{11D97}', '\u{11D97}', 9),
+    ('\u{11F41}', '\u{11F42}', 9),
+    ('\u{16AF0}', '\u{16AF4}', 1),
+    ('\u{16B30}', '\u{16B36}', 230),
+    ('\u{16FF0}', '\u{16FF1}', 6),
+    ('\u{1BC9E}', '\u{1BC9E}', 1),
+    ('\u{1D165}', '\u{1D166}', 216),
+    ('\u{1D167}', '\u{1D169}', 1),
+    ('\u{1D16D}', '\u{1D16D}', 226),
+    ('\u{1D16E}', '\u{1D172}', 216),
+    ('\u{1D17B}', '\u{1D182}', 220),
+    ('\u{1D185}', '\u{1D189}', 230),
+    ('\u{1D18A}', '\u{1D18B}', 220),
+    ('\u{1D1AA}', '\u{1D1AD}', 230),
+    ('\u{1D242}', '\u{1D244}', 230),
+    ('\u{1E000}', '\u{1E006}', 230),
+    ('\u{1E008}', '\u{1E018}', 230),
+    ('\u{1E01B}', '\u{1E021}', 230),
+    ('\u{1E023}', '\u{1E024}', 230),
+    ('\u{1E026}', '\u{1E02A}', 230),
+    ('\u{1E08F}', '\u{1E08F}', 230),
+    ('\u{1E130}', '\u{1E136}', 230),
+    ('\u{1E2AE}', '\u{1E2AE}', 230),
+    ('\u{1E2EC}', '\u{1E2EF}', 230),
+    ('\u{1E4EC}', '\u{1E4ED}', 232),
+    ('\u{1E4EE}', '\u{1E4EE}', 220),
+    ('\u{1E4EF}', '\u{1E4EF}', 230),
+    ('\u{1E8D0}', '\u{1E8D6}', 220),
+    ('\u{1E944}', '\u{1E949}', 230),
+    ('\u{1E94A}', '\u{1E94A}', 7),
+];
+
+//* (character, compatibility only, mapping), the mappings are applied recursively
+pub(super) static DECOMPOSITION: [(char, bool, &[char]); 5857] = [
+    ('\u{A0}', true, &['\u{20}']),
+    ('\u{A8}', true, &['\u{20}', '\u{308}']),
+    ('\u{AA}', true, &['a']),
+    ('\u{AF}', true, &['\u{20}', '\u{304}']),
+    ('\u{B2}', true, &['2']),
+    ('\u{B3}', true, &['3']),
+    ('\u{B4}', true, &['\u{20}', '\u{301}']),
+    ('\u{B5}', true, &['\u{3BC}']),
+    ('\u{B8}', true, &['\u{20}', '\u{327}']),
+    ('\u{B9}', true, &['1']),
+    ('\u{BA}', true, &['o']),
+    ('\u{BC}', true, &['1', '\u{2044}', '4']),
+    ('\u{BD}', true, &['1', '\u{2044}', '2']),
+    ('\u{BE}', true, &['3', '\u{2044}', '4']),
+    ('\u{C0}', false, &['A', '\u{300}']),
+    ('\u{C1}', false, &['A', '\u{301}']),
+    ('\u{C2}', false, &['A', '\u{302}']),
+    ('\u{C3}', false, &['A', '\u{303}']),
+    ('\u{C4}', false, &['A', '\u{308}']),
+    ('\u{C5}', false, &['A', '\u{30A}']),
+    ('\u{C7}', false, &['C', '\u{327}']),
+    ('\u{C8}', false, &['E', '\u{300}']),
+    ('\u{C9}', false, &['E', '\u{301}']),
+    ('\u{CA}', false, &['E', '\u{302}']),
+    ('\u{CB}', false, &['E', '\u{308}']),
+    ('\u{CC}', false, &['I', '\u{300}']),
+    ('\u{CD}', false, &['I', '\u{301}']),
+    ('\u{CE}', false, &['I', '\u{302}']),
+    ('\u{CF}', false, &['I', '\u{308}']),
+    ('\u{D1}', false, &['N', '\u{303}']),
+    ('\u{D2}', false, &['O', '\u{300}']),
+    ('\u{D3}', false, &['O', '\u{301}']),
+    ('\u{D4}', false, &['O', '\u{302}']),
+    ('\u{D5}', false, &['O', '\u{303}']),
+    ('\u{D6}', false, &['O', '\u{308}']),
+    ('\u{D9}', false, &['U', '\u{300}']),
+    ('\u{DA}', false, &['U', '\u{301}']),
+    ('\u{DB}', false, &['U', '\u{302}']),
+    ('\u{DC}', false, &['U', '\u{308}']),
+    ('\u{DD}', false, &['Y', '\u{301}']),
+    ('\u{E0}', false, &['a', '\u{300}']),
+    ('\u{E1}', false, &['a', '\u{301}']),
+    ('\u{E2}', false, &['a', '\u{302}']),
+    ('\u{E3}', false, &['a', '\u{303}']),
+    ('\u{E4}', false, &['a', '\u{308}']),
+    ('\u{E5}', false, &['a', '\u{30A}']),
+    ('\u{E7}', false, &['c', '\u{327}']),
+    ('\u{E8}', false, &['e', '\u{300}']),
+    ('\u{E9}', false, &['e', '\u{301}']),
+    ('\u{EA}', false, &['e', '\u{302}']),
+    ('\u{EB}', false, &['e', '\u{308}']),
+    ('\u{EC}', false, &['i', '\u{300}']),
+    ('\u{ED}', false, &['i', '\u{301}']),
+    ('\u{EE}', false, &['i', '\u{302}']),
+    ('\u{EF}', false, &['i', '\u{308}']),
+    ('\u{F1}', false, &['n', '\u{303}']),
+    ('\u{F2}', false, &['o', '\u{300}']),
+    ('\u{F3}', false, &['o', '\u{301}']),
+    ('\u{F4}', false, &['o', '\u{302}']),
+    ('\u{F5}', false, &['o', '\u{303}']),
+    ('\u{F6}', false, &['o', '\u{308}']),
+    ('\u{F9}', false, &['u', '\u{300}']),
+    ('\u{FA}', false, &['u', '\u{301}']),
+    ('\u{FB}', false, &['u', '\u{302}']),
+    ('\u{FC}', false, &['u', '\u{308}']),
+    ('\u{FD}', false, &['y', '\u{301}']),
+    ('\u{FF}', false, &['y', '\u{308}']),
+    ('\u{100}', false, &['A', '\u{304}']),
+    ('\u{101}', false, &['a', '\u{304}']),
+    ('\u{102}', false, &['A', '\u{306}']),
+    ('\u{103}', false, &['a', '\u{306}']),
+    ('\u{104}', false, &['A', '\u{328}']),
+    ('\u{105}', false, &['a', '\u{328}']),
+    ('\u{106}', false, &['C', '\u{301}']),
+    ('\u{107}', false, &['c', '\u{301}']),
+    ('\u{108}', false, &['C', '\u{302}']),
+    ('\u{109}', false, &['c', '\u{302}']),
+    ('\u{10A}', false, &['C', '\u{307}']),
+    ('\u{10B}', false, &['c', '\u{307}']),
+    ('\u{10C}', false, &['C', '\u{30C}']),
+    ('\u{10D}', false, &['c', '\u{30C}']),
+    ('\u{10E}', false, &['D', '\u{30C}']),
+    ('\u{10F}', false, &['d', '\u{30C}']),
+    ('\u{112}', false, &['E', '\u{304}']),
+    ('\u{113}', false, &['e', '\u{304}']),
+    ('\u{114}', false, &['E', '\u{306}']),
+    ('\u{115}', false, &['e', '\u{306}']),
+    ('\u{116}', false, &['E', '\u{307}']),
+    ('\u{117}', false, &['e', '\u{307}']),
+    ('\u{118}', false, &['E', '\u{328}']),
+    ('\u{119}', false, &['e', '\u{328}']),
+    ('\u{11A}', false, &['E', '\u{30C}']),
+    ('\u{11B}', false, &['e', '\u{30C}']),
+    ('\u{11C}', false, &['G', '\u{302}']),
+    ('\u{11D}', false, &['g', '\u{302}']),
+    ('\u{11E}', false, &['G', '\u{306}']),
+    ('\u{11F}', false, &['g', '\u{306}']),
+    ('\u{120}', false, &['G', '\u{307}']),
+    ('\u{121}', false, &['g', '\u{307}']),
+    ('\u{122}', false, &['G', '\u{327}']),
+    ('\u{123}', false, &['g', '\u{327}']),
+    ('\u{124}', false, &['H', '\u{302}']),
+    ('\u{125}', false, &['h', '\u{302}']),
+    ('\u{128}', false, &['I', '\u{303}']),
+    ('\u{129}', false, &['i', '\u{303}']),
+    ('\u{12A}', false, &['I', '\u{304}']),
+    ('\u{12B}', false, &['i', '\u{304}']),
+    ('\u{12C}', false, &['I', '\u{306}']),
+    ('\u{12D}', false, &['i', '\u{306}']),
+    ('\u{12E}', false, &['I', '\u{328}']),
+    ('\u{12F}', false, &['i', '\u{328}']),
+    ('\u{130}', false, &['I', '\u{307}']),
+    ('\u{132}', true, &['I', 'J']),
+    ('\u{133}', true, &['i', 'j']),
+    ('\u{134}', false, &['J', '\u{302}']),
+    ('\u{135}', false, &['j', '\u{302}']),
+    ('\u{136}', false, &['K', '\u{327}']),
+    ('\u{137}', false, &['k', '\u{327}']),
+    ('\u{139}', false, &['L', '\u{301}']),
+    ('\u{13A}', false, &['l', '\u{301}']),
+    ('\u{13B}', false, &['L', '\u{327}']),
+    ('\u{13C}', false, &['l', '\u{327}']),
+    ('\u{13D}', false, &['L', '\u{30C}']),
+    ('\u{13E}', false, &['l', '\u{30C}']),
+    ('\u{13F}', true, &['L', '\u{B7}']),
+    ('\u{140}', true, &['l', '\u{B7}']),
+    ('\u{143}', false, &['N', '\u{301}']),
+    ('\u{144}', false, &['n', '\u{301}']),
+    ('\u{145}', false, &['N', '\u{327}']),
+    ('\u{146}', false, &['n', '\u{327}']),
+    ('\u{147}', false, &['N', '\u{30C}']),
+    ('\u{148}', false, &['n', '\u{30C}']),
+    ('\u{149}', true, &['\u{2BC}', 'n']),
+    ('\u{14C}', false, &['O', '\u{304}']),
+    ('\u{14D}', false, &['o', '\u{304}']),
+    ('\u{14E}', false, &['O', '\u{306}']),
+    ('\u{14F}', false, &['o', '\u{306}']),
+    ('\u{150}', false, &['O', '\u{30B}']),
+    ('\u{151}', false, &['o', '\u{30B}']),
+    ('\u{154}', false, &['R', '\u{301}']),
+    ('\u{155}', false, &['r', '\u{301}']),
+    ('\u{156}', false, &['R', '\u{327}']),
+    ('\u{157}', false, &['r', '\u{327}']),
+    ('\u{158}', false, &['R', '\u{30C}']),
+    ('\u{159}', false, &['r', '\u{30C}']),
+    ('\u{15A}', false, &['S', '\u{301}']),
+    ('\u{15B}', false, &['s', '\u{301}']),
+    ('\u{15C}', false, &['S', '\u{302}']),
+    ('\u{15D}', false, &['s', '\u{302}']),
+    ('\u{15E}', false, &['S', '\u{327}']),
+    ('\u{15F}', false, &['s', '\u{327}']),
+    ('\u{160}', false, &['S', '\u{30C}']),
+    ('\u{161}', false, &['s', '\u{30C}']),
+    ('\u{162}', false, &['T', '\u{327}']),
+    ('\u{163}', false, &['t', '\u{327}']),
+    ('\u{164}', false, &['T', '\u{30C}']),
+    ('\u{165}', false, &['t', '\u{30C}']),
+    ('\u{168}', false, &['U', '\u{303}']),
+    ('\u{169}', false, &['u', '\u{303}']),
+    ('\u{16A}', false, &['U', '\u{304}']),
+    ('\u{16B}', false, &['u', '\u{304}']),
+    ('\u{16C}', false, &['U', '\u{306}']),
+    ('\u{16D}', false, &['u', '\u{306}']),
+    ('\u{16E}', false, &['U', '\u{30A}']),
+    ('\u{16F}', false, &['u', '\u{30A}']),
+    ('\u{170}', false, &['U', '\u{30B}']),
+    ('\u{171}', false, &['u', '\u{30B}']),
+    ('\u{172}', false, &['U', '\u{328}']),
+    ('\u{173}', false, &['u', '\u{328}']),
+    ('\u{174}', false, &['W', '\u{302}']),
+    ('\u{175}', false, &['w', '\u{302}']),
+    ('\u{176}', false, &['Y', '\u{302}']),
+    ('\u{177}', false, &['y', '\u{302}']),
+    ('\u{178}', false, &['Y', '\u{308}']),
+    ('\u{179}', false, &['Z', '\u{301}']),
+    ('\u{17A}', false, &['z', '\u{301}']),
+    ('\u{17B}', false, &['Z', '\u{307}']),
+    ('\u{17C}', false, &['z', '\u{307}']),
+    ('\u{17D}', false, &['Z', '\u{30C}']),
+    ('\u{17E}', false, &['z', '\u{30C}']),
+    ('\u{17F}', true, &['s']),
+    ('\u{1A0}', false, &['O', '\u{31B}']),
+    ('\u{1A1}', false, &['o', '\u{31B}']),
+    ('\u{1AF}', false, &['U', '\u{31B}']),
+    ('\u{1B0}', false, &['u', '\u{31B}']),
+    ('\u{1C4}', true, &['D', '\u{17D}']),
+    ('\u{1C5}', true, &['D', '\u{17E}']),
+    ('\u{1C6}', true, &['d', '\u{17E}']),
+    ('\u{1C7}', true, &['L', 'J']),
+    ('\u{1C8}', true, &['L', 'j']),
+    ('\u{1C9}', true, &['l', 'j']),
+    ('\u{1CA}', true, &['N', 'J']),
+    ('\u{1CB}', true, &['N', 'j']),
+    ('\u{1CC}', true, &['n', 'j']),
+    ('\u{1CD}', false, &['A', '\u{30C}']),
+    ('\u{1CE}', false, &['a', '\u{30C}']),
+    ('\u{1CF}', false, &['I', '\u{30C}']),
+    ('\u{1D0}', false, &['i', '\u{30C}']),
+    ('\u{1D1}', false, &['O', '\u{30C}']),
+    ('\u{1D2}', false, &['o', '\u{30C}']),
+    ('\u{1D3}', false, &['U', '\u{30C}']),
+    ('\u{1D4}', false, &['u', '\u{30C}']),
+    ('\u{1D5}', false, &['\u{DC}', '\u{304}']),
+    ('\u{1D6}', false, &['\u{FC}', '\u{304}']),
+    ('\u{1D7}', false, &['\u{DC}', '\u{301}']),
+    ('\u{1D8}', false, &['\u{FC}', '\u{301}']),
+    ('\u{1D9}', false, &['\u{DC}', '\u{30C}']),
+    ('\u{1DA}', false, &['\u{FC}', '\u{30C}']),
+    ('\u{1DB}', false, &['\u{DC}', '\u{300}']),
+    ('\u{1DC}', false, &['\u{FC}', '\u{300}']),
+    ('\u{1DE}', false, &['\u{C4}', '\u{304}']),
+    ('\u{1DF}', false, &['\u{E4}', '\u{304}']),
+    ('\u{1E0}', false, &['\u{226}', '\u{304}']),
+    ('\u{1E1}', false, &['\u{227}', '\u{304}']),
+    ('\u{1E2}', false, &['\u{C6}', '\u{304}']),
+    ('\u{1E3}', false, &['\u{E6}', '\u{304}']),
+    ('\u{1E6}', false, &['G', '\u{30C}']),
+    ('\u{1E7}', false, &['g', '\u{30C}']),
+    ('\u{1E8}', false, &['K', '\u{30C}']),
+    ('\u{1E9}', false, &['k', '\u{30C}']),
+    ('\u{1EA}', false, &['O', '\u{328}']),
+    ('\u{1EB}', false, &['o', '\u{328}']),
+    ('\u{1EC}', false, &['\u{1EA}', '\u{304}']),
+    ('\u{1ED}', false, &['\u{1EB}', '\u{304}']),
+    ('\u{1EE}', false, &['\u{1B7}', '\u{30C}']),
+    ('\u{1EF}', false, &['\u{292}', '\u{30C}']),
+    ('\u{1F0}', false, &['j', '\u{30C}']),
+    ('\u{1F1}', true, &['D', 'Z']),
+    ('\u{1F2}', true, &['D', 'z']),
+    ('\u{1F3}', true, &['d', 'z']),
+    ('\u{1F4}', false, &['G', '\u{301}']),
+    ('\u{1F5}', false, &['g', '\u{301}']),
+    ('\u{1F8}', false, &['N', '\u{300}']),
+    ('\u{1F9}', false, &['n', '\u{300}']),
+    ('\u{1FA}', false, &['\u{C5}', '\u{301}']),
+    ('\u{1FB}', false, &['\u{E5}', '\u{301}']),
+    ('\u{1FC}', false, &['\u{C6}', '\u{301}']),
+    ('\u{1FD}', false, &['\u{E6}', '\u{301}']),
+    ('\u{1FE}', false, &['\u{D8}', '\u{301}']),
+    ('\u{1FF}', false, &['\u{F8}', '\u{301}']),
+    ('\u{200}', false, &['A', '\u{30F}']),
+    ('\u{201}', false, &['a', '\u{30F}']),
+    ('\u{202}', false, &['A', '\u{311}']),
+    ('\u{203}', false, &['a', '\u{311}']),
+    ('\u{204}', false, &['E', '\u{30F}']),
+    ('\u{205}', false, &['e', '\u{30F}']),
+    ('\u{206}', false, &['E', '\u{311}']),
+    ('\u{207}', false, &['e', '\u{311}']),
+    ('\u{208}', false, &['I', '\u{30F}']),
+    ('\u{209}', false, &['i', '\u{30F}']),
+    ('\u{20A}', false, &['I', '\u{311}']),
+    ('\u{20B}', false, &['i', '\u{311}']),
+    ('\u{20C}', false, &['O', '\u{30F}']),
+    ('\u{20D}', false, &['o', '\u{30F}']),
+    ('\u{20E}', false, &['O', '\u{311}']),
+    ('\u{20F}', false, &['o', '\u{311}']),
+    ('\u{210}', false, &['R', '\u{30F}']),
+    ('\u{211}', false, &['r', '\u{30F}']),
+    ('\u{212}', false, &['R', '\u{311}']),
+    ('\u{213}', false, &['r', '\u{311}']),
+    ('\u{214}', false, &['U', '\u{30F}']),
+    ('\u{215}', false, &['u', '\u{30F}']),
+    ('\u{216}', false, &['U', '\u{311}']),
+    ('\u{217}', false, &['u', '\u{311}']),
+    ('\u{218}', false, &['S', '\u{326}']),
+    ('\u{219}', false, &['s', '\u{326}']),
+    ('\u{21A}', false, &['T', '\u{326}']),
+    ('\u{21B}', false, &['t', '\u{326}']),
+    ('\u{21E}', false, &['H', '\u{30C}']),
+    ('\u{21F}', false, &['h', '\u{30C}']),
+    ('\u{226}', false, &['A', '\u{307}']),
+    ('\u{227}', false, &['a', '\u{307}']),
+    ('\u{228}', false, &['E', '\u{327}']),
+    ('\u{229}', false, &['e', '\u{327}']),
+    ('\u{22A}', false, &['\u{D6}', '\u{304}']),
+    ('\u{22B}', false, &['\u{F6}', '\u{304}']),
+    ('\u{22C}', false, &['\u{D5}', '\u{304}']),
+    ('\u{22D}', false, &['\u{F5}', '\u{304}']),
+    ('\u{22E}', false, &['O', '\u{307}']),
+    ('\u{22F}', false, &['o', '\u{307}']),
+    ('\u{230}', false, &['\u{22E}', '\u{304}']),
+    ('\u{231}', false, &['\u{22F}', '\u{304}']),
+    ('\u{232}', false, &['Y', '\u{304}']),
+    ('\u{233}', false, &['y', '\u{304}']),
+    ('\u{2B0}', true, &['h']),
+    ('\u{2B1}', true, &['\u{266}']),
+    ('\u{2B2}', true, &['j']),
+    ('\u{2B3}', true, &['r']),
+    ('\u{2B4}', true, &['\u{279}']),
+    ('\u{2B5}', true, &['\u{27B}']),
+    ('\u{2B6}', true, &['\u{281}']),
+    ('\u{2B7}', true, &['w']),
+    ('\u{2B8}', true, &['y']),
+    ('\u{2D8}', true, &['\u{20}', '\u{306}']),
+    ('\u{2D9}', true, &['\u{20}', '\u{307}']),
+    ('\u{2DA}', true, &['\u{20}', '\u{30A}']),
+    ('\u{2DB}', true, &['\u{20}', '\u{328}']),
+    ('\u{2DC}', true, &['\u{20}', '\u{303}']),
+    ('\u{2DD}', true, &['\u{20}', '\u{30B}']),
+    ('\u{2E0}', true, &['\u{263}']),
+    ('\u{2E1}', true, &['l']),
+    ('\u{2E2}', true, &['s']),
+    ('\u{2E3}', true, &['x']),
+    ('\u{2E4}', true, &['\u{295}']),
+    ('\u{340}', false, &['\u{300}']),
+    ('\u{341}', false, &['\u{301}']),
+    ('\u{343}', false, &['\u{313}']),
+    ('\u{344}', false, &['\u{308}', '\u{301}']),
+    ('\u{374}', false, &['\u{2B9}']),
+    ('\u{37A}', true, &['\u{20}', '\u{345}']),
+    ('\u{37E}', false, &[';']),
+    ('\u{384}', true, &['\u{20}', '\u{301}']),
+    ('\u{385}', false, &['\u{A8}', '\u{301}']),
+    ('\u{386}', false, &['\u{391}', '\u{301}']),
+    ('\u{387}', false, &['\u{B7}']),
+    ('\u{388}', false, &['\u{395}', '\u{301}']),
+    ('\u{389}', false, &['\u{397}', '\u{301}']),
+    ('\u{38A}', false, &['\u{399}', '\u{301}']),
+    ('\u{38C}', false, &['\u{39F}', '\u{301}']),
+    ('\u{38E}', false, &['\u{3A5}', '\u{301}']),
+    ('\u{38F}', false, &['\u{3A9}', '\u{301}']),
+    ('\u{390}', false, &['\u{3CA}', '\u{301}']),
+    ('\u{3AA}', false, &['\u{399}', '\u{308}']),
+    ('\u{3AB}', false, &['\u{3A5}', '\u{308}']),
+    ('\u{3AC}', false, &['\u{3B1}', '\u{301}']),
+    ('\u{3AD}', false, &['\u{3B5}', '\u{301}']),
+    ('\u{3AE}', false, &['\u{3B7}', '\u{301}']),
+    ('\u{3AF}', false, &['\u{3B9}', '\u{301}']),
+    ('\u{3B0}', false, &['\u{3CB}', '\u{301}']),
+    ('\u{3CA}', false, &['\u{3B9}', '\u{308}']),
+    ('\u{3CB}', false, &['\u{3C5}', '\u{308}']),
+    ('\u{3CC}', false, &['\u{3BF}', '\u{301}']),
+    ('\u{3CD}', false, &['\u{3C5}', '\u{301}']),
+    ('\u{3CE}', false, &['\u{3C9}', '\u{301}']),
+    ('\u{3D0}', true, &['\u{3B2}']),
+    ('\u{3D1}', true, &['\u{3B8}']),
+    ('\u{3D2}', true, &['\u{3A5}']),
+    ('\u{3D3}', false, &['\u{3D2}', '\u{301}']),
+    ('\u{3D4}', false, &['\u{3D2}', '\u{308}']),
+    ('\u{3D5}', true, &['\u{3C6}']),
+    ('\u{3D6}', true, &['\u{3C0}']),
+    ('\u{3F0}', true, &['\u{3BA}']),
+    ('\u{3F1}', true, &['\u{3C1}']),
+    ('\u{3F2}', true, &['\u{3C2}']),
+    ('\u{3F4}', true, &['\u{398}']),
+    ('\u{3F5}', true, &['\u{3B5}']),
+    ('\u{3F9}', true, &['\u{3A3}']),
+    ('\u{400}', false, &['\u{415}', '\u{300}']),
+    ('\u{401}', false, &['\u{415}', '\u{308}']),
+    ('\u{403}', false, &['\u{413}', '\u{301}']),
+    ('\u{407}', false, &['\u{406}', '\u{308}']),
+    ('\u{40C}', false, &['\u{41A}', '\u{301}']),
+    ('\u{40D}', false, &['\u{418}', '\u{300}']),
+    ('\u{40E}', false, &['\u{423}', '\u{306}']),
+    ('\u{419}', false, &['\u{418}', '\u{306}']),
+    ('\u{439}', false, &['\u{438}', '\u{306}']),
+    ('\u{450}', false, &['\u{435}', '\u{300}']),
+    ('\u{451}', false, &['\u{435}', '\u{308}']),
+    ('\u{453}', false, &['\u{433}', '\u{301}']),
+    ('\u{457}', false, &['\u{456}', '\u{308}']),
+    ('\u{45C}', false, &['\u{43A}', '\u{301}']),
+    ('\u{45D}', false, &['\u{438}', '\u{300}']),
+    ('\u{45E}', false, &['\u{443}', '\u{306}']),
+    ('\u{476}', false, &['\u{474}', '\u{30F}']),
+    ('\u{477}', false, &['\u{475}', '\u{30F}']),
+    ('\u{4C1}', false, &['\u{416}', '\u{306}']),
+    ('\u{4C2}', false, &['\u{436}', '\u{306}']),
+    ('\u{4D0}', false, &['\u{410}', '\u{306}']),
+    ('\u{4D1}', false, &['\u{430}', '\u{306}']),
+    ('\u{4D2}', false, &['\u{410}', '\u{308}']),
+    ('\u{4D3}', false, &['\u{430}', '\u{308}']),
+    ('\u{4D6}', false, &['\u{415}', '\u{306}']),
+    ('\u{4D7}', false, &['\u{435}', '\u{306}']),
+    ('\u{4DA}', false, &['\u{4D8}', '\u{308}']),
+    ('\u{4DB}', false, &['\u{4D9}', '\u{308}']),
+    ('\u{4DC}', false, &['\u{416}', '\u{308}']),
+    ('\u{4DD}', false, &['\u{436}', '\u{308}']),
+    ('\u{4DE}', false, &['\u{417}', '\u{308}']),
+    ('\u{4DF}', false, &['\u{437}', '\u{308}']),
+    ('\u{4E2}', false, &['\u{418}', '\u{304}']),
+    ('\u{4E3}', false, &['\u{438}', '\u{304}']),
+    ('\u{4E4}', false, &['\u{418}', '\u{308}']),
+    ('\u{4E5}', false, &['\u{438}', '\u{308}']),
+    ('\u{4E6}', false, &['\u{41E}', '\u{308}']),
+    ('\u{4E7}', false, &['\u{43E}', '\u{308}']),
+    ('\u{4EA}', false, &['\u{4E8}', '\u{308}']),
+    ('\u{4EB}', false, &['\u{4E9}', '\u{308}']),
+    ('\u{4EC}', false, &['\u{42D}', '\u{308}']),
+    ('\u{4ED}', false, &['\u{44D}', '\u{308}']),
+    ('\u{4EE}', false, &['\u{423}', '\u{304}']),
+    ('\u{4EF}', false, &['\u{443}', '\u{304}']),
+    ('\u{4F0}', false, &['\u{423}', '\u{308}']),
+    ('\u{4F1}', false, &['\u{443}', '\u{308}']),
+    ('\u{4F2}', false, &['\u{423}', '\u{30B}']),
+    ('\u{4F3}', false, &['\u{443}', '\u{30B}']),
+    ('\u{4F4}', false, &['\u{427}', '\u{308}']),
+    ('\u{4F5}', false, &['\u{447}', '\u{308}']),
+    ('\u{4F8}', false, &['\u{42B}', '\u{308}']),
+    ('\u{4F9}', false, &['\u{44B}', '\u{308}']),
+    ('\u{587}', true, &['\u{565}', '\u{582}']),
+    ('\u{622}', false, &['\u{627}', '\u{653}']),
+    ('\u{623}', false, &['\u{627}', '\u{654}']),
+    ('\u{624}', false, &['\u{648}', '\u{654}']),
+    ('\u{625}', false, &['\u{627}', '\u{655}']),
+    ('\u{626}', false, &['\u{64A}', '\u{654}']),
+    ('\u{675}', true, &['\u{627}', '\u{674}']),
+    ('\u{676}', true, &['\u{648}', '\u{674}']),
+    ('\u{677}', true, &['\u{6C7}', '\u{674}']),
+    ('\u{678}', true, &['\u{64A}', '\u{674}']),
+    ('\u{6C0}', false, &['\u{6D5}', '\u{654}']),
+    ('\u{6C2}', false, &['\u{6C1}', '\u{654}']),
+    ('\u{6D3}', false, &['\u{6D2}', '\u{654}']),
+    ('\u{929}', false, &['\u{928}', '\u{93C}']),
+    ('\u{931}', false, &['\u{930}', '\u{93C}']),
+    ('\u{934}', false, &['\u{933}', '\u{93C}']),
+    ('\u{958}', false, &['\u{915}', '\u{93C}']),
+    ('\u{959}', false, &['\u{916}', '\u{93C}']),
+    ('\u{95A}', false, &['\u{917}', '\u{93C}']),
+    ('\u{95B}', false, &['\u{91C}', '\u{93C}']),
+    ('\u{95C}', false, &['\u{921}', '\u{93C}']),
+    ('\u{95D}', false, &['\u{922}', '\u{93C}']),
+    ('\u{95E}', false, &['\u{92B}', '\u{93C}']),
+    ('\u{95F}', false, &['\u{92F}', '\u{93C}']),
+    ('\u{9CB}', false, &['\u{9C7}', '\u{9BE}']),
+    ('\u{9CC}', false, &['\u{9C7}', '\u{9D7}']),
+    ('\u{9DC}', false, &['\u{9A1}', '\u{9BC}']),
+    ('\u{9DD}', false, &['\u{9A2}', '\u{9BC}']),
+    ('\u{9DF}', false, &['\u{9AF}', '\u{9BC}']),
+    ('\u{A33}', false, &['\u{A32}', '\u{A3C}']),
+    ('\u{A36}', false, &['\u{A38}', '\u{A3C}']),
+    ('\u{A59}', false, &['\u{A16}', '\u{A3C}']),
+    ('\u{A5A}', false, &['\u{A17}', '\u{A3C}']),
+    ('\u{A5B}', false, &['\u{A1C}', '\u{A3C}']),
+    ('\u{A5E}', false, &['\u{A2B}', '\u{A3C}']),
+    ('\u{B48}', false, &['\u{B47}', '\u{B56}']),
+    ('\u{B4B}', false, &['\u{B47}', '\u{B3E}']),
+    ('\u{B4C}', false, &['\u{B47}', '\u{B57}']),
+    ('\u{B5C}', false, &['\u{B21}', '\u{B3C}']),
+    ('\u{B5D}', false, &['\u{B22}', '\u{B3C}']),
+    ('\u{B94}', false, &['\u{B92}', '\u{BD7}']),
+    ('\u{BCA}', false, &['\u{BC6}', '\u{BBE}']),
+    ('\u{BCB}', false, &['\u{BC7}', '\u{BBE}']),
+    ('\u{BCC}', false, &['\u{BC6}', '\u{BD7}']),
+    ('\u{C48}', false, &['\u{C46}', '\u{C56}']),
+    ('\u{CC0}', false, &['\u{CBF}', '\u{CD5}']),
+    ('\u{CC7}', false, &['\u{CC6}', '\u{CD5}']),
+    ('\u{CC8}', false, &['\u{CC6}', '\u{CD6}']),
+    ('\u{CCA}', false, &['\u{CC6}', '\u{CC2}']),
+    ('\u{CCB}', false, &['\u{CCA}', '\u{CD5}']),
+    ('\u{D4A}', false, &['\u{D46}', '\u{D3E}']),
+    ('\u{D4B}', false, &['\u{D47}', '\u{D3E}']),
+    ('\u{D4C}', false, &['\u{D46}', '\u{D57}']),
+    ('\u{DDA}', false, &['\u{DD9}', '\u{DCA}']),
+    ('\u{DDC}', false, &['\u{DD9}', '\u{DCF}']),
+    ('\u{DDD}', false, &['\u{DDC}', '\u{DCA}']),
+    ('\u{DDE}', false, &['\u{DD9}', '\u{DDF}']),
+    ('\u{E33}', true, &['\u{E4D}', '\u{E32}']),
+    ('\u{EB3}', true, &['\u{ECD}', '\u{EB2}']),
+    ('\u{EDC}', true, &['\u{EAB}', '\u{E99}']),
+    ('\u{EDD}', true, &['\u{EAB}', '\u{EA1}']),
+    ('\u{F0C}', true, &['\u{F0B}']),
+    ('\u{F43}', false, &['\u{F42}', '\u{FB7}']),
+    ('\u{F4D}', false, &['\u{F4C}', '\u{FB7}']),
+    ('\u{F52}', false, &['\u{F51}', '\u{FB7}']),
+    ('\u{F57}', false, &['\u{F56}', '\u{FB7}']),
+    ('\u{F5C}', false, &['\u{F5B}', '\u{FB7}']),
+    ('\u{F69}', false, &['\u{F40}', '\u{FB5}']),
+    ('\u{F73}', false, &['\u{F71}', '\u{F72}']),
+    ('\u{F75}', false, &['\u{F71}', '\u{F74}']),
+    ('\u{F76}', false, &['\u{FB2}', '\u{F80}']),
+    ('\u{F77}', true, &['\u{FB2}', '\u{F81}']),
+    ('\u{F78}', false, &['\u{FB3}', '\u{F80}']),
+    ('\u{F79}', true, &['\u{FB3}', '\u{F81}']),
+    ('\u{F81}', false, &['\u{F71}', '\u{F80}']),
+    ('\u{F93}', false, &['\u{F92}', '\u{FB7}']),
+    ('\u{F9D}', false, &['\u{F9C}', '\u{FB7}']),
+    ('\u{FA2}', false, &['\u{FA1}', '\u{FB7}']),
+    ('\u{FA7}', false, &['\u{FA6}', '\u{FB7}']),
+    ('\u{FAC}', false, &['\u{FAB}', '\u{FB7}']),
+    ('\u{FB9}', false, &['\u{F90}', '\u{FB5}']),
+    ('\u{1026}', false, &['\u{1025}', '\u{102E}']),
+    ('\u{10FC}', true, &['\u{10DC}']),
+    ('\u{1B06}', false, &['\u{1B05}', '\u{1B35}']),
+    ('\u{1B08}', false, &['\u{1B07}', '\u{1B35}']),
+    ('\u{1B0A}', false, &['\u{1B09}', '\u{1B35}']),
+    ('\u{1B0C}', false, &['\u{1B0B}', '\u{1B35}']),
+    ('\u{1B0E}', false, &['\u{1B0D}', '\u{1B35}']),
+    ('\u{1B12}', false, &['\u{1B11}', '\u{1B35}']),
+    ('\u{1B3B}', false, &['\u{1B3A}', '\u{1B35}']),
+    ('\u{1B3D}', false, &['\u{1B3C}', '\u{1B35}']),
+    ('\u{1B40}', false, &['\u{1B3E}', '\u{1B35}']),
+    ('\u{1B41}', false, &['\u{1B3F}', '\u{1B35}']),
+    ('\u{1B43}', false, &['\u{1B42}', '\u{1B35}']),
+    ('\u{1D2C}', true, &['A']),
+    ('\u{1D2D}', true, &['\u{C6}']),
+    ('\u{1D2E}', true, &['B']),
+    ('\u{1D30}', true, &['D']),
+    ('\u{1D31}', true, &['E']),
+    ('\u{1D32}', true, &['\u{18E}']),
+    ('\u{1D33}', true, &['G']),
+    ('\u{1D34}', true, &['H']),
+    ('\u{1D35}', true, &['I']),
+    ('\u{1D36}', true, &['J']),
+    ('\u{1D37}', true, &['K']),
+    ('\u{1D38}', true, &['L']),
+    ('\u{1D39}', true, &['M']),
+    ('\u{1D3A}', true, &['N']),
+    ('\u{1D3C}', true, &['O']),
+    ('\u{1D3D}', true, &['\u{222}']),
+    ('\u{1D3E}', true, &['P']),
+    ('\u{1D3F}', true, &['R']),
+    ('\u{1D40}', true, &['T']),
+    ('\u{1D41}', true, &['U']),
+    ('\u{1D42}', true, &['W']),
+    ('\u{1D43}', true, &['a']),
+    ('\u{1D44}', true, &['\u{250}']),
+    ('\u{1D45}', true, &['\u{251}']),
+    ('\u{1D46}', true, &['\u{1D02}']),
+    ('\u{1D47}', true, &['b']),
+    ('\u{1D48}', true, &['d']),
+    ('\u{1D49}', true, &['e']),
+    ('\u{1D4A}', true, &['\u{259}']),
+    ('\u{1D4B}', true, &['\u{25B}']),
+    ('\u{1D4C}', true, &['\u{25C}']),
+    ('\u{1D4D}', true, &['g']),
+    ('\u{1D4F}', true, &['k']),
+    ('\u{1D50}', true, &['m']),
+    ('\u{1D51}', true, &['\u{14B}']),
+    ('\u{1D52}', true, &['o']),
+    ('\u{1D53}', true, &['\u{254}']),
+    ('\u{1D54}', true, &['\u{1D16}']),
+    ('\u{1D55}', true, &['\u{1D17}']),
+    ('\u{1D56}', true, &['p']),
+    ('\u{1D57}', true, &['t']),
+    ('\u{1D58}', true, &['u']),
+    ('\u{1D59}', true, &['\u{1D1D}']),
+    ('\u{1D5A}', true, &['\u{26F}']),
+    ('\u{1D5B}', true, &['v']),
+    ('\u{1D5C}', true, &['\u{1D25}']),
+    ('\u{1D5D}', true, &['\u{3B2}']),
+    ('\u{1D5E}', true, &['\u{3B3}']),
+    ('\u{1D5F}', true, &['\u{3B4}']),
+    ('\u{1D60}', true, &['\u{3C6}']),
+    ('\u{1D61}', true, &['\u{3C7}']),
+    ('\u{1D62}', true, &['i']),
+    ('\u{1D63}', true, &['r']),
+    ('\u{1D64}', true, &['u']),
+    ('\u{1D65}', true, &['v']),
+    ('\u{1D66}', true, &['\u{3B2}']),
+    ('\u{1D67}', true, &['\u{3B3}']),
+    ('\u{1D68}', true, &['\u{3C1}']),
+    ('\u{1D69}', true, &['\u{3C6}']),
+    ('\u{1D6A}', true, &['\u{3C7}']),
+    ('\u{1D78}', true, &['\u{43D}']),
+    ('\u{1D9B}', true, &['\u{252}']),
+    ('\u{1D9C}', true, &['c']),
+    ('\u{1D9D}', true, &['\u{255}']),
+    ('\u{1D9E}', true, &['\u{F0}']),
+    ('\u{1D9F}', true, &['\u{25C}']),
+    ('\u{1DA0}', true, &['f']),
+    ('\u{1DA1}', true, &['\u{25F}']),
+    ('\u{1DA2}', true, &['\u{261}']),
+    ('\u{1DA3}', true, &['\u{265}']),
+    ('\u{1DA4}', true, &['\u{268}']),
+    ('\u{1DA5}', true, &['\u{269}']),
+    ('\u{1DA6}', true, &['\u{26A}']),
+    ('\u{1DA7}', true, &['\u{1D7B}']),
+    ('\u{1DA8}', true, &['\u{29D}']),
+    ('\u{1DA9}', true, &['\u{26D}']),
+    ('\u{1DAA}', true, &['\u{1D85}']),
+    ('\u{1DAB}', true, &['\u{29F}']),
+    ('\u{1DAC}', true, &['\u{271}']),
+    ('\u{1DAD}', true, &['\u{270}']),
+    ('\u{1DAE}', true, &['\u{272}']),
+    ('\u{1DAF}', true, &['\u{273}']),
+    ('\u{1DB0}', true, &['\u{274}']),
+    ('\u{1DB1}', true, &['\u{275}']),
+    ('\u{1DB2}', true, &['\u{278}']),
+    ('\u{1DB3}', true, &['\u{282}']),
+    ('\u{1DB4}', true, &['\u{283}']),
+    ('\u{1DB5}', true, &['\u{1AB}']),
+    ('\u{1DB6}', true, &['\u{289}']),
+    ('\u{1DB7}', true, &['\u{28A}']),
+    ('\u{1DB8}', true, &['\u{1D1C}']),
+    ('\u{1DB9}', true, &['\u{28B}']),
+    ('\u{1DBA}', true, &['\u{28C}']),
+    ('\u{1DBB}', true, &['z']),
+    ('\u{1DBC}', true, &['\u{290}']),
+    ('\u{1DBD}', true, &['\u{291}']),
+    ('\u{1DBE}', true, &['\u{292}']),
+    ('\u{1DBF}', true, &['\u{3B8}']),
+    ('\u{1E00}', false, &['A', '\u{325}']),
+    ('\u{1E01}', false, &['a', '\u{325}']),
+    ('\u{1E02}', false, &['B', '\u{307}']),
+    ('\u{1E03}', false, &['b', '\u{307}']),
+    ('\u{1E04}', false, &['B', '\u{323}']),
+    ('\u{1E05}', false, &['b', '\u{323}']),
+    ('\u{1E06}', false, &['B', '\u{331}']),
+    ('\u{1E07}', false, &['b', '\u{331}']),
+    ('\u{1E08}', false, &['\u{C7}', '\u{301}']),
+    ('\u{1E09}', false, &['\u{E7}', '\u{301}']),
+    ('\u{1E0A}', false, &['D', '\u{307}']),
+    ('\u{1E0B}', false, &['d', '\u{307}']),
+    ('\u{1E0C}', false, &['D', '\u{323}']),
+    ('\u{1E0D}', false, &['d', '\u{323}']),
+    ('\u{1E0E}', false, &['D', '\u{331}']),
+    ('\u{1E0F}', false, &['d', '\u{331}']),
+    ('\u{1E10}', false, &['D', '\u{327}']),
+    ('\u{1E11}', false, &['d', '\u{327}']),
+    ('\u{1E12}', false, &['D', '\u{32D}']),
+    ('\u{1E13}', false, &['d', '\u{32D}']),
+    ('\u{1E14}', false, &['\u{112}', '\u{300}']),
+    ('\u{1E15}', false, &['\u{113}', '\u{300}']),
+    ('\u{1E16}', false, &['\u{112}', '\u{301}']),
+    ('\u{1E17}', false, &['\u{113}', '\u{301}']),
+    ('\u{1E18}', false, &['E', '\u{32D}']),
+    ('\u{1E19}', false, &['e', '\u{32D}']),
+    ('\u{1E1A}', false, &['E', '\u{330}']),
+    ('\u{1E1B}', false, &['e', '\u{330}']),
+    ('\u{1E1C}', false, &['\u{228}', '\u{306}']),
+    ('\u{1E1D}', false, &['\u{229}', '\u{306}']),
+    ('\u{1E1E}', false, &['F', '\u{307}']),
+    ('\u{1E1F}', false, &['f', '\u{307}']),
+    ('\u{1E20}', false, &['G', '\u{304}']),
+    ('\u{1E21}', false, &['g', '\u{304}']),
+    ('\u{1E22}', false, &['H', '\u{307}']),
+    ('\u{1E23}', false, &['h', '\u{307}']),
+    ('\u{1E24}', false, &['H', '\u{323}']),
+    ('\u{1E25}', false, &['h', '\u{323}']),
+    ('\u{1E26}', false, &['H', '\u{308}']),
+    ('\u{1E27}', false, &['h', '\u{308}']),
+    ('\u{1E28}', false, &['H', '\u{327}']),
+    ('\u{1E29}', false, &['h', '\u{327}']),
+    ('\u{1E2A}', false, &['H', '\u{32E}']),
+    ('\u{1E2B}', false, &['h', '\u{32E}']),
+    ('\u{1E2C}', false, &['I', '\u{330}']),
+    ('\u{1E2D}', false, &['i', '\u{330}']),
+    ('\u{1E2E}', false, &['\u{CF}', '\u{301}']),
+    ('\u{1E2F}', false, &['\u{EF}', '\u{301}']),
+    ('\u{1E30}', false, &['K', '\u{301}']),
+    ('\u{1E31}', false, &['k', '\u{301}']),
+    ('\u{1E32}', false, &['K', '\u{323}']),
+    ('\u{1E33}', false, &['k', '\u{323}']),
+    ('\u{1E34}', false, &['K', '\u{331}']),
+    ('\u{1E35}', false, &['k', '\u{331}']),
+    ('\u{1E36}', false, &['L', '\u{323}']),
+    ('\u{1E37}', false, &['l', '\u{323}']),
+    ('\u{1E38}', false, &['\u{1E36}', '\u{304}']),
+    ('\u{1E39}', false, &['\u{1E37}', '\u{304}']),
+    ('\u{1E3A}', false, &['L', '\u{331}']),
+    ('\u{1E3B}', false, &['l', '\u{331}']),
+    ('\u{1E3C}', false, &['L', '\u{32D}']),
+    ('\u{1E3D}', false, &['l', '\u{32D}']),
+    ('\u{1E3E}', false, &['M', '\u{301}']),
+    ('\u{1E3F}', false, &['m', '\u{301}']),
+    ('\u{1E40}', false, &['M', '\u{307}']),
+    ('\u{1E41}', false, &['m', '\u{307}']),
+    ('\u{1E42}', false, &['M', '\u{323}']),
+    ('\u{1E43}', false, &['m', '\u{323}']),
+    ('\u{1E44}', false, &['N', '\u{307}']),
+    ('\u{1E45}', false, &['n', '\u{307}']),
+    ('\u{1E46}', false, &['N', '\u{323}']),
+    ('\u{1E47}', false, &['n', '\u{323}']),
+    ('\u{1E48}', false, &['N', '\u{331}']),
+    ('\u{1E49}', false, &['n', '\u{331}']),
+    ('\u{1E4A}', false, &['N', '\u{32D}']),
+    ('\u{1E4B}', false, &['n', '\u{32D}']),
+    ('\u{1E4C}', false, &['\u{D5}', '\u{301}']),
+    ('\u{1E4D}', false, &['\u{F5}', '\u{301}']),
+    ('\u{1E4E}', false, &['\u{D5}', '\u{308}']),
+    ('\u{1E4F}', false, &['\u{F5}', '\u{308}']),
+    ('\u{1E50}', false, &['\u{14C}', '\u{300}']),
+    ('\u{1E51}', false, &['\u{14D}', '\u{300}']),
+    ('\u{1E52}', false, &['\u{14C}', '\u{301}']),
+    ('\u{1E53}', false, &['\u{14D}', '\u{301}']),
+    ('\u{1E54}', false, &['P', '\u{301}']),
+    ('\u{1E55}', false, &['p', '\u{301}']),
+    ('\u{1E56}', false, &['P', '\u{307}']),
+    ('\u{1E57}', false, &['p', '\u{307}']),
+    ('\u{1E58}', false, &['R', '\u{307}']),
+    ('\u{1E59}', false, &['r', '\u{307}']),
+    ('\u{1E5A}', false, &['R', '\u{323}']),
+    ('\u{1E5B}', false, &['r', '\u{323}']),
+    ('\u{1E5C}', false, &['\u{1E5A}', '\u{304}']),
+    ('\u{1E5D}', false, &['\u{1E5B}', '\u{304}']),
+    ('\u{1E5E}', false, &['R', '\u{331}']),
+    ('\u{1E5F}', false, &['r', '\u{331}']),
+    ('\u{1E60}', false, &['S', '\u{307}']),
+    ('\u{1E61}', false, &['s', '\u{307}']),
+    ('\u{1E62}', false, &['S', '\u{323}']),
+    ('\u{1E63}', false, &['s', '\u{323}']),
+    ('\u{1E64}', false, &['\u{15A}', '\u{307}']),
+    ('\u{1E65}', false, &['\u{15B}', '\u{307}']),
+    ('\u{1E66}', false, &['\u{160}', '\u{307}']),
+    ('\u{1E67}', false, &['\u{161}', '\u{307}']),
+    ('\u{1E68}', false, &['\u{1E62}', '\u{307}']),
+    ('\u{1E69}', false, &['\u{1E63}', '\u{307}']),
+    ('\u{1E6A}', false, &['T', '\u{307}']),
+    ('\u{1E6B}', false, &['t', '\u{307}']),
+    ('\u{1E6C}', false, &['T', '\u{323}']),
+    ('\u{1E6D}', false, &['t', '\u{323}']),
+    ('\u{1E6E}', false, &['T', '\u{331}']),
+    ('\u{1E6F}', false, &['t', '\u{331}']),
+    ('\u{1E70}', false, &['T', '\u{32D}']),
+    ('\u{1E71}', false, &['t', '\u{32D}']),
+    ('\u{1E72}', false, &['U', '\u{324}']),
+    ('\u{1E73}', false, &['u', '\u{324}']),
+    ('\u{1E74}', false, &['U', '\u{330}']),
+    ('\u{1E75}', false, &['u', '\u{330}']),
+    ('\u{1E76}', false, &['U', '\u{32D}']),
+    ('\u{1E77}', false, &['u', '\u{32D}']),
+    ('\u{1E78}', false, &['\u{168}', '\u{301}']),
+    ('\u{1E79}', false, &['\u{169}', '\u{301}']),
+    ('\u{1E7A}', false, &['\u{16A}', '\u{308}']),
+    ('\u{1E7B}', false, &['\u{16B}', '\u{308}']),
+    ('\u{1E7C}', false, &['V', '\u{303}']),
+    ('\u{1E7D}', false, &['v', '\u{303}']),
+    ('\u{1E7E}', false, &['V', '\u{323}']),
+    ('\u{1E7F}', false, &['v', '\u{323}']),
+    ('\u{1E80}', false, &['W', '\u{300}']),
+    ('\u{1E81}', false, &['w', '\u{300}']),
+    ('\u{1E82}', false, &['W', '\u{301}']),
+    ('\u{1E83}', false, &['w', '\u{301}']),
+    ('\u{1E84}', false, &['W', '\u{308}']),
+    ('\u{1E85}', false, &['w', '\u{308}']),
+    ('\u{1E86}', false, &['W', '\u{307}']),
+    ('\u{1E87}', false, &['w', '\u{307}']),
+    ('\u{1E88}', false, &['W', '\u{323}']),
+    ('\u{1E89}', false, &['w', '\u{323}']),
+    ('\u{1E8A}', false, &['X', '\u{307}']),
+    ('\u{1E8B}', false, &['x', '\u{307}']),
+    ('\u{1E8C}', false, &['X', '\u{308}']),
+    ('\u{1E8D}', false, &['x', '\u{308}']),
+    ('\u{1E8E}', false, &['Y', '\u{307}']),
+    ('\u{1E8F}', false, &['y', '\u{307}']),
+    ('\u{1E90}', false, &['Z', '\u{302}']),
+    ('\u{1E91}', false, &['z', '\u{302}']),
+    ('\u{1E92}', false, &['Z', '\u{323}']),
+    ('\u{1E93}', false, &['z', '\u{323}']),
+    ('\u{1E94}', false, &['Z', '\u{331}']),
+    ('\u{1E95}', false, &['z', '\u{331}']),
+    ('\u{1E96}', false, &['h', '\u{331}']),
+    ('\u{1E97}', false, &['t', '\u{308}']),
+    ('\u{1E98}', false, &['w', '\u{30A}']),
+    ('\u{1E99}', false, &['y', '\u{30A}']),
+    ('\u{1E9A}', true, &['a', '\u{2BE}']),
+    ('\u{1E9B}', false, &['\u{17F}', '\u{307}']),
+    ('\u{1EA0}', false, &['A', '\u{323}']),
+    ('\u{1EA1}', false, &['a', '\u{323}']),
+    ('\u{1EA2}', false, &['A', '\u{309}']),
+    ('\u{1EA3}', false, &['a', '\u{309}']),
+    ('\u{1EA4}', false, &['\u{C2}', '\u{301}']),
+    ('\u{1EA5}', false, &['\u{E2}', '\u{301}']),
+    ('\u{1EA6}', false, &['\u{C2}', '\u{300}']),
+    ('\u{1EA7}', false, &['\u{E2}', '\u{300}']),
+    ('\u{1EA8}', false, &['\u{C2}', '\u{309}']),
+    ('\u{1EA9}', false, &['\u{E2}', '\u{309}']),
+    ('\u{1EAA}', false, &['\u{C2}', '\u{303}']),
+    ('\u{1EAB}', false, &['\u{E2}', '\u{303}']),
+    ('\u{1EAC}', false, &['\u{1EA0}', '\u{302}']),
+    ('\u{1EAD}', false, &['\u{1EA1}', '\u{302}']),
+    ('\u{1EAE}', false, &['\u{102}', '\u{301}']),
+    ('\u{1EAF}', false, &['\u{103}', '\u{301}']),
+    ('\u{1EB0}', false, &['\u{102}', '\u{300}']),
+    ('\u{1EB1}', false, &['\u{103}', '\u{300}']),
+    ('\u{1EB2}', false, &['\u{102}', '\u{309}']),
+    ('\u{1EB3}', false, &['\u{103}', '\u{309}']),
+    ('\u{1EB4}', false, &['\u{102}', '\u{303}']),
+    ('\u{1EB5}', false, &['\u{103}', '\u{303}']),
+    ('\u{1EB6}', false, &['\u{1EA0}', '\u{306}']),
+    ('\u{1EB7}', false, &['\u{1EA1}', '\u{306}']),
+    ('\u{1EB8}', false, &['E', '\u{323}']),
+    ('\u{1EB9}', false, &['e', '\u{323}']),
+    ('\u{1EBA}', false, &['E', '\u{309}']),
+    ('\u{1EBB}', false, &['e', '\u{309}']),
+    ('\u{1EBC}', false, &['E', '\u{303}']),
+    ('\u{1EBD}', false, &['e', '\u{303}']),
+    ('\u{1EBE}', false, &['\u{CA}', '\u{301}']),
+    ('\u{1EBF}', false, &['\u{EA}', '\u{301}']),
+    ('\u{1EC0}', false, &['\u{CA}', '\u{300}']),
+    ('\u{1EC1}', false, &['\u{EA}', '\u{300}']),
+    ('\u{1EC2}', false, &['\u{CA}', '\u{309}']),
+    ('\u{1EC3}', false, &['\u{EA}', '\u{309}']),
+    ('\u{1EC4}', false, &['\u{CA}', '\u{303}']),
+    ('\u{1EC5}', false, &['\u{EA}', '\u{303}']),
+    ('\u{1EC6}', false, &['\u{1EB8}', '\u{302}']),
+    ('\u{1EC7}', false, &['\u{1EB9}', '\u{302}']),
+    ('\u{1EC8}', false, &['I', '\u{309}']),
+    ('\u{1EC9}', false, &['i', '\u{309}']),
+    ('\u{1ECA}', false, &['I', '\u{323}']),
+    ('\u{1ECB}', false, &['i', '\u{323}']),
+    ('\u{1ECC}', false, &['O', '\u{323}']),
+    ('\u{1ECD}', false, &['o', '\u{323}']),
+    ('\u{1ECE}', false, &['O', '\u{309}']),
+    ('\u{1ECF}', false, &['o', '\u{309}']),
+    ('\u{1ED0}', false, &['\u{D4}', '\u{301}']),
+    ('\u{1ED1}', false, &['\u{F4}', '\u{301}']),
+    ('\u{1ED2}', false, &['\u{D4}', '\u{300}']),
+    ('\u{1ED3}', false, &['\u{F4}', '\u{300}']),
+    ('\u{1ED4}', false, &['\u{D4}', '\u{309}']),
+    ('\u{1ED5}', false, &['\u{F4}', '\u{309}']),
+    ('\u{1ED6}', false, &['\u{D4}', '\u{303}']),
+    ('\u{1ED7}', false, &['\u{F4}', '\u{303}']),
+    ('\u{1ED8}', false, &['\u{1ECC}', '\u{302}']),
+    ('\u{1ED9}', false, &['\u{1ECD}', '\u{302}']),
+    ('\u{1EDA}', false, &['\u{1A0}', '\u{301}']),
+    ('\u{1EDB}', false, &['\u{1A1}', '\u{301}']),
+    ('\u{1EDC}', false, &['\u{1A0}', '\u{300}']),
+    ('\u{1EDD}', false, &['\u{1A1}', '\u{300}']),
+    ('\u{1EDE}', false, &['\u{1A0}', '\u{309}']),
+    ('\u{1EDF}', false, &['\u{1A1}', '\u{309}']),
+    ('\u{1EE0}', false, &['\u{1A0}', '\u{303}']),
+    ('\u{1EE1}', false, &['\u{1A1}', '\u{303}']),
+    ('\u{1EE2}', false, &['\u{1A0}', '\u{323}']),
+    ('\u{1EE3}', false, &['\u{1A1}', '\u{323}']),
+    ('\u{1EE4}', false, &['U', '\u{323}']),
+    ('\u{1EE5}', false, &['u', '\u{323}']),
+    ('\u{1EE6}', false, &['U', '\u{309}']),
+    ('\u{1EE7}', false, &['u', '\u{309}']),
+    ('\u{1EE8}', false, &['\u{1AF}', '\u{301}']),
+    ('\u{1EE9}', false, &['\u{1B0}', '\u{301}']),
+    ('\u{1EEA}', false, &['\u{1AF}', '\u{300}']),
+    ('\u{1EEB}', false, &['\u{1B0}', '\u{300}']),
+    ('\u{1EEC}', false, &['\u{1AF}', '\u{309}']),
+    ('\u{1EED}', false, &['\u{1B0}', '\u{309}']),
+    ('\u{1EEE}', false, &['\u{1AF}', '\u{303}']),
+    ('\u{1EEF}', false, &['\u{1B0}', '\u{303}']),
+    ('\u{1EF0}', false, &['\u{1AF}', '\u{323}']),
+    ('\u{1EF1}', false, &['\u{1B0}', '\u{323}']),
+    ('\u{1EF2}', false, &['Y', '\u{300}']),
+    ('\u{1EF3}', false, &['y', '\u{300}']),
+    ('\u{1EF4}', false, &['Y', '\u{323}']),
+    ('\u{1EF5}', false, &['y', '\u{323}']),
+    ('\u{1EF6}', false, &['Y', '\u{309}']),
+    ('\u{1EF7}', false, &['y', '\u{309}']),
+    ('\u{1EF8}', false, &['Y', '\u{303}']),
+    ('\u{1EF9}', false, &['y', '\u{303}']),
+    ('\u{1F00}', false, &['\u{3B1}', '\u{313}']),
+    ('\u{1F01}', false, &['\u{3B1}', '\u{314}']),
+    ('\u{1F02}', false, &['\u{1F00}', '\u{300}']),
+    ('\u{1F03}', false, &['\u{1F01}', '\u{300}']),
+    ('\u{1F04}', false, &['\u{1F00}', '\u{301}']),
+    ('\u{1F05}', false, &['\u{1F01}', '\u{301}']),
+    ('\u{1F06}', false, &['\u{1F00}', '\u{342}']),
+    ('\u{1F07}', false, &['\u{1F01}', '\u{342}']),
+    ('\u{1F08}', false, &['\u{391}', '\u{313}']),
+    ('\u{1F09}', false, &['\u{391}', '\u{314}']),
+    ('\u{1F0A}', false, &['\u{1F08}', '\u{300}']),
+    ('\u{1F0B}', false, &['\u{1F09}', '\u{300}']),
+    ('\u{1F0C}', false, &['\u{1F08}', '\u{301}']),
+    ('\u{1F0D}', false, &['\u{1F09}', '\u{301}']),
+    ('\u{1F0E}', false, &['\u{1F08}', '\u{342}']),
+    ('\u{1F0F}', false, &['\u{1F09}', '\u{342}']),
+    ('\u{1F10}', false, &['\u{3B5}', '\u{313}']),
+    ('\u{1F11}', false, &['\u{3B5}', '\u{314}']),
+    ('\u{1F12}', false, &['\u{1F10}', '\u{300}']),
+    ('\u{1F13}', false, &['\u{1F11}', '\u{300}']),
+    ('\u{1F14}', false, &['\u{1F10}', '\u{301}']),
+    ('\u{1F15}', false, &['\u{1F11}', '\u{301}']),
+    ('\u{1F18}', false, &['\u{395}', '\u{313}']),
+    ('\u{1F19}', false, &['\u{395}', '\u{314}']),
+    ('\u{1F1A}', false, &['\u{1F18}', '\u{300}']),
+    ('\u{1F1B}', false, &['\u{1F19}', '\u{300}']),
+    ('\u{1F1C}', false, &['\u{1F18}', '\u{301}']),
+    ('\u{1F1D}', false, &['\u{1F19}', '\u{301}']),
+    ('\u{1F20}', false, &['\u{3B7}', '\u{313}']),
+    ('\u{1F21}', false, &['\u{3B7}', '\u{314}']),
+    ('\u{1F22}', false, &['\u{1F20}', '\u{300}']),
+    ('\u{1F23}', false, &['\u{1F21}', '\u{300}']),
+    ('\u{1F24}', false, &['\u{1F20}', '\u{301}']),
+    ('\u{1F25}', false, &['\u{1F21}', '\u{301}']),
+    ('\u{1F26}', false, &['\u{1F20}', '\u{342}']),
+    ('\u{1F27}', false, &['\u{1F21}', '\u{342}']),
+    ('\u{1F28}', false, &['\u{397}', '\u{313}']),
+    ('\u{1F29}', false, &['\u{397}', '\u{314}']),
+    ('\u{1F2A}', false, &['\u{1F28}', '\u{300}']),
+    ('\u{1F2B}', false, &['\u{1F29}', '\u{300}']),
+    ('\u{1F2C}', false, &['\u{1F28}', '\u{301}']),
+    ('\u{1F2D}', false, &['\u{1F29}', '\u{301}']),
+    ('\u{1F2E}', false, &['\u{1F28}', '\u{342}']),
+    ('\u{1F2F}', false, &['\u{1F29}', '\u{342}']),
+    ('\u{1F30}', false, &['\u{3B9}', '\u{313}']),
+    ('\u{1F31}', false, &['\u{3B9}', '\u{314}']),
+    ('\u{1F32}', false, &['\u{1F30}', '\u{300}']),
+    ('\u{1F33}', false, &['\u{1F31}', '\u{300}']),
+    ('\u{1F34}', false, &['\u{1F30}', '\u{301}']),
+    ('\u{1F35}', false, &['\u{1F31}', '\u{301}']),
+    ('\u{1F36}', false, &['\u{1F30}', '\u{342}']),
+    ('\u{1F37}', false, &['\u{1F31}', '\u{342}']),
+    ('\u{1F38}', false, &['\u{399}', '\u{313}']),
+    ('\u{1F39}', false, &['\u{399}', '\u{314}']),
+    ('\u{1F3A}', false, &['\u{1F38}', '\u{300}']),
+    ('\u{1F3B}', false, &['\u{1F39}', '\u{300}']),
+    ('\u{1F3C}', false, &['\u{1F38}', '\u{301}']),
+    ('\u{1F3D}', false, &['\u{1F39}', '\u{301}']),
+    ('\u{1F3E}', false, &['\u{1F38}', '\u{342}']),
+    ('\u{1F3F}', false, &['\u{1F39}', '\u{342}']),
+    ('\u{1F40}', false, &['\u{3BF}', '\u{313}']),
+    ('\u{1F41}', false, &['\u{3BF}', '\u{314}']),
+    ('\u{1F42}', false, &['\u{1F40}', '\u{300}']),
+    ('\u{1F43}', false, &['\u{1F41}', '\u{300}']),
+    ('\u{1F44}', false, &['\u{1F40}', '\u{301}']),
+    ('\u{1F45}', false, &['\u{1F41}', '\u{301}']),
+    ('\u{1F48}', false, &['\u{39F}', '\u{313}']),
+    ('\u{1F49}', false, &['\u{39F}', '\u{314}']),
+    ('\u{1F4A}', false, &['\u{1F48}', '\u{300}']),
+    ('\u{1F4B}', false, &['\u{1F49}', '\u{300}']),
+    ('\u{1F4C}', false, &['\u{1F48}', '\u{301}']),
+    ('\u{1F4D}', false, &['\u{1F49}', '\u{301}']),
+    ('\u{1F50}', false, &['\u{3C5}', '\u{313}']),
+    ('\u{1F51}', false, &['\u{3C5}', '\u{314}']),
+    ('\u{1F52}', false, &['\u{1F50}', '\u{300}']),
+    ('\u{1F53}', false, &['\u{1F51}', '\u{300}']),
+    ('\u{1F54}', false, &['\u{1F50}', '\u{301}']),
+    ('\u{1F55}', false, &['\u{1F51}', '\u{301}']),
+    ('\u{1F56}', false, &['\u{1F50}', '\u{342}']),
+    ('\u{1F57}', false, &['\u{1F51}', '\u{342}']),
+    ('\u{1F59}', false, &['\u{3A5}', '\u{314}']),
+    ('\u{1F5B}', false, &['\u{1F59}', '\u{300}']),
+    ('\u{1F5D}', false, &['\u{1F59}', '\u{301}']),
+    ('\u{1F5F}', false, &['\u{1F59}', '\u{342}']),
+    ('\u{1F60}', false, &['\u{3C9}', '\u{313}']),
+    ('\u{1F61}', false, &['\u{3C9}', '\u{314}']),
+    ('\u{1F62}', false, &['\u{1F60}', '\u{300}']),
+    ('\u{1F63}', false, &['\u{1F61}', '\u{300}']),
+    ('\u{1F64}', false, &['\u{1F60}', '\u{301}']),
+    ('\u{1F65}', false, &['\u{1F61}', '\u{301}']),
+    ('\u{1F66}', false, &['\u{1F60}', '\u{342}']),
+    ('\u{1F67}', false, &['\u{1F61}', '\u{342}']),
+    ('\u{1F68}', false, &['\u{3A9}', '\u{313}']),
+    ('\u{1F69}', false, &['\u{3A9}', '\u{314}']),
+    ('\u{1F6A}', false, &['\u{1F68}', '\u{300}']),
+    ('\u{1F6B}', false, &['\u{1F69}', '\u{300}']),
+    ('\u{1F6C}', false, &['\u{1F68}', '\u{301}']),
+    ('\u{1F6D}', false, &['\u{1F69}', '\u{301}']),
+    ('\u{1F6E}', false, &['\u{1F68}', '\u{342}']),
+    ('\u{1F6F}', false, &['\u{1F69}', '\u{342}']),
+    ('\u{1F70}', false, &['\u{3B1}', '\u{300}']),
+    ('\u{1F71}', false, &['\u{3AC}']),
+    ('\u{1F72}', false, &['\u{3B5}', '\u{300}']),
+    ('\u{1F73}', false, &['\u{3AD}']),
+    ('\u{1F74}', false, &['\u{3B7}', '\u{300}']),
+    ('\u{1F75}', false, &['\u{3AE}']),
+    ('\u{1F76}', false, &['\u{3B9}', '\u{300}']),
+    ('\u{1F77}', false, &['\u{3AF}']),
+    ('\u{1F78}', false, &['\u{3BF}', '\u{300}']),
+    ('\u{1F79}', false, &['\u{3CC}']),
+    ('\u{1F7A}', false, &['\u{3C5}', '\u{300}']),
+    ('\u{1F7B}', false, &['\u{3CD}']),
+    ('\u{1F7C}', false, &['\u{3C9}', '\u{300}']),
+    ('\u{1F7D}', false, &['\u{3CE}']),
+    ('\u{1F80}', false, &['\u{1F00}', '\u{345}']),
+    ('\u{1F81}', false, &['\u{1F01}', '\u{345}']),
+    ('\u{1F82}', false, &['\u{1F02}', '\u{345}']),
+    ('\u{1F83}', false, &['\u{1F03}', '\u{345}']),
+    ('\u{1F84}', false, &['\u{1F04}', '\u{345}']),
+    ('\u{1F85}', false, &['\u{1F05}', '\u{345}']),
+    ('\u{1F86}', false, &['\u{1F06}', '\u{345}']),
+    ('\u{1F87}', false, &['\u{1F07}', '\u{345}']),
+    ('\u{1F88}', false, &['\u{1F08}', '\u{345}']),
+    ('\u{1F89}', false, &['\u{1F09}', '\u{345}']),
+    ('\u{1F8A}', false, &['\u{1F0A}', '\u{345}']),
+    ('\u{1F8B}', false, &['\u{1F0B}', '\u{345}']),
+    ('\u{1F8C}', false, &['\u{1F0C}', '\u{345}']),
+    ('\u{1F8D}', false, &['\u{1F0D}', '\u{345}']),
+    ('\u{1F8E}', false, &['\u{1F0E}', '\u{345}']),
+    ('\u{1F8F}', false, &['\u{1F0F}', '\u{345}']),
+    ('\u{1F90}', false, &['\u{1F20}', '\u{345}']),
+    ('\u{1F91}', false, &['\u{1F21}', '\u{345}']),
+    ('\u{1F92}', false, &['\u{1F22}', '\u{345}']),
+    ('\u{1F93}', false, &['\u{1F23}', '\u{345}']),
+    ('\u{1F94}', false, &['\u{1F24}', '\u{345}']),
+    ('\u{1F95}', false, &['\u{1F25}', '\u{345}']),
+    ('\u{1F96}', false, &['\u{1F26}', '\u{345}']),
+    ('\u{1F97}', false, &['\u{1F27}', '\u{345}']),
+    ('\u{1F98}', false, &['\u{1F28}', '\u{345}']),
+    ('\u{1F99}', false, &['\u{1F29}', '\u{345}']),
+    ('\u{1F9A}', false, &['\u{1F2A}', '\u{345}']),
+    ('\u{1F9B}', false, &['\u{1F2B}', '\u{345}']),
+    ('\u{1F9C}', false, &['\u{1F2C}', '\u{345}']),
+    ('\u{1F9D}', false, &['\u{1F2D}', '\u{345}']),
+    ('\u{1F9E}', false, &['\u{1F2E}', '\u{345}']),
+    ('\u{1F9F}', false, &['\u{1F2F}', '\u{345}']),
+    ('\u{1FA0}', false, &['\u{1F60}', '\u{345}']),
+    ('\u{1FA1}', false, &['\u{1F61}', '\u{345}']),
+    ('\u{1FA2}', false, &['\u{1F62}', '\u{345}']),
+    ('\u{1FA3}', false, &['\u{1F63}', '\u{345}']),
+    ('\u{1FA4}', false, &['\u{1F64}', '\u{345}']),
+    ('\u{1FA5}', false, &['\u{1F65}', '\u{345}']),
+    ('\u{1FA6}', false, &['\u{1F66}', '\u{345}']),
+    ('\u{1FA7}', false, &['\u{1F67}', '\u{345}']),
+    ('\u{1FA8}', false, &['\u{1F68}', '\u{345}']),
+    ('\u{1FA9}', false, &['\u{1F69}', '\u{345}']),
+    ('\u{1FAA}', false, &['\u{1F6A}', '\u{345}']),
+    ('\u{1FAB}', false, &['\u{1F6B}', '\u{345}']),
+    ('\u{1FAC}', false, &['\u{1F6C}', '\u{345}']),
+    ('\u{1FAD}', false, &['\u{1F6D}', '\u{345}']),
+    ('\u{1FAE}', false, &['\u{1F6E}', '\u{345}']),
+    ('\u{1FAF}', false, &['\u{1F6F}', '\u{345}']),
+    ('\u{1FB0}', false, &['\u{3B1}', '\u{306}']),
+    ('\u{1FB1}', false, &['\u{3B1}', '\u{304}']),
+    ('\u{1FB2}', false, &['\u{1F70}', '\u{345}']),
+    ('\u{1FB3}', false, &['\u{3B1}', '\u{345}']),
+    ('\u{1FB4}', false, &['\u{3AC}', '\u{345}']),
+    ('\u{1FB6}', false, &['\u{3B1}', '\u{342}']),
+    ('\u{1FB7}', false, &['\u{1FB6}', '\u{345}']),
+    ('\u{1FB8}', false, &['\u{391}', '\u{306}']),
+    ('\u{1FB9}', false, &['\u{391}', '\u{304}']),
+    ('\u{1FBA}', false, &['\u{391}', '\u{300}']),
+    ('\u{1FBB}', false, &['\u{386}']),
+    ('\u{1FBC}', false, &['\u{391}', '\u{345}']),
+    ('\u{1FBD}', true, &['\u{20}', '\u{313}']),
+    ('\u{1FBE}', false, &['\u{3B9}']),
+    ('\u{1FBF}', true, &['\u{20}', '\u{313}']),
+    ('\u{1FC0}', true, &['\u{20}', '\u{342}']),
+    ('\u{1FC1}', false, &['\u{A8}', '\u{342}']),
+    ('\u{1FC2}', false, &['\u{1F74}', '\u{345}']),
+    ('\u{1FC3}', false, &['\u{3B7}', '\u{345}']),
+    ('\u{1FC4}', false, &['\u{3AE}', '\u{345}']),
+    ('\u{1FC6}', false, &['\u{3B7}', '\u{342}']),
+    ('\u{1FC7}', false, &['\u{1FC6}', '\u{345}']),
+    ('\u{1FC8}', false, &['\u{395}', '\u{300}']),
+    ('\u{1FC9}', false, &['\u{388}']),
+    ('\u{1FCA}', false, &['\u{397}', '\u{300}']),
+    ('\u{1FCB}', false, &['\u{389}']),
+    ('\u{1FCC}', false, &['\u{397}', '\u{345}']),
+    ('\u{1FCD}', false, &['\u{1FBF}', '\u{300}']),
+    ('\u{1FCE}', false, &['\u{1FBF}', '\u{301}']),
+    ('\u{1FCF}', false, &['\u{1FBF}', '\u{342}']),
+    ('\u{1FD0}', false, &['\u{3B9}', '\u{306}']),
+    ('\u{1FD1}', false, &['\u{3B9}', '\u{304}']),
+    ('\u{1FD2}', false, &['\u{3CA}', '\u{300}']),
+    ('\u{1FD3}', false, &['\u{390}']),
+    ('\u{1FD6}', false, &['\u{3B9}', '\u{342}']),
+    ('\u{1FD7}', false, &['\u{3CA}', '\u{342}']),
+    ('\u{1FD8}', false, &['\u{399}', '\u{306}']),
+    ('\u{1FD9}', false, &['\u{399}', '\u{304}']),
+    ('\u{1FDA}', false, &['\u{399}', '\u{300}']),
+    ('\u{1FDB}', false, &['\u{38A}']),
+    ('\u{1FDD}', false, &['\u{1FFE}', '\u{300}']),
+    ('\u{1FDE}', false, &['\u{1FFE}', '\u{301}']),
+    ('\u{1FDF}', false, &['\u{1FFE}', '\u{342}']),
+    ('\u{1FE0}', false, &['\u{3C5}', '\u{306}']),
+    ('\u{1FE1}', false, &['\u{3C5}', '\u{304}']),
+    ('\u{1FE2}', false, &['\u{3CB}', '\u{300}']),
+    ('\u{1FE3}', false, &['\u{3B0}']),
+    ('\u{1FE4}', false, &['\u{3C1}', '\u{313}']),
+    ('\u{1FE5}', false, &['\u{3C1}', '\u{314}']),
+    ('\u{1FE6}', false, &['\u{3C5}', '\u{342}']),
+    ('\u{1FE7}', false, &['\u{3CB}', '\u{342}']),
+    ('\u{1FE8}', false, &['\u{3A5}', '\u{306}']),
+    ('\u{1FE9}', false, &['\u{3A5}', '\u{304}']),
+    ('\u{1FEA}', false, &['\u{3A5}', '\u{300}']),
+    ('\u{1FEB}', false, &['\u{38E}']),
+    ('\u{1FEC}', false, &['\u{3A1}', '\u{314}']),
+    ('\u{1FED}', false, &['\u{A8}', '\u{300}']),
+    ('\u{1FEE}', false, &['\u{385}']),
+    ('\u{1FEF}', false, &['`']),
+    ('\u{1FF2}', false, &['\u{1F7C}', '\u{345}']),
+    ('\u{1FF3}', false, &['\u{3C9}', '\u{345}']),
+    ('\u{1FF4}', false, &['\u{3CE}', '\u{345}']),
+    ('\u{1FF6}', false, &['\u{3C9}', '\u{342}']),
+    ('\u{1FF7}', false, &['\u{1FF6}', '\u{345}']),
+    ('\u{1FF8}', false, &['\u{39F}', '\u{300}']),
+    ('\u{1FF9}', false, &['\u{38C}']),
+    ('\u{1FFA}', false, &['\u{3A9}', '\u{300}']),
+    ('\u{1FFB}', false, &['\u{38F}']),
+    ('\u{1FFC}', false, &['\u{3A9}', '\u{345}']),
+    ('\u{1FFD}', false, &['\u{B4}']),
+    ('\u{1FFE}', true, &['\u{20}', '\u{314}']),
+    ('\u{2000}', false, &['\u{2002}']),
+    ('\u{2001}', false, &['\u{2003}']),
+    ('\u{2002}', true, &['\u{20}']),
+    ('\u{2003}', true, &['\u{20}']),
+    ('\u{2004}', true, &['\u{20}']),
+    ('\u{2005}', true, &['\u{20}']),
+    ('\u{2006}', true, &['\u{20}']),
+    ('\u{2007}', true, &['\u{20}']),
+    ('\u{2008}', true, &['\u{20}']),
+    ('\u{2009}', true, &['\u{20}']),
+    ('\u{200A}', true, &['\u{20}']),
+    ('\u{2011}', true, &['\u{2010}']),
+    ('\u{2017}', true, &['\u{20}', '\u{333}']),
+    ('\u{2024}', true, &['.']),
+    ('\u{2025}', true, &['.', '.']),
+    ('\u{2026}', true, &['.', '.', '.']),
+    ('\u{202F}', true, &['\u{20}']),
+    ('\u{2033}', true, &['\u{2032}', '\u{2032}']),
+    ('\u{2034}', true, &['\u{2032}', '\u{2032}', '\u{2032}']),
+    ('\u{2036}', true, &['\u{2035}', '\u{2035}']),
+    ('\u{2037}', true, &['\u{2035}', '\u{2035}', '\u{2035}']),
+    ('\u{203C}', true, &['!', '!']),
+    ('\u{203E}', true, &['\u{20}', '\u{305}']),
+    ('\u{2047}', true, &['?', '?']),
+    ('\u{2048}', true, &['?', '!']),
+    ('\u{2049}', true, &['!', '?']),
+    ('\u{2057}', true, &['\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}']),
+    ('\u{205F}', true, &['\u{20}']),
+    ('\u{2070}', true, &['0']),
+    ('\u{2071}', true, &['i']),
+    ('\u{2074}', true, &['4']),
+    ('\u{2075}', true, &['5']),
+    ('\u{2076}', true, &['6']),
+    ('\u{2077}', true, &['7']),
+    ('\u{2078}', true, &['8']),
+    ('\u{2079}', true, &['9']),
+    ('\u{207A}', true, &['+']),
+    ('\u{207B}', true, &['\u{2212}']),
+    ('\u{207C}', true, &['=']),
+    ('\u{207D}', true, &['(']),
+    ('\u{207E}', true, &[')']),
+    ('\u{207F}', true, &['n']),
+    ('\u{2080}', true, &['0']),
+    ('\u{2081}', true, &['1']),
+    ('\u{2082}', true, &['2']),
+    ('\u{2083}', true, &['3']),
+    ('\u{2084}', true, &['4']),
+    ('\u{2085}', true, &['5']),
+    ('\u{2086}', true, &['6']),
+    ('\u{2087}', true, &['7']),
+    ('\u{2088}', true, &['8']),
+    ('\u{2089}', true, &['9']),
+    ('\u{208A}', true, &['+']),
+    ('\u{208B}', true, &['\u{2212}']),
+    ('\u{208C}', true, &['=']),
+    ('\u{208D}', true, &['(']),
+    ('\u{208E}', true, &[')']),
+    ('\u{2090}', true, &['a']),
+    ('\u{2091}', true, &['e']),
+    ('\u{2092}', true, &['o']),
+    ('\u{2093}', true, &['x']),
+    ('\u{2094}', true, &['\u{259}']),
+    ('\u{2095}', true, &['h']),
+    ('\u{2096}', true, &['k']),
+    ('\u{2097}', true, &['l']),
+    ('\u{2098}', true, &['m']),
+    ('\u{2099}', true, &['n']),
+    ('\u{209A}', true, &['p']),
+    ('\u{209B}', true, &['s']),
+    ('\u{209C}', true, &['t']),
+    ('\u{20A8}', true, &['R', 's']),
+    ('\u{2100}', true, &['a', '/', 'c']),
+    ('\u{2101}', true, &['a', '/', 's']),
+    ('\u{2102}', true, &['C']),
+    ('\u{2103}', true, &['\u{B0}', 'C']),
+    ('\u{2105}', true, &['c', '/', 'o']),
+    ('\u{2106}', true, &['c', '/', 'u']),
+    ('\u{2107}', true, &['\u{190}']),
+    ('\u{2109}', true, &['\u{B0}', 'F']),
+    ('\u{210A}', true, &['g']),
+    ('\u{210B}', true, &['H']),
+    ('\u{210C}', true, &['H']),
+    ('\u{210D}', true, &['H']),
+    ('\u{210E}', true, &['h']),
+    ('\u{210F}', true, &['\u{127}']),
+    ('\u{2110}', true, &['I']),
+    ('\u{2111}', true, &['I']),
+    ('\u{2112}', true, &['L']),
+    ('\u{2113}', true, &['l']),
+    ('\u{2115}', true, &['N']),
+    ('\u{2116}', true, &['N', 'o']),
+    ('\u{2119}', true, &['P']),
+    ('\u{211A}', true, &['Q']),
+    ('\u{211B}', true, &['R']),
+    ('\u{211C}', true, &['R']),
+    ('\u{211D}', true, &['R']),
+    ('\u{2120}', true, &['S', 'M']),
+    ('\u{2121}', true, &['T', 'E', 'L']),
+    ('\u{2122}', true, &['T', 'M']),
+    ('\u{2124}', true, &['Z']),
+    ('\u{2126}', false, &['\u{3A9}']),
+    ('\u{2128}', true, &['Z']),
+    ('\u{212A}', false, &['K']),
+    ('\u{212B}', false, &['\u{C5}']),
+    ('\u{212C}', true, &['B']),
+    ('\u{212D}', true, &['C']),
+    ('\u{212F}', true, &['e']),
+    ('\u{2130}', true, &['E']),
+    ('\u{2131}', true, &['F']),
+    ('\u{2133}', true, &['M']),
+    ('\u{2134}', true, &['o']),
+    ('\u{2135}', true, &['\u{5D0}']),
+    ('\u{2136}', true, &['\u{5D1}']),
+    ('\u{2137}', true, &['\u{5D2}']),
+    ('\u{2138}', true, &['\u{5D3}']),
+    ('\u{2139}', true, &['i']),
+    ('\u{213B}', true, &['F', 'A', 'X']),
+    ('\u{213C}', true, &['\u{3C0}']),
+    ('\u{213D}', true, &['\u{3B3}']),
+    ('\u{213E}', true, &['\u{393}']),
+    ('\u{213F}', true, &['\u{3A0}']),
+    ('\u{2140}', true, &['\u{2211}']),
+    ('\u{2145}', true, &['D']),
+    ('\u{2146}', true, &['d']),
+    ('\u{2147}', true, &['e']),
+    ('\u{2148}', true, &['i']),
+    ('\u{2149}', true, &['j']),
+    ('\u{2150}', true, &['1', '\u{2044}', '7']),
+    ('\u{2151}', true, &['1', '\u{2044}', '9']),
+    ('\u{2152}', true, &['1', '\u{2044}', '1', '0']),
+    ('\u{2153}', true, &['1', '\u{2044}', '3']),
+    ('\u{2154}', true, &['2', '\u{2044}', '3']),
+    ('\u{2155}', true, &['1', '\u{2044}', '5']),
+    ('\u{2156}', true, &['2', '\u{2044}', '5']),
+    ('\u{2157}', true, &['3', '\u{2044}', '5']),
+    ('\u{2158}', true, &['4', '\u{2044}', '5']),
+    ('\u{2159}', true, &['1', '\u{2044}', '6']),
+    ('\u{215A}', true, &['5', '\u{2044}', '6']),
+    ('\u{215B}', true, &['1', '\u{2044}', '8']),
+    ('\u{215C}', true, &['3', '\u{2044}', '8']),
+    ('\u{215D}', true, &['5', '\u{2044}', '8']),
+    ('\u{215E}', true, &['7', '\u{2044}', '8']),
+    ('\u{215F}', true, &['1', '\u{2044}']),
+    ('\u{2160}', true, &['I']),
+    ('\u{2161}', true, &['I', 'I']),
+    ('\u{2162}', true, &['I', 'I', 'I']),
+    ('\u{2163}', true, &['I', 'V']),
+    ('\u{2164}', true, &['V']),
+    ('\u{2165}', true, &['V', 'I']),
+    ('\u{2166}', true, &['V', 'I', 'I']),
+    ('\u{2167}', true, &['V', 'I', 'I', 'I']),
+    ('\u{2168}', true, &['I', 'X']),
+    ('\u{2169}', true, &['X']),
+    ('\u{216A}', true, &['X', 'I']),
+    ('\u{216B}', true, &['X', 'I', 'I']),
+    ('\u{216C}', true, &['L']),
+    ('\u{216D}', true, &['C']),
+    ('\u{216E}', true, &['D']),
+    ('\u{216F}', true, &['M']),
+    ('\u{2170}', true, &['i']),
+    ('\u{2171}', true, &['i', 'i']),
+    ('\u{2172}', true, &['i', 'i', 'i']),
+    ('\u{2173}', true, &['i', 'v']),
+    ('\u{2174}', true, &['v']),
+    ('\u{2175}', true, &['v', 'i']),
+    ('\u{2176}', true, &['v', 'i', 'i']),
+    ('\u{2177}', true, &['v', 'i', 'i', 'i']),
+    ('\u{2178}', true, &['i', 'x']),
+    ('\u{2179}', true, &['x']),
+    ('\u{217A}', true, &['x', 'i']),
+    ('\u{217B}', true, &['x', 'i', 'i']),
+    ('\u{217C}', true, &['l']),
+    ('\u{217D}', true, &['c']),
+    ('\u{217E}', true, &['d']),
+    ('\u{217F}', true, &['m']),
+    ('\u{2189}', true, &['0', '\u{2044}', '3']),
+    ('\u{219A}', false, &['\u{2190}', '\u{338}']),
+    ('\u{219B}', false, &['\u{2192}', '\u{338}']),
+    ('\u{21AE}', false, &['\u{2194}', '\u{338}']),
+    ('\u{21CD}', false, &['\u{21D0}', '\u{338}']),
+    ('\u{21CE}', false, &['\u{21D4}', '\u{338}']),
+    ('\u{21CF}', false, &['\u{21D2}', '\u{338}']),
+    ('\u{2204}', false, &['\u{2203}', '\u{338}']),
+    ('\u{2209}', false, &['\u{2208}', '\u{338}']),
+    ('\u{220C}', false, &['\u{220B}', '\u{338}']),
+    ('\u{2224}', false, &['\u{2223}', '\u{338}']),
+    ('\u{2226}', false, &['\u{2225}', '\u{338}']),
+    ('\u{222C}', true, &['\u{222B}', '\u{222B}']),
+    ('\u{222D}', true, &['\u{222B}', '\u{222B}', '\u{222B}']),
+    ('\u{222F}', true, &['\u{222E}', '\u{222E}']),
+    ('\u{2230}', true, &['\u{222E}', '\u{222E}', '\u{222E}']),
+    ('\u{2241}', false, &['\u{223C}', '\u{338}']),
+    ('\u{2244}', false, &['\u{2243}', '\u{338}']),
+    ('\u{2247}', false, &['\u{2245}', '\u{338}']),
+    ('\u{2249}', false, &['\u{2248}', '\u{338}']),
+    ('\u{2260}', false, &['=', '\u{338}']),
+    ('\u{2262}', false, &['\u{2261}', '\u{338}']),
+    ('\u{226D}', false, &['\u{224D}', '\u{338}']),
+    ('\u{226E}', false, &['<', '\u{338}']),
+    ('\u{226F}', false, &['>', '\u{338}']),
+    ('\u{2270}', false, &['\u{2264}', '\u{338}']),
+    ('\u{2271}', false, &['\u{2265}', '\u{338}']),
+    ('\u{2274}', false, &['\u{2272}', '\u{338}']),
+    ('\u{2275}', false, &['\u{2273}', '\u{338}']),
+    ('\u{2278}', false, &['\u{2276}', '\u{338}']),
+    ('\u{2279}', false, &['\u{2277}', '\u{338}']),
+    ('\u{2280}', false, &['\u{227A}', '\u{338}']),
+    ('\u{2281}', false, &['\u{227B}', '\u{338}']),
+    ('\u{2284}', false, &['\u{2282}', '\u{338}']),
+    ('\u{2285}', false, &['\u{2283}', '\u{338}']),
+    ('\u{2288}', false, &['\u{2286}', '\u{338}']),
+    ('\u{2289}', false, &['\u{2287}', '\u{338}']),
+    ('\u{22AC}', false, &['\u{22A2}', '\u{338}']),
+    ('\u{22AD}', false, &['\u{22A8}', '\u{338}']),
+    ('\u{22AE}', false, &['\u{22A9}', '\u{338}']),
+    ('\u{22AF}', false, &['\u{22AB}', '\u{338}']),
+    ('\u{22E0}', false, &['\u{227C}', '\u{338}']),
+    ('\u{22E1}', false, &['\u{227D}', '\u{338}']),
+    ('\u{22E2}', false, &['\u{2291}', '\u{338}']),
+    ('\u{22E3}', false, &['\u{2292}', '\u{338}']),
+    ('\u{22EA}', false, &['\u{22B2}', '\u{338}']),
+    ('\u{22EB}', false, &['\u{22B3}', '\u{338}']),
+    ('\u{22EC}', false, &['\u{22B4}', '\u{338}']),
+    ('\u{22ED}', false, &['\u{22B5}', '\u{338}']),
+    ('\u{2329}', false, &['\u{3008}']),
+    ('\u{232A}', false, &['\u{3009}']),
+    ('\u{2460}', true, &['1']),
+    ('\u{2461}', true, &['2']),
+    ('\u{2462}', true, &['3']),
+    ('\u{2463}', true, &['4']),
+    ('\u{2464}', true, &['5']),
+    ('\u{2465}', true, &['6']),
+    ('\u{2466}', true, &['7']),
+    ('\u{2467}', true, &['8']),
+    ('\u{2468}', true, &['9']),
+    ('\u{2469}', true, &['1', '0']),
+    ('\u{246A}', true, &['1', '1']),
+    ('\u{246B}', true, &['1', '2']),
+    ('\u{246C}', true, &['1', '3']),
+    ('\u{246D}', true, &['1', '4']),
+    ('\u{246E}', true, &['1', '5']),
+    ('\u{246F}', true, &['1', '6']),
+    ('\u{2470}', true, &['1', '7']),
+    ('\u{2471}', true, &['1', '8']),
+    ('\u{2472}', true, &['1', '9']),
+    ('\u{2473}', true, &['2', '0']),
+    ('\u{2474}', true, &['(', '1', ')']),
+    ('\u{2475}', true, &['(', '2', ')']),
+    ('\u{2476}', true, &['(', '3', ')']),
+    ('\u{2477}', true, &['(', '4', ')']),
+    ('\u{2478}', true, &['(', '5', ')']),
+    ('\u{2479}', true, &['(', '6', ')']),
+    ('\u{247A}', true, &['(', '7', ')']),
+    ('\u{247B}', true, &['(', '8', ')']),
+    ('\u{247C}', true, &['(', '9', ')']),
+    ('\u{247D}', true, &['(', '1', '0', ')']),
+    ('\u{247E}', true, &['(', '1', '1', ')']),
+    ('\u{247F}', true, &['(', '1', '2', ')']),
+    ('\u{2480}', true, &['(', '1', '3', ')']),
+    ('\u{2481}', true, &['(', '1', '4', ')']),
+    ('\u{2482}', true, &['(', '1', '5', ')']),
+    ('\u{2483}', true, &['(', '1', '6', ')']),
+    ('\u{2484}', true, &['(', '1', '7', ')']),
+    ('\u{2485}', true, &['(', '1', '8', ')']),
+    ('\u{2486}', true, &['(', '1', '9', ')']),
+    ('\u{2487}', true, &['(', '2', '0', ')']),
+    ('\u{2488}', true, &['1', '.']),
+    ('\u{2489}', true, &['2', '.']),
+    ('\u{248A}', true, &['3', '.']),
+    ('\u{248B}', true, &['4', '.']),
+    ('\u{248C}', true, &['5', '.']),
+    ('\u{248D}', true, &['6', '.']),
+    ('\u{248E}', true, &['7', '.']),
+    ('\u{248F}', true, &['8', '.']),
+    ('\u{2490}', true, &['9', '.']),
+    ('\u{2491}', true, &['1', '0', '.']),
+    ('\u{2492}', true, &['1', '1', '.']),
+    ('\u{2493}', true, &['1', '2', '.']),
+    ('\u{2494}', true, &['1', '3', '.']),
+    ('\u{2495}', true, &['1', '4', '.']),
+    ('\u{2496}', true, &['1', '5', '.']),
+    ('\u{2497}', true, &['1', '6', '.']),
+    ('\u{2498}', true, &['1', '7', '.']),
+    ('\u{2499}', true, &['1', '8', '.']),
+    ('\u{249A}', true, &['1', '9', '.']),
+    ('\u{249B}', true, &['2', '0', '.']),
+    ('\u{249C}', true, &['(', 'a', ')']),
+    ('\u{249D}', true, &['(', 'b', ')']),
+    ('\u{249E}', true, &['(', 'c', ')']),
+    ('\u{249F}', true, &['(', 'd', ')']),
+    ('\u{24A0}', true, &['(', 'e', ')']),
+    ('\u{24A1}', true, &['(', 'f', ')']),
+    ('\u{24A2}', true, &['(', 'g', ')']),
+    ('\u{24A3}', true, &['(', 'h', ')']),
+    ('\u{24A4}', true, &['(', 'i', ')']),
+    ('\u{24A5}', true, &['(', 'j', ')']),
+    ('\u{24A6}', true, &['(', 'k', ')']),
+    ('\u{24A7}', true, &['(', 'l', ')']),
+    ('\u{24A8}', true, &['(', 'm', ')']),
+    ('\u{24A9}', true, &['(', 'n', ')']),
+    ('\u{24AA}', true, &['(', 'o', ')']),
+    ('\u{24AB}', true, &['(', 'p', ')']),
+    ('\u{24AC}', true, &['(', 'q', ')']),
+    ('\u{24AD}', true, &['(', 'r', ')']),
+    ('\u{24AE}', true, &['(', 's', ')']),
+    ('\u{24AF}', true, &['(', 't', ')']),
+    ('\u{24B0}', true, &['(', 'u', ')']),
+    ('\u{24B1}', true, &['(', 'v', ')']),
+    ('\u{24B2}', true, &['(', 'w', ')']),
+    ('\u{24B3}', true, &['(', 'x', ')']),
+    ('\u{24B4}', true, &['(', 'y', ')']),
+    ('\u{24B5}', true, &['(', 'z', ')']),
+    ('\u{24B6}', true, &['A']),
+    ('\u{24B7}', true, &['B']),
+    ('\u{24B8}', true, &['C']),
+    ('\u{24B9}', true, &['D']),
+    ('\u{24BA}', true, &['E']),
+    ('\u{24BB}', true, &['F']),
+    ('\u{24BC}', true, &['G']),
+    ('\u{24BD}', true, &['H']),
+    ('\u{24BE}', true, &['I']),
+    ('\u{24BF}', true, &['J']),
+    ('\u{24C0}', true, &['K']),
+    ('\u{24C1}', true, &['L']),
+    ('\u{24C2}', true, &['M']),
+    ('\u{24C3}', true, &['N']),
+    ('\u{24C4}', true, &['O']),
+    ('\u{24C5}', true, &['P']),
+    ('\u{24C6}', true, &['Q']),
+    ('\u{24C7}', true, &['R']),
+    ('\u{24C8}', true, &['S']),
+    ('\u{24C9}', true, &['T']),
+    ('\u{24CA}', true, &['U']),
+    ('\u{24CB}', true, &['V']),
+    ('\u{24CC}', true, &['W']),
+    ('\u{24CD}', true, &['X']),
+    ('\u{24CE}', true, &['Y']),
+    ('\u{24CF}', true, &['Z']),
+    ('\u{24D0}', true, &['a']),
+    ('\u{24D1}', true, &['b']),
+    ('\u{24D2}', true, &['c']),
+    ('\u{24D3}', true, &['d']),
+    ('\u{24D4}', true, &['e']),
+    ('\u{24D5}', true, &['f']),
+    ('\u{24D6}', true, &['g']),
+    ('\u{24D7}', true, &['h']),
+    ('\u{24D8}', true, &['i']),
+    ('\u{24D9}', true, &['j']),
+    ('\u{24DA}', true, &['k']),
+    ('\u{24DB}', true, &['l']),
+    ('\u{24DC}', true, &['m']),
+    ('\u{24DD}', true, &['n']),
+    ('\u{24DE}', true, &['o']),
+    ('\u{24DF}', true, &['p']),
+    ('\u{24E0}', true, &['q']),
+    ('\u{24E1}', true, &['r']),
+    ('\u{24E2}', true, &['s']),
+    ('\u{24E3}', true, &['t']),
+    ('\u{24E4}', true, &['u']),
+    ('\u{24E5}', true, &['v']),
+    ('\u{24E6}', true, &['w']),
+    ('\u{24E7}', true, &['x']),
+    ('\u{24E8}', true, &['y']),
+    ('\u{24E9}', true, &['z']),
+    ('\u{24EA}', true, &['0']),
+    ('\u{2A0C}', true, &['\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}']),
+    ('\u{2A74}', true, &[':', ':', '=']),
+    ('\u{2A75}', true, &['=', '=']),
+    ('\u{2A76}', true, &['=', '=', '=']),
+    ('\u{2ADC}', false, &['\u{2ADD}', '\u{338}']),
+    ('\u{2C7C}', true, &['j']),
+    ('\u{2C7D}', true, &['V']),
+    ('\u{2D6F}', true, &['\u{2D61}']),
+    ('\u{2E9F}', true, &['\u{6BCD}']),
+    ('\u{2EF3}', true, &['\u{9F9F}']),
+    ('\u{2F00}', true, &['\u{4E00}']),
+    ('\u{2F01}', true, &['\u{4E28}']),
+    ('\u{2F02}', true, &['\u{4E36}']),
+    ('\u{2F03}', true, &['\u{4E3F}']),
+    ('\u{2F04}', true, &['\u{4E59}']),
+    ('\u{2F05}', true, &['\u{4E85}']),
+    ('\u{2F06}', true, &['\u{4E8C}']),
+    ('\u{2F07}', true, &['\u{4EA0}']),
+    ('\u{2F08}', true, &['\u{4EBA}']),
+    ('\u{2F09}', true, &['\u{513F}']),
+    ('\u{2F0A}', true, &['\u{5165}']),
+    ('\u{2F0B}', true, &['\u{516B}']),
+    ('\u{2F0C}', true, &['\u{5182}']),
+    ('\u{2F0D}', true, &['\u{5196}']),
+    ('\u{2F0E}', true, &['\u{51AB}']),
+    ('\u{2F0F}', true, &['\u{51E0}']),
+    ('\u{2F10}', true, &['\u{51F5}']),
+    ('\u{2F11}', true, &['\u{5200}']),
+    ('\u{2F12}', true, &['\u{529B}']),
+    ('\u{2F13}', true, &['\u{52F9}']),
+    ('\u{2F14}', true, &['\u{5315}']),
+    ('\u{2F15}', true, &['\u{531A}']),
+    ('\u{2F16}', true, &['\u{5338}']),
+    ('\u{2F17}', true, &['\u{5341}']),
+    ('\u{2F18}', true, &['\u{535C}']),
+    ('\u{2F19}', true, &['\u{5369}']),
+    ('\u{2F1A}', true, &['\u{5382}']),
+    ('\u{2F1B}', true, &['\u{53B6}']),
+    ('\u{2F1C}', true, &['\u{53C8}']),
+    ('\u{2F1D}', true, &['\u{53E3}']),
+    ('\u{2F1E}', true, &['\u{56D7}']),
+    ('\u{2F1F}', true, &['\u{571F}']),
+    ('\u{2F20}', true, &['\u{58EB}']),
+    ('\u{2F21}', true, &['\u{5902}']),
+    ('\u{2F22}', true, &['\u{590A}']),
+    ('\u{2F23}', true, &['\u{5915}']),
+    ('\u{2F24}', true, &['\u{5927}']),
+    ('\u{2F25}', true, &['\u{5973}']),
+    ('\u{2F26}', true, &['\u{5B50}']),
+    ('\u{2F27}', true, &['\u{5B80}']),
+    ('\u{2F28}', true, &['\u{5BF8}']),
+    ('\u{2F29}', true, &['\u{5C0F}']),
+    ('\u{2F2A}', true, &['\u{5C22}']),
+    ('\u{2F2B}', true, &['\u{5C38}']),
+    ('\u{2F2C}', true, &['\u{5C6E}']),
+    ('\u{2F2D}', true, &['\u{5C71}']),
+    ('\u{2F2E}', true, &['\u{5DDB}']),
+    ('\u{2F2F}', true, &['\u{5DE5}']),
+    ('\u{2F30}', true, &['\u{5DF1}']),
+    ('\u{2F31}', true, &['\u{5DFE}']),
+    ('\u{2F32}', true, &['\u{5E72}']),
+    ('\u{2F33}', true, &['\u{5E7A}']),
+    ('\u{2F34}', true, &['\u{5E7F}']),
+    ('\u{2F35}', true, &['\u{5EF4}']),
+    ('\u{2F36}', true, &['\u{5EFE}']),
+    ('\u{2F37}', true, &['\u{5F0B}']),
+    ('\u{2F38}', true, &['\u{5F13}']),
+    ('\u{2F39}', true, &['\u{5F50}']),
+    ('\u{2F3A}', true, &['\u{5F61}']),
+    ('\u{2F3B}', true, &['\u{5F73}']),
+    ('\u{2F3C}', true, &['\u{5FC3}']),
+    ('\u{2F3D}', true, &['\u{6208}']),
+    ('\u{2F3E}', true, &['\u{6236}']),
+    ('\u{2F3F}', true, &['\u{624B}']),
+    ('\u{2F40}', true, &['\u{652F}']),
+    ('\u{2F41}', true, &['\u{6534}']),
+    ('\u{2F42}', true, &['\u{6587}']),
+    ('\u{2F43}', true, &['\u{6597}']),
+    ('\u{2F44}', true, &['\u{65A4}']),
+    ('\u{2F45}', true, &['\u{65B9}']),
+    ('\u{2F46}', true, &['\u{65E0}']),
+    ('\u{2F47}', true, &['\u{65E5}']),
+    ('\u{2F48}', true, &['\u{66F0}']),
+    ('\u{2F49}', true, &['\u{6708}']),
+    ('\u{2F4A}', true, &['\u{6728}']),
+    ('\u{2F4B}', true, &['\u{6B20}']),
+    ('\u{2F4C}', true, &['\u{6B62}']),
+    ('\u{2F4D}', true, &['\u{6B79}']),
+    ('\u{2F4E}', true, &['\u{6BB3}']),
+    ('\u{2F4F}', true, &['\u{6BCB}']),
+    ('\u{2F50}', true, &['\u{6BD4}']),
+    ('\u{2F51}', true, &['\u{6BDB}']),
+    ('\u{2F52}', true, &['\u{6C0F}']),
+    ('\u{2F53}', true, &['\u{6C14}']),
+    ('\u{2F54}', true, &['\u{6C34}']),
+    ('\u{2F55}', true, &['\u{706B}']),
+    ('\u{2F56}', true, &['\u{722A}']),
+    ('\u{2F57}', true, &['\u{7236}']),
+    ('\u{2F58}', true, &['\u{723B}']),
+    ('\u{2F59}', true, &['\u{723F}']),
+    ('\u{2F5A}', true, &['\u{7247}']),
+    ('\u{2F5B}', true, &['\u{7259}']),
+    ('\u{2F5C}', true, &['\u{725B}']),
+    ('\u{2F5D}', true, &['\u{72AC}']),
+    ('\u{2F5E}', true, &['\u{7384}']),
+    ('\u{2F5F}', true, &['\u{7389}']),
+    ('\u{2F60}', true, &['\u{74DC}']),
+    ('\u{2F61}', true, &['\u{74E6}']),
+    ('\u{2F62}', true, &['\u{7518}']),
+    ('\u{2F63}', true, &['\u{751F}']),
+    ('\u{2F64}', true, &['\u{7528}']),
+    ('\u{2F65}', true, &['\u{7530}']),
+    ('\u{2F66}', true, &['\u{758B}']),
+    ('\u{2F67}', true, &['\u{7592}']),
+    ('\u{2F68}', true, &['\u{7676}']),
+    ('\u{2F69}', true, &['\u{767D}']),
+    ('\u{2F6A}', true, &['\u{76AE}']),
+    ('\u{2F6B}', true, &['\u{76BF}']),
+    ('\u{2F6C}', true, &['\u{76EE}']),
+    ('\u{2F6D}', true, &['\u{77DB}']),
+    ('\u{2F6E}', true, &['\u{77E2}']),
+    ('\u{2F6F}', true, &['\u{77F3}']),
+    ('\u{2F70}', true, &['\u{793A}']),
+    ('\u{2F71}', true, &['\u{79B8}']),
+    ('\u{2F72}', true, &['\u{79BE}']),
+    ('\u{2F73}', true, &['\u{7A74}']),
+    ('\u{2F74}', true, &['\u{7ACB}']),
+    ('\u{2F75}', true, &['\u{7AF9}']),
+    ('\u{2F76}', true, &['\u{7C73}']),
+    ('\u{2F77}', true, &['\u{7CF8}']),
+    ('\u{2F78}', true, &['\u{7F36}']),
+    ('\u{2F79}', true, &['\u{7F51}']),
+    ('\u{2F7A}', true, &['\u{7F8A}']),
+    ('\u{2F7B}', true, &['\u{7FBD}']),
+    ('\u{2F7C}', true, &['\u{8001}']),
+    ('\u{2F7D}', true, &['\u{800C}']),
+    ('\u{2F7E}', true, &['\u{8012}']),
+    ('\u{2F7F}', true, &['\u{8033}']),
+    ('\u{2F80}', true, &['\u{807F}']),
+    ('\u{2F81}', true, &['\u{8089}']),
+    ('\u{2F82}', true, &['\u{81E3}']),
+    ('\u{2F83}', true, &['\u{81EA}']),
+    ('\u{2F84}', true, &['\u{81F3}']),
+    ('\u{2F85}', true, &['\u{81FC}']),
+    ('\u{2F86}', true, &['\u{820C}']),
+    ('\u{2F87}', true, &['\u{821B}']),
+    ('\u{2F88}', true, &['\u{821F}']),
+    ('\u{2F89}', true, &['\u{826E}']),
+    ('\u{2F8A}', true, &['\u{8272}']),
+    ('\u{2F8B}', true, &['\u{8278}']),
+    ('\u{2F8C}', true, &['\u{864D}']),
+    ('\u{2F8D}', true, &['\u{866B}']),
+    ('\u{2F8E}', true, &['\u{8840}']),
+    ('\u{2F8F}', true, &['\u{884C}']),
+    ('\u{2F90}', true, &['\u{8863}']),
+    ('\u{2F91}', true, &['\u{897E}']),
+    ('\u{2F92}', true, &['\u{898B}']),
+    ('\u{2F93}', true, &['\u{89D2}']),
+    ('\u{2F94}', true, &['\u{8A00}']),
+    ('\u{2F95}', true, &['\u{8C37}']),
+    ('\u{2F96}', true, &['\u{8C46}']),
+    ('\u{2F97}', true, &['\u{8C55}']),
+    ('\u{2F98}', true, &['\u{8C78}']),
+    ('\u{2F99}', true, &['\u{8C9D}']),
+    ('\u{2F9A}', true, &['\u{8D64}']),
+    ('\u{2F9B}', true, &['\u{8D70}']),
+    ('\u{2F9C}', true, &['\u{8DB3}']),
+    ('\u{2F9D}', true, &['\u{8EAB}']),
+    ('\u{2F9E}', true, &['\u{8ECA}']),
+    ('\u{2F9F}', true, &['\u{8F9B}']),
+    ('\u{2FA0}', true, &['\u{8FB0}']),
+    ('\u{2FA1}', true, &['\u{8FB5}']),
+    ('\u{2FA2}', true, &['\u{9091}']),
+    ('\u{2FA3}', true, &['\u{9149}']),
+    ('\u{2FA4}', true, &['\u{91C6}']),
+    ('\u{2FA5}', true, &['\u{91CC}']),
+    ('\u{2FA6}', true, &['\u{91D1}']),
+    ('\u{2FA7}', true, &['\u{9577}']),
+    ('\u{2FA8}', true, &['\u{9580}']),
+    ('\u{2FA9}', true, &['\u{961C}']),
+    ('\u{2FAA}', true, &['\u{96B6}']),
+    ('\u{2FAB}', true, &['\u{96B9}']),
+    ('\u{2FAC}', true, &['\u{96E8}']),
+    ('\u{2FAD}', true, &['\u{9751}']),
+    ('\u{2FAE}', true, &['\u{975E}']),
+    ('\u{2FAF}', true, &['\u{9762}']),
+    ('\u{2FB0}', true, &['\u{9769}']),
+    ('\u{2FB1}', true, &['\u{97CB}']),
+    ('\u{2FB2}', true, &['\u{97ED}']),
+    ('\u{2FB3}', true, &['\u{97F3}']),
+    ('\u{2FB4}', true, &['\u{9801}']),
+    ('\u{2FB5}', true, &['\u{98A8}']),
+    ('\u{2FB6}', true, &['\u{98DB}']),
+    ('\u{2FB7}', true, &['\u{98DF}']),
+    ('\u{2FB8}', true, &['\u{9996}']),
+    ('\u{2FB9}', true, &['\u{9999}']),
+    ('\u{2FBA}', true, &['\u{99AC}']),
+    ('\u{2FBB}', true, &['\u{9AA8}']),
+    ('\u{2FBC}', true, &['\u{9AD8}']),
+    ('\u{2FBD}', true, &['\u{9ADF}']),
+    ('\u{2FBE}', true, &['\u{9B25}']),
+    ('\u{2FBF}', true, &['\u{9B2F}']),
+    ('\u{2FC0}', true, &['\u{9B32}']),
+    ('\u{2FC1}', true, &['\u{9B3C}']),
+    ('\u{2FC2}', true, &['\u{9B5A}']),
+    ('\u{2FC3}', true, &['\u{9CE5}']),
+    ('\u{2FC4}', true, &['\u{9E75}']),
+    ('\u{2FC5}', true, &['\u{9E7F}']),
+    ('\u{2FC6}', true, &['\u{9EA5}']),
+    ('\u{2FC7}', true, &['\u{9EBB}']),
+    ('\u{2FC8}', true, &['\u{9EC3}']),
+    ('\u{2FC9}', true, &['\u{9ECD}']),
+    ('\u{2FCA}', true, &['\u{9ED1}']),
+    ('\u{2FCB}', true, &['\u{9EF9}']),
+    ('\u{2FCC}', true, &['\u{9EFD}']),
+    ('\u{2FCD}', true, &['\u{9F0E}']),
+    ('\u{2FCE}', true, &['\u{9F13}']),
+    ('\u{2FCF}', true, &['\u{9F20}']),
+    ('\u{2FD0}', true, &['\u{9F3B}']),
+    ('\u{2FD1}', true, &['\u{9F4A}']),
+    ('\u{2FD2}', true, &['\u{9F52}']),
+    ('\u{2FD3}', true, &['\u{9F8D}']),
+    ('\u{2FD4}', true, &['\u{9F9C}']),
+    ('\u{2FD5}', true, &['\u{9FA0}']),
+    ('\u{3000}', true, &['\u{20}']),
+    ('\u{3036}', true, &['\u{3012}']),
+    ('\u{3038}', true, &['\u{5341}']),
+    ('\u{3039}', true, &['\u{5344}']),
+    ('\u{303A}', true, &['\u{5345}']),
+    ('\u{304C}', false, &['\u{304B}', '\u{3099}']),
+    ('\u{304E}', false, &['\u{304D}', '\u{3099}']),
+    ('\u{3050}', false, &['\u{304F}', '\u{3099}']),
+    ('\u{3052}', false, &['\u{3051}', '\u{3099}']),
+    ('\u{3054}', false, &['\u{3053}', '\u{3099}']),
+    ('\u{3056}', false, &['\u{3055}', '\u{3099}']),
+    ('\u{3058}', false, &['\u{3057}', '\u{3099}']),
+    ('\u{305A}', false, &['\u{3059}', '\u{3099}']),
+    ('\u{305C}', false, &['\u{305B}', '\u{3099}']),
+    ('\u{305E}', false, &['\u{305D}', '\u{3099}']),
+    ('\u{3060}', false, &['\u{305F}', '\u{3099}']),
+    ('\u{3062}', false, &['\u{3061}', '\u{3099}']),
+    ('\u{3065}', false, &['\u{3064}', '\u{3099}']),
+    ('\u{3067}', false, &['\u{3066}', '\u{3099}']),
+    ('\u{3069}', false, &['\u{3068}', '\u{3099}']),
+    ('\u{3070}', false, &['\u{306F}', '\u{3099}']),
+    ('\u{3071}', false, &['\u{306F}', '\u{309A}']),
+    ('\u{3073}', false, &['\u{3072}', '\u{3099}']),
+    ('\u{3074}', false, &['\u{3072}', '\u{309A}']),
+    ('\u{3076}', false, &['\u{3075}', '\u{3099}']),
+    ('\u{3077}', false, &['\u{3075}', '\u{309A}']),
+    ('\u{3079}', false, &['\u{3078}', '\u{3099}']),
+    ('\u{307A}', false, &['\u{3078}', '\u{309A}']),
+    ('\u{307C}', false, &['\u{307B}', '\u{3099}']),
+    ('\u{307D}', false, &['\u{307B}', '\u{309A}']),
+    ('\u{3094}', false, &['\u{3046}', '\u{3099}']),
+    ('\u{309B}', true, &['\u{20}', '\u{3099}']),
+    ('\u{309C}', true, &['\u{20}', '\u{309A}']),
+    ('\u{309E}', false, &['\u{309D}', '\u{3099}']),
+    ('\u{309F}', true, &['\u{3088}', '\u{308A}']),
+    ('\u{30AC}', false, &['\u{30AB}', '\u{3099}']),
+    ('\u{30AE}', false, &['\u{30AD}', '\u{3099}']),
+    ('\u{30B0}', false, &['\u{30AF}', '\u{3099}']),
+    ('\u{30B2}', false, &['\u{30B1}', '\u{3099}']),
+    ('\u{30B4}', false, &['\u{30B3}', '\u{3099}']),
+    ('\u{30B6}', false, &['\u{30B5}', '\u{3099}']),
+    ('\u{30B8}', false, &['\u{30B7}', '\u{3099}']),
+    ('\u{30BA}', false, &['\u{30B9}', '\u{3099}']),
+    ('\u{30BC}', false, &['\u{30BB}', '\u{3099}']),
+    ('\u{30BE}', false, &['\u{30BD}', '\u{3099}']),
+    ('\u{30C0}', false, &['\u{30BF}', '\u{3099}']),
+    ('\u{30C2}', false, &['\u{30C1}', '\u{3099}']),
+    ('\u{30C5}', false, &['\u{30C4}', '\u{3099}']),
+    ('\u{30C7}', false, &['\u{30C6}', '\u{3099}']),
+    ('\u{30C9}', false, &['\u{30C8}', '\u{3099}']),
+    ('\u{30D0}', false, &['\u{30CF}', '\u{3099}']),
+    ('\u{30D1}', false, &['\u{30CF}', '\u{309A}']),
+    ('\u{30D3}', false, &['\u{30D2}', '\u{3099}']),
+    ('\u{30D4}', false, &['\u{30D2}', '\u{309A}']),
+    ('\u{30D6}', false, &['\u{30D5}', '\u{3099}']),
+    ('\u{30D7}', false, &['\u{30D5}', '\u{309A}']),
+    ('\u{30D9}', false, &['\u{30D8}', '\u{3099}']),
+    ('\u{30DA}', false, &['\u{30D8}', '\u{309A}']),
+    ('\u{30DC}', false, &['\u{30DB}', '\u{3099}']),
+    ('\u{30DD}', false, &['\u{30DB}', '\u{309A}']),
+    ('\u{30F4}', false, &['\u{30A6}', '\u{3099}']),
+    ('\u{30F7}', false, &['\u{30EF}', '\u{3099}']),
+    ('\u{30F8}', false, &['\u{30F0}', '\u{3099}']),
+    ('\u{30F9}', false, &['\u{30F1}', '\u{3099}']),
+    ('\u{30FA}', false, &['\u{30F2}', '\u{3099}']),
+    ('\u{30FE}', false, &['\u{30FD}', '\u{3099}']),
+    ('\u{30FF}', true, &['\u{30B3}', '\u{30C8}']),
+    ('\u{3131}', true, &['\u{1100}']),
+    ('\u{3132}', true, &['\u{1101}']),
+    ('\u{3133}', true, &['\u{11AA}']),
+    ('\u{3134}', true, &['\u{1102}']),
+    ('\u{3135}', true, &['\u{11AC}']),
+    ('\u{3136}', true, &['\u{11AD}']),
+    ('\u{3137}', true, &['\u{1103}']),
+    ('\u{3138}', true, &['\u{1104}']),
+    ('\u{3139}', true, &['\u{1105}']),
+    ('\u{313A}', true, &['\u{11B0}']),
+    ('\u{313B}', true, &['\u{11B1}']),
+    ('\u{313C}', true, &['\u{11B2}']),
+    ('\u{313D}', true, &['\u{11B3}']),
+    ('\u{313E}', true, &['\u{11B4}']),
+    ('\u{313F}', true, &['\u{11B5}']),
+    ('\u{3140}', true, &['\u{111A}']),
+    ('\u{3141}', true, &['\u{1106}']),
+    ('\u{3142}', true, &['\u{1107}']),
+    ('\u{3143}', true, &['\u{1108}']),
+    ('\u{3144}', true, &['\u{1121}']),
+    ('\u{3145}', true, &['\u{1109}']),
+    ('\u{3146}', true, &['\u{110A}']),
+    ('\u{3147}', true, &['\u{110B}']),
+    ('\u{3148}', true, &['\u{110C}']),
+    ('\u{3149}', true, &['\u{110D}']),
+    ('\u{314A}', true, &['\u{110E}']),
+    ('\u{314B}', true, &['\u{110F}']),
+    ('\u{314C}', true, &['\u{1110}']),
+    ('\u{314D}', true, &['\u{1111}']),
+    ('\u{314E}', true, &['\u{1112}']),
+    ('\u{314F}', true, &['\u{1161}']),
+    ('\u{3150}', true, &['\u{1162}']),
+    ('\u{3151}', true, &['\u{1163}']),
+    ('\u{3152}', true, &['\u{1164}']),
+    ('\u{3153}', true, &['\u{1165}']),
+    ('\u{3154}', true, &['\u{1166}']),
+    ('\u{3155}', true, &['\u{1167}']),
+    ('\u{3156}', true, &['\u{1168}']),
+    ('\u{3157}', true, &['\u{1169}']),
+    ('\u{3158}', true, &['\u{116A}']),
+    ('\u{3159}', true, &['\u{116B}']),
+    ('\u{315A}', true, &['\u{116C}']),
+    ('\u{315B}', true, &['\u{116D}']),
+    ('\u{315C}', true, &['\u{116E}']),
+    ('\u{315D}', true, &['\u{116F}']),
+    ('\u{315E}', true, &['\u{1170}']),
+    ('\u{315F}', true, &['\u{1171}']),
+    ('\u{3160}', true, &['\u{1172}']),
+    ('\u{3161}', true, &['\u{1173}']),
+    ('\u{3162}', true, &['\u{1174}']),
+    ('\u{3163}', true, &['\u{1175}']),
+    ('\u{3164}', true, &['\u{1160}']),
+    ('\u{3165}', true, &['\u{1114}']),
+    ('\u{3166}', true, &['\u{1115}']),
+    ('\u{3167}', true, &['\u{11C7}']),
+    ('\u{3168}', true, &['\u{11C8}']),
+    ('\u{3169}', true, &['\u{11CC}']),
+    ('\u{316A}', true, &['\u{11CE}']),
+    ('\u{316B}', true, &['\u{11D3}']),
+    ('\u{316C}', true, &['\u{11D7}']),
+    ('\u{316D}', true, &['\u{11D9}']),
+    ('\u{316E}', true, &['\u{111C}']),
+    ('\u{316F}', true, &['\u{11DD}']),
+    ('\u{3170}', true, &['\u{11DF}']),
+    ('\u{3171}', true, &['\u{111D}']),
+    ('\u{3172}', true, &['\u{111E}']),
+    ('\u{3173}', true, &['\u{1120}']),
+    ('\u{3174}', true, &['\u{1122}']),
+    ('\u{3175}', true, &['\u{1123}']),
+    ('\u{3176}', true, &['\u{1127}']),
+    ('\u{3177}', true, &['\u{1129}']),
+    ('\u{3178}', true, &['\u{112B}']),
+    ('\u{3179}', true, &['\u{112C}']),
+    ('\u{317A}', true, &['\u{112D}']),
+    ('\u{317B}', true, &['\u{112E}']),
+    ('\u{317C}', true, &['\u{112F}']),
+    ('\u{317D}', true, &['\u{1132}']),
+    ('\u{317E}', true, &['\u{1136}']),
+    ('\u{317F}', true, &['\u{1140}']),
+    ('\u{3180}', true, &['\u{1147}']),
+    ('\u{3181}', true, &['\u{114C}']),
+    ('\u{3182}', true, &['\u{11F1}']),
+    ('\u{3183}', true, &['\u{11F2}']),
+    ('\u{3184}', true, &['\u{1157}']),
+    ('\u{3185}', true, &['\u{1158}']),
+    ('\u{3186}', true, &['\u{1159}']),
+    ('\u{3187}', true, &['\u{1184}']),
+    ('\u{3188}', true, &['\u{1185}']),
+    ('\u{3189}', true, &['\u{1188}']),
+    ('\u{318A}', true, &['\u{1191}']),
+    ('\u{318B}', true, &['\u{1192}']),
+    ('\u{318C}', true, &['\u{1194}']),
+    ('\u{318D}', true, &['\u{119E}']),
+    ('\u{318E}', true, &['\u{11A1}']),
+    ('\u{3192}', true, &['\u{4E00}']),
+    ('\u{3193}', true, &['\u{4E8C}']),
+    ('\u{3194}', true, &['\u{4E09}']),
+    ('\u{3195}', true, &['\u{56DB}']),
+    ('\u{3196}', true, &['\u{4E0A}']),
+    ('\u{3197}', true, &['\u{4E2D}']),
+    ('\u{3198}', true, &['\u{4E0B}']),
+    ('\u{3199}', true, &['\u{7532}']),
+    ('\u{319A}', true, &['\u{4E59}']),
+    ('\u{319B}', true, &['\u{4E19}']),
+    ('\u{319C}', true, &['\u{4E01}']),
+    ('\u{319D}', true, &['\u{5929}']),
+    ('\u{319E}', true, &['\u{5730}']),
+    ('\u{319F}', true, &['\u{4EBA}']),
+    ('\u{3200}', true, &['(', '\u{1100}', ')']),
+    ('\u{3201}', true, &['(', '\u{1102}', ')']),
+    ('\u{3202}', true, &['(', '\u{1103}', ')']),
+    ('\u{3203}', true, &['(', '\u{1105}', ')']),
+    ('\u{3204}', true, &['(', '\u{1106}', ')']),
+    ('\u{3205}', true, &['(', '\u{1107}', ')']),
+    ('\u{3206}', true, &['(', '\u{1109}', ')']),
+    ('\u{3207}', true, &['(', '\u{110B}', ')']),
+    ('\u{3208}', true, &['(', '\u{110C}', ')']),
+    ('\u{3209}', true, &['(', '\u{110E}', ')']),
+    ('\u{320A}', true, &['(', '\u{110F}', ')']),
+    ('\u{320B}', true, &['(', '\u{1110}', ')']),
+    ('\u{320C}', true, &['(', '\u{1111}', ')']),
+    ('\u{320D}', true, &['(', '\u{1112}', ')']),
+    ('\u{320E}', true, &['(', '\u{1100}', '\u{1161}', ')']),
+    ('\u{320F}', true, &['(', '\u{1102}', '\u{1161}', ')']),
+    ('\u{3210}', true, &['(', '\u{1103}', '\u{1161}', ')']),
+    ('\u{3211}', true, &['(', '\u{1105}', '\u{1161}', ')']),
+    ('\u{3212}', true, &['(', '\u{1106}', '\u{1161}', ')']),
+    ('\u{3213}', true, &['(', '\u{1107}', '\u{1161}', ')']),
+    ('\u{3214}', true, &['(', '\u{1109}', '\u{1161}', ')']),
+    ('\u{3215}', true, &['(', '\u{110B}', '\u{1161}', ')']),
+    ('\u{3216}', true, &['(', '\u{110C}', '\u{1161}', ')']),
+    ('\u{3217}', true, &['(', '\u{110E}', '\u{1161}', ')']),
+    ('\u{3218}', true, &['(', '\u{110F}', '\u{1161}', ')']),
+    ('\u{3219}', true, &['(', '\u{1110}', '\u{1161}', ')']),
+    ('\u{321A}', true, &['(', '\u{1111}', '\u{1161}', ')']),
+    ('\u{321B}', true, &['(', '\u{1112}', '\u{1161}', ')']),
+    ('\u{321C}', true, &['(', '\u{110C}', '\u{116E}', ')']),
+    ('\u{321D}', true, &['(', '\u{110B}', '\u{1169}', '\u{110C}', '\u{1165}', '\u{11AB}', ')']),
+    ('\u{321E}', true, &['(', '\u{110B}', '\u{1169}', '\u{1112}', '\u{116E}', ')']),
+    ('\u{3220}', true, &['(', '\u{4E00}', ')']),
+    ('\u{3221}', true, &['(', '\u{4E8C}', ')']),
+    ('\u{3222}', true, &['(', '\u{4E09}', ')']),
+    ('\u{3223}', true, &['(', '\u{56DB}', ')']),
+    ('\u{3224}', true, &['(', '\u{4E94}', ')']),
+    ('\u{3225}', true, &['(', '\u{516D}', ')']),
+    ('\u{3226}', true, &['(', '\u{4E03}', ')']),
+    ('\u{3227}', true, &['(', '\u{516B}', ')']),
+    ('\u{3228}', true, &['(', '\u{4E5D}', ')']),
+    ('\u{3229}', true, &['(', '\u{5341}', ')']),
+    ('\u{322A}', true, &['(', '\u{6708}', ')']),
+    ('\u{322B}', true, &['(', '\u{706B}', ')']),
+    ('\u{322C}', true, &['(', '\u{6C34}', ')']),
+    ('\u{322D}', true, &['(', '\u{6728}', ')']),
+    ('\u{322E}', true, &['(', '\u{91D1}', ')']),
+    ('\u{322F}', true, &['(', '\u{571F}', ')']),
+    ('\u{3230}', true, &['(', '\u{65E5}', ')']),
+    ('\u{3231}', true, &['(', '\u{682A}', ')']),
+    ('\u{3232}', true, &['(', '\u{6709}', ')']),
+    ('\u{3233}', true, &['(', '\u{793E}', ')']),
+    ('\u{3234}', true, &['(', '\u{540D}', ')']),
+    ('\u{3235}', true, &['(', '\u{7279}', ')']),
+    ('\u{3236}', true, &['(', '\u{8CA1}', ')']),
+    ('\u{3237}', true, &['(', '\u{795D}', ')']),
+    ('\u{3238}', true, &['(', '\u{52B4}', ')']),
+    ('\u{3239}', true, &['(', '\u{4EE3}', ')']),
+    ('\u{323A}', true, &['(', '\u{547C}', ')']),
+    ('\u{323B}', true, &['(', '\u{5B66}', ')']),
+    ('\u{323C}', true, &['(', '\u{76E3}', ')']),
+    ('\u{323D}', true, &['(', '\u{4F01}', ')']),
+    ('\u{323E}', true, &['(', '\u{8CC7}', ')']),
+    ('\u{323F}', true, &['(', '\u{5354}', ')']),
+    ('\u{3240}', true, &['(', '\u{796D}', ')']),
+    ('\u{3241}', true, &['(', '\u{4F11}', ')']),
+    ('\u{3242}', true, &['(', '\u{81EA}', ')']),
+    ('\u{3243}', true, &['(', '\u{81F3}', ')']),
+    ('\u{3244}', true, &['\u{554F}']),
+    ('\u{3245}', true, &['\u{5E7C}']),
+    ('\u{3246}', true, &['\u{6587}']),
+    ('\u{3247}', true, &['\u{7B8F}']),
+    ('\u{3250}', true, &['P', 'T', 'E']),
+    ('\u{3251}', true, &['2', '1']),
+    ('\u{3252}', true, &['2', '2']),
+    ('\u{3253}', true, &['2', '3']),
+    ('\u{3254}', true, &['2', '4']),
+    ('\u{3255}', true, &['2', '5']),
+    ('\u{3256}', true, &['2', '6']),
+    ('\u{3257}', true, &['2', '7']),
+    ('\u{3258}', true, &['2', '8']),
+    ('\u{3259}', true, &['2', '9']),
+    ('\u{325A}', true, &['3', '0']),
+    ('\u{325B}', true, &['3', '1']),
+    ('\u{325C}', true, &['3', '2']),
+    ('\u{325D}', true, &['3', '3']),
+    ('\u{325E}', true, &['3', '4']),
+    ('\u{325F}', true, &['3', '5']),
+    ('\u{3260}', true, &['\u{1100}']),
+    ('\u{3261}', true, &['\u{1102}']),
+    ('\u{3262}', true, &['\u{1103}']),
+    ('\u{3263}', true, &['\u{1105}']),
+    ('\u{3264}', true, &['\u{1106}']),
+    ('\u{3265}', true, &['\u{1107}']),
+    ('\u{3266}', true, &['\u{1109}']),
+    ('\u{3267}', true, &['\u{110B}']),
+    ('\u{3268}', true, &['\u{110C}']),
+    ('\u{3269}', true, &['\u{110E}']),
+    ('\u{326A}', true, &['\u{110F}']),
+    ('\u{326B}', true, &['\u{1110}']),
+    ('\u{326C}', true, &['\u{1111}']),
+    ('\u{326D}', true, &['\u{1112}']),
+    ('\u{326E}', true, &['\u{1100}', '\u{1161}']),
+    ('\u{326F}', true, &['\u{1102}', '\u{1161}']),
+    ('\u{3270}', true, &['\u{1103}', '\u{1161}']),
+    ('\u{3271}', true, &['\u{1105}', '\u{1161}']),
+    ('\u{3272}', true, &['\u{1106}', '\u{1161}']),
+    ('\u{3273}', true, &['\u{1107}', '\u{1161}']),
+    ('\u{3274}', true, &['\u{1109}', '\u{1161}']),
+    ('\u{3275}', true, &['\u{110B}', '\u{1161}']),
+    ('\u{3276}', true, &['\u{110C}', '\u{1161}']),
+    ('\u{3277}', true, &['\u{110E}', '\u{1161}']),
+    ('\u{3278}', true, &['\u{110F}', '\u{1161}']),
+    ('\u{3279}', true, &['\u{1110}', '\u{1161}']),
+    ('\u{327A}', true, &['\u{1111}', '\u{1161}']),
+    ('\u{327B}', true, &['\u{1112}', '\u{1161}']),
+    ('\u{327C}', true, &['\u{110E}', '\u{1161}', '\u{11B7}', '\u{1100}', '\u{1169}']),
+    ('\u{327D}', true, &['\u{110C}', '\u{116E}', '\u{110B}', '\u{1174}']),
+    ('\u{327E}', true, &['\u{110B}', '\u{116E}']),
+    ('\u{3280}', true, &['\u{4E00}']),
+    ('\u{3281}', true, &['\u{4E8C}']),
+    ('\u{3282}', true, &['\u{4E09}']),
+    ('\u{3283}', true, &['\u{56DB}']),
+    ('\u{3284}', true, &['\u{4E94}']),
+    ('\u{3285}', true, &['\u{516D}']),
+    ('\u{3286}', true, &['\u{4E03}']),
+    ('\u{3287}', true, &['\u{516B}']),
+    ('\u{3288}', true, &['\u{4E5D}']),
+    ('\u{3289}', true, &['\u{5341}']),
+    ('\u{328A}', true, &['\u{6708}']),
+    ('\u{328B}', true, &['\u{706B}']),
+    ('\u{328C}', true, &['\u{6C34}']),
+    ('\u{328D}', true, &['\u{6728}']),
+    ('\u{328E}', true, &['\u{91D1}']),
+    ('\u{328F}', true, &['\u{571F}']),
+    ('\u{3290}', true, &['\u{65E5}']),
+    ('\u{3291}', true, &['\u{682A}']),
+    ('\u{3292}', true, &['\u{6709}']),
+    ('\u{3293}', true, &['\u{793E}']),
+    ('\u{3294}', true, &['\u{540D}']),
+    ('\u{3295}', true, &['\u{7279}']),
+    ('\u{3296}', true, &['\u{8CA1}']),
+    ('\u{3297}', true, &['\u{795D}']),
+    ('\u{3298}', true, &['\u{52B4}']),
+    ('\u{3299}', true, &['\u{79D8}']),
+    ('\u{329A}', true, &['\u{7537}']),
+    ('\u{329B}', true, &['\u{5973}']),
+    ('\u{329C}', true, &['\u{9069}']),
+    ('\u{329D}', true, &['\u{512A}']),
+    ('\u{329E}', true, &['\u{5370}']),
+    ('\u{329F}', true, &['\u{6CE8}']),
+    ('\u{32A0}', true, &['\u{9805}']),
+    ('\u{32A1}', true, &['\u{4F11}']),
+    ('\u{32A2}', true, &['\u{5199}']),
+    ('\u{32A3}', true, &['\u{6B63}']),
+    ('\u{32A4}', true, &['\u{4E0A}']),
+    ('\u{32A5}', true, &['\u{4E2D}']),
+    ('\u{32A6}', true, &['\u{4E0B}']),
+    ('\u{32A7}', true, &['\u{5DE6}']),
+    ('\u{32A8}', true, &['\u{53F3}']),
+    ('\u{32A9}', true, &['\u{533B}']),
+    ('\u{32AA}', true, &['\u{5B97}']),
+    ('\u{32AB}', true, &['\u{5B66}']),
+    ('\u{32AC}', true, &['\u{76E3}']),
+    ('\u{32AD}', true, &['\u{4F01}']),
+    ('\u{32AE}', true, &['\u{8CC7}']),
+    ('\u{32AF}', true, &['\u{5354}']),
+    ('\u{32B0}', true, &['\u{591C}']),
+    ('\u{32B1}', true, &['3', '6']),
+    ('\u{32B2}', true, &['3', '7']),
+    ('\u{32B3}', true, &['3', '8']),
+    ('\u{32B4}', true, &['3', '9']),
+    ('\u{32B5}', true, &['4', '0']),
+    ('\u{32B6}', true, &['4', '1']),
+    ('\u{32B7}', true, &['4', '2']),
+    ('\u{32B8}', true, &['4', '3']),
+    ('\u{32B9}', true, &['4', '4']),
+    ('\u{32BA}', true, &['4', '5']),
+    ('\u{32BB}', true, &['4', '6']),
+    ('\u{32BC}', true, &['4', '7']),
+    ('\u{32BD}', true, &['4', '8']),
+    ('\u{32BE}', true, &['4', '9']),
+    ('\u{32BF}', true, &['5', '0']),
+    ('\u{32C0}', true, &['1', '\u{6708}']),
+    ('\u{32C1}', true, &['2', '\u{6708}']),
+    ('\u{32C2}', true, &['3', '\u{6708}']),
+    ('\u{32C3}', true, &['4', '\u{6708}']),
+    ('\u{32C4}', true, &['5', '\u{6708}']),
+    ('\u{32C5}', true, &['6', '\u{6708}']),
+    ('\u{32C6}', true, &['7', '\u{6708}']),
+    ('\u{32C7}', true, &['8', '\u{6708}']),
+    ('\u{32C8}', true, &['9', '\u{6708}']),
+    ('\u{32C9}', true, &['1', '0', '\u{6708}']),
+    ('\u{32CA}', true, &['1', '1', '\u{6708}']),
+    ('\u{32CB}', true, &['1', '2', '\u{6708}']),
+    ('\u{32CC}', true, &['H', 'g']),
+    ('\u{32CD}', true, &['e', 'r', 'g']),
+    ('\u{32CE}', true, &['e', 'V']),
+    ('\u{32CF}', true, &['L', 'T', 'D']),
+    ('\u{32D0}', true, &['\u{30A2}']),
+    ('\u{32D1}', true, &['\u{30A4}']),
+    ('\u{32D2}', true, &['\u{30A6}']),
+    ('\u{32D3}', true, &['\u{30A8}']),
+    ('\u{32D4}', true, &['\u{30AA}']),
+    ('\u{32D5}', true, &['\u{30AB}']),
+    ('\u{32D6}', true, &['\u{30AD}']),
+    ('\u{32D7}', true, &['\u{30AF}']),
+    ('\u{32D8}', true, &['\u{30B1}']),
+    ('\u{32D9}', true, &['\u{30B3}']),
+    ('\u{32DA}', true, &['\u{30B5}']),
+    ('\u{32DB}', true, &['\u{30B7}']),
+    ('\u{32DC}', true, &['\u{30B9}']),
+    ('\u{32DD}', true, &['\u{30BB}']),
+    ('\u{32DE}', true, &['\u{30BD}']),
+    ('\u{32DF}', true, &['\u{30BF}']),
+    ('\u{32E0}', true, &['\u{30C1}']),
+    ('\u{32E1}', true, &['\u{30C4}']),
+    ('\u{32E2}', true, &['\u{30C6}']),
+    ('\u{32E3}', true, &['\u{30C8}']),
+    ('\u{32E4}', true, &['\u{30CA}']),
+    ('\u{32E5}', true, &['\u{30CB}']),
+    ('\u{32E6}', true, &['\u{30CC}']),
+    ('\u{32E7}', true, &['\u{30CD}']),
+    ('\u{32E8}', true, &['\u{30CE}']),
+    ('\u{32E9}', true, &['\u{30CF}']),
+    ('\u{32EA}', true, &['\u{30D2}']),
+    ('\u{32EB}', true, &['\u{30D5}']),
+    ('\u{32EC}', true, &['\u{30D8}']),
+    ('\u{32ED}', true, &['\u{30DB}']),
+    ('\u{32EE}', true, &['\u{30DE}']),
+    ('\u{32EF}', true, &['\u{30DF}']),
+    ('\u{32F0}', true, &['\u{30E0}']),
+    ('\u{32F1}', true, &['\u{30E1}']),
+    ('\u{32F2}', true, &['\u{30E2}']),
+    ('\u{32F3}', true, &['\u{30E4}']),
+    ('\u{32F4}', true, &['\u{30E6}']),
+    ('\u{32F5}', true, &['\u{30E8}']),
+    ('\u{32F6}', true, &['\u{30E9}']),
+    ('\u{32F7}', true, &['\u{30EA}']),
+    ('\u{32F8}', true, &['\u{30EB}']),
+    ('\u{32F9}', true, &['\u{30EC}']),
+    ('\u{32FA}', true, &['\u{30ED}']),
+    ('\u{32FB}', true, &['\u{30EF}']),
+    ('\u{32FC}', true, &['\u{30F0}']),
+    ('\u{32FD}', true, &['\u{30F1}']),
+    ('\u{32FE}', true, &['\u{30F2}']),
+    ('\u{32FF}', true, &['\u{4EE4}', '\u{548C}']),
+    ('\u{3300}', true, &['\u{30A2}', '\u{30D1}', '\u{30FC}', '\u{30C8}']),
+    ('\u{3301}', true, &['\u{30A2}', '\u{30EB}', '\u{30D5}', '\u{30A1}']),
+    ('\u{3302}', true, &['\u{30A2}', '\u{30F3}', '\u{30DA}', '\u{30A2}']),
+    ('\u{3303}', true, &['\u{30A2}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3304}', true, &['\u{30A4}', '\u{30CB}', '\u{30F3}', '\u{30B0}']),
+    ('\u{3305}', true, &['\u{30A4}', '\u{30F3}', '\u{30C1}']),
+    ('\u{3306}', true, &['\u{30A6}', '\u{30A9}', '\u{30F3}']),
+    ('\u{3307}', true, &['\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C9}']),
+    ('\u{3308}', true, &['\u{30A8}', '\u{30FC}', '\u{30AB}', '\u{30FC}']),
+    ('\u{3309}', true, &['\u{30AA}', '\u{30F3}', '\u{30B9}']),
+    ('\u{330A}', true, &['\u{30AA}', '\u{30FC}', '\u{30E0}']),
+    ('\u{330B}', true, &['\u{30AB}', '\u{30A4}', '\u{30EA}']),
+    ('\u{330C}', true, &['\u{30AB}', '\u{30E9}', '\u{30C3}', '\u{30C8}']),
+    ('\u{330D}', true, &['\u{30AB}', '\u{30ED}', '\u{30EA}', '\u{30FC}']),
+    ('\u{330E}', true, &['\u{30AC}', '\u{30ED}', '\u{30F3}']),
+    ('\u{330F}', true, &['\u{30AC}', '\u{30F3}', '\u{30DE}']),
+    ('\u{3310}', true, &['\u{30AE}', '\u{30AC}']),
+    ('\u{3311}', true, &['\u{30AE}', '\u{30CB}', '\u{30FC}']),
+    ('\u{3312}', true, &['\u{30AD}', '\u{30E5}', '\u{30EA}', '\u{30FC}']),
+    ('\u{3313}', true, &['\u{30AE}', '\u{30EB}', '\u{30C0}', '\u{30FC}']),
+    ('\u{3314}', true, &['\u{30AD}', '\u{30ED}']),
+    ('\u{3315}', true, &['\u{30AD}', '\u{30ED}', '\u{30B0}', '\u{30E9}', '\u{30E0}']),
+    ('\u{3316}', true, &['\u{30AD}', '\u{30ED}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}']),
+    ('\u{3317}', true, &['\u{30AD}', '\u{30ED}', '\u{30EF}', '\u{30C3}', '\u{30C8}']),
+    ('\u{3318}', true, &['\u{30B0}', '\u{30E9}', '\u{30E0}']),
+    ('\u{3319}', true, &['\u{30B0}', '\u{30E9}', '\u{30E0}', '\u{30C8}', '\u{30F3}']),
+    ('\u{331A}', true, &['\u{30AF}', '\u{30EB}', '\u{30BC}', '\u{30A4}', '\u{30ED}']),
+    ('\u{331B}', true, &['\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}']),
+    ('\u{331C}', true, &['\u{30B1}', '\u{30FC}', '\u{30B9}']),
+    ('\u{331D}', true, &['\u{30B3}', '\u{30EB}', '\u{30CA}']),
+    ('\u{331E}', true, &['\u{30B3}', '\u{30FC}', '\u{30DD}']),
+    ('\u{331F}', true, &['\u{30B5}', '\u{30A4}', '\u{30AF}', '\u{30EB}']),
+    ('\u{3320}', true, &['\u{30B5}', '\u{30F3}', '\u{30C1}', '\u{30FC}', '\u{30E0}']),
+    ('\u{3321}', true, &['\u{30B7}', '\u{30EA}', '\u{30F3}', '\u{30B0}']),
+    ('\u{3322}', true, &['\u{30BB}', '\u{30F3}', '\u{30C1}']),
+    ('\u{3323}', true, &['\u{30BB}', '\u{30F3}', '\u{30C8}']),
+    ('\u{3324}', true, &['\u{30C0}', '\u{30FC}', '\u{30B9}']),
+    ('\u{3325}', true, &['\u{30C7}', '\u{30B7}']),
+    ('\u{3326}', true, &['\u{30C9}', '\u{30EB}']),
+    ('\u{3327}', true, &['\u{30C8}', '\u{30F3}']),
+    ('\u{3328}', true, &['\u{30CA}', '\u{30CE}']),
+    ('\u{3329}', true, &['\u{30CE}', '\u{30C3}', '\u{30C8}']),
+    ('\u{332A}', true, &['\u{30CF}', '\u{30A4}', '\u{30C4}']),
+    ('\u{332B}', true, &['\u{30D1}', '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}']),
+    ('\u{332C}', true, &['\u{30D1}', '\u{30FC}', '\u{30C4}']),
+    ('\u{332D}', true, &['\u{30D0}', '\u{30FC}', '\u{30EC}', '\u{30EB}']),
+    ('\u{332E}', true, &['\u{30D4}', '\u{30A2}', '\u{30B9}', '\u{30C8}', '\u{30EB}']),
+    ('\u{332F}', true, &['\u{30D4}', '\u{30AF}', '\u{30EB}']),
+    ('\u{3330}', true, &['\u{30D4}', '\u{30B3}']),
+    ('\u{3331}', true, &['\u{30D3}', '\u{30EB}']),
+    ('\u{3332}', true, &['\u{30D5}', '\u{30A1}', '\u{30E9}', '\u{30C3}', '\u{30C9}']),
+    ('\u{3333}', true, &['\u{30D5}', '\u{30A3}', '\u{30FC}', '\u{30C8}']),
+    ('\u{3334}', true, &['\u{30D6}', '\u{30C3}', '\u{30B7}', '\u{30A7}', '\u{30EB}']),
+    ('\u{3335}', true, &['\u{30D5}', '\u{30E9}', '\u{30F3}']),
+    ('\u{3336}', true, &['\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3337}', true, &['\u{30DA}', '\u{30BD}']),
+    ('\u{3338}', true, &['\u{30DA}', '\u{30CB}', '\u{30D2}']),
+    ('\u{3339}', true, &['\u{30D8}', '\u{30EB}', '\u{30C4}']),
+    ('\u{333A}', true, &['\u{30DA}', '\u{30F3}', '\u{30B9}']),
+    ('\u{333B}', true, &['\u{30DA}', '\u{30FC}', '\u{30B8}']),
+    ('\u{333C}', true, &['\u{30D9}', '\u{30FC}', '\u{30BF}']),
+    ('\u{333D}', true, &['\u{30DD}', '\u{30A4}', '\u{30F3}', '\u{30C8}']),
+    ('\u{333E}', true, &['\u{30DC}', '\u{30EB}', '\u{30C8}']),
+    ('\u{333F}', true, &['\u{30DB}', '\u{30F3}']),
+    ('\u{3340}', true, &['\u{30DD}', '\u{30F3}', '\u{30C9}']),
+    ('\u{3341}', true, &['\u{30DB}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3342}', true, &['\u{30DB}', '\u{30FC}', '\u{30F3}']),
+    ('\u{3343}', true, &['\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}']),
+    ('\u{3344}', true, &['\u{30DE}', '\u{30A4}', '\u{30EB}']),
+    ('\u{3345}', true, &['\u{30DE}', '\u{30C3}', '\u{30CF}']),
+    ('\u{3346}', true, &['\u{30DE}', '\u{30EB}', '\u{30AF}']),
+    ('\u{3347}', true, &['\u{30DE}', '\u{30F3}', '\u{30B7}', '\u{30E7}', '\u{30F3}']),
+    ('\u{3348}', true, &['\u{30DF}', '\u{30AF}', '\u{30ED}', '\u{30F3}']),
+    ('\u{3349}', true, &['\u{30DF}', '\u{30EA}']),
+    ('\u{334A}', true, &['\u{30DF}', '\u{30EA}', '\u{30D0}', '\u{30FC}', '\u{30EB}']),
+    ('\u{334B}', true, &['\u{30E1}', '\u{30AC}']),
+    ('\u{334C}', true, &['\u{30E1}', '\u{30AC}', '\u{30C8}', '\u{30F3}']),
+    ('\u{334D}', true, &['\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}']),
+    ('\u{334E}', true, &['\u{30E4}', '\u{30FC}', '\u{30C9}']),
+    ('\u{334F}', true, &['\u{30E4}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3350}', true, &['\u{30E6}', '\u{30A2}', '\u{30F3}']),
+    ('\u{3351}', true, &['\u{30EA}', '\u{30C3}', '\u{30C8}', '\u{30EB}']),
+    ('\u{3352}', true, &['\u{30EA}', '\u{30E9}']),
+    ('\u{3353}', true, &['\u{30EB}', '\u{30D4}', '\u{30FC}']),
+    ('\u{3354}', true, &['\u{30EB}', '\u{30FC}', '\u{30D6}', '\u{30EB}']),
+    ('\u{3355}', true, &['\u{30EC}', '\u{30E0}']),
+    ('\u{3356}', true, &['\u{30EC}', '\u{30F3}', '\u{30C8}', '\u{30B2}', '\u{30F3}']),
+    ('\u{3357}', true, &['\u{30EF}', '\u{30C3}', '\u{30C8}']),
+    ('\u{3358}', true, &['0', '\u{70B9}']),
+    ('\u{3359}', true, &['1', '\u{70B9}']),
+    ('\u{335A}', true, &['2', '\u{70B9}']),
+    ('\u{335B}', true, &['3', '\u{70B9}']),
+    ('\u{335C}', true, &['4', '\u{70B9}']),
+    ('\u{335D}', true, &['5', '\u{70B9}']),
+    ('\u{335E}', true, &['6', '\u{70B9}']),
+    ('\u{335F}', true, &['7', '\u{70B9}']),
+    ('\u{3360}', true, &['8', '\u{70B9}']),
+    ('\u{3361}', true, &['9', '\u{70B9}']),
+    ('\u{3362}', true, &['1', '0', '\u{70B9}']),
+    ('\u{3363}', true, &['1', '1', '\u{70B9}']),
+    ('\u{3364}', true, &['1', '2', '\u{70B9}']),
+    ('\u{3365}', true, &['1', '3', '\u{70B9}']),
+    ('\u{3366}', true, &['1', '4', '\u{70B9}']),
+    ('\u{3367}', true, &['1', '5', '\u{70B9}']),
+    ('\u{3368}', true, &['1', '6', '\u{70B9}']),
+    ('\u{3369}', true, &['1', '7', '\u{70B9}']),
+    ('\u{336A}', true, &['1', '8', '\u{70B9}']),
+    ('\u{336B}', true, &['1', '9', '\u{70B9}']),
+    ('\u{336C}', true, &['2', '0', '\u{70B9}']),
+    ('\u{336D}', true, &['2', '1', '\u{70B9}']),
+    ('\u{336E}', true, &['2', '2', '\u{70B9}']),
+    ('\u{336F}', true, &['2', '3', '\u{70B9}']),
+    ('\u{3370}', true, &['2', '4', '\u{70B9}']),
+    ('\u{3371}', true, &['h', 'P', 'a']),
+    ('\u{3372}', true, &['d', 'a']),
+    ('\u{3373}', true, &['A', 'U']),
+    ('\u{3374}', true, &['b', 'a', 'r']),
+    ('\u{3375}', true, &['o', 'V']),
+    ('\u{3376}', true, &['p', 'c']),
+    ('\u{3377}', true, &['d', 'm']),
+    ('\u{3378}', true, &['d', 'm', '\u{B2}']),
+    ('\u{3379}', true, &['d', 'm', '\u{B3}']),
+    ('\u{337A}', true, &['I', 'U']),
+    ('\u{337B}', true, &['\u{5E73}', '\u{6210}']),
+    ('\u{337C}', true, &['\u{662D}', '\u{548C}']),
+    ('\u{337D}', true, &['\u{5927}', '\u{6B63}']),
+    ('\u{337E}', true, &['\u{660E}', '\u{6CBB}']),
+    ('\u{337F}', true, &['\u{682A}', '\u{5F0F}', '\u{4F1A}', '\u{793E}']),
+    ('\u{3380}', true, &['p', 'A']),
+    ('\u{3381}', true, &['n', 'A']),
+    ('\u{3382}', true, &['\u{3BC}', 'A']),
+    ('\u{3383}', true, &['m', 'A']),
+    ('\u{3384}', true, &['k', 'A']),
+    ('\u{3385}', true, &['K', 'B']),
+    ('\u{3386}', true, &['M', 'B']),
+    ('\u{3387}', true, &['G', 'B']),
+    ('\u{3388}', true, &['c', 'a', 'l']),
+    ('\u{3389}', true, &['k', 'c', 'a', 'l']),
+    ('\u{338A}', true, &['p', 'F']),
+    ('\u{338B}', true, &['n', 'F']),
+    ('\u{338C}', true, &['\u{3BC}', 'F']),
+    ('\u{338D}', true, &['\u{3BC}', 'g']),
+    ('\u{338E}', true, &['m', 'g']),
+    ('\u{338F}', true, &['k', 'g']),
+    ('\u{3390}', true, &['H', 'z']),
+    ('\u{3391}', true, &['k', 'H', 'z']),
+    ('\u{3392}', true, &['M', 'H', 'z']),
+    ('\u{3393}', true, &['G', 'H', 'z']),
+    ('\u{3394}', true, &['T', 'H', 'z']),
+    ('\u{3395}', true, &['\u{3BC}', '\u{2113}']),
+    ('\u{3396}', true, &['m', '\u{2113}']),
+    ('\u{3397}', true, &['d', '\u{2113}']),
+    ('\u{3398}', true, &['k', '\u{2113}']),
+    ('\u{3399}', true, &['f', 'm']),
+    ('\u{339A}', true, &['n', 'm']),
+    ('\u{339B}', true, &['\u{3BC}', 'm']),
+    ('\u{339C}', true, &['m', 'm']),
+    ('\u{339D}', true, &['c', 'm']),
+    ('\u{339E}', true, &['k', 'm']),
+    ('\u{339F}', true, &['m', 'm', '\u{B2}']),
+    ('\u{33A0}', true, &['c', 'm', '\u{B2}']),
+    ('\u{33A1}', true, &['m', '\u{B2}']),
+    ('\u{33A2}', true, &['k', 'm', '\u{B2}']),
+    ('\u{33A3}', true, &['m', 'm', '\u{B3}']),
+    ('\u{33A4}', true, &['c', 'm', '\u{B3}']),
+    ('\u{33A5}', true, &['m', '\u{B3}']),
+    ('\u{33A6}', true, &['k', 'm', '\u{B3}']),
+    ('\u{33A7}', true, &['m', '\u{2215}', 's']),
+    ('\u{33A8}', true, &['m', '\u{2215}', 's', '\u{B2}']),
+    ('\u{33A9}', true, &['P', 'a']),
+    ('\u{33AA}', true, &['k', 'P', 'a']),
+    ('\u{33AB}', true, &['M', 'P', 'a']),
+    ('\u{33AC}', true, &['G', 'P', 'a']),
+    ('\u{33AD}', true, &['r', 'a', 'd']),
+    ('\u{33AE}', true, &['r', 'a', 'd', '\u{2215}', 's']),
+    ('\u{33AF}', true, &['r', 'a', 'd', '\u{2215}', 's', '\u{B2}']),
+    ('\u{33B0}', true, &['p', 's']),
+    ('\u{33B1}', true, &['n', 's']),
+    ('\u{33B2}', true, &['\u{3BC}', 's']),
+    ('\u{33B3}', true, &['m', 's']),
+    ('\u{33B4}', true, &['p', 'V']),
+    ('\u{33B5}', true, &['n', 'V']),
+    ('\u{33B6}', true, &['\u{3BC}', 'V']),
+    ('\u{33B7}', true, &['m', 'V']),
+    ('\u{33B8}', true, &['k', 'V']),
+    ('\u{33B9}', true, &['M', 'V']),
+    ('\u{33BA}', true, &['p', 'W']),
+    ('\u{33BB}', true, &['n', 'W']),
+    ('\u{33BC}', true, &['\u{3BC}', 'W']),
+    ('\u{33BD}', true, &['m', 'W']),
+    ('\u{33BE}', true, &['k', 'W']),
+    ('\u{33BF}', true, &['M', 'W']),
+    ('\u{33C0}', true, &['k', '\u{3A9}']),
+    ('\u{33C1}', true, &['M', '\u{3A9}']),
+    ('\u{33C2}', true, &['a', '.', 'm', '.']),
+    ('\u{33C3}', true, &['B', 'q']),
+    ('\u{33C4}', true, &['c', 'c']),
+    ('\u{33C5}', true, &['c', 'd']),
+    ('\u{33C6}', true, &['C', '\u{2215}', 'k', 'g']),
+    ('\u{33C7}', true, &['C', 'o', '.']),
+    ('\u{33C8}', true, &['d', 'B']),
+    ('\u{33C9}', true, &['G', 'y']),
+    ('\u{33CA}', true, &['h', 'a']),
+    ('\u{33CB}', true, &['H', 'P']),
+    ('\u{33CC}', true, &['i', 'n']),
+    ('\u{33CD}', true, &['K', 'K']),
+    ('\u{33CE}', true, &['K', 'M']),
+    ('\u{33CF}', true, &['k', 't']),
+    ('\u{33D0}', true, &['l', 'm']),
+    ('\u{33D1}', true, &['l', 'n']),
+    ('\u{33D2}', true, &['l', 'o', 'g']),
+    ('\u{33D3}', true, &['l', 'x']),
+    ('\u{33D4}', true, &['m', 'b']),
+    ('\u{33D5}', true, &['m', 'i', 'l']),
+    ('\u{33D6}', true, &['m', 'o', 'l']),
+    ('\u{33D7}', true, &['P', 'H']),
+    ('\u{33D8}', true, &['p', '.', 'm', '.']),
+    ('\u{33D9}', true, &['P', 'P', 'M']),
+    ('\u{33DA}', true, &['P', 'R']),
+    ('\u{33DB}', true, &['s', 'r']),
+    ('\u{33DC}', true, &['S', 'v']),
+    ('\u{33DD}', true, &['W', 'b']),
+    ('\u{33DE}', true, &['V', '\u{2215}', 'm']),
+    ('\u{33DF}', true, &['A', '\u{2215}', 'm']),
+    ('\u{33E0}', true, &['1', '\u{65E5}']),
+    ('\u{33E1}', true, &['2', '\u{65E5}']),
+    ('\u{33E2}', true, &['3', '\u{65E5}']),
+    ('\u{33E3}', true, &['4', '\u{65E5}']),
+    ('\u{33E4}', true, &['5', '\u{65E5}']),
+    ('\u{33E5}', true, &['6', '\u{65E5}']),
+    ('\u{33E6}', true, &['7', '\u{65E5}']),
+    ('\u{33E7}', true, &['8', '\u{65E5}']),
+    ('\u{33E8}', true, &['9', '\u{65E5}']),
+    ('\u{33E9}', true, &['1', '0', '\u{65E5}']),
+    ('\u{33EA}', true, &['1', '1', '\u{65E5}']),
+    ('\u{33EB}', true, &['1', '2', '\u{65E5}']),
+    ('\u{33EC}', true, &['1', '3', '\u{65E5}']),
+    ('\u{33ED}', true, &['1', '4', '\u{65E5}']),
+    ('\u{33EE}', true, &['1', '5', '\u{65E5}']),
+    ('\u{33EF}', true, &['1', '6', '\u{65E5}']),
+    ('\u{33F0}', true, &['1', '7', '\u{65E5}']),
+    ('\u{33F1}', true, &['1', '8', '\u{65E5}']),
+    ('\u{33F2}', true, &['1', '9', '\u{65E5}']),
+    ('\u{33F3}', true, &['2', '0', '\u{65E5}']),
+    ('\u{33F4}', true, &['2', '1', '\u{65E5}']),
+    ('\u{33F5}', true, &['2', '2', '\u{65E5}']),
+    ('\u{33F6}', true, &['2', '3', '\u{65E5}']),
+    ('\u{33F7}', true, &['2', '4', '\u{65E5}']),
+    ('\u{33F8}', true, &['2', '5', '\u{65E5}']),
+    ('\u{33F9}', true, &['2', '6', '\u{65E5}']),
+    ('\u{33FA}', true, &['2', '7', '\u{65E5}']),
+    ('\u{33FB}', true, &['2', '8', '\u{65E5}']),
+    ('\u{33FC}', true, &['2', '9', '\u{65E5}']),
+    ('\u{33FD}', true, &['3', '0', '\u{65E5}']),
+    ('\u{33FE}', true, &['3', '1', '\u{65E5}']),
+    ('\u{33FF}', true, &['g', 'a', 'l']),
+    ('\u{A69C}', true, &['\u{44A}']),
+    ('\u{A69D}', true, &['\u{44C}']),
+    ('\u{A770}', true, &['\u{A76F}']),
+    ('\u{A7F2}', true, &['C']),
+    ('\u{A7F3}', true, &['F']),
+    ('\u{A7F4}', true, &['Q']),
+    ('\u{A7F8}', true, &['\u{126}']),
+    ('\u{A7F9}', true, &['\u{153}']),
+    ('\u{AB5C}', true, &['\u{A727}']),
+    ('\u{AB5D}', true, &['\u{AB37}']),
+    ('\u{AB5E}', true, &['\u{26B}']),
+    ('\u{AB5F}', true, &['\u{AB52}']),
+    ('\u{AB69}', true, &['\u{28D}']),
+    ('\u{F900}', false, &['\u{8C48}']),
+    ('\u{F901}', false, &['\u{66F4}']),
+    ('\u{F902}', false, &['\u{8ECA}']),
+    ('\u{F903}', false, &['\u{8CC8}']),
+    ('\u{F904}', false, &['\u{6ED1}']),
+    ('\u{F905}', false, &['\u{4E32}']),
+    ('\u{F906}', false, &['\u{53E5}']),
+    ('\u{F907}', false, &['\u{9F9C}']),
+    ('\u{F908}', false, &['\u{9F9C}']),
+    ('\u{F909}', false, &['\u{5951}']),
+    ('\u{F90A}', false, &['\u{91D1}']),
+    ('\u{F90B}', false, &['\u{5587}']),
+    ('\u{F90C}', false, &['\u{5948}']),
+    ('\u{F90D}', false, &['\u{61F6}']),
+    ('\u{F90E}', false, &['\u{7669}']),
+    ('\u{F90F}', false, &['\u{7F85}']),
+    ('\u{F910}', false, &['\u{863F}']),
+    ('\u{F911}', false, &['\u{87BA}']),
+    ('\u{F912}', false, &['\u{88F8}']),
+    ('\u{F913}', false, &['\u{908F}']),
+    ('\u{F914}', false, &['\u{6A02}']),
+    ('\u{F915}', false, &['\u{6D1B}']),
+    ('\u{F916}', false, &['\u{70D9}']),
+    ('\u{F917}', false, &['\u{73DE}']),
+    ('\u{F918}', false, &['\u{843D}']),
+    ('\u{F919}', false, &['\u{916A}']),
+    ('\u{F91A}', false, &['\u{99F1}']),
+    ('\u{F91B}', false, &['\u{4E82}']),
+    ('\u{F91C}', false, &['\u{5375}']),
+    ('\u{F91D}', false, &['\u{6B04}']),
+    ('\u{F91E}', false, &['\u{721B}']),
+    ('\u{F91F}', false, &['\u{862D}']),
+    ('\u{F920}', false, &['\u{9E1E}']),
+    ('\u{F921}', false, &['\u{5D50}']),
+    ('\u{F922}', false, &['\u{6FEB}']),
+    ('\u{F923}', false, &['\u{85CD}']),
+    ('\u{F924}', false, &['\u{8964}']),
+    ('\u{F925}', false, &['\u{62C9}']),
+    ('\u{F926}', false, &['\u{81D8}']),
+    ('\u{F927}', false, &['\u{881F}']),
+    ('\u{F928}', false, &['\u{5ECA}']),
+    ('\u{F929}', false, &['\u{6717}']),
+    ('\u{F92A}', false, &['\u{6D6A}']),
+    ('\u{F92B}', false, &['\u{72FC}']),
+    ('\u{F92C}', false, &['\u{90CE}']),
+    ('\u{F92D}', false, &['\u{4F86}']),
+    ('\u{F92E}', false, &['\u{51B7}']),
+    ('\u{F92F}', false, &['\u{52DE}']),
+    ('\u{F930}', false, &['\u{64C4}']),
+    ('\u{F931}', false, &['\u{6AD3}']),
+    ('\u{F932}', false, &['\u{7210}']),
+    ('\u{F933}', false, &['\u{76E7}']),
+    ('\u{F934}', false, &['\u{8001}']),
+    ('\u{F935}', false, &['\u{8606}']),
+    ('\u{F936}', false, &['\u{865C}']),
+    ('\u{F937}', false, &['\u{8DEF}']),
+    ('\u{F938}', false, &['\u{9732}']),
+    ('\u{F939}', false, &['\u{9B6F}']),
+    ('\u{F93A}', false, &['\u{9DFA}']),
+    ('\u{F93B}', false, &['\u{788C}']),
+    ('\u{F93C}', false, &['\u{797F}']),
+    ('\u{F93D}', false, &['\u{7DA0}']),
+    ('\u{F93E}', false, &['\u{83C9}']),
+    ('\u{F93F}', false, &['\u{9304}']),
+    ('\u{F940}', false, &['\u{9E7F}']),
+    ('\u{F941}', false, &['\u{8AD6}']),
+    ('\u{F942}', false, &['\u{58DF}']),
+    ('\u{F943}', false, &['\u{5F04}']),
+    ('\u{F944}', false, &['\u{7C60}']),
+    ('\u{F945}', false, &['\u{807E}']),
+    ('\u{F946}', false, &['\u{7262}']),
+    ('\u{F947}', false, &['\u{78CA}']),
+    ('\u{F948}', false, &['\u{8CC2}']),
+    ('\u{F949}', false, &['\u{96F7}']),
+    ('\u{F94A}', false, &['\u{58D8}']),
+    ('\u{F94B}', false, &['\u{5C62}']),
+    ('\u{F94C}', false, &['\u{6A13}']),
+    ('\u{F94D}', false, &['\u{6DDA}']),
+    ('\u{F94E}', false, &['\u{6F0F}']),
+    ('\u{F94F}', false, &['\u{7D2F}']),
+    ('\u{F950}', false, &['\u{7E37}']),
+    ('\u{F951}', false, &['\u{964B}']),
+    ('\u{F952}', false, &['\u{52D2}']),
+    ('\u{F953}', false, &['\u{808B}']),
+    ('\u{F954}', false, &['\u{51DC}']),
+    ('\u{F955}', false, &['\u{51CC}']),
+    ('\u{F956}', false, &['\u{7A1C}']),
+    ('\u{F957}', false, &['\u{7DBE}']),
+    ('\u{F958}', false, &['\u{83F1}']),
+    ('\u{F959}', false, &['\u{9675}']),
+    ('\u{F95A}', false, &['\u{8B80}']),
+    ('\u{F95B}', false, &['\u{62CF}']),
+    ('\u{F95C}', false, &['\u{6A02}']),
+    ('\u{F95D}', false, &['\u{8AFE}']),
+    ('\u{F95E}', false, &['\u{4E39}']),
+    ('\u{F95F}', false, &['\u{5BE7}']),
+    ('\u{F960}', false, &['\u{6012}']),
+    ('\u{F961}', false, &['\u{7387}']),
+    ('\u{F962}', false, &['\u{7570}']),
+    ('\u{F963}', false, &['\u{5317}']),
+    ('\u{F964}', false, &['\u{78FB}']),
+    ('\u{F965}', false, &['\u{4FBF}']),
+    ('\u{F966}', false, &['\u{5FA9}']),
+    ('\u{F967}', false, &['\u{4E0D}']),
+    ('\u{F968}', false, &['\u{6CCC}']),
+    ('\u{F969}', false, &['\u{6578}']),
+    ('\u{F96A}', false, &['\u{7D22}']),
+    ('\u{F96B}', false, &['\u{53C3}']),
+    ('\u{F96C}', false, &['\u{585E}']),
+    ('\u{F96D}', false, &['\u{7701}']),
+    ('\u{F96E}', false, &['\u{8449}']),
+    ('\u{F96F}', false, &['\u{8AAA}']),
+    ('\u{F970}', false, &['\u{6BBA}']),
+    ('\u{F971}', false, &['\u{8FB0}']),
+    ('\u{F972}', false, &['\u{6C88}']),
+    ('\u{F973}', false, &['\u{62FE}']),
+    ('\u{F974}', false, &['\u{82E5}']),
+    ('\u{F975}', false, &['\u{63A0}']),
+    ('\u{F976}', false, &['\u{7565}']),
+    ('\u{F977}', false, &['\u{4EAE}']),
+    ('\u{F978}', false, &['\u{5169}']),
+    ('\u{F979}', false, &['\u{51C9}']),
+    ('\u{F97A}', false, &['\u{6881}']),
+    ('\u{F97B}', false, &['\u{7CE7}']),
+    ('\u{F97C}', false, &['\u{826F}']),
+    ('\u{F97D}', false, &['\u{8AD2}']),
+    ('\u{F97E}', false, &['\u{91CF}']),
+    ('\u{F97F}', false, &['\u{52F5}']),
+    ('\u{F980}', false, &['\u{5442}']),
+    ('\u{F981}', false, &['\u{5973}']),
+    ('\u{F982}', false, &['\u{5EEC}']),
+    ('\u{F983}', false, &['\u{65C5}']),
+    ('\u{F984}', false, &['\u{6FFE}']),
+    ('\u{F985}', false, &['\u{792A}']),
+    ('\u{F986}', false, &['\u{95AD}']),
+    ('\u{F987}', false, &['\u{9A6A}']),
+    ('\u{F988}', false, &['\u{9E97}']),
+    ('\u{F989}', false, &['\u{9ECE}']),
+    ('\u{F98A}', false, &['\u{529B}']),
+    ('\u{F98B}', false, &['\u{66C6}']),
+    ('\u{F98C}', false, &['\u{6B77}']),
+    ('\u{F98D}', false, &['\u{8F62}']),
+    ('\u{F98E}', false, &['\u{5E74}']),
+    ('\u{F98F}', false, &['\u{6190}']),
+    ('\u{F990}', false, &['\u{6200}']),
+    ('\u{F991}', false, &['\u{649A}']),
+    ('\u{F992}', false, &['\u{6F23}']),
+    ('\u{F993}', false, &['\u{7149}']),
+    ('\u{F994}', false, &['\u{7489}']),
+    ('\u{F995}', false, &['\u{79CA}']),
+    ('\u{F996}', false, &['\u{7DF4}']),
+    ('\u{F997}', false, &['\u{806F}']),
+    ('\u{F998}', false, &['\u{8F26}']),
+    ('\u{F999}', false, &['\u{84EE}']),
+    ('\u{F99A}', false, &['\u{9023}']),
+    ('\u{F99B}', false, &['\u{934A}']),
+    ('\u{F99C}', false, &['\u{5217}']),
+    ('\u{F99D}', false, &['\u{52A3}']),
+    ('\u{F99E}', false, &['\u{54BD}']),
+    ('\u{F99F}', false, &['\u{70C8}']),
+    ('\u{F9A0}', false, &['\u{88C2}']),
+    ('\u{F9A1}', false, &['\u{8AAA}']),
+    ('\u{F9A2}', false, &['\u{5EC9}']),
+    ('\u{F9A3}', false, &['\u{5FF5}']),
+    ('\u{F9A4}', false, &['\u{637B}']),
+    ('\u{F9A5}', false, &['\u{6BAE}']),
+    ('\u{F9A6}', false, &['\u{7C3E}']),
+    ('\u{F9A7}', false, &['\u{7375}']),
+    ('\u{F9A8}', false, &['\u{4EE4}']),
+    ('\u{F9A9}', false, &['\u{56F9}']),
+    ('\u{F9AA}', false, &['\u{5BE7}']),
+    ('\u{F9AB}', false, &['\u{5DBA}']),
+    ('\u{F9AC}', false, &['\u{601C}']),
+    ('\u{F9AD}', false, &['\u{73B2}']),
+    ('\u{F9AE}', false, &['\u{7469}']),
+    ('\u{F9AF}', false, &['\u{7F9A}']),
+    ('\u{F9B0}', false, &['\u{8046}']),
+    ('\u{F9B1}', false, &['\u{9234}']),
+    ('\u{F9B2}', false, &['\u{96F6}']),
+    ('\u{F9B3}', false, &['\u{9748}']),
+    ('\u{F9B4}', false, &['\u{9818}']),
+    ('\u{F9B5}', false, &['\u{4F8B}']),
+    ('\u{F9B6}', false, &['\u{79AE}']),
+    ('\u{F9B7}', false, &['\u{91B4}']),
+    ('\u{F9B8}', false, &['\u{96B8}']),
+    ('\u{F9B9}', false, &['\u{60E1}']),
+    ('\u{F9BA}', false, &['\u{4E86}']),
+    ('\u{F9BB}', false, &['\u{50DA}']),
+    ('\u{F9BC}', false, &['\u{5BEE}']),
+    ('\u{F9BD}', false, &['\u{5C3F}']),
+    ('\u{F9BE}', false, &['\u{6599}']),
+    ('\u{F9BF}', false, &['\u{6A02}']),
+    ('\u{F9C0}', false, &['\u{71CE}']),
+    ('\u{F9C1}', false, &['\u{7642}']),
+    ('\u{F9C2}', false, &['\u{84FC}']),
+    ('\u{F9C3}', false, &['\u{907C}']),
+    ('\u{F9C4}', false, &['\u{9F8D}']),
+    ('\u{F9C5}', false, &['\u{6688}']),
+    ('\u{F9C6}', false, &['\u{962E}']),
+    ('\u{F9C7}', false, &['\u{5289}']),
+    ('\u{F9C8}', false, &['\u{677B}']),
+    ('\u{F9C9}', false, &['\u{67F3}']),
+    ('\u{F9CA}', false, &['\u{6D41}']),
+    ('\u{F9CB}', false, &['\u{6E9C}']),
+    ('\u{F9CC}', false, &['\u{7409}']),
+    ('\u{F9CD}', false, &['\u{7559}']),
+    ('\u{F9CE}', false, &['\u{786B}']),
+    ('\u{F9CF}', false, &['\u{7D10}']),
+    ('\u{F9D0}', false, &['\u{985E}']),
+    ('\u{F9D1}', false, &['\u{516D}']),
+    ('\u{F9D2}', false, &['\u{622E}']),
+    ('\u{F9D3}', false, &['\u{9678}']),
+    ('\u{F9D4}', false, &['\u{502B}']),
+    ('\u{F9D5}', false, &['\u{5D19}']),
+    ('\u{F9D6}', false, &['\u{6DEA}']),
+    ('\u{F9D7}', false, &['\u{8F2A}']),
+    ('\u{F9D8}', false, &['\u{5F8B}']),
+    ('\u{F9D9}', false, &['\u{6144}']),
+    ('\u{F9DA}', false, &['\u{6817}']),
+    ('\u{F9DB}', false, &['\u{7387}']),
+    ('\u{F9DC}', false, &['\u{9686}']),
+    ('\u{F9DD}', false, &['\u{5229}']),
+    ('\u{F9DE}', false, &['\u{540F}']),
+    ('\u{F9DF}', false, &['\u{5C65}']),
+    ('\u{F9E0}', false, &['\u{6613}']),
+    ('\u{F9E1}', false, &['\u{674E}']),
+    ('\u{F9E2}', false, &['\u{68A8}']),
+    ('\u{F9E3}', false, &['\u{6CE5}']),
+    ('\u{F9E4}', false, &['\u{7406}']),
+    ('\u{F9E5}', false, &['\u{75E2}']),
+    ('\u{F9E6}', false, &['\u{7F79}']),
+    ('\u{F9E7}', false, &['\u{88CF}']),
+    ('\u{F9E8}', false, &['\u{88E1}']),
+    ('\u{F9E9}', false, &['\u{91CC}']),
+    ('\u{F9EA}', false, &['\u{96E2}']),
+    ('\u{F9EB}', false, &['\u{533F}']),
+    ('\u{F9EC}', false, &['\u{6EBA}']),
+    ('\u{F9ED}', false, &['\u{541D}']),
+    ('\u{F9EE}', false, &['\u{71D0}']),
+    ('\u{F9EF}', false, &['\u{7498}']),
+    ('\u{F9F0}', false, &['\u{85FA}']),
+    ('\u{F9F1}', false, &['\u{96A3}']),
+    ('\u{F9F2}', false, &['\u{9C57}']),
+    ('\u{F9F3}', false, &['\u{9E9F}']),
+    ('\u{F9F4}', false, &['\u{6797}']),
+    ('\u{F9F5}', false, &['\u{6DCB}']),
+    ('\u{F9F6}', false, &['\u{81E8}']),
+    ('\u{F9F7}', false, &['\u{7ACB}']),
+    ('\u{F9F8}', false, &['\u{7B20}']),
+    ('\u{F9F9}', false, &['\u{7C92}']),
+    ('\u{F9FA}', false, &['\u{72C0}']),
+    ('\u{F9FB}', false, &['\u{7099}']),
+    ('\u{F9FC}', false, &['\u{8B58}']),
+    ('\u{F9FD}', false, &['\u{4EC0}']),
+    ('\u{F9FE}', false, &['\u{8336}']),
+    ('\u{F9FF}', false, &['\u{523A}']),
+    ('\u{FA00}', false, &['\u{5207}']),
+    ('\u{FA01}', false, &['\u{5EA6}']),
+    ('\u{FA02}', false, &['\u{62D3}']),
+    ('\u{FA03}', false, &['\u{7CD6}']),
+    ('\u{FA04}', false, &['\u{5B85}']),
+    ('\u{FA05}', false, &['\u{6D1E}']),
+    ('\u{FA06}', false, &['\u{66B4}']),
+    ('\u{FA07}', false, &['\u{8F3B}']),
+    ('\u{FA08}', false, &['\u{884C}']),
+    ('\u{FA09}', false, &['\u{964D}']),
+    ('\u{FA0A}', false, &['\u{898B}']),
+    ('\u{FA0B}', false, &['\u{5ED3}']),
+    ('\u{FA0C}', false, &['\u{5140}']),
+    ('\u{FA0D}', false, &['\u{55C0}']),
+    ('\u{FA10}', false, &['\u{585A}']),
+    ('\u{FA12}', false, &['\u{6674}']),
+    ('\u{FA15}', false, &['\u{51DE}']),
+    ('\u{FA16}', false, &['\u{732A}']),
+    ('\u{FA17}', false, &['\u{76CA}']),
+    ('\u{FA18}', false, &['\u{793C}']),
+    ('\u{FA19}', false, &['\u{795E}']),
+    ('\u{FA1A}', false, &['\u{7965}']),
+    ('\u{FA1B}', false, &['\u{798F}']),
+    ('\u{FA1C}', false, &['\u{9756}']),
+    ('\u{FA1D}', false, &['\u{7CBE}']),
+    ('\u{FA1E}', false, &['\u{7FBD}']),
+    ('\u{FA20}', false, &['\u{8612}']),
+    ('\u{FA22}', false, &['\u{8AF8}']),
+    ('\u{FA25}', false, &['\u{9038}']),
+    ('\u{FA26}', false, &['\u{90FD}']),
+    ('\u{FA2A}', false, &['\u{98EF}']),
+    ('\u{FA2B}', false, &['\u{98FC}']),
+    ('\u{FA2C}', false, &['\u{9928}']),
+    ('\u{FA2D}', false, &['\u{9DB4}']),
+    ('\u{FA2E}', false, &['\u{90DE}']),
+    ('\u{FA2F}', false, &['\u{96B7}']),
+    ('\u{FA30}', false, &['\u{4FAE}']),
+    ('\u{FA31}', false, &['\u{50E7}']),
+    ('\u{FA32}', false, &['\u{514D}']),
+    ('\u{FA33}', false, &['\u{52C9}']),
+    ('\u{FA34}', false, &['\u{52E4}']),
+    ('\u{FA35}', false, &['\u{5351}']),
+    ('\u{FA36}', false, &['\u{559D}']),
+    ('\u{FA37}', false, &['\u{5606}']),
+    ('\u{FA38}', false, &['\u{5668}']),
+    ('\u{FA39}', false, &['\u{5840}']),
+    ('\u{FA3A}', false, &['\u{58A8}']),
+    ('\u{FA3B}', false, &['\u{5C64}']),
+    ('\u{FA3C}', false, &['\u{5C6E}']),
+    ('\u{FA3D}', false, &['\u{6094}']),
+    ('\u{FA3E}', false, &['\u{6168}']),
+    ('\u{FA3F}', false, &['\u{618E}']),
+    ('\u{FA40}', false, &['\u{61F2}']),
+    ('\u{FA41}', false, &['\u{654F}']),
+    ('\u{FA42}', false, &['\u{65E2}']),
+    ('\u{FA43}', false, &['\u{6691}']),
+    ('\u{FA44}', false, &['\u{6885}']),
+    ('\u{FA45}', false, &['\u{6D77}']),
+    ('\u{FA46}', false, &['\u{6E1A}']),
+    ('\u{FA47}', false, &['\u{6F22}']),
+    ('\u{FA48}', false, &['\u{716E}']),
+    ('\u{FA49}', false, &['\u{722B}']),
+    ('\u{FA4A}', false, &['\u{7422}']),
+    ('\u{FA4B}', false, &['\u{7891}']),
+    ('\u{FA4C}', false, &['\u{793E}']),
+    ('\u{FA4D}', false, &['\u{7949}']),
+    ('\u{FA4E}', false, &['\u{7948}']),
+    ('\u{FA4F}', false, &['\u{7950}']),
+    ('\u{FA50}', false, &['\u{7956}']),
+    ('\u{FA51}', false, &['\u{795D}']),
+    ('\u{FA52}', false, &['\u{798D}']),
+    ('\u{FA53}', false, &['\u{798E}']),
+    ('\u{FA54}', false, &['\u{7A40}']),
+    ('\u{FA55}', false, &['\u{7A81}']),
+    ('\u{FA56}', false, &['\u{7BC0}']),
+    ('\u{FA57}', false, &['\u{7DF4}']),
+    ('\u{FA58}', false, &['\u{7E09}']),
+    ('\u{FA59}', false, &['\u{7E41}']),
+    ('\u{FA5A}', false, &['\u{7F72}']),
+    ('\u{FA5B}', false, &['\u{8005}']),
+    ('\u{FA5C}', false, &['\u{81ED}']),
+    ('\u{FA5D}', false, &['\u{8279}']),
+    ('\u{FA5E}', false, &['\u{8279}']),
+    ('\u{FA5F}', false, &['\u{8457}']),
+    ('\u{FA60}', false, &['\u{8910}']),
+    ('\u{FA61}', false, &['\u{8996}']),
+    ('\u{FA62}', false, &['\u{8B01}']),
+    ('\u{FA63}', false, &['\u{8B39}']),
+    ('\u{FA64}', false, &['\u{8CD3}']),
+    ('\u{FA65}', false, &['\u{8D08}']),
+    ('\u{FA66}', false, &['\u{8FB6}']),
+    ('\u{FA67}', false, &['\u{9038}']),
+    ('\u{FA68}', false, &['\u{96E3}']),
+    ('\u{FA69}', false, &['\u{97FF}']),
+    ('\u{FA6A}', false, &['\u{983B}']),
+    ('\u{FA6B}', false, &['\u{6075}']),
+    ('\u{FA6C}', false, &['\u{242EE}']),
+    ('\u{FA6D}', false, &['\u{8218}']),
+    ('\u{FA70}', false, &['\u{4E26}']),
+    ('\u{FA71}', false, &['\u{51B5}']),
+    ('\u{FA72}', false, &['\u{5168}']),
+    ('\u{FA73}', false, &['\u{4F80}']),
+    ('\u{FA74}', false, &['\u{5145}']),
+    ('\u{FA75}', false, &['\u{5180}']),
+    ('\u{FA76}', false, &['\u{52C7}']),
+    ('\u{FA77}', false, &['\u{52FA}']),
+    ('\u{FA78}', false, &['\u{559D}']),
+    ('\u{FA79}', false, &['\u{5555}']),
+    ('\u{FA7A}', false, &['\u{5599}']),
+    ('\u{FA7B}', false, &['\u{55E2}']),
+    ('\u{FA7C}', false, &['\u{585A}']),
+    ('\u{FA7D}', false, &['\u{58B3}']),
+    ('\u{FA7E}', false, &['\u{5944}']),
+    ('\u{FA7F}', false, &['\u{5954}']),
+    ('\u{FA80}', false, &['\u{5A62}']),
+    ('\u{FA81}', false, &['\u{5B28}']),
+    ('\u{FA82}', false, &['\u{5ED2}']),
+    ('\u{FA83}', false, &['\u{5ED9}']),
+    ('\u{FA84}', false, &['\u{5F69}']),
+    ('\u{FA85}', false, &['\u{5FAD}']),
+    ('\u{FA86}', false, &['\u{60D8}']),
+    ('\u{FA87}', false, &['\u{614E}']),
+    ('\u{FA88}', false, &['\u{6108}']),
+    ('\u{FA89}', false, &['\u{618E}']),
+    ('\u{FA8A}', false, &['\u{6160}']),
+    ('\u{FA8B}', false, &['\u{61F2}']),
+    ('\u{FA8C}', false, &['\u{6234}']),
+    ('\u{FA8D}', false, &['\u{63C4}']),
+    ('\u{FA8E}', false, &['\u{641C}']),
+    ('\u{FA8F}', false, &['\u{6452}']),
+    ('\u{FA90}', false, &['\u{6556}']),
+    ('\u{FA91}', false, &['\u{6674}']),
+    ('\u{FA92}', false, &['\u{6717}']),
+    ('\u{FA93}', false, &['\u{671B}']),
+    ('\u{FA94}', false, &['\u{6756}']),
+    ('\u{FA95}', false, &['\u{6B79}']),
+    ('\u{FA96}', false, &['\u{6BBA}']),
+    ('\u{FA97}', false, &['\u{6D41}']),
+    ('\u{FA98}', false, &['\u{6EDB}']),
+    ('\u{FA99}', false, &['\u{6ECB}']),
+    ('\u{FA9A}', false, &['\u{6F22}']),
+    ('\u{FA9B}', false, &['\u{701E}']),
+    ('\u{FA9C}', false, &['\u{716E}']),
+    ('\u{FA9D}', false, &['\u{77A7}']),
+    ('\u{FA9E}', false, &['\u{7235}']),
+    ('\u{FA9F}', false, &['\u{72AF}']),
+    ('\u{FAA0}', false, &['\u{732A}']),
+    ('\u{FAA1}', false, &['\u{7471}']),
+    ('\u{FAA2}', false, &['\u{7506}']),
+    ('\u{FAA3}', false, &['\u{753B}']),
+    ('\u{FAA4}', false, &['\u{761D}']),
+    ('\u{FAA5}', false, &['\u{761F}']),
+    ('\u{FAA6}', false, &['\u{76CA}']),
+    ('\u{FAA7}', false, &['\u{76DB}']),
+    ('\u{FAA8}', false, &['\u{76F4}']),
+    ('\u{FAA9}', false, &['\u{774A}']),
+    ('\u{FAAA}', false, &['\u{7740}']),
+    ('\u{FAAB}', false, &['\u{78CC}']),
+    ('\u{FAAC}', false, &['\u{7AB1}']),
+    ('\u{FAAD}', false, &['\u{7BC0}']),
+    ('\u{FAAE}', false, &['\u{7C7B}']),
+    ('\u{FAAF}', false, &['\u{7D5B}']),
+    ('\u{FAB0}', false, &['\u{7DF4}']),
+    ('\u{FAB1}', false, &['\u{7F3E}']),
+    ('\u{FAB2}', false, &['\u{8005}']),
+    ('\u{FAB3}', false, &['\u{8352}']),
+    ('\u{FAB4}', false, &['\u{83EF}']),
+    ('\u{FAB5}', false, &['\u{8779}']),
+    ('\u{FAB6}', false, &['\u{8941}']),
+    ('\u{FAB7}', false, &['\u{8986}']),
+    ('\u{FAB8}', false, &['\u{8996}']),
+    ('\u{FAB9}', false, &['\u{8ABF}']),
+    ('\u{FABA}', false, &['\u{8AF8}']),
+    ('\u{FABB}', false, &['\u{8ACB}']),
+    ('\u{FABC}', false, &['\u{8B01}']),
+    ('\u{FABD}', false, &['\u{8AFE}']),
+    ('\u{FABE}', false, &['\u{8AED}']),
+    ('\u{FABF}', false, &['\u{8B39}']),
+    ('\u{FAC0}', false, &['\u{8B8A}']),
+    ('\u{FAC1}', false, &['\u{8D08}']),
+    ('\u{FAC2}', false, &['\u{8F38}']),
+    ('\u{FAC3}', false, &['\u{9072}']),
+    ('\u{FAC4}', false, &['\u{9199}']),
+    ('\u{FAC5}', false, &['\u{9276}']),
+    ('\u{FAC6}', false, &['\u{967C}']),
+    ('\u{FAC7}', false, &['\u{96E3}']),
+    ('\u{FAC8}', false, &['\u{9756}']),
+    ('\u{FAC9}', false, &['\u{97DB}']),
+    ('\u{FACA}', false, &['\u{97FF}']),
+    ('\u{FACB}', false, &['\u{980B}']),
+    ('\u{FACC}', false, &['\u{983B}']),
+    ('\u{FACD}', false, &['\u{9B12}']),
+    ('\u{FACE}', false, &['\u{9F9C}']),
+    ('\u{FACF}', false, &['\u{2284A}']),
+    ('\u{FAD0}', false, &['\u{22844}']),
+    ('\u{FAD1}', false, &['\u{233D5}']),
+    ('\u{FAD2}', false, &['\u{3B9D}']),
+    ('\u{FAD3}', false, &['\u{4018}']),
+    ('\u{FAD4}', false, &['\u{4039}']),
+    ('\u{FAD5}', false, &['\u{25249}']),
+    ('\u{FAD6}', false, &['\u{25CD0}']),
+    ('\u{FAD7}', false, &['\u{27ED3}']),
+    ('\u{FAD8}', false, &['\u{9F43}']),
+    ('\u{FAD9}', false, &['\u{9F8E}']),
+    ('\u{FB00}', true, &['f', 'f']),
+    ('\u{FB01}', true, &['f', 'i']),
+    ('\u{FB02}', true, &['f', 'l']),
+    ('\u{FB03}', true, &['f', 'f', 'i']),
+    ('\u{FB04}', true, &['f', 'f', 'l']),
+    ('\u{FB05}', true, &['\u{17F}', 't']),
+    ('\u{FB06}', true, &['s', 't']),
+    ('\u{FB13}', true, &['\u{574}', '\u{576}']),
+    ('\u{FB14}', true, &['\u{574}', '\u{565}']),
+    ('\u{FB15}', true, &['\u{574}', '\u{56B}']),
+    ('\u{FB16}', true, &['\u{57E}', '\u{576}']),
+    ('\u{FB17}', true, &['\u{574}', '\u{56D}']),
+    ('\u{FB1D}', false, &['\u{5D9}', '\u{5B4}']),
+    ('\u{FB1F}', false, &['\u{5F2}', '\u{5B7}']),
+    ('\u{FB20}', true, &['\u{5E2}']),
+    ('\u{FB21}', true, &['\u{5D0}']),
+    ('\u{FB22}', true, &['\u{5D3}']),
+    ('\u{FB23}', true, &['\u{5D4}']),
+    ('\u{FB24}', true, &['\u{5DB}']),
+    ('\u{FB25}', true, &['\u{5DC}']),
+    ('\u{FB26}', true, &['\u{5DD}']),
+    ('\u{FB27}', true, &['\u{5E8}']),
+    ('\u{FB28}', true, &['\u{5EA}']),
+    ('\u{FB29}', true, &['+']),
+    ('\u{FB2A}', false, &['\u{5E9}', '\u{5C1}']),
+    ('\u{FB2B}', false, &['\u{5E9}', '\u{5C2}']),
+    ('\u{FB2C}', false, &['\u{FB49}', '\u{5C1}']),
+    ('\u{FB2D}', false, &['\u{FB49}', '\u{5C2}']),
+    ('\u{FB2E}', false, &['\u{5D0}', '\u{5B7}']),
+    ('\u{FB2F}', false, &['\u{5D0}', '\u{5B8}']),
+    ('\u{FB30}', false, &['\u{5D0}', '\u{5BC}']),
+    ('\u{FB31}', false, &['\u{5D1}', '\u{5BC}']),
+    ('\u{FB32}', false, &['\u{5D2}', '\u{5BC}']),
+    ('\u{FB33}', false, &['\u{5D3}', '\u{5BC}']),
+    ('\u{FB34}', false, &['\u{5D4}', '\u{5BC}']),
+    ('\u{FB35}', false, &['\u{5D5}', '\u{5BC}']),
+    ('\u{FB36}', false, &['\u{5D6}', '\u{5BC}']),
+    ('\u{FB38}', false, &['\u{5D8}', '\u{5BC}']),
+    ('\u{FB39}', false, &['\u{5D9}', '\u{5BC}']),
+    ('\u{FB3A}', false, &['\u{5DA}', '\u{5BC}']),
+    ('\u{FB3B}', false, &['\u{5DB}', '\u{5BC}']),
+    ('\u{FB3C}', false, &['\u{5DC}', '\u{5BC}']),
+    ('\u{FB3E}', false, &['\u{5DE}', '\u{5BC}']),
+    ('\u{FB40}', false, &['\u{5E0}', '\u{5BC}']),
+    ('\u{FB41}', false, &['\u{5E1}', '\u{5BC}']),
+    ('\u{FB43}', false, &['\u{5E3}', '\u{5BC}']),
+    ('\u{FB44}', false, &['\u{5E4}', '\u{5BC}']),
+    ('\u{FB46}', false, &['\u{5E6}', '\u{5BC}']),
+    ('\u{FB47}', false, &['\u{5E7}', '\u{5BC}']),
+    ('\u{FB48}', false, &['\u{5E8}', '\u{5BC}']),
+    ('\u{FB49}', false, &['\u{5E9}', '\u{5BC}']),
+    ('\u{FB4A}', false, &['\u{5EA}', '\u{5BC}']),
+    ('\u{FB4B}', false, &['\u{5D5}', '\u{5B9}']),
+    ('\u{FB4C}', false, &['\u{5D1}', '\u{5BF}']),
+    ('\u{FB4D}', false, &['\u{5DB}', '\u{5BF}']),
+    ('\u{FB4E}', false, &['\u{5E4}', '\u{5BF}']),
+    ('\u{FB4F}', true, &['\u{5D0}', '\u{5DC}']),
+    ('\u{FB50}', true, &['\u{671}']),
+    ('\u{FB51}', true, &['\u{671}']),
+    ('\u{FB52}', true, &['\u{67B}']),
+    ('\u{FB53}', true, &['\u{67B}']),
+    ('\u{FB54}', true, &['\u{67B}']),
+    ('\u{FB55}', true, &['\u{67B}']),
+    ('\u{FB56}', true, &['\u{67E}']),
+    ('\u{FB57}', true, &['\u{67E}']),
+    ('\u{FB58}', true, &['\u{67E}']),
+    ('\u{FB59}', true, &['\u{67E}']),
+    ('\u{FB5A}', true, &['\u{680}']),
+    ('\u{FB5B}', true, &['\u{680}']),
+    ('\u{FB5C}', true, &['\u{680}']),
+    ('\u{FB5D}', true, &['\u{680}']),
+    ('\u{FB5E}', true, &['\u{67A}']),
+    ('\u{FB5F}', true, &['\u{67A}']),
+    ('\u{FB60}', true, &['\u{67A}']),
+    ('\u{FB61}', true, &['\u{67A}']),
+    ('\u{FB62}', true, &['\u{67F}']),
+    ('\u{FB63}', true, &['\u{67F}']),
+    ('\u{FB64}', true, &['\u{67F}']),
+    ('\u{FB65}', true, &['\u{67F}']),
+    ('\u{FB66}', true, &['\u{679}']),
+    ('\u{FB67}', true, &['\u{679}']),
+    ('\u{FB68}', true, &['\u{679}']),
+    ('\u{FB69}', true, &['\u{679}']),
+    ('\u{FB6A}', true, &['\u{6A4}']),
+    ('\u{FB6B}', true, &['\u{6A4}']),
+    ('\u{FB6C}', true, &['\u{6A4}']),
+    ('\u{FB6D}', true, &['\u{6A4}']),
+    ('\u{FB6E}', true, &['\u{6A6}']),
+    ('\u{FB6F}', true, &['\u{6A6}']),
+    ('\u{FB70}', true, &['\u{6A6}']),
+    ('\u{FB71}', true, &['\u{6A6}']),
+    ('\u{FB72}', true, &['\u{684}']),
+    ('\u{FB73}', true, &['\u{684}']),
+    ('\u{FB74}', true, &['\u{684}']),
+    ('\u{FB75}', true, &['\u{684}']),
+    ('\u{FB76}', true, &['\u{683}']),
+    ('\u{FB77}', true, &['\u{683}']),
+    ('\u{FB78}', true, &['\u{683}']),
+    ('\u{FB79}', true, &['\u{683}']),
+    ('\u{FB7A}', true, &['\u{686}']),
+    ('\u{FB7B}', true, &['\u{686}']),
+    ('\u{FB7C}', true, &['\u{686}']),
+    ('\u{FB7D}', true, &['\u{686}']),
+    ('\u{FB7E}', true, &['\u{687}']),
+    ('\u{FB7F}', true, &['\u{687}']),
+    ('\u{FB80}', true, &['\u{687}']),
+    ('\u{FB81}', true, &['\u{687}']),
+    ('\u{FB82}', true, &['\u{68D}']),
+    ('\u{FB83}', true, &['\u{68D}']),
+    ('\u{FB84}', true, &['\u{68C}']),
+    ('\u{FB85}', true, &['\u{68C}']),
+    ('\u{FB86}', true, &['\u{68E}']),
+    ('\u{FB87}', true, &['\u{68E}']),
+    ('\u{FB88}', true, &['\u{688}']),
+    ('\u{FB89}', true, &['\u{688}']),
+    ('\u{FB8A}', true, &['\u{698}']),
+    ('\u{FB8B}', true, &['\u{698}']),
+    ('\u{FB8C}', true, &['\u{691}']),
+    ('\u{FB8D}', true, &['\u{691}']),
+    ('\u{FB8E}', true, &['\u{6A9}']),
+    ('\u{FB8F}', true, &['\u{6A9}']),
+    ('\u{FB90}', true, &['\u{6A9}']),
+    ('\u{FB91}', true, &['\u{6A9}']),
+    ('\u{FB92}', true, &['\u{6AF}']),
+    ('\u{FB93}', true, &['\u{6AF}']),
+    ('\u{FB94}', true, &['\u{6AF}']),
+    ('\u{FB95}', true, &['\u{6AF}']),
+    ('\u{FB96}', true, &['\u{6B3}']),
+    ('\u{FB97}', true, &['\u{6B3}']),
+    ('\u{FB98}', true, &['\u{6B3}']),
+    ('\u{FB99}', true, &['\u{6B3}']),
+    ('\u{FB9A}', true, &['\u{6B1}']),
+    ('\u{FB9B}', true, &['\u{6B1}']),
+    ('\u{FB9C}', true, &['\u{6B1}']),
+    ('\u{FB9D}', true, &['\u{6B1}']),
+    ('\u{FB9E}', true, &['\u{6BA}']),
+    ('\u{FB9F}', true, &['\u{6BA}']),
+    ('\u{FBA0}', true, &['\u{6BB}']),
+    ('\u{FBA1}', true, &['\u{6BB}']),
+    ('\u{FBA2}', true, &['\u{6BB}']),
+    ('\u{FBA3}', true, &['\u{6BB}']),
+    ('\u{FBA4}', true, &['\u{6C0}']),
+    ('\u{FBA5}', true, &['\u{6C0}']),
+    ('\u{FBA6}', true, &['\u{6C1}']),
+    ('\u{FBA7}', true, &['\u{6C1}']),
+    ('\u{FBA8}', true, &['\u{6C1}']),
+    ('\u{FBA9}', true, &['\u{6C1}']),
+    ('\u{FBAA}', true, &['\u{6BE}']),
+    ('\u{FBAB}', true, &['\u{6BE}']),
+    ('\u{FBAC}', true, &['\u{6BE}']),
+    ('\u{FBAD}', true, &['\u{6BE}']),
+    ('\u{FBAE}', true, &['\u{6D2}']),
+    ('\u{FBAF}', true, &['\u{6D2}']),
+    ('\u{FBB0}', true, &['\u{6D3}']),
+    ('\u{FBB1}', true, &['\u{6D3}']),
+    ('\u{FBD3}', true, &['\u{6AD}']),
+    ('\u{FBD4}', true, &['\u{6AD}']),
+    ('\u{FBD5}', true, &['\u{6AD}']),
+    ('\u{FBD6}', true, &['\u{6AD}']),
+    ('\u{FBD7}', true, &['\u{6C7}']),
+    ('\u{FBD8}', true, &['\u{6C7}']),
+    ('\u{FBD9}', true, &['\u{6C6}']),
+    ('\u{FBDA}', true, &['\u{6C6}']),
+    ('\u{FBDB}', true, &['\u{6C8}']),
+    ('\u{FBDC}', true, &['\u{6C8}']),
+    ('\u{FBDD}', true, &['\u{677}']),
+    ('\u{FBDE}', true, &['\u{6CB}']),
+    ('\u{FBDF}', true, &['\u{6CB}']),
+    ('\u{FBE0}', true, &['\u{6C5}']),
+    ('\u{FBE1}', true, &['\u{6C5}']),
+    ('\u{FBE2}', true, &['\u{6C9}']),
+    ('\u{FBE3}', true, &['\u{6C9}']),
+    ('\u{FBE4}', true, &['\u{6D0}']),
+    ('\u{FBE5}', true, &['\u{6D0}']),
+    ('\u{FBE6}', true, &['\u{6D0}']),
+    ('\u{FBE7}', true, &['\u{6D0}']),
+    ('\u{FBE8}', true, &['\u{649}']),
+    ('\u{FBE9}', true, &['\u{649}']),
+    ('\u{FBEA}', true, &['\u{626}', '\u{627}']),
+    ('\u{FBEB}', true, &['\u{626}', '\u{627}']),
+    ('\u{FBEC}', true, &['\u{626}', '\u{6D5}']),
+    ('\u{FBED}', true, &['\u{626}', '\u{6D5}']),
+    ('\u{FBEE}', true, &['\u{626}', '\u{648}']),
+    ('\u{FBEF}', true, &['\u{626}', '\u{648}']),
+    ('\u{FBF0}', true, &['\u{626}', '\u{6C7}']),
+    ('\u{FBF1}', true, &['\u{626}', '\u{6C7}']),
+    ('\u{FBF2}', true, &['\u{626}', '\u{6C6}']),
+    ('\u{FBF3}', true, &['\u{626}', '\u{6C6}']),
+    ('\u{FBF4}', true, &['\u{626}', '\u{6C8}']),
+    ('\u{FBF5}', true, &['\u{626}', '\u{6C8}']),
+    ('\u{FBF6}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF7}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF8}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF9}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFA}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFB}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFC}', true, &['\u{6CC}']),
+    ('\u{FBFD}', true, &['\u{6CC}']),
+    ('\u{FBFE}', true, &['\u{6CC}']),
+    ('\u{FBFF}', true, &['\u{6CC}']),
+    ('\u{FC00}', true, &['\u{626}', '\u{62C}']),
+    ('\u{FC01}', true, &['\u{626}', '\u{62D}']),
+    ('\u{FC02}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC03}', true, &['\u{626}', '\u{649}']),
+    ('\u{FC04}', true, &['\u{626}', '\u{64A}']),
+    ('\u{FC05}', true, &['\u{628}', '\u{62C}']),
+    ('\u{FC06}', true, &['\u{628}', '\u{62D}']),
+    ('\u{FC07}', true, &['\u{628}', '\u{62E}']),
+    ('\u{FC08}', true, &['\u{628}', '\u{645}']),
+    ('\u{FC09}', true, &['\u{628}', '\u{649}']),
+    ('\u{FC0A}', true, &['\u{628}', '\u{64A}']),
+    ('\u{FC0B}', true, &['\u{62A}', '\u{62C}']),
+    ('\u{FC0C}', true, &['\u{62A}', '\u{62D}']),
+    ('\u{FC0D}', true, &['\u{62A}', '\u{62E}']),
+    ('\u{FC0E}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FC0F}', true, &['\u{62A}', '\u{649}']),
+    ('\u{FC10}', true, &['\u{62A}', '\u{64A}']),
+    ('\u{FC11}', true, &['\u{62B}', '\u{62C}']),
+    ('\u{FC12}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FC13}', true, &['\u{62B}', '\u{649}']),
+    ('\u{FC14}', true, &['\u{62B}', '\u{64A}']),
+    ('\u{FC15}', true, &['\u{62C}', '\u{62D}']),
+    ('\u{FC16}', true, &['\u{62C}', '\u{645}']),
+    ('\u{FC17}', true, &['\u{62D}', '\u{62C}']),
+    ('\u{FC18}', true, &['\u{62D}', '\u{645}']),
+    ('\u{FC19}', true, &['\u{62E}', '\u{62C}']),
+    ('\u{FC1A}', true, &['\u{62E}', '\u{62D}']),
+    ('\u{FC1B}', true, &['\u{62E}', '\u{645}']),
+    ('\u{FC1C}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FC1D}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FC1E}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FC1F}', true, &['\u{633}', '\u{645}']),
+    ('\u{FC20}', true, &['\u{635}', '\u{62D}']),
+    ('\u{FC21}', true, &['\u{635}', '\u{645}']),
+    ('\u{FC22}', true, &['\u{636}', '\u{62C}']),
+    ('\u{FC23}', true, &['\u{636}', '\u{62D}']),
+    ('\u{FC24}', true, &['\u{636}', '\u{62E}']),
+    ('\u{FC25}', true, &['\u{636}', '\u{645}']),
+    ('\u{FC26}', true, &['\u{637}', '\u{62D}']),
+    ('\u{FC27}', true, &['\u{637}', '\u{645}']),
+    ('\u{FC28}', true, &['\u{638}', '\u{645}']),
+    ('\u{FC29}', true, &['\u{639}', '\u{62C}']),
+    ('\u{FC2A}', true, &['\u{639}', '\u{645}']),
+    ('\u{FC2B}', true, &['\u{63A}', '\u{62C}']),
+    ('\u{FC2C}', true, &['\u{63A}', '\u{645}']),
+    ('\u{FC2D}', true, &['\u{641}', '\u{62C}']),
+    ('\u{FC2E}', true, &['\u{641}', '\u{62D}']),
+    ('\u{FC2F}', true, &['\u{641}', '\u{62E}']),
+    ('\u{FC30}', true, &['\u{641}', '\u{645}']),
+    ('\u{FC31}', true, &['\u{641}', '\u{649}']),
+    ('\u{FC32}', true, &['\u{641}', '\u{64A}']),
+    ('\u{FC33}', true, &['\u{642}', '\u{62D}']),
+    ('\u{FC34}', true, &['\u{642}', '\u{645}']),
+    ('\u{FC35}', true, &['\u{642}', '\u{649}']),
+    ('\u{FC36}', true, &['\u{642}', '\u{64A}']),
+    ('\u{FC37}', true, &['\u{643}', '\u{627}']),
+    ('\u{FC38}', true, &['\u{643}', '\u{62C}']),
+    ('\u{FC39}', true, &['\u{643}', '\u{62D}']),
+    ('\u{FC3A}', true, &['\u{643}', '\u{62E}']),
+    ('\u{FC3B}', true, &['\u{643}', '\u{644}']),
+    ('\u{FC3C}', true, &['\u{643}', '\u{645}']),
+    ('\u{FC3D}', true, &['\u{643}', '\u{649}']),
+    ('\u{FC3E}', true, &['\u{643}', '\u{64A}']),
+    ('\u{FC3F}', true, &['\u{644}', '\u{62C}']),
+    ('\u{FC40}', true, &['\u{644}', '\u{62D}']),
+    ('\u{FC41}', true, &['\u{644}', '\u{62E}']),
+    ('\u{FC42}', true, &['\u{644}', '\u{645}']),
+    ('\u{FC43}', true, &['\u{644}', '\u{649}']),
+    ('\u{FC44}', true, &['\u{644}', '\u{64A}']),
+    ('\u{FC45}', true, &['\u{645}', '\u{62C}']),
+    ('\u{FC46}', true, &['\u{645}', '\u{62D}']),
+    ('\u{FC47}', true, &['\u{645}', '\u{62E}']),
+    ('\u{FC48}', true, &['\u{645}', '\u{645}']),
+    ('\u{FC49}', true, &['\u{645}', '\u{649}']),
+    ('\u{FC4A}', true, &['\u{645}', '\u{64A}']),
+    ('\u{FC4B}', true, &['\u{646}', '\u{62C}']),
+    ('\u{FC4C}', true, &['\u{646}', '\u{62D}']),
+    ('\u{FC4D}', true, &['\u{646}', '\u{62E}']),
+    ('\u{FC4E}', true, &['\u{646}', '\u{645}']),
+    ('\u{FC4F}', true, &['\u{646}', '\u{649}']),
+    ('\u{FC50}', true, &['\u{646}', '\u{64A}']),
+    ('\u{FC51}', true, &['\u{647}', '\u{62C}']),
+    ('\u{FC52}', true, &['\u{647}', '\u{645}']),
+    ('\u{FC53}', true, &['\u{647}', '\u{649}']),
+    ('\u{FC54}', true, &['\u{647}', '\u{64A}']),
+    ('\u{FC55}', true, &['\u{64A}', '\u{62C}']),
+    ('\u{FC56}', true, &['\u{64A}', '\u{62D}']),
+    ('\u{FC57}', true, &['\u{64A}', '\u{62E}']),
+    ('\u{FC58}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FC59}', true, &['\u{64A}', '\u{649}']),
+    ('\u{FC5A}', true, &['\u{64A}', '\u{64A}']),
+    ('\u{FC5B}', true, &['\u{630}', '\u{670}']),
+    ('\u{FC5C}', true, &['\u{631}', '\u{670}']),
+    ('\u{FC5D}', true, &['\u{649}', '\u{670}']),
+    ('\u{FC5E}', true, &['\u{20}', '\u{64C}', '\u{651}']),
+    ('\u{FC5F}', true, &['\u{20}', '\u{64D}', '\u{651}']),
+    ('\u{FC60}', true, &['\u{20}', '\u{64E}', '\u{651}']),
+    ('\u{FC61}', true, &['\u{20}', '\u{64F}', '\u{651}']),
+    ('\u{FC62}', true, &['\u{20}', '\u{650}', '\u{651}']),
+    ('\u{FC63}', true, &['\u{20}', '\u{651}', '\u{670}']),
+    ('\u{FC64}', true, &['\u{626}', '\u{631}']),
+    ('\u{FC65}', true, &['\u{626}', '\u{632}']),
+    ('\u{FC66}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC67}', true, &['\u{626}', '\u{646}']),
+    ('\u{FC68}', true, &['\u{626}', '\u{649}']),
+    ('\u{FC69}', true, &['\u{626}', '\u{64A}']),
+    ('\u{FC6A}', true, &['\u{628}', '\u{631}']),
+    ('\u{FC6B}', true, &['\u{628}', '\u{632}']),
+    ('\u{FC6C}', true, &['\u{628}', '\u{645}']),
+    ('\u{FC6D}', true, &['\u{628}', '\u{646}']),
+    ('\u{FC6E}', true, &['\u{628}', '\u{649}']),
+    ('\u{FC6F}', true, &['\u{628}', '\u{64A}']),
+    ('\u{FC70}', true, &['\u{62A}', '\u{631}']),
+    ('\u{FC71}', true, &['\u{62A}', '\u{632}']),
+    ('\u{FC72}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FC73}', true, &['\u{62A}', '\u{646}']),
+    ('\u{FC74}', true, &['\u{62A}', '\u{649}']),
+    ('\u{FC75}', true, &['\u{62A}', '\u{64A}']),
+    ('\u{FC76}', true, &['\u{62B}', '\u{631}']),
+    ('\u{FC77}', true, &['\u{62B}', '\u{632}']),
+    ('\u{FC78}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FC79}', true, &['\u{62B}', '\u{646}']),
+    ('\u{FC7A}', true, &['\u{62B}', '\u{649}']),
+    ('\u{FC7B}', true, &['\u{62B}', '\u{64A}']),
+    ('\u{FC7C}', true, &['\u{641}', '\u{649}']),
+    ('\u{FC7D}', true, &['\u{641}', '\u{64A}']),
+    ('\u{FC7E}', true, &['\u{642}', '\u{649}']),
+    ('\u{FC7F}', true, &['\u{642}', '\u{64A}']),
+    ('\u{FC80}', true, &['\u{643}', '\u{627}']),
+    ('\u{FC81}', true, &['\u{643}', '\u{644}']),
+    ('\u{FC82}', true, &['\u{643}', '\u{645}']),
+    ('\u{FC83}', true, &['\u{643}', '\u{649}']),
+    ('\u{FC84}', true, &['\u{643}', '\u{64A}']),
+    ('\u{FC85}', true, &['\u{644}', '\u{645}']),
+    ('\u{FC86}', true, &['\u{644}', '\u{649}']),
+    ('\u{FC87}', true, &['\u{644}', '\u{64A}']),
+    ('\u{FC88}', true, &['\u{645}', '\u{627}']),
+    ('\u{FC89}', true, &['\u{645}', '\u{645}']),
+    ('\u{FC8A}', true, &['\u{646}', '\u{631}']),
+    ('\u{FC8B}', true, &['\u{646}', '\u{632}']),
+    ('\u{FC8C}', true, &['\u{646}', '\u{645}']),
+    ('\u{FC8D}', true, &['\u{646}', '\u{646}']),
+    ('\u{FC8E}', true, &['\u{646}', '\u{649}']),
+    ('\u{FC8F}', true, &['\u{646}', '\u{64A}']),
+    ('\u{FC90}', true, &['\u{649}', '\u{670}']),
+    ('\u{FC91}', true, &['\u{64A}', '\u{631}']),
+    ('\u{FC92}', true, &['\u{64A}', '\u{632}']),
+    ('\u{FC93}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FC94}', true, &['\u{64A}', '\u{646}']),
+    ('\u{FC95}', true, &['\u{64A}', '\u{649}']),
+    ('\u{FC96}', true, &['\u{64A}', '\u{64A}']),
+    ('\u{FC97}', true, &['\u{626}', '\u{62C}']),
+    ('\u{FC98}', true, &['\u{626}', '\u{62D}']),
+    ('\u{FC99}', true, &['\u{626}', '\u{62E}']),
+    ('\u{FC9A}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC9B}', true, &['\u{626}', '\u{647}']),
+    ('\u{FC9C}', true, &['\u{628}', '\u{62C}']),
+    ('\u{FC9D}', true, &['\u{628}', '\u{62D}']),
+    ('\u{FC9E}', true, &['\u{628}', '\u{62E}']),
+    ('\u{FC9F}', true, &['\u{628}', '\u{645}']),
+    ('\u{FCA0}', true, &['\u{628}', '\u{647}']),
+    ('\u{FCA1}', true, &['\u{62A}', '\u{62C}']),
+    ('\u{FCA2}', true, &['\u{62A}', '\u{62D}']),
+    ('\u{FCA3}', true, &['\u{62A}', '\u{62E}']),
+    ('\u{FCA4}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FCA5}', true, &['\u{62A}', '\u{647}']),
+    ('\u{FCA6}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FCA7}', true, &['\u{62C}', '\u{62D}']),
+    ('\u{FCA8}', true, &['\u{62C}', '\u{645}']),
+    ('\u{FCA9}', true, &['\u{62D}', '\u{62C}']),
+    ('\u{FCAA}', true, &['\u{62D}', '\u{645}']),
+    ('\u{FCAB}', true, &['\u{62E}', '\u{62C}']),
+    ('\u{FCAC}', true, &['\u{62E}', '\u{645}']),
+    ('\u{FCAD}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FCAE}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FCAF}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FCB0}', true, &['\u{633}', '\u{645}']),
+    ('\u{FCB1}', true, &['\u{635}', '\u{62D}']),
+    ('\u{FCB2}', true, &['\u{635}', '\u{62E}']),
+    ('\u{FCB3}', true, &['\u{635}', '\u{645}']),
+    ('\u{FCB4}', true, &['\u{636}', '\u{62C}']),
+    ('\u{FCB5}', true, &['\u{636}', '\u{62D}']),
+    ('\u{FCB6}', true, &['\u{636}', '\u{62E}']),
+    ('\u{FCB7}', true, &['\u{636}', '\u{645}']),
+    ('\u{FCB8}', true, &['\u{637}', '\u{62D}']),
+    ('\u{FCB9}', true, &['\u{638}', '\u{645}']),
+    ('\u{FCBA}', true, &['\u{639}', '\u{62C}']),
+    ('\u{FCBB}', true, &['\u{639}', '\u{645}']),
+    ('\u{FCBC}', true, &['\u{63A}', '\u{62C}']),
+    ('\u{FCBD}', true, &['\u{63A}', '\u{645}']),
+    ('\u{FCBE}', true, &['\u{641}', '\u{62C}']),
+    ('\u{FCBF}', true, &['\u{641}', '\u{62D}']),
+    ('\u{FCC0}', true, &['\u{641}', '\u{62E}']),
+    ('\u{FCC1}', true, &['\u{641}', '\u{645}']),
+    ('\u{FCC2}', true, &['\u{642}', '\u{62D}']),
+    ('\u{FCC3}', true, &['\u{642}', '\u{645}']),
+    ('\u{FCC4}', true, &['\u{643}', '\u{62C}']),
+    ('\u{FCC5}', true, &['\u{643}', '\u{62D}']),
+    ('\u{FCC6}', true, &['\u{643}', '\u{62E}']),
+    ('\u{FCC7}', true, &['\u{643}', '\u{644}']),
+    ('\u{FCC8}', true, &['\u{643}', '\u{645}']),
+    ('\u{FCC9}', true, &['\u{644}', '\u{62C}']),
+    ('\u{FCCA}', true, &['\u{644}', '\u{62D}']),
+    ('\u{FCCB}', true, &['\u{644}', '\u{62E}']),
+    ('\u{FCCC}', true, &['\u{644}', '\u{645}']),
+    ('\u{FCCD}', true, &['\u{644}', '\u{647}']),
+    ('\u{FCCE}', true, &['\u{645}', '\u{62C}']),
+    ('\u{FCCF}', true, &['\u{645}', '\u{62D}']),
+    ('\u{FCD0}', true, &['\u{645}', '\u{62E}']),
+    ('\u{FCD1}', true, &['\u{645}', '\u{645}']),
+    ('\u{FCD2}', true, &['\u{646}', '\u{62C}']),
+    ('\u{FCD3}', true, &['\u{646}', '\u{62D}']),
+    ('\u{FCD4}', true, &['\u{646}', '\u{62E}']),
+    ('\u{FCD5}', true, &['\u{646}', '\u{645}']),
+    ('\u{FCD6}', true, &['\u{646}', '\u{647}']),
+    ('\u{FCD7}', true, &['\u{647}', '\u{62C}']),
+    ('\u{FCD8}', true, &['\u{647}', '\u{645}']),
+    ('\u{FCD9}', true, &['\u{647}', '\u{670}']),
+    ('\u{FCDA}', true, &['\u{64A}', '\u{62C}']),
+    ('\u{FCDB}', true, &['\u{64A}', '\u{62D}']),
+    ('\u{FCDC}', true, &['\u{64A}', '\u{62E}']),
+    ('\u{FCDD}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FCDE}', true, &['\u{64A}', '\u{647}']),
+    ('\u{FCDF}', true, &['\u{626}', '\u{645}']),
+    ('\u{FCE0}', true, &['\u{626}', '\u{647}']),
+    ('\u{FCE1}', true, &['\u{628}', '\u{645}']),
+    ('\u{FCE2}', true, &['\u{628}', '\u{647}']),
+    ('\u{FCE3}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FCE4}', true, &['\u{62A}', '\u{647}']),
+    ('\u{FCE5}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FCE6}', true, &['\u{62B}', '\u{647}']),
+    ('\u{FCE7}', true, &['\u{633}', '\u{645}']),
+    ('\u{FCE8}', true, &['\u{633}', '\u{647}']),
+    ('\u{FCE9}', true, &['\u{634}', '\u{645}']),
+    ('\u{FCEA}', true, &['\u{634}', '\u{647}']),
+    ('\u{FCEB}', true, &['\u{643}', '\u{644}']),
+    ('\u{FCEC}', true, &['\u{643}', '\u{645}']),
+    ('\u{FCED}', true, &['\u{644}', '\u{645}']),
+    ('\u{FCEE}', true, &['\u{646}', '\u{645}']),
+    ('\u{FCEF}', true, &['\u{646}', '\u{647}']),
+    ('\u{FCF0}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FCF1}', true, &['\u{64A}', '\u{647}']),
+    ('\u{FCF2}', true, &['\u{640}', '\u{64E}', '\u{651}']),
+    ('\u{FCF3}', true, &['\u{640}', '\u{64F}', '\u{651}']),
+    ('\u{FCF4}', true, &['\u{640}', '\u{650}', '\u{651}']),
+    ('\u{FCF5}', true, &['\u{637}', '\u{649}']),
+    ('\u{FCF6}', true, &['\u{637}', '\u{64A}']),
+    ('\u{FCF7}', true, &['\u{639}', '\u{649}']),
+    ('\u{FCF8}', true, &['\u{639}', '\u{64A}']),
+    ('\u{FCF9}', true, &['\u{63A}', '\u{649}']),
+    ('\u{FCFA}', true, &['\u{63A}', '\u{64A}']),
+    ('\u{FCFB}', true, &['\u{633}', '\u{649}']),
+    ('\u{FCFC}', true, &['\u{633}', '\u{64A}']),
+    ('\u{FCFD}', true, &['\u{634}', '\u{649}']),
+    ('\u{FCFE}', true, &['\u{634}', '\u{64A}']),
+    ('\u{FCFF}', true, &['\u{62D}', '\u{649}']),
+    ('\u{FD00}', true, &['\u{62D}', '\u{64A}']),
+    ('\u{FD01}', true, &['\u{62C}', '\u{649}']),
+    ('\u{FD02}', true, &['\u{62C}', '\u{64A}']),
+    ('\u{FD03}', true, &['\u{62E}', '\u{649}']),
+    ('\u{FD04}', true, &['\u{62E}', '\u{64A}']),
+    ('\u{FD05}', true, &['\u{635}', '\u{649}']),
+    ('\u{FD06}', true, &['\u{635}', '\u{64A}']),
+    ('\u{FD07}', true, &['\u{636}', '\u{649}']),
+    ('\u{FD08}', true, &['\u{636}', '\u{64A}']),
+    ('\u{FD09}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD0A}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD0B}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD0C}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD0D}', true, &['\u{634}', '\u{631}']),
+    ('\u{FD0E}', true, &['\u{633}', '\u{631}']),
+    ('\u{FD0F}', true, &['\u{635}', '\u{631}']),
+    ('\u{FD10}', true, &['\u{636}', '\u{631}']),
+    ('\u{FD11}', true, &['\u{637}', '\u{649}']),
+    ('\u{FD12}', true, &['\u{637}', '\u{64A}']),
+    ('\u{FD13}', true, &['\u{639}', '\u{649}']),
+    ('\u{FD14}', true, &['\u{639}', '\u{64A}']),
+    ('\u{FD15}', true, &['\u{63A}', '\u{649}']),
+    ('\u{FD16}', true, &['\u{63A}', '\u{64A}']),
+    ('\u{FD17}', true, &['\u{633}', '\u{649}']),
+    ('\u{FD18}', true, &['\u{633}', '\u{64A}']),
+    ('\u{FD19}', true, &['\u{634}', '\u{649}']),
+    ('\u{FD1A}', true, &['\u{634}', '\u{64A}']),
+    ('\u{FD1B}', true, &['\u{62D}', '\u{649}']),
+    ('\u{FD1C}', true, &['\u{62D}', '\u{64A}']),
+    ('\u{FD1D}', true, &['\u{62C}', '\u{649}']),
+    ('\u{FD1E}', true, &['\u{62C}', '\u{64A}']),
+    ('\u{FD1F}', true, &['\u{62E}', '\u{649}']),
+    ('\u{FD20}', true, &['\u{62E}', '\u{64A}']),
+    ('\u{FD21}', true, &['\u{635}', '\u{649}']),
+    ('\u{FD22}', true, &['\u{635}', '\u{64A}']),
+    ('\u{FD23}', true, &['\u{636}', '\u{649}']),
+    ('\u{FD24}', true, &['\u{636}', '\u{64A}']),
+    ('\u{FD25}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD26}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD27}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD28}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD29}', true, &['\u{634}', '\u{631}']),
+    ('\u{FD2A}', true, &['\u{633}', '\u{631}']),
+    ('\u{FD2B}', true, &['\u{635}', '\u{631}']),
+    ('\u{FD2C}', true, &['\u{636}', '\u{631}']),
+    ('\u{FD2D}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD2E}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD2F}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD30}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD31}', true, &['\u{633}', '\u{647}']),
+    ('\u{FD32}', true, &['\u{634}', '\u{647}']),
+    ('\u{FD33}', true, &['\u{637}', '\u{645}']),
+    ('\u{FD34}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FD35}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FD36}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FD37}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD38}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD39}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD3A}', true, &['\u{637}', '\u{645}']),
+    ('\u{FD3B}', true, &['\u{638}', '\u{645}']),
+    ('\u{FD3C}', true, &['\u{627}', '\u{64B}']),
+    ('\u{FD3D}', true, &['\u{627}', '\u{64B}']),
+    ('\u{FD50}', true, &['\u{62A}', '\u{62C}', '\u{645}']),
+    ('\u{FD51}', true, &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD52}', true, &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD53}', true, &['\u{62A}', '\u{62D}', '\u{645}']),
+    ('\u{FD54}', true, &['\u{62A}', '\u{62E}', '\u{645}']),
+    ('\u{FD55}', true, &['\u{62A}', '\u{645}', '\u{62C}']),
+    ('\u{FD56}', true, &['\u{62A}', '\u{645}', '\u{62D}']),
+    ('\u{FD57}', true, &['\u{62A}', '\u{645}', '\u{62E}']),
+    ('\u{FD58}', true, &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD59}', true, &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD5A}', true, &['\u{62D}', '\u{645}', '\u{64A}']),
+    ('\u{FD5B}', true, &['\u{62D}', '\u{645}', '\u{649}']),
+    ('\u{FD5C}', true, &['\u{633}', '\u{62D}', '\u{62C}']),
+    ('\u{FD5D}', true, &['\u{633}', '\u{62C}', '\u{62D}']),
+    ('\u{FD5E}', true, &['\u{633}', '\u{62C}', '\u{649}']),
+    ('\u{FD5F}', true, &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD60}', true, &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD61}', true, &['\u{633}', '\u{645}', '\u{62C}']),
+    ('\u{FD62}', true, &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD63}', true, &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD64}', true, &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD65}', true, &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD66}', true, &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FD67}', true, &['\u{634}', '\u{62D}', '\u{645}']),
+    ('\u{FD68}', true, &['\u{634}', '\u{62D}', '\u{645}']),
+    ('\u{FD69}', true, &['\u{634}', '\u{62C}', '\u{64A}']),
+    ('\u{FD6A}', true, &['\u{634}', '\u{645}', '\u{62E}']),
+    ('\u{FD6B}', true, &['\u{634}', '\u{645}', '\u{62E}']),
+    ('\u{FD6C}', true, &['\u{634}', '\u{645}', '\u{645}']),
+    ('\u{FD6D}', true, &['\u{634}', '\u{645}', '\u{645}']),
+    ('\u{FD6E}', true, &['\u{636}', '\u{62D}', '\u{649}']),
+    ('\u{FD6F}', true, &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD70}', true, &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD71}', true, &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD72}', true, &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD73}', true, &['\u{637}', '\u{645}', '\u{645}']),
+    ('\u{FD74}', true, &['\u{637}', '\u{645}', '\u{64A}']),
+    ('\u{FD75}', true, &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FD76}', true, &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD77}', true, &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD78}', true, &['\u{639}', '\u{645}', '\u{649}']),
+    ('\u{FD79}', true, &['\u{63A}', '\u{645}', '\u{645}']),
+    ('\u{FD7A}', true, &['\u{63A}', '\u{645}', '\u{64A}']),
+    ('\u{FD7B}', true, &['\u{63A}', '\u{645}', '\u{649}']),
+    ('\u{FD7C}', true, &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7D}', true, &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7E}', true, &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FD7F}', true, &['\u{642}', '\u{645}', '\u{645}']),
+    ('\u{FD80}', true, &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FD81}', true, &['\u{644}', '\u{62D}', '\u{64A}']),
+    ('\u{FD82}', true, &['\u{644}', '\u{62D}', '\u{649}']),
+    ('\u{FD83}', true, &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD84}', true, &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD85}', true, &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD86}', true, &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD87}', true, &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD88}', true, &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD89}', true, &['\u{645}', '\u{62D}', '\u{62C}']),
+    ('\u{FD8A}', true, &['\u{645}', '\u{62D}', '\u{645}']),
+    ('\u{FD8B}', true, &['\u{645}', '\u{62D}', '\u{64A}']),
+    ('\u{FD8C}', true, &['\u{645}', '\u{62C}', '\u{62D}']),
+    ('\u{FD8D}', true, &['\u{645}', '\u{62C}', '\u{645}']),
+    ('\u{FD8E}', true, &['\u{645}', '\u{62E}', '\u{62C}']),
+    ('\u{FD8F}', true, &['\u{645}', '\u{62E}', '\u{645}']),
+    ('\u{FD92}', true, &['\u{645}', '\u{62C}', '\u{62E}']),
+    ('\u{FD93}', true, &['\u{647}', '\u{645}', '\u{62C}']),
+    ('\u{FD94}', true, &['\u{647}', '\u{645}', '\u{645}']),
+    ('\u{FD95}', true, &['\u{646}', '\u{62D}', '\u{645}']),
+    ('\u{FD96}', true, &['\u{646}', '\u{62D}', '\u{649}']),
+    ('\u{FD97}', true, &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD98}', true, &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD99}', true, &['\u{646}', '\u{62C}', '\u{649}']),
+    ('\u{FD9A}', true, &['\u{646}', '\u{645}', '\u{64A}']),
+    ('\u{FD9B}', true, &['\u{646}', '\u{645}', '\u{649}']),
+    ('\u{FD9C}', true, &['\u{64A}', '\u{645}', '\u{645}']),
+    ('\u{FD9D}', true, &['\u{64A}', '\u{645}', '\u{645}']),
+    ('\u{FD9E}', true, &['\u{628}', '\u{62E}', '\u{64A}']),
+    ('\u{FD9F}', true, &['\u{62A}', '\u{62C}', '\u{64A}']),
+    ('\u{FDA0}', true, &['\u{62A}', '\u{62C}', '\u{649}']),
+    ('\u{FDA1}', true, &['\u{62A}', '\u{62E}', '\u{64A}']),
+    ('\u{FDA2}', true, &['\u{62A}', '\u{62E}', '\u{649}']),
+    ('\u{FDA3}', true, &['\u{62A}', '\u{645}', '\u{64A}']),
+    ('\u{FDA4}', true, &['\u{62A}', '\u{645}', '\u{649}']),
+    ('\u{FDA5}', true, &['\u{62C}', '\u{645}', '\u{64A}']),
+    ('\u{FDA6}', true, &['\u{62C}', '\u{62D}', '\u{649}']),
+    ('\u{FDA7}', true, &['\u{62C}', '\u{645}', '\u{649}']),
+    ('\u{FDA8}', true, &['\u{633}', '\u{62E}', '\u{649}']),
+    ('\u{FDA9}', true, &['\u{635}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAA}', true, &['\u{634}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAB}', true, &['\u{636}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAC}', true, &['\u{644}', '\u{62C}', '\u{64A}']),
+    ('\u{FDAD}', true, &['\u{644}', '\u{645}', '\u{64A}']),
+    ('\u{FDAE}', true, &['\u{64A}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAF}', true, &['\u{64A}', '\u{62C}', '\u{64A}']),
+    ('\u{FDB0}', true, &['\u{64A}', '\u{645}', '\u{64A}']),
+    ('\u{FDB1}', true, &['\u{645}', '\u{645}', '\u{64A}']),
+    ('\u{FDB2}', true, &['\u{642}', '\u{645}', '\u{64A}']),
+    ('\u{FDB3}', true, &['\u{646}', '\u{62D}', '\u{64A}']),
+    ('\u{FDB4}', true, &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FDB5}', true, &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FDB6}', true, &['\u{639}', '\u{645}', '\u{64A}']),
+    ('\u{FDB7}', true, &['\u{643}', '\u{645}', '\u{64A}']),
+    ('\u{FDB8}', true, &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDB9}', true, &['\u{645}', '\u{62E}', '\u{64A}']),
+    ('\u{FDBA}', true, &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBB}', true, &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDBC}', true, &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBD}', true, &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDBE}', true, &['\u{62C}', '\u{62D}', '\u{64A}']),
+    ('\u{FDBF}', true, &['\u{62D}', '\u{62C}', '\u{64A}']),
+    ('\u{FDC0}', true, &['\u{645}', '\u{62C}', '\u{64A}']),
+    ('\u{FDC1}', true, &['\u{641}', '\u{645}', '\u{64A}']),
+    ('\u{FDC2}', true, &['\u{628}', '\u{62D}', '\u{64A}']),
+    ('\u{FDC3}', true, &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDC4}', true, &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FDC5}', true, &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FDC6}', true, &['\u{633}', '\u{62E}', '\u{64A}']),
+    ('\u{FDC7}', true, &['\u{646}', '\u{62C}', '\u{64A}']),
+    ('\u{FDF0}', true, &['\u{635}', '\u{644}', '\u{6D2}']),
+    ('\u{FDF1}', true, &['\u{642}', '\u{644}', '\u{6D2}']),
+    ('\u{FDF2}', true, &['\u{627}', '\u{644}', '\u{644}', '\u{647}']),
+    ('\u{FDF3}', true, &['\u{627}', '\u{643}', '\u{628}', '\u{631}']),
+    ('\u{FDF4}', true, &['\u{645}', '\u{62D}', '\u{645}', '\u{62F}']),
+    ('\u{FDF5}', true, &['\u{635}', '\u{644}', '\u{639}', '\u{645}']),
+    ('\u{FDF6}', true, &['\u{631}', '\u{633}', '\u{648}', '\u{644}']),
+    ('\u{FDF7}', true, &['\u{639}', '\u{644}', '\u{64A}', '\u{647}']),
+    ('\u{FDF8}', true, &['\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDF9}', true, &['\u{635}', '\u{644}', '\u{649}']),
+    ('\u{FDFA}', true, &['\u{635}', '\u{644}', '\u{649}', '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}', '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDFB}', true, &['\u{62C}', '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{627}', '\u{644}', '\u{647}']),
+    ('\u{FDFC}', true, &['\u{631}', '\u{6CC}', '\u{627}', '\u{644}']),
+    ('\u{FE10}', true, &[',']),
+    ('\u{FE11}', true, &['\u{3001}']),
+    ('\u{FE12}', true, &['\u{3002}']),
+    ('\u{FE13}', true, &[':']),
+    ('\u{FE14}', true, &[';']),
+    ('\u{FE15}', true, &['!']),
+    ('\u{FE16}', true, &['?']),
+    ('\u{FE17}', true, &['\u{3016}']),
+    ('\u{FE18}', true, &['\u{3017}']),
+    ('\u{FE19}', true, &['\u{2026}']),
+    ('\u{FE30}', true, &['\u{2025}']),
+    ('\u{FE31}', true, &['\u{2014}']),
+    ('\u{FE32}', true, &['\u{2013}']),
+    ('\u{FE33}', true, &['_']),
+    ('\u{FE34}', true, &['_']),
+    ('\u{FE35}', true, &['(']),
+    ('\u{FE36}', true, &[')']),
+    ('\u{FE37}', true, &['{']),
+    ('\u{FE38}', true, &['}']),
+    ('\u{FE39}', true, &['\u{3014}']),
+    ('\u{FE3A}', true, &['\u{3015}']),
+    ('\u{FE3B}', true, &['\u{3010}']),
+    ('\u{FE3C}', true, &['\u{3011}']),
+    ('\u{FE3D}', true, &['\u{300A}']),
+    ('\u{FE3E}', true, &['\u{300B}']),
+    ('\u{FE3F}', true, &['\u{3008}']),
+    ('\u{FE40}', true, &['\u{3009}']),
+    ('\u{FE41}', true, &['\u{300C}']),
+    ('\u{FE42}', true, &['\u{300D}']),
+    ('\u{FE43}', true, &['\u{300E}']),
+    ('\u{FE44}', true, &['\u{300F}']),
+    ('\u{FE47}', true, &['[']),
+    ('\u{FE48}', true, &[']']),
+    ('\u{FE49}', true, &['\u{203E}']),
+    ('\u{FE4A}', true, &['\u{203E}']),
+    ('\u{FE4B}', true, &['\u{203E}']),
+    ('\u{FE4C}', true, &['\u{203E}']),
+    ('\u{FE4D}', true, &['_']),
+    ('\u{FE4E}', true, &['_']),
+    ('\u{FE4F}', true, &['_']),
+    ('\u{FE50}', true, &[',']),
+    ('\u{FE51}', true, &['\u{3001}']),
+    ('\u{FE52}', true, &['.']),
+    ('\u{FE54}', true, &[';']),
+    ('\u{FE55}', true, &[':']),
+    ('\u{FE56}', true, &['?']),
+    ('\u{FE57}', true, &['!']),
+    ('\u{FE58}', true, &['\u{2014}']),
+    ('\u{FE59}', true, &['(']),
+    ('\u{FE5A}', true, &[')']),
+    ('\u{FE5B}', true, &['{']),
+    ('\u{FE5C}', true, &['}']),
+    ('\u{FE5D}', true, &['\u{3014}']),
+    ('\u{FE5E}', true, &['\u{3015}']),
+    ('\u{FE5F}', true, &['#']),
+    ('\u{FE60}', true, &['&']),
+    ('\u{FE61}', true, &['*']),
+    ('\u{FE62}', true, &['+']),
+    ('\u{FE63}', true, &['-']),
+    ('\u{FE64}', true, &['<']),
+    ('\u{FE65}', true, &['>']),
+    ('\u{FE66}', true, &['=']),
+    ('\u{FE68}', true, &['\u{5C}']),
+    ('\u{FE69}', true, &['$']),
+    ('\u{FE6A}', true, &['%']),
+    ('\u{FE6B}', true, &['@']),
+    ('\u{FE70}', true, &['\u{20}', '\u{64B}']),
+    ('\u{FE71}', true, &['\u{640}', '\u{64B}']),
+    ('\u{FE72}', true, &['\u{20}', '\u{64C}']),
+    ('\u{FE74}', true, &['\u{20}', '\u{64D}']),
+    ('\u{FE76}', true, &['\u{20}', '\u{64E}']),
+    ('\u{FE77}', true, &['\u{640}', '\u{64E}']),
+    ('\u{FE78}', true, &['\u{20}', '\u{64F}']),
+    ('\u{FE79}', true, &['\u{640}', '\u{64F}']),
+    ('\u{FE7A}', true, &['\u{20}', '\u{650}']),
+    ('\u{FE7B}', true, &['\u{640}', '\u{650}']),
+    ('\u{FE7C}', true, &['\u{20}', '\u{651}']),
+    ('\u{FE7D}', true, &['\u{640}', '\u{651}']),
+    ('\u{FE7E}', true, &['\u{20}', '\u{652}']),
+    ('\u{FE7F}', true, &['\u{640}', '\u{652}']),
+    ('\u{FE80}', true, &['\u{621}']),
+    ('\u{FE81}', true, &['\u{622}']),
+    ('\u{FE82}', true, &['\u{622}']),
+    ('\u{FE83}', true, &['\u{623}']),
+    ('\u{FE84}', true, &['\u{623}']),
+    ('\u{FE85}', true, &['\u{624}']),
+    ('\u{FE86}', true, &['\u{624}']),
+    ('\u{FE87}', true, &['\u{625}']),
+    ('\u{FE88}', true, &['\u{625}']),
+    ('\u{FE89}', true, &['\u{626}']),
+    ('\u{FE8A}', true, &['\u{626}']),
+    ('\u{FE8B}', true, &['\u{626}']),
+    ('\u{FE8C}', true, &['\u{626}']),
+    ('\u{FE8D}', true, &['\u{627}']),
+    ('\u{FE8E}', true, &['\u{627}']),
+    ('\u{FE8F}', true, &['\u{628}']),
+    ('\u{FE90}', true, &['\u{628}']),
+    ('\u{FE91}', true, &['\u{628}']),
+    ('\u{FE92}', true, &['\u{628}']),
+    ('\u{FE93}', true, &['\u{629}']),
+    ('\u{FE94}', true, &['\u{629}']),
+    ('\u{FE95}', true, &['\u{62A}']),
+    ('\u{FE96}', true, &['\u{62A}']),
+    ('\u{FE97}', true, &['\u{62A}']),
+    ('\u{FE98}', true, &['\u{62A}']),
+    ('\u{FE99}', true, &['\u{62B}']),
+    ('\u{FE9A}', true, &['\u{62B}']),
+    ('\u{FE9B}', true, &['\u{62B}']),
+    ('\u{FE9C}', true, &['\u{62B}']),
+    ('\u{FE9D}', true, &['\u{62C}']),
+    ('\u{FE9E}', true, &['\u{62C}']),
+    ('\u{FE9F}', true, &['\u{62C}']),
+    ('\u{FEA0}', true, &['\u{62C}']),
+    ('\u{FEA1}', true, &['\u{62D}']),
+    ('\u{FEA2}', true, &['\u{62D}']),
+    ('\u{FEA3}', true, &['\u{62D}']),
+    ('\u{FEA4}', true, &['\u{62D}']),
+    ('\u{FEA5}', true, &['\u{62E}']),
+    ('\u{FEA6}', true, &['\u{62E}']),
+    ('\u{FEA7}', true, &['\u{62E}']),
+    ('\u{FEA8}', true, &['\u{62E}']),
+    ('\u{FEA9}', true, &['\u{62F}']),
+    ('\u{FEAA}', true, &['\u{62F}']),
+    ('\u{FEAB}', true, &['\u{630}']),
+    ('\u{FEAC}', true, &['\u{630}']),
+    ('\u{FEAD}', true, &['\u{631}']),
+    ('\u{FEAE}', true, &['\u{631}']),
+    ('\u{FEAF}', true, &['\u{632}']),
+    ('\u{FEB0}', true, &['\u{632}']),
+    ('\u{FEB1}', true, &['\u{633}']),
+    ('\u{FEB2}', true, &['\u{633}']),
+    ('\u{FEB3}', true, &['\u{633}']),
+    ('\u{FEB4}', true, &['\u{633}']),
+    ('\u{FEB5}', true, &['\u{634}']),
+    ('\u{FEB6}', true, &['\u{634}']),
+    ('\u{FEB7}', true, &['\u{634}']),
+    ('\u{FEB8}', true, &['\u{634}']),
+    ('\u{FEB9}', true, &['\u{635}']),
+    ('\u{FEBA}', true, &['\u{635}']),
+    ('\u{FEBB}', true, &['\u{635}']),
+    ('\u{FEBC}', true, &['\u{635}']),
+    ('\u{FEBD}', true, &['\u{636}']),
+    ('\u{FEBE}', true, &['\u{636}']),
+    ('\u{FEBF}', true, &['\u{636}']),
+    ('\u{FEC0}', true, &['\u{636}']),
+    ('\u{FEC1}', true, &['\u{637}']),
+    ('\u{FEC2}', true, &['\u{637}']),
+    ('\u{FEC3}', true, &['\u{637}']),
+    ('\u{FEC4}', true, &['\u{637}']),
+    ('\u{FEC5}', true, &['\u{638}']),
+    ('\u{FEC6}', true, &['\u{638}']),
+    ('\u{FEC7}', true, &['\u{638}']),
+    ('\u{FEC8}', true, &['\u{638}']),
+    ('\u{FEC9}', true, &['\u{639}']),
+    ('\u{FECA}', true, &['\u{639}']),
+    ('\u{FECB}', true, &['\u{639}']),
+    ('\u{FECC}', true, &['\u{639}']),
+    ('\u{FECD}', true, &['\u{63A}']),
+    ('\u{FECE}', true, &['\u{63A}']),
+    ('\u{FECF}', true, &['\u{63A}']),
+    ('\u{FED0}', true, &['\u{63A}']),
+    ('\u{FED1}', true, &['\u{641}']),
+    ('\u{FED2}', true, &['\u{641}']),
+    ('\u{FED3}', true, &['\u{641}']),
+    ('\u{FED4}', true, &['\u{641}']),
+    ('\u{FED5}', true, &['\u{642}']),
+    ('\u{FED6}', true, &['\u{642}']),
+    ('\u{FED7}', true, &['\u{642}']),
+    ('\u{FED8}', true, &['\u{642}']),
+    ('\u{FED9}', true, &['\u{643}']),
+    ('\u{FEDA}', true, &['\u{643}']),
+    ('\u{FEDB}', true, &['\u{643}']),
+    ('\u{FEDC}', true, &['\u{643}']),
+    ('\u{FEDD}', true, &['\u{644}']),
+    ('\u{FEDE}', true, &['\u{644}']),
+    ('\u{FEDF}', true, &['\u{644}']),
+    ('\u{FEE0}', true, &['\u{644}']),
+    ('\u{FEE1}', true, &['\u{645}']),
+    ('\u{FEE2}', true, &['\u{645}']),
+    ('\u{FEE3}', true, &['\u{645}']),
+    ('\u{FEE4}', true, &['\u{645}']),
+    ('\u{FEE5}', true, &['\u{646}']),
+    ('\u{FEE6}', true, &['\u{646}']),
+    ('\u{FEE7}', true, &['\u{646}']),
+    ('\u{FEE8}', true, &['\u{646}']),
+    ('\u{FEE9}', true, &['\u{647}']),
+    ('\u{FEEA}', true, &['\u{647}']),
+    ('\u{FEEB}', true, &['\u{647}']),
+    ('\u{FEEC}', true, &['\u{647}']),
+    ('\u{FEED}', true, &['\u{648}']),
+    ('\u{FEEE}', true, &['\u{648}']),
+    ('\u{FEEF}', true, &['\u{649}']),
+    ('\u{FEF0}', true, &['\u{649}']),
+    ('\u{FEF1}', true, &['\u{64A}']),
+    ('\u{FEF2}', true, &['\u{64A}']),
+    ('\u{FEF3}', true, &['\u{64A}']),
+    ('\u{FEF4}', true, &['\u{64A}']),
+    ('\u{FEF5}', true, &['\u{644}', '\u{622}']),
+    ('\u{FEF6}', true, &['\u{644}', '\u{622}']),
+    ('\u{FEF7}', true, &['\u{644}', '\u{623}']),
+    ('\u{FEF8}', true, &['\u{644}', '\u{623}']),
+    ('\u{FEF9}', true, &['\u{644}', '\u{625}']),
+    ('\u{FEFA}', true, &['\u{644}', '\u{625}']),
+    ('\u{FEFB}', true, &['\u{644}', '\u{627}']),
+    ('\u{FEFC}', true, &['\u{644}', '\u{627}']),
+    ('\u{FF01}', true, &['!']),
+    ('\u{FF02}', true, &['"']),
+    ('\u{FF03}', true, &['#']),
+    ('\u{FF04}', true, &['$']),
+    ('\u{FF05}', true, &['%']),
+    ('\u{FF06}', true, &['&']),
+    ('\u{FF07}', true, &['\u{27}']),
+    ('\u{FF08}', true, &['(']),
+    ('\u{FF09}', true, &[')']),
+    ('\u{FF0A}', true, &['*']),
+    ('\u{FF0B}', true, &['+']),
+    ('\u{FF0C}', true, &[',']),
+    ('\u{FF0D}', true, &['-']),
+    ('\u{FF0E}', true, &['.']),
+    ('\u{FF0F}', true, &['/']),
+    ('\u{FF10}', true, &['0']),
+    ('\u{FF11}', true, &['1']),
+    ('\u{FF12}', true, &['2']),
+    ('\u{FF13}', true, &['3']),
+    ('\u{FF14}', true, &['4']),
+    ('\u{FF15}', true, &['5']),
+    ('\u{FF16}', true, &['6']),
+    ('\u{FF17}', true, &['7']),
+    ('\u{FF18}', true, &['8']),
+    ('\u{FF19}', true, &['9']),
+    ('\u{FF1A}', true, &[':']),
+    ('\u{FF1B}', true, &[';']),
+    ('\u{FF1C}', true, &['<']),
+    ('\u{FF1D}', true, &['=']),
+    ('\u{FF1E}', true, &['>']),
+    ('\u{FF1F}', true, &['?']),
+    ('\u{FF20}', true, &['@']),
+    ('\u{FF21}', true, &['A']),
+    ('\u{FF22}', true, &['B']),
+    ('\u{FF23}', true, &['C']),
+    ('\u{FF24}', true, &['D']),
+    ('\u{FF25}', true, &['E']),
+    ('\u{FF26}', true, &['F']),
+    ('\u{FF27}', true, &['G']),
+    ('\u{FF28}', true, &['H']),
+    ('\u{FF29}', true, &['I']),
+    ('\u{FF2A}', true, &['J']),
+    ('\u{FF2B}', true, &['K']),
+    ('\u{FF2C}', true, &['L']),
+    ('\u{FF2D}', true, &['M']),
+    ('\u{FF2E}', true, &['N']),
+    ('\u{FF2F}', true, &['O']),
+    ('\u{FF30}', true, &['P']),
+    ('\u{FF31}', true, &['Q']),
+    ('\u{FF32}', true, &['R']),
+    ('\u{FF33}', true, &['S']),
+    ('\u{FF34}', true, &['T']),
+    ('\u{FF35}', true, &['U']),
+    ('\u{FF36}', true, &['V']),
+    ('\u{FF37}', true, &['W']),
+    ('\u{FF38}', true, &['X']),
+    ('\u{FF39}', true, &['Y']),
+    ('\u{FF3A}', true, &['Z']),
+    ('\u{FF3B}', true, &['[']),
+    ('\u{FF3C}', true, &['\u{5C}']),
+    ('\u{FF3D}', true, &[']']),
+    ('\u{FF3E}', true, &['^']),
+    ('\u{FF3F}', true, &['_']),
+    ('\u{FF40}', true, &['`']),
+    ('\u{FF41}', true, &['a']),
+    ('\u{FF42}', true, &['b']),
+    ('\u{FF43}', true, &['c']),
+    ('\u{FF44}', true, &['d']),
+    ('\u{FF45}', true, &['e']),
+    ('\u{FF46}', true, &['f']),
+    ('\u{FF47}', true, &['g']),
+    ('\u{FF48}', true, &['h']),
+    ('\u{FF49}', true, &['i']),
+    ('\u{FF4A}', true, &['j']),
+    ('\u{FF4B}', true, &['k']),
+    ('\u{FF4C}', true, &['l']),
+    ('\u{FF4D}', true, &['m']),
+    ('\u{FF4E}', true, &['n']),
+    ('\u{FF4F}', true, &['o']),
+    ('\u{FF50}', true, &['p']),
+    ('\u{FF51}', true, &['q']),
+    ('\u{FF52}', true, &['r']),
+    ('\u{FF53}', true, &['s']),
+    ('\u{FF54}', true, &['t']),
+    ('\u{FF55}', true, &['u']),
+    ('\u{FF56}', true, &['v']),
+    ('\u{FF57}', true, &['w']),
+    ('\u{FF58}', true, &['x']),
+    ('\u{FF59}', true, &['y']),
+    ('\u{FF5A}', true, &['z']),
+    ('\u{FF5B}', true, &['{']),
+    ('\u{FF5C}', true, &['|']),
+    ('\u{FF5D}', true, &['}']),
+    ('\u{FF5E}', true, &['~']),
+    ('\u{FF5F}', true, &['\u{2985}']),
+    ('\u{FF60}', true, &['\u{2986}']),
+    ('\u{FF61}', true, &['\u{3002}']),
+    ('\u{FF62}', true, &['\u{300C}']),
+    ('\u{FF63}', true, &['\u{300D}']),
+    ('\u{FF64}', true, &['\u{3001}']),
+    ('\u{FF65}', true, &['\u{30FB}']),
+    ('\u{FF66}', true, &['\u{30F2}']),
+    ('\u{FF67}', true, &['\u{30A1}']),
+    ('\u{FF68}', true, &['\u{30A3}']),
+    ('\u{FF69}', true, &['\u{30A5}']),
+    ('\u{FF6A}', true, &['\u{30A7}']),
+    ('\u{FF6B}', true, &['\u{30A9}']),
+    ('\u{FF6C}', true, &['\u{30E3}']),
+    ('\u{FF6D}', true, &['\u{30E5}']),
+    ('\u{FF6E}', true, &['\u{30E7}']),
+    ('\u{FF6F}', true, &['\u{30C3}']),
+    ('\u{FF70}', true, &['\u{30FC}']),
+    ('\u{FF71}', true, &['\u{30A2}']),
+    ('\u{FF72}', true, &['\u{30A4}']),
+    ('\u{FF73}', true, &['\u{30A6}']),
+    ('\u{FF74}', true, &['\u{30A8}']),
+    ('\u{FF75}', true, &['\u{30AA}']),
+    ('\u{FF76}', true, &['\u{30AB}']),
+    ('\u{FF77}', true, &['\u{30AD}']),
+    ('\u{FF78}', true, &['\u{30AF}']),
+    ('\u{FF79}', true, &['\u{30B1}']),
+    ('\u{FF7A}', true, &['\u{30B3}']),
+    ('\u{FF7B}', true, &['\u{30B5}']),
+    ('\u{FF7C}', true, &['\u{30B7}']),
+    ('\u{FF7D}', true, &['\u{30B9}']),
+    ('\u{FF7E}', true, &['\u{30BB}']),
+    ('\u{FF7F}', true, &['\u{30BD}']),
+    ('\u{FF80}', true, &['\u{30BF}']),
+    ('\u{FF81}', true, &['\u{30C1}']),
+    ('\u{FF82}', true, &['\u{30C4}']),
+    ('\u{FF83}', true, &['\u{30C6}']),
+    ('\u{FF84}', true, &['\u{30C8}']),
+    ('\u{FF85}', true, &['\u{30CA}']),
+    ('\u{FF86}', true, &['\u{30CB}']),
+    ('\u{FF87}', true, &['\u{30CC}']),
+    ('\u{FF88}', true, &['\u{30CD}']),
+    ('\u{FF89}', true, &['\u{30CE}']),
+    ('\u{FF8A}', true, &['\u{30CF}']),
+    ('\u{FF8B}', true, &['\u{30D2}']),
+    ('\u{FF8C}', true, &['\u{30D5}']),
+    ('\u{FF8D}', true, &['\u{30D8}']),
+    ('\u{FF8E}', true, &['\u{30DB}']),
+    ('\u{FF8F}', true, &['\u{30DE}']),
+    ('\u{FF90}', true, &['\u{30DF}']),
+    ('\u{FF91}', true, &['\u{30E0}']),
+    ('\u{FF92}', true, &['\u{30E1}']),
+    ('\u{FF93}', true, &['\u{30E2}']),
+    ('\u{FF94}', true, &['\u{30E4}']),
+    ('\u{FF95}', true, &['\u{30E6}']),
+    ('\u{FF96}', true, &['\u{30E8}']),
+    ('\u{FF97}', true, &['\u{30E9}']),
+    ('\u{FF98}', true, &['\u{30EA}']),
+    ('\u{FF99}', true, &['\u{30EB}']),
+    ('\u{FF9A}', true, &['\u{30EC}']),
+    ('\u{FF9B}', true, &['\u{30ED}']),
+    ('\u{FF9C}', true, &['\u{30EF}']),
+    ('\u{FF9D}', true, &['\u{30F3}']),
+    ('\u{FF9E}', true, &['\u{3099}']),
+    ('\u{FF9F}', true, &['\u{309A}']),
+    ('\u{FFA0}', true, &['\u{3164}']),
+    ('\u{FFA1}', true, &['\u{3131}']),
+    ('\u{FFA2}', true, &['\u{3132}']),
+    ('\u{FFA3}', true, &['\u{3133}']),
+    ('\u{FFA4}', true, &['\u{3134}']),
+    ('\u{FFA5}', true, &['\u{3135}']),
+    ('\u{FFA6}', true, &['\u{3136}']),
+    ('\u{FFA7}', true, &['\u{3137}']),
+    ('\u{FFA8}', true, &['\u{3138}']),
+    ('\u{FFA9}', true, &['\u{3139}']),
+    ('\u{FFAA}', true, &['\u{313A}']),
+    ('\u{FFAB}', true, &['\u{313B}']),
+    ('\u{FFAC}', true, &['\u{313C}']),
+    ('\u{FFAD}', true, &['\u{313D}']),
+    ('\u{FFAE}', true, &['\u{313E}']),
+    ('\u{FFAF}', true, &['\u{313F}']),
+    ('\u{FFB0}', true, &['\u{3140}']),
+    ('\u{FFB1}', true, &['\u{3141}']),
+    ('\u{FFB2}', true, &['\u{3142}']),
+    ('\u{FFB3}', true, &['\u{3143}']),
+    ('\u{FFB4}', true, &['\u{3144}']),
+    ('\u{FFB5}', true, &['\u{3145}']),
+    ('\u{FFB6}', true, &['\u{3146}']),
+    ('\u{FFB7}', true, &['\u{3147}']),
+    ('\u{FFB8}', true, &['\u{3148}']),
+    ('\u{FFB9}', true, &['\u{3149}']),
+    ('\u{FFBA}', true, &['\u{314A}']),
+    ('\u{FFBB}', true, &['\u{314B}']),
+    ('\u{FFBC}', true, &['\u{314C}']),
+    ('\u{FFBD}', true, &['\u{314D}']),
+    ('\u{FFBE}', true, &['\u{314E}']),
+    ('\u{FFC2}', true, &['\u{314F}']),
+    ('\u{FFC3}', true, &['\u{3150}']),
+    ('\u{FFC4}', true, &['\u{3151}']),
+    ('\u{FFC5}', true, &['\u{3152}']),
+    ('\u{FFC6}', true, &['\u{3153}']),
+    ('\u{FFC7}', true, &['\u{3154}']),
+    ('\u{FFCA}', true, &['\u{3155}']),
+    ('\u{FFCB}', true, &['\u{3156}']),
+    ('\u{FFCC}', true, &['\u{3157}']),
+    ('\u{FFCD}', true, &['\u{3158}']),
+    ('\u{FFCE}', true, &['\u{3159}']),
+    ('\u{FFCF}', true, &['\u{315A}']),
+    ('\u{FFD2}', true, &['\u{315B}']),
+    ('\u{FFD3}', true, &['\u{315C}']),
+    ('\u{FFD4}', true, &['\u{315D}']),
+    ('\u{FFD5}', true, &['\u{315E}']),
+    ('\u{FFD6}', true, &['\u{315F}']),
+    ('\u{FFD7}', true, &['\u{3160}']),
+    ('\u{FFDA}', true, &['\u{3161}']),
+    ('\u{FFDB}', true, &['\u{3162}']),
+    ('\u{FFDC}', true, &['\u{3163}']),
+    ('\u{FFE0}', true, &['\u{A2}']),
+    ('\u{FFE1}', true, &['\u{A3}']),
+    ('\u{FFE2}', true, &['\u{AC}']),
+    ('\u{FFE3}', true, &['\u{AF}']),
+    ('\u{FFE4}', true, &['\u{A6}']),
+    ('\u{FFE5}', true, &['\u{A5}']),
+    ('\u{FFE6}', true, &['\u{20A9}']),
+    ('\u{FFE8}', true, &['\u{2502}']),
+    ('\u{FFE9}', true, &['\u{2190}']),
+    ('\u{FFEA}', true, &['\u{2191}']),
+    ('\u{FFEB}', true, &['\u{2192}']),
+    ('\u{FFEC}', true, &['\u{2193}']),
+    ('\u{FFED}', true, &['\u{25A0}']),
+    ('\u{FFEE}', true, &['\u{25CB}']),
+    ('\u{10781}', true, &['\u{2D0}']),
+    ('\u{10782}', true, &['\u{2D1}']),
+    ('\u{10783}', true, &['\u{E6}']),
+    ('\u{10784}', true, &['\u{299}']),
+    ('\u{10785}', true, &['\u{253}']),
+    ('\u{10787}', true, &['\u{2A3}']),
+    ('\u{10788}', true, &['\u{AB66}']),
+    ('\u{10789}', true, &['\u{2A5}']),
+    ('\u{1078A}', true, &['\u{2A4}']),
+    ('\u{1078B}', true, &['\u{256}']),
+    ('\u{1078C}', true, &['\u{257}']),
+    ('\u{1078D}', true, &['\u{1D91}']),
+    ('\u{1078E}', true, &['\u{258}']),
+    ('\u{1078F}', true, &['\u{25E}']),
+    ('\u{10790}', true, &['\u{2A9}']),
+    ('\u{10791}', true, &['\u{264}']),
+    ('\u{10792}', true, &['\u{262}']),
+    ('\u{10793}', true, &['\u{260}']),
+    ('\u{10794}', true, &['\u{29B}']),
+    ('\u{10795}', true, &['\u{127}']),
+    ('\u{10796}', true, &['\u{29C}']),
+    ('\u{10797}', true, &['\u{267}']),
+    ('\u{10798}', true, &['\u{284}']),
+    ('\u{10799}', true, &['\u{2AA}']),
+    ('\u{1079A}', true, &['\u{2AB}']),
+    ('\u{1079B}', true, &['\u{26C}']),
+    ('\u{1079C}', true, &['\u{1DF04}']),
+    ('\u{1079D}', true, &['\u{A78E}']),
+    ('\u{1079E}', true, &['\u{26E}']),
+    ('\u{1079F}', true, &['\u{1DF05}']),
+    ('\u{107A0}', true, &['\u{28E}']),
+    ('\u{107A1}', true, &['\u{1DF06}']),
+    ('\u{107A2}', true, &['\u{F8}']),
+    ('\u{107A3}', true, &['\u{276}']),
+    ('\u{107A4}', true, &['\u{277}']),
+    ('\u{107A5}', true, &['q']),
+    ('\u{107A6}', true, &['\u{27A}']),
+    ('\u{107A7}', true, &['\u{1DF08}']),
+    ('\u{107A8}', true, &['\u{27D}']),
+    ('\u{107A9}', true, &['\u{27E}']),
+    ('\u{107AA}', true, &['\u{280}']),
+    ('\u{107AB}', true, &['\u{2A8}']),
+    ('\u{107AC}', true, &['\u{2A6}']),
+    ('\u{107AD}', true, &['\u{AB67}']),
+    ('\u{107AE}', true, &['\u{2A7}']),
+    ('\u{107AF}', true, &['\u{288}']),
+    ('\u{107B0}', true, &['\u{2C71}']),
+    ('\u{107B2}', true, &['\u{28F}']),
+    ('\u{107B3}', true, &['\u{2A1}']),
+    ('\u{107B4}', true, &['\u{2A2}']),
+    ('\u{107B5}', true, &['\u{298}']),
+    ('\u{107B6}', true, &['\u{1C0}']),
+    ('\u{107B7}', true, &['\u{1C1}']),
+    ('\u{107B8}', true, &['\u{1C2}']),
+    ('\u{107B9}', true, &['\u{1DF0A}']),
+    ('\u{107BA}', true, &['\u{1DF1E}']),
+    ('\u{1109A}', false, &['\u{11099}', '\u{110BA}']),
+    ('\u{1109C}', false, &['\u{1109B}', '\u{110BA}']),
+    ('\u{110AB}', false, &['\u{110A5}', '\u{110BA}']),
+    ('\u{1112E}', false, &['\u{11131}', '\u{11127}']),
+    ('\u{1112F}', false, &['\u{11132}', '\u{11127}']),
+    ('\u{1134B}', false, &['\u{11347}', '\u{1133E}']),
+    ('\u{1134C}', false, &['\u{11347}', '\u{11357}']),
+    ('\u{114BB}', false, &['\u{114B9}', '\u{114BA}']),
+    ('\u{114BC}', false, &['\u{114B9}', '\u{114B0}']),
+    ('\u{114BE}', false, &['\u{114B9}', '\u{114BD}']),
+    ('\u{115BA}', false, &['\u{115B8}', '\u{115AF}']),
+    ('\u{115BB}', false, &['\u{115B9}', '\u{115AF}']),
+    ('\u{11938}', false, &['\u{11935}', '\u{11930}']),
+    ('\u{1D15E}', false, &['\u{1D157}', '\u{1D165}']),
+    ('\u{1D15F}', false, &['\u{1D158}', '\u{1D165}']),
+    ('\u{1D160}', false, &['\u{1D15F}', '\u{1D16E}']),
+    ('\u{1D161}', false, &['\u{1D15F}', '\u{1D16F}']),
+    ('\u{1D162}', false, &['\u{1D15F}', '\u{1D170}']),
+    ('\u{1D163}', false, &['\u{1D15F}', '\u{1D171}']),
+    ('\u{1D164}', false, &['\u{1D15F}', '\u{1D172}']),
+    ('\u{1D1BB}', false, &['\u{1D1B9}', '\u{1D165}']),
+    ('\u{1D1BC}', false, &['\u{1D1BA}', '\u{1D165}']),
+    ('\u{1D1BD}', false, &['\u{1D1BB}', '\u{1D16E}']),
+    ('\u{1D1BE}', false, &['\u{1D1BC}', '\u{1D16E}']),
+    ('\u{1D1BF}', false, &['\u{1D1BB}', '\u{1D16F}']),
+    ('\u{1D1C0}', false, &['\u{1D1BC}', '\u{1D16F}']),
+    ('\u{1D400}', true, &['A']),
+    ('\u{1D401}', true, &['B']),
+    ('\u{1D402}', true, &['C']),
+    ('\u{1D403}', true, &['D']),
+    ('\u{1D404}', true, &['E']),
+    ('\u{1D405}', true, &['F']),
+    ('\u{1D406}', true, &['G']),
+    ('\u{1D407}', true, &['H']),
+    ('\u{1D408}', true, &['I']),
+    ('\u{1D409}', true, &['J']),
+    ('\u{1D40A}', true, &['K']),
+    ('\u{1D40B}', true, &['L']),
+    ('\u{1D40C}', true, &['M']),
+    ('\u{1D40D}', true, &['N']),
+    ('\u{1D40E}', true, &['O']),
+    ('\u{1D40F}', true, &['P']),
+    ('\u{1D410}', true, &['Q']),
+    ('\u{1D411}', true, &['R']),
+    ('\u{1D412}', true, &['S']),
+    ('\u{1D413}', true, &['T']),
+    ('\u{1D414}', true, &['U']),
+    ('\u{1D415}', true, &['V']),
+    ('\u{1D416}', true, &['W']),
+    ('\u{1D417}', true, &['X']),
+    ('\u{1D418}', true, &['Y']),
+    ('\u{1D419}', true, &['Z']),
+    ('\u{1D41A}', true, &['a']),
+    ('\u{1D41B}', true, &['b']),
+    ('\u{1D41C}', true, &['c']),
+    ('\u{1D41D}', true, &['d']),
+    ('\u{1D41E}', true, &['e']),
+    ('\u{1D41F}', true, &['f']),
+    ('\u{1D420}', true, &['g']),
+    ('\u{1D421}', true, &['h']),
+    ('\u{1D422}', true, &['i']),
+    ('\u{1D423}', true, &['j']),
+    ('\u{1D424}', true, &['k']),
+    ('\u{1D425}', true, &['l']),
+    ('\u{1D426}', true, &['m']),
+    ('\u{1D427}', true, &['n']),
+    ('\u{1D428}', true, &['o']),
+    ('\u{1D429}', true, &['p']),
+    ('\u{1D42A}', true, &['q']),
+    ('\u{1D42B}', true, &['r']),
+    ('\u{1D42C}', true, &['s']),
+    ('\u{1D42D}', true, &['t']),
+    ('\u{1D42E}', true, &['u']),
+    ('\u{1D42F}', true, &['v']),
+    ('\u{1D430}', true, &['w']),
+    ('\u{1D431}', true, &['x']),
+    ('\u{1D432}', true, &['y']),
+    ('\u{1D433}', true, &['z']),
+    ('\u{1D434}', true, &['A']),
+    ('\u{1D435}', true, &['B']),
+    ('\u{1D436}', true, &['C']),
+    ('\u{1D437}', true, &['D']),
+    ('\u{1D438}', true, &['E']),
+    ('\u{1D439}', true, &['F']),
+    ('\u{1D43A}', true, &['G']),
+    ('\u{1D43B}', true, &['H']),
+    ('\u{1D43C}', true, &['I']),
+    ('\u{1D43D}', true, &['J']),
+    ('\u{1D43E}', true, &['K']),
+    ('\u{1D43F}', true, &['L']),
+    ('\u{1D440}', true, &['M']),
+    ('\u{1D441}', true, &['N']),
+    ('\u{1D442}', true, &['O']),
+    ('\u{1D443}', true, &['P']),
+    ('\u{1D444}', true, &['Q']),
+    ('\u{1D445}', true, &['R']),
+    ('\u{1D446}', true, &['S']),
+    ('\u{1D447}', true, &['T']),
+    ('\u{1D448}', true, &['U']),
+    ('\u{1D449}', true, &['V']),
+    ('\u{1D44A}', true, &['W']),
+    ('\u{1D44B}', true, &['X']),
+    ('\u{1D44C}', true, &['Y']),
+    ('\u{1D44D}', true, &['Z']),
+    ('\u{1D44E}', true, &['a']),
+    ('\u{1D44F}', true, &['b']),
+    ('\u{1D450}', true, &['c']),
+    ('\u{1D451}', true, &['d']),
+    ('\u{1D452}', true, &['e']),
+    ('\u{1D453}', true, &['f']),
+    ('\u{1D454}', true, &['g']),
+    ('\u{1D456}', true, &['i']),
+    ('\u{1D457}', true, &['j']),
+    ('\u{1D458}', true, &['k']),
+    ('\u{1D459}', true, &['l']),
+    ('\u{1D45A}', true, &['m']),
+    ('\u{1D45B}', true, &['n']),
+    ('\u{1D45C}', true, &['o']),
+    ('\u{1D45D}', true, &['p']),
+    ('\u{1D45E}', true, &['q']),
+    ('\u{1D45F}', true, &['r']),
+    ('\u{1D460}', true, &['s']),
+    ('\u{1D461}', true, &['t']),
+    ('\u{1D462}', true, &['u']),
+    ('\u{1D463}', true, &['v']),
+    ('\u{1D464}', true, &['w']),
+    ('\u{1D465}', true, &['x']),
+    ('\u{1D466}', true, &['y']),
+    ('\u{1D467}', true, &['z']),
+    ('\u{1D468}', true, &['A']),
+    ('\u{1D469}', true, &['B']),
+    ('\u{1D46A}', true, &['C']),
+    ('\u{1D46B}', true, &['D']),
+    ('\u{1D46C}', true, &['E']),
+    ('\u{1D46D}', true, &['F']),
+    ('\u{1D46E}', true, &['G']),
+    ('\u{1D46F}', true, &['H']),
+    ('\u{1D470}', true, &['I']),
+    ('\u{1D471}', true, &['J']),
+    ('\u{1D472}', true, &['K']),
+    ('\u{1D473}', true, &['L']),
+    ('\u{1D474}', true, &['M']),
+    ('\u{1D475}', true, &['N']),
+    ('\u{1D476}', true, &['O']),
+    ('\u{1D477}', true, &['P']),
+    ('\u{1D478}', true, &['Q']),
+    ('\u{1D479}', true, &['R']),
+    ('\u{1D47A}', true, &['S']),
+    ('\u{1D47B}', true, &['T']),
+    ('\u{1D47C}', true, &['U']),
+    ('\u{1D47D}', true, &['V']),
+    ('\u{1D47E}', true, &['W']),
+    ('\u{1D47F}', true, &['X']),
+    ('\u{1D480}', true, &['Y']),
+    ('\u{1D481}', true, &['Z']),
+    ('\u{1D482}', true, &['a']),
+    ('\u{1D483}', true, &['b']),
+    ('\u{1D484}', true, &['c']),
+    ('\u{1D485}', true, &['d']),
+    ('\u{1D486}', true, &['e']),
+    ('\u{1D487}', true, &['f']),
+    ('\u{1D488}', true, &['g']),
+    ('\u{1D489}', true, &['h']),
+    ('\u{1D48A}', true, &['i']),
+    ('\u{1D48B}', true, &['j']),
+    ('\u{1D48C}', true, &['k']),
+    ('\u{1D48D}', true, &['l']),
+    ('\u{1D48E}', true, &['m']),
+    ('\u{1D48F}', true, &['n']),
+    ('\u{1D490}', true, &['o']),
+    ('\u{1D491}', true, &['p']),
+    ('\u{1D492}', true, &['q']),
+    ('\u{1D493}', true, &['r']),
+    ('\u{1D494}', true, &['s']),
+    ('\u{1D495}', true, &['t']),
+    ('\u{1D496}', true, &['u']),
+    ('\u{1D497}', true, &['v']),
+    ('\u{1D498}', true, &['w']),
+    ('\u{1D499}', true, &['x']),
+    ('\u{1D49A}', true, &['y']),
+    ('\u{1D49B}', true, &['z']),
+    ('\u{1D49C}', true, &['A']),
+    ('\u{1D49E}', true, &['C']),
+    ('\u{1D49F}', true, &['D']),
+    ('\u{1D4A2}', true, &['G']),
+    ('\u{1D4A5}', true, &['J']),
+    ('\u{1D4A6}', true, &['K']),
+    ('\u{1D4A9}', true, &['N']),
+    ('\u{1D4AA}', true, &['O']),
+    ('\u{1D4AB}', true, &['P']),
+    ('\u{1D4AC}', true, &['Q']),
+    ('\u{1D4AE}', true, &['S']),
+    ('\u{1D4AF}', true, &['T']),
+    ('\u{1D4B0}', true, &['U']),
+    ('\u{1D4B1}', true, &['V']),
+    ('\u{1D4B2}', true, &['W']),
+    ('\u{1D4B3}', true, &['X']),
+    ('\u{1D4B4}', true, &['Y']),
+    ('\u{1D4B5}', true, &['Z']),
+    ('\u{1D4B6}', true, &['a']),
+    ('\u{1D4B7}', true, &['b']),
+    ('\u{1D4B8}', true, &['c']),
+    ('\u{1D4B9}', true, &['d']),
+    ('\u{1D4BB}', true, &['f']),
+    ('\u{1D4BD}', true, &['h']),
+    ('\u{1D4BE}', true, &['i']),
+    ('\u{1D4BF}', true, &['j']),
+    ('\u{1D4C0}', true, &['k']),
+    ('\u{1D4C1}', true, &['l']),
+    ('\u{1D4C2}', true, &['m']),
+    ('\u{1D4C3}', true, &['n']),
+    ('\u{1D4C5}', true, &['p']),
+    ('\u{1D4C6}', true, &['q']),
+    ('\u{1D4C7}', true, &['r']),
+    ('\u{1D4C8}', true, &['s']),
+    ('\u{1D4C9}', true, &['t']),
+    ('\u{1D4CA}', true, &['u']),
+    ('\u{1D4CB}', true, &['v']),
+    ('\u{1D4CC}', true, &['w']),
+    ('\u{1D4CD}', true, &['x']),
+    ('\u{1D4CE}', true, &['y']),
+    ('\u{1D4CF}', true, &['z']),
+    ('\u{1D4D0}', true, &['A']),
+    ('\u{1D4D1}', true, &['B']),
+    ('\u{1D4D2}', true, &['C']),
+    ('\u{1D4D3}', true, &['D']),
+    ('\u{1D4D4}', true, &['E']),
+    ('\u{1D4D5}', true, &['F']),
+    ('\u{1D4D6}', true, &['G']),
+    ('\u{1D4D7}', true, &['H']),
+    ('\u{1D4D8}', true, &['I']),
+    ('\u{1D4D9}', true, &['J']),
+    ('\u{1D4DA}', true, &['K']),
+    ('\u{1D4DB}', true, &['L']),
+    ('\u{1D4DC}', true, &['M']),
+    ('\u{1D4DD}', true, &['N']),
+    ('\u{1D4DE}', true, &['O']),
+    ('\u{1D4DF}', true, &['P']),
+    ('\u{1D4E0}', true, &['Q']),
+    ('\u{1D4E1}', true, &['R']),
+    ('\u{1D4E2}', true, &['S']),
+    ('\u{1D4E3}', true, &['T']),
+    ('\u{1D4E4}', true, &['U']),
+    ('\u{1D4E5}', true, &['V']),
+    ('\u{1D4E6}', true, &['W']),
+    ('\u{1D4E7}', true, &['X']),
+    ('\u{1D4E8}', true, &['Y']),
+    ('\u{1D4E9}', true, &['Z']),
+    ('\u{1D4EA}', true, &['a']),
+    ('\u{1D4EB}', true, &['b']),
+    ('\u{1D4EC}', true, &['c']),
+    ('\u{1D4ED}', true, &['d']),
+    ('\u{1D4EE}', true, &['e']),
+    ('\u{1D4EF}', true, &['f']),
+    ('\u{1D4F0}', true, &['g']),
+    ('\u{1D4F1}', true, &['h']),
+    ('\u{1D4F2}', true, &['i']),
+    ('\u{1D4F3}', true, &['j']),
+    ('\u{1D4F4}', true, &['k']),
+    ('\u{1D4F5}', true, &['l']),
+    ('\u{1D4F6}', true, &['m']),
+    ('\u{1D4F7}', true, &['n']),
+    ('\u{1D4F8}', true, &['o']),
+    ('\u{1D4F9}', true, &['p']),
+    ('\u{1D4FA}', true, &['q']),
+    ('\u{1D4FB}', true, &['r']),
+    ('\u{1D4FC}', true, &['s']),
+    ('\u{1D4FD}', true, &['t']),
+    ('\u{1D4FE}', true, &['u']),
+    ('\u{1D4FF}', true, &['v']),
+    ('\u{1D500}', true, &['w']),
+    ('\u{1D501}', true, &['x']),
+    ('\u{1D502}', true, &['y']),
+    ('\u{1D503}', true, &['z']),
+    ('\u{1D504}', true, &['A']),
+    ('\u{1D505}', true, &['B']),
+    ('\u{1D507}', true, &['D']),
+    ('\u{1D508}', true, &['E']),
+    ('\u{1D509}', true, &['F']),
+    ('\u{1D50A}', true, &['G']),
+    ('\u{1D50D}', true, &['J']),
+    ('\u{1D50E}', true, &['K']),
+    ('\u{1D50F}', true, &['L']),
+    ('\u{1D510}', true, &['M']),
+    ('\u{1D511}', true, &['N']),
+    ('\u{1D512}', true, &['O']),
+    ('\u{1D513}', true, &['P']),
+    ('\u{1D514}', true, &['Q']),
+    ('\u{1D516}', true, &['S']),
+    ('\u{1D517}', true, &['T']),
+    ('\u{1D518}', true, &['U']),
+    ('\u{1D519}', true, &['V']),
+    ('\u{1D51A}', true, &['W']),
+    ('\u{1D51B}', true, &['X']),
+    ('\u{1D51C}', true, &['Y']),
+    ('\u{1D51E}', true, &['a']),
+    ('\u{1D51F}', true, &['b']),
+    ('\u{1D520}', true, &['c']),
+    ('\u{1D521}', true, &['d']),
+    ('\u{1D522}', true, &['e']),
+    ('\u{1D523}', true, &['f']),
+    ('\u{1D524}', true, &['g']),
+    ('\u{1D525}', true, &['h']),
+    ('\u{1D526}', true, &['i']),
+    ('\u{1D527}', true, &['j']),
+    ('\u{1D528}', true, &['k']),
+    ('\u{1D529}', true, &['l']),
+    ('\u{1D52A}', true, &['m']),
+    ('\u{1D52B}', true, &['n']),
+    ('\u{1D52C}', true, &['o']),
+    ('\u{1D52D}', true, &['p']),
+    ('\u{1D52E}', true, &['q']),
+    ('\u{1D52F}', true, &['r']),
+    ('\u{1D530}', true, &['s']),
+    ('\u{1D531}', true, &['t']),
+    ('\u{1D532}', true, &['u']),
+    ('\u{1D533}', true, &['v']),
+    ('\u{1D534}', true, &['w']),
+    ('\u{1D535}', true, &['x']),
+    ('\u{1D536}', true, &['y']),
+    ('\u{1D537}', true, &['z']),
+    ('\u{1D538}', true, &['A']),
+    ('\u{1D539}', true, &['B']),
+    ('\u{1D53B}', true, &['D']),
+    ('\u{1D53C}', true, &['E']),
+    ('\u{1D53D}', true, &['F']),
+    ('\u{1D53E}', true, &['G']),
+    ('\u{1D540}', true, &['I']),
+    ('\u{1D541}', true, &['J']),
+    ('\u{1D542}', true, &['K']),
+    ('\u{1D543}', true, &['L']),
+    ('\u{1D544}', true, &['M']),
+    ('\u{1D546}', true, &['O']),
+    ('\u{1D54A}', true, &['S']),
+    ('\u{1D54B}', true, &['T']),
+    ('\u{1D54C}', true, &['U']),
+    ('\u{1D54D}', true, &['V']),
+    ('\u{1D54E}', true, &['W']),
+    ('\u{1D54F}', true, &['X']),
+    ('\u{1D550}', true, &['Y']),
+    ('\u{1D552}', true, &['a']),
+    ('\u{1D553}', true, &['b']),
+    ('\u{1D554}', true, &['c']),
+    ('\u{1D555}', true, &['d']),
+    ('\u{1D556}', true, &['e']),
+    ('\u{1D557}', true, &['f']),
+    ('\u{1D558}', true, &['g']),
+    ('\u{1D559}', true, &['h']),
+    ('\u{1D55A}', true, &['i']),
+    ('\u{1D55B}', true, &['j']),
+    ('\u{1D55C}', true, &['k']),
+    ('\u{1D55D}', true, &['l']),
+    ('\u{1D55E}', true, &['m']),
+    ('\u{1D55F}', true, &['n']),
+    ('\u{1D560}', true, &['o']),
+    ('\u{1D561}', true, &['p']),
+    ('\u{1D562}', true, &['q']),
+    ('\u{1D563}', true, &['r']),
+    ('\u{1D564}', true, &['s']),
+    ('\u{1D565}', true, &['t']),
+    ('\u{1D566}', true, &['u']),
+    ('\u{1D567}', true, &['v']),
+    ('\u{1D568}', true, &['w']),
+    ('\u{1D569}', true, &['x']),
+    ('\u{1D56A}', true, &['y']),
+    ('\u{1D56B}', true, &['z']),
+    ('\u{1D56C}', true, &['A']),
+    ('\u{1D56D}', true, &['B']),
+    ('\u{1D56E}', true, &['C']),
+    ('\u{1D56F}', true, &['D']),
+    ('\u{1D570}', true, &['E']),
+    ('\u{1D571}', true, &['F']),
+    ('\u{1D572}', true, &['G']),
+    ('\u{1D573}', true, &['H']),
+    ('\u{1D574}', true, &['I']),
+    ('\u{1D575}', true, &['J']),
+    ('\u{1D576}', true, &['K']),
+    ('\u{1D577}', true, &['L']),
+    ('\u{1D578}', true, &['M']),
+    ('\u{1D579}', true, &['N']),
+    ('\u{1D57A}', true, &['O']),
+    ('\u{1D57B}', true, &['P']),
+    ('\u{1D57C}', true, &['Q']),
+    ('\u{1D57D}', true, &['R']),
+    ('\u{1D57E}', true, &['S']),
+    ('\u{1D57F}', true, &['T']),
+    ('\u{1D580}', true, &['U']),
+    ('\u{1D581}', true, &['V']),
+    ('\u{1D582}', true, &['W']),
+    ('\u{1D583}', true, &['X']),
+    ('\u{1D584}', true, &['Y']),
+    ('\u{1D585}', true, &['Z']),
+    ('\u{1D586}', true, &['a']),
+    ('\u{1D587}', true, &['b']),
+    ('\u{1D588}', true, &['c']),
+    ('\u{1D589}', true, &['d']),
+    ('\u{1D58A}', true, &['e']),
+    ('\u{1D58B}', true, &['f']),
+    ('\u{1D58C}', true, &['g']),
+    ('\u{1D58D}', true, &['h']),
+    ('\u{1D58E}', true, &['i']),
+    ('\u{1D58F}', true, &['j']),
+    ('\u{1D590}', true, &['k']),
+    ('\u{1D591}', true, &['l']),
+    ('\u{1D592}', true, &['m']),
+    ('\u{1D593}', true, &['n']),
+    ('\u{1D594}', true, &['o']),
+    ('\u{1D595}', true, &['p']),
+    ('\u{1D596}', true, &['q']),
+    ('\u{1D597}', true, &['r']),
+    ('\u{1D598}', true, &['s']),
+    ('\u{1D599}', true, &['t']),
+    ('\u{1D59A}', true, &['u']),
+    ('\u{1D59B}', true, &['v']),
+    ('\u{1D59C}', true, &['w']),
+    ('\u{1D59D}', true, &['x']),
+    ('\u{1D59E}', true, &['y']),
+    ('\u{1D59F}', true, &['z']),
+    ('\u{1D5A0}', true, &['A']),
+    ('\u{1D5A1}', true, &['B']),
+    ('\u{1D5A2}', true, &['C']),
+    ('\u{1D5A3}', true, &['D']),
+    ('\u{1D5A4}', true, &['E']),
+    ('\u{1D5A5}', true, &['F']),
+    ('\u{1D5A6}', true, &['G']),
+    ('\u{1D5A7}', true, &['H']),
+    ('\u{1D5A8}', true, &['I']),
+    ('\u{1D5A9}', true, &['J']),
+    ('\u{1D5AA}', true, &['K']),
+    ('\u{1D5AB}', true, &['L']),
+    ('\u{1D5AC}', true, &['M']),
+    ('\u{1D5AD}', true, &['N']),
+    ('\u{1D5AE}', true, &['O']),
+    ('\u{1D5AF}', true, &['P']),
+    ('\u{1D5B0}', true, &['Q']),
+    ('\u{1D5B1}', true, &['R']),
+    ('\u{1D5B2}', true, &['S']),
+    ('\u{1D5B3}', true, &['T']),
+    ('\u{1D5B4}', true, &['U']),
+    ('\u{1D5B5}', true, &['V']),
+    ('\u{1D5B6}', true, &['W']),
+    ('\u{1D5B7}', true, &['X']),
+    ('\u{1D5B8}', true, &['Y']),
+    ('\u{1D5B9}', true, &['Z']),
+    ('\u{1D5BA}', true, &['a']),
+    ('\u{1D5BB}', true, &['b']),
+    ('\u{1D5BC}', true, &['c']),
+    ('\u{1D5BD}', true, &['d']),
+    ('\u{1D5BE}', true, &['e']),
+    ('\u{1D5BF}', true, &['f']),
+    ('\u{1D5C0}', true, &['g']),
+    ('\u{1D5C1}', true, &['h']),
+    ('\u{1D5C2}', true, &['i']),
+    ('\u{1D5C3}', true, &['j']),
+    ('\u{1D5C4}', true, &['k']),
+    ('\u{1D5C5}', true, &['l']),
+    ('\u{1D5C6}', true, &['m']),
+    ('\u{1D5C7}', true, &['n']),
+    ('\u{1D5C8}', true, &['o']),
+    ('\u{1D5C9}', true, &['p']),
+    ('\u{1D5CA}', true, &['q']),
+    ('\u{1D5CB}', true, &['r']),
+    ('\u{1D5CC}', true, &['s']),
+    ('\u{1D5CD}', true, &['t']),
+    ('\u{1D5CE}', true, &['u']),
+    ('\u{1D5CF}', true, &['v']),
+    ('\u{1D5D0}', true, &['w']),
+    ('\u{1D5D1}', true, &['x']),
+    ('\u{1D5D2}', true, &['y']),
+    ('\u{1D5D3}', true, &['z']),
+    ('\u{1D5D4}', true, &['A']),
+    ('\u{1D5D5}', true, &['B']),
+    ('\u{1D5D6}', true, &['C']),
+    ('\u{1D5D7}', true, &['D']),
+    ('\u{1D5D8}', true, &['E']),
+    ('\u{1D5D9}', true, &['F']),
+    ('\u{1D5DA}', true, &['G']),
+    ('\u{1D5DB}', true, &['H']),
+    ('\u{1D5DC}', true, &['I']),
+    ('\u{1D5DD}', true, &['J']),
+    ('\u{1D5DE}', true, &['K']),
+    ('\u{1D5DF}', true, &['L']),
+    ('\u{1D5E0}', true, &['M']),
+    ('\u{1D5E1}', true, &['N']),
+    ('\u{1D5E2}', true, &['O']),
+    ('\u{1D5E3}', true, &['P']),
+    ('\u{1D5E4}', true, &['Q']),
+    ('\u{1D5E5}', true, &['R']),
+    ('\u{1D5E6}', true, &['S']),
+    ('\u{1D5E7}', true, &['T']),
+    ('\u{1D5E8}', true, &['U']),
+    ('\u{1D5E9}', true, &['V']),
+    ('\u{1D5EA}', true, &['W']),
+    ('\u{1D5EB}', true, &['X']),
+    ('\u{1D5EC}', true, &['Y']),
+    ('\u{1D5ED}', true, &['Z']),
+    ('\u{1D5EE}', true, &['a']),
+    ('\u{1D5EF}', true, &['b']),
+    ('\u{1D5F0}', true, &['c']),
+    ('\u{1D5F1}', true, &['d']),
+    ('\u{1D5F2}', true, &['e']),
+    ('\u{1D5F3}', true, &['f']),
+    ('\u{1D5F4}', true, &['g']),
+    ('\u{1D5F5}', true, &['h']),
+    ('\u{1D5F6}', true, &['i']),
+    ('\u{1D5F7}', true, &['j']),
+    ('\u{1D5F8}', true, &['k']),
+    ('\u{1D5F9}', true, &['l']),
+    ('\u{1D5FA}', true, &['m']),
+    ('\u{1D5FB}', true, &['n']),
+    ('\u{1D5FC}', true, &['o']),
+    ('\u{1D5FD}', true, &['p']),
+    ('\u{1D5FE}', true, &['q']),
+    ('\u{1D5FF}', true, &['r']),
+    ('\u{1D600}', true, &['s']),
+    ('\u{1D601}', true, &['t']),
+    ('\u{1D602}', true, &['u']),
+    ('\u{1D603}', true, &['v']),
+    ('\u{1D604}', true, &['w']),
+    ('\u{1D605}', true, &['x']),
+    ('\u{1D606}', true, &['y']),
+    ('\u{1D607}', true, &['z']),
+    ('\u{1D608}', true, &['A']),
+    ('\u{1D609}', true, &['B']),
+    ('\u{1D60A}', true, &['C']),
+    ('\u{1D60B}', true, &['D']),
+    ('\u{1D60C}', true, &['E']),
+    ('\u{1D60D}', true, &['F']),
+    ('\u{1D60E}', true, &['G']),
+    ('\u{1D60F}', true, &['H']),
+    ('\u{1D610}', true, &['I']),
+    ('\u{1D611}', true, &['J']),
+    ('\u{1D612}', true, &['K']),
+    ('\u{1D613}', true, &['L']),
+    ('\u{1D614}', true, &['M']),
+    ('\u{1D615}', true, &['N']),
+    ('\u{1D616}', true, &['O']),
+    ('\u{1D617}', true, &['P']),
+    ('\u{1D618}', true, &['Q']),
+    ('\u{1D619}', true, &['R']),
+    ('\u{1D61A}', true, &['S']),
+    ('\u{1D61B}', true, &['T']),
+    ('\u{1D61C}', true, &['U']),
+    ('\u{1D61D}', true, &['V']),
+    ('\u{1D61E}', true, &['W']),
+    ('\u{1D61F}', true, &['X']),
+    ('\u{1D620}', true, &['Y']),
+    ('\u{1D621}', true, &['Z']),
+    ('\u{1D622}', true, &['a']),
+    ('\u{1D623}', true, &['b']),
+    ('\u{1D624}', true, &['c']),
+    ('\u{1D625}', true, &['d']),
+    ('\u{1D626}', true, &['e']),
+    ('\u{1D627}', true, &['f']),
+    ('\u{1D628}', true, &['g']),
+    ('\u{1D629}', true, &['h']),
+    ('\u{1D62A}', true, &['i']),
+    ('\u{1D62B}', true, &['j']),
+    ('\u{1D62C}', true, &['k']),
+    ('\u{1D62D}', true, &['l']),
+    ('\u{1D62E}', true, &['m']),
+    ('\u{1D62F}', true, &['n']),
+    ('\u{1D630}', true, &['o']),
+    ('\u{1D631}', true, &['p']),
+    ('\u{1D632}', true, &['q']),
+    ('\u{1D633}', true, &['r']),
+    ('\u{1D634}', true, &['s']),
+    ('\u{1D635}', true, &['t']),
+    ('\u{1D636}', true, &['u']),
+    ('\u{1D637}', true, &['v']),
+    ('\u{1D638}', true, &['w']),
+    ('\u{1D639}', true, &['x']),
+    ('\u{1D63A}', true, &['y']),
+    ('\u{1D63B}', true, &['z']),
+    ('\u{1D63C}', true, &['A']),
+    ('\u{1D63D}', true, &['B']),
+    ('\u{1D63E}', true, &['C']),
+    ('\u{1D63F}', true, &['D']),
+    ('\u{1D640}', true, &['E']),
+    ('\u{1D641}', true, &['F']),
+    ('\u{1D642}', true, &['G']),
+    ('\u{1D643}', true, &['H']),
+    ('\u{1D644}', true, &['I']),
+    ('\u{1D645}', true, &['J']),
+    ('\u{1D646}', true, &['K']),
+    ('\u{1D647}', true, &['L']),
+    ('\u{1D648}', true, &['M']),
+    ('\u{1D649}', true, &['N']),
+    ('\u{1D64A}', true, &['O']),
+    ('\u{1D64B}', true, &['P']),
+    ('\u{1D64C}', true, &['Q']),
+    ('\u{1D64D}', true, &['R']),
+    ('\u{1D64E}', true, &['S']),
+    ('\u{1D64F}', true, &['T']),
+    ('\u{1D650}', true, &['U']),
+    ('\u{1D651}', true, &['V']),
+    ('\u{1D652}', true, &['W']),
+    ('\u{1D653}', true, &['X']),
+    ('\u{1D654}', true, &['Y']),
+    ('\u{1D655}', true, &['Z']),
+    ('\u{1D656}', true, &['a']),
+    ('\u{1D657}', true, &['b']),
+    ('\u{1D658}', true, &['c']),
+    ('\u{1D659}', true, &['d']),
+    ('\u{1D65A}', true, &['e']),
+    ('\u{1D65B}', true, &['f']),
+    ('\u{1D65C}', true, &['g']),
+    ('\u{1D65D}', true, &['h']),
+    ('\u{1D65E}', true, &['i']),
+    ('\u{1D65F}', true, &['j']),
+    ('\u{1D660}', true, &['k']),
+    ('\u{1D661}', true, &['l']),
+    ('\u{1D662}', true, &['m']),
+    ('\u{1D663}', true, &['n']),
+    ('\u{1D664}', true, &['o']),
+    ('\u{1D665}', true, &['p']),
+    ('\u{1D666}', true, &['q']),
+    ('\u{1D667}', true, &['r']),
+    ('\u{1D668}', true, &['s']),
+    ('\u{1D669}', true, &['t']),
+    ('\u{1D66A}', true, &['u']),
+    ('\u{1D66B}', true, &['v']),
+    ('\u{1D66C}', true, &['w']),
+    ('\u{1D66D}', true, &['x']),
+    ('\u{1D66E}', true, &['y']),
+    ('\u{1D66F}', true, &['z']),
+    ('\u{1D670}', true, &['A']),
+    ('\u{1D671}', true, &['B']),
+    ('\u{1D672}', true, &['C']),
+    ('\u{1D673}', true, &['D']),
+    ('\u{1D674}', true, &['E']),
+    ('\u{1D675}', true, &['F']),
+    ('\u{1D676}', true, &['G']),
+    ('\u{1D677}', true, &['H']),
+    ('\u{1D678}', true, &['I']),
+    ('\u{1D679}', true, &['J']),
+    ('\u{1D67A}', true, &['K']),
+    ('\u{1D67B}', true, &['L']),
+    ('\u{1D67C}', true, &['M']),
+    ('\u{1D67D}', true, &['N']),
+    ('\u{1D67E}', true, &['O']),
+    ('\u{1D67F}', true, &['P']),
+    ('\u{1D680}', true, &['Q']),
+    ('\u{1D681}', true, &['R']),
+    ('\u{1D682}', true, &['S']),
+    ('\u{1D683}', true, &['T']),
+    ('\u{1D684}', true, &['U']),
+    ('\u{1D685}', true, &['V']),
+    ('\u{1D686}', true, &['W']),
+    ('\u{1D687}', true, &['X']),
+    ('\u{1D688}', true, &['Y']),
+    ('\u{1D689}', true, &['Z']),
+    ('\u{1D68A}', true, &['a']),
+    ('\u{1D68B}', true, &['b']),
+    ('\u{1D68C}', true, &['c']),
+    ('\u{1D68D}', true, &['d']),
+    ('\u{1D68E}', true, &['e']),
+    ('\u{1D68F}', true, &['f']),
+    ('\u{1D690}', true, &['g']),
+    ('\u{1D691}', true, &['h']),
+    ('\u{1D692}', true, &['i']),
+    ('\u{1D693}', true, &['j']),
+    ('\u{1D694}', true, &['k']),
+    ('\u{1D695}', true, &['l']),
+    ('\u{1D696}', true, &['m']),
+    ('\u{1D697}', true, &['n']),
+    ('\u{1D698}', true, &['o']),
+    ('\u{1D699}', true, &['p']),
+    ('\u{1D69A}', true, &['q']),
+    ('\u{1D69B}', true, &['r']),
+    ('\u{1D69C}', true, &['s']),
+    ('\u{1D69D}', true, &['t']),
+    ('\u{1D69E}', true, &['u']),
+    ('\u{1D69F}', true, &['v']),
+    ('\u{1D6A0}', true, &['w']),
+    ('\u{1D6A1}', true, &['x']),
+    ('\u{1D6A2}', true, &['y']),
+    ('\u{1D6A3}', true, &['z']),
+    ('\u{1D6A4}', true, &['\u{131}']),
+    ('\u{1D6A5}', true, &['\u{237}']),
+    ('\u{1D6A8}', true, &['\u{391}']),
+    ('\u{1D6A9}', true, &['\u{392}']),
+    ('\u{1D6AA}', true, &['\u{393}']),
+    ('\u{1D6AB}', true, &['\u{394}']),
+    ('\u{1D6AC}', true, &['\u{395}']),
+    ('\u{1D6AD}', true, &['\u{396}']),
+    ('\u{1D6AE}', true, &['\u{397}']),
+    ('\u{1D6AF}', true, &['\u{398}']),
+    ('\u{1D6B0}', true, &['\u{399}']),
+    ('\u{1D6B1}', true, &['\u{39A}']),
+    ('\u{1D6B2}', true, &['\u{39B}']),
+    ('\u{1D6B3}', true, &['\u{39C}']),
+    ('\u{1D6B4}', true, &['\u{39D}']),
+    ('\u{1D6B5}', true, &['\u{39E}']),
+    ('\u{1D6B6}', true, &['\u{39F}']),
+    ('\u{1D6B7}', true, &['\u{3A0}']),
+    ('\u{1D6B8}', true, &['\u{3A1}']),
+    ('\u{1D6B9}', true, &['\u{3F4}']),
+    ('\u{1D6BA}', true, &['\u{3A3}']),
+    ('\u{1D6BB}', true, &['\u{3A4}']),
+    ('\u{1D6BC}', true, &['\u{3A5}']),
+    ('\u{1D6BD}', true, &['\u{3A6}']),
+    ('\u{1D6BE}', true, &['\u{3A7}']),
+    ('\u{1D6BF}', true, &['\u{3A8}']),
+    ('\u{1D6C0}', true, &['\u{3A9}']),
+    ('\u{1D6C1}', true, &['\u{2207}']),
+    ('\u{1D6C2}', true, &['\u{3B1}']),
+    ('\u{1D6C3}', true, &['\u{3B2}']),
+    ('\u{1D6C4}', true, &['\u{3B3}']),
+    ('\u{1D6C5}', true, &['\u{3B4}']),
+    ('\u{1D6C6}', true, &['\u{3B5}']),
+    ('\u{1D6C7}', true, &['\u{3B6}']),
+    ('\u{1D6C8}', true, &['\u{3B7}']),
+    ('\u{1D6C9}', true, &['\u{3B8}']),
+    ('\u{1D6CA}', true, &['\u{3B9}']),
+    ('\u{1D6CB}', true, &['\u{3BA}']),
+    ('\u{1D6CC}', true, &['\u{3BB}']),
+    ('\u{1D6CD}', true, &['\u{3BC}']),
+    ('\u{1D6CE}', true, &['\u{3BD}']),
+    ('\u{1D6CF}', true, &['\u{3BE}']),
+    ('\u{1D6D0}', true, &['\u{3BF}']),
+    ('\u{1D6D1}', true, &['\u{3C0}']),
+    ('\u{1D6D2}', true, &['\u{3C1}']),
+    ('\u{1D6D3}', true, &['\u{3C2}']),
+    ('\u{1D6D4}', true, &['\u{3C3}']),
+    ('\u{1D6D5}', true, &['\u{3C4}']),
+    ('\u{1D6D6}', true, &['\u{3C5}']),
+    ('\u{1D6D7}', true, &['\u{3C6}']),
+    ('\u{1D6D8}', true, &['\u{3C7}']),
+    ('\u{1D6D9}', true, &['\u{3C8}']),
+    ('\u{1D6DA}', true, &['\u{3C9}']),
+    ('\u{1D6DB}', true, &['\u{2202}']),
+    ('\u{1D6DC}', true, &['\u{3F5}']),
+    ('\u{1D6DD}', true, &['\u{3D1}']),
+    ('\u{1D6DE}', true, &['\u{3F0}']),
+    ('\u{1D6DF}', true, &['\u{3D5}']),
+    ('\u{1D6E0}', true, &['\u{3F1}']),
+    ('\u{1D6E1}', true, &['\u{3D6}']),
+    ('\u{1D6E2}', true, &['\u{391}']),
+    ('\u{1D6E3}', true, &['\u{392}']),
+    ('\u{1D6E4}', true, &['\u{393}']),
+    ('\u{1D6E5}', true, &['\u{394}']),
+    ('\u{1D6E6}', true, &['\u{395}']),
+    ('\u{1D6E7}', true, &['\u{396}']),
+    ('\u{1D6E8}', true, &['\u{397}']),
+    ('\u{1D6E9}', true, &['\u{398}']),
+    ('\u{1D6EA}', true, &['\u{399}']),
+    ('\u{1D6EB}', true, &['\u{39A}']),
+    ('\u{1D6EC}', true, &['\u{39B}']),
+    ('\u{1D6ED}', true, &['\u{39C}']),
+    ('\u{1D6EE}', true, &['\u{39D}']),
+    ('\u{1D6EF}', true, &['\u{39E}']),
+    ('\u{1D6F0}', true, &['\u{39F}']),
+    ('\u{1D6F1}', true, &['\u{3A0}']),
+    ('\u{1D6F2}', true, &['\u{3A1}']),
+    ('\u{1D6F3}', true, &['\u{3F4}']),
+    ('\u{1D6F4}', true, &['\u{3A3}']),
+    ('\u{1D6F5}', true, &['\u{3A4}']),
+    ('\u{1D6F6}', true, &['\u{3A5}']),
+    ('\u{1D6F7}', true, &['\u{3A6}']),
+    ('\u{1D6F8}', true, &['\u{3A7}']),
+    ('\u{1D6F9}', true, &['\u{3A8}']),
+    ('\u{1D6FA}', true, &['\u{3A9}']),
+    ('\u{1D6FB}', true, &['\u{2207}']),
+    ('\u{1D6FC}', true, &['\u{3B1}']),
+    ('\u{1D6FD}', true, &['\u{3B2}']),
+    ('\u{1D6FE}', true, &['\u{3B3}']),
+    ('\u{1D6FF}', true, &['\u{3B4}']),
+    ('\u{1D700}', true, &['\u{3B5}']),
+    ('\u{1D701}', true, &['\u{3B6}']),
+    ('\u{1D702}', true, &['\u{3B7}']),
+    ('\u{1D703}', true, &['\u{3B8}']),
+    ('\u{1D704}', true, &['\u{3B9}']),
+    ('\u{1D705}', true, &['\u{3BA}']),
+    ('\u{1D706}', true, &['\u{3BB}']),
+    ('\u{1D707}', true, &['\u{3BC}']),
+    ('\u{1D708}', true, &['\u{3BD}']),
+    ('\u{1D709}', true, &['\u{3BE}']),
+    ('\u{1D70A}', true, &['\u{3BF}']),
+    ('\u{1D70B}', true, &['\u{3C0}']),
+    ('\u{1D70C}', true, &['\u{3C1}']),
+    ('\u{1D70D}', true, &['\u{3C2}']),
+    ('\u{1D70E}', true, &['\u{3C3}']),
+    ('\u{1D70F}', true, &['\u{3C4}']),
+    ('\u{1D710}', true, &['\u{3C5}']),
+    ('\u{1D711}', true, &['\u{3C6}']),
+    ('\u{1D712}', true, &['\u{3C7}']),
+    ('\u{1D713}', true, &['\u{3C8}']),
+    ('\u{1D714}', true, &['\u{3C9}']),
+    ('\u{1D715}', true, &['\u{2202}']),
+    ('\u{1D716}', true, &['\u{3F5}']),
+    ('\u{1D717}', true, &['\u{3D1}']),
+    ('\u{1D718}', true, &['\u{3F0}']),
+    ('\u{1D719}', true, &['\u{3D5}']),
+    ('\u{1D71A}', true, &['\u{3F1}']),
+    ('\u{1D71B}', true, &['\u{3D6}']),
+    ('\u{1D71C}', true, &['\u{391}']),
+    ('\u{1D71D}', true, &['\u{392}']),
+    ('\u{1D71E}', true, &['\u{393}']),
+    ('\u{1D71F}', true, &['\u{394}']),
+    ('\u{1D720}', true, &['\u{395}']),
+    ('\u{1D721}', true, &['\u{396}']),
+    ('\u{1D722}', true, &['\u{397}']),
+    ('\u{1D723}', true, &['\u{398}']),
+    ('\u{1D724}', true, &['\u{399}']),
+    ('\u{1D725}', true, &['\u{39A}']),
+    ('\u{1D726}', true, &['\u{39B}']),
+    ('\u{1D727}', true, &['\u{39C}']),
+    ('\u{1D728}', true, &['\u{39D}']),
+    ('\u{1D729}', true, &['\u{39E}']),
+    ('\u{1D72A}', true, &['\u{39F}']),
+    ('\u{1D72B}', true, &['\u{3A0}']),
+    ('\u{1D72C}', true, &['\u{3A1}']),
+    ('\u{1D72D}', true, &['\u{3F4}']),
+    ('\u{1D72E}', true, &['\u{3A3}']),
+    ('\u{1D72F}', true, &['\u{3A4}']),
+    ('\u{1D730}', true, &['\u{3A5}']),
+    ('\u{1D731}', true, &['\u{3A6}']),
+    ('\u{1D732}', true, &['\u{3A7}']),
+    ('\u{1D733}', true, &['\u{3A8}']),
+    ('\u{1D734}', true, &['\u{3A9}']),
+    ('\u{1D735}', true, &['\u{2207}']),
+    ('\u{1D736}', true, &['\u{3B1}']),
+    ('\u{1D737}', true, &['\u{3B2}']),
+    ('\u{1D738}', true, &['\u{3B3}']),
+    ('\u{1D739}', true, &['\u{3B4}']),
+    ('\u{1D73A}', true, &['\u{3B5}']),
+    ('\u{1D73B}', true, &['\u{3B6}']),
+    ('\u{1D73C}', true, &['\u{3B7}']),
+    ('\u{1D73D}', true, &['\u{3B8}']),
+    ('\u{1D73E}', true, &['\u{3B9}']),
+    ('\u{1D73F}', true, &['\u{3BA}']),
+    ('\u{1D740}', true, &['\u{3BB}']),
+    ('\u{1D741}', true, &['\u{3BC}']),
+    ('\u{1D742}', true, &['\u{3BD}']),
+    ('\u{1D743}', true, &['\u{3BE}']),
+    ('\u{1D744}', true, &['\u{3BF}']),
+    ('\u{1D745}', true, &['\u{3C0}']),
+    ('\u{1D746}', true, &['\u{3C1}']),
+    ('\u{1D747}', true, &['\u{3C2}']),
+    ('\u{1D748}', true, &['\u{3C3}']),
+    ('\u{1D749}', true, &['\u{3C4}']),
+    ('\u{1D74A}', true, &['\u{3C5}']),
+    ('\u{1D74B}', true, &['\u{3C6}']),
+    ('\u{1D74C}', true, &['\u{3C7}']),
+    ('\u{1D74D}', true, &['\u{3C8}']),
+    ('\u{1D74E}', true, &['\u{3C9}']),
+    ('\u{1D74F}', true, &['\u{2202}']),
+    ('\u{1D750}', true, &['\u{3F5}']),
+    ('\u{1D751}', true, &['\u{3D1}']),
+    ('\u{1D752}', true, &['\u{3F0}']),
+    ('\u{1D753}', true, &['\u{3D5}']),
+    ('\u{1D754}', true, &['\u{3F1}']),
+    ('\u{1D755}', true, &['\u{3D6}']),
+    ('\u{1D756}', true, &['\u{391}']),
+    ('\u{1D757}', true, &['\u{392}']),
+    ('\u{1D758}', true, &['\u{393}']),
+    ('\u{1D759}', true, &['\u{394}']),
+    ('\u{1D75A}', true, &['\u{395}']),
+    ('\u{1D75B}', true, &['\u{396}']),
+    ('\u{1D75C}', true, &['\u{397}']),
+    ('\u{1D75D}', true, &['\u{398}']),
+    ('\u{1D75E}', true, &['\u{399}']),
+    ('\u{1D75F}', true, &['\u{39A}']),
+    ('\u{1D760}', true, &['\u{39B}']),
+    ('\u{1D761}', true, &['\u{39C}']),
+    ('\u{1D762}', true, &['\u{39D}']),
+    ('\u{1D763}', true, &['\u{39E}']),
+    ('\u{1D764}', true, &['\u{39F}']),
+    ('\u{1D765}', true, &['\u{3A0}']),
+    ('\u{1D766}', true, &['\u{3A1}']),
+    ('\u{1D767}', true, &['\u{3F4}']),
+    ('\u{1D768}', true, &['\u{3A3}']),
+    ('\u{1D769}', true, &['\u{3A4}']),
+    ('\u{1D76A}', true, &['\u{3A5}']),
+    ('\u{1D76B}', true, &['\u{3A6}']),
+    ('\u{1D76C}', true, &['\u{3A7}']),
+    ('\u{1D76D}', true, &['\u{3A8}']),
+    ('\u{1D76E}', true, &['\u{3A9}']),
+    ('\u{1D76F}', true, &['\u{2207}']),
+    ('\u{1D770}', true, &['\u{3B1}']),
+    ('\u{1D771}', true, &['\u{3B2}']),
+    ('\u{1D772}', true, &['\u{3B3}']),
+    ('\u{1D773}', true, &['\u{3B4}']),
+    ('\u{1D774}', true, &['\u{3B5}']),
+    ('\u{1D775}', true, &['\u{3B6}']),
+    ('\u{1D776}', true, &['\u{3B7}']),
+    ('\u{1D777}', true, &['\u{3B8}']),
+    ('\u{1D778}', true, &['\u{3B9}']),
+    ('\u{1D779}', true, &['\u{3BA}']),
+    ('\u{1D77A}', true, &['\u{3BB}']),
+    ('\u{1D77B}', true, &['\u{3BC}']),
+    ('\u{1D77C}', true, &['\u{3BD}']),
+    ('\u{1D77D}', true, &['\u{3BE}']),
+    ('\u{1D77E}', true, &['\u{3BF}']),
+    ('\u{1D77F}', true, &['\u{3C0}']),
+    ('\u{1D780}', true, &['\u{3C1}']),
+    ('\u{1D781}', true, &['\u{3C2}']),
+    ('\u{1D782}', true, &['\u{3C3}']),
+    ('\u{1D783}', true, &['\u{3C4}']),
+    ('\u{1D784}', true, &['\u{3C5}']),
+    ('\u{1D785}', true, &['\u{3C6}']),
+    ('\u{1D786}', true, &['\u{3C7}']),
+    ('\u{1D787}', true, &['\u{3C8}']),
+    ('\u{1D788}', true, &['\u{3C9}']),
+    ('\u{1D789}', true, &['\u{2202}']),
+    ('\u{1D78A}', true, &['\u{3F5}']),
+    ('\u{1D78B}', true, &['\u{3D1}']),
+    ('\u{1D78C}', true, &['\u{3F0}']),
+    ('\u{1D78D}', true, &['\u{3D5}']),
+    ('\u{1D78E}', true, &['\u{3F1}']),
+    ('\u{1D78F}', true, &['\u{3D6}']),
+    ('\u{1D790}', true, &['\u{391}']),
+    ('\u{1D791}', true, &['\u{392}']),
+    ('\u{1D792}', true, &['\u{393}']),
+    ('\u{1D793}', true, &['\u{394}']),
+    ('\u{1D794}', true, &['\u{395}']),
+    ('\u{1D795}', true, &['\u{396}']),
+    ('\u{1D796}', true, &['\u{397}']),
+    ('\u{1D797}', true, &['\u{398}']),
+    ('\u{1D798}', true, &['\u{399}']),
+    ('\u{1D799}', true, &['\u{39A}']),
+    ('\u{1D79A}', true, &['\u{39B}']),
+    ('\u{1D79B}', true, &['\u{39C}']),
+    ('\u{1D79C}', true, &['\u{39D}']),
+    ('\u{1D79D}', true, &['\u{39E}']),
+    ('\u{1D79E}', true, &['\u{39F}']),
+    ('\u{1D79F}', true, &['\u{3A0}']),
+    ('\u{1D7A0}', true, &['\u{3A1}']),
+    ('\u{1D7A1}', true, &['\u{3F4}']),
+    ('\u{1D7A2}', true, &['\u{3A3}']),
+    ('\u{1D7A3}', true, &['\u{3A4}']),
+    ('\u{1D7A4}', true, &['\u{3A5}']),
+    ('\u{1D7A5}', true, &['\u{3A6}']),
+    ('\u{1D7A6}', true, &['\u{3A7}']),
+    ('\u{1D7A7}', true, &['\u{3A8}']),
+    ('\u{1D7A8}', true, &['\u{3A9}']),
+    ('\u{1D7A9}', true, &['\u{2207}']),
+    ('\u{1D7AA}', true, &['\u{3B1}']),
+    ('\u{1D7AB}', true, &['\u{3B2}']),
+    ('\u{1D7AC}', true, &['\u{3B3}']),
+    ('\u{1D7AD}', true, &['\u{3B4}']),
+    ('\u{1D7AE}', true, &['\u{3B5}']),
+    ('\u{1D7AF}', true, &['\u{3B6}']),
+    ('\u{1D7B0}', true, &['\u{3B7}']),
+    ('\u{1D7B1}', true, &['\u{3B8}']),
+    ('\u{1D7B2}', true, &['\u{3B9}']),
+    ('\u{1D7B3}', true, &['\u{3BA}']),
+    ('\u{1D7B4}', true, &['\u{3BB}']),
+    ('\u{1D7B5}', true, &['\u{3BC}']),
+    ('\u{1D7B6}', true, &['\u{3BD}']),
+    ('\u{1D7B7}', true, &['\u{3BE}']),
+    ('\u{1D7B8}', true, &['\u{3BF}']),
+    ('\u{1D7B9}', true, &['\u{3C0}']),
+    ('\u{1D7BA}', true, &['\u{3C1}']),
+    ('\u{1D7BB}', true, &['\u{3C2}']),
+    ('\u{1D7BC}', true, &['\u{3C3}']),
+    ('\u{1D7BD}', true, &['\u{3C4}']),
+    ('\u{1D7BE}', true, &['\u{3C5}']),
+    ('\u{1D7BF}', true, &['\u{3C6}']),
+    ('\u{1D7C0}', true, &['\u{3C7}']),
+    ('\u{1D7C1}', true, &['\u{3C8}']),
+    ('\u{1D7C2}', true, &['\u{3C9}']),
+    ('\u{1D7C3}', true, &['\u{2202}']),
+    ('\u{1D7C4}', true, &['\u{3F5}']),
+    ('\u{1D7C5}', true, &['\u{3D1}']),
+    ('\u{1D7C6}', true, &['\u{3F0}']),
+    ('\u{1D7C7}', true, &['\u{3D5}']),
+    ('\u{1D7C8}', true, &['\u{3F1}']),
+    ('\u{1D7C9}', true, &['\u{3D6}']),
+    ('\u{1D7CA}', true, &['\u{3DC}']),
+    ('\u{1D7CB}', true, &['\u{3DD}']),
+    ('\u{1D7CE}', true, &['0']),
+    ('\u{1D7CF}', true, &['1']),
+    ('\u{1D7D0}', true, &['2']),
+    ('\u{1D7D1}', true, &['3']),
+    ('\u{1D7D2}', true, &['4']),
+    ('\u{1D7D3}', true, &['5']),
+    ('\u{1D7D4}', true, &['6']),
+    ('\u{1D7D5}', true, &['7']),
+    ('\u{1D7D6}', true, &['8']),
+    ('\u{1D7D7}', true, &['9']),
+    ('\u{1D7D8}', true, &['0']),
+    ('\u{1D7D9}', true, &['1']),
+    ('\u{1D7DA}', true, &['2']),
+    ('\u{1D7DB}', true, &['3']),
+    ('\u{1D7DC}', true, &['4']),
+    ('\u{1D7DD}', true, &['5']),
+    ('\u{1D7DE}', true, &['6']),
+    ('\u{1D7DF}', true, &['7']),
+    ('\u{1D7E0}', true, &['8']),
+    ('\u{1D7E1}', true, &['9']),
+    ('\u{1D7E2}', true, &['0']),
+    ('\u{1D7E3}', true, &['1']),
+    ('\u{1D7E4}', true, &['2']),
+    ('\u{1D7E5}', true, &['3']),
+    ('\u{1D7E6}', true, &['4']),
+    ('\u{1D7E7}', true, &['5']),
+    ('\u{1D7E8}', true, &['6']),
+    ('\u{1D7E9}', true, &['7']),
+    ('\u{1D7EA}', true, &['8']),
+    ('\u{1D7EB}', true, &['9']),
+    ('\u{1D7EC}', true, &['0']),
+    ('\u{1D7ED}', true, &['1']),
+    ('\u{1D7EE}', true, &['2']),
+    ('\u{1D7EF}', true, &['3']),
+    ('\u{1D7F0}', true, &['4']),
+    ('\u{1D7F1}', true, &['5']),
+    ('\u{1D7F2}', true, &['6']),
+    ('\u{1D7F3}', true, &['7']),
+    ('\u{1D7F4}', true, &['8']),
+    ('\u{1D7F5}', true, &['9']),
+    ('\u{1D7F6}', true, &['0']),
+    ('\u{1D7F7}', true, &['1']),
+    ('\u{1D7F8}', true, &['2']),
+    ('\u{1D7F9}', true, &['3']),
+    ('\u{1D7FA}', true, &['4']),
+    ('\u{1D7FB}', true, &['5']),
+    ('\u{1D7FC}', true, &['6']),
+    ('\u{1D7FD}', true, &['7']),
+    ('\u{1D7FE}', true, &['8']),
+    ('\u{1D7FF}', true, &['9']),
+    ('\u{1E030}', true, &['\u{430}']),
+    ('\u{1E031}', true, &['\u{431}']),
+    ('\u{1E032}', true, &['\u{432}']),
+    ('\u{1E033}', true, &['\u{433}']),
+    ('\u{1E034}', true, &['\u{434}']),
+    ('\u{1E035}', true, &['\u{435}']),
+    ('\u{1E036}', true, &['\u{436}']),
+    ('\u{1E037}', true, &['\u{437}']),
+    ('\u{1E038}', true, &['\u{438}']),
+    ('\u{1E039}', true, &['\u{43A}']),
+    ('\u{1E03A}', true, &['\u{43B}']),
+    ('\u{1E03B}', true, &['\u{43C}']),
+    ('\u{1E03C}', true, &['\u{43E}']),
+    ('\u{1E03D}', true, &['\u{43F}']),
+    ('\u{1E03E}', true, &['\u{440}']),
+    ('\u{1E03F}', true, &['\u{441}']),
+    ('\u{1E040}', true, &['\u{442}']),
+    ('\u{1E041}', true, &['\u{443}']),
+    ('\u{1E042}', true, &['\u{444}']),
+    ('\u{1E043}', true, &['\u{445}']),
+    ('\u{1E044}', true, &['\u{446}']),
+    ('\u{1E045}', true, &['\u{447}']),
+    ('\u{1E046}', true, &['\u{448}']),
+    ('\u{1E047}', true, &['\u{44B}']),
+    ('\u{1E048}', true, &['\u{44D}']),
+    ('\u{1E049}', true, &['\u{44E}']),
+    ('\u{1E04A}', true, &['\u{A689}']),
+    ('\u{1E04B}', true, &['\u{4D9}']),
+    ('\u{1E04C}', true, &['\u{456}']),
+    ('\u{1E04D}', true, &['\u{458}']),
+    ('\u{1E04E}', true, &['\u{4E9}']),
+    ('\u{1E04F}', true, &['\u{4AF}']),
+    ('\u{1E050}', true, &['\u{4CF}']),
+    ('\u{1E051}', true, &['\u{430}']),
+    ('\u{1E052}', true, &['\u{431}']),
+    ('\u{1E053}', true, &['\u{432}']),
+    ('\u{1E054}', true, &['\u{433}']),
+    ('\u{1E055}', true, &['\u{434}']),
+    ('\u{1E056}', true, &['\u{435}']),
+    ('\u{1E057}', true, &['\u{436}']),
+    ('\u{1E058}', true, &['\u{437}']),
+    ('\u{1E059}', true, &['\u{438}']),
+    ('\u{1E05A}', true, &['\u{43A}']),
+    ('\u{1E05B}', true, &['\u{43B}']),
+    ('\u{1E05C}', true, &['\u{43E}']),
+    ('\u{1E05D}', true, &['\u{43F}']),
+    ('\u{1E05E}', true, &['\u{441}']),
+    ('\u{1E05F}', true, &['\u{443}']),
+    ('\u{1E060}', true, &['\u{444}']),
+    ('\u{1E061}', true, &['\u{445}']),
+    ('\u{1E062}', true, &['\u{446}']),
+    ('\u{1E063}', true, &['\u{447}']),
+    ('\u{1E064}', true, &['\u{448}']),
+    ('\u{1E065}', true, &['\u{44A}']),
+    ('\u{1E066}', true, &['\u{44B}']),
+    ('\u{1E067}', true, &['\u{491}']),
+    ('\u{1E068}', true, &['\u{456}']),
+    ('\u{1E069}', true, &['\u{455}']),
+    ('\u{1E06A}', true, &['\u{45F}']),
+    ('\u{1E06B}', true, &['\u{4AB}']),
+    ('\u{1E06C}', true, &['\u{A651}']),
+    ('\u{1E06D}', true, &['\u{4B1}']),
+    ('\u{1EE00}', true, &['\u{627}']),
+    ('\u{1EE01}', true, &['\u{628}']),
+    ('\u{1EE02}', true, &['\u{62C}']),
+    ('\u{1EE03}', true, &['\u{62F}']),
+    ('\u{1EE05}', true, &['\u{648}']),
+    ('\u{1EE06}', true, &['\u{632}']),
+    ('\u{1EE07}', true, &['\u{62D}']),
+    ('\u{1EE08}', true, &['\u{637}']),
+    ('\u{1EE09}', true, &['\u{64A}']),
+    ('\u{1EE0A}', true, &['\u{643}']),
+    ('\u{1EE0B}', true, &['\u{644}']),
+    ('\u{1EE0C}', true, &['\u{645}']),
+    ('\u{1EE0D}', true, &['\u{646}']),
+    ('\u{1EE0E}', true, &['\u{633}']),
+    ('\u{1EE0F}', true, &['\u{639}']),
+    ('\u{1EE10}', true, &['\u{641}']),
+    ('\u{1EE11}', true, &['\u{635}']),
+    ('\u{1EE12}', true, &['\u{642}']),
+    ('\u{1EE13}', true, &['\u{631}']),
+    ('\u{1EE14}', true, &['\u{634}']),
+    ('\u{1EE15}', true, &['\u{62A}']),
+    ('\u{1EE16}', true, &['\u{62B}']),
+    ('\u{1EE17}', true, &['\u{62E}']),
+    ('\u{1EE18}', true, &['\u{630}']),
+    ('\u{1EE19}', true, &['\u{636}']),
+    ('\u{1EE1A}', true, &['\u{638}']),
+    ('\u{1EE1B}', true, &['\u{63A}']),
+    ('\u{1EE1C}', true, &['\u{66E}']),
+    ('\u{1EE1D}', true, &['\u{6BA}']),
+    ('\u{1EE1E}', true, &['\u{6A1}']),
+    ('\u{1EE1F}', true, &['\u{66F}']),
+    ('\u{1EE21}', true, &['\u{628}']),
+    ('\u{1EE22}', true, &['\u{62C}']),
+    ('\u{1EE24}', true, &['\u{647}']),
+    ('\u{1EE27}', true, &['\u{62D}']),
+    ('\u{1EE29}', true, &['\u{64A}']),
+    ('\u{1EE2A}', true, &['\u{643}']),
+    ('\u{1EE2B}', true, &['\u{644}']),
+    ('\u{1EE2C}', true, &['\u{645}']),
+    ('\u{1EE2D}', true, &['\u{646}']),
+    ('\u{1EE2E}', true, &['\u{633}']),
+    ('\u{1EE2F}', true, &['\u{639}']),
+    ('\u{1EE30}', true, &['\u{641}']),
+    ('\u{1EE31}', true, &['\u{635}']),
+    ('\u{1EE32}', true, &['\u{642}']),
+    ('\u{1EE34}', true, &['\u{634}']),
+    ('\u{1EE35}', true, &['\u{62A}']),
+    ('\u{1EE36}', true, &['\u{62B}']),
+    ('\u{1EE37}', true, &['\u{62E}']),
+    ('\u{1EE39}', true, &['\u{636}']),
+    ('\u{1EE3B}', true, &['\u{63A}']),
+    ('\u{1EE42}', true, &['\u{62C}']),
+    ('\u{1EE47}', true, &['\u{62D}']),
+    ('\u{1EE49}', true, &['\u{64A}']),
+    ('\u{1EE4B}', true, &['\u{644}']),
+    ('\u{1EE4D}', true, &['\u{646}']),
+    ('\u{1EE4E}', true, &['\u{633}']),
+    ('\u{1EE4F}', true, &['\u{639}']),
+    ('\u{1EE51}', true, &['\u{635}']),
+    ('\u{1EE52}', true, &['\u{642}']),
+    ('\u{1EE54}', true, &['\u{634}']),
+    ('\u{1EE57}', true, &['\u{62E}']),
+    ('\u{1EE59}', true, &['\u{636}']),
+    ('\u{1EE5B}', true, &['\u{63A}']),
+    ('\u{1EE5D}', true, &['\u{6BA}']),
+    ('\u{1EE5F}', true, &['\u{66F}']),
+    ('\u{1EE61}', true, &['\u{628}']),
+    ('\u{1EE62}', true, &['\u{62C}']),
+    ('\u{1EE64}', true, &['\u{647}']),
+    ('\u{1EE67}', true, &['\u{62D}']),
+    ('\u{1EE68}', true, &['\u{637}']),
+    ('\u{1EE69}', true, &['\u{64A}']),
+    ('\u{1EE6A}', true, &['\u{643}']),
+    ('\u{1EE6C}', true, &['\u{645}']),
+    ('\u{1EE6D}', true, &['\u{646}']),
+    ('\u{1EE6E}', true, &['\u{633}']),
+    ('\u{1EE6F}', true, &['\u{639}']),
+    ('\u{1EE70}', true, &['\u{641}']),
+    ('\u{1EE71}', true, &['\u{635}']),
+    ('\u{1EE72}', true, &['\u{642}']),
+    ('\u{1EE74}', true, &['\u{634}']),
+    ('\u{1EE75}', true, &['\u{62A}']),
+    ('\u{1EE76}', true, &['\u{62B}']),
+    ('\u{1EE77}', true, &['\u{62E}']),
+    ('\u{1EE79}', true, &['\u{636}']),
+    ('\u{1EE7A}', true, &['\u{638}']),
+    ('\u{1EE7B}', true, &['\u{63A}']),
+    ('\u{1EE7C}', true, &['\u{66E}']),
+    ('\u{1EE7E}', true, &['\u{6A1}']),
+    ('\u{1EE80}', true, &['\u{627}']),
+    ('\u{1EE81}', true, &['\u{628}']),
+    ('\u{1EE82}', true, &['\u{62C}']),
+    ('\u{1EE83}', true, &['\u{62F}']),
+    ('\u{1EE84}', true, &['\u{647}']),
+    ('\u{1EE85}', true, &['\u{648}']),
+    ('\u{1EE86}', true, &['\u{632}']),
+    ('\u{1EE87}', true, &['\u{62D}']),
+    ('\u{1EE88}', true, &['\u{637}']),
+    ('\u{1EE89}', true, &['\u{64A}']),
+    ('\u{1EE8B}', true, &['\u{644}']),
+    ('\u{1EE8C}', true, &['\u{645}']),
+    ('\u{1EE8D}', true, &['\u{646}']),
+    ('\u{1EE8E}', true, &['\u{633}']),
+    ('\u{1EE8F}', true, &['\u{639}']),
+    ('\u{1EE90}', true, &['\u{641}']),
+    ('\u{1EE91}', true, &['\u{635}']),
+    ('\u{1EE92}', true, &['\u{642}']),
+    ('\u{1EE93}', true, &['\u{631}']),
+    ('\u{1EE94}', true, &['\u{634}']),
+    ('\u{1EE95}', true, &['\u{62A}']),
+    ('\u{1EE96}', true, &['\u{62B}']),
+    ('\u{1EE97}', true, &['\u{62E}']),
+    ('\u{1EE98}', true, &['\u{630}']),
+    ('\u{1EE99}', true, &['\u{636}']),
+    ('\u{1EE9A}', true, &['\u{638}']),
+    ('\u{1EE9B}', true, &['\u{63A}']),
+    ('\u{1EEA1}', true, &['\u{628}']),
+    ('\u{1EEA2}', true, &['\u{62C}']),
+    ('\u{1EEA3}', true, &['\u{62F}']),
+    ('\u{1EEA5}', true, &['\u{648}']),
+    ('\u{1EEA6}', true, &['\u{632}']),
+    ('\u{1EEA7}', true, &['\u{62D}']),
+    ('\u{1EEA8}', true, &['\u{637}']),
+    ('\u{1EEA9}', true, &['\u{64A}']),
+    ('\u{1EEAB}', true, &['\u{644}']),
+    ('\u{1EEAC}', true, &['\u{645}']),
+    ('\u{1EEAD}', true, &['\u{646}']),
+    ('\u{1EEAE}', true, &['\u{633}']),
+    ('\u{1EEAF}', true, &['\u{639}']),
+    ('\u{1EEB0}', true, &['\u{641}']),
+    ('\u{1EEB1}', true, &['\u{635}']),
+    ('\u{1EEB2}', true, &['\u{642}']),
+    ('\u{1EEB3}', true, &['\u{631}']),
+    ('\u{1EEB4}', true, &['\u{634}']),
+    ('\u{1EEB5}', true, &['\u{62A}']),
+    ('\u{1EEB6}', true, &['\u{62B}']),
+    ('\u{1EEB7}', true, &['\u{62E}']),
+    ('\u{1EEB8}', true, &['\u{630}']),
+    ('\u{1EEB9}', true, &['\u{636}']),
+    ('\u{1EEBA}', true, &['\u{638}']),
+    ('\u{1EEBB}', true, &['\u{63A}']),
+    ('\u{1F100}', true, &['0', '.']),
+    ('\u{1F101}', true, &['0', ',']),
+    ('\u{1F102}', true, &['1', ',']),
+    ('\u{1F103}', true, &['2', ',']),
+    ('\u{1F104}', true, &['3', ',']),
+    ('\u{1F105}', true, &['4', ',']),
+    ('\u{1F106}', true, &['5', ',']),
+    ('\u{1F107}', true, &['6', ',']),
+    ('\u{1F108}', true, &['7', ',']),
+    ('\u{1F109}', true, &['8', ',']),
+    ('\u{1F10A}', true, &['9', ',']),
+    ('\u{1F110}', true, &['(', 'A', ')']),
+    ('\u{1F111}', true, &['(', 'B', ')']),
+    ('\u{1F112}', true, &['(', 'C', ')']),
+    ('\u{1F113}', true, &['(', 'D', ')']),
+    ('\u{1F114}', true, &['(', 'E', ')']),
+    ('\u{1F115}', true, &['(', 'F', ')']),
+    ('\u{1F116}', true, &['(', 'G', ')']),
+    ('\u{1F117}', true, &['(', 'H', ')']),
+    ('\u{1F118}', true, &['(', 'I', ')']),
+    ('\u{1F119}', true, &['(', 'J', ')']),
+    ('\u{1F11A}', true, &['(', 'K', ')']),
+    ('\u{1F11B}', true, &['(', 'L', ')']),
+    ('\u{1F11C}', true, &['(', 'M', ')']),
+    ('\u{1F11D}', true, &['(', 'N', ')']),
+    ('\u{1F11E}', true, &['(', 'O', ')']),
+    ('\u{1F11F}', true, &['(', 'P', ')']),
+    ('\u{1F120}', true, &['(', 'Q', ')']),
+    ('\u{1F121}', true, &['(', 'R', ')']),
+    ('\u{1F122}', true, &['(', 'S', ')']),
+    ('\u{1F123}', true, &['(', 'T', ')']),
+    ('\u{1F124}', true, &['(', 'U', ')']),
+    ('\u{1F125}', true, &['(', 'V', ')']),
+    ('\u{1F126}', true, &['(', 'W', ')']),
+    ('\u{1F127}', true, &['(', 'X', ')']),
+    ('\u{1F128}', true, &['(', 'Y', ')']),
+    ('\u{1F129}', true, &['(', 'Z', ')']),
+    ('\u{1F12A}', true, &['\u{3014}', 'S', '\u{3015}']),
+    ('\u{1F12B}', true, &['C']),
+    ('\u{1F12C}', true, &['R']),
+    ('\u{1F12D}', true, &['C', 'D']),
+    ('\u{1F12E}', true, &['W', 'Z']),
+    ('\u{1F130}', true, &['A']),
+    ('\u{1F131}', true, &['B']),
+    ('\u{1F132}', true, &['C']),
+    ('\u{1F133}', true, &['D']),
+    ('\u{1F134}', true, &['E']),
+    ('\u{1F135}', true, &['F']),
+    ('\u{1F136}', true, &['G']),
+    ('\u{1F137}', true, &['H']),
+    ('\u{1F138}', true, &['I']),
+    ('\u{1F139}', true, &['J']),
+    ('\u{1F13A}', true, &['K']),
+    ('\u{1F13B}', true, &['L']),
+    ('\u{1F13C}', true, &['M']),
+    ('\u{1F13D}', true, &['N']),
+    ('\u{1F13E}', true, &['O']),
+    ('\u{1F13F}', true, &['P']),
+    ('\u{1F140}', true, &['Q']),
+    ('\u{1F141}', true, &['R']),
+    ('\u{1F142}', true, &['S']),
+    ('\u{1F143}', true, &['T']),
+    ('\u{1F144}', true, &['U']),
+    ('\u{1F145}', true, &['V']),
+    ('\u{1F146}', true, &['W']),
+    ('\u{1F147}', true, &['X']),
+    ('\u{1F148}', true, &['Y']),
+    ('\u{1F149}', true, &['Z']),
+    ('\u{1F14A}', true, &['H', 'V']),
+    ('\u{1F14B}', true, &['M', 'V']),
+    ('\u{1F14C}', true, &['S', 'D']),
+    ('\u{1F14D}', true, &['S', 'S']),
+    ('\u{1F14E}', true, &['P', 'P', 'V']),
+    ('\u{1F14F}', true, &['W', 'C']),
+    ('\u{1F16A}', true, &['M', 'C']),
+    ('\u{1F16B}', true, &['M', 'D']),
+    ('\u{1F16C}', true, &['M', 'R']),
+    ('\u{1F190}', true, &['D', 'J']),
+    ('\u{1F200}', true, &['\u{307B}', '\u{304B}']),
+    ('\u{1F201}', true, &['\u{30B3}', '\u{30B3}']),
+    ('\u{1F202}', true, &['\u{30B5}']),
+    ('\u{1F210}', true, &['\u{624B}']),
+    ('\u{1F211}', true, &['\u{5B57}']),
+    ('\u{1F212}', true, &['\u{53CC}']),
+    ('\u{1F213}', true, &['\u{30C7}']),
+    ('\u{1F214}', true, &['\u{4E8C}']),
+    ('\u{1F215}', true, &['\u{591A}']),
+    ('\u{1F216}', true, &['\u{89E3}']),
+    ('\u{1F217}', true, &['\u{5929}']),
+    ('\u{1F218}', true, &['\u{4EA4}']),
+    ('\u{1F219}', true, &['\u{6620}']),
+    ('\u{1F21A}', true, &['\u{7121}']),
+    ('\u{1F21B}', true, &['\u{6599}']),
+    ('\u{1F21C}', true, &['\u{524D}']),
+    ('\u{1F21D}', true, &['\u{5F8C}']),
+    ('\u{1F21E}', true, &['\u{518D}']),
+    ('\u{1F21F}', true, &['\u{65B0}']),
+    ('\u{1F220}', true, &['\u{521D}']),
+    ('\u{1F221}', true, &['\u{7D42}']),
+    ('\u{1F222}', true, &['\u{751F}']),
+    ('\u{1F223}', true, &['\u{8CA9}']),
+    ('\u{1F224}', true, &['\u{58F0}']),
+    ('\u{1F225}', true, &['\u{5439}']),
+    ('\u{1F226}', true, &['\u{6F14}']),
+    ('\u{1F227}', true, &['\u{6295}']),
+    ('\u{1F228}', true, &['\u{6355}']),
+    ('\u{1F229}', true, &['\u{4E00}']),
+    ('\u{1F22A}', true, &['\u{4E09}']),
+    ('\u{1F22B}', true, &['\u{904A}']),
+    ('\u{1F22C}', true, &['\u{5DE6}']),
+    ('\u{1F22D}', true, &['\u{4E2D}']),
+    ('\u{1F22E}', true, &['\u{53F3}']),
+    ('\u{1F22F}', true, &['\u{6307}']),
+    ('\u{1F230}', true, &['\u{8D70}']),
+    ('\u{1F231}', true, &['\u{6253}']),
+    ('\u{1F232}', true, &['\u{7981}']),
+    ('\u{1F233}', true, &['\u{7A7A}']),
+    ('\u{1F234}', true, &['\u{5408}']),
+    ('\u{1F235}', true, &['\u{6E80}']),
+    ('\u{1F236}', true, &['\u{6709}']),
+    ('\u{1F237}', true, &['\u{6708}']),
+    ('\u{1F238}', true, &['\u{7533}']),
+    ('\u{1F239}', true, &['\u{5272}']),
+    ('\u{1F23A}', true, &['\u{55B6}']),
+    ('\u{1F23B}', true, &['\u{914D}']),
+    ('\u{1F240}', true, &['\u{3014}', '\u{672C}', '\u{3015}']),
+    ('\u{1F241}', true, &['\u{3014}', '\u{4E09}', '\u{3015}']),
+    ('\u{1F242}', true, &['\u{3014}', '\u{4E8C}', '\u{3015}']),
+    ('\u{1F243}', true, &['\u{3014}', '\u{5B89}', '\u{3015}']),
+    ('\u{1F244}', true, &['\u{3014}', '\u{70B9}', '\u{3015}']),
+    ('\u{1F245}', true, &['\u{3014}', '\u{6253}', '\u{3015}']),
+    ('\u{1F246}', true, &['\u{3014}', '\u{76D7}', '\u{3015}']),
+    ('\u{1F247}', true, &['\u{3014}', '\u{52DD}', '\u{3015}']),
+    ('\u{1F248}', true, &['\u{3014}', '\u{6557}', '\u{3015}']),
+    ('\u{1F250}', true, &['\u{5F97}']),
+    ('\u{1F251}', true, &['\u{53EF}']),
+    ('\u{1FBF0}', true, &['0']),
+    ('\u{1FBF1}', true, &['1']),
+    ('\u{1FBF2}', true, &['2']),
+    ('\u{1FBF3}', true, &['3']),
+    ('\u{1FBF4}', true, &['4']),
+    ('\u{1FBF5}', true, &['5']),
+    ('\u{1FBF6}', true, &['6']),
+    ('\u{1FBF7}', true, &['7']),
+    ('\u{1FBF8}', true, &['8']),
+    ('\u{1FBF9}', true, &['9']),
+    ('\u{2F800}', false, &['\u{4E3D}']),
+    ('\u{2F801}', false, &['\u{4E38}']),
+    ('\u{2F802}', false, &['\u{4E41}']),
+    ('\u{2F803}', false, &['\u{20122}']),
+    ('\u{2F804}', false, &['\u{4F60}']),
+    ('\u{2F805}', false, &['\u{4FAE}']),
+    ('\u{2F806}', false, &['\u{4FBB}']),
+    ('\u{2F807}', false, &['\u{5002}']),
+    ('\u{2F808}', false, &['\u{507A}']),
+    ('\u{2F809}', false, &['\u{5099}']),
+    ('\u{2F80A}', false, &['\u{50E7}']),
+    ('\u{2F80B}', false, &['\u{50CF}']),
+    ('\u{2F80C}', false, &['\u{349E}']),
+    ('\u{2F80D}', false, &['\u{2063A}']),
+    ('\u{2F80E}', false, &['\u{514D}']),
+    ('\u{2F80F}', false, &['\u{5154}']),
+    ('\u{2F810}', false, &['\u{5164}']),
+    ('\u{2F811}', false, &['\u{5177}']),
+    ('\u{2F812}', false, &['\u{2051C}']),
+    ('\u{2F813}', false, &['\u{34B9}']),
+    ('\u{2F814}', false, &['\u{5167}']),
+    ('\u{2F815}', false, &['\u{518D}']),
+    ('\u{2F816}', false, &['\u{2054B}']),
+    ('\u{2F817}', false, &['\u{5197}']),
+    ('\u{2F818}', false, &['\u{51A4}']),
+    ('\u{2F819}', false, &['\u{4ECC}']),
+    ('\u{2F81A}', false, &['\u{51AC}']),
+    ('\u{2F81B}', false, &['\u{51B5}']),
+    ('\u{2F81C}', false, &['\u{291DF}']),
+    ('\u{2F81D}', false, &['\u{51F5}']),
+    ('\u{2F81E}', false, &['\u{5203}']),
+    ('\u{2F81F}', false, &['\u{34DF}']),
+    ('\u{2F820}', false, &['\u{523B}']),
+    ('\u{2F821}', false, &['\u{5246}']),
+    ('\u{2F822}', false, &['\u{5272}']),
+    ('\u{2F823}', false, &['\u{5277}']),
+    ('\u{2F824}', false, &['\u{3515}']),
+    ('\u{2F825}', false, &['\u{52C7}']),
+    ('\u{2F826}', false, &['\u{52C9}']),
+    ('\u{2F827}', false, &['\u{52E4}']),
+    ('\u{2F828}', false, &['\u{52FA}']),
+    ('\u{2F829}', false, &['\u{5305}']),
+    ('\u{2F82A}', false, &['\u{5306}']),
+    ('\u{2F82B}', false, &['\u{5317}']),
+    ('\u{2F82C}', false, &['\u{5349}']),
+    ('\u{2F82D}', false, &['\u{5351}']),
+    ('\u{2F82E}', false, &['\u{535A}']),
+    ('\u{2F82F}', false, &['\u{5373}']),
+    ('\u{2F830}', false, &['\u{537D}']),
+    ('\u{2F831}', false, &['\u{537F}']),
+    ('\u{2F832}', false, &['\u{537F}']),
+    ('\u{2F833}', false, &['\u{537F}']),
+    ('\u{2F834}', false, &['\u{20A2C}']),
+    ('\u{2F835}', false, &['\u{7070}']),
+    ('\u{2F836}', false, &['\u{53CA}']),
+    ('\u{2F837}', false, &['\u{53DF}']),
+    ('\u{2F838}', false, &['\u{20B63}']),
+    ('\u{2F839}', false, &['\u{53EB}']),
+    ('\u{2F83A}', false, &['\u{53F1}']),
+    ('\u{2F83B}', false, &['\u{5406}']),
+    ('\u{2F83C}', false, &['\u{549E}']),
+    ('\u{2F83D}', false, &['\u{5438}']),
+    ('\u{2F83E}', false, &['\u{5448}']),
+    ('\u{2F83F}', false, &['\u{5468}']),
+    ('\u{2F840}', false, &['\u{54A2}']),
+    ('\u{2F841}', false, &['\u{54F6}']),
+    ('\u{2F842}', false, &['\u{5510}']),
+    ('\u{2F843}', false, &['\u{5553}']),
+    ('\u{2F844}', false, &['\u{5563}']),
+    ('\u{2F845}', false, &['\u{5584}']),
+    ('\u{2F846}', false, &['\u{5584}']),
+    ('\u{2F847}', false, &['\u{5599}']),
+    ('\u{2F848}', false, &['\u{55AB}']),
+    ('\u{2F849}', false, &['\u{55B3}']),
+    ('\u{2F84A}', false, &['\u{55C2}']),
+    ('\u{2F84B}', false, &['\u{5716}']),
+    ('\u{2F84C}', false, &['\u{5606}']),
+    ('\u{2F84D}', false, &['\u{5717}']),
+    ('\u{2F84E}', false, &['\u{5651}']),
+    ('\u{2F84F}', false, &['\u{5674}']),
+    ('\u{2F850}', false, &['\u{5207}']),
+    ('\u{2F851}', false, &['\u{58EE}']),
+    ('\u{2F852}', false, &['\u{57CE}']),
+    ('\u{2F853}', false, &['\u{57F4}']),
+    ('\u{2F854}', false, &['\u{580D}']),
+    ('\u{2F855}', false, &['\u{578B}']),
+    ('\u{2F856}', false, &['\u{5832}']),
+    ('\u{2F857}', false, &['\u{5831}']),
+    ('\u{2F858}', false, &['\u{58AC}']),
+    ('\u{2F859}', false, &['\u{214E4}']),
+    ('\u{2F85A}', false, &['\u{58F2}']),
+    ('\u{2F85B}', false, &['\u{58F7}']),
+    ('\u{2F85C}', false, &['\u{5906}']),
+    ('\u{2F85D}', false, &['\u{591A}']),
+    ('\u{2F85E}', false, &['\u{5922}']),
+    ('\u{2F85F}', false, &['\u{5962}']),
+    ('\u{2F860}', false, &['\u{216A8}']),
+    ('\u{2F861}', false, &['\u{216EA}']),
+    ('\u{2F862}', false, &['\u{59EC}']),
+    ('\u{2F863}', false, &['\u{5A1B}']),
+    ('\u{2F864}', false, &['\u{5A27}']),
+    ('\u{2F865}', false, &['\u{59D8}']),
+    ('\u{2F866}', false, &['\u{5A66}']),
+    ('\u{2F867}', false, &['\u{36EE}']),
+    ('\u{2F868}', false, &['\u{36FC}']),
+    ('\u{2F869}', false, &['\u{5B08}']),
+    ('\u{2F86A}', false, &['\u{5B3E}']),
+    ('\u{2F86B}', false, &['\u{5B3E}']),
+    ('\u{2F86C}', false, &['\u{219C8}']),
+    ('\u{2F86D}', false, &['\u{5BC3}']),
+    ('\u{2F86E}', false, &['\u{5BD8}']),
+    ('\u{2F86F}', false, &['\u{5BE7}']),
+    ('\u{2F870}', false, &['\u{5BF3}']),
+    ('\u{2F871}', false, &['\u{21B18}']),
+    ('\u{2F872}', false, &['\u{5BFF}']),
+    ('\u{2F873}', false, &['\u{5C06}']),
+    ('\u{2F874}', false, &['\u{5F53}']),
+    ('\u{2F875}', false, &['\u{5C22}']),
+    ('\u{2F876}', false, &['\u{3781}']),
+    ('\u{2F877}', false, &['\u{5C60}']),
+    ('\u{2F878}', false, &['\u{5C6E}']),
+    ('\u{2F879}', false, &['\u{5CC0}']),
+    ('\u{2F87A}', false, &['\u{5C8D}']),
+    ('\u{2F87B}', false, &['\u{21DE4}']),
+    ('\u{2F87C}', false, &['\u{5D43}']),
+    ('\u{2F87D}', false, &['\u{21DE6}']),
+    ('\u{2F87E}', false, &['\u{5D6E}']),
+    ('\u{2F87F}', false, &['\u{5D6B}']),
+    ('\u{2F880}', false, &['\u{5D7C}']),
+    ('\u{2F881}', false, &['\u{5DE1}']),
+    ('\u{2F882}', false, &['\u{5DE2}']),
+    ('\u{2F883}', false, &['\u{382F}']),
+    ('\u{2F884}', false, &['\u{5DFD}']),
+    ('\u{2F885}', false, &['\u{5E28}']),
+    ('\u{2F886}', false, &['\u{5E3D}']),
+    ('\u{2F887}', false, &['\u{5E69}']),
+    ('\u{2F888}', false, &['\u{3862}']),
+    ('\u{2F889}', false, &['\u{22183}']),
+    ('\u{2F88A}', false, &['\u{387C}']),
+    ('\u{2F88B}', false, &['\u{5EB0}']),
+    ('\u{2F88C}', false, &['\u{5EB3}']),
+    ('\u{2F88D}', false, &['\u{5EB6}']),
+    ('\u{2F88E}', false, &['\u{5ECA}']),
+    ('\u{2F88F}', false, &['\u{2A392}']),
+    ('\u{2F890}', false, &['\u{5EFE}']),
+    ('\u{2F891}', false, &['\u{22331}']),
+    ('\u{2F892}', false, &['\u{22331}']),
+    ('\u{2F893}', false, &['\u{8201}']),
+    ('\u{2F894}', false, &['\u{5F22}']),
+    ('\u{2F895}', false, &['\u{5F22}']),
+    ('\u{2F896}', false, &['\u{38C7}']),
+    ('\u{2F897}', false, &['\u{232B8}']),
+    ('\u{2F898}', false, &['\u{261DA}']),
+    ('\u{2F899}', false, &['\u{5F62}']),
+    ('\u{2F89A}', false, &['\u{5F6B}']),
+    ('\u{2F89B}', false, &['\u{38E3}']),
+    ('\u{2F89C}', false, &['\u{5F9A}']),
+    ('\u{2F89D}', false, &['\u{5FCD}']),
+    ('\u{2F89E}', false, &['\u{5FD7}']),
+    ('\u{2F89F}', false, &['\u{5FF9}']),
+    ('\u{2F8A0}', false, &['\u{6081}']),
+    ('\u{2F8A1}', false, &['\u{393A}']),
+    ('\u{2F8A2}', false, &['\u{391C}']),
+    ('\u{2F8A3}', false, &['\u{6094}']),
+    ('\u{2F8A4}', false, &['\u{226D4}']),
+    ('\u{2F8A5}', false, &['\u{60C7}']),
+    ('\u{2F8A6}', false, &['\u{6148}']),
+    ('\u{2F8A7}', false, &['\u{614C}']),
+    ('\u{2F8A8}', false, &['\u{614E}']),
+    ('\u{2F8A9}', false, &['\u{614C}']),
+    ('\u{2F8AA}', false, &['\u{617A}']),
+    ('\u{2F8AB}', false, &['\u{618E}']),
+    ('\u{2F8AC}', false, &['\u{61B2}']),
+    ('\u{2F8AD}', false, &['\u{61A4}']),
+    ('\u{2F8AE}', false, &['\u{61AF}']),
+    ('\u{2F8AF}', false, &['\u{61DE}']),
+    ('\u{2F8B0}', false, &['\u{61F2}']),
+    ('\u{2F8B1}', false, &['\u{61F6}']),
+    ('\u{2F8B2}', false, &['\u{6210}']),
+    ('\u{2F8B3}', false, &['\u{621B}']),
+    ('\u{2F8B4}', false, &['\u{625D}']),
+    ('\u{2F8B5}', false, &['\u{62B1}']),
+    ('\u{2F8B6}', false, &['\u{62D4}']),
+    ('\u{2F8B7}', false, &['\u{6350}']),
+    ('\u{2F8B8}', false, &['\u{22B0C}']),
+    ('\u{2F8B9}', false, &['\u{633D}']),
+    ('\u{2F8BA}', false, &['\u{62FC}']),
+    ('\u{2F8BB}', false, &['\u{6368}']),
+    ('\u{2F8BC}', false, &['\u{6383}']),
+    ('\u{2F8BD}', false, &['\u{63E4}']),
+    ('\u{2F8BE}', false, &['\u{22BF1}']),
+    ('\u{2F8BF}', false, &['\u{6422}']),
+    ('\u{2F8C0}', false, &['\u{63C5}']),
+    ('\u{2F8C1}', false, &['\u{63A9}']),
+    ('\u{2F8C2}', false, &['\u{3A2E}']),
+    ('\u{2F8C3}', false, &['\u{6469}']),
+    ('\u{2F8C4}', false, &['\u{647E}']),
+    ('\u{2F8C5}', false, &['\u{649D}']),
+    ('\u{2F8C6}', false, &['\u{6477}']),
+    ('\u{2F8C7}', false, &['\u{3A6C}']),
+    ('\u{2F8C8}', false, &['\u{654F}']),
+    ('\u{2F8C9}', false, &['\u{656C}']),
+    ('\u{2F8CA}', false, &['\u{2300A}']),
+    ('\u{2F8CB}', false, &['\u{65E3}']),
+    ('\u{2F8CC}', false, &['\u{66F8}']),
+    ('\u{2F8CD}', false, &['\u{6649}']),
+    ('\u{2F8CE}', false, &['\u{3B19}']),
+    ('\u{2F8CF}', false, &['\u{6691}']),
+    ('\u{2F8D0}', false, &['\u{3B08}']),
+    ('\u{2F8D1}', false, &['\u{3AE4}']),
+    ('\u{2F8D2}', false, &['\u{5192}']),
+    ('\u{2F8D3}', false, &['\u{5195}']),
+    ('\u{2F8D4}', false, &['\u{6700}']),
+    ('\u{2F8D5}', false, &['\u{669C}']),
+    ('\u{2F8D6}', false, &['\u{80AD}']),
+    ('\u{2F8D7}', false, &['\u{43D9}']),
+    ('\u{2F8D8}', false, &['\u{6717}']),
+    ('\u{2F8D9}', false, &['\u{671B}']),
+    ('\u{2F8DA}', false, &['\u{6721}']),
+    ('\u{2F8DB}', false, &['\u{675E}']),
+    ('\u{2F8DC}', false, &['\u{6753}']),
+    ('\u{2F8DD}', false, &['\u{233C3}']),
+    ('\u{2F8DE}', false, &['\u{3B49}']),
+    ('\u{2F8DF}', false, &['\u{67FA}']),
+    ('\u{2F8E0}', false, &['\u{6785}']),
+    ('\u{2F8E1}', false, &['\u{6852}']),
+    ('\u{2F8E2}', false, &['\u{6885}']),
+    ('\u{2F8E3}', false, &['\u{2346D}']),
+    ('\u{2F8E4}', false, &['\u{688E}']),
+    ('\u{2F8E5}', false, &['\u{681F}']),
+    ('\u{2F8E6}', false, &['\u{6914}']),
+    ('\u{2F8E7}', false, &['\u{3B9D}']),
+    ('\u{2F8E8}', false, &['\u{6942}']),
+    ('\u{2F8E9}', false, &['\u{69A3}']),
+    ('\u{2F8EA}', false, &['\u{69EA}']),
+    ('\u{2F8EB}', false, &['\u{6AA8}']),
+    ('\u{2F8EC}', false, &['\u{236A3}']),
+    ('\u{2F8ED}', false, &['\u{6ADB}']),
+    ('\u{2F8EE}', false, &['\u{3C18}']),
+    ('\u{2F8EF}', false, &['\u{6B21}']),
+    ('\u{2F8F0}', false, &['\u{238A7}']),
+    ('\u{2F8F1}', false, &['\u{6B54}']),
+    ('\u{2F8F2}', false, &['\u{3C4E}']),
+    ('\u{2F8F3}', false, &['\u{6B72}']),
+    ('\u{2F8F4}', false, &['\u{6B9F}']),
+    ('\u{2F8F5}', false, &['\u{6BBA}']),
+    ('\u{2F8F6}', false, &['\u{6BBB}']),
+    ('\u{2F8F7}', false, &['\u{23A8D}']),
+    ('\u{2F8F8}', false, &['\u{21D0B}']),
+    ('\u{2F8F9}', false, &['\u{23AFA}']),
+    ('\u{2F8FA}', false, &['\u{6C4E}']),
+    ('\u{2F8FB}', false, &['\u{23CBC}']),
+    ('\u{2F8FC}', false, &['\u{6CBF}']),
+    ('\u{2F8FD}', false, &['\u{6CCD}']),
+    ('\u{2F8FE}', false, &['\u{6C67}']),
+    ('\u{2F8FF}', false, &['\u{6D16}']),
+    ('\u{2F900}', false, &['\u{6D3E}']),
+    ('\u{2F901}', false, &['\u{6D77}']),
+    ('\u{2F902}', false, &['\u{6D41}']),
+    ('\u{2F903}', false, &['\u{6D69}']),
+    ('\u{2F904}', false, &['\u{6D78}']),
+    ('\u{2F905}', false, &['\u{6D85}']),
+    ('\u{2F906}', false, &['\u{23D1E}']),
+    ('\u{2F907}', false, &['\u{6D34}']),
+    ('\u{2F908}', false, &['\u{6E2F}']),
+    ('\u{2F909}', false, &['\u{6E6E}']),
+    ('\u{2F90A}', false, &['\u{3D33}']),
+    ('\u{2F90B}', false, &['\u{6ECB}']),
+    ('\u{2F90C}', false, &['\u{6EC7}']),
+    ('\u{2F90D}', false, &['\u{23ED1}']),
+    ('\u{2F90E}', false, &['\u{6DF9}']),
+    ('\u{2F90F}', false, &['\u{6F6E}']),
+    ('\u{2F910}', false, &['\u{23F5E}']),
+    ('\u{2F911}', false, &['\u{23F8E}']),
+    ('\u{2F912}', false, &['\u{6FC6}']),
+    ('\u{2F913}', false, &['\u{7039}']),
+    ('\u{2F914}', false, &['\u{701E}']),
+    ('\u{2F915}', false, &['\u{701B}']),
+    ('\u{2F916}', false, &['\u{3D96}']),
+    ('\u{2F917}', false, &['\u{704A}']),
+    ('\u{2F918}', false, &['\u{707D}']),
+    ('\u{2F919}', false, &['\u{7077}']),
+    ('\u{2F91A}', false, &['\u{70AD}']),
+    ('\u{2F91B}', false, &['\u{20525}']),
+    ('\u{2F91C}', false, &['\u{7145}']),
+    ('\u{2F91D}', false, &['\u{24263}']),
+    ('\u{2F91E}', false, &['\u{719C}']),
+    ('\u{2F91F}', false, &['\u{243AB}']),
+    ('\u{2F920}', false, &['\u{7228}']),
+    ('\u{2F921}', false, &['\u{7235}']),
+    ('\u{2F922}', false, &['\u{7250}']),
+    ('\u{2F923}', false, &['\u{24608}']),
+    ('\u{2F924}', false, &['\u{7280}']),
+    ('\u{2F925}', false, &['\u{7295}']),
+    ('\u{2F926}', false, &['\u{24735}']),
+    ('\u{2F927}', false, &['\u{24814}']),
+    ('\u{2F928}', false, &['\u{737A}']),
+    ('\u{2F929}', false, &['\u{738B}']),
+    ('\u{2F92A}', false, &['\u{3EAC}']),
+    ('\u{2F92B}', false, &['\u{73A5}']),
+    ('\u{2F92C}', false, &['\u{3EB8}']),
+    ('\u{2F92D}', false, &['\u{3EB8}']),
+    ('\u{2F92E}', false, &['\u{7447}']),
+    ('\u{2F92F}', false, &['\u{745C}']),
+    ('\u{2F930}', false, &['\u{7471}']),
+    ('\u{2F931}', false, &['\u{7485}']),
+    ('\u{2F932}', false, &['\u{74CA}']),
+    ('\u{2F933}', false, &['\u{3F1B}']),
+    ('\u{2F934}', false, &['\u{7524}']),
+    ('\u{2F935}', false, &['\u{24C36}']),
+    ('\u{2F936}', false, &['\u{753E}']),
+    ('\u{2F937}', false, &['\u{24C92}']),
+    ('\u{2F938}', false, &['\u{7570}']),
+    ('\u{2F939}', false, &['\u{2219F}']),
+    ('\u{2F93A}', false, &['\u{7610}']),
+    ('\u{2F93B}', false, &['\u{24FA1}']),
+    ('\u{2F93C}', false, &['\u{24FB8}']),
+    ('\u{2F93D}', false, &['\u{25044}']),
+    ('\u{2F93E}', false, &['\u{3FFC}']),
+    ('\u{2F93F}', false, &['\u{4008}']),
+    ('\u{2F940}', false, &['\u{76F4}']),
+    ('\u{2F941}', false, &['\u{250F3}']),
+    ('\u{2F942}', false, &['\u{250F2}']),
+    ('\u{2F943}', false, &['\u{25119}']),
+    ('\u{2F944}', false, &['\u{25133}']),
+    ('\u{2F945}', false, &['\u{771E}']),
+    ('\u{2F946}', false, &['\u{771F}']),
+    ('\u{2F947}', false, &['\u{771F}']),
+    ('\u{2F948}', false, &['\u{774A}']),
+    ('\u{2F949}', false, &['\u{4039}']),
+    ('\u{2F94A}', false, &['\u{778B}']),
+    ('\u{2F94B}', false, &['\u{4046}']),
+    ('\u{2F94C}', false, &['\u{4096}']),
+    ('\u{2F94D}', false, &['\u{2541D}']),
+    ('\u{2F94E}', false, &['\u{784E}']),
+    ('\u{2F94F}', false, &['\u{788C}']),
+    ('\u{2F950}', false, &['\u{78CC}']),
+    ('\u{2F951}', false, &['\u{40E3}']),
+    ('\u{2F952}', false, &['\u{25626}']),
+    ('\u{2F953}', false, &['\u{7956}']),
+    ('\u{2F954}', false, &['\u{2569A}']),
+    ('\u{2F955}', false, &['\u{256C5}']),
+    ('\u{2F956}', false, &['\u{798F}']),
+    ('\u{2F957}', false, &['\u{79EB}']),
+    ('\u{2F958}', false, &['\u{412F}']),
+    ('\u{2F959}', false, &['\u{7A40}']),
+    ('\u{2F95A}', false, &['\u{7A4A}']),
+    ('\u{2F95B}', false, &['\u{7A4F}']),
+    ('\u{2F95C}', false, &['\u{2597C}']),
+    ('\u{2F95D}', false, &['\u{25AA7}']),
+    ('\u{2F95E}', false, &['\u{25AA7}']),
+    ('\u{2F95F}', false, &['\u{7AEE}']),
+    ('\u{2F960}', false, &['\u{4202}']),
+    ('\u{2F961}', false, &['\u{25BAB}']),
+    ('\u{2F962}', false, &['\u{7BC6}']),
+    ('\u{2F963}', false, &['\u{7BC9}']),
+    ('\u{2F964}', false, &['\u{4227}']),
+    ('\u{2F965}', false, &['\u{25C80}']),
+    ('\u{2F966}', false, &['\u{7CD2}']),
+    ('\u{2F967}', false, &['\u{42A0}']),
+    ('\u{2F968}', false, &['\u{7CE8}']),
+    ('\u{2F969}', false, &['\u{7CE3}']),
+    ('\u{2F96A}', false, &['\u{7D00}']),
+    ('\u{2F96B}', false, &['\u{25F86}']),
+    ('\u{2F96C}', false, &['\u{7D63}']),
+    ('\u{2F96D}', false, &['\u{4301}']),
+    ('\u{2F96E}', false, &['\u{7DC7}']),
+    ('\u{2F96F}', false, &['\u{7E02}']),
+    ('\u{2F970}', false, &['\u{7E45}']),
+    ('\u{2F971}', false, &['\u{4334}']),
+    ('\u{2F972}', false, &['\u{26228}']),
+    ('\u{2F973}', false, &['\u{26247}']),
+    ('\u{2F974}', false, &['\u{4359}']),
+    ('\u{2F975}', false, &['\u{262D9}']),
+    ('\u{2F976}', false, &['\u{7F7A}']),
+    ('\u{2F977}', false, &['\u{2633E}']),
+    ('\u{2F978}', false, &['\u{7F95}']),
+    ('\u{2F979}', false, &['\u{7FFA}']),
+    ('\u{2F97A}', false, &['\u{8005}']),
+    ('\u{2F97B}', false, &['\u{264DA}']),
+    ('\u{2F97C}', false, &['\u{26523}']),
+    ('\u{2F97D}', false, &['\u{8060}']),
+    ('\u{2F97E}', false, &['\u{265A8}']),
+    ('\u{2F97F}', false, &['\u{8070}']),
+    ('\u{2F980}', false, &['\u{2335F}']),
+    ('\u{2F981}', false, &['\u{43D5}']),
+    ('\u{2F982}', false, &['\u{80B2}']),
+    ('\u{2F983}', false, &['\u{8103}']),
+    ('\u{2F984}', false, &['\u{440B}']),
+    ('\u{2F985}', false, &['\u{813E}']),
+    ('\u{2F986}', false, &['\u{5AB5}']),
+    ('\u{2F987}', false, &['\u{267A7}']),
+    ('\u{2F988}', false, &['\u{267B5}']),
+    ('\u{2F989}', false, &['\u{23393}']),
+    ('\u{2F98A}', false, &['\u{2339C}']),
+    ('\u{2F98B}', false, &['\u{8201}']),
+    ('\u{2F98C}', false, &['\u{8204}']),
+    ('\u{2F98D}', false, &['\u{8F9E}']),
+    ('\u{2F98E}', false, &['\u{446B}']),
+    ('\u{2F98F}', false, &['\u{8291}']),
+    ('\u{2F990}', false, &['\u{828B}']),
+    ('\u{2F991}', false, &['\u{829D}']),
+    ('\u{2F992}', false, &['\u{52B3}']),
+    ('\u{2F993}', false, &['\u{82B1}']),
+    ('\u{2F994}', false, &['\u{82B3}']),
+    ('\u{2F995}', false, &['\u{82BD}']),
+    ('\u{2F996}', false, &['\u{82E6}']),
+    ('\u{2F997}', false, &['\u{26B3C}']),
+    ('\u{2F998}', false, &['\u{82E5}']),
+    ('\u{2F999}', false, &['\u{831D}']),
+    ('\u{2F99A}', false, &['\u{8363}']),
+    ('\u{2F99B}', false, &['\u{83AD}']),
+    ('\u{2F99C}', false, &['\u{8323}']),
+    ('\u{2F99D}', false, &['\u{83BD}']),
+    ('\u{2F99E}', false, &['\u{83E7}']),
+    ('\u{2F99F}', false, &['\u{8457}']),
+    ('\u{2F9A0}', false, &['\u{8353}']),
+    ('\u{2F9A1}', false, &['\u{83CA}']),
+    ('\u{2F9A2}', false, &['\u{83CC}']),
+    ('\u{2F9A3}', false, &['\u{83DC}']),
+    ('\u{2F9A4}', false, &['\u{26C36}']),
+    ('\u{2F9A5}', false, &['\u{26D6B}']),
+    ('\u{2F9A6}', false, &['\u{26CD5}']),
+    ('\u{2F9A7}', false, &['\u{452B}']),
+    ('\u{2F9A8}', false, &['\u{84F1}']),
+    ('\u{2F9A9}', false, &['\u{84F3}']),
+    ('\u{2F9AA}', false, &['\u{8516}']),
+    ('\u{2F9AB}', false, &['\u{273CA}']),
+    ('\u{2F9AC}', false, &['\u{8564}']),
+    ('\u{2F9AD}', false, &['\u{26F2C}']),
+    ('\u{2F9AE}', false, &['\u{455D}']),
+    ('\u{2F9AF}', false, &['\u{4561}']),
+    ('\u{2F9B0}', false, &['\u{26FB1}']),
+    ('\u{2F9B1}', false, &['\u{270D2}']),
+    ('\u{2F9B2}', false, &['\u{456B}']),
+    ('\u{2F9B3}', false, &['\u{8650}']),
+    ('\u{2F9B4}', false, &['\u{865C}']),
+    ('\u{2F9B5}', false, &['\u{8667}']),
+    ('\u{2F9B6}', false, &['\u{8669}']),
+    ('\u{2F9B7}', false, &['\u{86A9}']),
+    ('\u{2F9B8}', false, &['\u{8688}']),
+    ('\u{2F9B9}', false, &['\u{870E}']),
+    ('\u{2F9BA}', false, &['\u{86E2}']),
+    ('\u{2F9BB}', false, &['\u{8779}']),
+    ('\u{2F9BC}', false, &['\u{8728}']),
+    ('\u{2F9BD}', false, &['\u{876B}']),
+    ('\u{2F9BE}', false, &['\u{8786}']),
+    ('\u{2F9BF}', false, &['\u{45D7}']),
+    ('\u{2F9C0}', false, &['\u{87E1}']),
+    ('\u{2F9C1}', false, &['\u{8801}']),
+    ('\u{2F9C2}', false, &['\u{45F9}']),
+    ('\u{2F9C3}', false, &['\u{8860}']),
+    ('\u{2F9C4}', false, &['\u{8863}']),
+    ('\u{2F9C5}', false, &['\u{27667}']),
+    ('\u{2F9C6}', false, &['\u{88D7}']),
+    ('\u{2F9C7}', false, &['\u{88DE}']),
+    ('\u{2F9C8}', false, &['\u{4635}']),
+    ('\u{2F9C9}', false, &['\u{88FA}']),
+    ('\u{2F9CA}', false, &['\u{34BB}']),
+    ('\u{2F9CB}', false, &['\u{278AE}']),
+    ('\u{2F9CC}', false, &['\u{27966}']),
+    ('\u{2F9CD}', false, &['\u{46BE}']),
+    ('\u{2F9CE}', false, &['\u{46C7}']),
+    ('\u{2F9CF}', false, &['\u{8AA0}']),
+    ('\u{2F9D0}', false, &['\u{8AED}']),
+    ('\u{2F9D1}', false, &['\u{8B8A}']),
+    ('\u{2F9D2}', false, &['\u{8C55}']),
+    ('\u{2F9D3}', false, &['\u{27CA8}']),
+    ('\u{2F9D4}', false, &['\u{8CAB}']),
+    ('\u{2F9D5}', false, &['\u{8CC1}']),
+    ('\u{2F9D6}', false, &['\u{8D1B}']),
+    ('\u{2F9D7}', false, &['\u{8D77}']),
+    ('\u{2F9D8}', false, &['\u{27F2F}']),
+    ('\u{2F9D9}', false, &['\u{20804}']),
+    ('\u{2F9DA}', false, &['\u{8DCB}']),
+    ('\u{2F9DB}', false, &['\u{8DBC}']),
+    ('\u{2F9DC}', false, &['\u{8DF0}']),
+    ('\u{2F9DD}', false, &['\u{208DE}']),
+    ('\u{2F9DE}', false, &['\u{8ED4}']),
+    ('\u{2F9DF}', false, &['\u{8F38}']),
+    ('\u{2F9E0}', false, &['\u{285D2}']),
+    ('\u{2F9E1}', false, &['\u{285ED}']),
+    ('\u{2F9E2}', false, &['\u{9094}']),
+    ('\u{2F9E3}', false, &['\u{90F1}']),
+    ('\u{2F9E4}', false, &['\u{9111}']),
+    ('\u{2F9E5}', false, &['\u{2872E}']),
+    ('\u{2F9E6}', false, &['\u{911B}']),
+    ('\u{2F9E7}', false, &['\u{9238}']),
+    ('\u{2F9E8}', false, &['\u{92D7}']),
+    ('\u{2F9E9}', false, &['\u{92D8}']),
+    ('\u{2F9EA}', false, &['\u{927C}']),
+    ('\u{2F9EB}', false, &['\u{93F9}']),
+    ('\u{2F9EC}', false, &['\u{9415}']),
+    ('\u{2F9ED}', false, &['\u{28BFA}']),
+    ('\u{2F9EE}', false, &['\u{958B}']),
+    ('\u{2F9EF}', false, &['\u{4995}']),
+    ('\u{2F9F0}', false, &['\u{95B7}']),
+    ('\u{2F9F1}', false, &['\u{28D77}']),
+    ('\u{2F9F2}', false, &['\u{49E6}']),
+    ('\u{2F9F3}', false, &['\u{96C3}']),
+    ('\u{2F9F4}', false, &['\u{5DB2}']),
+    ('\u{2F9F5}', false, &['\u{9723}']),
+    ('\u{2F9F6}', false, &['\u{29145}']),
+    ('\u{2F9F7}', false, &['\u{2921A}']),
+    ('\u{2F9F8}', false, &['\u{4A6E}']),
+    ('\u{2F9F9}', false, &['\u{4A76}']),
+    ('\u{2F9FA}', false, &['\u{97E0}']),
+    ('\u{2F9FB}', false, &['\u{2940A}']),
+    ('\u{2F9FC}', false, &['\u{4AB2}']),
+    ('\u{2F9FD}', false, &['\u{29496}']),
+    ('\u{2F9FE}', false, &['\u{980B}']),
+    ('\u{2F9FF}', false, &['\u{980B}']),
+    ('\u{2FA00}', false, &['\u{9829}']),
+    ('\u{2FA01}', false, &['\u{295B6}']),
+    ('\u{2FA02}', false, &['\u{98E2}']),
+    ('\u{2FA03}', false, &['\u{4B33}']),
+    ('\u{2FA04}', false, &['\u{9929}']),
+    ('\u{2FA05}', false, &['\u{99A7}']),
+    ('\u{2FA06}', false, &['\u{99C2}']),
+    ('\u{2FA07}', false, &['\u{99FE}']),
+    ('\u{2FA08}', false, &['\u{4BCE}']),
+    ('\u{2FA09}', false, &['\u{29B30}']),
+    ('\u{2FA0A}', false, &['\u{9B12}']),
+    ('\u{2FA0B}', false, &['\u{9C40}']),
+    ('\u{2FA0C}', false, &['\u{9CFD}']),
+    ('\u{2FA0D}', false, &['\u{4CCE}']),
+    ('\u{2FA0E}', false, &['\u{4CED}']),
+    ('\u{2FA0F}', false, &['\u{9D67}']),
+    ('\u{2FA10}', false, &['\u{2A0CE}']),
+    ('\u{2FA11}', false, &['\u{4CF8}']),
+    ('\u{2FA12}', false, &['\u{2A105}']),
+    ('\u{2FA13}', false, &['\u{2A20E}']),
+    ('\u{2FA14}', false, &['\u{2A291}']),
+    ('\u{2FA15}', false, &['\u{9EBB}']),
+    ('\u{2FA16}', false, &['\u{4D56}']),
+    ('\u{2FA17}', false, &['\u{9EF9}']),
+    ('\u{2FA18}', false, &['\u{9EFE}']),
+    ('\u{2FA19}', false, &['\u{9F05}']),
+    ('\u{2FA1A}', false, &['\u{9F0F}']),
+    ('\u{2FA1B}', false, &['\u{9F16}']),
+    ('\u{2FA1C}', false, &['\u{9F3B}']),
+    ('\u{2FA1D}', false, &['\u{2A600}']),
+];
+
+//* (first, second, composite) for the primary composites, i.e. without the composition exclusions
+pub(super) static COMPOSITION: [(char, char, char); 941] = [
+    ('<', '\u{338}', '\u{226E}'),
+    ('=', '\u{338}', '\u{2260}'),
+    ('>', '\u{338}', '\u{226F}'),
+    ('A', '\u{300}', '\u{C0}'),
+    ('A', '\u{301}', '\u{C1}'),
+    ('A', '\u{302}', '\u{C2}'),
+    ('A', '\u{303}', '\u{C3}'),
+    ('A', '\u{304}', '\u{100}'),
+    ('A', '\u{306}', '\u{102}'),
+    ('A', '\u{307}', '\u{226}'),
+    ('A', '\u{308}', '\u{C4}'),
+    ('A', '\u{309}', '\u{1EA2}'),
+    ('A', '\u{30A}', '\u{C5}'),
+    ('A', '\u{30C}', '\u{1CD}'),
+    ('A', '\u{30F}', '\u{200}'),
+    ('A', '\u{311}', '\u{202}'),
+    ('A', '\u{323}', '\u{1EA0}'),
+    ('A', '\u{325}', '\u{1E00}'),
+    ('A', '\u{328}', '\u{104}'),
+    ('B', '\u{307}', '\u{1E02}'),
+    ('B', '\u{323}', '\u{1E04}'),
+    ('B', '\u{331}', '\u{1E06}'),
+    ('C', '\u{301}', '\u{106}'),
+    ('C', '\u{302}', '\u{108}'),
+    ('C', '\u{307}', '\u{10A}'),
+    ('C', '\u{30C}', '\u{10C}'),
+    ('C', '\u{327}', '\u{C7}'),
+    ('D', '\u{307}', '\u{1E0A}'),
+    ('D', '\u{30C}', '\u{10E}'),
+    ('D', '\u{323}', '\u{1E0C}'),
+    ('D', '\u{327}', '\u{1E10}'),
+    ('D', '\u{32D}', '\u{1E12}'),
+    ('D', '\u{331}', '\u{1E0E}'),
+    ('E', '\u{300}', '\u{C8}'),
+    ('E', '\u{301}', '\u{C9}'),
+    ('E', '\u{302}', '\u{CA}'),
+    ('E', '\u{303}', '\u{1EBC}'),
+    ('E', '\u{304}', '\u{112}'),
+    ('E', '\u{306}', '\u{114}'),
+    ('E', '\u{307}', '\u{116}'),
+    ('E', '\u{308}', '\u{CB}'),
+    ('E', '\u{309}', '\u{1EBA}'),
+    ('E', '\u{30C}', '\u{11A}'),
+    ('E', '\u{30F}', '\u{204}'),
+    ('E', '\u{311}', '\u{206}'),
+    ('E', '\u{323}', '\u{1EB8}'),
+    ('E', '\u{327}', '\u{228}'),
+    ('E', '\u{328}', '\u{118}'),
+    ('E', '\u{32D}', '\u{1E18}'),
+    ('E', '\u{330}', '\u{1E1A}'),
+    ('F', '\u{307}', '\u{1E1E}'),
+    ('G', '\u{301}', '\u{1F4}'),
+    ('G', '\u{302}', '\u{11C}'),
+    ('G', '\u{304}', '\u{1E20}'),
+    ('G', '\u{306}', '\u{11E}'),
+    ('G', '\u{307}', '\u{120}'),
+    ('G', '\u{30C}', '\u{1E6}'),
+    ('G', '\u{327}', '\u{122}'),
+    ('H', '\u{302}', '\u{124}'),
+    ('H', '\u{307}', '\u{1E22}'),
+    ('H', '\u{308}', '\u{1E26}'),
+    ('H', '\u{30C}', '\u{21E}'),
+    ('H', '\u{323}', '\u{1E24}'),
+    ('H', '\u{327}', '\u{1E28}'),
+    ('H', '\u{32E}', '\u{1E2A}'),
+    ('I', '\u{300}', '\u{CC}'),
+    ('I', '\u{301}', '\u{CD}'),
+    ('I', '\u{302}', '\u{CE}'),
+    ('I', '\u{303}', '\u{128}'),
+    ('I', '\u{304}', '\u{12A}'),
+    ('I', '\u{306}', '\u{12C}'),
+    ('I', '\u{307}', '\u{130}'),
+    ('I', '\u{308}', '\u{CF}'),
+    ('I', '\u{309}', '\u{1EC8}'),
+    ('I', '\u{30C}', '\u{1CF}'),
+    ('I', '\u{30F}', '\u{208}'),
+    ('I', '\u{311}', '\u{20A}'),
+    ('I', '\u{323}', '\u{1ECA}'),
+    ('I', '\u{328}', '\u{12E}'),
+    ('I', '\u{330}', '\u{1E2C}'),
+    ('J', '\u{302}', '\u{134}'),
+    ('K', '\u{301}', '\u{1E30}'),
+    ('K', '\u{30C}', '\u{1E8}'),
+    ('K', '\u{323}', '\u{1E32}'),
+    ('K', '\u{327}', '\u{136}'),
+    ('K', '\u{331}', '\u{1E34}'),
+    ('L', '\u{301}', '\u{139}'),
+    ('L', '\u{30C}', '\u{13D}'),
+    ('L', '\u{323}', '\u{1E36}'),
+    ('L', '\u{327}', '\u{13B}'),
+    ('L', '\u{32D}', '\u{1E3C}'),
+    ('L', '\u{331}', '\u{1E3A}'),
+    ('M', '\u{301}', '\u{1E3E}'),
+    ('M', '\u{307}', '\u{1E40}'),
+    ('M', '\u{323}', '\u{1E42}'),
+    ('N', '\u{300}', '\u{1F8}'),
+    ('N', '\u{301}', '\u{143}'),
+    ('N', '\u{303}', '\u{D1}'),
+    ('N', '\u{307}', '\u{1E44}'),
+    ('N', '\u{30C}', '\u{147}'),
+    ('N', '\u{323}', '\u{1E46}'),
+    ('N', '\u{327}', '\u{145}'),
+    ('N', '\u{32D}', '\u{1E4A}'),
+    ('N', '\u{331}', '\u{1E48}'),
+    ('O', '\u{300}', '\u{D2}'),
+    ('O', '\u{301}', '\u{D3}'),
+    ('O', '\u{302}', '\u{D4}'),
+    ('O', '\u{303}', '\u{D5}'),
+    ('O', '\u{304}', '\u{14C}'),
+    ('O', '\u{306}', '\u{14E}'),
+    ('O', '\u{307}', '\u{22E}'),
+    ('O', '\u{308}', '\u{D6}'),
+    ('O', '\u{309}', '\u{1ECE}'),
+    ('O', '\u{30B}', '\u{150}'),
+    ('O', '\u{30C}', '\u{1D1}'),
+    ('O', '\u{30F}', '\u{20C}'),
+    ('O', '\u{311}', '\u{20E}'),
+    ('O', '\u{31B}', '\u{1A0}'),
+    ('O', '\u{323}', '\u{1ECC}'),
+    ('O', '\u{328}', '\u{1EA}'),
+    ('P', '\u{301}', '\u{1E54}'),
+    ('P', '\u{307}', '\u{1E56}'),
+    ('R', '\u{301}', '\u{154}'),
+    ('R', '\u{307}', '\u{1E58}'),
+    ('R', '\u{30C}', '\u{158}'),
+    ('R', '\u{30F}', '\u{210}'),
+    ('R', '\u{311}', '\u{212}'),
+    ('R', '\u{323}', '\u{1E5A}'),
+    ('R', '\u{327}', '\u{156}'),
+    ('R', '\u{331}', '\u{1E5E}'),
+    ('S', '\u{301}', '\u{15A}'),
+    ('S', '\u{302}', '\u{15C}'),
+    ('S', '\u{307}', '\u{1E60}'),
+    ('S', '\u{30C}', '\u{160}'),
+    ('S', '\u{323}', '\u{1E62}'),
+    ('S', '\u{326}', '\u{218}'),
+    ('S', '\u{327}', '\u{15E}'),
+    ('T', '\u{307}', '\u{1E6A}'),
+    ('T', '\u{30C}', '\u{164}'),
+    ('T', '\u{323}', '\u{1E6C}'),
+    ('T', '\u{326}', '\u{21A}'),
+    ('T', '\u{327}', '\u{162}'),
+    ('T', '\u{32D}', '\u{1E70}'),
+    ('T', '\u{331}', '\u{1E6E}'),
+    ('U', '\u{300}', '\u{D9}'),
+    ('U', '\u{301}', '\u{DA}'),
+    ('U', '\u{302}', '\u{DB}'),
+    ('U', '\u{303}', '\u{168}'),
+    ('U', '\u{304}', '\u{16A}'),
+    ('U', '\u{306}', '\u{16C}'),
+    ('U', '\u{308}', '\u{DC}'),
+    ('U', '\u{309}', '\u{1EE6}'),
+    ('U', '\u{30A}', '\u{16E}'),
+    ('U', '\u{30B}', '\u{170}'),
+    ('U', '\u{30C}', '\u{1D3}'),
+    ('U', '\u{30F}', '\u{214}'),
+    ('U', '\u{311}', '\u{216}'),
+    ('U', '\u{31B}', '\u{1AF}'),
+    ('U', '\u{323}', '\u{1EE4}'),
+    ('U', '\u{324}', '\u{1E72}'),
+    ('U', '\u{328}', '\u{172}'),
+    ('U', '\u{32D}', '\u{1E76}'),
+    ('U', '\u{330}', '\u{1E74}'),
+    ('V', '\u{303}', '\u{1E7C}'),
+    ('V', '\u{323}', '\u{1E7E}'),
+    ('W', '\u{300}', '\u{1E80}'),
+    ('W', '\u{301}', '\u{1E82}'),
+    ('W', '\u{302}', '\u{174}'),
+    ('W', '\u{307}', '\u{1E86}'),
+    ('W', '\u{308}', '\u{1E84}'),
+    ('W', '\u{323}', '\u{1E88}'),
+    ('X', '\u{307}', '\u{1E8A}'),
+    ('X', '\u{308}', '\u{1E8C}'),
+    ('Y', '\u{300}', '\u{1EF2}'),
+    ('Y', '\u{301}', '\u{DD}'),
+    ('Y', '\u{302}', '\u{176}'),
+    ('Y', '\u{303}', '\u{1EF8}'),
+    ('Y', '\u{304}', '\u{232}'),
+    ('Y', '\u{307}', '\u{1E8E}'),
+    ('Y', '\u{308}', '\u{178}'),
+    ('Y', '\u{309}', '\u{1EF6}'),
+    ('Y', '\u{323}', '\u{1EF4}'),
+    ('Z', '\u{301}', '\u{179}'),
+    ('Z', '\u{302}', '\u{1E90}'),
+    ('Z', '\u{307}', '\u{17B}'),
+    ('Z', '\u{30C}', '\u{17D}'),
+    ('Z', '\u{323}', '\u{1E92}'),
+    ('Z', '\u{331}', '\u{1E94}'),
+    ('a', '\u{300}', '\u{E0}'),
+    ('a', '\u{301}', '\u{E1}'),
+    ('a', '\u{302}', '\u{E2}'),
+    ('a', '\u{303}', '\u{E3}'),
+    ('a', '\u{304}', '\u{101}'),
+    ('a', '\u{306}', '\u{103}'),
+    ('a', '\u{307}', '\u{227}'),
+    ('a', '\u{308}', '\u{E4}'),
+    ('a', '\u{309}', '\u{1EA3}'),
+    ('a', '\u{30A}', '\u{E5}'),
+    ('a', '\u{30C}', '\u{1CE}'),
+    ('a', '\u{30F}', '\u{201}'),
+    ('a', '\u{311}', '\u{203}'),
+    ('a', '\u{323}', '\u{1EA1}'),
+    ('a', '\u{325}', '\u{1E01}'),
+    ('a', '\u{328}', '\u{105}'),
+    ('b', '\u{307}', '\u{1E03}'),
+    ('b', '\u{323}', '\u{1E05}'),
+    ('b', '\u{331}', '\u{1E07}'),
+    ('c', '\u{301}', '\u{107}'),
+    ('c', '\u{302}', '\u{109}'),
+    ('c', '\u{307}', '\u{10B}'),
+    ('c', '\u{30C}', '\u{10D}'),
+    ('c', '\u{327}', '\u{E7}'),
+    ('d', '\u{307}', '\u{1E0B}'),
+    ('d', '\u{30C}', '\u{10F}'),
+    ('d', '\u{323}', '\u{1E0D}'),
+    ('d', '\u{327}', '\u{1E11}'),
+    ('d', '\u{32D}', '\u{1E13}'),
+    ('d', '\u{331}', '\u{1E0F}'),
+    ('e', '\u{300}', '\u{E8}'),
+    ('e', '\u{301}', '\u{E9}'),
+    ('e', '\u{302}', '\u{EA}'),
+    ('e', '\u{303}', '\u{1EBD}'),
+    ('e', '\u{304}', '\u{113}'),
+    ('e', '\u{306}', '\u{115}'),
+    ('e', '\u{307}', '\u{117}'),
+    ('e', '\u{308}', '\u{EB}'),
+    ('e', '\u{309}', '\u{1EBB}'),
+    ('e', '\u{30C}', '\u{11B}'),
+    ('e', '\u{30F}', '\u{205}'),
+    ('e', '\u{311}', '\u{207}'),
+    ('e', '\u{323}', '\u{1EB9}'),
+    ('e', '\u{327}', '\u{229}'),
+    ('e', '\u{328}', '\u{119}'),
+    ('e', '\u{32D}', '\u{1E19}'),
+    ('e', '\u{330}', '\u{1E1B}'),
+    ('f', '\u{307}', '\u{1E1F}'),
+    ('g', '\u{301}', '\u{1F5}'),
+    ('g', '\u{302}', '\u{11D}'),
+    ('g', '\u{304}', '\u{1E21}'),
+    ('g', '\u{306}', '\u{11F}'),
+    ('g', '\u{307}', '\u{121}'),
+    ('g', '\u{30C}', '\u{1E7}'),
+    ('g', '\u{327}', '\u{123}'),
+    ('h', '\u{302}', '\u{125}'),
+    ('h', '\u{307}', '\u{1E23}'),
+    ('h', '\u{308}', '\u{1E27}'),
+    ('h', '\u{30C}', '\u{21F}'),
+    ('h', '\u{323}', '\u{1E25}'),
+    ('h', '\u{327}', '\u{1E29}'),
+    ('h', '\u{32E}', '\u{1E2B}'),
+    ('h', '\u{331}', '\u{1E96}'),
+    ('i', '\u{300}', '\u{EC}'),
+    ('i', '\u{301}', '\u{ED}'),
+    ('i', '\u{302}', '\u{EE}'),
+    ('i', '\u{303}', '\u{129}'),
+    ('i', '\u{304}', '\u{12B}'),
+    ('i', '\u{306}', '\u{12D}'),
+    ('i', '\u{308}', '\u{EF}'),
+    ('i', '\u{309}', '\u{1EC9}'),
+    ('i', '\u{30C}', '\u{1D0}'),
+    ('i', '\u{30F}', '\u{209}'),
+    ('i', '\u{311}', '\u{20B}'),
+    ('i', '\u{323}', '\u{1ECB}'),
+    ('i', '\u{328}', '\u{12F}'),
+    ('i', '\u{330}', '\u{1E2D}'),
+    ('j', '\u{302}', '\u{135}'),
+    ('j', '\u{30C}', '\u{1F0}'),
+    ('k', '\u{301}', '\u{1E31}'),
+    ('k', '\u{30C}', '\u{1E9}'),
+    ('k', '\u{323}', '\u{1E33}'),
+    ('k', '\u{327}', '\u{137}'),
+    ('k', '\u{331}', '\u{1E35}'),
+    ('l', '\u{301}', '\u{13A}'),
+    ('l', '\u{30C}', '\u{13E}'),
+    ('l', '\u{323}', '\u{1E37}'),
+    ('l', '\u{327}', '\u{13C}'),
+    ('l', '\u{32D}', '\u{1E3D}'),
+    ('l', '\u{331}', '\u{1E3B}'),
+    ('m', '\u{301}', '\u{1E3F}'),
+    ('m', '\u{307}', '\u{1E41}'),
+    ('m', '\u{323}', '\u{1E43}'),
+    ('n', '\u{300}', '\u{1F9}'),
+    ('n', '\u{301}', '\u{144}'),
+    ('n', '\u{303}', '\u{F1}'),
+    ('n', '\u{307}', '\u{1E45}'),
+    ('n', '\u{30C}', '\u{148}'),
+    ('n', '\u{323}', '\u{1E47}'),
+    ('n', '\u{327}', '\u{146}'),
+    ('n', '\u{32D}', '\u{1E4B}'),
+    ('n', '\u{331}', '\u{1E49}'),
+    ('o', '\u{300}', '\u{F2}'),
+    ('o', '\u{301}', '\u{F3}'),
+    ('o', '\u{302}', '\u{F4}'),
+    ('o', '\u{303}', '\u{F5}'),
+    ('o', '\u{304}', '\u{14D}'),
+    ('o', '\u{306}', '\u{14F}'),
+    ('o', '\u{307}', '\u{22F}'),
+    ('o', '\u{308}', '\u{F6}'),
+    ('o', '\u{309}', '\u{1ECF}'),
+    ('o', '\u{30B}', '\u{151}'),
+    ('o', '\u{30C}', '\u{1D2}'),
+    ('o', '\u{30F}', '\u{20D}'),
+    ('o', '\u{311}', '\u{20F}'),
+    ('o', '\u{31B}', '\u{1A1}'),
+    ('o', '\u{323}', '\u{1ECD}'),
+    ('o', '\u{328}', '\u{1EB}'),
+    ('p', '\u{301}', '\u{1E55}'),
+    ('p', '\u{307}', '\u{1E57}'),
+    ('r', '\u{301}', '\u{155}'),
+    ('r', '\u{307}', '\u{1E59}'),
+    ('r', '\u{30C}', '\u{159}'),
+    ('r', '\u{30F}', '\u{211}'),
+    ('r', '\u{311}', '\u{213}'),
+    ('r', '\u{323}', '\u{1E5B}'),
+    ('r', '\u{327}', '\u{157}'),
+    ('r', '\u{331}', '\u{1E5F}'),
+    ('s', '\u{301}', '\u{15B}'),
+    ('s', '\u{302}', '\u{15D}'),
+    ('s', '\u{307}', '\u{1E61}'),
+    ('s', '\u{30C}', '\u{161}'),
+    ('s', '\u{323}', '\u{1E63}'),
+    ('s', '\u{326}', '\u{219}'),
+    ('s', '\u{327}', '\u{15F}'),
+    ('t', '\u{307}', '\u{1E6B}'),
+    ('t', '\u{308}', '\u{1E97}'),
+    ('t', '\u{30C}', '\u{165}'),
+    ('t', '\u{323}', '\u{1E6D}'),
+    ('t', '\u{326}', '\u{21B}'),
+    ('t', '\u{327}', '\u{163}'),
+    ('t', '\u{32D}', '\u{1E71}'),
+    ('t', '\u{331}', '\u{1E6F}'),
+    ('u', '\u{300}', '\u{F9}'),
+    ('u', '\u{301}', '\u{FA}'),
+    ('u', '\u{302}', '\u{FB}'),
+    ('u', '\u{303}', '\u{169}'),
+    ('u', '\u{304}', '\u{16B}'),
+    ('u', '\u{306}', '\u{16D}'),
+    ('u', '\u{308}', '\u{FC}'),
+    ('u', '\u{309}', '\u{1EE7}'),
+    ('u', '\u{30A}', '\u{16F}'),
+    ('u', '\u{30B}', '\u{171}'),
+    ('u', '\u{30C}', '\u{1D4}'),
+    ('u', '\u{30F}', '\u{215}'),
+    ('u', '\u{311}', '\u{217}'),
+    ('u', '\u{31B}', '\u{1B0}'),
+    ('u', '\u{323}', '\u{1EE5}'),
+    ('u', '\u{324}', '\u{1E73}'),
+    ('u', '\u{328}', '\u{173}'),
+    ('u', '\u{32D}', '\u{1E77}'),
+    ('u', '\u{330}', '\u{1E75}'),
+    ('v', '\u{303}', '\u{1E7D}'),
+    ('v', '\u{323}', '\u{1E7F}'),
+    ('w', '\u{300}', '\u{1E81}'),
+    ('w', '\u{301}', '\u{1E83}'),
+    ('w', '\u{302}', '\u{175}'),
+    ('w', '\u{307}', '\u{1E87}'),
+    ('w', '\u{308}', '\u{1E85}'),
+    ('w', '\u{30A}', '\u{1E98}'),
+    ('w', '\u{323}', '\u{1E89}'),
+    ('x', '\u{307}', '\u{1E8B}'),
+    ('x', '\u{308}', '\u{1E8D}'),
+    ('y', '\u{300}', '\u{1EF3}'),
+    ('y', '\u{301}', '\u{FD}'),
+    ('y', '\u{302}', '\u{177}'),
+    ('y', '\u{303}', '\u{1EF9}'),
+    ('y', '\u{304}', '\u{233}'),
+    ('y', '\u{307}', '\u{1E8F}'),
+    ('y', '\u{308}', '\u{FF}'),
+    ('y', '\u{309}', '\u{1EF7}'),
+    ('y', '\u{30A}', '\u{1E99}'),
+    ('y', '\u{323}', '\u{1EF5}'),
+    ('z', '\u{301}', '\u{17A}'),
+    ('z', '\u{302}', '\u{1E91}'),
+    ('z', '\u{307}', '\u{17C}'),
+    ('z', '\u{30C}', '\u{17E}'),
+    ('z', '\u{323}', '\u{1E93}'),
+    ('z', '\u{331}', '\u{1E95}'),
+    ('\u{A8}', '\u{300}', '\u{1FED}'),
+    ('\u{A8}', '\u{301}', '\u{385}'),
+    ('\u{A8}', '\u{342}', '\u{1FC1}'),
+    ('\u{C2}', '\u{300}', '\u{1EA6}'),
+    ('\u{C2}', '\u{301}', '\u{1EA4}'),
+    ('\u{C2}', '\u{303}', '\u{1EAA}'),
+    ('\u{C2}', '\u{309}', '\u{1EA8}'),
+    ('\u{C4}', '\u{304}', '\u{1DE}'),
+    ('\u{C5}', '\u{301}', '\u{1FA}'),
+    ('\u{C6}', '\u{301}', '\u{1FC}'),
+    ('\u{C6}', '\u{304}', '\u{1E2}'),
+    ('\u{C7}', '\u{301}', '\u{1E08}'),
+    ('\u{CA}', '\u{300}', '\u{1EC0}'),
+    ('\u{CA}', '\u{301}', '\u{1EBE}'),
+    ('\u{CA}', '\u{303}', '\u{1EC4}'),
+    ('\u{CA}', '\u{309}', '\u{1EC2}'),
+    ('\u{CF}', '\u{301}', '\u{1E2E}'),
+    ('\u{D4}', '\u{300}', '\u{1ED2}'),
+    ('\u{D4}', '\u{301}', '\u{1ED0}'),
+    ('\u{D4}', '\u{303}', '\u{1ED6}'),
+    ('\u{D4}', '\u{309}', '\u{1ED4}'),
+    ('\u{D5}', '\u{301}', '\u{1E4C}'),
+    ('\u{D5}', '\u{304}', '\u{22C}'),
+    ('\u{D5}', '\u{308}', '\u{1E4E}'),
+    ('\u{D6}', '\u{304}', '\u{22A}'),
+    ('\u{D8}', '\u{301}', '\u{1FE}'),
+    ('\u{DC}', '\u{300}', '\u{1DB}'),
+    ('\u{DC}', '\u{301}', '\u{1D7}'),
+    ('\u{DC}', '\u{304}', '\u{1D5}'),
+    ('\u{DC}', '\u{30C}', '\u{1D9}'),
+    ('\u{E2}', '\u{300}', '\u{1EA7}'),
+    ('\u{E2}', '\u{301}', '\u{1EA5}'),
+    ('\u{E2}', '\u{303}', '\u{1EAB}'),
+    ('\u{E2}', '\u{309}', '\u{1EA9}'),
+    ('\u{E4}', '\u{304}', '\u{1DF}'),
+    ('\u{E5}', '\u{301}', '\u{1FB}'),
+    ('\u{E6}', '\u{301}', '\u{1FD}'),
+    ('\u{E6}', '\u{304}', '\u{1E3}'),
+    ('\u{E7}', '\u{301}', '\u{1E09}'),
+    ('\u{EA}', '\u{300}', '\u{1EC1}'),
+    ('\u{EA}', '\u{301}', '\u{1EBF}'),
+    ('\u{EA}', '\u{303}', '\u{1EC5}'),
+    ('\u{EA}', '\u{309}', '\u{1EC3}'),
+    ('\u{EF}', '\u{301}', '\u{1E2F}'),
+    ('\u{F4}', '\u{300}', '\u{1ED3}'),
+    ('\u{F4}', '\u{301}', '\u{1ED1}'),
+    ('\u{F4}', '\u{303}', '\u{1ED7}'),
+    ('\u{F4}', '\u{309}', '\u{1ED5}'),
+    ('\u{F5}', '\u{301}', '\u{1E4D}'),
+    ('\u{F5}', '\u{304}', '\u{22D}'),
+    ('\u{F5}', '\u{308}', '\u{1E4F}'),
+    ('\u{F6}', '\u{304}', '\u{22B}'),
+    ('\u{F8}', '\u{301}', '\u{1FF}'),
+    ('\u{FC}', '\u{300}', '\u{1DC}'),
+    ('\u{FC}', '\u{301}', '\u{1D8}'),
+    ('\u{FC}', '\u{304}', '\u{1D6}'),
+    ('\u{FC}', '\u{30C}', '\u{1DA}'),
+    ('\u{102}', '\u{300}', '\u{1EB0}'),
+    ('\u{102}', '\u{301}', '\u{1EAE}'),
+    ('\u{102}', '\u{303}', '\u{1EB4}'),
+    ('\u{102}', '\u{309}', '\u{1EB2}'),
+    ('\u{103}', '\u{300}', '\u{1EB1}'),
+    ('\u{103}', '\u{301}', '\u{1EAF}'),
+    ('\u{103}', '\u{303}', '\u{1EB5}'),
+    ('\u{103}', '\u{309}', '\u{1EB3}'),
+    ('\u{112}', '\u{300}', '\u{1E14}'),
+    ('\u{112}', '\u{301}', '\u{1E16}'),
+    ('\u{113}', '\u{300}', '\u{1E15}'),
+    ('\u{113}', '\u{301}', '\u{1E17}'),
+    ('\u{14C}', '\u{300}', '\u{1E50}'),
+    ('\u{14C}', '\u{301}', '\u{1E52}'),
+    ('\u{14D}', '\u{300}', '\u{1E51}'),
+    ('\u{14D}', '\u{301}', '\u{1E53}'),
+    ('\u{15A}', '\u{307}', '\u{1E64}'),
+    ('\u{15B}', '\u{307}', '\u{1E65}'),
+    ('\u{160}', '\u{307}', '\u{1E66}'),
+    ('\u{161}', '\u{307}', '\u{1E67}'),
+    ('\u{168}', '\u{301}', '\u{1E78}'),
+    ('\u{169}', '\u{301}', '\u{1E79}'),
+    ('\u{16A}', '\u{308}', '\u{1E7A}'),
+    ('\u{16B}', '\u{308}', '\u{1E7B}'),
+    ('\u{17F}', '\u{307}', '\u{1E9B}'),
+    ('\u{1A0}', '\u{300}', '\u{1EDC}'),
+    ('\u{1A0}', '\u{301}', '\u{1EDA}'),
+    ('\u{1A0}', '\u{303}', '\u{1EE0}'),
+    ('\u{1A0}', '\u{309}', '\u{1EDE}'),
+    ('\u{1A0}', '\u{323}', '\u{1EE2}'),
+    ('\u{1A1}', '\u{300}', '\u{1EDD}'),
+    ('\u{1A1}', '\u{301}', '\u{1EDB}'),
+    ('\u{1A1}', '\u{303}', '\u{1EE1}'),
+    ('\u{1A1}', '\u{309}', '\u{1EDF}'),
+    ('\u{1A1}', '\u{323}', '\u{1EE3}'),
+    ('\u{1AF}', '\u{300}', '\u{1EEA}'),
+    ('\u{1AF}', '\u{301}', '\u{1EE8}'),
+    ('\u{1AF}', '\u{303}', '\u{1EEE}'),
+    ('\u{1AF}', '\u{309}', '\u{1EEC}'),
+    ('\u{1AF}', '\u{323}', '\u{1EF0}'),
+    ('\u{1B0}', '\u{300}', '\u{1EEB}'),
+    ('\u{1B0}', '\u{301}', '\u{1EE9}'),
+    ('\u{1B0}', '\u{303}', '\u{1EEF}'),
+    ('\u{1B0}', '\u{309}', '\u{1EED}'),
+    ('\u{1B0}', '\u{323}', '\u{1EF1}'),
+    ('\u{1B7}', '\u{30C}', '\u{1EE}'),
+    ('\u{1EA}', '\u{304}', '\u{1EC}'),
+    ('\u{1EB}', '\u{304}', '\u{1ED}'),
+    ('\u{226}', '\u{304}', '\u{1E0}'),
+    ('\u{227}', '\u{304}', '\u{1E1}'),
+    ('\u{228}', '\u{306}', '\u{1E1C}'),
+    ('\u{229}', '\u{306}', '\u{1E1D}'),
+    ('\u{22E}', '\u{304}', '\u{230}'),
+    ('\u{22F}', '\u{304}', '\u{231}'),
+    ('\u{292}', '\u{30C}', '\u{1EF}'),
+    ('\u{391}', '\u{300}', '\u{1FBA}'),
+    ('\u{391}', '\u{301}', '\u{386}'),
+    ('\u{391}', '\u{304}', '\u{1FB9}'),
+    ('\u{391}', '\u{306}', '\u{1FB8}'),
+    ('\u{391}', '\u{313}', '\u{1F08}'),
+    ('\u{391}', '\u{314}', '\u{1F09}'),
+    ('\u{391}', '\u{345}', '\u{1FBC}'),
+    ('\u{395}', '\u{300}', '\u{1FC8}'),
+    ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1F18}'),
+    ('\u{395}', '\u{314}', '\u{1F19}'),
+    ('\u{397}', '\u{300}', '\u{1FCA}'),
+    ('\u{397}', '\u{301}', '\u{389}'),
+    ('\u{397}', '\u{313}', '\u{1F28}'),
+    ('\u{397}', '\u{314}', '\u{1F29}'),
+    ('\u{397}', '\u{345}', '\u{1FCC}'),
+    ('\u{399}', '\u{300}', '\u{1FDA}'),
+    ('\u{399}', '\u{301}', '\u{38A}'),
+    ('\u{399}', '\u{304}', '\u{1FD9}'),
+    ('\u{399}', '\u{306}', '\u{1FD8}'),
+    ('\u{399}', '\u{308}', '\u{3AA}'),
+    ('\u{399}', '\u{313}', '\u{1F38}'),
+    ('\u{399}', '\u{314}', '\u{1F39}'),
+    ('\u{39F}', '\u{300}', '\u{1FF8}'),
+    ('\u{39F}', '\u{301}', '\u{38C}'),
+    ('\u{39F}', '\u{313}', '\u{1F48}'),
+    ('\u{39F}', '\u{314}', '\u{1F49}'),
+    ('\u{3A1}', '\u{314}', '\u{1FEC}'),
+    ('\u{3A5}', '\u{300}', '\u{1FEA}'),
+    ('\u{3A5}', '\u{301}', '\u{38E}'),
+    ('\u{3A5}', '\u{304}', '\u{1FE9}'),
+    ('\u{3A5}', '\u{306}', '\u{1FE8}'),
+    ('\u{3A5}', '\u{308}', '\u{3AB}'),
+    ('\u{3A5}', '\u{314}', '\u{1F59}'),
+    ('\u{3A9}', '\u{300}', '\u{1FFA}'),
+    ('\u{3A9}', '\u{301}', '\u{38F}'),
+    ('\u{3A9}', '\u{313}', '\u{1F68}'),
+    ('\u{3A9}', '\u{314}', '\u{1F69}'),
+    ('\u{3A9}', '\u{345}', '\u{1FFC}'),
+    ('\u{3AC}', '\u{345}', '\u{1FB4}'),
+    ('\u{3AE}', '\u{345}', '\u{1FC4}'),
+    ('\u{3B1}', '\u{300}', '\u{1F70}'),
+    ('\u{3B1}', '\u{301}', '\u{3AC}'),
+    ('\u{3B1}', '\u{304}', '\u{1FB1}'),
+    ('\u{3B1}', '\u{306}', '\u{1FB0}'),
+    ('\u{3B1}', '\u{313}', '\u{1F00}'),
+    ('\u{3B1}', '\u{314}', '\u{1F01}'),
+    ('\u{3B1}', '\u{342}', '\u{1FB6}'),
+    ('\u{3B1}', '\u{345}', '\u{1FB3}'),
+    ('\u{3B5}', '\u{300}', '\u{1F72}'),
+    ('\u{3B5}', '\u{301}', '\u{3AD}'),
+    ('\u{3B5}', '\u{313}', '\u{1F10}'),
+    ('\u{3B5}', '\u{314}', '\u{1F11}'),
+    ('\u{3B7}', '\u{300}', '\u{1F74}'),
+    ('\u{3B7}', '\u{301}', '\u{3AE}'),
+    ('\u{3B7}', '\u{313}', '\u{1F20}'),
+    ('\u{3B7}', '\u{314}', '\u{1F21}'),
+    ('\u{3B7}', '\u{342}', '\u{1FC6}'),
+    ('\u{3B7}', '\u{345}', '\u{1FC3}'),
+    ('\u{3B9}', '\u{300}', '\u{1F76}'),
+    ('\u{3B9}', '\u{301}', '\u{3AF}'),
+    ('\u{3B9}', '\u{304}', '\u{1FD1}'),
+    ('\u{3B9}', '\u{306}', '\u{1FD0}'),
+    ('\u{3B9}', '\u{308}', '\u{3CA}'),
+    ('\u{3B9}', '\u{313}', '\u{1F30}'),
+    ('\u{3B9}', '\u{314}', '\u{1F31}'),
+    ('\u{3B9}', '\u{342}', '\u{1FD6}'),
+    ('\u{3BF}', '\u{300}', '\u{1F78}'),
+    ('\u{3BF}', '\u{301}', '\u{3CC}'),
+    ('\u{3BF}', '\u{313}', '\u{1F40}'),
+    ('\u{3BF}', '\u{314}', '\u{1F41}'),
+    ('\u{3C1}', '\u{313}', '\u{1FE4}'),
+    ('\u{3C1}', '\u{314}', '\u{1FE5}'),
+    ('\u{3C5}', '\u{300}', '\u{1F7A}'),
+    ('\u{3C5}', '\u{301}', '\u{3CD}'),
+    ('\u{3C5}', '\u{304}', '\u{1FE1}'),
+    ('\u{3C5}', '\u{306}', '\u{1FE0}'),
+    ('\u{3C5}', '\u{308}', '\u{3CB}'),
+    ('\u{3C5}', '\u{313}', '\u{1F50}'),
+    ('\u{3C5}', '\u{314}', '\u{1F51}'),
+    ('\u{3C5}', '\u{342}', '\u{1FE6}'),
+    ('\u{3C9}', '\u{300}', '\u{1F7C}'),
+    ('\u{3C9}', '\u{301}', '\u{3CE}'),
+    ('\u{3C9}', '\u{313}', '\u{1F60}'),
+    ('\u{3C9}', '\u{314}', '\u{1F61}'),
+    ('\u{3C9}', '\u{342}', '\u{1FF6}'),
+    ('\u{3C9}', '\u{345}', '\u{1FF3}'),
+    ('\u{3CA}', '\u{300}', '\u{1FD2}'),
+    ('\u{3CA}', '\u{301}', '\u{390}'),
+    ('\u{3CA}', '\u{342}', '\u{1FD7}'),
+    ('\u{3CB}', '\u{300}', '\u{1FE2}'),
+    ('\u{3CB}', '\u{301}', '\u{3B0}'),
+    ('\u{3CB}', '\u{342}', '\u{1FE7}'),
+    ('\u{3CE}', '\u{345}', '\u{1FF4}'),
+    ('\u{3D2}', '\u{301}', '\u{3D3}'),
+    ('\u{3D2}', '\u{308}', '\u{3D4}'),
+    ('\u{406}', '\u{308}', '\u{407}'),
+    ('\u{410}', '\u{306}', '\u{4D0}'),
+    ('\u{410}', '\u{308}', '\u{4D2}'),
+    ('\u{413}', '\u{301}', '\u{403}'),
+    ('\u{415}', '\u{300}', '\u{400}'),
+    ('\u{415}', '\u{306}', '\u{4D6}'),
+    ('\u{415}', '\u{308}', '\u{401}'),
+    ('\u{416}', '\u{306}', '\u{4C1}'),
+    ('\u{416}', '\u{308}', '\u{4DC}'),
+    ('\u{417}', '\u{308}', '\u{4DE}'),
+    ('\u{418}', '\u{300}', '\u{40D}'),
+    ('\u{418}', '\u{304}', '\u{4E2}'),
+    ('\u{418}', '\u{306}', '\u{419}'),
+    ('\u{418}', '\u{308}', '\u{4E4}'),
+    ('\u{41A}', '\u{301}', '\u{40C}'),
+    ('\u{41E}', '\u{308}', '\u{4E6}'),
+    ('\u{423}', '\u{304}', '\u{4EE}'),
+    ('\u{423}', '\u{306}', '\u{40E}'),
+    ('\u{423}', '\u{308}', '\u{4F0}'),
+    ('\u{423}', '\u{30B}', '\u{4F2}'),
+    ('\u{427}', '\u{308}', '\u{4F4}'),
+    ('\u{42B}', '\u{308}', '\u{4F8}'),
+    ('\u{42D}', '\u{308}', '\u{4EC}'),
+    ('\u{430}', '\u{306}', '\u{4D1}'),
+    ('\u{430}', '\u{308}', '\u{4D3}'),
+    ('\u{433}', '\u{301}', '\u{453}'),
+    ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4D7}'),
+    ('\u{435}', '\u{308}', '\u{451}'),
+    ('\u{436}', '\u{306}', '\u{4C2}'),
+    ('\u{436}', '\u{308}', '\u{4DD}'),
+    ('\u{437}', '\u{308}', '\u{4DF}'),
+    ('\u{438}', '\u{300}', '\u{45D}'),
+    ('\u{438}', '\u{304}', '\u{4E3}'),
+    ('\u{438}', '\u{306}', '\u{439}'),
+    ('\u{438}', '\u{308}', '\u{4E5}'),
+    ('\u{43A}', '\u{301}', '\u{45C}'),
+    ('\u{43E}', '\u{308}', '\u{4E7}'),
+    ('\u{443}', '\u{304}', '\u{4EF}'),
+    ('\u{443}', '\u{306}', '\u{45E}'),
+    ('\u{443}', '\u{308}', '\u{4F1}'),
+    ('\u{443}', '\u{30B}', '\u{4F3}'),
+    ('\u{447}', '\u{308}', '\u{4F5}'),
+    ('\u{44B}', '\u{308}', '\u{4F9}'),
+    ('\u{44D}', '\u{308}', '\u{4ED}'),
+    ('\u{456}', '\u{308}', '\u{457}'),
+    ('\u{474}', '\u{30F}', '\u{476}'),
+    ('\u{475}', '\u{30F}', '\u{477}'),
+    ('\u{4D8}', '\u{308}', '\u{4DA}'),
+    ('\u{4D9}', '\u{308}', '\u{4DB}'),
+    ('\u{4E8}', '\u{308}', '\u{4EA}'),
+    ('\u{4E9}', '\u{308}', '\u{4EB}'),
+    ('\u{627}', '\u{653}', '\u{622}'),
+    ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'),
+    ('\u{648}', '\u{654}', '\u{624}'),
+    ('\u{64A}', '\u{654}', '\u{626}'),
+    ('\u{6C1}', '\u{654}', '\u{6C2}'),
+    ('\u{6D2}', '\u{654}', '\u{6D3}'),
+    ('\u{6D5}', '\u{654}', '\u{6C0}'),
+    ('\u{928}', '\u{93C}', '\u{929}'),
+    ('\u{930}', '\u{93C}', '\u{931}'),
+    ('\u{933}', '\u{93C}', '\u{934}'),
+    ('\u{9C7}', '\u{9BE}', '\u{9CB}'),
+    ('\u{9C7}', '\u{9D7}', '\u{9CC}'),
+    ('\u{B47}', '\u{B3E}', '\u{B4B}'),
+    ('\u{B47}', '\u{B56}', '\u{B48}'),
+    ('\u{B47}', '\u{B57}', '\u{B4C}'),
+    ('\u{B92}', '\u{BD7}', '\u{B94}'),
+    ('\u{BC6}', '\u{BBE}', '\u{BCA}'),
+    ('\u{BC6}', '\u{BD7}', '\u{BCC}'),
+    ('\u{BC7}', '\u{BBE}', '\u{BCB}'),
+    ('\u{C46}', '\u{C56}', '\u{C48}'),
+    ('\u{CBF}', '\u{CD5}', '\u{CC0}'),
+    ('\u{CC6}', '\u{CC2}', '\u{CCA}'),
+    ('\u{CC6}', '\u{CD5}', '\u{CC7}'),
+    ('\u{CC6}', '\u{CD6}', '\u{CC8}'),
+    ('\u{CCA}', '\u{CD5}', '\u{CCB}'),
+    ('\u{D46}', '\u{D3E}', '\u{D4A}'),
+    ('\u{D46}', '\u{D57}', '\u{D4C}'),
+    ('\u{D47}', '\u{D3E}', '\u{D4B}'),
+    ('\u{DD9}', '\u{DCA}', '\u{DDA}'),
+    ('\u{DD9}', '\u{DCF}', '\u{DDC}'),
+    ('\u{DD9}', '\u{DDF}', '\u{DDE}'),
+    ('\u{DDC}', '\u{DCA}', '\u{DDD}'),
+    ('\u{1025}', '\u{102E}', '\u{1026}'),
+    ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'),
+    ('\u{1B09}', '\u{1B35}', '\u{1B0A}'),
+    ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'),
+    ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'),
+    ('\u{1B11}', '\u{1B35}', '\u{1B12}'),
+    ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'),
+    ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'),
+    ('\u{1B3E}', '\u{1B35}', '\u{1B40}'),
+    ('\u{1B3F}', '\u{1B35}', '\u{1B41}'),
+    ('\u{1B42}', '\u{1B35}', '\u{1B43}'),
+    ('\u{1E36}', '\u{304}', '\u{1E38}'),
+    ('\u{1E37}', '\u{304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{304}', '\u{1E5C}'),
+    ('\u{1E5B}', '\u{304}', '\u{1E5D}'),
+    ('\u{1E62}', '\u{307}', '\u{1E68}'),
+    ('\u{1E63}', '\u{307}', '\u{1E69}'),
+    ('\u{1EA0}', '\u{302}', '\u{1EAC}'),
+    ('\u{1EA0}', '\u{306}', '\u{1EB6}'),
+    ('\u{1EA1}', '\u{302}', '\u{1EAD}'),
+    ('\u{1EA1}', '\u{306}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{302}', '\u{1EC6}'),
+    ('\u{1EB9}', '\u{302}', '\u{1EC7}'),
+    ('\u{1ECC}', '\u{302}', '\u{1ED8}'),
+    ('\u{1ECD}', '\u{302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{300}', '\u{1F02}'),
+    ('\u{1F00}', '\u{301}', '\u{1F04}'),
+    ('\u{1F00}', '\u{342}', '\u{1F06}'),
+    ('\u{1F00}', '\u{345}', '\u{1F80}'),
+    ('\u{1F01}', '\u{300}', '\u{1F03}'),
+    ('\u{1F01}', '\u{301}', '\u{1F05}'),
+    ('\u{1F01}', '\u{342}', '\u{1F07}'),
+    ('\u{1F01}', '\u{345}', '\u{1F81}'),
+    ('\u{1F02}', '\u{345}', '\u{1F82}'),
+    ('\u{1F03}', '\u{345}', '\u{1F83}'),
+    ('\u{1F04}', '\u{345}', '\u{1F84}'),
+    ('\u{1F05}', '\u{345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{345}', '\u{1F86}'),
+    ('\u{1F07}', '\u{345}', '\u{1F87}'),
+    ('\u{1F08}', '\u{300}', '\u{1F0A}'),
+    ('\u{1F08}', '\u{301}', '\u{1F0C}'),
+    ('\u{1F08}', '\u{342}', '\u{1F0E}'),
+    ('\u{1F08}', '\u{345}', '\u{1F88}'),
+    ('\u{1F09}', '\u{300}', '\u{1F0B}'),
+    ('\u{1F09}', '\u{301}', '\u{1F0D}'),
+    ('\u{1F09}', '\u{342}', '\u{1F0F}'),
+    ('\u{1F09}', '\u{345}', '\u{1F89}'),
+    ('\u{1F0A}', '\u{345}', '\u{1F8A}'),
+    ('\u{1F0B}', '\u{345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{345}', '\u{1F8C}'),
+    ('\u{1F0D}', '\u{345}', '\u{1F8D}'),
+    ('\u{1F0E}', '\u{345}', '\u{1F8E}'),
+    ('\u{1F0F}', '\u{345}', '\u{1F8F}'),
+    ('\u{1F10}', '\u{300}', '\u{1F12}'),
+    ('\u{1F10}', '\u{301}', '\u{1F14}'),
+    ('\u{1F11}', '\u{300}', '\u{1F13}'),
+    ('\u{1F11}', '\u{301}', '\u{1F15}'),
+    ('\u{1F18}', '\u{300}', '\u{1F1A}'),
+    ('\u{1F18}', '\u{301}', '\u{1F1C}'),
+    ('\u{1F19}', '\u{300}', '\u{1F1B}'),
+    ('\u{1F19}', '\u{301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{300}', '\u{1F22}'),
+    ('\u{1F20}', '\u{301}', '\u{1F24}'),
+    ('\u{1F20}', '\u{342}', '\u{1F26}'),
+    ('\u{1F20}', '\u{345}', '\u{1F90}'),
+    ('\u{1F21}', '\u{300}', '\u{1F23}'),
+    ('\u{1F21}', '\u{301}', '\u{1F25}'),
+    ('\u{1F21}', '\u{342}', '\u{1F27}'),
+    ('\u{1F21}', '\u{345}', '\u{1F91}'),
+    ('\u{1F22}', '\u{345}', '\u{1F92}'),
+    ('\u{1F23}', '\u{345}', '\u{1F93}'),
+    ('\u{1F24}', '\u{345}', '\u{1F94}'),
+    ('\u{1F25}', '\u{345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{345}', '\u{1F96}'),
+    ('\u{1F27}', '\u{345}', '\u{1F97}'),
+    ('\u{1F28}', '\u{300}', '\u{1F2A}'),
+    ('\u{1F28}', '\u{301}', '\u{1F2C}'),
+    ('\u{1F28}', '\u{342}', '\u{1F2E}'),
+    ('\u{1F28}', '\u{345}', '\u{1F98}'),
+    ('\u{1F29}', '\u{300}', '\u{1F2B}'),
+    ('\u{1F29}', '\u{301}', '\u{1F2D}'),
+    ('\u{1F29}', '\u{342}', '\u{1F2F}'),
+    ('\u{1F29}', '\u{345}', '\u{1F99}'),
+    ('\u{1F2A}', '\u{345}', '\u{1F9A}'),
+    ('\u{1F2B}', '\u{345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{345}', '\u{1F9C}'),
+    ('\u{1F2D}', '\u{345}', '\u{1F9D}'),
+    ('\u{1F2E}', '\u{345}', '\u{1F9E}'),
+    ('\u{1F2F}', '\u{345}', '\u{1F9F}'),
+    ('\u{1F30}', '\u{300}', '\u{1F32}'),
+    ('\u{1F30}', '\u{301}', '\u{1F34}'),
+    ('\u{1F30}', '\u{342}', '\u{1F36}'),
+    ('\u{1F31}', '\u{300}', '\u{1F33}'),
+    ('\u{1F31}', '\u{301}', '\u{1F35}'),
+    ('\u{1F31}', '\u{342}', '\u{1F37}'),
+    ('\u{1F38}', '\u{300}', '\u{1F3A}'),
+    ('\u{1F38}', '\u{301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{342}', '\u{1F3E}'),
+    ('\u{1F39}', '\u{300}', '\u{1F3B}'),
+    ('\u{1F39}', '\u{301}', '\u{1F3D}'),
+    ('\u{1F39}', '\u{342}', '\u{1F3F}'),
+    ('\u{1F40}', '\u{300}', '\u{1F42}'),
+    ('\u{1F40}', '\u{301}', '\u{1F44}'),
+    ('\u{1F41}', '\u{300}', '\u{1F43}'),
+    ('\u{1F41}', '\u{301}', '\u{1F45}'),
+    ('\u{1F48}', '\u{300}', '\u{1F4A}'),
+    ('\u{1F48}', '\u{301}', '\u{1F4C}'),
+    ('\u{1F49}', '\u{300}', '\u{1F4B}'),
+    ('\u{1F49}', '\u{301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{300}', '\u{1F52}'),
+    ('\u{1F50}', '\u{301}', '\u{1F54}'),
+    ('\u{1F50}', '\u{342}', '\u{1F56}'),
+    ('\u{1F51}', '\u{300}', '\u{1F53}'),
+    ('\u{1F51}', '\u{301}', '\u{1F55}'),
+    ('\u{1F51}', '\u{342}', '\u{1F57}'),
+    ('\u{1F59}', '\u{300}', '\u{1F5B}'),
+    ('\u{1F59}', '\u{301}', '\u{1F5D}'),
+    ('\u{1F59}', '\u{342}', '\u{1F5F}'),
+    ('\u{1F60}', '\u{300}', '\u{1F62}'),
+    ('\u{1F60}', '\u{301}', '\u{1F64}'),
+    ('\u{1F60}', '\u{342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{345}', '\u{1FA0}'),
+    ('\u{1F61}', '\u{300}', '\u{1F63}'),
+    ('\u{1F61}', '\u{301}', '\u{1F65}'),
+    ('\u{1F61}', '\u{342}', '\u{1F67}'),
+    ('\u{1F61}', '\u{345}', '\u{1FA1}'),
+    ('\u{1F62}', '\u{345}', '\u{1FA2}'),
+    ('\u{1F63}', '\u{345}', '\u{1FA3}'),
+    ('\u{1F64}', '\u{345}', '\u{1FA4}'),
+    ('\u{1F65}', '\u{345}', '\u{1FA5}'),
+    ('\u{1F66}', '\u{345}', '\u{1FA6}'),
+    ('\u{1F67}', '\u{345}', '\u{1FA7}'),
+    ('\u{1F68}', '\u{300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{301}', '\u{1F6C}'),
+    ('\u{1F68}', '\u{342}', '\u{1F6E}'),
+    ('\u{1F68}', '\u{345}', '\u{1FA8}'),
+    ('\u{1F69}', '\u{300}', '\u{1F6B}'),
+    ('\u{1F69}', '\u{301}', '\u{1F6D}'),
+    ('\u{1F69}', '\u{342}', '\u{1F6F}'),
+    ('\u{1F69}', '\u{345}', '\u{1FA9}'),
+    ('\u{1F6A}', '\u{345}', '\u{1FAA}'),
+    ('\u{1F6B}', '\u{345}', '\u{1FAB}'),
+    ('\u{1F6C}', '\u{345}', '\u{1FAC}'),
+    ('\u{1F6D}', '\u{345}', '\u{1FAD}'),
+    ('\u{1F6E}', '\u{345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{345}', '\u{1FAF}'),
+    ('\u{1F70}', '\u{345}', '\u{1FB2}'),
+    ('\u{1F74}', '\u{345}', '\u{1FC2}'),
+    ('\u{1F7C}', '\u{345}', '\u{1FF2}'),
+    ('\u{1FB6}', '\u{345}', '\u{1FB7}'),
+    ('\u{1FBF}', '\u{300}', '\u{1FCD}'),
+    ('\u{1FBF}', '\u{301}', '\u{1FCE}'),
+    ('\u{1FBF}', '\u{342}', '\u{1FCF}'),
+    ('\u{1FC6}', '\u{345}', '\u{1FC7}'),
+    ('\u{1FF6}', '\u{345}', '\u{1FF7}'),
+    ('\u{1FFE}', '\u{300}', '\u{1FDD}'),
+    ('\u{1FFE}', '\u{301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{342}', '\u{1FDF}'),
+    ('\u{2190}', '\u{338}', '\u{219A}'),
+    ('\u{2192}', '\u{338}', '\u{219B}'),
+    ('\u{2194}', '\u{338}', '\u{21AE}'),
+    ('\u{21D0}', '\u{338}', '\u{21CD}'),
+    ('\u{21D2}', '\u{338}', '\u{21CF}'),
+    ('\u{21D4}', '\u{338}', '\u{21CE}'),
+    ('\u{2203}', '\u{338}', '\u{2204}'),
+    ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220B}', '\u{338}', '\u{220C}'),
+    ('\u{2223}', '\u{338}', '\u{2224}'),
+    ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223C}', '\u{338}', '\u{2241}'),
+    ('\u{2243}', '\u{338}', '\u{2244}'),
+    ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'),
+    ('\u{224D}', '\u{338}', '\u{226D}'),
+    ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'),
+    ('\u{2265}', '\u{338}', '\u{2271}'),
+    ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'),
+    ('\u{2276}', '\u{338}', '\u{2278}'),
+    ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227A}', '\u{338}', '\u{2280}'),
+    ('\u{227B}', '\u{338}', '\u{2281}'),
+    ('\u{227C}', '\u{338}', '\u{22E0}'),
+    ('\u{227D}', '\u{338}', '\u{22E1}'),
+    ('\u{2282}', '\u{338}', '\u{2284}'),
+    ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'),
+    ('\u{2287}', '\u{338}', '\u{2289}'),
+    ('\u{2291}', '\u{338}', '\u{22E2}'),
+    ('\u{2292}', '\u{338}', '\u{22E3}'),
+    ('\u{22A2}', '\u{338}', '\u{22AC}'),
+    ('\u{22A8}', '\u{338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{338}', '\u{22AE}'),
+    ('\u{22AB}', '\u{338}', '\u{22AF}'),
+    ('\u{22B2}', '\u{338}', '\u{22EA}'),
+    ('\u{22B3}', '\u{338}', '\u{22EB}'),
+    ('\u{22B4}', '\u{338}', '\u{22EC}'),
+    ('\u{22B5}', '\u{338}', '\u{22ED}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'),
+    ('\u{304B}', '\u{3099}', '\u{304C}'),
+    ('\u{304D}', '\u{3099}', '\u{304E}'),
+    ('\u{304F}', '\u{3099}', '\u{3050}'),
+    ('\u{3051}', '\u{3099}', '\u{3052}'),
+    ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'),
+    ('\u{3057}', '\u{3099}', '\u{3058}'),
+    ('\u{3059}', '\u{3099}', '\u{305A}'),
+    ('\u{305B}', '\u{3099}', '\u{305C}'),
+    ('\u{305D}', '\u{3099}', '\u{305E}'),
+    ('\u{305F}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'),
+    ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'),
+    ('\u{306F}', '\u{3099}', '\u{3070}'),
+    ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'),
+    ('\u{3072}', '\u{309A}', '\u{3074}'),
+    ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309A}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'),
+    ('\u{3078}', '\u{309A}', '\u{307A}'),
+    ('\u{307B}', '\u{3099}', '\u{307C}'),
+    ('\u{307B}', '\u{309A}', '\u{307D}'),
+    ('\u{309D}', '\u{3099}', '\u{309E}'),
+    ('\u{30A6}', '\u{3099}', '\u{30F4}'),
+    ('\u{30AB}', '\u{3099}', '\u{30AC}'),
+    ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'),
+    ('\u{30B1}', '\u{3099}', '\u{30B2}'),
+    ('\u{30B3}', '\u{3099}', '\u{30B4}'),
+    ('\u{30B5}', '\u{3099}', '\u{30B6}'),
+    ('\u{30B7}', '\u{3099}', '\u{30B8}'),
+    ('\u{30B9}', '\u{3099}', '\u{30BA}'),
+    ('\u{30BB}', '\u{3099}', '\u{30BC}'),
+    ('\u{30BD}', '\u{3099}', '\u{30BE}'),
+    ('\u{30BF}', '\u{3099}', '\u{30C0}'),
+    ('\u{30C1}', '\u{3099}', '\u{30C2}'),
+    ('\u{30C4}', '\u{3099}', '\u{30C5}'),
+    ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'),
+    ('\u{30CF}', '\u{3099}', '\u{30D0}'),
+    ('\u{30CF}', '\u{309A}', '\u{30D1}'),
+    ('\u{30D2}', '\u{3099}', '\u{30D3}'),
+    ('\u{30D2}', '\u{309A}', '\u{30D4}'),
+    ('\u{30D5}', '\u{3099}', '\u{30D6}'),
+    ('\u{30D5}', '\u{309A}', '\u{30D7}'),
+    ('\u{30D8}', '\u{3099}', '\u{30D9}'),
+    ('\u{30D8}', '\u{309A}', '\u{30DA}'),
+    ('\u{30DB}', '\u{3099}', '\u{30DC}'),
+    ('\u{30DB}', '\u{309A}', '\u{30DD}'),
+    ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'),
+    ('\u{30F1}', '\u{3099}', '\u{30F9}'),
+    ('\u{30F2}', '\u{3099}', '\u{30FA}'),
+    ('\u{30FD}', '\u{3099}', '\u{30FE}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'),
+    ('\u{1109B}', '\u{110BA}', '\u{1109C}'),
+    ('\u{110A5}', '\u{110BA}', '\u{110AB}'),
+    ('\u{11131}', '\u{11127}', '\u{1112E}'),
+    ('\u{11132}', '\u{11127}', '\u{1112F}'),
+    ('\u{11347}', '\u{1133E}', '\u{1134B}'),
+    ('\u{11347}', '\u{11357}', '\u{1134C}'),
+    ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'),
+    ('\u{114B9}', '\u{114BD}', '\u{114BE}'),
+    ('\u{115B8}', '\u{115AF}', '\u{115BA}'),
+    ('\u{115B9}', '\u{115AF}', '\u{115BB}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
+
+//* Full case folding (status C and F), the Turkic mappings (status T) are not included
+pub(super) static CASE_FOLDING: [(char, &[char]); 1530] = [
+    ('A', &['a']),
+    ('B', &['b']),
+    ('C', &['c']),
+    ('D', &['d']),
+    ('E', &['e']),
+    ('F', &['f']),
+    ('G', &['g']),
+    ('H', &['h']),
+    ('I', &['i']),
+    ('J', &['j']),
+    ('K', &['k']),
+    ('L', &['l']),
+    ('M', &['m']),
+    ('N', &['n']),
+    ('O', &['o']),
+    ('P', &['p']),
+    ('Q', &['q']),
+    ('R', &['r']),
+    ('S', &['s']),
+    ('T', &['t']),
+    ('U', &['u']),
+    ('V', &['v']),
+    ('W', &['w']),
+    ('X', &['x']),
+    ('Y', &['y']),
+    ('Z', &['z']),
+    ('\u{B5}', &['\u{3BC}']),
+    ('\u{C0}', &['\u{E0}']),
+    ('\u{C1}', &['\u{E1}']),
+    ('\u{C2}', &['\u{E2}']),
+    ('\u{C3}', &['\u{E3}']),
+    ('\u{C4}', &['\u{E4}']),
+    ('\u{C5}', &['\u{E5}']),
+    ('\u{C6}', &['\u{E6}']),
+    ('\u{C7}', &['\u{E7}']),
+    ('\u{C8}', &['\u{E8}']),
+    ('\u{C9}', &['\u{E9}']),
+    ('\u{CA}', &['\u{EA}']),
+    ('\u{CB}', &['\u{EB}']),
+    ('\u{CC}', &['\u{EC}']),
+    ('\u{CD}', &['\u{ED}']),
+    ('\u{CE}', &['\u{EE}']),
+    ('\u{CF}', &['\u{EF}']),
+    ('\u{D0}', &['\u{F0}']),
+    ('\u{D1}', &['\u{F1}']),
+    ('\u{D2}', &['\u{F2}']),
+    ('\u{D3}', &['\u{F3}']),
+    ('\u{D4}', &['\u{F4}']),
+    ('\u{D5}', &['\u{F5}']),
+    ('\u{D6}', &['\u{F6}']),
+    ('\u{D8}', &['\u{F8}']),
+    ('\u{D9}', &['\u{F9}']),
+    ('\u{DA}', &['\u{FA}']),
+    ('\u{DB}', &['\u{FB}']),
+    ('\u{DC}', &['\u{FC}']),
+    ('\u{DD}', &['\u{FD}']),
+    ('\u{DE}', &['\u{FE}']),
+    ('\u{DF}', &['s', 's']),
+    ('\u{100}', &['\u{101}']),
+    ('\u{102}', &['\u{103}']),
+    ('\u{104}', &['\u{105}']),
+    ('\u{106}', &['\u{107}']),
+    ('\u{108}', &['\u{109}']),
+    ('\u{10A}', &['\u{10B}']),
+    ('\u{10C}', &['\u{10D}']),
+    ('\u{10E}', &['\u{10F}']),
+    ('\u{110}', &['\u{111}']),
+    ('\u{112}', &['\u{113}']),
+    ('\u{114}', &['\u{115}']),
+    ('\u{116}', &['\u{117}']),
+    ('\u{118}', &['\u{119}']),
+    ('\u{11A}', &['\u{11B}']),
+    ('\u{11C}', &['\u{11D}']),
+    ('\u{11E}', &['\u{11F}']),
+    ('\u{120}', &['\u{121}']),
+    ('\u{122}', &['\u{123}']),
+    ('\u{124}', &['\u{125}']),
+    ('\u{126}', &['\u{127}']),
+    ('\u{128}', &['\u{129}']),
+    ('\u{12A}', &['\u{12B}']),
+    ('\u{12C}', &['\u{12D}']),
+    ('\u{12E}', &['\u{12F}']),
+    ('\u{130}', &['i', '\u{307}']),
+    ('\u{132}', &['\u{133}']),
+    ('\u{134}', &['\u{135}']),
+    ('\u{136}', &['\u{137}']),
+    ('\u{139}', &['\u{13A}']),
+    ('\u{13B}', &['\u{13C}']),
+    ('\u{13D}', &['\u{13E}']),
+    ('\u{13F}', &['\u{140}']),
+    ('\u{141}', &['\u{142}']),
+    ('\u{143}', &['\u{144}']),
+    ('\u{145}', &['\u{146}']),
+    ('\u{147}', &['\u{148}']),
+    ('\u{149}', &['\u{2BC}', 'n']),
+    ('\u{14A}', &['\u{14B}']),
+    ('\u{14C}', &['\u{14D}']),
+    ('\u{14E}', &['\u{14F}']),
+    ('\u{150}', &['\u{151}']),
+    ('\u{152}', &['\u{153}']),
+    ('\u{154}', &['\u{155}']),
+    ('\u{156}', &['\u{157}']),
+    ('\u{158}', &['\u{159}']),
+    ('\u{15A}', &['\u{15B}']),
+    ('\u{15C}', &['\u{15D}']),
+    ('\u{15E}', &['\u{15F}']),
+    ('\u{160}', &['\u{161}']),
+    ('\u{162}', &['\u{163}']),
+    ('\u{164}', &['\u{165}']),
+    ('\u{166}', &['\u{167}']),
+    ('\u{168}', &['\u{169}']),
+    ('\u{16A}', &['\u{16B}']),
+    ('\u{16C}', &['\u{16D}']),
+    ('\u{16E}', &['\u{16F}']),
+    ('\u{170}', &['\u{171}']),
+    ('\u{172}', &['\u{173}']),
+    ('\u{174}', &['\u{175}']),
+    ('\u{176}', &['\u{177}']),
+    ('\u{178}', &['\u{FF}']),
+    ('\u{179}', &['\u{17A}']),
+    ('\u{17B}', &['\u{17C}']),
+    ('\u{17D}', &['\u{17E}']),
+    ('\u{17F}', &['s']),
+    ('\u{181}', &['\u{253}']),
+    ('\u{182}', &['\u{183}']),
+    ('\u{184}', &['\u{185}']),
+    ('\u{186}', &['\u{254}']),
+    ('\u{187}', &['\u{188}']),
+    ('\u{189}', &['\u{256}']),
+    ('\u{18A}', &['\u{257}']),
+    ('\u{18B}', &['\u{18C}']),
+    ('\u{18E}', &['\u{1DD}']),
+    ('\u{18F}', &['\u{259}']),
+    ('\u{190}', &['\u{25B}']),
+    ('\u{191}', &['\u{192}']),
+    ('\u{193}', &['\u{260}']),
+    ('\u{194}', &['\u{263}']),
+    ('\u{196}', &['\u{269}']),
+    ('\u{197}', &['\u{268}']),
+    ('\u{198}', &['\u{199}']),
+    ('\u{19C}', &['\u{26F}']),
+    ('\u{19D}', &['\u{272}']),
+    ('\u{19F}', &['\u{275}']),
+    ('\u{1A0}', &['\u{1A1}']),
+    ('\u{1A2}', &['\u{1A3}']),
+    ('\u{1A4}', &['\u{1A5}']),
+    ('\u{1A6}', &['\u{280}']),
+    ('\u{1A7}', &['\u{1A8}']),
+    ('\u{1A9}', &['\u{283}']),
+    ('\u{1AC}', &['\u{1AD}']),
+    ('\u{1AE}', &['\u{288}']),
+    ('\u{1AF}', &['\u{1B0}']),
+    ('\u{1B1}', &['\u{28A}']),
+    ('\u{1B2}', &['\u{28B}']),
+    ('\u{1B3}', &['\u{1B4}']),
+    ('\u{1B5}', &['\u{1B6}']),
+    ('\u{1B7}', &['\u{292}']),
+    ('\u{1B8}', &['\u{1B9}']),
+    ('\u{1BC}', &['\u{1BD}']),
+    ('\u{1C4}', &['\u{1C6}']),
+    ('\u{1C5}', &['\u{1C6}']),
+    ('\u{1C7}', &['\u{1C9}']),
+    ('\u{1C8}', &['\u{1C9}']),
+    ('\u{1CA}', &['\u{1CC}']),
+    ('\u{1CB}', &['\u{1CC}']),
+    ('\u{1CD}', &['\u{1CE}']),
+    ('\u{1CF}', &['\u{1D0}']),
+    ('\u{1D1}', &['\u{1D2}']),
+    ('\u{1D3}', &['\u{1D4}']),
+    ('\u{1D5}', &['\u{1D6}']),
+    ('\u{1D7}', &['\u{1D8}']),
+    ('\u{1D9}', &['\u{1DA}']),
+    ('\u{1DB}', &['\u{1DC}']),
+    ('\u{1DE}', &['\u{1DF}']),
+    ('\u{1E0}', &['\u{1E1}']),
+    ('\u{1E2}', &['\u{1E3}']),
+    ('\u{1E4}', &['\u{1E5}']),
+    ('\u{1E6}', &['\u{1E7}']),
+    ('\u{1E8}', &['\u{1E9}']),
+    ('\u{1EA}', &['\u{1EB}']),
+    ('\u{1EC}', &['\u{1ED}']),
+    ('\u{1EE}', &['\u{1EF}']),
+    ('\u{1F0}', &['j', '\u{30C}']),
+    ('\u{1F1}', &['\u{1F3}']),
+    ('\u{1F2}', &['\u{1F3}']),
+    ('\u{1F4}', &['\u{1F5}']),
+    ('\u{1F6}', &['\u{195}']),
+    ('\u{1F7}', &['\u{1BF}']),
+    ('\u{1F8}', &['\u{1F9}']),
+    ('\u{1FA}', &['\u{1FB}']),
+    ('\u{1FC}', &['\u{1FD}']),
+    ('\u{1FE}', &['\u{1FF}']),
+    ('\u{200}', &['\u{201}']),
+    ('\u{202}', &['\u{203}']),
+    ('\u{204}', &['\u{205}']),
+    ('\u{206}', &['\u{207}']),
+    ('\u{208}', &['\u{209}']),
+    ('\u{20A}', &['\u{20B}']),
+    ('\u{20C}', &['\u{20D}']),
+    ('\u{20E}', &['\u{20F}']),
+    ('\u{210}', &['\u{211}']),
+    ('\u{212}', &['\u{213}']),
+    ('\u{214}', &['\u{215}']),
+    ('\u{216}', &['\u{217}']),
+    ('\u{218}', &['\u{219}']),
+    ('\u{21A}', &['\u{21B}']),
+    ('\u{21C}', &['\u{21D}']),
+    ('\u{21E}', &['\u{21F}']),
+    ('\u{220}', &['\u{19E}']),
+    ('\u{222}', &['\u{223}']),
+    ('\u{224}', &['\u{225}']),
+    ('\u{226}', &['\u{227}']),
+    ('\u{228}', &['\u{229}']),
+    ('\u{22A}', &['\u{22B}']),
+    ('\u{22C}', &['\u{22D}']),
+    ('\u{22E}', &['\u{22F}']),
+    ('\u{230}', &['\u{231}']),
+    ('\u{232}', &['\u{233}']),
+    ('\u{23A}', &['\u{2C65}']),
+    ('\u{23B}', &['\u{23C}']),
+    ('\u{23D}', &['\u{19A}']),
+    ('\u{23E}', &['\u{2C66}']),
+    ('\u{241}', &['\u{242}']),
+    ('\u{243}', &['\u{180}']),
+    ('\u{244}', &['\u{289}']),
+    ('\u{245}', &['\u{28C}']),
+    ('\u{246}', &['\u{247}']),
+    ('\u{248}', &['\u{249}']),
+    ('\u{24A}', &['\u{24B}']),
+    ('\u{24C}', &['\u{24D}']),
+    ('\u{24E}', &['\u{24F}']),
+    ('\u{345}', &['\u{3B9}']),
+    ('\u{370}', &['\u{371}']),
+    ('\u{372}', &['\u{373}']),
+    ('\u{376}', &['\u{377}']),
+    ('\u{37F}', &['\u{3F3}']),
+    ('\u{386}', &['\u{3AC}']),
+    ('\u{388}', &['\u{3AD}']),
+    ('\u{389}', &['\u{3AE}']),
+    ('\u{38A}', &['\u{3AF}']),
+    ('\u{38C}', &['\u{3CC}']),
+    ('\u{38E}', &['\u{3CD}']),
+    ('\u{38F}', &['\u{3CE}']),
+    ('\u{390}', &['\u{3B9}', '\u{308}', '\u{301}']),
+    ('\u{391}', &['\u{3B1}']),
+    ('\u{392}', &['\u{3B2}']),
+    ('\u{393}', &['\u{3B3}']),
+    ('\u{394}', &['\u{3B4}']),
+    ('\u{395}', &['\u{3B5}']),
+    ('\u{396}', &['\u{3B6}']),
+    ('\u{397}', &['\u{3B7}']),
+    ('\u{398}', &['\u{3B8}']),
+    ('\u{399}', &['\u{3B9}']),
+    ('\u{39A}', &['\u{3BA}']),
+    ('\u{39B}', &['\u{3BB}']),
+    ('\u{39C}', &['\u{3BC}']),
+    ('\u{39D}', &['\u{3BD}']),
+    ('\u{39E}', &['\u{3BE}']),
+    ('\u{39F}', &['\u{3BF}']),
+    ('\u{3A0}', &['\u{3C0}']),
+    ('\u{3A1}', &['\u{3C1}']),
+    ('\u{3A3}', &['\u{3C3}']),
+    ('\u{3A4}', &['\u{3C4}']),
+    ('\u{3A5}', &['\u{3C5}']),
+    ('\u{3A6}', &['\u{3C6}']),
+    ('\u{3A7}', &['\u{3C7}']),
+    ('\u{3A8}', &['\u{3C8}']),
+    ('\u{3A9}', &['\u{3C9}']),
+    ('\u{3AA}', &['\u{3CA}']),
+    ('\u{3AB}', &['\u{3CB}']),
+    ('\u{3B0}', &['\u{3C5}', '\u{308}', '\u{301}']),
+    ('\u{3C2}', &['\u{3C3}']),
+    ('\u{3CF}', &['\u{3D7}']),
+    ('\u{3D0}', &['\u{3B2}']),
+    ('\u{3D1}', &['\u{3B8}']),
+    ('\u{3D5}', &['\u{3C6}']),
+    ('\u{3D6}', &['\u{3C0}']),
+    ('\u{3D8}', &['\u{3D9}']),
+    ('\u{3DA}', &['\u{3DB}']),
+    ('\u{3DC}', &['\u{3DD}']),
+    ('\u{3DE}', &['\u{3DF}']),
+    ('\u{3E0}', &['\u{3E1}']),
+    ('\u{3E2}', &['\u{3E3}']),
+    ('\u{3E4}', &['\u{3E5}']),
+    ('\u{3E6}', &['\u{3E7}']),
+    ('\u{3E8}', &['\u{3E9}']),
+    ('\u{3EA}', &['\u{3EB}']),
+    ('\u{3EC}', &['\u{3ED}']),
+    ('\u{3EE}', &['\u{3EF}']),
+    ('\u{3F0}', &['\u{3BA}']),
+    ('\u{3F1}', &['\u{3C1}']),
+    ('\u{3F4}', &['\u{3B8}']),
+    ('\u{3F5}', &['\u{3B5}']),
+    ('\u{3F7}', &['\u{3F8}']),
+    ('\u{3F9}', &['\u{3F2}']),
+    ('\u{3FA}', &['\u{3FB}']),
+    ('\u{3FD}', &['\u{37B}']),
+    ('\u{3FE}', &['\u{37C}']),
+    ('\u{3FF}', &['\u{37D}']),
+    ('\u{400}', &['\u{450}']),
+    ('\u{401}', &['\u{451}']),
+    ('\u{402}', &['\u{452}']),
+    ('\u{403}', &['\u{453}']),
+    ('\u{404}', &['\u{454}']),
+    ('\u{405}', &['\u{455}']),
+    ('\u{406}', &['\u{456}']),
+    ('\u{407}', &['\u{457}']),
+    ('\u{408}', &['\u{458}']),
+    ('\u{409}', &['\u{459}']),
+    ('\u{40A}', &['\u{45A}']),
+    ('\u{40B}', &['\u{45B}']),
+    ('\u{40C}', &['\u{45C}']),
+    ('\u{40D}', &['\u{45D}']),
+    ('\u{40E}', &['\u{45E}']),
+    ('\u{40F}', &['\u{45F}']),
+    ('\u{410}', &['\u{430}']),
+    ('\u{411}', &['\u{431}']),
+    ('\u{412}', &['\u{432}']),
+    ('\u{413}', &['\u{433}']),
+    ('\u{414}', &['\u{434}']),
+    ('\u{415}', &['\u{435}']),
+    ('\u{416}', &['\u{436}']),
+    ('\u{417}', &['\u{437}']),
+    ('\u{418}', &['\u{438}']),
+    ('\u{419}', &['\u{439}']),
+    ('\u{41A}', &['\u{43A}']),
+    ('\u{41B}', &['\u{43B}']),
+    ('\u{41C}', &['\u{43C}']),
+    ('\u{41D}', &['\u{43D}']),
+    ('\u{41E}', &['\u{43E}']),
+    ('\u{41F}', &['\u{43F}']),
+    ('\u{420}', &['\u{440}']),
+    ('\u{421}', &['\u{441}']),
+    ('\u{422}', &['\u{442}']),
+    ('\u{423}', &['\u{443}']),
+    ('\u{424}', &['\u{444}']),
+    ('\u{425}', &['\u{445}']),
+    ('\u{426}', &['\u{446}']),
+    ('\u{427}', &['\u{447}']),
+    ('\u{428}', &['\u{448}']),
+    ('\u{429}', &['\u{449}']),
+    ('\u{42A}', &['\u{44A}']),
+    ('\u{42B}', &['\u{44B}']),
+    ('\u{42C}', &['\u{44C}']),
+    ('\u{42D}', &['\u{44D}']),
+    ('\u{42E}', &['\u{44E}']),
+    ('\u{42F}', &['\u{44F}']),
+    ('\u{460}', &['\u{461}']),
+    ('\u{462}', &['\u{463}']),
+    ('\u{464}', &['\u{465}']),
+    ('\u{466}', &['\u{467}']),
+    ('\u{468}', &['\u{469}']),
+    ('\u{46A}', &['\u{46B}']),
+    ('\u{46C}', &['\u{46D}']),
+    ('\u{46E}', &['\u{46F}']),
+    ('\u{470}', &['\u{471}']),
+    ('\u{472}', &['\u{473}']),
+    ('\u{474}', &['\u{475}']),
+    ('\u{476}', &['\u{477}']),
+    ('\u{478}', &['\u{479}']),
+    ('\u{47A}', &['\u{47B}']),
+    ('\u{47C}', &['\u{47D}']),
+    ('\u{47E}', &['\u{47F}']),
+    ('\u{480}', &['\u{481}']),
+    ('\u{48A}', &['\u{48B}']),
+    ('\u{48C}', &['\u{48D}']),
+    ('\u{48E}', &['\u{48F}']),
+    ('\u{490}', &['\u{491}']),
+    ('\u{492}', &['\u{493}']),
+    ('\u{494}', &['\u{495}']),
+    ('\u{496}', &['\u{497}']),
+    ('\u{498}', &['\u{499}']),
+    ('\u{49A}', &['\u{49B}']),
+    ('\u{49C}', &['\u{49D}']),
+    ('\u{49E}', &['\u{49F}']),
+    ('\u{4A0}', &['\u{4A1}']),
+    ('\u{4A2}', &['\u{4A3}']),
+    ('\u{4A4}', &['\u{4A5}']),
+    ('\u{4A6}', &['\u{4A7}']),
+    ('\u{4A8}', &['\u{4A9}']),
+    ('\u{4AA}', &['\u{4AB}']),
+    ('\u{4AC}', &['\u{4AD}']),
+    ('\u{4AE}', &['\u{4AF}']),
+    ('\u{4B0}', &['\u{4B1}']),
+    ('\u{4B2}', &['\u{4B3}']),
+    ('\u{4B4}', &['\u{4B5}']),
+    ('\u{4B6}', &['\u{4B7}']),
+    ('\u{4B8}', &['\u{4B9}']),
+    ('\u{4BA}', &['\u{4BB}']),
+    ('\u{4BC}', &['\u{4BD}']),
+    ('\u{4BE}', &['\u{4BF}']),
+    ('\u{4C0}', &['\u{4CF}']),
+    ('\u{4C1}', &['\u{4C2}']),
+    ('\u{4C3}', &['\u{4C4}']),
+    ('\u{4C5}', &['\u{4C6}']),
+    ('\u{4C7}', &['\u{4C8}']),
+    ('\u{4C9}', &['\u{4CA}']),
+    ('\u{4CB}', &['\u{4CC}']),
+    ('\u{4CD}', &['\u{4CE}']),
+    ('\u{4D0}', &['\u{4D1}']),
+    ('\u{4D2}', &['\u{4D3}']),
+    ('\u{4D4}', &['\u{4D5}']),
+    ('\u{4D6}', &['\u{4D7}']),
+    ('\u{4D8}', &['\u{4D9}']),
+    ('\u{4DA}', &['\u{4DB}']),
+    ('\u{4DC}', &['\u{4DD}']),
+    ('\u{4DE}', &['\u{4DF}']),
+    ('\u{4E0}', &['\u{4E1}']),
+    ('\u{4E2}', &['\u{4E3}']),
+    ('\u{4E4}', &['\u{4E5}']),
+    ('\u{4E6}', &['\u{4E7}']),
+    ('\u{4E8}', &['\u{4E9}']),
+    ('\u{4EA}', &['\u{4EB}']),
+    ('\u{4EC}', &['\u{4ED}']),
+    ('\u{4EE}', &['\u{4EF}']),
+    ('\u{4F0}', &['\u{4F1}']),
+    ('\u{4F2}', &['\u{4F3}']),
+    ('\u{4F4}', &['\u{4F5}']),
+    ('\u{4F6}', &['\u{4F7}']),
+    ('\u{4F8}', &['\u{4F9}']),
+    ('\u{4FA}', &['\u{4FB}']),
+    ('\u{4FC}', &['\u{4FD}']),
+    ('\u{4FE}', &['\u{4FF}']),
+    ('\u{500}', &['\u{501}']),
+    ('\u{502}', &['\u{503}']),
+    ('\u{504}', &['\u{505}']),
+    ('\u{506}', &['\u{507}']),
+    ('\u{508}', &['\u{509}']),
+    ('\u{50A}', &['\u{50B}']),
+    ('\u{50C}', &['\u{50D}']),
+    ('\u{50E}', &['\u{50F}']),
+    ('\u{510}', &['\u{511}']),
+    ('\u{512}', &['\u{513}']),
+    ('\u{514}', &['\u{515}']),
+    ('\u{516}', &['\u{517}']),
+    ('\u{518}', &['\u{519}']),
+    ('\u{51A}', &['\u{51B}']),
+    ('\u{51C}', &['\u{51D}']),
+    ('\u{51E}', &['\u{51F}']),
+    ('\u{520}', &['\u{521}']),
+    ('\u{522}', &['\u{523}']),
+    ('\u{524}', &['\u{525}']),
+    ('\u{526}', &['\u{527}']),
+    ('\u{528}', &['\u{529}']),
+    ('\u{52A}', &['\u{52B}']),
+    ('\u{52C}', &['\u{52D}']),
+    ('\u{52E}', &['\u{52F}']),
+    ('\u{531}', &['\u{561}']),
+    ('\u{532}', &['\u{562}']),
+    ('\u{533}', &['\u{563}']),
+    ('\u{534}', &['\u{564}']),
+    ('\u{535}', &['\u{565}']),
+    ('\u{536}', &['\u{566}']),
+    ('\u{537}', &['\u{567}']),
+    ('\u{538}', &['\u{568}']),
+    ('\u{539}', &['\u{569}']),
+    ('\u{53A}', &['\u{56A}']),
+    ('\u{53B}', &['\u{56B}']),
+    ('\u{53C}', &['\u{56C}']),
+    ('\u{53D}', &['\u{56D}']),
+    ('\u{53E}', &['\u{56E}']),
+    ('\u{53F}', &['\u{56F}']),
+    ('\u{540}', &['\u{570}']),
+    ('\u{541}', &['\u{571}']),
+    ('\u{542}', &['\u{572}']),
+    ('\u{543}', &['\u{573}']),
+    ('\u{544}', &['\u{574}']),
+    ('\u{545}', &['\u{575}']),
+    ('\u{546}', &['\u{576}']),
+    ('\u{547}', &['\u{577}']),
+    ('\u{548}', &['\u{578}']),
+    ('\u{549}', &['\u{579}']),
+    ('\u{54A}', &['\u{57A}']),
+    ('\u{54B}', &['\u{57B}']),
+    ('\u{54C}', &['\u{57C}']),
+    ('\u{54D}', &['\u{57D}']),
+    ('\u{54E}', &['\u{57E}']),
+    ('\u{54F}', &['\u{57F}']),
+    ('\u{550}', &['\u{580}']),
+    ('\u{551}', &['\u{581}']),
+    ('\u{552}', &['\u{582}']),
+    ('\u{553}', &['\u{583}']),
+    ('\u{554}', &['\u{584}']),
+    ('\u{555}', &['\u{585}']),
+    ('\u{556}', &['\u{586}']),
+    ('\u{587}', &['\u{565}', '\u{582}']),
+    ('\u{10A0}', &['\u{2D00}']),
+    ('\u{10A1}', &['\u{2D01}']),
+    ('\u{10A2}', &['\u{2D02}']),
+    ('\u{10A3}', &['\u{2D03}']),
+    ('\u{10A4}', &['\u{2D04}']),
+    ('\u{10A5}', &['\u{2D05}']),
+    ('\u{10A6}', &['\u{2D06}']),
+    ('\u{10A7}', &['\u{2D07}']),
+    ('\u{10A8}', &['\u{2D08}']),
+    ('\u{10A9}', &['\u{2D09}']),
+    ('\u{10AA}', &['\u{2D0A}']),
+    ('\u{10AB}', &['\u{2D0B}']),
+    ('\u{10AC}', &['\u{2D0C}']),
+    ('\u{10AD}', &['\u{2D0D}']),
+    ('\u{10AE}', &['\u{2D0E}']),
+    ('\u{10AF}', &['\u{2D0F}']),
+    ('\u{10B0}', &['\u{2D10}']),
+    ('\u{10B1}', &['\u{2D11}']),
+    ('\u{10B2}', &['\u{2D12}']),
+    ('\u{10B3}', &['\u{2D13}']),
+    ('\u{10B4}', &['\u{2D14}']),
+    ('\u{10B5}', &['\u{2D15}']),
+    ('\u{10B6}', &['\u{2D16}']),
+    ('\u{10B7}', &['\u{2D17}']),
+    ('\u{10B8}', &['\u{2D18}']),
+    ('\u{10B9}', &['\u{2D19}']),
+    ('\u{10BA}', &['\u{2D1A}']),
+    ('\u{10BB}', &['\u{2D1B}']),
+    ('\u{10BC}', &['\u{2D1C}']),
+    ('\u{10BD}', &['\u{2D1D}']),
+    ('\u{10BE}', &['\u{2D1E}']),
+    ('\u{10BF}', &['\u{2D1F}']),
+    ('\u{10C0}', &['\u{2D20}']),
+    ('\u{10C1}', &['\u{2D21}']),
+    ('\u{10C2}', &['\u{2D22}']),
+    ('\u{10C3}', &['\u{2D23}']),
+    ('\u{10C4}', &['\u{2D24}']),
+    ('\u{10C5}', &['\u{2D25}']),
+    ('\u{10C7}', &['\u{2D27}']),
+    ('\u{10CD}', &['\u{2D2D}']),
+    ('\u{13F8}', &['\u{13F0}']),
+    ('\u{13F9}', &['\u{13F1}']),
+    ('\u{13FA}', &['\u{13F2}']),
+    ('\u{13FB}', &['\u{13F3}']),
+    ('\u{13FC}', &['\u{13F4}']),
+    ('\u{13FD}', &['\u{13F5}']),
+    ('\u{1C80}', &['\u{432}']),
+    ('\u{1C81}', &['\u{434}']),
+    ('\u{1C82}', &['\u{43E}']),
+    ('\u{1C83}', &['\u{441}']),
+    ('\u{1C84}', &['\u{442}']),
+    ('\u{1C85}', &['\u{442}']),
+    ('\u{1C86}', &['\u{44A}']),
+    ('\u{1C87}', &['\u{463}']),
+    ('\u{1C88}', &['\u{A64B}']),
+    ('\u{1C90}', &['\u{10D0}']),
+    ('\u{1C91}', &['\u{10D1}']),
+    ('\u{1C92}', &['\u{10D2}']),
+    ('\u{1C93}', &['\u{10D3}']),
+    ('\u{1C94}', &['\u{10D4}']),
+    ('\u{1C95}', &['\u{10D5}']),
+    ('\u{1C96}', &['\u{10D6}']),
+    ('\u{1C97}', &['\u{10D7}']),
+    ('\u{1C98}', &['\u{10D8}']),
+    ('\u{1C99}', &['\u{10D9}']),
+    ('\u{1C9A}', &['\u{10DA}']),
+    ('\u{1C9B}', &['\u{10DB}']),
+    ('\u{1C9C}', &['\u{10DC}']),
+    ('\u{1C9D}', &['\u{10DD}']),
+    ('\u{1C9E}', &['\u{10DE}']),
+    ('\u{1C9F}', &['\u{10DF}']),
+    ('\u{1CA0}', &['\u{10E0}']),
+    ('\u{1CA1}', &['\u{10E1}']),
+    ('\u{1CA2}', &['\u{10E2}']),
+    ('\u{1CA3}', &['\u{10E3}']),
+    ('\u{1CA4}', &['\u{10E4}']),
+    ('\u{1CA5}', &['\u{10E5}']),
+    ('\u{1CA6}', &['\u{10E6}']),
+    ('\u{1CA7}', &['\u{10E7}']),
+    ('\u{1CA8}', &['\u{10E8}']),
+    ('\u{1CA9}', &['\u{10E9}']),
+    ('\u{1CAA}', &['\u{10EA}']),
+    ('\u{1CAB}', &['\u{10EB}']),
+    ('\u{1CAC}', &['\u{10EC}']),
+    ('\u{1CAD}', &['\u{10ED}']),
+    ('\u{1CAE}', &['\u{10EE}']),
+    ('\u{1CAF}', &['\u{10EF}']),
+    ('\u{1CB0}', &['\u{10F0}']),
+    ('\u{1CB1}', &['\u{10F1}']),
+    ('\u{1CB2}', &['\u{10F2}']),
+    ('\u{1CB3}', &['\u{10F3}']),
+    ('\u{1CB4}', &['\u{10F4}']),
+    ('\u{1CB5}', &['\u{10F5}']),
+    ('\u{1CB6}', &['\u{10F6}']),
+    ('\u{1CB7}', &['\u{10F7}']),
+    ('\u{1CB8}', &['\u{10F8}']),
+    ('\u{1CB9}', &['\u{10F9}']),
+    ('\u{1CBA}', &['\u{10FA}']),
+    ('\u{1CBD}', &['\u{10FD}']),
+    ('\u{1CBE}', &['\u{10FE}']),
+    ('\u{1CBF}', &['\u{10FF}']),
+    ('\u{1E00}', &['\u{1E01}']),
+    ('\u{1E02}', &['\u{1E03}']),
+    ('\u{1E04}', &['\u{1E05}']),
+    ('\u{1E06}', &['\u{1E07}']),
+    ('\u{1E08}', &['\u{1E09}']),
+    ('\u{1E0A}', &['\u{1E0B}']),
+    ('\u{1E0C}', &['\u{1E0D}']),
+    ('\u{1E0E}', &['\u{1E0F}']),
+    ('\u{1E10}', &['\u{1E11}']),
+    ('\u{1E12}', &['\u{1E13}']),
+    ('\u{1E14}', &['\u{1E15}']),
+    ('\u{1E16}', &['\u{1E17}']),
+    ('\u{1E18}', &['\u{1E19}']),
+    ('\u{1E1A}', &['\u{1E1B}']),
+    ('\u{1E1C}', &['\u{1E1D}']),
+    ('\u{1E1E}', &['\u{1E1F}']),
+    ('\u{1E20}', &['\u{1E21}']),
+    ('\u{1E22}', &['\u{1E23}']),
+    ('\u{1E24}', &['\u{1E25}']),
+    ('\u{1E26}', &['\u{1E27}']),
+    ('\u{1E28}', &['\u{1E29}']),
+    ('\u{1E2A}', &['\u{1E2B}']),
+    ('\u{1E2C}', &['\u{1E2D}']),
+    ('\u{1E2E}', &['\u{1E2F}']),
+    ('\u{1E30}', &['\u{1E31}']),
+    ('\u{1E32}', &['\u{1E33}']),
+    ('\u{1E34}', &['\u{1E35}']),
+    ('\u{1E36}', &['\u{1E37}']),
+    ('\u{1E38}', &['\u{1E39}']),
+    ('\u{1E3A}', &['\u{1E3B}']),
+    ('\u{1E3C}', &['\u{1E3D}']),
+    ('\u{1E3E}', &['\u{1E3F}']),
+    ('\u{1E40}', &['\u{1E41}']),
+    ('\u{1E42}', &['\u{1E43}']),
+    ('\u{1E44}', &['\u{1E45}']),
+    ('\u{1E46}', &['\u{1E47}']),
+    ('\u{1E48}', &['\u{1E49}']),
+    ('\u{1E4A}', &['\u{1E4B}']),
+    ('\u{1E4C}', &['\u{1E4D}']),
+    ('\u{1E4E}', &['\u{1E4F}']),
+    ('\u{1E50}', &['\u{1E51}']),
+    ('\u{1E52}', &['\u{1E53}']),
+    ('\u{1E54}', &['\u{1E55}']),
+    ('\u{1E56}', &['\u{1E57}']),
+    ('\u{1E58}', &['\u{1E59}']),
+    ('\u{1E5A}', &['\u{1E5B}']),
+    ('\u{1E5C}', &['\u{1E5D}']),
+    ('\u{1E5E}', &['\u{1E5F}']),
+    ('\u{1E60}', &['\u{1E61}']),
+    ('\u{1E62}', &['\u{1E63}']),
+    ('\u{1E64}', &['\u{1E65}']),
+    ('\u{1E66}', &['\u{1E67}']),
+    ('\u{1E68}', &['\u{1E69}']),
+    ('\u{1E6A}', &['\u{1E6B}']),
+    ('\u{1E6C}', &['\u{1E6D}']),
+    ('\u{1E6E}', &['\u{1E6F}']),
+    ('\u{1E70}', &['\u{1E71}']),
+    ('\u{1E72}', &['\u{1E73}']),
+    ('\u{1E74}', &['\u{1E75}']),
+    ('\u{1E76}', &['\u{1E77}']),
+    ('\u{1E78}', &['\u{1E79}']),
+    ('\u{1E7A}', &['\u{1E7B}']),
+    ('\u{1E7C}', &['\u{1E7D}']),
+    ('\u{1E7E}', &['\u{1E7F}']),
+    ('\u{1E80}', &['\u{1E81}']),
+    ('\u{1E82}', &['\u{1E83}']),
+    ('\u{1E84}', &['\u{1E85}']),
+    ('\u{1E86}', &['\u{1E87}']),
+    ('\u{1E88}', &['\u{1E89}']),
+    ('\u{1E8A}', &['\u{1E8B}']),
+    ('\u{1E8C}', &['\u{1E8D}']),
+    ('\u{1E8E}', &['\u{1E8F}']),
+    ('\u{1E90}', &['\u{1E91}']),
+    ('\u{1E92}', &['\u{1E93}']),
+    ('\u{1E94}', &['\u{1E95}']),
+    ('\u{1E96}', &['h', '\u{331}']),
+    ('\u{1E97}', &['t', '\u{308}']),
+    ('\u{1E98}', &['w', '\u{30A}']),
+    ('\u{1E99}', &['y', '\u{30A}']),
+    ('\u{1E9A}', &['a', '\u{2BE}']),
+    ('\u{1E9B}', &['\u{1E61}']),
+    ('\u{1E9E}', &['s', 's']),
+    ('\u{1EA0}', &['\u{1EA1}']),
+    ('\u{1EA2}', &['\u{1EA3}']),
+    ('\u{1EA4}', &['\u{1EA5}']),
+    ('\u{1EA6}', &['\u{1EA7}']),
+    ('\u{1EA8}', &['\u{1EA9}']),
+    ('\u{1EAA}', &['\u{1EAB}']),
+    ('\u{1EAC}', &['\u{1EAD}']),
+    ('\u{1EAE}', &['\u{1EAF}']),
+    ('\u{1EB0}', &['\u{1EB1}']),
+    ('\u{1EB2}', &['\u{1EB3}']),
+    ('\u{1EB4}', &['\u{1EB5}']),
+    ('\u{1EB6}', &['\u{1EB7}']),
+    ('\u{1EB8}', &['\u{1EB9}']),
+    ('\u{1EBA}', &['\u{1EBB}']),
+    ('\u{1EBC}', &['\u{1EBD}']),
+    ('\u{1EBE}', &['\u{1EBF}']),
+    ('\u{1EC0}', &['\u{1EC1}']),
+    ('\u{1EC2}', &['\u{1EC3}']),
+    ('\u{1EC4}', &['\u{1EC5}']),
+    ('\u{1EC6}', &['\u{1EC7}']),
+    ('\u{1EC8}', &['\u{1EC9}']),
+    ('\u{1ECA}', &['\u{1ECB}']),
+    ('\u{1ECC}', &['\u{1ECD}']),
+    ('\u{1ECE}', &['\u{1ECF}']),
+    ('\u{1ED0}', &['\u{1ED1}']),
+    ('\u{1ED2}', &['\u{1ED3}']),
+    ('\u{1ED4}', &['\u{1ED5}']),
+    ('\u{1ED6}', &['\u{1ED7}']),
+    ('\u{1ED8}', &['\u{1ED9}']),
+    ('\u{1EDA}', &['\u{1EDB}']),
+    ('\u{1EDC}', &['\u{1EDD}']),
+    ('\u{1EDE}', &['\u{1EDF}']),
+    ('\u{1EE0}', &['\u{1EE1}']),
+    ('\u{1EE2}', &['\u{1EE3}']),
+    ('\u{1EE4}', &['\u{1EE5}']),
+    ('\u{1EE6}', &['\u{1EE7}']),
+    ('\u{1EE8}', &['\u{1EE9}']),
+    ('\u{1EEA}', &['\u{1EEB}']),
+    ('\u{1EEC}', &['\u{1EED}']),
+    ('\u{1EEE}', &['\u{1EEF}']),
+    ('\u{1EF0}', &['\u{1EF1}']),
+    ('\u{1EF2}', &['\u{1EF3}']),
+    ('\u{1EF4}', &['\u{1EF5}']),
+    ('\u{1EF6}', &['\u{1EF7}']),
+    ('\u{1EF8}', &['\u{1EF9}']),
+    ('\u{1EFA}', &['\u{1EFB}']),
+    ('\u{1EFC}', &['\u{1EFD}']),
+    ('\u{1EFE}', &['\u{1EFF}']),
+    ('\u{1F08}', &['\u{1F00}']),
+    ('\u{1F09}', &['\u{1F01}']),
+    ('\u{1F0A}', &['\u{1F02}']),
+    ('\u{1F0B}', &['\u{1F03}']),
+    ('\u{1F0C}', &['\u{1F04}']),
+    ('\u{1F0D}', &['\u{1F05}']),
+    ('\u{1F0E}', &['\u{1F06}']),
+    ('\u{1F0F}', &['\u{1F07}']),
+    ('\u{1F18}', &['\u{1F10}']),
+    ('\u{1F19}', &['\u{1F11}']),
+    ('\u{1F1A}', &['\u{1F12}']),
+    ('\u{1F1B}', &['\u{1F13}']),
+    ('\u{1F1C}', &['\u{1F14}']),
+    ('\u{1F1D}', &['\u{1F15}']),
+    ('\u{1F28}', &['\u{1F20}']),
+    ('\u{1F29}', &['\u{1F21}']),
+    ('\u{1F2A}', &['\u{1F22}']),
+    ('\u{1F2B}', &['\u{1F23}']),
+    ('\u{1F2C}', &['\u{1F24}']),
+    ('\u{1F2D}', &['\u{1F25}']),
+    ('\u{1F2E}', &['\u{1F26}']),
+    ('\u{1F2F}', &['\u{1F27}']),
+    ('\u{1F38}', &['\u{1F30}']),
+    ('\u{1F39}', &['\u{1F31}']),
+    ('\u{1F3A}', &['\u{1F32}']),
+    ('\u{1F3B}', &['\u{1F33}']),
+    ('\u{1F3C}', &['\u{1F34}']),
+    ('\u{1F3D}', &['\u{1F35}']),
+    ('\u{1F3E}', &['\u{1F36}']),
+    ('\u{1F3F}', &['\u{1F37}']),
+    ('\u{1F48}', &['\u{1F40}']),
+    ('\u{1F49}', &['\u{1F41}']),
+    ('\u{1F4A}', &['\u{1F42}']),
+    ('\u{1F4B}', &['\u{1F43}']),
+    ('\u{1F4C}', &['\u{1F44}']),
+    ('\u{1F4D}', &['\u{1F45}']),
+    ('\u{1F50}', &['\u{3C5}', '\u{313}']),
+    ('\u{1F52}', &['\u{3C5}', '\u{313}', '\u{300}']),
+    ('\u{1F54}', &['\u{3C5}', '\u{313}', '\u{301}']),
+    ('\u{1F56}', &['\u{3C5}', '\u{313}', '\u{342}']),
+    ('\u{1F59}', &['\u{1F51}']),
+    ('\u{1F5B}', &['\u{1F53}']),
+    ('\u{1F5D}', &['\u{1F55}']),
+    ('\u{1F5F}', &['\u{1F57}']),
+    ('\u{1F68}', &['\u{1F60}']),
+    ('\u{1F69}', &['\u{1F61}']),
+    ('\u{1F6A}', &['\u{1F62}']),
+    ('\u{1F6B}', &['\u{1F63}']),
+    ('\u{1F6C}', &['\u{1F64}']),
+    ('\u{1F6D}', &['\u{1F65}']),
+    ('\u{1F6E}', &['\u{1F66}']),
+    ('\u{1F6F}', &['\u{1F67}']),
+    ('\u{1F80}', &['\u{1F00}', '\u{3B9}']),
+    ('\u{1F81}', &['\u{1F01}', '\u{3B9}']),
+    ('\u{1F82}', &['\u{1F02}', '\u{3B9}']),
+    ('\u{1F83}', &['\u{1F03}', '\u{3B9}']),
+    ('\u{1F84}', &['\u{1F04}', '\u{3B9}']),
+    ('\u{1F85}', &['\u{1F05}', '\u{3B9}']),
+    ('\u{1F86}', &['\u{1F06}', '\u{3B9}']),
+    ('\u{1F87}', &['\u{1F07}', '\u{3B9}']),
+    ('\u{1F88}', &['\u{1F00}', '\u{3B9}']),
+    ('\u{1F89}', &['\u{1F01}', '\u{3B9}']),
+    ('\u{1F8A}', &['\u{1F02}', '\u{3B9}']),
+    ('\u{1F8B}', &['\u{1F03}', '\u{3B9}']),
+    ('\u{1F8C}', &['\u{1F04}', '\u{3B9}']),
+    ('\u{1F8D}', &['\u{1F05}', '\u{3B9}']),
+    ('\u{1F8E}', &['\u{1F06}', '\u{3B9}']),
+    ('\u{1F8F}', &['\u{1F07}', '\u{3B9}']),
+    ('\u{1F90}', &['\u{1F20}', '\u{3B9}']),
+    ('\u{1F91}', &['\u{1F21}', '\u{3B9}']),
+    ('\u{1F92}', &['\u{1F22}', '\u{3B9}']),
+    ('\u{1F93}', &['\u{1F23}', '\u{3B9}']),
+    ('\u{1F94}', &['\u{1F24}', '\u{3B9}']),
+    ('\u{1F95}', &['\u{1F25}', '\u{3B9}']),
+    ('\u{1F96}', &['\u{1F26}', '\u{3B9}']),
+    ('\u{1F97}', &['\u{1F27}', '\u{3B9}']),
+    ('\u{1F98}', &['\u{1F20}', '\u{3B9}']),
+    ('\u{1F99}', &['\u{1F21}', '\u{3B9}']),
+    ('\u{1F9A}', &['\u{1F22}', '\u{3B9}']),
+    ('\u{1F9B}', &['\u{1F23}', '\u{3B9}']),
+    ('\u{1F9C}', &['\u{1F24}', '\u{3B9}']),
+    ('\u{1F9D}', &['\u{1F25}', '\u{3B9}']),
+    ('\u{1F9E}', &['\u{1F26}', '\u{3B9}']),
+    ('\u{1F9F}', &['\u{1F27}', '\u{3B9}']),
+    ('\u{1FA0}', &['\u{1F60}', '\u{3B9}']),
+    ('\u{1FA1}', &['\u{1F61}', '\u{3B9}']),
+    ('\u{1FA2}', &['\u{1F62}', '\u{3B9}']),
+    ('\u{1FA3}', &['\u{1F63}', '\u{3B9}']),
+    ('\u{1FA4}', &['\u{1F64}', '\u{3B9}']),
+    ('\u{1FA5}', &['\u{1F65}', '\u{3B9}']),
+    ('\u{1FA6}', &['\u{1F66}', '\u{3B9}']),
+    ('\u{1FA7}', &['\u{1F67}', '\u{3B9}']),
+    ('\u{1FA8}', &['\u{1F60}', '\u{3B9}']),
+    ('\u{1FA9}', &['\u{1F61}', '\u{3B9}']),
+    ('\u{1FAA}', &['\u{1F62}', '\u{3B9}']),
+    ('\u{1FAB}', &['\u{1F63}', '\u{3B9}']),
+    ('\u{1FAC}', &['\u{1F64}', '\u{3B9}']),
+    ('\u{1FAD}', &['\u{1F65}', '\u{3B9}']),
+    ('\u{1FAE}', &['\u{1F66}', '\u{3B9}']),
+    ('\u{1FAF}', &['\u{1F67}', '\u{3B9}']),
+    ('\u{1FB2}', &['\u{1F70}', '\u{3B9}']),
+    ('\u{1FB3}', &['\u{3B1}', '\u{3B9}']),
+    ('\u{1FB4}', &['\u{3AC}', '\u{3B9}']),
+    ('\u{1FB6}', &['\u{3B1}', '\u{342}']),
+    ('\u{1FB7}', &['\u{3B1}', '\u{342}', '\u{3B9}']),
+    ('\u{1FB8}', &['\u{1FB0}']),
+    ('\u{1FB9}', &['\u{1FB1}']),
+    ('\u{1FBA}', &['\u{1F70}']),
+    ('\u{1FBB}', &['\u{1F71}']),
+    ('\u{1FBC}', &['\u{3B1}', '\u{3B9}']),
+    ('\u{1FBE}', &['\u{3B9}']),
+    ('\u{1FC2}', &['\u{1F74}', '\u{3B9}']),
+    ('\u{1FC3}', &['\u{3B7}', '\u{3B9}']),
+    ('\u{1FC4}', &['\u{3AE}', '\u{3B9}']),
+    ('\u{1FC6}', &['\u{3B7}', '\u{342}']),
+    ('\u{1FC7}', &['\u{3B7}', '\u{342}', '\u{3B9}']),
+    ('\u{1FC8}', &['\u{1F72}']),
+    ('\u{1FC9}', &['\u{1F73}']),
+    ('\u{1FCA}', &['\u{1F74}']),
+    ('\u{1FCB}', &['\u{1F75}']),
+    ('\u{1FCC}', &['\u{3B7}', '\u{3B9}']),
+    ('\u{1FD2}', &['\u{3B9}', '\u{308}', '\u{300}']),
+    ('\u{1FD3}', &['\u{3B9}', '\u{308}', '\u{301}']),
+    ('\u{1FD6}', &['\u{3B9}', '\u{342}']),
+    ('\u{1FD7}', &['\u{3B9}', '\u{308}', '\u{342}']),
+    ('\u{1FD8}', &['\u{1FD0}']),
+    ('\u{1FD9}', &['\u{1FD1}']),
+    ('\u{1FDA}', &['\u{1F76}']),
+    ('\u{1FDB}', &['\u{1F77}']),
+    ('\u{1FE2}', &['\u{3C5}', '\u{308}', '\u{300}']),
+    ('\u{1FE3}', &['\u{3C5}', '\u{308}', '\u{301}']),
+    ('\u{1FE4}', &['\u{3C1}', '\u{313}']),
+    ('\u{1FE6}', &['\u{3C5}', '\u{342}']),
+    ('\u{1FE7}', &['\u{3C5}', '\u{308}', '\u{342}']),
+    ('\u{1FE8}', &['\u{1FE0}']),
+    ('\u{1FE9}', &['\u{1FE1}']),
+    ('\u{1FEA}', &['\u{1F7A}']),
+    ('\u{1FEB}', &['\u{1F7B}']),
+    ('\u{1FEC}', &['\u{1FE5}']),
+    ('\u{1FF2}', &['\u{1F7C}', '\u{3B9}']),
+    ('\u{1FF3}', &['\u{3C9}', '\u{3B9}']),
+    ('\u{1FF4}', &['\u{3CE}', '\u{3B9}']),
+    ('\u{1FF6}', &['\u{3C9}', '\u{342}']),
+    ('\u{1FF7}', &['\u{3C9}', '\u{342}', '\u{3B9}']),
+    ('\u{1FF8}', &['\u{1F78}']),
+    ('\u{1FF9}', &['\u{1F79}']),
+    ('\u{1FFA}', &['\u{1F7C}']),
+    ('\u{1FFB}', &['\u{1F7D}']),
+    ('\u{1FFC}', &['\u{3C9}', '\u{3B9}']),
+    ('\u{2126}', &['\u{3C9}']),
+    ('\u{212A}', &['k']),
+    ('\u{212B}', &['\u{E5}']),
+    ('\u{2132}', &['\u{214E}']),
+    ('\u{2160}', &['\u{2170}']),
+    ('\u{2161}', &['\u{2171}']),
+    ('\u{2162}', &['\u{2172}']),
+    ('\u{2163}', &['\u{2173}']),
+    ('\u{2164}', &['\u{2174}']),
+    ('\u{2165}', &['\u{2175}']),
+    ('\u{2166}', &['\u{2176}']),
+    ('\u{2167}', &['\u{2177}']),
+    ('\u{2168}', &['\u{2178}']),
+    ('\u{2169}', &['\u{2179}']),
+    ('\u{216A}', &['\u{217A}']),
+    ('\u{216B}', &['\u{217B}']),
+    ('\u{216C}', &['\u{217C}']),
+    ('\u{216D}', &['\u{217D}']),
+    ('\u{216E}', &['\u{217E}']),
+    ('\u{216F}', &['\u{217F}']),
+    ('\u{2183}', &['\u{2184}']),
+    ('\u{24B6}', &['\u{24D0}']),
+    ('\u{24B7}', &['\u{24D1}']),
+    ('\u{24B8}', &['\u{24D2}']),
+    ('\u{24B9}', &['\u{24D3}']),
+    ('\u{24BA}', &['\u{24D4}']),
+    ('\u{24BB}', &['\u{24D5}']),
+    ('\u{24BC}', &['\u{24D6}']),
+    ('\u{24BD}', &['\u{24D7}']),
+    ('\u{24BE}', &['\u{24D8}']),
+    ('\u{24BF}', &['\u{24D9}']),
+    ('\u{24C0}', &['\u{24DA}']),
+    ('\u{24C1}', &['\u{24DB}']),
+    ('\u{24C2}', &['\u{24DC}']),
+    ('\u{24C3}', &['\u{24DD}']),
+    ('\u{24C4}', &['\u{24DE}']),
+    ('\u{24C5}', &['\u{24DF}']),
+    ('\u{24C6}', &['\u{24E0}']),
+    ('\u{24C7}', &['\u{24E1}']),
+    ('\u{24C8}', &['\u{24E2}']),
+    ('\u{24C9}', &['\u{24E3}']),
+    ('\u{24CA}', &['\u{24E4}']),
+    ('\u{24CB}', &['\u{24E5}']),
+    ('\u{24CC}', &['\u{24E6}']),
+    ('\u{24CD}', &['\u{24E7}']),
+    ('\u{24CE}', &['\u{24E8}']),
+    ('\u{24CF}', &['\u{24E9}']),
+    ('\u{2C00}', &['\u{2C30}']),
+    ('\u{2C01}', &['\u{2C31}']),
+    ('\u{2C02}', &['\u{2C32}']),
+    ('\u{2C03}', &['\u{2C33}']),
+    ('\u{2C04}', &['\u{2C34}']),
+    ('\u{2C05}', &['\u{2C35}']),
+    ('\u{2C06}', &['\u{2C36}']),
+    ('\u{2C07}', &['\u{2C37}']),
+    ('\u{2C08}', &['\u{2C38}']),
+    ('\u{2C09}', &['\u{2C39}']),
+    ('\u{2C0A}', &['\u{2C3A}']),
+    ('\u{2C0B}', &['\u{2C3B}']),
+    ('\u{2C0C}', &['\u{2C3C}']),
+    ('\u{2C0D}', &['\u{2C3D}']),
+    ('\u{2C0E}', &['\u{2C3E}']),
+    ('\u{2C0F}', &['\u{2C3F}']),
+    ('\u{2C10}', &['\u{2C40}']),
+    ('\u{2C11}', &['\u{2C41}']),
+    ('\u{2C12}', &['\u{2C42}']),
+    ('\u{2C13}', &['\u{2C43}']),
+    ('\u{2C14}', &['\u{2C44}']),
+    ('\u{2C15}', &['\u{2C45}']),
+    ('\u{2C16}', &['\u{2C46}']),
+    ('\u{2C17}', &['\u{2C47}']),
+    ('\u{2C18}', &['\u{2C48}']),
+    ('\u{2C19}', &['\u{2C49}']),
+    ('\u{2C1A}', &['\u{2C4A}']),
+    ('\u{2C1B}', &['\u{2C4B}']),
+    ('\u{2C1C}', &['\u{2C4C}']),
+    ('\u{2C1D}', &['\u{2C4D}']),
+    ('\u{2C1E}', &['\u{2C4E}']),
+    ('\u{2C1F}', &['\u{2C4F}']),
+    ('\u{2C20}', &['\u{2C50}']),
+    ('\u{2C21}', &['\u{2C51}']),
+    ('\u{2C22}', &['\u{2C52}']),
+    ('\u{2C23}', &['\u{2C53}']),
+    ('\u{2C24}', &['\u{2C54}']),
+    ('\u{2C25}', &['\u{2C55}']),
+    ('\u{2C26}', &['\u{2C56}']),
+    ('\u{2C27}', &['\u{2C57}']),
+    ('\u{2C28}', &['\u{2C58}']),
+    ('\u{2C29}', &['\u{2C59}']),
+    ('\u{2C2A}', &['\u{2C5A}']),
+    ('\u{2C2B}', &['\u{2C5B}']),
+    ('\u{2C2C}', &['\u{2C5C}']),
+    ('\u{2C2D}', &['\u{2C5D}']),
+    ('\u{2C2E}', &['\u{2C5E}']),
+    ('\u{2C2F}', &['\u{2C5F}']),
+    ('\u{2C60}', &['\u{2C61}']),
+    ('\u{2C62}', &['\u{26B}']),
+    ('\u{2C63}', &['\u{1D7D}']),
+    ('\u{2C64}', &['\u{27D}']),
+    ('\u{2C67}', &['\u{2C68}']),
+    ('\u{2C69}', &['\u{2C6A}']),
+    ('\u{2C6B}', &['\u{2C6C}']),
+    ('\u{2C6D}', &['\u{251}']),
+    ('\u{2C6E}', &['\u{271}']),
+    ('\u{2C6F}', &['\u{250}']),
+    ('\u{2C70}', &['\u{252}']),
+    ('\u{2C72}', &['\u{2C73}']),
+    ('\u{2C75}', &['\u{2C76}']),
+    ('\u{2C7E}', &['\u{23F}']),
+    ('\u{2C7F}', &['\u{240}']),
+    ('\u{2C80}', &['\u{2C81}']),
+    ('\u{2C82}', &['\u{2C83}']),
+    ('\u{2C84}', &['\u{2C85}']),
+    ('\u{2C86}', &['\u{2C87}']),
+    ('\u{2C88}', &['\u{2C89}']),
+    ('\u{2C8A}', &['\u{2C8B}']),
+    ('\u{2C8C}', &['\u{2C8D}']),
+    ('\u{2C8E}', &['\u{2C8F}']),
+    ('\u{2C90}', &['\u{2C91}']),
+    ('\u{2C92}', &['\u{2C93}']),
+    ('\u{2C94}', &['\u{2C95}']),
+    ('\u{2C96}', &['\u{2C97}']),
+    ('\u{2C98}', &['\u{2C99}']),
+    ('\u{2C9A}', &['\u{2C9B}']),
+    ('\u{2C9C}', &['\u{2C9D}']),
+    ('\u{2C9E}', &['\u{2C9F}']),
+    ('\u{2CA0}', &['\u{2CA1}']),
+    ('\u{2CA2}', &['\u{2CA3}']),
+    ('\u{2CA4}', &['\u{2CA5}']),
+    ('\u{2CA6}', &['\u{2CA7}']),
+    ('\u{2CA8}', &['\u{2CA9}']),
+    ('\u{2CAA}', &['\u{2CAB}']),
+    ('\u{2CAC}', &['\u{2CAD}']),
+    ('\u{2CAE}', &['\u{2CAF}']),
+    ('\u{2CB0}', &['\u{2CB1}']),
+    ('\u{2CB2}', &['\u{2CB3}']),
+    ('\u{2CB4}', &['\u{2CB5}']),
+    ('\u{2CB6}', &['\u{2CB7}']),
+    ('\u{2CB8}', &['\u{2CB9}']),
+    ('\u{2CBA}', &['\u{2CBB}']),
+    ('\u{2CBC}', &['\u{2CBD}']),
+    ('\u{2CBE}', &['\u{2CBF}']),
+    ('\u{2CC0}', &['\u{2CC1}']),
+    ('\u{2CC2}', &['\u{2CC3}']),
+    ('\u{2CC4}', &['\u{2CC5}']),
+    ('\u{2CC6}', &['\u{2CC7}']),
+    ('\u{2CC8}', &['\u{2CC9}']),
+    ('\u{2CCA}', &['\u{2CCB}']),
+    ('\u{2CCC}', &['\u{2CCD}']),
+    ('\u{2CCE}', &['\u{2CCF}']),
+    ('\u{2CD0}', &['\u{2CD1}']),
+    ('\u{2CD2}', &['\u{2CD3}']),
+    ('\u{2CD4}', &['\u{2CD5}']),
+    ('\u{2CD6}', &['\u{2CD7}']),
+    ('\u{2CD8}', &['\u{2CD9}']),
+    ('\u{2CDA}', &['\u{2CDB}']),
+    ('\u{2CDC}', &['\u{2CDD}']),
+    ('\u{2CDE}', &['\u{2CDF}']),
+    ('\u{2CE0}', &['\u{2CE1}']),
+    ('\u{2CE2}', &['\u{2CE3}']),
+    ('\u{2CEB}', &['\u{2CEC}']),
+    ('\u{2CED}', &['\u{2CEE}']),
+    ('\u{2CF2}', &['\u{2CF3}']),
+    ('\u{A640}', &['\u{A641}']),
+    ('\u{A642}', &['\u{A643}']),
+    ('\u{A644}', &['\u{A645}']),
+    ('\u{A646}', &['\u{A647}']),
+    ('\u{A648}', &['\u{A649}']),
+    ('\u{A64A}', &['\u{A64B}']),
+    ('\u{A64C}', &['\u{A64D}']),
+    ('\u{A64E}', &['\u{A64F}']),
+    ('\u{A650}', &['\u{A651}']),
+    ('\u{A652}', &['\u{A653}']),
+    ('\u{A654}', &['\u{A655}']),
+    ('\u{A656}', &['\u{A657}']),
+    ('\u{A658}', &['\u{A659}']),
+    ('\u{A65A}', &['\u{A65B}']),
+    ('\u{A65C}', &['\u{A65D}']),
+    ('\u{A65E}', &['\u{A65F}']),
+    ('\u{A660}', &['\u{A661}']),
+    ('\u{A662}', &['\u{A663}']),
+    ('\u{A664}', &['\u{A665}']),
+    ('\u{A666}', &['\u{A667}']),
+    ('\u{A668}', &['\u{A669}']),
+    ('\u{A66A}', &['\u{A66B}']),
+    ('\u{A66C}', &['\u{A66D}']),
+    ('\u{A680}', &['\u{A681}']),
+    ('\u{A682}', &['\u{A683}']),
+    ('\u{A684}', &['\u{A685}']),
+    ('\u{A686}', &['\u{A687}']),
+    ('\u{A688}', &['\u{A689}']),
+    ('\u{A68A}', &['\u{A68B}']),
+    ('\u{A68C}', &['\u{A68D}']),
+    ('\u{A68E}', &['\u{A68F}']),
+    ('\u{A690}', &['\u{A691}']),
+    ('\u{A692}', &['\u{A693}']),
+    ('\u{A694}', &['\u{A695}']),
+    ('\u{A696}', &['\u{A697}']),
+    ('\u{A698}', &['\u{A699}']),
+    ('\u{A69A}', &['\u{A69B}']),
+    ('\u{A722}', &['\u{A723}']),
+    ('\u{A724}', &['\u{A725}']),
+    ('\u{A726}', &['\u{A727}']),
+    ('\u{A728}', &['\u{A729}']),
+    ('\u{A72A}', &['\u{A72B}']),
+    ('\u{A72C}', &['\u{A72D}']),
+    ('\u{A72E}', &['\u{A72F}']),
+    ('\u{A732}', &['\u{A733}']),
+    ('\u{A734}', &['\u{A735}']),
+    ('\u{A736}', &['\u{A737}']),
+    ('\u{A738}', &['\u{A739}']),
+    ('\u{A73A}', &['\u{A73B}']),
+    ('\u{A73C}', &['\u{A73D}']),
+    ('\u{A73E}', &['\u{A73F}']),
+    ('\u{A740}', &['\u{A741}']),
+    ('\u{A742}', &['\u{A743}']),
+    ('\u{A744}', &['\u{A745}']),
+    ('\u{A746}', &['\u{A747}']),
+    ('\u{A748}', &['\u{A749}']),
+    ('\u{A74A}', &['\u{A74B}']),
+    ('\u{A74C}', &['\u{A74D}']),
+    ('\u{A74E}', &['\u{A74F}']),
+    ('\u{A750}', &['\u{A751}']),
+    ('\u{A752}', &['\u{A753}']),
+    ('\u{A754}', &['\u{A755}']),
+    ('\u{A756}', &['\u{A757}']),
+    ('\u{A758}', &['\u{A759}']),
+    ('\u{A75A}', &['\u{A75B}']),
+    ('\u{A75C}', &['\u{A75D}']),
+    ('\u{A75E}', &['\u{A75F}']),
+    ('\u{A760}', &['\u{A761}']),
+    ('\u{A762}', &['\u{A763}']),
+    ('\u{A764}', &['\u{A765}']),
+    ('\u{A766}', &['\u{A767}']),
+    ('\u{A768}', &['\u{A769}']),
+    ('\u{A76A}', &['\u{A76B}']),
+    ('\u{A76C}', &['\u{A76D}']),
+    ('\u{A76E}', &['\u{A76F}']),
+    ('\u{A779}', &['\u{A77A}']),
+    ('\u{A77B}', &['\u{A77C}']),
+    ('\u{A77D}', &['\u{1D79}']),
+    ('\u{A77E}', &['\u{A77F}']),
+    ('\u{A780}', &['\u{A781}']),
+    ('\u{A782}', &['\u{A783}']),
+    ('\u{A784}', &['\u{A785}']),
+    ('\u{A786}', &['\u{A787}']),
+    ('\u{A78B}', &['\u{A78C}']),
+    ('\u{A78D}', &['\u{265}']),
+    ('\u{A790}', &['\u{A791}']),
+    ('\u{A792}', &['\u{A793}']),
+    ('\u{A796}', &['\u{A797}']),
+    ('\u{A798}', &['\u{A799}']),
+    ('\u{A79A}', &['\u{A79B}']),
+    ('\u{A79C}', &['\u{A79D}']),
+    ('\u{A79E}', &['\u{A79F}']),
+    ('\u{A7A0}', &['\u{A7A1}']),
+    ('\u{A7A2}', &['\u{A7A3}']),
+    ('\u{A7A4}', &['\u{A7A5}']),
+    ('\u{A7A6}', &['\u{A7A7}']),
+    ('\u{A7A8}', &['\u{A7A9}']),
+    ('\u{A7AA}', &['\u{266}']),
+    ('\u{A7AB}', &['\u{25C}']),
+    ('\u{A7AC}', &['\u{261}']),
+    ('\u{A7AD}', &['\u{26C}']),
+    ('\u{A7AE}', &['\u{26A}']),
+    ('\u{A7B0}', &['\u{29E}']),
+    ('\u{A7B1}', &['\u{287}']),
+    ('\u{A7B2}', &['\u{29D}']),
+    ('\u{A7B3}', &['\u{AB53}']),
+    ('\u{A7B4}', &['\u{A7B5}']),
+    ('\u{A7B6}', &['\u{A7B7}']),
+    ('\u{A7B8}', &['\u{A7B9}']),
+    ('\u{A7BA}', &['\u{A7BB}']),
+    ('\u{A7BC}', &['\u{A7BD}']),
+    ('\u{A7BE}', &['\u{A7BF}']),
+    ('\u{A7C0}', &['\u{A7C1}']),
+    ('\u{A7C2}', &['\u{A7C3}']),
+    ('\u{A7C4}', &['\u{A794}']),
+    ('\u{A7C5}', &['\u{282}']),
+    ('\u{A7C6}', &['\u{1D8E}']),
+    ('\u{A7C7}', &['\u{A7C8}']),
+    ('\u{A7C9}', &['\u{A7CA}']),
+    ('\u{A7D0}', &['\u{A7D1}']),
+    ('\u{A7D6}', &['\u{A7D7}']),
+    ('\u{A7D8}', &['\u{A7D9}']),
+    ('\u{A7F5}', &['\u{A7F6}']),
+    ('\u{AB70}', &['\u{13A0}']),
+    ('\u{AB71}', &['\u{13A1}']),
+    ('\u{AB72}', &['\u{13A2}']),
+    ('\u{AB73}', &['\u{13A3}']),
+    ('\u{AB74}', &['\u{13A4}']),
+    ('\u{AB75}', &['\u{13A5}']),
+    ('\u{AB76}', &['\u{13A6}']),
+    ('\u{AB77}', &['\u{13A7}']),
+    ('\u{AB78}', &['\u{13A8}']),
+    ('\u{AB79}', &['\u{13A9}']),
+    ('\u{AB7A}', &['\u{13AA}']),
+    ('\u{AB7B}', &['\u{13AB}']),
+    ('\u{AB7C}', &['\u{13AC}']),
+    ('\u{AB7D}', &['\u{13AD}']),
+    ('\u{AB7E}', &['\u{13AE}']),
+    ('\u{AB7F}', &['\u{13AF}']),
+    ('\u{AB80}', &['\u{13B0}']),
+    ('\u{AB81}', &['\u{13B1}']),
+    ('\u{AB82}', &['\u{13B2}']),
+    ('\u{AB83}', &['\u{13B3}']),
+    ('\u{AB84}', &['\u{13B4}']),
+    ('\u{AB85}', &['\u{13B5}']),
+    ('\u{AB86}', &['\u{13B6}']),
+    ('\u{AB87}', &['\u{13B7}']),
+    ('\u{AB88}', &['\u{13B8}']),
+    ('\u{AB89}', &['\u{13B9}']),
+    ('\u{AB8A}', &['\u{13BA}']),
+    ('\u{AB8B}', &['\u{13BB}']),
+    ('\u{AB8C}', &['\u{13BC}']),
+    ('\u{AB8D}', &['\u{13BD}']),
+    ('\u{AB8E}', &['\u{13BE}']),
+    ('\u{AB8F}', &['\u{13BF}']),
+    ('\u{AB90}', &['\u{13C0}']),
+    ('\u{AB91}', &['\u{13C1}']),
+    ('\u{AB92}', &['\u{13C2}']),
+    ('\u{AB93}', &['\u{13C3}']),
+    ('\u{AB94}', &['\u{13C4}']),
+    ('\u{AB95}', &['\u{13C5}']),
+    ('\u{AB96}', &['\u{13C6}']),
+    ('\u{AB97}', &['\u{13C7}']),
+    ('\u{AB98}', &['\u{13C8}']),
+    ('\u{AB99}', &['\u{13C9}']),
+    ('\u{AB9A}', &['\u{13CA}']),
+    ('\u{AB9B}', &['\u{13CB}']),
+    ('\u{AB9C}', &['\u{13CC}']),
+    ('\u{AB9D}', &['\u{13CD}']),
+    ('\u{AB9E}', &['\u{13CE}']),
+    ('\u{AB9F}', &['\u{13CF}']),
+    ('\u{ABA0}', &['\u{13D0}']),
+    ('\u{ABA1}', &['\u{13D1}']),
+    ('\u{ABA2}', &['\u{13D2}']),
+    ('\u{ABA3}', &['\u{13D3}']),
+    ('\u{ABA4}', &['\u{13D4}']),
+    ('\u{ABA5}', &['\u{13D5}']),
+    ('\u{ABA6}', &['\u{13D6}']),
+    ('\u{ABA7}', &['\u{13D7}']),
+    ('\u{ABA8}', &['\u{13D8}']),
+    ('\u{ABA9}', &['\u{13D9}']),
+    ('\u{ABAA}', &['\u{13DA}']),
+    ('\u{ABAB}', &['\u{13DB}']),
+    ('\u{ABAC}', &['\u{13DC}']),
+    ('\u{ABAD}', &['\u{13DD}']),
+    ('\u{ABAE}', &['\u{13DE}']),
+    ('\u{ABAF}', &['\u{13DF}']),
+    ('\u{ABB0}', &['\u{13E0}']),
+    ('\u{ABB1}', &['\u{13E1}']),
+    ('\u{ABB2}', &['\u{13E2}']),
+    ('\u{ABB3}', &['\u{13E3}']),
+    ('\u{ABB4}', &['\u{13E4}']),
+    ('\u{ABB5}', &['\u{13E5}']),
+    ('\u{ABB6}', &['\u{13E6}']),
+    ('\u{ABB7}', &['\u{13E7}']),
+    ('\u{ABB8}', &['\u{13E8}']),
+    ('\u{ABB9}', &['\u{13E9}']),
+    ('\u{ABBA}', &['\u{13EA}']),
+    ('\u{ABBB}', &['\u{13EB}']),
+    ('\u{ABBC}', &['\u{13EC}']),
+    ('\u{ABBD}', &['\u{13ED}']),
+    ('\u{ABBE}', &['\u{13EE}']),
+    ('\u{ABBF}', &['\u{13EF}']),
+    ('\u{FB00}', &['f', 'f']),
+    ('\u{FB01}', &['f', 'i']),
+    ('\u{FB02}', &['f', 'l']),
+    ('\u{FB03}', &['f', 'f', 'i']),
+    ('\u{FB04}', &['f', 'f', 'l']),
+    ('\u{FB05}', &['s', 't']),
+    ('\u{FB06}', &['s', 't']),
+    ('\u{FB13}', &['\u{574}', '\u{576}']),
+    ('\u{FB14}', &['\u{574}', '\u{565}']),
+    ('\u{FB15}', &['\u{574}', '\u{56B}']),
+    ('\u{FB16}', &['\u{57E}', '\u{576}']),
+    ('\u{FB17}', &['\u{574}', '\u{56D}']),
+    ('\u{FF21}', &['\u{FF41}']),
+    ('\u{FF22}', &['\u{FF42}']),
+    ('\u{FF23}', &['\u{FF43}']),
+    ('\u{FF24}', &['\u{FF44}']),
+    ('\u{FF25}', &['\u{FF45}']),
+    ('\u{FF26}', &['\u{FF46}']),
+    ('\u{FF27}', &['\u{FF47}']),
+    ('\u{FF28}', &['\u{FF48}']),
+    ('\u{FF29}', &['\u{FF49}']),
+    ('\u{FF2A}', &['\u{FF4A}']),
+    ('\u{FF2B}', &['\u{FF4B}']),
+    ('\u{FF2C}', &['\u{FF4C}']),
+    ('\u{FF2D}', &['\u{FF4D}']),
+    ('\u{FF2E}', &['\u{FF4E}']),
+    ('\u{FF2F}', &['\u{FF4F}']),
+    ('\u{FF30}', &['\u{FF50}']),
+    ('\u{FF31}', &['\u{FF51}']),
+    ('\u{FF32}', &['\u{FF52}']),
+    ('\u{FF33}', &['\u{FF53}']),
+    ('\u{FF34}', &['\u{FF54}']),
+    ('\u{FF35}', &['\u{FF55}']),
+    ('\u{FF36}', &['\u{FF56}']),
+    ('\u{FF37}', &['\u{FF57}']),
+    ('\u{FF38}', &['\u{FF58}']),
+    ('\u{FF39}', &['\u{FF59}']),
+    ('\u{FF3A}', &['\u{FF5A}']),
+    ('\u{10400}', &['\u{10428}']),
+    ('\u{10401}', &['\u{10429}']),
+    ('\u{10402}', &['\u{1042A}']),
+    ('\u{10403}', &['\u{1042B}']),
+    ('\u{10404}', &['\u{1042C}']),
+    ('\u{10405}', &['\u{1042D}']),
+    ('\u{10406}', &['\u{1042E}']),
+    ('\u{10407}', &['\u{1042F}']),
+    ('\u{10408}', &['\u{10430}']),
+    ('\u{10409}', &['\u{10431}']),
+    ('\u{1040A}', &['\u{10432}']),
+    ('\u{1040B}', &['\u{10433}']),
+    ('\u{1040C}', &['\u{10434}']),
+    ('\u{1040D}', &['\u{10435}']),
+    ('\u{1040E}', &['\u{10436}']),
+    ('\u{1040F}', &['\u{10437}']),
+    ('\u{10410}', &['\u{10438}']),
+    ('\u{10411}', &['\u{10439}']),
+    ('\u{10412}', &['\u{1043A}']),
+    ('\u{10413}', &['\u{1043B}']),
+    ('\u{10414}', &['\u{1043C}']),
+    ('\u{10415}', &['\u{1043D}']),
+    ('\u{10416}', &['\u{1043E}']),
+    ('\u{10417}', &['\u{1043F}']),
+    ('\u{10418}', &['\u{10440}']),
+    ('\u{10419}', &['\u{10441}']),
+    ('\u{1041A}', &['\u{10442}']),
+    ('\u{1041B}', &['\u{10443}']),
+    ('\u{1041C}', &['\u{10444}']),
+    ('\u{1041D}', &['\u{10445}']),
+    ('\u{1041E}', &['\u{10446}']),
+    ('\u{1041F}', &['\u{10447}']),
+    ('\u{10420}', &['\u{10448}']),
+    ('\u{10421}', &['\u{10449}']),
+    ('\u{10422}', &['\u{1044A}']),
+    ('\u{10423}', &['\u{1044B}']),
+    ('\u{10424}', &['\u{1044C}']),
+    ('\u{10425}', &['\u{1044D}']),
+    ('\u{10426}', &['\u{1044E}']),
+    ('\u{10427}', &['\u{1044F}']),
+    ('\u{104B0}', &['\u{104D8}']),
+    ('\u{104B1}', &['\u{104D9}']),
+    ('\u{104B2}', &['\u{104DA}']),
+    ('\u{104B3}', &['\u{104DB}']),
+    ('\u{104B4}', &['\u{104DC}']),
+    ('\u{104B5}', &['\u{104DD}']),
+    ('\u{104B6}', &['\u{104DE}']),
+    ('\u{104B7}', &['\u{104DF}']),
+    ('\u{104B8}', &['\u{104E0}']),
+    ('\u{104B9}', &['\u{104E1}']),
+    ('\u{104BA}', &['\u{104E2}']),
+    ('\u{104BB}', &['\u{104E3}']),
+    ('\u{104BC}', &['\u{104E4}']),
+    ('\u{104BD}', &['\u{104E5}']),
+    ('\u{104BE}', &['\u{104E6}']),
+    ('\u{104BF}', &['\u{104E7}']),
+    ('\u{104C0}', &['\u{104E8}']),
+    ('\u{104C1}', &['\u{104E9}']),
+    ('\u{104C2}', &['\u{104EA}']),
+    ('\u{104C3}', &['\u{104EB}']),
+    ('\u{104C4}', &['\u{104EC}']),
+    ('\u{104C5}', &['\u{104ED}']),
+    ('\u{104C6}', &['\u{104EE}']),
+    ('\u{104C7}', &['\u{104EF}']),
+    ('\u{104C8}', &['\u{104F0}']),
+    ('\u{104C9}', &['\u{104F1}']),
+    ('\u{104CA}', &['\u{104F2}']),
+    ('\u{104CB}', &['\u{104F3}']),
+    ('\u{104CC}', &['\u{104F4}']),
+    ('\u{104CD}', &['\u{104F5}']),
+    ('\u{104CE}', &['\u{104F6}']),
+    ('\u{104CF}', &['\u{104F7}']),
+    ('\u{104D0}', &['\u{104F8}']),
+    ('\u{104D1}', &['\u{104F9}']),
+    ('\u{104D2}', &['\u{104FA}']),
+    ('\u{104D3}', &['\u{104FB}']),
+    ('\u{10570}', &['\u{10597}']),
+    ('\u{10571}', &['\u{10598}']),
+    ('\u{10572}', &['\u{10599}']),
+    ('\u{10573}', &['\u{1059A}']),
+    ('\u{10574}', &['\u{1059B}']),
+    ('\u{10575}', &['\u{1059C}']),
+    ('\u{10576}', &['\u{1059D}']),
+    ('\u{10577}', &['\u{1059E}']),
+    ('\u{10578}', &['\u{1059F}']),
+    ('\u{10579}', &['\u{105A0}']),
+    ('\u{1057A}', &['\u{105A1}']),
+    ('\u{1057C}', &['\u{105A3}']),
+    ('\u{1057D}', &['\u{105A4}']),
+    ('\u{1057E}', &['\u{105A5}']),
+    ('\u{1057F}', &['\u{105A6}']),
+    ('\u{10580}', &['\u{105A7}']),
+    ('\u{10581}', &['\u{105A8}']),
+    ('\u{10582}', &['\u{105A9}']),
+    ('\u{10583}', &['\u{105AA}']),
+    ('\u{10584}', &['\u{105AB}']),
+    ('\u{10585}', &['\u{105AC}']),
+    ('\u{10586}', &['\u{105AD}']),
+    ('\u{10587}', &['\u{105AE}']),
+    ('\u{10588}', &['\u{105AF}']),
+    ('\u{10589}', &['\u{105B0}']),
+    ('\u{1058A}', &['\u{105B1}']),
+    ('\u{1058C}', &['\u{105B3}']),
+    ('\u{1058D}', &['\u{105B4}']),
+    ('\u{1058E}', &['\u{105B5}']),
+    ('\u{1058F}', &['\u{105B6}']),
+    ('\u{10590}', &['\u{105B7}']),
+    ('\u{10591}', &['\u{105B8}']),
+    ('\u{10592}', &['\u{105B9}']),
+    ('\u{10594}', &['\u{105BB}']),
+    ('\u{10595}', &['\u{105BC}']),
+    ('\u{10C80}', &['\u{10CC0}']),
+    ('\u{10C81}', &['\u{10CC1}']),
+    ('\u{10C82}', &['\u{10CC2}']),
+    ('\u{10C83}', &['\u{10CC3}']),
+    ('\u{10C84}', &['\u{10CC4}']),
+    ('\u{10C85}', &['\u{10CC5}']),
+    ('\u{10C86}', &['\u{10CC6}']),
+    ('\u{10C87}', &['\u{10CC7}']),
+    ('\u{10C88}', &['\u{10CC8}']),
+    ('\u{10C89}', &['\u{10CC9}']),
+    ('\u{10C8A}', &['\u{10CCA}']),
+    ('\u{10C8B}', &['\u{10CCB}']),
+    ('\u{10C8C}', &['\u{10CCC}']),
+    ('\u{10C8D}', &['\u{10CCD}']),
+    ('\u{10C8E}', &['\u{10CCE}']),
+    ('\u{10C8F}', &['\u{10CCF}']),
+    ('\u{10C90}', &['\u{10CD0}']),
+    ('\u{10C91}', &['\u{10CD1}']),
+    ('\u{10C92}', &['\u{10CD2}']),
+    ('\u{10C93}', &['\u{10CD3}']),
+    ('\u{10C94}', &['\u{10CD4}']),
+    ('\u{10C95}', &['\u{10CD5}']),
+    ('\u{10C96}', &['\u{10CD6}']),
+    ('\u{10C97}', &['\u{10CD7}']),
+    ('\u{10C98}', &['\u{10CD8}']),
+    ('\u{10C99}', &['\u{10CD9}']),
+    ('\u{10C9A}', &['\u{10CDA}']),
+    ('\u{10C9B}', &['\u{10CDB}']),
+    ('\u{10C9C}', &['\u{10CDC}']),
+    ('\u{10C9D}', &['\u{10CDD}']),
+    ('\u{10C9E}', &['\u{10CDE}']),
+    ('\u{10C9F}', &['\u{10CDF}']),
+    ('\u{10CA0}', &['\u{10CE0}']),
+    ('\u{10CA1}', &['\u{10CE1}']),
+    ('\u{10CA2}', &['\u{10CE2}']),
+    ('\u{10CA3}', &['\u{10CE3}']),
+    ('\u{10CA4}', &['\u{10CE4}']),
+    ('\u{10CA5}', &['\u{10CE5}']),
+    ('\u{10CA6}', &['\u{10CE6}']),
+    ('\u{10CA7}', &['\u{10CE7}']),
+    ('\u{10CA8}', &['\u{10CE8}']),
+    ('\u{10CA9}', &['\u{10CE9}']),
+    ('\u{10CAA}', &['\u{10CEA}']),
+    ('\u{10CAB}', &['\u{10CEB}']),
+    ('\u{10CAC}', &['\u{10CEC}']),
+    ('\u{10CAD}', &['\u{10CED}']),
+    ('\u{10CAE}', &['\u{10CEE}']),
+    ('\u{10CAF}', &['\u{10CEF}']),
+    ('\u{10CB0}', &['\u{10CF0}']),
+    ('\u{10CB1}', &['\u{10CF1}']),
+    ('\u{10CB2}', &['\u{10CF2}']),
+    ('\u{118A0}', &['\u{118C0}']),
+    ('\u{118A1}', &['\u{118C1}']),
+    ('\u{118A2}', &['\u{118C2}']),
+    ('\u{118A3}', &['\u{118C3}']),
+    ('\u{118A4}', &['\u{118C4}']),
+    ('\u{118A5}', &['\u{118C5}']),
+    ('\u{118A6}', &['\u{118C6}']),
+    ('\u{118A7}', &['\u{118C7}']),
+    ('\u{118A8}', &['\u{118C8}']),
+    ('\u{118A9}', &['\u{118C9}']),
+    ('\u{118AA}', &['\u{118CA}']),
+    ('\u{118AB}', &['\u{118CB}']),
+    ('\u{118AC}', &['\u{118CC}']),
+    ('\u{118AD}', &['\u{118CD}']),
+    ('\u{118AE}', &['\u{118CE}']),
+    ('\u{118AF}', &['\u{118CF}']),
+    ('\u{118B0}', &['\u{118D0}']),
+    ('\u{118B1}', &['\u{118D1}']),
+    ('\u{118B2}', &['\u{118D2}']),
+    ('\u{118B3}', &['\u{118D3}']),
+    ('\u{118B4}', &['\u{118D4}']),
+    ('\u{118B5}', &['\u{118D5}']),
+    ('\u{118B6}', &['\u{118D6}']),
+    ('\u{118B7}', &['\u{118D7}']),
+    ('\u{118B8}', &['\u{118D8}']),
+    ('\u{118B9}', &['\u{118D9}']),
+    ('\u{118BA}', &['\u{118DA}']),
+    ('\u{118BB}', &['\u{118DB}']),
+    ('\u{118BC}', &['\u{118DC}']),
+    ('\u{118BD}', &['\u{118DD}']),
+    ('\u{118BE}', &['\u{118DE}']),
+    ('\u{118BF}', &['\u{118DF}']),
+    ('\u{16E40}', &['\u{16E60}']),
+    ('\u{16E41}', &['\u{16E61}']),
+    ('\u{16E42}', &['\u{16E62}']),
+    ('\u{16E43}', &['\u{16E63}']),
+    ('\u{16E44}', &['\u{16E64}']),
+    ('\u{16E45}', &['\u{16E65}']),
+    ('\u{16E46}', &['\u{16E66}']),
+    ('\u{16E47}', &['\u{16E67}']),
+    ('\u{16E48}', &['\u{16E68}']),
+    ('\u{16E49}', &['\u{16E69}']),
+    ('\u{16E4A}', &['\u{16E6A}']),
+    ('\u{16E4B}', &['\u{16E6B}']),
+    ('\u{16E4C}', &['\u{16E6C}']),
+    ('\u{16E4D}', &['\u{16E6D}']),
+    ('\u{16E4E}', &['\u{16E6E}']),
+    ('\u{16E4F}', &['\u{16E6F}']),
+    ('\u{16E50}', &['\u{16E70}']),
+    ('\u{16E51}', &['\u{16E71}']),
+    ('\u{16E52}', &['\u{16E72}']),
+    ('\u{16E53}', &['\u{16E73}']),
+    ('\u{16E54}', &['\u{16E74}']),
+    ('\u{16E55}', &['\u{16E75}']),
+    ('\u{16E56}', &['\u{16E76}']),
+    ('\u{16E57}', &['\u{16E77}']),
+    ('\u{16E58}', &['\u{16E78}']),
+    ('\u{16E59}', &['\u{16E79}']),
+    ('\u{16E5A}', &['\u{16E7A}']),
+    ('\u{16E5B}', &['\u{16E7B}']),
+    ('\u{16E5C}', &['\u{16E7C}']),
+    ('\u{16E5D}', &['\u{16E7D}']),
+    ('\u{16E5E}', &['\u{16E7E}']),
+    ('\u{16E5F}', &['\u{16E7F}']),
+    ('\u{1E900}', &['\u{1E922}']),
+    ('\u{1E901}', &['\u{1E923}']),
+    ('\u{1E902}', &['\u{1E924}']),
+    ('\u{1E903}', &['\u{1E925}']),
+    ('\u{1E904}', &['\u{1E926}']),
+    ('\u{1E905}', &['\u{1E927}']),
+    ('\u{1E906}', &['\u{1E928}']),
+    ('\u{1E907}', &['\u{1E929}']),
+    ('\u{1E908}', &['\u{1E92A}']),
+    ('\u{1E909}', &['\u{1E92B}']),
+    ('\u{1E90A}', &['\u{1E92C}']),
+    ('\u{1E90B}', &['\u{1E92D}']),
+    ('\u{1E90C}', &['\u{1E92E}']),
+    ('\u{1E90D}', &['\u{1E92F}']),
+    ('\u{1E90E}', &['\u{1E930}']),
+    ('\u{1E90F}', &['\u{1E931}']),
+    ('\u{1E910}', &['\u{1E932}']),
+    ('\u{1E911}', &['\u{1E933}']),
+    ('\u{1E912}', &['\u{1E934}']),
+    ('\u{1E913}', &['\u{1E935}']),
+    ('\u{1E914}', &['\u{1E936}']),
+    ('\u{1E915}', &['\u{1E937}']),
+    ('\u{1E916}', &['\u{1E938}']),
+    ('\u{1E917}', &['\u{1E939}']),
+    ('\u{1E918}', &['\u{1E93A}']),
+    ('\u{1E919}', &['\u{1E93B}']),
+    ('\u{1E91A}', &['\u{1E93C}']),
+    ('\u{1E91B}', &['\u{1E93D}']),
+    ('\u{1E91C}', &['\u{1E93E}']),
+    ('\u{1E91D}', &['\u{1E93F}']),
+    ('\u{1E91E}', &['\u{1E940}']),
+    ('\u{1E91F}', &['\u{1E941}']),
+    ('\u{1E920}', &['\u{1E942}']),
+    ('\u{1E921}', &['\u{1E943}']),
+];
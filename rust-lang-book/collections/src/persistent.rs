@@ -0,0 +1,21 @@
+mod pmap;
+mod pvec;
+
+pub use pmap::PMap;
+pub use pvec::PVec;
+
+//* Persistent collections: "changing" one returns a new version and leaves the old one untouched
+//*     let _first = &v[0];
+//*     v.push(4);              // ! v is borrowed, push would invalidate _first
+//* becomes
+//*     let first = &v[0];
+//*     let w = v.push(4);      // v and first stay valid, w is v plus 4
+//*
+//* Both types are trees of reference counted nodes, a new version copies only the nodes on the path
+//* to the change (O(log n) of them, and the tree is 32 wide so that is at most a handful) and shares all others
+//* with the old version. Cloning a version is O(1)
+//*     PVec    a vector trie like Clojure's, the last up to 32 elements sit in a separate tail so push is cheap
+//*     PMap    a hash array mapped trie (HAMT), every level uses 5 more bits of the key's hash
+//*
+//* Nodes that aren't shared (reference count 1) are changed in place instead of being copied,
+//* so building a collection with collect() doesn't copy anything
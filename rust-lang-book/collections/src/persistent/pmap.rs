@@ -0,0 +1,373 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
+use std::ops::Index;
+use std::rc::Rc;
+
+const BITS: u32 = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+//* Level n of the trie picks a slot with bits 5n..5n+5 of the key's hash
+//* A branch only stores the slots in use, a bitmap says which of the 32 possible ones those are:
+//* the position of slot i is the number of bits set below bit i
+//* A slot holds an entry until a second key needs it, then both move into a new branch one level down,
+//* removing an entry undoes that, so a branch never holds just a single entry (except the root)
+//* Keys and values are cloned when a branch is copied, wrap expensive ones in an Rc
+pub struct PMap<K, V, S = RandomState> {
+    root: Rc<Node<K, V>>,
+    len: usize,
+    hasher: S,
+}
+
+#[derive(Clone)]
+enum Node<K, V> {
+    Branch { bitmap: u32, slots: Vec<Slot<K, V>> },
+    //* keys whose hashes are equal in all 64 bits, they can only be told apart by comparing them
+    Collision { hash: u64, entries: Vec<(K, V)> },
+}
+
+#[derive(Clone)]
+enum Slot<K, V> {
+    Entry { hash: u64, key: K, value: V },
+    Node(Rc<Node<K, V>>),
+}
+
+impl<K, V> Node<K, V> {
+    fn empty() -> Node<K, V> {
+        Node::Branch { bitmap: 0, slots: Vec::new() }
+    }
+
+    //* The node for two entries that need the same slot at level 'shift' - BITS
+    fn pair(shift: u32, first: Slot<K, V>, second: Slot<K, V>) -> Node<K, V> {
+        let (first_hash, second_hash) = match (&first, &second) {
+            (Slot::Entry { hash: a, .. }, Slot::Entry { hash: b, .. }) => (*a, *b),
+            _ => unreachable!("only entries are paired"),
+        };
+        if shift >= u64::BITS {
+            let mut entries = Vec::with_capacity(2);
+            for slot in [first, second] {
+                if let Slot::Entry { key, value, .. } = slot {
+                    entries.push((key, value));
+                }
+            }
+            return Node::Collision { hash: first_hash, entries };
+        }
+        let (a, b) = ((first_hash >> shift) & MASK, (second_hash >> shift) & MASK);
+        if a == b {
+            let child = Node::pair(shift + BITS, first, second);
+            Node::Branch { bitmap: 1 << a, slots: vec![Slot::Node(Rc::new(child))] }
+        } else if a < b {
+            Node::Branch { bitmap: 1 << a | 1 << b, slots: vec![first, second] }
+        } else {
+            Node::Branch { bitmap: 1 << a | 1 << b, slots: vec![second, first] }
+        }
+    }
+}
+
+//* The bit of the slot for 'hash' at level 'shift' and its position among the slots in use
+fn locate(bitmap: u32, hash: u64, shift: u32) -> (u32, usize) {
+    let bit = 1 << ((hash >> shift) & MASK);
+    (bit, (bitmap & (bit - 1)).count_ones() as usize)
+}
+
+impl<K, V> PMap<K, V> {
+    pub fn new() -> PMap<K, V> {
+        PMap::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V, S> PMap<K, V, S> {
+    pub fn with_hasher(hasher: S) -> PMap<K, V, S> {
+        PMap { root: Rc::new(Node::empty()), len: 0, hasher }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //* In the order of the keys' hashes, which is arbitrary like HashMap's order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let slots = match &*self.root {
+            Node::Branch { slots, .. } => slots.iter(),
+            Node::Collision { .. } => unreachable!("the root is a branch"),
+        };
+        Iter { stack: vec![slots], collision: [].iter(), remaining: self.len }
+    }
+
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> PMap<K, V, S> {
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hasher.hash_one(key);
+        let mut node = &*self.root;
+        let mut shift = 0;
+        loop {
+            match node {
+                Node::Branch { bitmap, slots } => {
+                    let (bit, position) = locate(*bitmap, hash, shift);
+                    if bitmap & bit == 0 {
+                        return None;
+                    }
+                    match &slots[position] {
+                        Slot::Entry { hash: found, key: k, value } => {
+                            return if *found == hash && k.borrow() == key { Some(value) } else { None };
+                        }
+                        Slot::Node(child) => node = child,
+                    }
+                    shift += BITS;
+                }
+                Node::Collision { entries, .. } => {
+                    return entries.iter().find(|(k, _)| k.borrow() == key).map(|(_, value)| value);
+                }
+            }
+        }
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Clone> PMap<K, V, S> {
+    //* A new version with 'key' mapped to 'value'
+    pub fn insert(&self, key: K, value: V) -> PMap<K, V, S> {
+        let mut inserted = self.clone();
+        inserted.insert_mut(key, value);
+        inserted
+    }
+
+    //* A new version without 'key', a version sharing everything if 'key' isn't there
+    pub fn remove<Q>(&self, key: &Q) -> PMap<K, V, S>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut removed = self.clone();
+        //* Checking first saves copying the path to a key that isn't there
+        if self.contains_key(key) {
+            let hash = self.hasher.hash_one(key);
+            remove(&mut removed.root, 0, hash, key);
+            removed.len -= 1;
+        }
+        removed
+    }
+
+    fn insert_mut(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hasher.hash_one(&key);
+        let old = insert(&mut self.root, 0, hash, key, value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+}
+
+fn insert<K: Eq + Clone, V: Clone>(node: &mut Rc<Node<K, V>>, shift: u32, hash: u64, key: K, value: V) -> Option<V> {
+    let (bitmap, slots) = match Rc::make_mut(node) {
+        Node::Branch { bitmap, slots } => (bitmap, slots),
+        Node::Collision { entries, .. } => {
+            if let Some((_, old)) = entries.iter_mut().find(|(k, _)| *k == key) {
+                return Some(std::mem::replace(old, value));
+            }
+            entries.push((key, value));
+            return None;
+        }
+    };
+    let (bit, position) = locate(*bitmap, hash, shift);
+    if *bitmap & bit == 0 {
+        *bitmap |= bit;
+        slots.insert(position, Slot::Entry { hash, key, value });
+        return None;
+    }
+    let slot = &mut slots[position];
+    match slot {
+        Slot::Node(child) => return insert(child, shift + BITS, hash, key, value),
+        Slot::Entry { hash: found, key: k, value: old } => {
+            if *found == hash && *k == key {
+                return Some(std::mem::replace(old, value));
+            }
+        }
+    }
+    //* Another key has the slot, both go one level down
+    let other = std::mem::replace(slot, Slot::Node(Rc::new(Node::empty())));
+    let pair = Node::pair(shift + BITS, other, Slot::Entry { hash, key, value });
+    *slot = Slot::Node(Rc::new(pair));
+    None
+}
+
+//* Only called for keys that are in the map
+fn remove<K, V, Q>(node: &mut Rc<Node<K, V>>, shift: u32, hash: u64, key: &Q)
+where
+    K: Borrow<Q> + Clone,
+    V: Clone,
+    Q: Eq + ?Sized,
+{
+    let (bitmap, slots) = match Rc::make_mut(node) {
+        Node::Branch { bitmap, slots } => (bitmap, slots),
+        Node::Collision { entries, .. } => {
+            entries.retain(|(k, _)| k.borrow() != key);
+            return;
+        }
+    };
+    let (bit, position) = locate(*bitmap, hash, shift);
+    match &mut slots[position] {
+        Slot::Entry { .. } => {
+            *bitmap &= !bit;
+            slots.remove(position);
+        }
+        Slot::Node(child) => {
+            remove(child, shift + BITS, hash, key);
+            lift(&mut slots[position]);
+        }
+    }
+}
+
+//* A node that is left with a single entry is replaced by that entry
+fn lift<K: Clone, V: Clone>(slot: &mut Slot<K, V>) {
+    let single = match slot {
+        Slot::Node(child) => match &**child {
+            Node::Branch { slots, .. } => slots.len() == 1 && matches!(slots[0], Slot::Entry { .. }),
+            Node::Collision { entries, .. } => entries.len() == 1,
+        },
+        Slot::Entry { .. } => false,
+    };
+    if !single {
+        return;
+    }
+    if let Slot::Node(child) = std::mem::replace(slot, Slot::Node(Rc::new(Node::empty()))) {
+        *slot = match Rc::try_unwrap(child).unwrap_or_else(|shared| (*shared).clone()) {
+            Node::Branch { mut slots, .. } => slots.pop().expect("a single entry"),
+            Node::Collision { hash, mut entries } => {
+                let (key, value) = entries.pop().expect("a single entry");
+                Slot::Entry { hash, key, value }
+            }
+        };
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    //* the slots still to visit on each level down to the current branch
+    stack: Vec<std::slice::Iter<'a, Slot<K, V>>>,
+    collision: std::slice::Iter<'a, (K, V)>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, value)) = self.collision.next() {
+                self.remaining -= 1;
+                return Some((key, value));
+            }
+            let slots = self.stack.last_mut()?;
+            match slots.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Slot::Entry { key, value, .. }) => {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+                Some(Slot::Node(node)) => match &**node {
+                    Node::Branch { slots, .. } => self.stack.push(slots.iter()),
+                    Node::Collision { entries, .. } => self.collision = entries.iter(),
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V, S> IntoIterator for &'a PMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Clone + Default> FromIterator<(K, V)> for PMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = PMap::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+//* Inserts in place, nodes shared with other versions are copied first
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Clone> Extend<(K, V)> for PMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert_mut(key, value);
+        }
+    }
+}
+
+impl<K, V, Q, S> Index<&Q> for PMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in PMap")
+    }
+}
+
+impl<K, V, S: Default> Default for PMap<K, V, S> {
+    fn default() -> Self {
+        PMap::with_hasher(S::default())
+    }
+}
+
+//* O(1), the clone shares all nodes
+impl<K, V, S: Clone> Clone for PMap<K, V, S> {
+    fn clone(&self) -> Self {
+        PMap { root: self.root.clone(), len: self.len, hasher: self.hasher.clone() }
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher> PartialEq for PMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, S: BuildHasher> Eq for PMap<K, V, S> {}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for PMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
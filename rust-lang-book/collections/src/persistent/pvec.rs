@@ -0,0 +1,325 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Index;
+use std::rc::Rc;
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+//* All elements but the tail live in leaves of 32, the branches above them have up to 32 children
+//* Element i is found by taking 5 bits of i per level, starting with the highest ones at the root
+//* Elements are cloned when a leaf is copied, wrap expensive ones in an Rc
+pub struct PVec<T> {
+    len: usize,
+    //* how far an index is shifted right to get the position in the root's children
+    shift: usize,
+    root: Rc<Node<T>>,
+    //* always a leaf, empty only if the vector is
+    tail: Rc<Node<T>>,
+}
+
+#[derive(Clone)]
+enum Node<T> {
+    Branch(Vec<Rc<Node<T>>>),
+    Leaf(Vec<T>),
+}
+
+impl<T> Node<T> {
+    fn children(&self) -> &[Rc<Node<T>>] {
+        match self {
+            Node::Branch(children) => children,
+            Node::Leaf(_) => unreachable!("a leaf where a branch was expected"),
+        }
+    }
+
+    fn values(&self) -> &[T] {
+        match self {
+            Node::Leaf(values) => values,
+            Node::Branch(_) => unreachable!("a branch where a leaf was expected"),
+        }
+    }
+}
+
+impl<T: Clone> Node<T> {
+    //* Copies the node first if it is shared with another version
+    fn children_mut(node: &mut Rc<Node<T>>) -> &mut Vec<Rc<Node<T>>> {
+        match Rc::make_mut(node) {
+            Node::Branch(children) => children,
+            Node::Leaf(_) => unreachable!("a leaf where a branch was expected"),
+        }
+    }
+
+    fn values_mut(node: &mut Rc<Node<T>>) -> &mut Vec<T> {
+        match Rc::make_mut(node) {
+            Node::Leaf(values) => values,
+            Node::Branch(_) => unreachable!("a branch where a leaf was expected"),
+        }
+    }
+}
+
+impl<T> PVec<T> {
+    pub fn new() -> PVec<T> {
+        PVec {
+            len: 0,
+            shift: BITS,
+            root: Rc::new(Node::Branch(Vec::new())),
+            tail: Rc::new(Node::Leaf(Vec::new())),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(&self.leaf(index)[index & MASK])
+        } else {
+            None
+        }
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { vec: self, index: 0, leaf: &[] }
+    }
+
+    //* The index of the tail's first element
+    fn tail_offset(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            (self.len - 1) & !MASK
+        }
+    }
+
+    //* The leaf or the tail holding the element at 'index'
+    fn leaf(&self, index: usize) -> &[T] {
+        if index >= self.tail_offset() {
+            return self.tail.values();
+        }
+        let mut node = &self.root;
+        let mut level = self.shift;
+        while level > 0 {
+            node = &node.children()[(index >> level) & MASK];
+            level -= BITS;
+        }
+        node.values()
+    }
+}
+
+impl<T: Clone> PVec<T> {
+    //* A new version with 'value' appended
+    pub fn push(&self, value: T) -> PVec<T> {
+        let mut pushed = self.clone();
+        pushed.push_mut(value);
+        pushed
+    }
+
+    //* A new version without the last element, None if the vector is empty
+    pub fn pop(&self) -> Option<PVec<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut popped = self.clone();
+        popped.pop_mut();
+        Some(popped)
+    }
+
+    //* A new version with the element at 'index' replaced
+    //* Panics if 'index' is out of bounds, like assigning to v[index] does
+    pub fn set(&self, index: usize, value: T) -> PVec<T> {
+        assert!(index < self.len, "index {} out of bounds for a PVec of length {}", index, self.len);
+        let mut updated = self.clone();
+        updated.set_mut(index, value);
+        updated
+    }
+
+    fn push_mut(&mut self, value: T) {
+        if self.tail.values().len() == WIDTH {
+            let leaf = std::mem::replace(&mut self.tail, Rc::new(Node::Leaf(Vec::with_capacity(WIDTH))));
+            if (self.len >> BITS) > (1 << self.shift) {
+                //* The tree is full, it gets a new root with the old one as its first child
+                let root = vec![self.root.clone(), new_path(self.shift, leaf)];
+                self.root = Rc::new(Node::Branch(root));
+                self.shift += BITS;
+            } else {
+                push_leaf(&mut self.root, self.shift, self.len - 1, leaf);
+            }
+        }
+        Node::values_mut(&mut self.tail).push(value);
+        self.len += 1;
+    }
+
+    fn pop_mut(&mut self) {
+        if self.len == 1 || self.tail.values().len() > 1 {
+            Node::values_mut(&mut self.tail).pop();
+        } else {
+            //* The tail would become empty, the last leaf of the tree takes its place
+            self.tail = pop_leaf(&mut self.root, self.shift, self.len - 2);
+            if self.shift > BITS && self.root.children().len() == 1 {
+                self.root = self.root.children()[0].clone();
+                self.shift -= BITS;
+            }
+        }
+        self.len -= 1;
+    }
+
+    fn set_mut(&mut self, index: usize, value: T) {
+        if index >= self.tail_offset() {
+            Node::values_mut(&mut self.tail)[index & MASK] = value;
+            return;
+        }
+        let mut node = &mut self.root;
+        let mut level = self.shift;
+        while level > 0 {
+            let children = Node::children_mut(node);
+            node = &mut children[(index >> level) & MASK];
+            level -= BITS;
+        }
+        Node::values_mut(node)[index & MASK] = value;
+    }
+}
+
+//* A chain of branches down to 'leaf', for a leaf that doesn't fit below an existing branch
+fn new_path<T>(level: usize, leaf: Rc<Node<T>>) -> Rc<Node<T>> {
+    if level == 0 {
+        leaf
+    } else {
+        Rc::new(Node::Branch(vec![new_path(level - BITS, leaf)]))
+    }
+}
+
+//* Appends a full leaf as the last one below 'node', 'last' is the index of the leaf's last element
+fn push_leaf<T: Clone>(node: &mut Rc<Node<T>>, level: usize, last: usize, leaf: Rc<Node<T>>) {
+    let children = Node::children_mut(node);
+    let position = (last >> level) & MASK;
+    if level == BITS {
+        children.push(leaf);
+    } else if position < children.len() {
+        push_leaf(&mut children[position], level - BITS, last, leaf);
+    } else {
+        children.push(new_path(level - BITS, leaf));
+    }
+}
+
+//* Takes the last leaf out of the tree, 'last' is the index of its last element
+//* Branches that become empty are removed on the way back up
+fn pop_leaf<T: Clone>(node: &mut Rc<Node<T>>, level: usize, last: usize) -> Rc<Node<T>> {
+    let children = Node::children_mut(node);
+    if level == BITS {
+        return children.pop().expect("a branch has at least one child");
+    }
+    let position = (last >> level) & MASK;
+    let leaf = pop_leaf(&mut children[position], level - BITS, last);
+    if children[position].children().is_empty() {
+        children.pop();
+    }
+    leaf
+}
+
+pub struct Iter<'a, T> {
+    vec: &'a PVec<T>,
+    index: usize,
+    //* the leaf of the current index, so that the tree is only walked once per 32 elements
+    leaf: &'a [T],
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index >= self.vec.len {
+            return None;
+        }
+        if self.index & MASK == 0 {
+            self.leaf = self.vec.leaf(self.index);
+        }
+        let value = &self.leaf[self.index & MASK];
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a PVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for PVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = PVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+//* Appends in place, nodes shared with other versions are copied first
+impl<T: Clone> Extend<T> for PVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_mut(value);
+        }
+    }
+}
+
+impl<T> Index<usize> for PVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("index {} out of bounds for a PVec of length {}", index, self.len),
+        }
+    }
+}
+
+impl<T> Default for PVec<T> {
+    fn default() -> Self {
+        PVec::new()
+    }
+}
+
+//* O(1), the clone shares all nodes
+impl<T> Clone for PVec<T> {
+    fn clone(&self) -> Self {
+        PVec { len: self.len, shift: self.shift, root: self.root.clone(), tail: self.tail.clone() }
+    }
+}
+
+impl<T: PartialEq> PartialEq for PVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PVec<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for PVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,34 @@
+mod string;
+mod vec;
+
+pub use string::SmallString;
+pub use vec::{IntoIter, SmallVec};
+
+//* Collections that keep their first few elements inline instead of on the heap
+//*     vec![1, 2, 3, 4]                            allocates
+//*     SmallVec::<[i32; 4]>::from([1, 2, 3, 4])    doesn't, until a fifth element is pushed
+//*     SmallString::<[u8; 16]>::from("Hello")      doesn't either
+//* Past the inline capacity they "spill" into a Vec, which is invisible apart from spilled()
+//* The array type names the element type and the inline capacity in one go, like the 'smallvec' crate does
+//*
+//* The inline buffer is a MaybeUninit array where only the first 'len' elements are initialized,
+//* that takes unsafe code: every unsafe block says which of those elements it relies on
+//* tests/small.rs exercises all of them, run it under Miri with "cargo +nightly miri test --test small"
+
+//* Implemented for all arrays [T; N] and nothing else, it is sealed because SmallVec relies on
+//* the implementing type being CAPACITY Items laid out one after the other
+pub trait Array: sealed::Sealed {
+    type Item;
+    const CAPACITY: usize;
+}
+
+impl<T, const N: usize> Array for [T; N] {
+    type Item = T;
+    const CAPACITY: usize = N;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T, const N: usize> Sealed for [T; N] {}
+}
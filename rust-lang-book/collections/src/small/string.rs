@@ -0,0 +1,238 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::str;
+
+use super::{Array, SmallVec};
+
+//* A String that keeps up to A::CAPACITY bytes inline, SmallString<[u8; 16]> holds "Hello" without allocating
+//* The bytes are always valid UTF-8, the same invariant String has, everything that adds bytes adds whole chars
+//* Derefs to str and hashes and compares like one, so it can be a HashMap key that is looked up with a &str
+pub struct SmallString<A: Array<Item = u8>> {
+    bytes: SmallVec<A>,
+}
+
+impl<A: Array<Item = u8>> SmallString<A> {
+    pub fn new() -> SmallString<A> {
+        SmallString { bytes: SmallVec::new() }
+    }
+
+    //* Starts on the heap right away if 'capacity' bytes don't fit inline
+    pub fn with_capacity(capacity: usize) -> SmallString<A> {
+        SmallString { bytes: SmallVec::with_capacity(capacity) }
+    }
+
+    pub fn inline_capacity() -> usize {
+        A::CAPACITY
+    }
+
+    //* In bytes, like String::len
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    pub fn spilled(&self) -> bool {
+        self.bytes.spilled()
+    }
+
+    pub fn as_str(&self) -> &str {
+        //* SAFETY: the bytes are valid UTF-8, see the struct
+        unsafe { str::from_utf8_unchecked(&self.bytes) }
+    }
+
+    pub fn as_mut_str(&mut self) -> &mut str {
+        //* SAFETY: the bytes are valid UTF-8, and str's methods keep them valid
+        unsafe { str::from_utf8_unchecked_mut(&mut self.bytes) }
+    }
+
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - ch.len_utf8());
+        Some(ch)
+    }
+
+    //* Panics if 'new_len' isn't on a char boundary, cutting a char in half would leave invalid UTF-8
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(self.as_str().is_char_boundary(new_len), "new length {} is not on a char boundary", new_len);
+            self.bytes.truncate(new_len);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    //* Moves the bytes back inline if they fit again
+    pub fn shrink_to_fit(&mut self) {
+        self.bytes.shrink_to_fit();
+    }
+
+    pub fn into_string(self) -> String {
+        //* SAFETY: the bytes are valid UTF-8, see the struct
+        unsafe { String::from_utf8_unchecked(self.bytes.into_vec()) }
+    }
+}
+
+impl<A: Array<Item = u8>> Deref for SmallString<A> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Array<Item = u8>> DerefMut for SmallString<A> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<A: Array<Item = u8>> From<&str> for SmallString<A> {
+    fn from(s: &str) -> Self {
+        let mut small = SmallString::with_capacity(s.len());
+        small.push_str(s);
+        small
+    }
+}
+
+//* Takes over the String's allocation if it doesn't fit inline
+impl<A: Array<Item = u8>> From<String> for SmallString<A> {
+    fn from(s: String) -> Self {
+        SmallString { bytes: SmallVec::from_vec(s.into_bytes()) }
+    }
+}
+
+impl<A: Array<Item = u8>> FromIterator<char> for SmallString<A> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut small = SmallString::new();
+        small.extend(iter);
+        small
+    }
+}
+
+impl<'a, A: Array<Item = u8>> FromIterator<&'a str> for SmallString<A> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut small = SmallString::new();
+        small.extend(iter);
+        small
+    }
+}
+
+impl<A: Array<Item = u8>> Extend<char> for SmallString<A> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl<'a, A: Array<Item = u8>> Extend<&'a str> for SmallString<A> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+//* So that write!() works on it
+impl<A: Array<Item = u8>> fmt::Write for SmallString<A> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl<A: Array<Item = u8>> Default for SmallString<A> {
+    fn default() -> Self {
+        SmallString::new()
+    }
+}
+
+impl<A: Array<Item = u8>> Clone for SmallString<A> {
+    fn clone(&self) -> Self {
+        SmallString::from(self.as_str())
+    }
+}
+
+impl<A: Array<Item = u8>> Borrow<str> for SmallString<A> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Array<Item = u8>> AsRef<str> for SmallString<A> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Array<Item = u8>> PartialEq for SmallString<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<A: Array<Item = u8>> Eq for SmallString<A> {}
+
+impl<A: Array<Item = u8>> PartialEq<str> for SmallString<A> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a, A: Array<Item = u8>> PartialEq<&'a str> for SmallString<A> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<A: Array<Item = u8>> PartialOrd for SmallString<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Array<Item = u8>> Ord for SmallString<A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+//* Must hash like str for Borrow<str>
+impl<A: Array<Item = u8>> Hash for SmallString<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<A: Array<Item = u8>> fmt::Display for SmallString<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<A: Array<Item = u8>> fmt::Debug for SmallString<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,460 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+use super::Array;
+
+//* A Vec that keeps up to A::CAPACITY elements inline, SmallVec<[u32; 4]> holds 4 u32 without allocating
+//* Derefs to a slice, so sorting, searching, indexing etc. all come from there
+pub struct SmallVec<A: Array> {
+    storage: Storage<A>,
+}
+
+enum Storage<A: Array> {
+    //* only the first 'len' elements of 'buf' are initialized, SmallVec's drop drops exactly those
+    Inline { len: usize, buf: MaybeUninit<A> },
+    Heap(Vec<A::Item>),
+}
+
+//* The first element of the inline buffer, arrays are laid out as their elements one after the other
+fn first<A: Array>(buf: &MaybeUninit<A>) -> *const A::Item {
+    buf.as_ptr() as *const A::Item
+}
+
+fn first_mut<A: Array>(buf: &mut MaybeUninit<A>) -> *mut A::Item {
+    buf.as_mut_ptr() as *mut A::Item
+}
+
+impl<A: Array> SmallVec<A> {
+    pub fn new() -> SmallVec<A> {
+        SmallVec { storage: Storage::Inline { len: 0, buf: MaybeUninit::uninit() } }
+    }
+
+    //* Starts on the heap right away if 'capacity' doesn't fit inline
+    pub fn with_capacity(capacity: usize) -> SmallVec<A> {
+        if capacity <= A::CAPACITY {
+            SmallVec::new()
+        } else {
+            SmallVec { storage: Storage::Heap(Vec::with_capacity(capacity)) }
+        }
+    }
+
+    //* The elements move inline if they fit, and the Vec's allocation is freed
+    pub fn from_vec(vec: Vec<A::Item>) -> SmallVec<A> {
+        if vec.len() > A::CAPACITY {
+            return SmallVec { storage: Storage::Heap(vec) };
+        }
+        let mut small = SmallVec::new();
+        small.extend(vec);
+        small
+    }
+
+    pub fn inline_capacity() -> usize {
+        A::CAPACITY
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline { len, .. } => *len,
+            Storage::Heap(vec) => vec.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Inline { .. } => A::CAPACITY,
+            Storage::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    //* True once the elements have moved to the heap
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    pub fn as_slice(&self) -> &[A::Item] {
+        match &self.storage {
+            //* SAFETY: the first 'len' elements are initialized
+            Storage::Inline { len, buf } => unsafe { slice::from_raw_parts(first(buf), *len) },
+            Storage::Heap(vec) => vec,
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        match &mut self.storage {
+            //* SAFETY: the first 'len' elements are initialized
+            Storage::Inline { len, buf } => unsafe { slice::from_raw_parts_mut(first_mut(buf), *len) },
+            Storage::Heap(vec) => vec,
+        }
+    }
+
+    pub fn push(&mut self, value: A::Item) {
+        match &mut self.storage {
+            Storage::Inline { len, buf } if *len < A::CAPACITY => {
+                //* SAFETY: 'len' < CAPACITY, so the slot is inside the buffer, and it isn't initialized yet
+                unsafe { first_mut(buf).add(*len).write(value) };
+                *len += 1;
+            }
+            Storage::Inline { .. } => self.spill(A::CAPACITY * 2 + 1).push(value),
+            Storage::Heap(vec) => vec.push(value),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<A::Item> {
+        match &mut self.storage {
+            Storage::Inline { len, buf } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                //* SAFETY: the last element is initialized, it isn't counted any more so it is read exactly once
+                Some(unsafe { first(buf).add(*len).read() })
+            }
+            Storage::Heap(vec) => vec.pop(),
+        }
+    }
+
+    //* Panics if 'index' > len
+    pub fn insert(&mut self, index: usize, value: A::Item) {
+        let length = self.len();
+        assert!(index <= length, "insertion index (is {}) should be <= len (is {})", index, length);
+        match &mut self.storage {
+            Storage::Inline { len, buf } if *len < A::CAPACITY => {
+                //* SAFETY: elements index..len are initialized and move one slot up, which is still inside the buffer
+                //* because len < CAPACITY, then the gap at 'index' is filled
+                unsafe {
+                    let slot = first_mut(buf).add(index);
+                    ptr::copy(slot, slot.add(1), *len - index);
+                    slot.write(value);
+                }
+                *len += 1;
+            }
+            Storage::Inline { .. } => self.spill(A::CAPACITY * 2 + 1).insert(index, value),
+            Storage::Heap(vec) => vec.insert(index, value),
+        }
+    }
+
+    //* Panics if 'index' >= len
+    pub fn remove(&mut self, index: usize) -> A::Item {
+        let length = self.len();
+        assert!(index < length, "removal index (is {}) should be < len (is {})", index, length);
+        match &mut self.storage {
+            Storage::Inline { len, buf } => {
+                //* SAFETY: the element at 'index' is initialized and read once,
+                //* the initialized elements after it move one slot down over it
+                let value = unsafe {
+                    let slot = first_mut(buf).add(index);
+                    let value = slot.read();
+                    ptr::copy(slot.add(1), slot, *len - index - 1);
+                    value
+                };
+                *len -= 1;
+                value
+            }
+            Storage::Heap(vec) => vec.remove(index),
+        }
+    }
+
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.storage {
+            Storage::Inline { len, buf } => {
+                if new_len >= *len {
+                    return;
+                }
+                let old_len = mem::replace(len, new_len);
+                //* SAFETY: elements new_len..old_len are initialized and not counted any more,
+                //* so they are dropped exactly once, even if dropping one of them panics
+                unsafe {
+                    let tail = first_mut(buf).add(new_len);
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, old_len - new_len));
+                }
+            }
+            Storage::Heap(vec) => vec.truncate(new_len),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    //* Makes room for at least 'additional' more elements, spilling if they don't fit inline
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.storage {
+            Storage::Inline { len, .. } => {
+                let needed = len.checked_add(additional).expect("capacity overflow");
+                if needed > A::CAPACITY {
+                    self.spill(needed);
+                }
+            }
+            Storage::Heap(vec) => vec.reserve(additional),
+        }
+    }
+
+    //* Moves the elements back inline if they fit again, otherwise shrinks the heap allocation
+    pub fn shrink_to_fit(&mut self) {
+        if let Storage::Heap(vec) = &mut self.storage {
+            if vec.len() > A::CAPACITY {
+                vec.shrink_to_fit();
+                return;
+            }
+            let mut vec = mem::take(vec);
+            let len = vec.len();
+            let mut buf = MaybeUninit::uninit();
+            //* SAFETY: len <= CAPACITY elements are moved, the Vec forgets them by setting its length to 0
+            unsafe {
+                ptr::copy_nonoverlapping(vec.as_ptr(), first_mut(&mut buf), len);
+                vec.set_len(0);
+            }
+            self.storage = Storage::Inline { len, buf };
+        }
+    }
+
+    pub fn into_vec(mut self) -> Vec<A::Item> {
+        match mem::replace(&mut self.storage, Storage::Inline { len: 0, buf: MaybeUninit::uninit() }) {
+            Storage::Heap(vec) => vec,
+            inline => SmallVec { storage: inline }.into_iter().collect(),
+        }
+    }
+
+    //* Moves the inline elements into a Vec with room for 'capacity' elements, returns the Vec
+    fn spill(&mut self, capacity: usize) -> &mut Vec<A::Item> {
+        if let Storage::Inline { len, buf } = &mut self.storage {
+            let mut vec = Vec::with_capacity(capacity.max(*len));
+            //* SAFETY: the first 'len' elements are initialized, they are moved into the Vec
+            //* and the inline buffer is dropped without dropping them (it is a MaybeUninit)
+            unsafe {
+                ptr::copy_nonoverlapping(first(buf), vec.as_mut_ptr(), *len);
+                vec.set_len(*len);
+            }
+            self.storage = Storage::Heap(vec);
+        }
+        match &mut self.storage {
+            Storage::Heap(vec) => vec,
+            Storage::Inline { .. } => unreachable!("spilled just now"),
+        }
+    }
+}
+
+impl<A: Array> SmallVec<A>
+where
+    A::Item: Copy,
+{
+    //* Copy and not Clone like Vec's, so that copying into the inline buffer is a single memcpy
+    pub fn extend_from_slice(&mut self, other: &[A::Item]) {
+        self.reserve(other.len());
+        match &mut self.storage {
+            Storage::Inline { len, buf } => {
+                //* SAFETY: reserve() spilled if the elements don't fit, so len..len + other.len() is inside the buffer
+                unsafe { ptr::copy_nonoverlapping(other.as_ptr(), first_mut(buf).add(*len), other.len()) };
+                *len += other.len();
+            }
+            Storage::Heap(vec) => vec.extend_from_slice(other),
+        }
+    }
+}
+
+impl<A: Array> Drop for SmallVec<A> {
+    fn drop(&mut self) {
+        if let Storage::Inline { len, buf } = &mut self.storage {
+            //* SAFETY: the first 'len' elements are initialized, the Vec of a spilled one drops itself
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(first_mut(buf), *len)) };
+        }
+    }
+}
+
+impl<A: Array> Deref for SmallVec<A> {
+    type Target = [A::Item];
+
+    fn deref(&self) -> &[A::Item] {
+        self.as_slice()
+    }
+}
+
+impl<A: Array> DerefMut for SmallVec<A> {
+    fn deref_mut(&mut self) -> &mut [A::Item] {
+        self.as_mut_slice()
+    }
+}
+
+pub struct IntoIter<A: Array> {
+    inner: IntoIterInner<A>,
+}
+
+enum IntoIterInner<A: Array> {
+    //* elements start..end are initialized and not yielded yet
+    Inline { buf: MaybeUninit<A>, start: usize, end: usize },
+    Heap(std::vec::IntoIter<A::Item>),
+}
+
+impl<A: Array> Iterator for IntoIter<A> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        match &mut self.inner {
+            IntoIterInner::Inline { buf, start, end } => {
+                if start == end {
+                    return None;
+                }
+                *start += 1;
+                //* SAFETY: the element at the old start is initialized and not counted any more, so it is read once
+                Some(unsafe { first(buf).add(*start - 1).read() })
+            }
+            IntoIterInner::Heap(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match &self.inner {
+            IntoIterInner::Inline { start, end, .. } => end - start,
+            IntoIterInner::Heap(iter) => iter.len(),
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<A: Array> DoubleEndedIterator for IntoIter<A> {
+    fn next_back(&mut self) -> Option<A::Item> {
+        match &mut self.inner {
+            IntoIterInner::Inline { buf, start, end } => {
+                if start == end {
+                    return None;
+                }
+                *end -= 1;
+                //* SAFETY: the element at the new end is initialized and not counted any more, so it is read once
+                Some(unsafe { first(buf).add(*end).read() })
+            }
+            IntoIterInner::Heap(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<A: Array> ExactSizeIterator for IntoIter<A> {}
+
+impl<A: Array> Drop for IntoIter<A> {
+    fn drop(&mut self) {
+        if let IntoIterInner::Inline { buf, start, end } = &mut self.inner {
+            //* SAFETY: elements start..end are initialized, they weren't yielded so they are dropped here
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(first_mut(buf).add(*start), *end - *start)) };
+        }
+    }
+}
+
+impl<A: Array> IntoIterator for SmallVec<A> {
+    type Item = A::Item;
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(mut self) -> IntoIter<A> {
+        //* The elements move to the iterator, self is dropped as an empty inline SmallVec
+        let inner = match mem::replace(&mut self.storage, Storage::Inline { len: 0, buf: MaybeUninit::uninit() }) {
+            Storage::Inline { len, buf } => IntoIterInner::Inline { buf, start: 0, end: len },
+            Storage::Heap(vec) => IntoIterInner::Heap(vec.into_iter()),
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<'a, A: Array> IntoIterator for &'a SmallVec<A> {
+    type Item = &'a A::Item;
+    type IntoIter = slice::Iter<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, A: Array> IntoIterator for &'a mut SmallVec<A> {
+    type Item = &'a mut A::Item;
+    type IntoIter = slice::IterMut<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<A: Array> FromIterator<A::Item> for SmallVec<A> {
+    fn from_iter<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+        let mut small = SmallVec::new();
+        small.extend(iter);
+        small
+    }
+}
+
+impl<A: Array> Extend<A::Item> for SmallVec<A> {
+    fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        match &mut self.storage {
+            Storage::Heap(vec) => vec.extend(iter),
+            Storage::Inline { .. } => {
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+    }
+}
+
+//* A full inline SmallVec
+impl<A: Array> From<A> for SmallVec<A> {
+    fn from(array: A) -> Self {
+        SmallVec { storage: Storage::Inline { len: A::CAPACITY, buf: MaybeUninit::new(array) } }
+    }
+}
+
+impl<A: Array> From<Vec<A::Item>> for SmallVec<A> {
+    fn from(vec: Vec<A::Item>) -> Self {
+        SmallVec::from_vec(vec)
+    }
+}
+
+impl<A: Array> Default for SmallVec<A> {
+    fn default() -> Self {
+        SmallVec::new()
+    }
+}
+
+impl<A: Array> Clone for SmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<A: Array> PartialEq for SmallVec<A>
+where
+    A::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Array> Eq for SmallVec<A> where A::Item: Eq {}
+
+impl<A: Array> Hash for SmallVec<A>
+where
+    A::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<A: Array> fmt::Debug for SmallVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+//* Word frequencies, n-grams and a few totals for one or more texts
+//* Every counter is the chapter's word count: map.entry(word).and_modify(|n| *n += 1).or_insert(1)
+
+//* A small English list for --stop-words english, other languages can be loaded from a file
+pub const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be", "because", "been", "but",
+    "by", "can", "could", "did", "do", "does", "for", "from", "had", "has", "have", "he", "her", "him", "his",
+    "how", "i", "if", "in", "into", "is", "it", "its", "just", "me", "my", "no", "not", "of", "on", "or", "our",
+    "out", "she", "so", "than", "that", "the", "their", "them", "then", "there", "these", "they", "this", "to",
+    "up", "us", "was", "we", "were", "what", "when", "which", "who", "will", "with", "would", "you", "your",
+];
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    //* Count "The" and "the" as the same word
+    pub fold_case: bool,
+    //* Compared case-insensitively, they are left out of word and n-gram counts
+    pub stop_words: HashSet<String>,
+    //* Length of the counted n-grams, 0 or 1 turns them off
+    pub ngram: usize,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { fold_case: true, stop_words: HashSet::new(), ngram: 2 }
+    }
+}
+
+impl Options {
+    pub fn english_stop_words() -> HashSet<String> {
+        ENGLISH_STOP_WORDS.iter().map(|w| w.to_string()).collect()
+    }
+
+    //* One word per line, empty lines and lines starting with '#' are ignored
+    pub fn load_stop_words<P: AsRef<Path>>(path: P) -> io::Result<HashSet<String>> {
+        let content = fs::read_to_string(path)?;
+        Ok(content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_lowercase)
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextStats {
+    options: Options,
+    words: HashMap<String, usize>,
+    ngrams: HashMap<String, usize>,
+    total_words: usize,
+    skipped_words: usize,
+    lines: usize,
+    chars: usize,
+}
+
+impl TextStats {
+    pub fn new(options: Options) -> TextStats {
+        TextStats {
+            options,
+            words: HashMap::new(),
+            ngrams: HashMap::new(),
+            total_words: 0,
+            skipped_words: 0,
+            lines: 0,
+            chars: 0,
+        }
+    }
+
+    //* Adds a whole document, n-grams never reach across two documents
+    pub fn add_text(&mut self, text: &str) {
+        self.lines += text.lines().count();
+        self.chars += text.chars().count();
+
+        let mut window: Vec<String> = Vec::with_capacity(self.options.ngram);
+        for token in tokenize(text) {
+            let lowercase = token.to_lowercase();
+            if self.options.stop_words.contains(&lowercase) {
+                self.skipped_words += 1;
+                continue;
+            }
+            let word = if self.options.fold_case { lowercase } else { token.to_string() };
+
+            if self.options.ngram > 1 {
+                if window.len() == self.options.ngram {
+                    window.remove(0);
+                }
+                window.push(word.clone());
+                if window.len() == self.options.ngram {
+                    self.ngrams.entry(window.join(" ")).and_modify(|n| *n += 1).or_insert(1);
+                }
+            }
+            self.words.entry(word).and_modify(|n| *n += 1).or_insert(1);
+            self.total_words += 1;
+        }
+    }
+
+    pub fn add_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let text = fs::read_to_string(path)?;
+        self.add_text(&text);
+        Ok(())
+    }
+
+    //* Counted words, stop words excluded
+    pub fn total_words(&self) -> usize {
+        self.total_words
+    }
+
+    pub fn skipped_words(&self) -> usize {
+        self.skipped_words
+    }
+
+    pub fn unique_words(&self) -> usize {
+        self.words.len()
+    }
+
+    //* Unique words / total words, 1.0 means no word was repeated
+    pub fn unique_ratio(&self) -> f64 {
+        if self.total_words == 0 {
+            0.0
+        } else {
+            self.words.len() as f64 / self.total_words as f64
+        }
+    }
+
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    pub fn chars(&self) -> usize {
+        self.chars
+    }
+
+    //* Looks the word up the way it was counted, i.e. folded when fold_case is set
+    pub fn count(&self, word: &str) -> usize {
+        let key = if self.options.fold_case { word.to_lowercase() } else { word.to_string() };
+        self.words.get(&key).copied().unwrap_or(0)
+    }
+
+    pub fn words(&self) -> &HashMap<String, usize> {
+        &self.words
+    }
+
+    //* The k most frequent words, ties are ordered alphabetically so the output is stable
+    pub fn top_words(&self, k: usize) -> Vec<(&str, usize)> {
+        top(&self.words, k)
+    }
+
+    //* The k most frequent n-grams, their words joined by a space
+    pub fn top_ngrams(&self, k: usize) -> Vec<(&str, usize)> {
+        top(&self.ngrams, k)
+    }
+}
+
+fn top(counts: &HashMap<String, usize>, k: usize) -> Vec<(&str, usize)> {
+    let mut sorted: Vec<(&str, usize)> = counts.iter().map(|(word, n)| (word.as_str(), *n)).collect();
+    sorted.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    sorted.truncate(k);
+    sorted
+}
+
+//* Splits a text into words without a dictionary:
+//*   - a word is a run of letters and digits, combining marks stay with their letter ("नमस्ते" is one word)
+//*   - apostrophes between letters belong to the word ("don't")
+//*   - Chinese and Japanese don't put spaces between words, there a word ends where the script changes
+//*     between Han, Hiragana and Katakana ("東京に行きます" -> "東京", "に", "行", "きます")
+//*     That is only an approximation, real segmentation needs a dictionary
+pub fn tokenize(text: &str) -> Tokens<'_> {
+    Tokens { text, pos: 0 }
+}
+
+pub struct Tokens<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = &self.text[self.pos..];
+        let (offset, first) = rest.char_indices().find(|(_, c)| c.is_alphanumeric())?;
+        let start = self.pos + offset;
+        let script = Script::of(first);
+        let mut end = start + first.len_utf8();
+
+        let mut chars = self.text[end..].chars().peekable();
+        while let Some(c) = chars.next() {
+            let continues = if c.is_alphanumeric() {
+                Script::of(c) == script
+            } else if is_combining_mark(c) {
+                true
+            } else if c == '\'' || c == '’' {
+                chars.peek().is_some_and(|next| next.is_alphabetic() && Script::of(*next) == script)
+            } else {
+                false
+            };
+            if !continues {
+                break;
+            }
+            end += c.len_utf8();
+        }
+
+        self.pos = end;
+        Some(&self.text[start..end])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Script {
+    Han,
+    Hiragana,
+    Katakana,
+    Other,
+}
+
+impl Script {
+    fn of(c: char) -> Script {
+        match c {
+            '\u{3040}'..='\u{309F}' => Script::Hiragana,
+            //* includes the prolonged sound mark 'ー'
+            '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => Script::Katakana,
+            '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{2FFFF}' => {
+                Script::Han
+            }
+            _ => Script::Other,
+        }
+    }
+}
+
+//* std has no general category lookup, these ranges cover the combining marks of the common scripts
+//* In the Indic blocks every non-alphanumeric character except the danda punctuation counts as a mark (virama, nukta, ...)
+fn is_combining_mark(c: char) -> bool {
+    match c {
+        '\u{0964}' | '\u{0965}' | '\u{0970}' => false,
+        '\u{0300}'..='\u{036F}'
+        | '\u{0483}'..='\u{0489}'
+        | '\u{0591}'..='\u{05BD}'
+        | '\u{05BF}'
+        | '\u{05C1}'..='\u{05C2}'
+        | '\u{05C4}'..='\u{05C5}'
+        | '\u{05C7}'
+        | '\u{0610}'..='\u{061A}'
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}'
+        | '\u{06DF}'..='\u{06E4}'
+        | '\u{06E7}'..='\u{06E8}'
+        | '\u{06EA}'..='\u{06ED}'
+        | '\u{0900}'..='\u{0DFF}'
+        | '\u{0E31}'
+        | '\u{0E34}'..='\u{0E3A}'
+        | '\u{0E47}'..='\u{0E4E}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{3099}'..='\u{309A}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{FE20}'..='\u{FE2F}' => !c.is_alphanumeric(),
+        _ => false,
+    }
+}
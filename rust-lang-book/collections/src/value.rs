@@ -0,0 +1,665 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+use crate::ordered_map::OrderedMap;
+
+//* The chapter's enum T { Int, Float, Text } grown into a dynamic value for loosely typed data (CSV rows, config files)
+//*
+//* Arithmetic coerces like this:
+//*     Int  op Int         Int, falls back to Float on overflow; '/' always gives a Float
+//*     Int  op Float       Float
+//*     Bool                counts as Int (true = 1) in arithmetic
+//*     Text + Text         concatenation,  Text * Int   repetition (up to MAX_TEXT_LEN bytes)
+//*     List + List         concatenation
+//*     anything else       ValueError::UnsupportedOperation, Null included
+//*
+//* Values of different types can be compared and sorted:
+//*     Null < Bool < numbers < Text < List < Map
+//* Ints and Floats compare by their numeric value (1 == 1.0), NaN sorts above all other numbers
+//*
+//* Display prints texts as they are, the literal syntax (see FromStr) quotes them:
+//*     null  true  42  -7  2.5  1e-3  "text"  'text'  [1, "two", 3.0]  {name: "Hans", "two words": 2}
+#[derive(Debug, Clone)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    List(Vec<Value>),
+    Map(OrderedMap<String, Value>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueError {
+    TypeMismatch { expected: &'static str, found: &'static str },
+    UnsupportedOperation { op: &'static str, left: &'static str, right: &'static str },
+    DivisionByZero,
+    TextTooLong { len: u128 },
+    Parse { position: usize, message: String },
+}
+
+//* Longest text 'Text * Int' creates, a bigger repetition is an error instead of a failed allocation
+pub const MAX_TEXT_LEN: usize = 1 << 30;
+
+//* Lists and maps nested deeper than this are rejected by the parser, so that its recursion can't overflow the stack
+pub const MAX_DEPTH: usize = 128;
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValueError::TypeMismatch { expected, found } => write!(f, "expected {}, found {}", expected, found),
+            ValueError::UnsupportedOperation { op, left, right } => write!(f, "cannot apply '{}' to {} and {}", op, left, right),
+            ValueError::DivisionByZero => write!(f, "division by zero"),
+            ValueError::TextTooLong { len } => write!(f, "a text of {} bytes is longer than {} bytes", len, MAX_TEXT_LEN),
+            ValueError::Parse { position, message } => write!(f, "invalid value at position {}: {}", position, message),
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Int(_) => "int",
+            Value::Float(_) => "float",
+            Value::Text(_) => "text",
+            Value::List(_) => "list",
+            Value::Map(_) => "map",
+        }
+    }
+
+    fn mismatch(&self, expected: &'static str) -> ValueError {
+        ValueError::TypeMismatch { expected, found: self.type_name() }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    pub fn is_number(&self) -> bool {
+        matches!(self, Value::Int(_) | Value::Float(_))
+    }
+
+    pub fn as_bool(&self) -> Result<bool, ValueError> {
+        match self {
+            Value::Bool(b) => Ok(*b),
+            other => Err(other.mismatch("bool")),
+        }
+    }
+
+    //* Floats are only accepted when they hold a whole number
+    pub fn as_int(&self) -> Result<i64, ValueError> {
+        match self {
+            Value::Int(i) => Ok(*i),
+            Value::Float(f) if f.fract() == 0.0 && f.abs() < 9.2e18 => Ok(*f as i64),
+            other => Err(other.mismatch("int")),
+        }
+    }
+
+    pub fn as_float(&self) -> Result<f64, ValueError> {
+        match self {
+            Value::Float(f) => Ok(*f),
+            Value::Int(i) => Ok(*i as f64),
+            other => Err(other.mismatch("float")),
+        }
+    }
+
+    pub fn as_str(&self) -> Result<&str, ValueError> {
+        match self {
+            Value::Text(s) => Ok(s),
+            other => Err(other.mismatch("text")),
+        }
+    }
+
+    pub fn as_list(&self) -> Result<&[Value], ValueError> {
+        match self {
+            Value::List(items) => Ok(items),
+            other => Err(other.mismatch("list")),
+        }
+    }
+
+    pub fn as_map(&self) -> Result<&OrderedMap<String, Value>, ValueError> {
+        match self {
+            Value::Map(map) => Ok(map),
+            other => Err(other.mismatch("map")),
+        }
+    }
+
+    //* Field of a map, None for missing fields and non-maps
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    //* Element of a list, None when out of bounds or not a list
+    pub fn at(&self, index: usize) -> Option<&Value> {
+        match self {
+            Value::List(items) => items.get(index),
+            _ => None,
+        }
+    }
+
+    //* Reads a single CSV cell or config value: literals are recognized, everything else becomes Text
+    //*     "42" -> Int, "2.5" -> Float, "true" -> Bool, "" -> Null, "Hello" -> Text("Hello")
+    pub fn infer(s: &str) -> Value {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Value::Null;
+        }
+        match trimmed.parse::<Value>() {
+            Ok(value @ Value::Null)
+            | Ok(value @ Value::Bool(_))
+            | Ok(value @ Value::Int(_))
+            | Ok(value @ Value::Float(_))
+            | Ok(value @ Value::Text(_)) => value,
+            _ => Value::Text(trimmed.to_string()),
+        }
+    }
+
+    //* The literal syntax, parses back into an equal value
+    pub fn to_literal(&self) -> String {
+        let mut out = String::new();
+        self.write_literal(&mut out);
+        out
+    }
+
+    fn write_literal(&self, out: &mut String) {
+        match self {
+            Value::Text(s) => write_quoted(s, out),
+            Value::List(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.write_literal(out);
+                }
+                out.push(']');
+            }
+            Value::Map(map) => {
+                out.push('{');
+                for (i, (key, item)) in map.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    if is_identifier(key) {
+                        out.push_str(key);
+                    } else {
+                        write_quoted(key, out);
+                    }
+                    out.push_str(": ");
+                    item.write_literal(out);
+                }
+                out.push('}');
+            }
+            scalar => out.push_str(&scalar.to_string()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Int(_) | Value::Float(_) => 2,
+            Value::Text(_) => 3,
+            Value::List(_) => 4,
+            Value::Map(_) => 5,
+        }
+    }
+
+    //* Bools take part in arithmetic as 0 and 1
+    fn numeric(&self) -> Option<Value> {
+        match self {
+            Value::Bool(b) => Some(Value::Int(i64::from(*b))),
+            Value::Int(_) | Value::Float(_) => Some(self.clone()),
+            _ => None,
+        }
+    }
+
+    fn arithmetic(
+        &self,
+        other: &Value,
+        op: &'static str,
+        int_op: fn(i64, i64) -> Option<i64>,
+        float_op: fn(f64, f64) -> f64,
+    ) -> Result<Value, ValueError> {
+        let unsupported = || ValueError::UnsupportedOperation { op, left: self.type_name(), right: other.type_name() };
+        match (self.numeric().ok_or_else(unsupported)?, other.numeric().ok_or_else(unsupported)?) {
+            (Value::Int(a), Value::Int(b)) => Ok(int_op(a, b).map_or_else(|| Value::Float(float_op(a as f64, b as f64)), Value::Int)),
+            (a, b) => Ok(Value::Float(float_op(a.as_float()?, b.as_float()?))),
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+        && !matches!(s, "null" | "true" | "false" | "inf" | "nan")
+}
+
+fn write_quoted(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null => f.pad("null"),
+            Value::Bool(b) => f.pad(if *b { "true" } else { "false" }),
+            Value::Int(i) => fmt::Display::fmt(i, f),
+            //* Whole floats keep their ".0" so they don't read back as Int, big ones use an exponent instead: 1e16
+            Value::Float(x) if x.is_nan() => f.pad("nan"),
+            Value::Float(x) if x.is_infinite() => f.pad(if *x > 0.0 { "inf" } else { "-inf" }),
+            Value::Float(x) if x.abs() >= 1e16 => f.pad(&format!("{:e}", x)),
+            Value::Float(x) if x.fract() == 0.0 => f.pad(&format!("{:.1}", x)),
+            Value::Float(x) => f.pad(&x.to_string()),
+            Value::Text(s) => f.pad(s),
+            list_or_map => f.pad(&list_or_map.to_literal()),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Null, Value::Null) => Ordering::Equal,
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Int(a), Value::Float(b)) => compare_int_float(*a, *b),
+            (Value::Float(a), Value::Int(b)) => compare_int_float(*b, *a).reverse(),
+            (Value::Float(a), Value::Float(b)) => compare_floats(*a, *b),
+            (Value::Text(a), Value::Text(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            //* Maps are compared by their entries sorted by key, the insertion order doesn't matter
+            (Value::Map(a), Value::Map(b)) => {
+                let mut a: Vec<_> = a.iter().collect();
+                let mut b: Vec<_> = b.iter().collect();
+                a.sort();
+                b.sort();
+                a.cmp(&b)
+            }
+            (a, b) => a.rank().cmp(&b.rank()),
+        }
+    }
+}
+
+//* NaN is equal to itself and greater than every other number, -0.0 == 0.0
+fn compare_floats(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+//* Exact even for ints that don't fit into a f64 without rounding
+fn compare_int_float(a: i64, b: f64) -> Ordering {
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+    if b >= i64::MAX as f64 {
+        return Ordering::Less;
+    }
+    if b < i64::MIN as f64 {
+        return Ordering::Greater;
+    }
+    let truncated = b.trunc() as i64;
+    a.cmp(&truncated).then_with(|| compare_floats(0.0, b.fract()))
+}
+
+impl Add for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn add(self, other: &Value) -> Self::Output {
+        match (self, other) {
+            (Value::Text(a), Value::Text(b)) => Ok(Value::Text(format!("{}{}", a, b))),
+            (Value::List(a), Value::List(b)) => Ok(Value::List(a.iter().chain(b).cloned().collect())),
+            _ => self.arithmetic(other, "+", i64::checked_add, |a, b| a + b),
+        }
+    }
+}
+
+impl Sub for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn sub(self, other: &Value) -> Self::Output {
+        self.arithmetic(other, "-", i64::checked_sub, |a, b| a - b)
+    }
+}
+
+impl Mul for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn mul(self, other: &Value) -> Self::Output {
+        match (self, other) {
+            (Value::Text(s), Value::Int(n)) | (Value::Int(n), Value::Text(s)) => {
+                let times = u64::try_from(*n).unwrap_or(0);
+                let len = s.len() as u128 * u128::from(times);
+                if len > MAX_TEXT_LEN as u128 {
+                    return Err(ValueError::TextTooLong { len });
+                }
+                Ok(Value::Text(s.repeat(times as usize)))
+            }
+            _ => self.arithmetic(other, "*", i64::checked_mul, |a, b| a * b),
+        }
+    }
+}
+
+impl Div for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn div(self, other: &Value) -> Self::Output {
+        if other.numeric().is_some_and(|n| n.as_float() == Ok(0.0)) && self.numeric().is_some() {
+            return Err(ValueError::DivisionByZero);
+        }
+        self.arithmetic(other, "/", |_, _| None, |a, b| a / b)
+    }
+}
+
+impl Rem for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn rem(self, other: &Value) -> Self::Output {
+        if other.numeric().is_some_and(|n| n.as_float() == Ok(0.0)) && self.numeric().is_some() {
+            return Err(ValueError::DivisionByZero);
+        }
+        self.arithmetic(other, "%", i64::checked_rem, |a, b| a % b)
+    }
+}
+
+impl Neg for &Value {
+    type Output = Result<Value, ValueError>;
+
+    fn neg(self) -> Self::Output {
+        &Value::Int(0) - self
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::Bool(b)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(i: i32) -> Value {
+        Value::Int(i64::from(i))
+    }
+}
+
+impl From<i64> for Value {
+    fn from(i: i64) -> Value {
+        Value::Int(i)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(x: f32) -> Value {
+        Value::Float(f64::from(x))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(x: f64) -> Value {
+        Value::Float(x)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Value {
+        Value::Text(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::Text(s)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Value {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(option: Option<T>) -> Value {
+        option.map_or(Value::Null, Into::into)
+    }
+}
+
+impl FromStr for Value {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { chars: s.chars().collect(), pos: 0, depth: 0 };
+        parser.skip_whitespace();
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("unexpected characters after the value"));
+        }
+        Ok(value)
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    //* lists and maps currently open
+    depth: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> ValueError {
+        ValueError::Parse { position: self.pos, message: message.to_string() }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ValueError> {
+        match self.peek() {
+            None => Err(self.error("expected a value")),
+            Some('"') | Some('\'') => self.quoted().map(Value::Text),
+            Some('[') => self.nested(Parser::list),
+            Some('{') => self.nested(Parser::map),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => self.number(),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let word = self.word();
+                match word.as_str() {
+                    "null" => Ok(Value::Null),
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "inf" => Ok(Value::Float(f64::INFINITY)),
+                    "nan" => Ok(Value::Float(f64::NAN)),
+                    _ => {
+                        self.pos -= word.chars().count();
+                        Err(self.error(&format!("unknown literal '{}', texts need quotes", word)))
+                    }
+                }
+            }
+            Some(c) => Err(self.error(&format!("unexpected '{}'", c))),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Parser) -> Result<Value, ValueError>) -> Result<Value, ValueError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("lists and maps are nested deeper than {} levels", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn word(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    //* Underscores may separate digits: 1_000_000
+    fn number(&mut self) -> Result<Value, ValueError> {
+        let start = self.pos;
+        if matches!(self.peek(), Some('-') | Some('+')) {
+            self.pos += 1;
+            if self.peek().is_some_and(|c| c.is_alphabetic()) {
+                let word = self.word();
+                let sign = if self.chars[start] == '-' { -1.0 } else { 1.0 };
+                return match word.as_str() {
+                    "inf" => Ok(Value::Float(sign * f64::INFINITY)),
+                    _ => {
+                        self.pos = start;
+                        Err(self.error("invalid number"))
+                    }
+                };
+            }
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '+' | '-')) {
+            //* a sign is only part of the number right after an exponent
+            if matches!(self.peek(), Some('+') | Some('-')) && !matches!(self.chars[self.pos - 1], 'e' | 'E') {
+                break;
+            }
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().filter(|c| **c != '_').collect();
+        let is_float = text.contains(['.', 'e', 'E']);
+        let parsed = if is_float {
+            text.parse().ok().map(Value::Float)
+        } else {
+            text.parse().ok().map(Value::Int)
+        };
+        parsed.ok_or_else(|| {
+            self.pos = start;
+            self.error(&format!("invalid number '{}'", text))
+        })
+    }
+
+    fn quoted(&mut self) -> Result<String, ValueError> {
+        let quote = self.chars[self.pos];
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("unterminated text"))?;
+            self.pos += 1;
+            match c {
+                c if c == quote => return Ok(s),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("unterminated text"))?;
+                    self.pos += 1;
+                    s.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        other => other,
+                    });
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ValueError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    //* Lists and maps allow a trailing comma
+    fn list(&mut self) -> Result<Value, ValueError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                return Ok(Value::List(items));
+            }
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {}
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn map(&mut self) -> Result<Value, ValueError> {
+        self.expect('{')?;
+        let mut map = OrderedMap::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                return Ok(Value::Map(map));
+            }
+            let key = match self.peek() {
+                Some('"') | Some('\'') => self.quoted()?,
+                Some(c) if c.is_alphabetic() || c == '_' => self.word(),
+                _ => return Err(self.error("expected a key")),
+            };
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            map.insert(key, self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {}
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+}
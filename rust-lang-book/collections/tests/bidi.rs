@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::Path;
+
+use collections::bidi::{bidi_class, display, paragraph_direction, BidiClass, Direction, Paragraph};
+
+//* Conformance tests from the Unicode Character Database, taken from the unicode-bidi 0.2.3 crate:
+//*     BidiTest-8.0.0-subset.txt           sequences of bidi classes, every paragraph direction of the bitset
+//*     BidiCharacterTest-8.0.0-subset.txt  real code points, mostly paired brackets
+//* They are the newest ones available offline, the bidi classes of the tables are from Unicode 15.1
+//* The full files are 15 MB, the subsets keep every 16th test case and the header of each file says what else
+//* Both give the levels after rule L1 and the visual order, characters removed by X9 are left out ('x')
+
+fn read(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data").join(name);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err))
+}
+
+fn removed_by_x9(c: char) -> bool {
+    use BidiClass::*;
+    matches!(bidi_class(c), RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+//* The levels and visual order the test files expect, None for the removed characters
+fn resolve(text: &str, direction: Option<Direction>) -> (u8, Vec<Option<u8>>, Vec<usize>) {
+    let paragraph = Paragraph::new(text, direction);
+    let chars: Vec<char> = text.chars().collect();
+    let levels = chars
+        .iter()
+        .zip(paragraph.display_levels())
+        .map(|(&c, &level)| if removed_by_x9(c) { None } else { Some(level) })
+        .collect();
+    let order = paragraph.visual_order().into_iter().filter(|&i| !removed_by_x9(chars[i])).collect();
+    let level = if paragraph.direction() == Direction::RightToLeft { 1 } else { 0 };
+    (level, levels, order)
+}
+
+fn parse_levels(field: &str) -> Vec<Option<u8>> {
+    field.split_whitespace().map(|level| level.parse().ok()).collect()
+}
+
+fn parse_order(field: &str) -> Vec<usize> {
+    field.split_whitespace().map(|i| i.parse().unwrap()).collect()
+}
+
+fn report(name: &str, cases: usize, expected_cases: usize, failures: &[String]) {
+    assert_eq!(cases, expected_cases, "cases in {}", name);
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases in {} failed:\n{}",
+        failures.len(),
+        cases,
+        name,
+        failures.iter().take(20).cloned().collect::<Vec<_>>().join("\n")
+    );
+}
+
+//* Any character of the class does, brackets are avoided because BidiTest.txt assumes there are none
+fn sample(class: &str) -> char {
+    match class {
+        "L" => 'a',
+        "R" => '\u{5D0}',
+        "AL" => '\u{627}',
+        "EN" => '1',
+        "ES" => '+',
+        "ET" => '$',
+        "AN" => '\u{660}',
+        "CS" => ',',
+        "NSM" => '\u{300}',
+        "BN" => '\u{AD}',
+        "B" => '\u{2029}',
+        "S" => '\t',
+        "WS" => ' ',
+        "ON" => '!',
+        "LRE" => '\u{202A}',
+        "RLE" => '\u{202B}',
+        "PDF" => '\u{202C}',
+        "LRO" => '\u{202D}',
+        "RLO" => '\u{202E}',
+        "LRI" => '\u{2066}',
+        "RLI" => '\u{2067}',
+        "FSI" => '\u{2068}',
+        "PDI" => '\u{2069}',
+        other => panic!("unknown bidi class {}", other),
+    }
+}
+
+#[test]
+fn samples_have_their_class() {
+    for class in ["L", "R", "AL", "EN", "ES", "ET", "AN", "CS", "NSM", "BN", "B", "S", "WS", "ON"] {
+        assert_eq!(format!("{:?}", bidi_class(sample(class))), class);
+    }
+    for class in ["LRE", "RLE", "PDF", "LRO", "RLO", "LRI", "RLI", "FSI", "PDI"] {
+        assert_eq!(format!("{:?}", bidi_class(sample(class))), class);
+    }
+}
+
+#[test]
+fn example_from_the_module_docs() {
+    let paragraph = Paragraph::new("Hello שלום 123!", None);
+    assert_eq!(paragraph.direction(), Direction::LeftToRight);
+    assert_eq!(paragraph.levels(), [0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 2, 2, 2, 0]);
+    assert_eq!(paragraph.display(), "Hello 123 םולש!");
+}
+
+#[test]
+fn paragraph_direction_comes_from_the_first_strong_character() {
+    assert_eq!(paragraph_direction("السلام عليكم"), Some(Direction::RightToLeft));
+    assert_eq!(paragraph_direction("123 Hello שלום"), Some(Direction::LeftToRight));
+    assert_eq!(paragraph_direction("123"), None);
+    assert_eq!(paragraph_direction(""), None);
+}
+
+#[test]
+fn runs_keep_numbers_and_points_in_order() {
+    let mixed = "Shalom is שלום (2 words?)";
+    let runs: Vec<&str> = Paragraph::new(mixed, None).runs().iter().map(|run| run.text).collect();
+    //* the number after the Hebrew word gets a level of its own (2), that keeps its digits left to right
+    assert_eq!(runs, ["Shalom is ", "שלום", " (", "2", " words?)"]);
+    assert_eq!(display(mixed), "Shalom is םולש (2 words?)");
+    //* the points stay after their letters
+    assert_eq!(display("שָׁלוֹם"), "םו\u{5B9}לש\u{5B8}\u{5C1}");
+}
+
+#[test]
+fn bidi_test() {
+    let content = read("BidiTest-8.0.0-subset.txt");
+    let mut levels = Vec::new();
+    let mut order = Vec::new();
+    let mut cases = 0;
+    let mut failures = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if let Some(field) = line.strip_prefix("@Levels:") {
+            levels = parse_levels(field);
+            continue;
+        }
+        if let Some(field) = line.strip_prefix("@Reorder:") {
+            order = parse_order(field);
+            continue;
+        }
+        if line.is_empty() || line.starts_with('@') {
+            continue;
+        }
+
+        let (input, bitset) = line.split_once(';').unwrap();
+        let text: String = input.split_whitespace().map(sample).collect();
+        let bitset = u8::from_str_radix(bitset.trim(), 16).unwrap();
+        for (bit, direction) in [(1, None), (2, Some(Direction::LeftToRight)), (4, Some(Direction::RightToLeft))] {
+            if bitset & bit == 0 {
+                continue;
+            }
+            cases += 1;
+            let (_, actual_levels, actual_order) = resolve(&text, direction);
+            if actual_levels != levels || actual_order != order {
+                failures.push(format!(
+                    "line {}: {} ({:?}): levels {:?}, order {:?}, expected {:?}, {:?}",
+                    number + 1,
+                    input,
+                    direction,
+                    actual_levels,
+                    actual_order,
+                    levels,
+                    order
+                ));
+            }
+        }
+    }
+    report("BidiTest-8.0.0-subset.txt", cases, 50_207, &failures);
+}
+
+#[test]
+fn bidi_character_test() {
+    let content = read("BidiCharacterTest-8.0.0-subset.txt");
+    let mut cases = 0;
+    let mut failures = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        assert_eq!(fields.len(), 5, "line {}", number + 1);
+        let text: String = fields[0]
+            .split_whitespace()
+            .map(|hex| char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap())
+            .collect();
+        let direction = match fields[1] {
+            "0" => Some(Direction::LeftToRight),
+            "1" => Some(Direction::RightToLeft),
+            _ => None,
+        };
+        let expected = (fields[2].parse::<u8>().unwrap(), parse_levels(fields[3]), parse_order(fields[4]));
+
+        cases += 1;
+        let actual = resolve(&text, direction);
+        if actual != expected {
+            failures.push(format!("line {}: {}: {:?}, expected {:?}", number + 1, fields[0], actual, expected));
+        }
+    }
+    report("BidiCharacterTest-8.0.0-subset.txt", cases, 5_911, &failures);
+}
@@ -0,0 +1,72 @@
+use collections::bimap::{BiMap, Overwritten};
+
+//* Both directions must hold exactly the same pairs
+fn check(map: &BiMap<u32, &str>, pairs: &[(u32, &str)]) {
+    assert_eq!(map.len(), pairs.len());
+    assert_eq!(map.right_values().len(), pairs.len());
+    for (left, right) in pairs {
+        assert_eq!(map.get_by_left(left), Some(right));
+        assert_eq!(map.get_by_right(right), Some(left));
+    }
+    let mut iterated: Vec<_> = map.iter().map(|(l, r)| (*l, *r)).collect();
+    iterated.sort_unstable();
+    assert_eq!(iterated, pairs);
+}
+
+fn map_of(pairs: &[(u32, &'static str)]) -> BiMap<u32, &'static str> {
+    pairs.iter().copied().collect()
+}
+
+#[test]
+fn insert_reports_what_it_overwrote() {
+    let mut map = map_of(&[(1, "a"), (2, "b")]);
+
+    assert_eq!(map.insert(3, "c"), Overwritten::Neither);
+    check(&map, &[(1, "a"), (2, "b"), (3, "c")]);
+
+    assert_eq!(map.insert(3, "c"), Overwritten::Pair(3, "c"));
+    check(&map, &[(1, "a"), (2, "b"), (3, "c")]);
+
+    //* 3 was paired with "c", "c" must not be found any more
+    assert_eq!(map.insert(3, "d"), Overwritten::Left(3, "c"));
+    check(&map, &[(1, "a"), (2, "b"), (3, "d")]);
+    assert_eq!(map.get_by_right("c"), None);
+
+    //* "d" was paired with 3, 3 must not be found any more
+    assert_eq!(map.insert(4, "d"), Overwritten::Right(3, "d"));
+    check(&map, &[(1, "a"), (2, "b"), (4, "d")]);
+    assert_eq!(map.get_by_left(&3), None);
+
+    //* 1 and "b" belonged to two different pairs, both are gone
+    assert_eq!(map.insert(1, "b"), Overwritten::Both((1, "a"), (2, "b")));
+    check(&map, &[(1, "b"), (4, "d")]);
+    assert!(!map.contains_left(&2) && !map.contains_right("a"));
+}
+
+#[test]
+fn insert_no_overwrite_hands_the_pair_back() {
+    let mut map = map_of(&[(1, "a")]);
+    assert_eq!(map.insert_no_overwrite(1, "b"), Err((1, "b")));
+    assert_eq!(map.insert_no_overwrite(2, "a"), Err((2, "a")));
+    assert_eq!(map.insert_no_overwrite(1, "a"), Err((1, "a")));
+    assert_eq!(map.insert_no_overwrite(2, "b"), Ok(()));
+    check(&map, &[(1, "a"), (2, "b")]);
+}
+
+#[test]
+fn removing_and_retaining_keep_both_sides_in_sync() {
+    let mut map = map_of(&[(1, "a"), (2, "b"), (3, "c"), (4, "d")]);
+    assert_eq!(map.remove_by_left(&1), Some((1, "a")));
+    assert_eq!(map.remove_by_right("b"), Some((2, "b")));
+    assert_eq!(map.remove_by_left(&1), None);
+    assert_eq!(map.remove_by_right("a"), None);
+    check(&map, &[(3, "c"), (4, "d")]);
+
+    map.retain(|left, _| left % 2 == 0);
+    check(&map, &[(4, "d")]);
+    assert_eq!(map.get_by_right("c"), None);
+
+    //* later pairs win, like repeated inserts
+    check(&map_of(&[(1, "a"), (2, "b"), (1, "c")]), &[(1, "c"), (2, "b")]);
+    check(&map_of(&[(1, "a"), (2, "a")]), &[(2, "a")]);
+}
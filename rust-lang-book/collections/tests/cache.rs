@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use collections::cache::{LfuCache, LruCache, ManualClock, Stats, TtlCache};
+
+fn keys<'a>(iter: impl Iterator<Item = (&'a i32, &'a &'static str)>) -> Vec<i32> {
+    iter.map(|(key, _)| *key).collect()
+}
+
+#[test]
+fn lru_evicts_least_recently_used() {
+    let mut cache = LruCache::new(3);
+    cache.put(1, "germany");
+    cache.put(2, "france");
+    cache.put(3, "united kingdom");
+    assert_eq!(keys(cache.iter()), vec![3, 2, 1]);
+
+    //* get makes 1 the most recently used, so 2 goes first
+    assert_eq!(cache.get(&1), Some(&"germany"));
+    assert_eq!(cache.push(4, "spain"), Some((2, "france")));
+    assert_eq!(keys(cache.iter()), vec![4, 1, 3]);
+
+    //* peek doesn't count as a use
+    assert_eq!(cache.peek(&3), Some(&"united kingdom"));
+    cache.put(5, "usa");
+    assert!(!cache.contains_key(&3));
+    assert_eq!(keys(cache.iter()), vec![5, 4, 1]);
+
+    //* updating a key makes it the most recently used and returns the old value
+    assert_eq!(cache.put(1, "GERMANY"), Some("germany"));
+    assert_eq!(cache.pop_lru(), Some((4, "spain")));
+    assert_eq!(keys(cache.iter()), vec![1, 5]);
+}
+
+#[test]
+fn lru_resize_and_pop() {
+    let mut cache: LruCache<i32, i32> = (0..5).fold(LruCache::new(5), |mut cache, i| {
+        cache.put(i, i * i);
+        cache
+    });
+    cache.resize(2);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.stats().evictions, 3);
+    assert_eq!(cache.pop(&4), Some(16));
+    assert_eq!(cache.pop(&4), None);
+    assert_eq!(cache.iter().collect::<Vec<_>>(), vec![(&3, &9)]);
+    cache.clear();
+    assert!(cache.is_empty());
+    cache.put(7, 49);
+    assert_eq!(cache.get(&7), Some(&49));
+}
+
+#[test]
+fn lfu_evicts_least_frequently_used() {
+    let mut cache = LfuCache::new(3);
+    cache.put(1, "germany");
+    cache.put(2, "france");
+    cache.put(3, "united kingdom");
+    cache.get(&1);
+    cache.get(&1);
+    cache.get(&3);
+    assert_eq!(cache.uses(&1), Some(3));
+    assert_eq!(keys(cache.iter()), vec![1, 3, 2]);
+
+    //* 2 was used least
+    assert_eq!(cache.push(4, "spain"), Some((2, "france")));
+    //* a new entry has been used only once, so it goes next
+    assert_eq!(cache.push(5, "usa"), Some((4, "spain")));
+
+    //* with equal counts the least recently used one goes
+    cache.get(&5);
+    assert_eq!(cache.uses(&5), Some(2));
+    assert_eq!(cache.uses(&3), Some(2));
+    assert_eq!(cache.push(6, "italy"), Some((3, "united kingdom")));
+    assert_eq!(cache.pop_lfu(), Some((6, "italy")));
+    assert_eq!(cache.pop_lfu(), Some((5, "usa")));
+    assert_eq!(cache.pop_lfu(), Some((1, "germany")));
+    assert_eq!(cache.pop_lfu(), None);
+}
+
+#[test]
+fn lfu_pop_keeps_lowest_count() {
+    let mut cache = LfuCache::new(3);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.get(&"b");
+    cache.put("c", 3);
+    cache.get(&"c");
+    cache.get(&"c");
+    //* removing the only entry with one use must find the next lowest count
+    assert_eq!(cache.pop(&"a"), Some(1));
+    assert_eq!(cache.pop_lfu(), Some(("b", 2)));
+    cache.resize(1);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.peek(&"c"), Some(&3));
+}
+
+#[test]
+fn ttl_entries_expire() {
+    let clock = ManualClock::new();
+    let mut cache = TtlCache::with_clock(3, Duration::from_secs(10), clock.clone());
+    cache.put(1, "germany");
+    clock.advance(Duration::from_secs(4));
+    cache.put(2, "france");
+    assert_eq!(cache.remaining(&1), Some(Duration::from_secs(6)));
+
+    clock.advance(Duration::from_secs(6));
+    assert_eq!(cache.peek(&1), None);
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.get(&2), Some(&"france"));
+    assert_eq!(cache.stats(), Stats { hits: 1, misses: 1, evictions: 0, expirations: 1 });
+
+    //* putting again starts the time again
+    clock.advance(Duration::from_secs(5));
+    cache.put(2, "FRANCE");
+    clock.advance(Duration::from_secs(9));
+    assert_eq!(cache.get(&2), Some(&"FRANCE"));
+    clock.advance(Duration::from_secs(1));
+    assert!(!cache.contains_key(&2));
+    assert_eq!(cache.purge_expired(), 1);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn ttl_evicts_oldest_when_full() {
+    let clock = ManualClock::new();
+    let mut cache = TtlCache::with_clock(2, Duration::from_secs(10), clock.clone());
+    cache.put(1, "germany");
+    clock.advance(Duration::from_secs(1));
+    cache.put(2, "france");
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(cache.push(3, "spain"), Some((1, "germany")));
+    assert_eq!(keys(cache.iter()), vec![3, 2]);
+
+    //* expired entries make room before anything is evicted
+    clock.advance(Duration::from_secs(9));
+    assert_eq!(cache.push(4, "usa"), None);
+    assert_eq!(keys(cache.iter()), vec![4, 3]);
+    assert_eq!(cache.stats().evictions, 1);
+    assert_eq!(cache.stats().expirations, 1);
+}
+
+#[test]
+fn get_or_insert_with_counts_hits_and_misses() {
+    let mut cache = LruCache::new(2);
+    let mut computed = 0;
+    for key in [1, 2, 1, 3, 1, 2] {
+        cache.get_or_insert_with(key, || {
+            computed += 1;
+            key * 10
+        });
+    }
+    assert_eq!(computed, 4);
+    assert_eq!(cache.stats(), Stats { hits: 2, misses: 4, evictions: 2, expirations: 0 });
+    assert_eq!(cache.stats().hit_ratio(), 2.0 / 6.0);
+
+    let failed: Result<&mut i32, &str> = cache.try_get_or_insert_with(9, || Err("lookup failed"));
+    assert_eq!(failed, Err("lookup failed"));
+    assert!(!cache.contains_key(&9));
+    assert_eq!(cache.try_get_or_insert_with(2, || Err("not called")), Ok(&mut 20));
+}
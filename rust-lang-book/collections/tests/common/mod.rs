@@ -0,0 +1,35 @@
+//* Shared by the test files, each one uses only some of it
+#![allow(dead_code)]
+
+use std::hash::{BuildHasherDefault, Hasher};
+
+//* Adds up the key's bytes and keeps the sum modulo HASHES, so that most keys share a hash with others
+//* Keys with the same sum collide in all 64 bits
+#[derive(Default)]
+pub struct CollidingHasher<const HASHES: u64>(u64);
+
+impl<const HASHES: u64> Hasher for CollidingHasher<HASHES> {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = self.0.wrapping_add(u64::from(*byte));
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 % HASHES
+    }
+}
+
+pub type Colliding<const HASHES: u64> = BuildHasherDefault<CollidingHasher<HASHES>>;
+
+//* xorshift64, good enough to pick operations and stays the same on every run
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
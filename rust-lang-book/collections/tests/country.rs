@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use collections::country::{Continent, Country, CountryId, UnknownCountry};
+
+fn names(countries: &[&Country]) -> Vec<&'static str> {
+    countries.iter().map(|country| country.name()).collect()
+}
+
+#[test]
+fn table_is_sorted_by_alpha2_and_codes_are_unique() {
+    let all = Country::all();
+    assert_eq!(all.len(), 249);
+
+    //* by_alpha2 is a binary search, so the rows must be strictly sorted
+    for pair in all.windows(2) {
+        assert!(pair[0].alpha2() < pair[1].alpha2(), "{} is not before {}", pair[0].alpha2(), pair[1].alpha2());
+    }
+
+    let mut alpha3 = HashSet::new();
+    let mut numeric = HashSet::new();
+    for country in all {
+        let upper = |code: &str, len: usize| code.len() == len && code.bytes().all(|b| b.is_ascii_uppercase());
+        assert!(upper(country.alpha2(), 2) && upper(country.alpha3(), 3), "{:?}", country);
+        assert!(alpha3.insert(country.alpha3()), "duplicate alpha-3 {}", country.alpha3());
+        assert!(numeric.insert(country.numeric()), "duplicate numeric {}", country.numeric());
+        assert!((1..1000).contains(&country.numeric()), "{:?}", country);
+        assert!(!country.name().is_empty());
+
+        //* every row can be found again by each of its codes
+        assert_eq!(Country::by_alpha2(&country.alpha2().to_lowercase()), Some(country));
+        assert_eq!(Country::by_alpha3(&country.alpha3().to_lowercase()), Some(country));
+        assert_eq!(Country::by_numeric(country.numeric()), Some(country));
+        assert_eq!(Country::by_code(&format!("{:03}", country.numeric())), Some(country));
+        assert_eq!(country.id().country(), country);
+    }
+}
+
+#[test]
+fn names_are_unique_after_folding() {
+    //* by_name keeps the first country for a name, a clash would make the second one unreachable by it
+    for country in Country::all() {
+        assert_eq!(Country::by_name(country.name()), Some(country), "{}", country.name());
+        assert_eq!(Country::by_name(country.official_name()), Some(country), "{}", country.official_name());
+        for alias in country.aliases() {
+            assert_eq!(Country::by_name(alias), Some(country), "{}", alias);
+        }
+    }
+}
+
+#[test]
+fn continents_cover_every_country() {
+    let total: usize = Continent::ALL.iter().map(|&continent| Country::in_continent(continent).count()).sum();
+    assert_eq!(total, Country::all().len());
+    assert!(Continent::ALL.iter().all(|&continent| Country::in_continent(continent).count() > 0));
+    assert_eq!(Country::by_alpha2("AQ").unwrap().capital(), None);
+    assert_eq!(format!("{:>15}", Continent::NorthAmerica), "  North America");
+}
+
+#[test]
+fn lookups() {
+    let germany = Country::by_alpha2("DE").unwrap();
+    assert_eq!((germany.alpha2(), germany.alpha3(), germany.numeric()), ("DE", "DEU", 276));
+    assert_eq!((germany.capital(), germany.continent()), (Some("Berlin"), Continent::Europe));
+    //* lookup takes every code and every name
+    for query in &["DE", "deu", "276", "Germany", "GERMANY", "Federal Republic of Germany"] {
+        assert_eq!(Country::lookup(query), Some(germany), "{}", query);
+    }
+    assert_eq!(Country::lookup("united kingdom").map(Country::alpha2), Some("GB"));
+    assert_eq!(Country::lookup("usa").map(Country::name), Some("United States"));
+    assert_eq!(Country::by_code(" deu "), Some(germany));
+    assert_eq!(Country::by_code("276"), Some(germany));
+    assert_eq!(Country::by_code("0276"), Some(germany));
+    assert_eq!(Country::by_code("DEUT"), None);
+    assert_eq!(Country::by_code(""), None);
+    assert_eq!(Country::by_code("99999999"), None);
+
+    //* case, accents and punctuation are ignored
+    let ivory_coast = Country::by_alpha2("CI").unwrap();
+    assert_eq!(Country::by_name("cote d'ivoire"), Some(ivory_coast));
+    assert_eq!(Country::by_name("  CÔTE D’IVOIRE "), Some(ivory_coast));
+    assert_eq!(Country::by_name("Korea, Republic of"), Country::by_name("korea republic of"));
+    assert_eq!(Country::lookup("UK"), Country::by_alpha2("GB"));
+    assert_eq!(Country::lookup("Atlantis"), None);
+
+    let id: CountryId = "germany".parse().unwrap();
+    assert_eq!((id.numeric(), id.name(), id.to_string()), (276, "Germany", "DE".to_string()));
+    assert_eq!("FR".parse::<CountryId>().unwrap().country().capital(), Some("Paris"));
+    assert_eq!(CountryId::from_numeric(0), None);
+    assert_eq!("Atlantis".parse::<CountryId>(), Err(UnknownCountry("Atlantis".to_string())));
+}
+
+#[test]
+fn search_ranks_exact_prefix_word_substring_then_typos() {
+    assert!(Country::search("").is_empty());
+    assert!(Country::search(" ,. ").is_empty());
+    assert!(Country::search("xqzw").is_empty());
+
+    //* exact matches come first, then names starting with the query
+    let results = Country::search("niger");
+    assert_eq!(names(&results[..2]), ["Niger", "Nigeria"]);
+
+    //* a word of the name starting with the query beats a plain substring
+    let results = Country::search("guinea");
+    assert_eq!(results[0].name(), "Guinea");
+    let papua = results.iter().position(|c| c.alpha2() == "PG").unwrap();
+    let bissau = results.iter().position(|c| c.alpha2() == "GW").unwrap();
+    assert!(bissau < papua, "{:?}", names(&results));
+
+    //* substrings inside a word are found too
+    assert!(names(&Country::search("land")).contains(&"Finland"));
+
+    //* small typos, up to a third of the query
+    assert_eq!(Country::search("germnay")[0].name(), "Germany");
+    assert_eq!(Country::search("Swizerland")[0].name(), "Switzerland");
+    assert_eq!(Country::search("frnce")[0].name(), "France");
+    assert_eq!(Country::search("Untied Kingdom")[0].alpha2(), "GB");
+    //* "grmny" is two edits away from "germany", one more than a five letter query allows
+    assert!(!names(&Country::search("grmny")).contains(&"Germany"));
+
+    //* official names and aliases are searched as well
+    assert_eq!(Country::search("emirates")[0].alpha2(), "AE");
+    assert_eq!(Country::search("Hellenic Republic")[0].alpha2(), "GR");
+}
+
+#[test]
+fn search_ties_are_sorted_by_name() {
+    let results = Country::search("islands");
+    assert!(results.len() > 5);
+    let exact_words: Vec<_> = results.iter().filter(|c| c.name().ends_with("Islands")).collect();
+    assert!(exact_words.windows(2).all(|pair| pair[0].name() <= pair[1].name()), "{:?}", names(&results));
+}
@@ -0,0 +1,126 @@
+use collections::multimap::{Entry, MultiMap};
+
+//* total_len() is kept up to date by every method, it has to match a count of the values
+fn check(map: &MultiMap<&str, i32>) {
+    assert_eq!(map.total_len(), map.iter_all().map(|(_, values)| values.len()).sum::<usize>());
+    assert_eq!(map.total_len(), map.iter().len());
+    assert_eq!(map.total_len(), map.iter().count());
+    assert_eq!(map.total_len(), map.values().count());
+    assert!(map.iter_all().all(|(_, values)| !values.is_empty()), "a key without values: {:?}", map);
+}
+
+fn sample() -> MultiMap<&'static str, i32> {
+    let mut map: MultiMap<_, _> = vec![("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+    map.insert_many("c", vec![4, 5, 6]);
+    map.insert_many("d", Vec::new());
+    map
+}
+
+#[test]
+fn inserting_counts_keys_and_values() {
+    let map = sample();
+    check(&map);
+    assert_eq!((map.len(), map.total_len()), (3, 6));
+    assert!(!map.contains_key("d"));
+    assert_eq!(map.get_all("a"), [1, 3]);
+    assert_eq!(map.get("c"), Some(&4));
+    assert_eq!(&map["c"], [4, 5, 6]);
+    assert_eq!(map.get_all("x"), [] as [i32; 0]);
+    assert!(map.contains("a", &3) && !map.contains("b", &3));
+}
+
+#[test]
+fn remove_value_keeps_total_len() {
+    let mut map = sample();
+    map.insert("a", 1);
+    assert_eq!(map.remove_value("a", &1), Some(1));
+    assert_eq!(map.get_all("a"), [3, 1]);
+    check(&map);
+
+    assert_eq!(map.remove_value("a", &7), None);
+    assert_eq!(map.remove_value("x", &1), None);
+    assert_eq!(map.total_len(), 6);
+
+    //* the last value takes the key with it
+    assert_eq!(map.remove_value("b", &2), Some(2));
+    assert!(!map.contains_key("b"));
+    assert_eq!((map.len(), map.total_len()), (2, 5));
+    check(&map);
+
+    assert_eq!(map.remove("c"), Some(vec![4, 5, 6]));
+    assert_eq!(map.remove("c"), None);
+    assert_eq!((map.len(), map.total_len()), (1, 2));
+    check(&map);
+}
+
+#[test]
+fn retain_keeps_total_len() {
+    let mut map = sample();
+    map.retain(|_, value| value % 2 == 0);
+    assert_eq!((map.len(), map.total_len()), (2, 3));
+    assert!(!map.contains_key("a"));
+    assert_eq!(map.get_all("c"), [4, 6]);
+    check(&map);
+
+    map.retain(|key, _| *key == "b");
+    assert_eq!((map.len(), map.total_len()), (1, 1));
+    check(&map);
+
+    map.retain(|_, _| false);
+    assert!(map.is_empty());
+    assert_eq!(map.total_len(), 0);
+    assert_eq!(map.iter().next(), None);
+}
+
+#[test]
+fn entries_keep_total_len() {
+    let mut map = sample();
+
+    *map.entry("a").or_insert(0) += 10;
+    *map.entry("e").or_insert(0) += 10;
+    assert_eq!(map.get_all("a"), [11, 3]);
+    assert_eq!(map.get_all("e"), [10]);
+    assert_eq!(map.total_len(), 7);
+    check(&map);
+
+    assert_eq!(map.entry("a").push(5), [11, 3, 5]);
+    assert_eq!(map.entry("f").push(1), [1]);
+    assert_eq!(map.total_len(), 9);
+    check(&map);
+
+    //* and_modify can change values but not how many there are
+    map.entry("c").and_modify(|values| values.reverse()).or_insert(0);
+    map.entry("g").and_modify(|values| values[0] = 0);
+    assert_eq!(map.get_all("c"), [6, 5, 4]);
+    assert!(!map.contains_key("g"));
+    check(&map);
+
+    match map.entry("c") {
+        Entry::Occupied(mut entry) => {
+            entry.push(7);
+            assert_eq!(entry.get(), [6, 5, 4, 7]);
+            assert_eq!(entry.remove(), [6, 5, 4, 7]);
+        }
+        Entry::Vacant(_) => panic!("\"c\" has values"),
+    }
+    assert_eq!((map.len(), map.total_len()), (4, 6));
+    check(&map);
+
+    match map.entry("h") {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), "h"),
+        Entry::Occupied(_) => panic!("\"h\" has no values"),
+    }
+    assert_eq!(map.total_len(), 6);
+    check(&map);
+
+    map.clear();
+    assert_eq!((map.len(), map.total_len()), (0, 0));
+}
+
+#[test]
+fn collecting_keeps_every_value_in_order() {
+    let map: MultiMap<bool, u32> = (0..10).map(|n| (n % 2 == 0, n)).collect();
+    assert_eq!((map.len(), map.total_len()), (2, 10));
+    assert_eq!(map.get_all(&true), [0, 2, 4, 6, 8]);
+    assert!(map.contains(&false, &3) && !map.contains(&false, &4));
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use collections::normalize::{canonically_equivalent, case_fold, caseless_eq, compatibility_caseless_eq, nfc, nfd, nfkc, nfkd};
+
+//* Conformance test as described in the header of the Unicode NormalizationTest.txt
+//* NormalizationTest-15.1.0.txt is the official file for the Unicode version of the tables,
+//* taken from the unicode-width 0.1.13 crate
+
+struct Line {
+    number: usize,
+    part: String,
+    columns: Vec<String>,
+}
+
+fn parse(content: &str) -> Vec<Line> {
+    let mut part = String::new();
+    let mut lines = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('@') {
+            part = name.trim().to_string();
+            continue;
+        }
+        let columns: Vec<String> = line
+            .split(';')
+            .take(5)
+            .map(|column| {
+                column
+                    .split_whitespace()
+                    .map(|hex| char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(columns.len(), 5, "line {}", i + 1);
+        lines.push(Line { number: i + 1, part: part.clone(), columns });
+    }
+    lines
+}
+
+fn check(path: &Path, lines: &[Line]) {
+    assert!(!lines.is_empty());
+
+    let mut failures = Vec::new();
+    for line in lines {
+        let c = &line.columns;
+        let expectations = [
+            ("NFC", &c[1], [0, 1, 2]),
+            ("NFC", &c[3], [3, 3, 4]),
+            ("NFD", &c[2], [0, 1, 2]),
+            ("NFD", &c[4], [3, 3, 4]),
+            ("NFKC", &c[3], [0, 1, 2]),
+            ("NFKC", &c[3], [3, 4, 4]),
+            ("NFKD", &c[4], [0, 1, 2]),
+            ("NFKD", &c[4], [3, 4, 4]),
+        ];
+        for (form, expected, sources) in expectations.iter() {
+            for &source in sources {
+                let actual = match *form {
+                    "NFC" => nfc(&c[source]),
+                    "NFD" => nfd(&c[source]),
+                    "NFKC" => nfkc(&c[source]),
+                    _ => nfkd(&c[source]),
+                };
+                if actual != **expected {
+                    failures.push(format!(
+                        "{}:{}: {}(c{}) = {:?}, expected {:?}",
+                        path.display(),
+                        line.number,
+                        form,
+                        source + 1,
+                        actual,
+                        expected
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} failures:\n{}", failures.len(), failures.iter().take(20).cloned().collect::<Vec<_>>().join("\n"));
+}
+
+fn read(name: &str) -> (PathBuf, Vec<Line>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data").join(name);
+    let content = fs::read_to_string(&path).unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    let lines = parse(&content);
+    (path, lines)
+}
+
+#[test]
+fn official_conformance() {
+    let (path, lines) = read("NormalizationTest-15.1.0.txt");
+    check(&path, &lines);
+
+    //* Every character that isn't listed in part 1 must be unchanged by all forms
+    let listed: HashSet<char> =
+        lines.iter().filter(|line| line.part.starts_with("Part1")).filter_map(|line| line.columns[0].chars().next()).collect();
+    for c in (0..=0x10FFFF).filter_map(char::from_u32).filter(|c| !listed.contains(c)) {
+        let s = c.to_string();
+        assert_eq!((nfc(&s), nfd(&s), nfkc(&s), nfkd(&s)), (s.clone(), s.clone(), s.clone(), s.clone()), "U+{:04X}", c as u32);
+    }
+}
+
+#[test]
+fn case_folding() {
+    assert_eq!(case_fold("Straße"), "strasse");
+    assert_eq!(case_fold("ΣΊΣΥΦΟΣ"), case_fold("σίσυφος"));
+    assert_eq!(case_fold("ЗДРАВСТВУЙТЕ"), "здравствуйте");
+    assert_eq!(case_fold("ﬁ"), "fi");
+    assert_eq!(case_fold("\u{130}"), "i\u{307}");
+    assert_eq!(case_fold("Ꭰ"), "Ꭰ");
+    assert_eq!(case_fold("ꭰ"), "Ꭰ");
+    assert_eq!(case_fold("שָׁלוֹם"), "שָׁלוֹם");
+}
+
+#[test]
+fn equivalence() {
+    assert!(canonically_equivalent("Dobrý den", "Dobry\u{301} den"));
+    assert!(canonically_equivalent("\u{212B}", "Å"));
+    assert!(!canonically_equivalent("ﬁ", "fi"));
+    //* The Hebrew points in a different order are still the same text
+    assert!(canonically_equivalent("\u{5E9}\u{5B8}\u{5C1}", "\u{5E9}\u{5C1}\u{5B8}"));
+    assert!(caseless_eq("DOBRY\u{301} DEN", "dobrý den"));
+    assert!(caseless_eq("STRASSE", "straße"));
+    assert!(caseless_eq("ﬁle", "FILE"));
+    assert!(!caseless_eq("Ｒｕｓｔ ①", "rust 1"));
+    assert!(compatibility_caseless_eq("Ｒｕｓｔ ①", "rust 1"));
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use collections::ordered_map::{Entry, OrderedMap};
+
+mod common;
+use common::Rng;
+
+//* Four different hashes, so that most keys share one with others
+type Colliding = common::Colliding<4>;
+
+//* The reference: a Vec in insertion order, found again by a linear scan
+fn check<S: BuildHasher>(map: &OrderedMap<u32, u64, S>, model: &[(u32, u64)]) {
+    assert_eq!(map.len(), model.len());
+    assert_eq!(map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), model);
+    for (index, (key, value)) in model.iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index), "key {}", key);
+        assert_eq!(map.get(key), Some(value));
+        assert_eq!(map.get_index(index), Some((key, value)));
+    }
+}
+
+fn random_operations<S: BuildHasher>(mut map: OrderedMap<u32, u64, S>, seed: u64) {
+    let mut rng = Rng(seed);
+    let mut model: Vec<(u32, u64)> = Vec::new();
+    let position = |model: &[(u32, u64)], key: u32| model.iter().position(|(k, _)| *k == key);
+
+    for step in 0..4000u64 {
+        let key = rng.below(64) as u32;
+        match rng.below(9) {
+            0..=2 => {
+                let old = match position(&model, key) {
+                    Some(i) => Some(std::mem::replace(&mut model[i].1, step)),
+                    None => {
+                        model.push((key, step));
+                        None
+                    }
+                };
+                assert_eq!(map.insert(key, step), old);
+            }
+            3 => {
+                let expected = position(&model, key).map(|i| model.swap_remove(i).1);
+                assert_eq!(map.swap_remove(&key), expected);
+            }
+            4 => {
+                let expected = position(&model, key).map(|i| model.remove(i).1);
+                assert_eq!(map.shift_remove(&key), expected);
+            }
+            5 => {
+                let index = rng.below(model.len() as u64 + 2) as usize;
+                let expected = if index < model.len() { Some(model.swap_remove(index)) } else { None };
+                assert_eq!(map.swap_remove_index(index), expected);
+            }
+            6 => {
+                let index = rng.below(model.len() as u64 + 2) as usize;
+                let expected = if index < model.len() { Some(model.remove(index)) } else { None };
+                assert_eq!(map.shift_remove_index(index), expected);
+            }
+            7 => match map.entry(key) {
+                Entry::Occupied(entry) if step % 2 == 0 => {
+                    let i = position(&model, key).unwrap();
+                    assert_eq!(entry.index(), i);
+                    assert_eq!(entry.remove(), model.remove(i).1);
+                }
+                entry => {
+                    *entry.or_insert(0) += step;
+                    match position(&model, key) {
+                        Some(i) => model[i].1 += step,
+                        None => model.push((key, step)),
+                    }
+                }
+            },
+            _ => {
+                if step % 50 == 0 {
+                    map.retain(|k, _| k % 3 != 0);
+                    model.retain(|(k, _)| k % 3 != 0);
+                } else {
+                    assert_eq!(map.pop(), model.pop());
+                }
+            }
+        }
+        check(&map, &model);
+    }
+
+    //* whatever is left must be found by key, not just by position
+    let reference: HashMap<u32, u64> = model.iter().copied().collect();
+    for key in 0..64 {
+        assert_eq!(map.get(&key), reference.get(&key));
+        assert_eq!(map.contains_key(&key), reference.contains_key(&key));
+    }
+}
+
+#[test]
+fn random_operations_match_a_vec() {
+    for seed in 1..=4 {
+        random_operations(OrderedMap::new(), 0x9e37_79b9_7f4a_7c15_u64.wrapping_mul(seed));
+    }
+}
+
+#[test]
+fn random_operations_with_colliding_hashes_match_a_vec() {
+    for seed in 1..=4 {
+        random_operations(OrderedMap::with_hasher(Colliding::default()), 0x9e37_79b9_7f4a_7c15_u64.wrapping_mul(seed));
+    }
+}
+
+#[test]
+fn swap_remove_moves_the_last_entry_into_the_gap() {
+    let mut map: OrderedMap<&str, i32, Colliding> = OrderedMap::with_hasher(Colliding::default());
+    for (i, key) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+        map.insert(key, i as i32);
+    }
+    assert_eq!(map.swap_remove("b"), Some(1));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "e", "c", "d"]);
+    assert_eq!(map.get_index_of("e"), Some(1));
+    assert_eq!(map.swap_remove("b"), None);
+
+    //* removing the last entry moves nothing
+    assert_eq!(map.swap_remove("d"), Some(3));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "e", "c"]);
+    assert_eq!(map.swap_remove_index(3), None);
+}
+
+#[test]
+fn shift_remove_keeps_the_order() {
+    let mut map: OrderedMap<&str, i32, Colliding> = OrderedMap::with_hasher(Colliding::default());
+    for (i, key) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+        map.insert(key, i as i32);
+    }
+    assert_eq!(map.shift_remove("b"), Some(1));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "c", "d", "e"]);
+    for (index, key) in ["a", "c", "d", "e"].iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index));
+    }
+    assert_eq!(map.shift_remove_index(0), Some(("a", 0)));
+    assert_eq!(map.shift_remove_index(3), None);
+    assert_eq!(map.first(), Some((&"c", &2)));
+
+    //* updating keeps the position
+    assert_eq!(map.insert("c", 20), Some(2));
+    assert_eq!(map.get_index_of("c"), Some(0));
+}
+
+#[test]
+fn entries_and_debug_follow_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert(3, "united kingdom".to_string());
+    map.insert(1, "germany".to_string());
+    map.insert(2, "france".to_string());
+    //* a new value for an existing key keeps its place
+    assert_eq!(map.insert(1, "uganda".to_string()), Some("germany".to_string()));
+    map.entry(2).or_insert("spain".to_string());
+    map.entry(4).or_insert("usa".to_string());
+    map.entry(3).and_modify(|val| val.push_str(" of great britain"));
+    map.entry(5).and_modify(|val| val.push_str(" never inserted"));
+    assert_eq!(format!("{:?}", map), r#"{3: "united kingdom of great britain", 1: "uganda", 2: "france", 4: "usa"}"#);
+    assert_eq!(map.get_index(1), Some((&1, &"uganda".to_string())));
+
+    let mut counts: OrderedMap<&str, u32> = OrderedMap::new();
+    for word in "the quick brown fox jumps over the lazy dog the end".split_whitespace() {
+        *counts.entry(word).or_default() += 1;
+    }
+    assert_eq!(counts.first(), Some((&"the", &3)));
+    assert_eq!(counts.len(), 9);
+}
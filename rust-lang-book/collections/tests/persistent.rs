@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use collections::persistent::{PMap, PVec};
+
+mod common;
+use common::Rng;
+
+//* 64 different hashes: keys with the same byte sum modulo 64 collide in all 64 bits,
+//* the other keys only differ in the lowest 6 bits, which pick the slots of the first two levels of the trie
+type Colliding = common::Colliding<64>;
+
+fn check_vec(vec: &PVec<u64>, model: &[u64]) {
+    assert_eq!(vec.len(), model.len());
+    assert_eq!(vec.is_empty(), model.is_empty());
+    assert_eq!(vec.iter().copied().collect::<Vec<_>>(), model);
+    assert_eq!(vec.first(), model.first());
+    assert_eq!(vec.last(), model.last());
+    assert_eq!(vec.get(model.len()), None);
+}
+
+#[test]
+fn random_operations_on_a_pvec_match_a_vec() {
+    for seed in 1..=4 {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15_u64.wrapping_mul(seed));
+        let mut vec = PVec::new();
+        let mut model = Vec::new();
+        let mut versions = Vec::new();
+
+        for step in 0..6000u64 {
+            //* mostly pushes in the first half and mostly pops in the second, so the tree grows
+            //* past two levels (more than 32 * 32 + 32 elements) and shrinks back to nothing
+            let grow = if step < 3000 { 7 } else { 3 };
+            match rng.below(10) {
+                n if n < grow => {
+                    vec = vec.push(step);
+                    model.push(step);
+                }
+                n if n < 9 => match vec.pop() {
+                    Some(popped) => {
+                        assert_eq!(model.pop().as_ref(), vec.last());
+                        vec = popped;
+                    }
+                    None => assert!(model.is_empty()),
+                },
+                _ if !model.is_empty() => {
+                    let index = rng.below(model.len() as u64) as usize;
+                    vec = vec.set(index, step);
+                    model[index] = step;
+                }
+                _ => {}
+            }
+            if step % 20 == 0 {
+                check_vec(&vec, &model);
+                for index in 0..model.len() {
+                    assert_eq!(vec[index], model[index], "index {}", index);
+                }
+            }
+            if step % 250 == 0 {
+                versions.push((vec.clone(), model.clone()));
+            }
+        }
+
+        //* older versions are untouched by everything done after them
+        for (vec, model) in &versions {
+            check_vec(vec, model);
+        }
+    }
+}
+
+#[test]
+fn pvec_pops_across_leaf_and_level_boundaries() {
+    //* 32 elements fill the tail, 32 * 32 + 32 the first two levels
+    for len in [1, 31, 32, 33, 64, 65, 1056, 1057, 1088, 1089, 32 * 32 * 32 + 32 + 1] {
+        let model: Vec<u64> = (0..len).collect();
+        let mut vec: PVec<u64> = model.iter().copied().collect();
+        let full = vec.clone();
+        for expected in (0..len).rev() {
+            assert_eq!(vec.last(), Some(&expected));
+            vec = vec.pop().unwrap();
+            //* pushing again on the popped version must not touch the version it was popped from
+            if expected % 97 == 0 {
+                let pushed = vec.push(u64::MAX);
+                assert_eq!(pushed.last(), Some(&u64::MAX));
+                assert_eq!(pushed.len(), expected as usize + 1);
+            }
+        }
+        assert!(vec.is_empty());
+        assert_eq!(vec.pop(), None);
+        check_vec(&full, &model);
+    }
+}
+
+#[test]
+fn borrows_of_old_versions_stay_valid() {
+    let v: PVec<i32> = vec![1, 2, 3].into_iter().collect();
+    let first = &v[0];
+    let w = v.push(4);
+    assert_eq!((*first, v.len()), (1, 3));
+    assert_eq!(w.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(w.set(0, 10)[0], 10);
+    assert_eq!(w[0], 1);
+    assert_eq!(w.pop(), Some(v.clone()));
+
+    let before = PMap::new().insert(1, "germany").insert(2, "france");
+    let after = before.insert(1, "uganda").remove(&2);
+    assert_eq!((before[&1], after[&1]), ("germany", "uganda"));
+    assert!(before.contains_key(&2) && !after.contains_key(&2));
+}
+
+fn check_map<S: BuildHasher>(map: &PMap<u32, u64, S>, model: &HashMap<u32, u64>) {
+    assert_eq!(map.len(), model.len());
+    let mut entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    entries.sort_unstable();
+    let mut expected: Vec<_> = model.iter().map(|(k, v)| (*k, *v)).collect();
+    expected.sort_unstable();
+    assert_eq!(entries, expected);
+    assert_eq!(map.keys().len(), model.len());
+}
+
+fn random_operations<S: BuildHasher + Clone>(empty: PMap<u32, u64, S>, seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = empty;
+    let mut model = HashMap::new();
+    let mut versions = Vec::new();
+
+    for step in 0..6000u64 {
+        let key = rng.below(1024) as u32;
+        //* more removes in the second half, so that branches are emptied and lifted again
+        let insert = if step < 3000 { 6 } else { 3 };
+        if rng.below(10) < insert {
+            map = map.insert(key, step);
+            model.insert(key, step);
+        } else {
+            let removed = map.remove(&key);
+            assert_eq!(removed.len(), map.len() - usize::from(model.remove(&key).is_some()));
+            map = removed;
+        }
+        assert_eq!(map.get(&key), model.get(&key));
+        assert_eq!(map.contains_key(&key), model.contains_key(&key));
+        if step % 50 == 0 {
+            check_map(&map, &model);
+            for key in 0..1024 {
+                assert_eq!(map.get(&key), model.get(&key), "key {}", key);
+            }
+        }
+        if step % 500 == 0 {
+            versions.push((map.clone(), model.clone()));
+        }
+    }
+
+    //* removing every key leaves nothing behind
+    for key in 0..1024 {
+        map = map.remove(&key);
+    }
+    assert!(map.is_empty());
+    assert_eq!(map.iter().count(), 0);
+
+    for (map, model) in &versions {
+        check_map(map, model);
+    }
+}
+
+#[test]
+fn random_operations_on_a_pmap_match_a_hash_map() {
+    for seed in 1..=4 {
+        random_operations(PMap::new(), 0x9e37_79b9_7f4a_7c15_u64.wrapping_mul(seed));
+    }
+}
+
+#[test]
+fn random_operations_with_colliding_hashes_match_a_hash_map() {
+    for seed in 1..=4 {
+        random_operations(PMap::with_hasher(Colliding::default()), 0x9e37_79b9_7f4a_7c15_u64.wrapping_mul(seed));
+    }
+}
+
+#[test]
+fn removing_from_a_collision_lifts_the_last_entry() {
+    //* 1, 256 and 65536 all have a byte sum of 1
+    let map: PMap<u32, &str, Colliding> = PMap::with_hasher(Colliding::default());
+    let map = map.insert(1, "a").insert(256, "b").insert(65536, "c").insert(2, "d");
+    let one = map.remove(&256).remove(&65536);
+    assert_eq!((one.len(), one.get(&1), one.get(&2)), (2, Some(&"a"), Some(&"d")));
+    let other = map.remove(&1).remove(&256);
+    assert_eq!((other.len(), other.get(&65536), other.get(&1)), (2, Some(&"c"), None));
+    //* the key is found again after being lifted, and a new colliding key goes back down
+    let again = other.insert(256, "e");
+    assert_eq!((again.get(&65536), again.get(&256)), (Some(&"c"), Some(&"e")));
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.remove(&3), map);
+}
@@ -0,0 +1,227 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::rc::Rc;
+
+use collections::small::{SmallString, SmallVec};
+
+//* These tests go through every unsafe block of src/small, run them under Miri to check those:
+//*     cargo +nightly miri test --test small
+
+//* Counts its drops, a leak or a double drop shows up in the count
+#[derive(Debug, Clone)]
+struct Counted {
+    value: u32,
+    drops: Rc<Cell<usize>>,
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+fn counted(drops: &Rc<Cell<usize>>, values: std::ops::Range<u32>) -> impl Iterator<Item = Counted> + '_ {
+    values.map(move |value| Counted { value, drops: drops.clone() })
+}
+
+fn values(small: &[Counted]) -> Vec<u32> {
+    small.iter().map(|c| c.value).collect()
+}
+
+#[test]
+fn stays_inline_until_full() {
+    let mut small: SmallVec<[u32; 4]> = SmallVec::new();
+    for i in 0..4 {
+        small.push(i);
+        assert!(!small.spilled());
+    }
+    assert_eq!(small.capacity(), 4);
+    small.push(4);
+    assert!(small.spilled());
+    assert_eq!(&*small, &[0, 1, 2, 3, 4]);
+    assert_eq!(SmallVec::<[u32; 4]>::inline_capacity(), 4);
+}
+
+#[test]
+fn push_pop_insert_remove_inline_and_spilled() {
+    for &count in &[3, 8] {
+        let mut small: SmallVec<[String; 4]> = (0..count).map(|i| i.to_string()).collect();
+        let mut vec: Vec<String> = (0..count).map(|i| i.to_string()).collect();
+        small.insert(1, "a".to_string());
+        vec.insert(1, "a".to_string());
+        small.insert(small.len(), "b".to_string());
+        vec.insert(vec.len(), "b".to_string());
+        assert_eq!(small.remove(0), vec.remove(0));
+        assert_eq!(small.pop(), vec.pop());
+        assert_eq!(small.as_slice(), vec.as_slice());
+        small.sort();
+        vec.sort();
+        assert_eq!(small.into_vec(), vec);
+    }
+    let mut empty: SmallVec<[String; 2]> = SmallVec::new();
+    assert_eq!(empty.pop(), None);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn insert_into_full_inline_spills() {
+    let mut small: SmallVec<[u8; 3]> = SmallVec::from([1, 2, 3]);
+    assert_eq!(small.len(), 3);
+    small.insert(0, 0);
+    assert!(small.spilled());
+    assert_eq!(&*small, &[0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 2) should be < len (is 2)")]
+fn remove_out_of_bounds_panics() {
+    let mut small: SmallVec<[u8; 3]> = SmallVec::from_vec(vec![1, 2]);
+    small.remove(2);
+}
+
+#[test]
+fn every_element_is_dropped_once() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut small: SmallVec<[Counted; 4]> = counted(&drops, 0..3).collect();
+        small.truncate(1);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(values(&small), vec![0]);
+        drop(small.remove(0));
+        assert_eq!(drops.get(), 3);
+        small.extend(counted(&drops, 0..3));
+    }
+    assert_eq!(drops.get(), 6);
+
+    drops.set(0);
+    {
+        let mut small: SmallVec<[Counted; 4]> = counted(&drops, 0..10).collect();
+        assert!(small.spilled());
+        small.truncate(2);
+        assert_eq!(drops.get(), 8);
+        small.shrink_to_fit();
+        assert!(!small.spilled());
+        assert_eq!(values(&small), vec![0, 1]);
+        assert_eq!(drops.get(), 8);
+        let cloned = small.clone();
+        small.clear();
+        assert_eq!(drops.get(), 10);
+        assert_eq!(values(&cloned), vec![0, 1]);
+    }
+    assert_eq!(drops.get(), 12);
+}
+
+#[test]
+fn into_iter_drops_what_it_doesnt_yield() {
+    let drops = Rc::new(Cell::new(0));
+    for &count in &[4, 9] {
+        drops.set(0);
+        let small: SmallVec<[Counted; 4]> = counted(&drops, 0..count).collect();
+        let mut iter = small.into_iter();
+        assert_eq!(iter.len(), count as usize);
+        assert_eq!(iter.next().map(|c| c.value), Some(0));
+        assert_eq!(iter.next_back().map(|c| c.value), Some(count - 1));
+        assert_eq!(drops.get(), 2);
+        drop(iter);
+        assert_eq!(drops.get(), count as usize);
+    }
+}
+
+#[test]
+fn spill_keeps_elements() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut small: SmallVec<[Counted; 2]> = SmallVec::new();
+        small.extend(counted(&drops, 0..2));
+        small.reserve(10);
+        assert!(small.spilled());
+        assert!(small.capacity() >= 12);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(values(&small), vec![0, 1]);
+    }
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn from_vec_moves_inline_if_it_fits() {
+    let small: SmallVec<[u64; 4]> = SmallVec::from_vec(vec![1, 2, 3]);
+    assert!(!small.spilled());
+    let small: SmallVec<[u64; 4]> = vec![1, 2, 3, 4, 5].into();
+    assert!(small.spilled());
+    assert_eq!(small.iter().sum::<u64>(), 15);
+}
+
+#[test]
+fn extend_from_slice_copies() {
+    let mut small: SmallVec<[u8; 8]> = SmallVec::new();
+    small.extend_from_slice(b"Hello");
+    assert!(!small.spilled());
+    small.extend_from_slice(b", world");
+    assert!(small.spilled());
+    assert_eq!(&*small, b"Hello, world");
+}
+
+#[test]
+fn zero_sized_and_zero_capacity() {
+    let mut units: SmallVec<[(); 2]> = SmallVec::new();
+    for _ in 0..5 {
+        units.push(());
+    }
+    assert_eq!(units.len(), 5);
+    assert_eq!(units.pop(), Some(()));
+
+    let mut none: SmallVec<[String; 0]> = SmallVec::new();
+    none.push("spills right away".to_string());
+    assert!(none.spilled());
+    assert_eq!(none.len(), 1);
+}
+
+#[test]
+fn small_string_keeps_utf8() {
+    let mut s: SmallString<[u8; 8]> = SmallString::from("Hallo");
+    assert!(!s.spilled());
+    s.push('!');
+    s.push('ü');
+    assert_eq!(s.len(), 8);
+    assert!(!s.spilled());
+    s.push_str(" Welt");
+    assert!(s.spilled());
+    assert_eq!(s, "Hallo!ü Welt");
+    assert_eq!(s.pop(), Some('t'));
+    s.truncate(8);
+    assert_eq!(s.pop(), Some('ü'));
+    s.shrink_to_fit();
+    assert!(!s.spilled());
+    assert_eq!(s.to_uppercase(), "HALLO!");
+    assert_eq!(s.into_string(), "Hallo!".to_string());
+}
+
+#[test]
+#[should_panic(expected = "not on a char boundary")]
+fn small_string_truncate_inside_a_char_panics() {
+    let mut s: SmallString<[u8; 8]> = SmallString::from("Grüße");
+    s.truncate(3);
+}
+
+#[test]
+fn small_string_works_like_str() {
+    let mut s: SmallString<[u8; 16]> = "Hello".chars().collect();
+    let name = "world";
+    write!(s, ", {}", name).unwrap();
+    assert_eq!(format!("{}", s), "Hello, world");
+    assert_eq!(format!("{:?}", s), "\"Hello, world\"");
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HELLO, WORLD");
+
+    let mut capitals: HashMap<SmallString<[u8; 16]>, &str> = HashMap::new();
+    capitals.insert("germany".into(), "Berlin");
+    capitals.insert(String::from("france").into(), "Paris");
+    assert_eq!(capitals.get("germany"), Some(&"Berlin"));
+
+    let long: SmallString<[u8; 4]> = String::from("united kingdom").into();
+    assert!(long.spilled());
+    let mut sorted: Vec<SmallString<[u8; 4]>> = vec!["b".into(), long.clone(), "a".into()];
+    sorted.sort();
+    assert_eq!(sorted, vec!["a", "b", "united kingdom"]);
+}
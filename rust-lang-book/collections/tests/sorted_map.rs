@@ -0,0 +1,43 @@
+use collections::sorted_map::SortedMap;
+
+fn countries() -> SortedMap<i32, String> {
+    let mut map = SortedMap::new();
+    map.insert(30, "united kingdom".to_string());
+    map.insert(10, "germany".to_string());
+    map.entry(20).or_insert("france".to_string());
+    map.entry(10).and_modify(|val| val.make_ascii_uppercase());
+    map
+}
+
+#[test]
+fn keys_stay_sorted() {
+    let map = countries();
+    assert_eq!(format!("{:?}", map), r#"{10: "GERMANY", 20: "france", 30: "united kingdom"}"#);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [10, 20, 30]);
+    assert_eq!((map.first().map(|(k, _)| *k), map.last().map(|(k, _)| *k)), (Some(10), Some(30)));
+}
+
+#[test]
+fn floor_and_ceiling_find_the_closest_keys() {
+    let map = countries();
+    assert_eq!(map.floor(&25), Some((&20, &"france".to_string())));
+    assert_eq!(map.ceiling(&25).map(|(k, _)| *k), Some(30));
+    //* an existing key is its own floor and ceiling
+    assert_eq!(map.floor(&20).map(|(k, _)| *k), Some(20));
+    assert_eq!(map.ceiling(&20).map(|(k, _)| *k), Some(20));
+    assert_eq!(map.floor(&9), None);
+    assert_eq!(map.ceiling(&31), None);
+}
+
+#[test]
+fn rank_and_nth_are_inverse() {
+    let map = countries();
+    for n in 0..map.len() {
+        let (key, _) = map.nth(n).unwrap();
+        assert_eq!(map.rank(key), n);
+    }
+    assert_eq!(map.nth(3), None);
+    //* keys that aren't in the map count the smaller ones
+    assert_eq!((map.rank(&5), map.rank(&25), map.rank(&99)), (0, 2, 3));
+    assert_eq!(map.range(15..=30).map(|(k, _)| *k).collect::<Vec<_>>(), [20, 30]);
+}
@@ -0,0 +1,119 @@
+use std::fs;
+
+use collections::text_stats::{tokenize, Options, TextStats, ENGLISH_STOP_WORDS};
+
+fn tokens(text: &str) -> Vec<&str> {
+    tokenize(text).collect()
+}
+
+#[test]
+fn words_are_runs_of_letters_and_digits() {
+    assert_eq!(tokens(""), Vec::<&str>::new());
+    assert_eq!(tokens(" \n\t.,!? -- "), Vec::<&str>::new());
+    assert_eq!(tokens("Hello, world!"), ["Hello", "world"]);
+    assert_eq!(tokens("route66 is 3.5km"), ["route66", "is", "3", "5km"]);
+    assert_eq!(tokens("e-mail foo_bar"), ["e", "mail", "foo", "bar"]);
+    assert_eq!(tokens("Grüße aus Köln"), ["Grüße", "aus", "Köln"]);
+    assert_eq!(tokens("Привет, мир"), ["Привет", "мир"]);
+}
+
+#[test]
+fn greetings() {
+    assert_eq!(tokens("नमस्ते"), ["नमस्ते"]);
+    assert_eq!(tokens("नमस्ते दुनिया। आप कैसे हैं?"), ["नमस्ते", "दुनिया", "आप", "कैसे", "हैं"]);
+    assert_eq!(tokens("こんにちは"), ["こんにちは"]);
+    assert_eq!(tokens("こんにちは、世界！"), ["こんにちは", "世界"]);
+    assert_eq!(tokens("नमस्ते, こんにちは, Hello"), ["नमस्ते", "こんにちは", "Hello"]);
+}
+
+#[test]
+fn combining_marks_stay_with_their_letter() {
+    //* decomposed "é", the acute accent is not alphanumeric itself
+    assert_eq!(tokens("cafe\u{301} au lait"), ["cafe\u{301}", "au", "lait"]);
+    assert_eq!(tokens("\u{301}abc"), ["abc"]);
+    //* virama and vowel signs in Devanagari, Bengali and Tamil
+    assert_eq!(tokens("क्षत्रिय"), ["क्षत्रिय"]);
+    assert_eq!(tokens("বাংলা ভাষা"), ["বাংলা", "ভাষা"]);
+    assert_eq!(tokens("தமிழ்நாடு"), ["தமிழ்நாடு"]);
+    //* a zero width joiner inside a conjunct doesn't split it
+    assert_eq!(tokens("क्\u{200D}ष"), ["क्\u{200D}ष"]);
+    //* the danda is punctuation, not a mark
+    assert_eq!(tokens("राम।श्याम॥"), ["राम", "श्याम"]);
+    //* dakuten in decomposed kana
+    assert_eq!(tokens("か\u{3099}"), ["か\u{3099}"]);
+}
+
+#[test]
+fn han_and_kana_are_split_where_the_script_changes() {
+    assert_eq!(tokens("こんにちは世界"), ["こんにちは", "世界"]);
+    assert_eq!(tokens("東京に行きます"), ["東京", "に", "行", "きます"]);
+    //* the prolonged sound mark belongs to Katakana
+    assert_eq!(tokens("コーヒーを飲む"), ["コーヒー", "を", "飲", "む"]);
+    assert_eq!(tokens("ﾊﾝｶｸ"), ["ﾊﾝｶｸ"]);
+    //* Latin letters and digits are a script of their own here
+    assert_eq!(tokens("Rust言語2024年"), ["Rust", "言語", "2024", "年"]);
+    assert_eq!(tokens("我爱北京"), ["我爱北京"]);
+}
+
+#[test]
+fn apostrophes_between_letters_belong_to_the_word() {
+    assert_eq!(tokens("don't 'quote'"), ["don't", "quote"]);
+    assert_eq!(tokens("it’s l’été"), ["it’s", "l’été"]);
+    assert_eq!(tokens("rock 'n' roll"), ["rock", "n", "roll"]);
+    assert_eq!(tokens("James' car"), ["James", "car"]);
+    assert_eq!(tokens("90's"), ["90's"]);
+    assert_eq!(tokens("o''clock"), ["o", "clock"]);
+    //* not before a digit or a different script
+    assert_eq!(tokens("rock'9 a'に"), ["rock", "9", "a", "に"]);
+}
+
+#[test]
+fn stop_words_are_skipped() {
+    let options = Options { stop_words: Options::english_stop_words(), ..Options::default() };
+    let mut stats = TextStats::new(options);
+    stats.add_text("The fox and THE dog. A fox is quick!");
+    assert_eq!(stats.skipped_words(), 5);
+    assert_eq!(stats.total_words(), 4);
+    assert_eq!(stats.count("the"), 0);
+    assert_eq!(stats.count("Fox"), 2);
+    assert_eq!(stats.top_words(10), [("fox", 2), ("dog", 1), ("quick", 1)]);
+    //* n-grams are built from the remaining words
+    assert_eq!(stats.top_ngrams(10), [("dog fox", 1), ("fox dog", 1), ("fox quick", 1)]);
+    assert_eq!((stats.lines(), stats.chars()), (1, 36));
+
+    assert!(ENGLISH_STOP_WORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(ENGLISH_STOP_WORDS.iter().all(|word| tokens(word) == [*word]));
+}
+
+#[test]
+fn case_folding_and_ngrams() {
+    let mut stats = TextStats::new(Options { fold_case: false, ngram: 3, ..Options::default() });
+    stats.add_text("The cat. the cat sat");
+    //* a new document starts a new n-gram window
+    stats.add_text("cat sat");
+    assert_eq!(stats.count("The"), 1);
+    assert_eq!(stats.count("the"), 1);
+    assert_eq!(stats.top_words(2), [("cat", 3), ("sat", 2)]);
+    assert_eq!(stats.top_ngrams(5), [("The cat the", 1), ("cat the cat", 1), ("the cat sat", 1)]);
+    assert_eq!(stats.unique_words(), 4);
+    assert!((stats.unique_ratio() - 4.0 / 7.0).abs() < 1e-12);
+
+    let mut stats = TextStats::new(Options { ngram: 1, ..Options::default() });
+    stats.add_text("a b a");
+    assert!(stats.top_ngrams(1).is_empty());
+    assert_eq!(TextStats::new(Options::default()).unique_ratio(), 0.0);
+}
+
+#[test]
+fn stop_words_from_a_file() {
+    let path = std::env::temp_dir().join(format!("collections-stop-words-{}.txt", std::process::id()));
+    fs::write(&path, "# German\nDer\n\n  die  \ndas\n").unwrap();
+    let stop_words = Options::load_stop_words(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(stop_words.len(), 3);
+    assert!(stop_words.contains("der") && stop_words.contains("die") && !stop_words.contains("# german"));
+
+    let mut stats = TextStats::new(Options { stop_words, ..Options::default() });
+    stats.add_text("Der Hund und die Katze");
+    assert_eq!(stats.top_words(5), [("hund", 1), ("katze", 1), ("und", 1)]);
+}
@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+
+use collections::ordered_map::OrderedMap;
+use collections::value::{Value, ValueError, MAX_DEPTH, MAX_TEXT_LEN};
+
+fn parse(literal: &str) -> Value {
+    literal.parse().unwrap_or_else(|err| panic!("{:?}: {}", literal, err))
+}
+
+fn parse_error(literal: &str) -> (usize, String) {
+    match literal.parse::<Value>() {
+        Err(ValueError::Parse { position, message }) => (position, message),
+        other => panic!("{:?} parsed as {:?}", literal, other),
+    }
+}
+
+fn map(entries: &[(&str, Value)]) -> Value {
+    Value::Map(entries.iter().map(|(k, v)| (k.to_string(), v.clone())).collect::<OrderedMap<_, _>>())
+}
+
+#[test]
+fn arithmetic_coercion() {
+    let (int, float) = (Value::Int(7), Value::Float(0.5));
+    assert_eq!(&int + &Value::Int(2), Ok(Value::Int(9)));
+    assert_eq!((&int + &Value::Int(2)).unwrap().type_name(), "int");
+    assert_eq!((&int + &float).unwrap().type_name(), "float");
+    assert_eq!(&int * &float, Ok(Value::Float(3.5)));
+    assert_eq!(&int / &Value::Int(2), Ok(Value::Float(3.5)));
+    assert_eq!(&int % &Value::Int(4), Ok(Value::Int(3)));
+    assert_eq!(-&int, Ok(Value::Int(-7)));
+
+    //* overflow falls back to Float instead of wrapping
+    assert_eq!((&Value::Int(i64::MAX) + &Value::Int(1)).unwrap(), Value::Float(2f64.powi(63)));
+    assert_eq!((&Value::Int(i64::MIN) * &Value::Int(-1)).unwrap().type_name(), "float");
+    assert_eq!((-&Value::Int(i64::MIN)).unwrap().type_name(), "float");
+
+    //* bools count as 0 and 1
+    assert_eq!(&Value::Bool(true) + &Value::Bool(true), Ok(Value::Int(2)));
+    assert_eq!(&Value::Float(1.5) * &Value::Bool(false), Ok(Value::Float(0.0)));
+
+    assert_eq!(&int / &Value::Int(0), Err(ValueError::DivisionByZero));
+    assert_eq!(&float % &Value::Float(0.0), Err(ValueError::DivisionByZero));
+    assert_eq!(&int / &Value::Bool(false), Err(ValueError::DivisionByZero));
+
+    assert_eq!(&Value::from("ab") + &Value::from("cd"), Ok(Value::from("abcd")));
+    assert_eq!(&Value::from(vec![1]) + &Value::from(vec![2, 3]), Ok(Value::from(vec![1, 2, 3])));
+    let unsupported = |op, left, right| Err(ValueError::UnsupportedOperation { op, left, right });
+    assert_eq!(&Value::Null + &int, unsupported("+", "null", "int"));
+    assert_eq!(&Value::from("a") - &Value::from("a"), unsupported("-", "text", "text"));
+    assert_eq!(&float + &Value::from(vec![1]), unsupported("+", "float", "list"));
+    assert_eq!((&Value::Null + &int).unwrap_err().to_string(), "cannot apply '+' to null and int");
+}
+
+#[test]
+fn text_repetition_is_limited() {
+    assert_eq!(&Value::from("ab") * &Value::Int(3), Ok(Value::from("ababab")));
+    assert_eq!(&Value::Int(2) * &Value::from("ab"), Ok(Value::from("abab")));
+    assert_eq!(&Value::from("ab") * &Value::Int(-3), Ok(Value::from("")));
+    assert_eq!(&Value::from("") * &Value::Int(i64::MAX), Ok(Value::from("")));
+
+    let err = (&Value::from("ab") * &Value::Int(i64::MAX)).unwrap_err();
+    assert_eq!(err, ValueError::TextTooLong { len: 2 * i64::MAX as u128 });
+    let message = format!("a text of {} bytes is longer than {} bytes", 2 * i64::MAX as u128, MAX_TEXT_LEN);
+    assert_eq!(err.to_string(), message);
+    let limit = Value::Int(MAX_TEXT_LEN as i64 + 1);
+    assert_eq!(&Value::from("a") * &limit, Err(ValueError::TextTooLong { len: MAX_TEXT_LEN as u128 + 1 }));
+}
+
+#[test]
+fn accessors() {
+    assert_eq!(Value::Float(3.0).as_int(), Ok(3));
+    assert_eq!(Value::Float(3.5).as_int(), Err(ValueError::TypeMismatch { expected: "int", found: "float" }));
+    assert!(Value::Float(1e19).as_int().is_err());
+    assert_eq!(Value::Int(3).as_float(), Ok(3.0));
+    assert_eq!(Value::Bool(true).as_int().unwrap_err().to_string(), "expected int, found bool");
+
+    let config = parse("{name: 'server', ports: [80, 443]}");
+    assert_eq!(config.get("name").and_then(|v| v.as_str().ok()), Some("server"));
+    assert_eq!(config.get("ports").and_then(|v| v.at(1)), Some(&Value::Int(443)));
+    assert_eq!(config.get("missing"), None);
+    assert_eq!(config.at(0), None);
+
+    assert_eq!(Value::infer(" 42 "), Value::Int(42));
+    assert_eq!(Value::infer("1_000"), Value::Int(1000));
+    assert_eq!(Value::infer("1.68"), Value::Float(1.68));
+    assert_eq!(Value::infer("false"), Value::Bool(false));
+    assert_eq!(Value::infer(""), Value::Null);
+    assert_eq!(Value::infer("Hello"), Value::from("Hello"));
+    assert_eq!(Value::infer("[1, 2]"), Value::from("[1, 2]"));
+}
+
+#[test]
+fn ordering_across_types() {
+    let sorted = vec![
+        Value::Null,
+        Value::Bool(false),
+        Value::Bool(true),
+        Value::Float(f64::NEG_INFINITY),
+        Value::Int(i64::MIN),
+        Value::Float(-0.5),
+        Value::Int(0),
+        Value::Float(0.5),
+        Value::Int(1),
+        Value::Int(i64::MAX),
+        Value::Float(f64::INFINITY),
+        Value::Float(f64::NAN),
+        Value::from(""),
+        Value::from("a"),
+        Value::from("b"),
+        Value::from(Vec::<i32>::new()),
+        Value::from(vec![1]),
+        Value::from(vec![1, 2]),
+        map(&[]),
+        map(&[("a", Value::Int(1))]),
+    ];
+    for (i, a) in sorted.iter().enumerate() {
+        for (j, b) in sorted.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+        }
+    }
+
+    //* ints and floats compare exactly, also where a f64 can't hold the int
+    assert_eq!(Value::Int(1), Value::Float(1.0));
+    assert_eq!(Value::Float(0.0), Value::Float(-0.0));
+    assert_eq!(Value::Float(f64::NAN), Value::Float(f64::NAN));
+    //* i64::MAX as f64 rounds up to 2^63
+    assert_eq!(Value::Int(i64::MAX).cmp(&Value::Float(i64::MAX as f64)), Ordering::Less);
+    assert_eq!(Value::Int((1 << 53) + 1).cmp(&Value::Float((1u64 << 53) as f64)), Ordering::Greater);
+    assert_eq!(Value::Int(-3).cmp(&Value::Float(-2.5)), Ordering::Less);
+
+    //* maps don't depend on the insertion order
+    let ab = map(&[("a", Value::Int(1)), ("b", Value::Int(2))]);
+    let ba = map(&[("b", Value::Int(2)), ("a", Value::Int(1))]);
+    assert_eq!(ab, ba);
+    assert!(ab < map(&[("a", Value::Int(1)), ("b", Value::Int(3))]));
+}
+
+#[test]
+fn literals_round_trip() {
+    let values = vec![
+        Value::Null,
+        Value::Bool(true),
+        Value::Int(0),
+        Value::Int(i64::MIN),
+        Value::Int(i64::MAX),
+        Value::Float(0.0),
+        Value::Float(-0.0),
+        Value::Float(2.5),
+        Value::Float(1e-7),
+        Value::Float(-3.0),
+        Value::Float(9007199254740993.0),
+        Value::Float(1e16),
+        Value::Float(-1e16),
+        Value::Float(1.2345678901234567e300),
+        Value::Float(f64::MAX),
+        Value::Float(f64::MIN_POSITIVE),
+        Value::Float(f64::INFINITY),
+        Value::Float(f64::NEG_INFINITY),
+        Value::from(""),
+        Value::from("quotes \" ' and \\ \n\t\r"),
+        Value::from("null"),
+        Value::from(vec![Value::Int(1), Value::Float(1.0), Value::from("1")]),
+        map(&[("name", Value::from("Hans")), ("two words", Value::Int(2)), ("true", Value::Null), ("", Value::Null)]),
+        map(&[("nested", map(&[("list", Value::from(vec![Value::from(Vec::<i32>::new())]))]))]),
+    ];
+    for value in values {
+        let literal = value.to_literal();
+        let parsed = parse(&literal);
+        assert_eq!(parsed, value, "{}", literal);
+        assert_eq!(parsed.type_name(), value.type_name(), "{}", literal);
+        assert_eq!(parsed.to_literal(), literal);
+    }
+    let nan = parse(&Value::Float(f64::NAN).to_literal());
+    assert!(nan.as_float().unwrap().is_nan());
+
+    //* big whole floats use an exponent, so that they don't read back as Int
+    assert_eq!(Value::Float(1e16).to_string(), "1e16");
+    assert_eq!(Value::Float(123.0).to_string(), "123.0");
+    assert_eq!(Value::Float(1e15).to_string(), "1000000000000000.0");
+    assert_eq!(format!("[{:>6}]", Value::Float(1e20)), "[  1e20]");
+
+    assert_eq!(parse("  [1, 2,] "), Value::from(vec![1, 2]));
+    assert_eq!(parse("{a: 1, a: 2}"), map(&[("a", Value::Int(2))]));
+    assert_eq!(parse("-inf"), Value::Float(f64::NEG_INFINITY));
+    assert_eq!(parse("+1_000.5e1"), Value::Float(10005.0));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse_error(""), (0, "expected a value".to_string()));
+    assert_eq!(parse_error("[1, 2,, 3]"), (6, "unexpected ','".to_string()));
+    assert_eq!(parse_error("[1 2]"), (3, "expected ',' or ']'".to_string()));
+    assert_eq!(parse_error("{a 1}"), (3, "expected ':'".to_string()));
+    assert_eq!(parse_error("{1: 2}"), (1, "expected a key".to_string()));
+    assert_eq!(parse_error("'open"), (5, "unterminated text".to_string()));
+    assert_eq!(parse_error("hello"), (0, "unknown literal 'hello', texts need quotes".to_string()));
+    assert_eq!(parse_error("1.2.3"), (0, "invalid number '1.2.3'".to_string()));
+    assert_eq!(parse_error("-infinity"), (0, "invalid number".to_string()));
+    assert_eq!(parse_error("1 2"), (2, "unexpected characters after the value".to_string()));
+    assert_eq!(
+        "[1, 2,, 3]".parse::<Value>().unwrap_err().to_string(),
+        "invalid value at position 6: unexpected ','"
+    );
+}
+
+#[test]
+fn deep_nesting_is_an_error_not_a_stack_overflow() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(nested(MAX_DEPTH).parse::<Value>().is_ok());
+    let message = format!("lists and maps are nested deeper than {} levels", MAX_DEPTH);
+    assert_eq!(parse_error(&nested(MAX_DEPTH + 1)), (MAX_DEPTH, message.clone()));
+    //* every "{a: [" opens two levels, the one past the limit starts after MAX_DEPTH / 2 of them
+    assert_eq!(parse_error(&"{a: [".repeat(100_000)), (MAX_DEPTH / 2 * 5, message));
+}
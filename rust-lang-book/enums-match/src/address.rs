@@ -0,0 +1,418 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::serializable;
+
+//* A house number with an optional suffix, e.g. 42 or 42a
+serializable! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct HouseNumber {
+        pub value: u32,
+        pub suffix: String,
+    }
+}
+
+impl HouseNumber {
+    pub fn new(value: u32) -> HouseNumber {
+        HouseNumber { value, suffix: String::new() }
+    }
+}
+
+impl fmt::Display for HouseNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.suffix)
+    }
+}
+
+//* Accepts "42", "42a", "42 A" and "42-a", the suffix is normalized to lower case
+impl FromStr for HouseNumber {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let digits: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let rest = s[digits.len()..].trim_start_matches([' ', '-']);
+
+        let value = digits.parse().map_err(|_| AddressError::InvalidNumber(s.to_string()))?;
+        if rest.chars().count() > 1 || !rest.chars().all(char::is_alphabetic) {
+            return Err(AddressError::InvalidNumber(s.to_string()));
+        }
+        Ok(HouseNumber { value, suffix: rest.to_lowercase() })
+    }
+}
+
+//* The countries whose address conventions we know
+//* 'Unknown' is used when an address doesn't name its country
+serializable! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum Country {
+        UnitedStates,
+        UnitedKingdom,
+        Germany,
+        Austria,
+        Switzerland,
+        France,
+        Netherlands,
+        Spain,
+        Italy,
+        CzechRepublic,
+        Unknown,
+    }
+}
+
+impl Country {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Country::UnitedStates => "United States",
+            Country::UnitedKingdom => "United Kingdom",
+            Country::Germany => "Germany",
+            Country::Austria => "Austria",
+            Country::Switzerland => "Switzerland",
+            Country::France => "France",
+            Country::Netherlands => "Netherlands",
+            Country::Spain => "Spain",
+            Country::Italy => "Italy",
+            Country::CzechRepublic => "Czech Republic",
+            Country::Unknown => "",
+        }
+    }
+
+    //* Recognizes English and native names as well as ISO codes, case-insensitive
+    pub fn from_name(name: &str) -> Option<Country> {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        let country = match name.as_str() {
+            "united states" | "united states of america" | "usa" | "us" | "u.s.a" | "u.s" => Country::UnitedStates,
+            "united kingdom" | "uk" | "gb" | "great britain" | "england" | "scotland" | "wales" => Country::UnitedKingdom,
+            "germany" | "deutschland" | "de" | "deu" => Country::Germany,
+            "austria" | "österreich" | "at" | "aut" => Country::Austria,
+            "switzerland" | "schweiz" | "suisse" | "ch" | "che" => Country::Switzerland,
+            "france" | "fr" | "fra" => Country::France,
+            "netherlands" | "the netherlands" | "nederland" | "nl" | "nld" => Country::Netherlands,
+            "spain" | "españa" | "es" | "esp" => Country::Spain,
+            "italy" | "italia" | "it" | "ita" => Country::Italy,
+            "czech republic" | "czechia" | "česko" | "cz" | "cze" => Country::CzechRepublic,
+            _ => return None,
+        };
+        Some(country)
+    }
+
+    //* "42 Baker Street" or "Hauptstraße 42"
+    fn number_before_street(&self) -> bool {
+        matches!(self, Country::UnitedStates | Country::UnitedKingdom | Country::France | Country::Unknown)
+    }
+
+    //* "10115 Berlin" or "London NW1 6XE"
+    fn postal_code_before_city(&self) -> bool {
+        !matches!(self, Country::UnitedStates | Country::UnitedKingdom)
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AddressError {
+    Empty,
+    MissingNumber(String),
+    InvalidNumber(String),
+    MissingCity,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::Empty => write!(f, "the address is empty"),
+            AddressError::MissingNumber(street) => write!(f, "no house number found in '{}'", street),
+            AddressError::InvalidNumber(number) => write!(f, "'{}' is not a valid house number", number),
+            AddressError::MissingCity => write!(f, "the address has no city"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+serializable! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Address {
+        pub street: String,
+        pub number: HouseNumber,
+        pub postal_code: Option<String>,
+        pub city: String,
+        //* State, county or province, only printed for countries that use it (e.g. "GA" in the US)
+        pub region: Option<String>,
+        pub country: Country,
+    }
+}
+
+impl Address {
+    //* Builds a normalized address from its parts
+    pub fn new(street: &str, number: HouseNumber, city: &str, country: Country) -> Address {
+        Address {
+            street: normalize_street(street),
+            number,
+            postal_code: None,
+            city: collapse_whitespace(city),
+            region: None,
+            country,
+        }
+    }
+
+    pub fn with_postal_code(mut self, postal_code: &str) -> Address {
+        self.postal_code = Some(normalize_postal_code(postal_code)).filter(|p| !p.is_empty());
+        self
+    }
+
+    pub fn with_region(mut self, region: &str) -> Address {
+        self.region = Some(collapse_whitespace(region)).filter(|r| !r.is_empty());
+        self
+    }
+
+    //* The first line, "42 Washington Street" or "Hauptstraße 5a"
+    pub fn street_line(&self) -> String {
+        let number = match self.country {
+            Country::UnitedKingdom => self.number.to_string().to_uppercase(),
+            _ => self.number.to_string(),
+        };
+        if self.country.number_before_street() {
+            format!("{} {}", number, self.street)
+        } else {
+            format!("{} {}", self.street, number)
+        }
+    }
+
+    //* The second line, "10115 Berlin", "London NW1 6XE" or "Atlanta, GA 30303"
+    pub fn city_line(&self) -> String {
+        let postal = self.postal_code.as_deref().unwrap_or("");
+        let line = match (self.country, &self.region) {
+            (Country::UnitedStates, Some(region)) => format!("{}, {} {}", self.city, region, postal),
+            (_, Some(region)) if self.country.postal_code_before_city() => {
+                format!("{} {}, {}", postal, self.city, region)
+            }
+            (_, Some(region)) => format!("{}, {} {}", self.city, region, postal),
+            (_, None) if self.country.postal_code_before_city() => format!("{} {}", postal, self.city),
+            (_, None) => format!("{} {}", self.city, postal),
+        };
+        line.trim().to_string()
+    }
+
+    //* The address as it would be written on an envelope, one line per entry
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![self.street_line(), self.city_line()];
+        if self.country != Country::Unknown {
+            lines.push(self.country.name().to_uppercase());
+        }
+        lines
+    }
+}
+
+//* Single line form, "Hauptstraße 5a, 10115 Berlin, Germany", which parses back to the same address
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, {}", self.street_line(), self.city_line())?;
+        if self.country != Country::Unknown {
+            write!(f, ", {}", self.country)?;
+        }
+        Ok(())
+    }
+}
+
+//* Tolerant parser for free-text addresses, parts may be separated by commas or line breaks:
+//*     "42 Washington Street, Atlanta, GA 30303, USA"
+//*     "Hauptstr. 5 a\n10115 Berlin\nDeutschland"
+//*     "221b baker st, London NW1 6XE"
+impl FromStr for Address {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<String> = s
+            .split([',', '\n', ';'])
+            .map(collapse_whitespace)
+            .filter(|p| !p.is_empty())
+            .collect();
+        if parts.is_empty() {
+            return Err(AddressError::Empty);
+        }
+
+        let mut country = Country::Unknown;
+        if parts.len() > 1 {
+            if let Some(c) = parts.last().and_then(|p| Country::from_name(p)) {
+                country = c;
+                parts.pop();
+            }
+        }
+
+        let street_part = parts.remove(0);
+        let (street, number) = split_street_and_number(&street_part)?;
+        if parts.is_empty() {
+            return Err(AddressError::MissingCity);
+        }
+
+        let mut postal_code = None;
+        let mut city = None;
+        let mut region = None;
+        //* "10115 Berlin", the layout of every country but the US and the UK, and of Country::Unknown
+        let mut code_before_city = false;
+        for part in parts {
+            let (code, rest) = split_postal_code(&part);
+            if city.is_none() && !rest.is_empty() {
+                code_before_city = code.as_deref().is_some_and(|code| part.starts_with(code));
+            }
+            if code.is_some() {
+                postal_code = code;
+            }
+            if rest.is_empty() {
+                continue;
+            }
+            if city.is_none() {
+                city = Some(rest);
+            } else if region.is_none() {
+                region = Some(rest);
+            }
+        }
+
+        //* only guessed from a US or UK layout, Display writes an unknown country's code before the city
+        if country == Country::Unknown && !code_before_city {
+            if region.is_some() && postal_code.as_deref().is_some_and(is_zip_code) {
+                country = Country::UnitedStates;
+            } else if postal_code.as_deref().is_some_and(is_uk_postcode) {
+                country = Country::UnitedKingdom;
+            }
+        }
+
+        let mut address = Address::new(&street, number, &city.ok_or(AddressError::MissingCity)?, country);
+        if let Some(code) = postal_code {
+            address = address.with_postal_code(&code);
+        }
+        if let Some(region) = region {
+            address = address.with_region(&region);
+        }
+        Ok(address)
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn looks_like_number(token: &str) -> bool {
+    token.chars().next().is_some_and(|c| c.is_ascii_digit()) && token.parse::<HouseNumber>().is_ok()
+}
+
+fn is_single_letter(token: &str) -> bool {
+    token.chars().count() == 1 && token.chars().all(char::is_alphabetic)
+}
+
+//* The number may stand in front of the street or behind it, possibly with a detached suffix ("5 a")
+fn split_street_and_number(part: &str) -> Result<(String, HouseNumber), AddressError> {
+    let tokens: Vec<&str> = part.split(' ').collect();
+    let n = tokens.len();
+    let missing = || AddressError::MissingNumber(part.to_string());
+
+    if n >= 2 && looks_like_number(tokens[0]) {
+        return Ok((tokens[1..].join(" "), tokens[0].parse()?));
+    }
+    if n >= 3 && looks_like_number(tokens[n - 2]) && is_single_letter(tokens[n - 1]) {
+        let number = format!("{}{}", tokens[n - 2], tokens[n - 1]);
+        return Ok((tokens[..n - 2].join(" "), number.parse()?));
+    }
+    if n >= 2 && looks_like_number(tokens[n - 1]) {
+        return Ok((tokens[..n - 1].join(" "), tokens[n - 1].parse()?));
+    }
+    Err(missing())
+}
+
+fn is_zip_code(code: &str) -> bool {
+    let digits = code.split('-').next().unwrap_or("");
+    digits.len() == 5 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+//* Outward code (A9, A99, A9A, AA9, AA99, AA9A) followed by an inward code (9AA)
+fn is_uk_postcode(code: &str) -> bool {
+    let mut halves = code.split(' ');
+    let (outward, inward) = match (halves.next(), halves.next(), halves.next()) {
+        (Some(o), Some(i), None) => (o, i),
+        _ => return false,
+    };
+    let o: Vec<char> = outward.chars().collect();
+    let i: Vec<char> = inward.chars().collect();
+
+    let letters = o.iter().take_while(|c| c.is_ascii_alphabetic()).count();
+    let outward_ok = (1..=2).contains(&letters)
+        && o.len() > letters
+        && o.len() <= letters + 2
+        && o[letters].is_ascii_digit()
+        && o[letters..].iter().all(|c| c.is_ascii_alphanumeric());
+    let inward_ok = i.len() == 3 && i[0].is_ascii_digit() && i[1..].iter().all(|c| c.is_ascii_alphabetic());
+    outward_ok && inward_ok
+}
+
+//* Pulls a postal code from the front ("10115 Berlin") or back ("Atlanta 30303", "London NW1 6XE") of a part
+fn split_postal_code(part: &str) -> (Option<String>, String) {
+    let tokens: Vec<&str> = part.split(' ').collect();
+    let n = tokens.len();
+    let numeric = |t: &str| t.chars().any(|c| c.is_ascii_digit()) && t.chars().all(|c| c.is_ascii_digit() || c == '-');
+
+    if n >= 2 && is_uk_postcode(&tokens[n - 2..].join(" ").to_uppercase()) {
+        return (Some(tokens[n - 2..].join(" ")), tokens[..n - 2].join(" "));
+    }
+    if numeric(tokens[0]) {
+        return (Some(tokens[0].to_string()), tokens[1..].join(" "));
+    }
+    if numeric(tokens[n - 1]) {
+        return (Some(tokens[n - 1].to_string()), tokens[..n - 1].join(" "));
+    }
+    (None, part.to_string())
+}
+
+fn normalize_postal_code(code: &str) -> String {
+    collapse_whitespace(code).to_uppercase()
+}
+
+//* Expands common abbreviations at the end of a street name and capitalizes English street types
+fn normalize_street(street: &str) -> String {
+    let mut words: Vec<String> = street.split_whitespace().map(String::from).collect();
+    if let Some(last) = words.last_mut() {
+        let expanded = match last.to_lowercase().trim_end_matches('.') {
+            "st" => Some("Street"),
+            "rd" => Some("Road"),
+            "ave" | "av" => Some("Avenue"),
+            "blvd" => Some("Boulevard"),
+            "ln" => Some("Lane"),
+            "dr" => Some("Drive"),
+            _ => None,
+        };
+        if let Some(expanded) = expanded {
+            *last = expanded.to_string();
+        }
+    }
+
+    //* German "Hauptstr." becomes "Hauptstraße"
+    if let Some(last) = words.last_mut() {
+        if last.ends_with("str.") {
+            let stem = last.trim_end_matches("str.").to_string();
+            *last = format!("{}straße", stem);
+        }
+    }
+
+    words
+        .iter()
+        .map(|w| capitalize(w))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+//* Only fully lowercase words are capitalized, "rue de Rivoli" keeps its particles
+fn capitalize(word: &str) -> String {
+    const PARTICLES: [&str; 9] = ["rue", "de", "la", "le", "du", "des", "van", "von", "der"];
+    if PARTICLES.contains(&word) || !word.chars().all(char::is_lowercase) {
+        return word.to_string();
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
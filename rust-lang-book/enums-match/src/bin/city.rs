@@ -0,0 +1,44 @@
+use std::env;
+use std::process;
+
+use enums_patterns::city::City;
+
+//* Lists the buildings of a city file grouped by city
+//* Usage: cargo run --bin city -- <file> [city]
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("Usage: {} <file> [city]", args[0]);
+        process::exit(2);
+    }
+
+    let registry = match City::load(&args[1]) {
+        Ok(registry) => registry,
+        Err(err) => {
+            eprintln!("Cannot load {}: {}", args[1], err);
+            process::exit(1);
+        }
+    };
+
+    let cities = match args.get(2) {
+        Some(city) => vec![city.as_str()],
+        None => registry.cities(),
+    };
+
+    for city in cities {
+        let buildings: Vec<_> = registry.in_city(city).collect();
+        if buildings.is_empty() {
+            println!("{}: no buildings", city);
+            continue;
+        }
+        println!("{}", city);
+        for (id, building) in buildings {
+            println!("\t{:>4} {:<15} {}", id.to_string(), building.kind().to_string(), building);
+        }
+    }
+
+    println!();
+    for (kind, count) in registry.count_by_kind() {
+        println!("{:<15} {}", kind.to_string(), count);
+    }
+}
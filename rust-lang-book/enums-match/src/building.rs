@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::address::Address;
+use crate::serializable;
+
+//* The Building enum from the chapter, every variant stands at an Address and so knows its city
+serializable! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Building {
+        School(String, Address),     // identified by name and address
+        House(Address),              // identified by its full address
+        PoliceStation(Address, i32), // identified by address and district number
+    }
+}
+
+//* The variants without their data, handy for queries and statistics
+serializable! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum BuildingKind {
+        School,
+        House,
+        PoliceStation,
+    }
+}
+
+impl Building {
+    pub fn kind(&self) -> BuildingKind {
+        match self {
+            Building::School(..) => BuildingKind::School,
+            Building::House(..) => BuildingKind::House,
+            Building::PoliceStation(..) => BuildingKind::PoliceStation,
+        }
+    }
+
+    pub fn address(&self) -> &Address {
+        match self {
+            Building::School(_, address) | Building::House(address) | Building::PoliceStation(address, _) => address,
+        }
+    }
+
+    pub fn city(&self) -> &str {
+        &self.address().city
+    }
+
+    pub fn district(&self) -> Option<i32> {
+        match self {
+            Building::PoliceStation(_, district) => Some(*district),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Building {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Building::School(name, address) => write!(f, "{}, {}", name, address),
+            Building::House(address) => write!(f, "{}", address),
+            Building::PoliceStation(address, district) => {
+                write!(f, "Police station district {}, {}", district, address)
+            }
+        }
+    }
+}
+
+impl fmt::Display for BuildingKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            BuildingKind::School => "school",
+            BuildingKind::House => "house",
+            BuildingKind::PoliceStation => "police station",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::address::Address;
+use crate::building::{Building, BuildingKind};
+
+//* Handle returned when a building is registered, stays valid until the building is demolished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BuildingId(u32);
+
+impl fmt::Display for BuildingId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+//* A registry of buildings, possibly spread over several cities
+//* Buildings are kept in the order they were added
+#[derive(Debug, Default, Clone)]
+pub struct City {
+    buildings: BTreeMap<BuildingId, Building>,
+    next_id: u32,
+}
+
+#[derive(Debug)]
+pub enum CityError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for CityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CityError::Io(err) => write!(f, "could not access the city file: {}", err),
+            CityError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for CityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CityError::Io(err) => Some(err),
+            CityError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for CityError {
+    fn from(err: io::Error) -> Self {
+        CityError::Io(err)
+    }
+}
+
+impl City {
+    pub fn new() -> City {
+        City::default()
+    }
+
+    pub fn add(&mut self, building: Building) -> BuildingId {
+        let id = BuildingId(self.next_id);
+        self.next_id += 1;
+        self.buildings.insert(id, building);
+        id
+    }
+
+    pub fn get(&self, id: BuildingId) -> Option<&Building> {
+        self.buildings.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.buildings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buildings.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (BuildingId, &Building)> {
+        self.buildings.iter().map(|(id, b)| (*id, b))
+    }
+
+    //* City names are compared case-insensitively, "atlanta" finds buildings in "Atlanta"
+    pub fn in_city<'a>(&'a self, city: &'a str) -> impl Iterator<Item = (BuildingId, &'a Building)> + 'a {
+        self.iter().filter(move |(_, b)| b.city().eq_ignore_ascii_case(city))
+    }
+
+    pub fn of_kind(&self, kind: BuildingKind) -> impl Iterator<Item = (BuildingId, &Building)> {
+        self.iter().filter(move |(_, b)| b.kind() == kind)
+    }
+
+    //* Only police stations are assigned to a district
+    pub fn in_district<'a>(&'a self, city: &'a str, district: i32) -> impl Iterator<Item = (BuildingId, &'a Building)> + 'a {
+        self.in_city(city).filter(move |(_, b)| b.district() == Some(district))
+    }
+
+    //* All city names, sorted and without duplicates, ignoring case like in_city() does
+    //* Of names that only differ in case the first in plain sort order is kept, "Atlanta" before "atlanta"
+    pub fn cities(&self) -> Vec<&str> {
+        let mut cities: Vec<&str> = self.buildings.values().map(|b| b.city()).collect();
+        cities.sort_unstable_by(|a, b| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()).then(a.cmp(b)));
+        cities.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        cities
+    }
+
+    pub fn count_by_kind(&self) -> BTreeMap<BuildingKind, usize> {
+        let mut counts = BTreeMap::new();
+        for building in self.buildings.values() {
+            *counts.entry(building.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    //* Removes the building and hands it back, None if there is no such building (anymore)
+    //* Announcing the demolition is up to the caller, the registry itself prints nothing
+    pub fn demolish(&mut self, id: BuildingId) -> Option<Building> {
+        self.buildings.remove(&id)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<City, CityError> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CityError> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+//* Text format, one building per line with fields separated by '|':
+//*     school | <name> | <address>
+//*     house  | <address>
+//*     police | <address> | <district>
+//* where <address> is anything Address::from_str accepts
+//* Blank lines and lines starting with '#' are skipped, a literal '|' or '\' is escaped with '\'
+impl FromStr for City {
+    type Err = CityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut city = City::new();
+        for (index, line) in s.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let building = parse_line(trimmed).map_err(|message| CityError::Parse { line: index + 1, message })?;
+            city.add(building);
+        }
+        Ok(city)
+    }
+}
+
+impl fmt::Display for City {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for building in self.buildings.values() {
+            match building {
+                Building::School(name, address) => {
+                    writeln!(f, "school | {} | {}", escape(name), escape(&address.to_string()))?
+                }
+                Building::House(address) => writeln!(f, "house | {}", escape(&address.to_string()))?,
+                Building::PoliceStation(address, district) => {
+                    writeln!(f, "police | {} | {}", escape(&address.to_string()), district)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Building, String> {
+    let fields = split_fields(line);
+    let field = |i: usize| fields[i].clone();
+    let address = |i: usize| fields[i].parse::<Address>().map_err(|err| err.to_string());
+    let number = |i: usize, what: &str| {
+        fields[i]
+            .parse::<i32>()
+            .map_err(|_| format!("{} '{}' is not a number", what, fields[i]))
+    };
+    let expect = |count: usize, kind: &str| {
+        if fields.len() == count {
+            Ok(())
+        } else {
+            Err(format!("a {} needs {} fields, found {}", kind, count, fields.len()))
+        }
+    };
+
+    match fields[0].to_ascii_lowercase().as_str() {
+        "school" => {
+            expect(3, "school")?;
+            Ok(Building::School(field(1), address(2)?))
+        }
+        "house" => {
+            expect(2, "house")?;
+            Ok(Building::House(address(1)?))
+        }
+        "police" => {
+            expect(3, "police station")?;
+            Ok(Building::PoliceStation(address(1)?, number(2, "district")?))
+        }
+        other => Err(format!("unknown building type '{}'", other)),
+    }
+}
+
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => fields.last_mut().unwrap().extend(chars.next()),
+            '|' => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields.iter().map(|f| f.trim().to_string()).collect()
+}
+
+fn escape(field: &str) -> String {
+    field.replace('\\', "\\\\").replace('|', "\\|")
+}
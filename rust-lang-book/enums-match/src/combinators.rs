@@ -0,0 +1,278 @@
+use std::fmt::Display;
+
+//* Combinators the standard library doesn't offer on Option and Result
+//* They cover the spots where the chapter writes out a full 'match' for a single case
+//* Logging goes to stderr, prefixed with "warning: "
+//* zip_map is called zip_with elsewhere, but std reserves that name for an unstable Option method
+
+/// Extension methods for [`Option`].
+pub trait OptionExt<T> {
+    /// Runs `f` if the option is `None` and passes the option on unchanged.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionExt;
+    ///
+    /// let mut missing = 0;
+    /// let name: Option<&str> = None.inspect_none(|| missing += 1);
+    /// assert_eq!(name, None);
+    /// assert_eq!(Some(3).inspect_none(|| missing += 1), Some(3));
+    /// assert_eq!(missing, 1);
+    /// ```
+    fn inspect_none<F: FnOnce()>(self, f: F) -> Option<T>;
+
+    /// Combines two options with `f` if both are `Some`.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionExt;
+    ///
+    /// let street = Some("Washington Street");
+    /// assert_eq!(street.zip_map(Some(42), |s, n| format!("{} {}", n, s)), Some(String::from("42 Washington Street")));
+    /// assert_eq!(street.zip_map(None::<i32>, |s, n| format!("{} {}", n, s)), None);
+    /// ```
+    fn zip_map<U, R, F: FnOnce(T, U) -> R>(self, other: Option<U>, f: F) -> Option<R>;
+
+    /// Returns the contained value or `T::default()`, printing `message` to stderr for `None`.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionExt;
+    ///
+    /// let port: Option<u16> = None;
+    /// assert_eq!(port.unwrap_or_default_with_warning("no port configured"), 0);
+    /// assert_eq!(Some(8080).unwrap_or_default_with_warning("no port configured"), 8080);
+    /// ```
+    fn unwrap_or_default_with_warning(self, message: &str) -> T
+    where
+        T: Default;
+
+    /// Turns the option into a `Result`, printing `err` to stderr for `None`.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionExt;
+    ///
+    /// let city: Option<&str> = None;
+    /// assert_eq!(city.ok_or_log("city is missing"), Err("city is missing"));
+    /// assert_eq!(Some("Atlanta").ok_or_log("city is missing"), Ok("Atlanta"));
+    /// ```
+    fn ok_or_log<E: Display>(self, err: E) -> Result<T, E>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn inspect_none<F: FnOnce()>(self, f: F) -> Option<T> {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+
+    fn zip_map<U, R, F: FnOnce(T, U) -> R>(self, other: Option<U>, f: F) -> Option<R> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            _ => None,
+        }
+    }
+
+    fn unwrap_or_default_with_warning(self, message: &str) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Some(value) => value,
+            None => {
+                eprintln!("warning: {}", message);
+                T::default()
+            }
+        }
+    }
+
+    fn ok_or_log<E: Display>(self, err: E) -> Result<T, E> {
+        match self {
+            Some(value) => Ok(value),
+            None => {
+                eprintln!("warning: {}", err);
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Extension methods for [`Result`].
+pub trait ResultExt<T, E> {
+    /// Turns the result into an `Option`, printing the error with `context` to stderr.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::ResultExt;
+    ///
+    /// assert_eq!("42".parse::<i32>().ok_or_log("house number"), Some(42));
+    /// assert_eq!("4x2".parse::<i32>().ok_or_log("house number"), None); // warning: house number: invalid digit found in string
+    /// ```
+    fn ok_or_log(self, context: &str) -> Option<T>
+    where
+        E: Display;
+
+    /// Runs `f` with a reference to the error and passes the result on unchanged.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::ResultExt;
+    ///
+    /// let mut errors = Vec::new();
+    /// let district = "two".parse::<i32>().inspect_error(|err| errors.push(err.to_string()));
+    /// assert!(district.is_err());
+    /// assert_eq!(errors, vec!["invalid digit found in string"]);
+    /// ```
+    fn inspect_error<F: FnOnce(&E)>(self, f: F) -> Result<T, E>;
+
+    /// Combines two results with `f` if both are `Ok`, otherwise returns the first error.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::ResultExt;
+    ///
+    /// let x = "3".parse::<i32>();
+    /// let y = "4".parse::<i32>();
+    /// assert_eq!(x.clone().zip_map(y, |x, y| x * y), Ok(12));
+    /// assert!(x.zip_map("four".parse::<i32>(), |x, y| x * y).is_err());
+    /// ```
+    fn zip_map<U, R, F: FnOnce(T, U) -> R>(self, other: Result<U, E>, f: F) -> Result<R, E>;
+
+    /// Returns the contained value or `T::default()`, printing the error with `context` to stderr.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::ResultExt;
+    ///
+    /// let district: i32 = "second".parse().unwrap_or_default_with_warning("district");
+    /// assert_eq!(district, 0);
+    /// ```
+    fn unwrap_or_default_with_warning(self, context: &str) -> T
+    where
+        T: Default,
+        E: Display;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    fn ok_or_log(self, context: &str) -> Option<T>
+    where
+        E: Display,
+    {
+        match self {
+            Ok(value) => Some(value),
+            Err(err) => {
+                eprintln!("warning: {}: {}", context, err);
+                None
+            }
+        }
+    }
+
+    fn inspect_error<F: FnOnce(&E)>(self, f: F) -> Result<T, E> {
+        if let Err(err) = &self {
+            f(err);
+        }
+        self
+    }
+
+    fn zip_map<U, R, F: FnOnce(T, U) -> R>(self, other: Result<U, E>, f: F) -> Result<R, E> {
+        Ok(f(self?, other?))
+    }
+
+    fn unwrap_or_default_with_warning(self, context: &str) -> T
+    where
+        T: Default,
+        E: Display,
+    {
+        self.ok_or_log(context).unwrap_or_default()
+    }
+}
+
+/// Extension methods for iterators over options.
+pub trait OptionIteratorExt<T>: Iterator<Item = Option<T>> + Sized {
+    /// Skips the `None`s and maps the `Some` values with `f`, keeping only the `Some` results.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionIteratorExt;
+    ///
+    /// let answers = vec![Some("1"), None, Some("two"), Some("3")];
+    /// let numbers: Vec<i32> = answers.into_iter().filter_map_some(|s| s.parse().ok()).collect();
+    /// assert_eq!(numbers, vec![1, 3]);
+    /// ```
+    fn filter_map_some<U, F: FnMut(T) -> Option<U>>(self, f: F) -> std::iter::FilterMap<std::iter::Flatten<Self>, F> {
+        self.flatten().filter_map(f)
+    }
+
+    /// Collects the `Some` values, printing how many `None`s were dropped to stderr.
+    ///
+    /// ```
+    /// use enums_patterns::combinators::OptionIteratorExt;
+    ///
+    /// let found = vec![Some(1), None, Some(3)].into_iter().collect_some_with_warning("missing numbers");
+    /// assert_eq!(found, vec![1, 3]); // warning: missing numbers: 1 of 3 values
+    /// ```
+    fn collect_some_with_warning(self, message: &str) -> Vec<T> {
+        let mut total = 0;
+        let values: Vec<T> = self.inspect(|_| total += 1).flatten().collect();
+        if values.len() < total {
+            eprintln!("warning: {}: {} of {} values", message, total - values.len(), total);
+        }
+        values
+    }
+}
+
+impl<T, I: Iterator<Item = Option<T>>> OptionIteratorExt<T> for I {}
+
+/// Matches several values at once, one pattern per value.
+///
+/// Arms list the patterns separated by commas and may have a guard,
+/// an optional `else` arm at the end catches everything else.
+///
+/// ```
+/// use enums_patterns::multi_match;
+///
+/// fn describe(city: Option<&str>, district: Option<i32>) -> String {
+///     multi_match!(city, district;
+///         Some(city), Some(1) => format!("{}, city center", city),
+///         Some(city), Some(d) if d < 0 => format!("{}, invalid district {}", city, d),
+///         Some(city), Some(d) => format!("{}, district {}", city, d),
+///         Some(city), None => city.to_string(),
+///         else => String::from("somewhere"),
+///     )
+/// }
+///
+/// assert_eq!(describe(Some("Atlanta"), Some(1)), "Atlanta, city center");
+/// assert_eq!(describe(Some("Atlanta"), Some(-2)), "Atlanta, invalid district -2");
+/// assert_eq!(describe(Some("Atlanta"), None), "Atlanta");
+/// assert_eq!(describe(None, Some(3)), "somewhere");
+/// ```
+///
+/// Without an `else` arm the patterns have to be exhaustive, just like in a `match`:
+///
+/// ```
+/// use enums_patterns::multi_match;
+///
+/// let fizzbuzz = |n: u32| multi_match!(n % 3, n % 5;
+///     0, 0 => String::from("FizzBuzz"),
+///     0, _ => String::from("Fizz"),
+///     _, 0 => String::from("Buzz"),
+///     _, _ => n.to_string(),
+/// );
+/// assert_eq!((1..=5).map(fizzbuzz).collect::<Vec<_>>(), vec!["1", "2", "Fizz", "4", "Buzz"]);
+/// assert_eq!(fizzbuzz(15), "FizzBuzz");
+/// ```
+#[macro_export]
+macro_rules! multi_match {
+    //* The arms are collected one by one, so that the catch-all 'else' can be told apart from patterns
+    (@arms $values:tt [$($acc:tt)*]) => {
+        match $values {
+            $($acc)*
+        }
+    };
+    (@arms $values:tt [$($acc:tt)*] else => $default:expr $(,)?) => {
+        match $values {
+            $($acc)*
+            _ => $default,
+        }
+    };
+    (@arms $values:tt [$($acc:tt)*] $($pat:pat),+ $(if $guard:expr)? => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::multi_match!(@arms $values [$($acc)* ($($pat,)+) $(if $guard)? => $body,] $($($rest)*)?)
+    };
+
+    ($($value:expr),+ ; $($arms:tt)*) => {
+        $crate::multi_match!(@arms ($($value,)+) [] $($arms)*)
+    };
+}
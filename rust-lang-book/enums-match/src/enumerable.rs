@@ -0,0 +1,132 @@
+use std::fmt;
+
+//* Enums without data ('fieldless' enums) are plain lists of names
+//* 'enumerable!' wraps such an enum definition and adds everything needed to list, print and parse it:
+//*     Enum::ALL, Enum::count(), Enum::iter()      every variant in declaration order
+//*     Display / name()                            the variant name, e.g. "PoliceStation"
+//*     FromStr                                     case-insensitive, '-', '_' and ' ' are ignored:
+//*                                                 "PoliceStation", "police-station" and "POLICE_STATION" all parse
+//*     TryFrom<u8>                                 the discriminant, explicit values like 'Bank = 7' are respected
+//*
+//*     enumerable! {
+//*         #[derive(Debug, Clone, Copy, PartialEq)]
+//*         pub enum Building { School, Bank, PoliceStation }
+//*     }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    pub enum_name: &'static str,
+    pub input: String,
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a valid {}, expected one of: {}",
+            self.input,
+            self.enum_name,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDiscriminant {
+    pub enum_name: &'static str,
+    pub value: u8,
+}
+
+impl fmt::Display for InvalidDiscriminant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not a discriminant of {}", self.value, self.enum_name)
+    }
+}
+
+impl std::error::Error for InvalidDiscriminant {}
+
+//* Compares names the way the generated FromStr does
+pub fn names_match(input: &str, name: &str) -> bool {
+    let normalize = |s: &str| -> Vec<char> {
+        s.chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    normalize(input) == normalize(name)
+}
+
+#[macro_export]
+macro_rules! enumerable {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident $(= $discriminant:expr)? ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $( $(#[$vmeta])* $variant $(= $discriminant)? ),*
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub const ALL: [$name; [$(stringify!($variant)),*].len()] = [$($name::$variant),*];
+            pub const NAMES: [&'static str; [$(stringify!($variant)),*].len()] = [$(stringify!($variant)),*];
+
+            pub const fn count() -> usize {
+                Self::ALL.len()
+            }
+
+            pub fn iter() -> impl Iterator<Item = $name> {
+                std::iter::IntoIterator::into_iter(Self::ALL)
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( $name::$variant => stringify!($variant), )*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::enumerable::ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let trimmed = s.trim();
+                $(
+                    if $crate::enumerable::names_match(trimmed, stringify!($variant)) {
+                        return Ok($name::$variant);
+                    }
+                )*
+                Err($crate::enumerable::ParseEnumError {
+                    enum_name: stringify!($name),
+                    input: s.to_string(),
+                    expected: &$name::NAMES,
+                })
+            }
+        }
+
+        impl std::convert::TryFrom<u8> for $name {
+            type Error = $crate::enumerable::InvalidDiscriminant;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                $(
+                    if $name::$variant as isize == value as isize {
+                        return Ok($name::$variant);
+                    }
+                )*
+                Err($crate::enumerable::InvalidDiscriminant { enum_name: stringify!($name), value })
+            }
+        }
+    };
+}
@@ -0,0 +1,7 @@
+pub mod address;
+pub mod building;
+pub mod city;
+pub mod combinators;
+pub mod enumerable;
+pub mod serial;
+pub mod state_machine;
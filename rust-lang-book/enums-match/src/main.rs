@@ -93,19 +93,17 @@ fn main() {
     }
 
     //* A match allows us to handle Option<T> in the same way
+    //* Turning None into "" takes a whole match: match opt { Some(name) => name, None => "" }
+    //* src/combinators.rs adds a one-liner for it, which also warns about the missing value
     let name = Some("Hans");
     let no_name: Option<&str> = None;
+    {
+        use enums_patterns::combinators::OptionExt;
 
-    fn match_option(opt: Option<&str>) -> &str {
-        match opt {
-            Some(name) => name, 
-            None => ""
-        }
+        let _name = name.unwrap_or_default_with_warning("no name given"); // _name == "Hans"
+        let _empty = no_name.unwrap_or_default_with_warning("no name given"); // _empty == "", warns on stderr
     }
 
-    let _name = match_option(name); // _name == "Hans";
-    let _empty = match_option(no_name); // _empty == ""
-
     //* For very specific patterns, match tends to inflate the code a lot
     //* The code below only wants to match a single value, otherwise do nothing
     let some_number = Some(3);
@@ -127,4 +125,266 @@ fn main() {
     } else {
         println!("Not one");
     }
-}
\ No newline at end of file
+
+    //* Building::House(String, String, i32) relies on remembering which string is the city and which the street
+    //* The Address struct in src/address.rs names these parts, the library's Building::House holds one
+    //* Addresses are parsed from free text and printed according to the conventions of their country
+    use enums_patterns::address::{Address, Country, HouseNumber};
+    {
+        let berlin: Address = "hauptstr. 5 A\n10115 Berlin\nDeutschland".parse().unwrap();
+        println!("{:?} number {} in {:?} {}", berlin.street, berlin.number, berlin.postal_code, berlin.city);
+        println!("{}", berlin); // written the German way, like in the country it was recognized as
+
+        let london: Address = "221b baker st, London NW1 6XE".parse().unwrap();
+        println!("{:?}", london.lines()); // the country is recognized by its postcode
+
+        let atlanta = Address::new("Washington Street", HouseNumber::new(42), "Atlanta", Country::UnitedStates)
+            .with_region("GA")
+            .with_postal_code("30303");
+        println!("{}", atlanta);
+
+        //* The printed form parses back into the same address, tests/address.rs checks that
+        for address in &[&berlin, &london, &atlanta] {
+            println!("{} parses back: {}", address, address.to_string().parse::<Address>().as_ref() == Ok(*address));
+        }
+
+        if let Err(err) = "Washington Street, Atlanta".parse::<Address>() {
+            println!("Invalid address: {}", err);
+        }
+    }
+
+    //* The Building enum becomes really useful once many buildings are managed together
+    //* src/city.rs keeps a registry of buildings that can be queried by city, variant and district
+    //* The binary in src/bin/city.rs lists a registry file, try "cargo run --bin city -- city.txt"
+    {
+        use enums_patterns::building::{Building, BuildingKind};
+        use enums_patterns::city::City;
+
+        let washington_street: Address = "42 Washington Street, Atlanta, GA 30303, USA".parse().unwrap();
+        let main_street: Address = "1 Main Street, Dayton, OH 45402, USA".parse().unwrap();
+        let garnett_street: Address = "150 Garnett Street, Atlanta, GA 30303, USA".parse().unwrap();
+
+        let mut registry = City::new();
+        registry.add(Building::School(String::from("Dayton School"), main_street));
+        let house = registry.add(Building::House(washington_street));
+        registry.add(Building::PoliceStation(garnett_street, 2));
+
+        for (_, building) in registry.in_city("atlanta") {
+            println!("in Atlanta: {}", building);
+        }
+        println!("schools: {}", registry.of_kind(BuildingKind::School).count());
+        println!("police stations in district 2 of Atlanta: {}", registry.in_district("Atlanta", 2).count());
+        println!("buildings by kind: {:?}", registry.count_by_kind());
+
+        //* Demolition removes the building and hands it back to us, the 💥 is ours to print
+        if let Some(building) = registry.demolish(house) {
+            println!("{} goes 💥", building);
+        }
+        println!("demolished twice: {:?}", registry.demolish(house));
+
+        //* The registry can be written to and read from a simple text format
+        let text = registry.to_string();
+        let reloaded: City = text.parse().unwrap();
+        print!("{}", reloaded);
+    }
+
+    //* src/serial.rs converts values into JSON or a compact binary format and back
+    //* Wrapping a struct or enum definition in 'serializable!' implements Serialize and Deserialize for it
+    //* Enums are written 'externally tagged': "Bank", {"School": ["Dayton", "Dayton School"]} or {"Move": {"x": 1, "y": 2}}
+    {
+        use enums_patterns::building::Building;
+        use enums_patterns::serial;
+        use enums_patterns::serializable;
+
+        //* The enums of this and the neighbouring chapters
+        serializable! {
+            #[derive(Debug, PartialEq)]
+            enum ChapterBuilding {
+                School(String, String),
+                House(String, String, i32),
+                PoliceStation(String, i32),
+            }
+        }
+        serializable! {
+            #[derive(Debug, PartialEq)]
+            enum Message { Hi, RequestAttendance, Bye }
+        }
+        serializable! {
+            #[derive(Debug, PartialEq)]
+            enum T { Int(i32), Float(f32), Text(String) }
+        }
+        //* Struct variants work the same way
+        serializable! {
+            #[derive(Debug, PartialEq)]
+            enum Shape {
+                Point,
+                Circle { x: f64, y: f64, radius: f64 },
+                Polygon(Vec<(f64, f64)>),
+            }
+        }
+
+        //* Every value comes back unchanged from JSON and from the binary format, tests/serial.rs checks that
+        let chapter_house = ChapterBuilding::House(String::from("Atlanta"), String::from("Washington Street"), 42);
+        println!("{}", serial::to_json(&chapter_house));
+        println!("{}", serial::to_json(&vec![T::Int(-7), T::Float(2.5), T::Text(String::from("\"quoted\" 💥"))]));
+        println!("{}", serial::to_json(&vec![Shape::Point, Shape::Circle { x: 1.0, y: -2.5, radius: 0.1 }]));
+
+        //* The library types are serializable as well
+        let house = Building::House("42 Washington Street, Atlanta, GA 30303, USA".parse().unwrap());
+        println!("{}", serial::to_json_pretty(&house));
+
+        //* Unit variants are plain strings, the binary format tags a string with 6 and its length
+        println!("{} {:?}", serial::to_json(&Message::Bye), serial::to_binary(&Message::Bye));
+
+        //* The binary format spends a single byte on small numbers and string lengths
+        println!("JSON {} bytes, binary {} bytes", serial::to_json(&house).len(), serial::to_binary(&house).len());
+
+        //* Decoding errors tell what went wrong and where
+        let err = serial::from_json::<ChapterBuilding>(r#"{"Church": ["Dayton"]}"#).unwrap_err();
+        println!("{}", err); // unknown variant `Church` of ChapterBuilding, expected one of: School, House, PoliceStation
+
+        let err = serial::from_json::<Shape>(r#"{"Circle": {"x": 1.0, "y": 2.0}}"#).unwrap_err();
+        println!("{}", err); // at Circle.radius: missing field `radius` in Circle
+
+        let err = serial::from_json::<Building>(r#"{"House": [{"street": "Main Street", "number": {"value": -1}}]}"#).unwrap_err();
+        println!("{}", err); // at House[0].number.value: -1 does not fit into u32
+
+        let err = serial::from_json::<Vec<T>>("[{\"Int\": [1]},\n {\"Int\": [2]]").unwrap_err();
+        println!("{}", err); // syntax error at line 2, column 13: expected ',' or '}'
+
+        let err = serial::from_binary::<Building>(&serial::to_binary(&house)[..10]).unwrap_err();
+        println!("{}", err); // unexpected end of input
+    }
+
+    //* src/combinators.rs adds the missing one-liners to Option and Result, see the doc tests there
+    //* 'multi_match!' matches several values at once without wrapping them in a tuple by hand
+    {
+        use enums_patterns::combinators::{OptionExt, OptionIteratorExt, ResultExt};
+        use enums_patterns::multi_match;
+
+        println!("name: {:?}", no_name.unwrap_or_default_with_warning("no name given"));
+
+        let street = Some("Washington Street");
+        let number = "42".parse::<i32>().ok_or_log("house number");
+        println!("{:?}", street.zip_map(number, |s, n| format!("{} {}", s, n)));
+
+        let districts = vec![Some("1"), None, Some("second"), Some("3")];
+        let parsed: Vec<i32> = districts.into_iter().filter_map_some(|d| d.parse().ok()).collect();
+        println!("districts {:?}", parsed);
+
+        let mut lookups = 0;
+        let _ = no_name.inspect_none(|| lookups += 1).ok_or_log("name lookup failed");
+        println!("failed lookups: {}", lookups);
+
+        let describe = |n: Option<i32>, name: Option<&str>| {
+            multi_match!(n, name;
+                Some(1), Some(name) => format!("One for {}", name),
+                Some(n), _ if n < 0 => String::from("Negative"),
+                None, None => String::from("Nothing"),
+                else => String::from("Not one"),
+            )
+        };
+        println!("{}", describe(some_number, name)); // Not one
+        println!("{}", describe(Some(1), name)); // One for Hans
+        println!("{}", describe(Some(-1), no_name)); // Negative
+        println!("{}", describe(None, None)); // Nothing
+    }
+
+    //* The fieldless enums at the top of the chapter can't be listed or read from text on their own
+    //* Wrapped in 'enumerable!' (src/enumerable.rs) they get ALL, iter(), count(), Display, FromStr and TryFrom<u8>
+    //* That way command line flags and config values map directly onto the enum
+    {
+        use enums_patterns::enumerable;
+        use std::convert::TryFrom;
+
+        enumerable! {
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            enum Building { School, Bank, PoliceStation }
+        }
+        enumerable! {
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            enum Enum { First = 1, Second, Third = 10 }
+        }
+
+        println!("{} buildings: {:?}", Building::count(), Building::ALL);
+        for building in Building::iter() {
+            println!("{:>15} = {}", building, building as u8);
+        }
+
+        //* Parsing ignores case as well as '-', '_' and spaces
+        for text in ["bank", "police-station", "POLICE_STATION"] {
+            println!("{:?} parses to {:?}", text, text.parse::<Building>());
+        }
+        if let Err(err) = "church".parse::<Building>() {
+            println!("{}", err); // 'church' is not a valid Building, expected one of: School, Bank, PoliceStation
+        }
+
+        //* Discriminants count on from the last explicit value
+        for value in [1, 2, 3, 10] {
+            println!("{} is {:?}", value, Enum::try_from(value)); // 3 is an error
+        }
+
+        //* e.g. a flag like '--kind=police-station'
+        let flag = "--kind=police-station";
+        let kind = flag.strip_prefix("--kind=").and_then(|value| value.parse::<Building>().ok());
+        println!("{} selects {:?}", flag, kind);
+    }
+
+    //* Lifecycles are a natural fit for enums: a building is planned, built, used and eventually torn down
+    //* Instead of a big match over (state, event), 'state_machine!' (src/state_machine.rs) declares the allowed transitions
+    {
+        use enums_patterns::state_machine;
+
+        state_machine! {
+            #[derive(Debug, Clone, PartialEq)]
+            enum Lifecycle {
+                Planned,
+                UnderConstruction { progress: u8 },
+                Vacant,
+                InUse(String),
+                Demolished,
+            }
+
+            #[derive(Debug, Clone, PartialEq)]
+            enum LifecycleEvent {
+                Start,
+                Progress(u8),
+                MoveIn(String),
+                MoveOut,
+                TearDown,
+            }
+
+            transitions {
+                Planned + Start => UnderConstruction { progress: 0 },
+                UnderConstruction { progress } + Progress(p) if progress.saturating_add(p) < 100 => UnderConstruction { progress: progress + p },
+                UnderConstruction { .. } + Progress(_) => Vacant,
+                Vacant + MoveIn(tenant) => InUse(tenant),
+                InUse(_) + MoveOut => Vacant,
+                Planned + TearDown => Demolished,
+                Vacant + TearDown => Demolished,
+            }
+        }
+
+        let mut building = Lifecycle::Planned;
+        for event in [
+            LifecycleEvent::Start,
+            LifecycleEvent::Progress(60),
+            LifecycleEvent::Progress(60),
+            LifecycleEvent::MoveIn(String::from("Dayton School")),
+        ] {
+            building = building.transition(event).unwrap();
+            println!("{:?}", building);
+        }
+        println!("allowed now: {:?}", building.allowed_events());
+
+        //* A forbidden event hands the state back untouched
+        let err = building.transition(LifecycleEvent::TearDown).unwrap_err();
+        println!("{}", err); // event TearDown is not allowed in state InUse
+        let building = err.state.transition(LifecycleEvent::MoveOut).unwrap();
+        println!("{:?}", building.transition(LifecycleEvent::TearDown)); // Ok(Demolished)
+
+        //* The graph can be rendered with Graphviz, the rest of the output has to be cut away first:
+        //*     cargo run | sed -n '/^digraph/,/^}/p' | dot -Tsvg > lifecycle.svg
+        print!("{}", Lifecycle::to_dot()); // one edge for each of the 7 transitions
+    }
+}
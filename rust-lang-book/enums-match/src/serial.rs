@@ -0,0 +1,1175 @@
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+
+//* Serialization happens in two steps:
+//*     (1) a value is turned into the format independent data model 'Value' (Serialize / Deserialize)
+//*     (2) the data model is written as JSON or as a compact binary format (and read back)
+//* Structs become maps, enums are 'externally tagged' like in most JSON APIs:
+//*     unit variant            "Bank"
+//*     tuple variant           {"School": ["Dayton", "Dayton School"]}
+//*     struct variant          {"Move": {"x": 1, "y": 2}}
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Int(_) | Value::UInt(_) => "integer",
+            Value::Float(_) => "float",
+            Value::Str(_) => "string",
+            Value::Seq(_) => "sequence",
+            Value::Map(_) => "map",
+        }
+    }
+
+    //* The representation of an enum variant that carries data
+    pub fn variant(name: &str, payload: Value) -> Value {
+        Value::Map(vec![(name.to_string(), payload)])
+    }
+
+    pub fn as_seq(&self) -> Result<&[Value], DecodeError> {
+        match self {
+            Value::Seq(items) => Ok(items),
+            other => Err(DecodeError::mismatch("sequence", other)),
+        }
+    }
+
+    pub fn as_map(&self) -> Result<&[(String, Value)], DecodeError> {
+        match self {
+            Value::Map(entries) => Ok(entries),
+            other => Err(DecodeError::mismatch("map", other)),
+        }
+    }
+
+    //* Splits an enum value into its variant name and optional payload
+    pub fn as_variant(&self) -> Result<(&str, Option<&Value>), DecodeError> {
+        match self {
+            Value::Str(name) => Ok((name, None)),
+            Value::Map(entries) if entries.len() == 1 => Ok((&entries[0].0, Some(&entries[0].1))),
+            other => Err(DecodeError::mismatch("enum variant", other)),
+        }
+    }
+}
+
+//* Where in the decoded value an error happened, e.g. Building::House[0].number
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+    Variant(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeErrorKind {
+    Syntax { line: usize, column: usize, message: String },
+    UnexpectedEof,
+    InvalidTag(u8),
+    InvalidUtf8,
+    TrailingData(usize),
+    TypeMismatch { expected: &'static str, found: &'static str },
+    OutOfRange { expected: &'static str, value: String },
+    UnknownVariant { enum_name: &'static str, variant: String, expected: &'static [&'static str] },
+    MissingField { type_name: &'static str, field: &'static str },
+    WrongLength { expected: usize, found: usize },
+    TooDeep { limit: usize },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    pub kind: DecodeErrorKind,
+    //* Outermost segment first
+    pub path: Vec<PathSegment>,
+}
+
+impl DecodeError {
+    pub fn new(kind: DecodeErrorKind) -> DecodeError {
+        DecodeError { kind, path: Vec::new() }
+    }
+
+    pub fn mismatch(expected: &'static str, found: &Value) -> DecodeError {
+        DecodeError::new(DecodeErrorKind::TypeMismatch { expected, found: found.type_name() })
+    }
+
+    pub fn missing_field(type_name: &'static str, field: &'static str) -> DecodeError {
+        DecodeError::new(DecodeErrorKind::MissingField { type_name, field })
+    }
+
+    //* Prepends a path segment while the error travels outwards
+    pub fn at(mut self, segment: PathSegment) -> DecodeError {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "at ")?;
+            for (i, segment) in self.path.iter().enumerate() {
+                match segment {
+                    PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                    PathSegment::Field(name) => write!(f, ".{}", name)?,
+                    PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                    PathSegment::Variant(name) if i == 0 => write!(f, "{}", name)?,
+                    PathSegment::Variant(name) => write!(f, "::{}", name)?,
+                }
+            }
+            write!(f, ": ")?;
+        }
+        match &self.kind {
+            DecodeErrorKind::Syntax { line, column, message } => {
+                write!(f, "syntax error at line {}, column {}: {}", line, column, message)
+            }
+            DecodeErrorKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            DecodeErrorKind::InvalidTag(tag) => write!(f, "invalid type tag 0x{:02x}", tag),
+            DecodeErrorKind::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            DecodeErrorKind::TrailingData(count) => write!(f, "{} unexpected bytes after the value", count),
+            DecodeErrorKind::TypeMismatch { expected, found } => write!(f, "expected {}, found {}", expected, found),
+            DecodeErrorKind::OutOfRange { expected, value } => write!(f, "{} does not fit into {}", value, expected),
+            DecodeErrorKind::UnknownVariant { enum_name, variant, expected } => write!(
+                f,
+                "unknown variant `{}` of {}, expected one of: {}",
+                variant,
+                enum_name,
+                expected.join(", ")
+            ),
+            DecodeErrorKind::MissingField { type_name, field } => write!(f, "missing field `{}` in {}", field, type_name),
+            DecodeErrorKind::WrongLength { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            }
+            DecodeErrorKind::TooDeep { limit } => write!(f, "values are nested deeper than {} levels", limit),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+pub trait Serialize {
+    fn to_value(&self) -> Value;
+}
+
+pub trait Deserialize: Sized {
+    fn from_value(value: &Value) -> Result<Self, DecodeError>;
+
+    //* Called when a struct field is absent, only optional types accept that
+    fn from_missing(type_name: &'static str, field: &'static str) -> Result<Self, DecodeError> {
+        Err(DecodeError::missing_field(type_name, field))
+    }
+}
+
+//* Looks up and decodes a struct field, used by the serializable! macro
+pub fn decode_field<T: Deserialize>(entries: &[(String, Value)], type_name: &'static str, field: &'static str) -> Result<T, DecodeError> {
+    match entries.iter().find(|(key, _)| key == field) {
+        Some((_, value)) => T::from_value(value),
+        None => T::from_missing(type_name, field),
+    }
+    .map_err(|err| err.at(PathSegment::Field(field.to_string())))
+}
+
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> String {
+    let mut out = String::new();
+    write_json(&value.to_value(), &mut out, None, 0);
+    out
+}
+
+pub fn to_json_pretty<T: Serialize + ?Sized>(value: &T) -> String {
+    let mut out = String::new();
+    write_json(&value.to_value(), &mut out, Some(2), 0);
+    out
+}
+
+pub fn from_json<T: Deserialize>(json: &str) -> Result<T, DecodeError> {
+    T::from_value(&parse_json(json)?)
+}
+
+pub fn to_binary<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_binary(&value.to_value(), &mut out);
+    out
+}
+
+pub fn from_binary<T: Deserialize>(bytes: &[u8]) -> Result<T, DecodeError> {
+    T::from_value(&parse_binary(bytes)?)
+}
+
+//* Implementations for the standard types
+
+impl Serialize for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl Deserialize for bool {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            other => Err(DecodeError::mismatch("bool", other)),
+        }
+    }
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for $t {
+                fn to_value(&self) -> Value {
+                    Value::Int(*self as i64)
+                }
+            }
+            impl Deserialize for $t {
+                fn from_value(value: &Value) -> Result<Self, DecodeError> {
+                    let out_of_range = |v: String| DecodeError::new(DecodeErrorKind::OutOfRange { expected: stringify!($t), value: v });
+                    match value {
+                        Value::Int(i) => <$t>::try_from(*i).map_err(|_| out_of_range(i.to_string())),
+                        Value::UInt(u) => <$t>::try_from(*u).map_err(|_| out_of_range(u.to_string())),
+                        other => Err(DecodeError::mismatch(stringify!($t), other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for $t {
+                fn to_value(&self) -> Value {
+                    Value::UInt(*self as u64)
+                }
+            }
+            impl Deserialize for $t {
+                fn from_value(value: &Value) -> Result<Self, DecodeError> {
+                    let out_of_range = |v: String| DecodeError::new(DecodeErrorKind::OutOfRange { expected: stringify!($t), value: v });
+                    match value {
+                        Value::Int(i) => <$t>::try_from(*i).map_err(|_| out_of_range(i.to_string())),
+                        Value::UInt(u) => <$t>::try_from(*u).map_err(|_| out_of_range(u.to_string())),
+                        other => Err(DecodeError::mismatch(stringify!($t), other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, isize);
+impl_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for $t {
+                fn to_value(&self) -> Value {
+                    Value::Float(*self as f64)
+                }
+            }
+            impl Deserialize for $t {
+                fn from_value(value: &Value) -> Result<Self, DecodeError> {
+                    match value {
+                        Value::Float(f) => Ok(*f as $t),
+                        Value::Int(i) => Ok(*i as $t),
+                        Value::UInt(u) => Ok(*u as $t),
+                        other => Err(DecodeError::mismatch(stringify!($t), other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl Serialize for str {
+    fn to_value(&self) -> Value {
+        Value::Str(self.to_string())
+    }
+}
+
+impl Serialize for String {
+    fn to_value(&self) -> Value {
+        Value::Str(self.clone())
+    }
+}
+
+impl Deserialize for String {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        match value {
+            Value::Str(s) => Ok(s.clone()),
+            other => Err(DecodeError::mismatch("string", other)),
+        }
+    }
+}
+
+impl Serialize for char {
+    fn to_value(&self) -> Value {
+        Value::Str(self.to_string())
+    }
+}
+
+impl Deserialize for char {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        let s = String::from_value(value)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(DecodeError::new(DecodeErrorKind::OutOfRange { expected: "char", value: format!("{:?}", s) })),
+        }
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Box<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: Deserialize> Deserialize for Box<T> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        T::from_value(value).map(Box::new)
+    }
+}
+
+impl<T: Serialize> Serialize for Option<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T: Deserialize> Deserialize for Option<T> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        match value {
+            Value::Null => Ok(None),
+            other => T::from_value(other).map(Some),
+        }
+    }
+
+    fn from_missing(_: &'static str, _: &'static str) -> Result<Self, DecodeError> {
+        Ok(None)
+    }
+}
+
+impl<T: Serialize, E: Serialize> Serialize for Result<T, E> {
+    fn to_value(&self) -> Value {
+        match self {
+            Ok(value) => Value::variant("Ok", value.to_value()),
+            Err(err) => Value::variant("Err", err.to_value()),
+        }
+    }
+}
+
+impl<T: Deserialize, E: Deserialize> Deserialize for Result<T, E> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        const VARIANTS: &[&str] = &["Ok", "Err"];
+        match value.as_variant()? {
+            ("Ok", Some(payload)) => T::from_value(payload).map(Ok),
+            ("Err", Some(payload)) => E::from_value(payload).map(Err),
+            (name, _) => Err(DecodeError::new(DecodeErrorKind::UnknownVariant {
+                enum_name: "Result",
+                variant: name.to_string(),
+                expected: VARIANTS,
+            })),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for [T] {
+    fn to_value(&self) -> Value {
+        Value::Seq(self.iter().map(Serialize::to_value).collect())
+    }
+}
+
+impl<T: Serialize> Serialize for Vec<T> {
+    fn to_value(&self) -> Value {
+        self.as_slice().to_value()
+    }
+}
+
+impl<T: Deserialize> Deserialize for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        value
+            .as_seq()?
+            .iter()
+            .enumerate()
+            .map(|(i, item)| T::from_value(item).map_err(|err| err.at(PathSegment::Index(i))))
+            .collect()
+    }
+}
+
+impl<A: Serialize, B: Serialize> Serialize for (A, B) {
+    fn to_value(&self) -> Value {
+        Value::Seq(vec![self.0.to_value(), self.1.to_value()])
+    }
+}
+
+impl<A: Deserialize, B: Deserialize> Deserialize for (A, B) {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        let items = value.as_seq()?;
+        if items.len() != 2 {
+            return Err(DecodeError::new(DecodeErrorKind::WrongLength { expected: 2, found: items.len() }));
+        }
+        let a = A::from_value(&items[0]).map_err(|err| err.at(PathSegment::Index(0)))?;
+        let b = B::from_value(&items[1]).map_err(|err| err.at(PathSegment::Index(1)))?;
+        Ok((a, b))
+    }
+}
+
+impl<T: Serialize> Serialize for BTreeMap<String, T> {
+    fn to_value(&self) -> Value {
+        Value::Map(self.iter().map(|(k, v)| (k.clone(), v.to_value())).collect())
+    }
+}
+
+impl<T: Deserialize> Deserialize for BTreeMap<String, T> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        value
+            .as_map()?
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), T::from_value(v).map_err(|err| err.at(PathSegment::Field(k.clone())))?)))
+            .collect()
+    }
+}
+
+//* Keys are sorted so that equal maps always produce the same output
+impl<T: Serialize> Serialize for HashMap<String, T> {
+    fn to_value(&self) -> Value {
+        let mut entries: Vec<_> = self.iter().map(|(k, v)| (k.clone(), v.to_value())).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Value::Map(entries)
+    }
+}
+
+impl<T: Deserialize> Deserialize for HashMap<String, T> {
+    fn from_value(value: &Value) -> Result<Self, DecodeError> {
+        BTreeMap::<String, T>::from_value(value).map(|map| map.into_iter().collect())
+    }
+}
+
+//* JSON
+
+//* Non-finite floats have no JSON representation and are written as null
+fn write_json(value: &Value, out: &mut String, indent: Option<usize>, depth: usize) {
+    let newline = |out: &mut String, depth: usize| {
+        if let Some(width) = indent {
+            out.push('\n');
+            out.push_str(&" ".repeat(width * depth));
+        }
+    };
+
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Int(i) => out.push_str(&i.to_string()),
+        Value::UInt(u) => out.push_str(&u.to_string()),
+        Value::Float(f) if f.is_finite() => {
+            let text = f.to_string();
+            out.push_str(&text);
+            //* keep floats recognizable as floats, 23.0 would otherwise be printed as 23
+            if !text.contains(['.', 'e', 'E']) {
+                out.push_str(".0");
+            }
+        }
+        Value::Float(_) => out.push_str("null"),
+        Value::Str(s) => write_json_string(s, out),
+        Value::Seq(items) if items.is_empty() => out.push_str("[]"),
+        Value::Seq(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                    if indent.is_none() {
+                        out.push(' ');
+                    }
+                }
+                newline(out, depth + 1);
+                write_json(item, out, indent, depth + 1);
+            }
+            newline(out, depth);
+            out.push(']');
+        }
+        Value::Map(entries) if entries.is_empty() => out.push_str("{}"),
+        Value::Map(entries) => {
+            out.push('{');
+            for (i, (key, item)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                    if indent.is_none() {
+                        out.push(' ');
+                    }
+                }
+                newline(out, depth + 1);
+                write_json_string(key, out);
+                out.push_str(": ");
+                write_json(item, out, indent, depth + 1);
+            }
+            newline(out, depth);
+            out.push('}');
+        }
+    }
+}
+
+fn write_json_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+//* Both parsers recurse once per nested sequence or map, deeper input is rejected instead of overflowing the stack
+pub const MAX_DEPTH: usize = 128;
+
+fn too_deep() -> DecodeError {
+    DecodeError::new(DecodeErrorKind::TooDeep { limit: MAX_DEPTH })
+}
+
+pub fn parse_json(json: &str) -> Result<Value, DecodeError> {
+    let mut parser = JsonParser { chars: json.chars().collect(), pos: 0, depth: 0 };
+    parser.skip_whitespace();
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("unexpected characters after the value"));
+    }
+    Ok(value)
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+    //* Sequences and maps that are currently open
+    depth: usize,
+}
+
+impl JsonParser {
+    fn error(&self, message: &str) -> DecodeError {
+        let consumed = &self.chars[..self.pos.min(self.chars.len())];
+        let line = consumed.iter().filter(|c| **c == '\n').count() + 1;
+        let column = consumed.iter().rev().take_while(|c| **c != '\n').count() + 1;
+        DecodeError::new(DecodeErrorKind::Syntax { line, column, message: message.to_string() })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<char, DecodeError> {
+        let c = self.peek().ok_or_else(|| DecodeError::new(DecodeErrorKind::UnexpectedEof))?;
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), DecodeError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(_) => Err(self.error(&format!("expected '{}'", expected))),
+            None => Err(DecodeError::new(DecodeErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Value) -> Result<Value, DecodeError> {
+        for expected in word.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("expected '{}'", word)));
+            }
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, DecodeError> {
+        match self.peek() {
+            None => Err(DecodeError::new(DecodeErrorKind::UnexpectedEof)),
+            Some('n') => self.keyword("null", Value::Null),
+            Some('t') => self.keyword("true", Value::Bool(true)),
+            Some('f') => self.keyword("false", Value::Bool(false)),
+            Some('"') => self.string().map(Value::Str),
+            Some('[') => self.nested(JsonParser::seq),
+            Some('{') => self.nested(JsonParser::map),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(_) => Err(self.error("expected a JSON value")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut JsonParser) -> Result<Value, DecodeError>) -> Result<Value, DecodeError> {
+        if self.depth == MAX_DEPTH {
+            return Err(too_deep());
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn seq(&mut self) -> Result<Value, DecodeError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Seq(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Value::Seq(items)),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error("expected ',' or ']'"));
+                }
+            }
+        }
+    }
+
+    fn map(&mut self) -> Result<Value, DecodeError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Map(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a string key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Value::Map(entries)),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error("expected ',' or '}'"));
+                }
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(s),
+                '\\' => match self.next()? {
+                    '"' => s.push('"'),
+                    '\\' => s.push('\\'),
+                    '/' => s.push('/'),
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'u' => s.push(self.unicode_escape()?),
+                    _ => {
+                        self.pos -= 1;
+                        return Err(self.error("invalid escape sequence"));
+                    }
+                },
+                c if (c as u32) < 0x20 => {
+                    self.pos -= 1;
+                    return Err(self.error("control character in string"));
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    //* \uXXXX, characters outside the BMP arrive as surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, DecodeError> {
+        let high = self.hex4()?;
+        if (0xd800..0xdc00).contains(&high) {
+            if self.next()? != '\\' || self.next()? != 'u' {
+                return Err(self.error("expected a low surrogate"));
+            }
+            let low = self.hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("invalid low surrogate"));
+            }
+            let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+            return char::from_u32(code).ok_or_else(|| self.error("invalid code point"));
+        }
+        char::from_u32(high).ok_or_else(|| self.error("invalid code point"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, DecodeError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self.next()?.to_digit(16).ok_or_else(|| self.error("invalid hex digit"))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    //* -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?, so no leading zeros, "1." or "+1"
+    fn number(&mut self) -> Result<Value, DecodeError> {
+        let start = self.pos;
+        let invalid = |parser: &mut JsonParser, message: &str| {
+            let text: String = parser.chars[start..parser.pos].iter().collect();
+            parser.pos = start;
+            Err(parser.error(&format!("invalid number '{}', {}", text, message)))
+        };
+
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        let int_start = self.pos;
+        match self.digits() {
+            0 => return invalid(self, "expected a digit"),
+            1 => {}
+            _ if self.chars[int_start] == '0' => return invalid(self, "leading zeros are not allowed"),
+            _ => {}
+        }
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            if self.digits() == 0 {
+                return invalid(self, "expected a digit after '.'");
+            }
+        }
+        if let Some('e') | Some('E') = self.peek() {
+            self.pos += 1;
+            if let Some('+') | Some('-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return invalid(self, "expected a digit in the exponent");
+            }
+        }
+        if self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+') {
+            self.pos += 1;
+            return invalid(self, "unexpected character");
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        let is_float = text.contains(['.', 'e', 'E']);
+
+        let parsed = if is_float {
+            text.parse().map(Value::Float).ok()
+        } else if text.starts_with('-') {
+            text.parse().map(Value::Int).ok()
+        } else {
+            text.parse().map(Value::UInt).ok()
+        };
+        parsed.ok_or_else(|| {
+            self.pos = start;
+            self.error(&format!("invalid number '{}'", text))
+        })
+    }
+}
+
+//* Binary format
+//* Every value starts with a one byte tag, lengths and integers are LEB128 varints:
+//*     0 null, 1 false, 2 true
+//*     3 signed integer (zigzag varint), 4 unsigned integer (varint), 5 float (8 bytes little endian)
+//*     6 string (length + UTF-8 bytes), 7 sequence (count + values), 8 map (count + (key string, value) pairs)
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_UINT: u8 = 4;
+const TAG_FLOAT: u8 = 5;
+const TAG_STR: u8 = 6;
+const TAG_SEQ: u8 = 7;
+const TAG_MAP: u8 = 8;
+
+fn write_varint(mut n: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_str(s: &str, out: &mut Vec<u8>) {
+    write_varint(s.len() as u64, out);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn write_binary(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push(TAG_NULL),
+        Value::Bool(false) => out.push(TAG_FALSE),
+        Value::Bool(true) => out.push(TAG_TRUE),
+        Value::Int(i) => {
+            out.push(TAG_INT);
+            write_varint(((i << 1) ^ (i >> 63)) as u64, out);
+        }
+        Value::UInt(u) => {
+            out.push(TAG_UINT);
+            write_varint(*u, out);
+        }
+        Value::Float(f) => {
+            out.push(TAG_FLOAT);
+            out.extend_from_slice(&f.to_le_bytes());
+        }
+        Value::Str(s) => {
+            out.push(TAG_STR);
+            write_str(s, out);
+        }
+        Value::Seq(items) => {
+            out.push(TAG_SEQ);
+            write_varint(items.len() as u64, out);
+            for item in items {
+                write_binary(item, out);
+            }
+        }
+        Value::Map(entries) => {
+            out.push(TAG_MAP);
+            write_varint(entries.len() as u64, out);
+            for (key, item) in entries {
+                write_str(key, out);
+                write_binary(item, out);
+            }
+        }
+    }
+}
+
+pub fn parse_binary(bytes: &[u8]) -> Result<Value, DecodeError> {
+    let mut reader = BinaryReader { bytes, pos: 0, depth: 0 };
+    let value = reader.value()?;
+    if reader.pos < bytes.len() {
+        return Err(DecodeError::new(DecodeErrorKind::TrailingData(bytes.len() - reader.pos)));
+    }
+    Ok(value)
+}
+
+struct BinaryReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() - self.pos < count {
+            return Err(DecodeError::new(DecodeErrorKind::UnexpectedEof));
+        }
+        let slice = &self.bytes[self.pos..self.pos + count];
+        self.pos += count;
+        Ok(slice)
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut n: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            n |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(DecodeError::new(DecodeErrorKind::OutOfRange { expected: "u64", value: String::from("varint") }))
+    }
+
+    //* Lengths are checked against the remaining input so corrupt data cannot trigger huge allocations
+    fn length(&mut self) -> Result<usize, DecodeError> {
+        let length = self.varint()?;
+        if length > (self.bytes.len() - self.pos) as u64 {
+            return Err(DecodeError::new(DecodeErrorKind::UnexpectedEof));
+        }
+        Ok(length as usize)
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        let length = self.length()?;
+        let bytes = self.take(length)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::new(DecodeErrorKind::InvalidUtf8))
+    }
+
+    fn value(&mut self) -> Result<Value, DecodeError> {
+        let tag = self.take(1)?[0];
+        match tag {
+            TAG_NULL => Ok(Value::Null),
+            TAG_FALSE => Ok(Value::Bool(false)),
+            TAG_TRUE => Ok(Value::Bool(true)),
+            TAG_INT => {
+                let n = self.varint()?;
+                Ok(Value::Int(((n >> 1) as i64) ^ -((n & 1) as i64)))
+            }
+            TAG_UINT => self.varint().map(Value::UInt),
+            TAG_FLOAT => {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(self.take(8)?);
+                Ok(Value::Float(f64::from_le_bytes(buf)))
+            }
+            TAG_STR => self.string().map(Value::Str),
+            TAG_SEQ => self.nested(|reader| {
+                let count = reader.length()?;
+                let mut items = Vec::with_capacity(count);
+                for _ in 0..count {
+                    items.push(reader.value()?);
+                }
+                Ok(Value::Seq(items))
+            }),
+            TAG_MAP => self.nested(|reader| {
+                let count = reader.length()?;
+                let mut entries = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let key = reader.string()?;
+                    entries.push((key, reader.value()?));
+                }
+                Ok(Value::Map(entries))
+            }),
+            other => Err(DecodeError::new(DecodeErrorKind::InvalidTag(other))),
+        }
+    }
+
+    fn nested(&mut self, read: impl FnOnce(&mut Self) -> Result<Value, DecodeError>) -> Result<Value, DecodeError> {
+        if self.depth == MAX_DEPTH {
+            return Err(too_deep());
+        }
+        self.depth += 1;
+        let value = read(self);
+        self.depth -= 1;
+        value
+    }
+}
+
+//* 'serializable!' works like a derive: wrap a struct or enum definition in it
+//* and it is emitted unchanged together with Serialize and Deserialize implementations
+//*
+//*     serializable! {
+//*         #[derive(Debug)]
+//*         pub enum Building {
+//*             School(String, Address),
+//*             House(Address),
+//*             PoliceStation(Address, i32),
+//*         }
+//*     }
+//*
+//* Supported are structs with named fields and enums with unit, tuple (up to 12 fields) and struct variants
+#[macro_export]
+macro_rules! serializable {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$fmeta:meta])* $fvis:vis $field:ident : $fty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $(#[$fmeta])* $fvis $field : $fty ),*
+        }
+
+        impl $crate::serial::Serialize for $name {
+            fn to_value(&self) -> $crate::serial::Value {
+                $crate::serial::Value::Map(vec![
+                    $( (stringify!($field).to_string(), $crate::serial::Serialize::to_value(&self.$field)) ),*
+                ])
+            }
+        }
+
+        impl $crate::serial::Deserialize for $name {
+            fn from_value(value: &$crate::serial::Value) -> Result<Self, $crate::serial::DecodeError> {
+                #[allow(unused_variables)]
+                let entries = value.as_map()?;
+                Ok($name {
+                    $( $field: $crate::serial::decode_field(entries, stringify!($name), stringify!($field))? ),*
+                })
+            }
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$vmeta:meta])*
+                $variant:ident
+                $( ( $($tty:ty),* $(,)? ) )?
+                $( { $($vfield:ident : $vfty:ty),* $(,)? } )?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant
+                $( ( $($tty),* ) )?
+                $( { $($vfield : $vfty),* } )?
+            ),*
+        }
+
+        impl $crate::serial::Serialize for $name {
+            fn to_value(&self) -> $crate::serial::Value {
+                $(
+                    if let Some(value) = $crate::__serial_variant!(@to self, $name, $variant $( ( $($tty),* ) )? $( { $($vfield : $vfty),* } )?) {
+                        return value;
+                    }
+                )*
+                unreachable!()
+            }
+        }
+
+        impl $crate::serial::Deserialize for $name {
+            fn from_value(value: &$crate::serial::Value) -> Result<Self, $crate::serial::DecodeError> {
+                const VARIANTS: &[&str] = &[$(stringify!($variant)),*];
+                let (tag, payload) = value.as_variant()?;
+                $(
+                    if tag == stringify!($variant) {
+                        return $crate::__serial_variant!(@from payload, $name, $variant $( ( $($tty),* ) )? $( { $($vfield : $vfty),* } )?)
+                            .map_err(|err| err.at($crate::serial::PathSegment::Variant(tag.to_string())));
+                    }
+                )*
+                Err($crate::serial::DecodeError::new($crate::serial::DecodeErrorKind::UnknownVariant {
+                    enum_name: stringify!($name),
+                    variant: tag.to_string(),
+                    expected: VARIANTS,
+                }))
+            }
+        }
+    };
+}
+
+//* Per-variant code generation for serializable!, not meant to be used directly
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serial_variant {
+    //* Unit variants
+    (@to $self:ident, $name:ident, $variant:ident) => {
+        match $self {
+            $name::$variant => Some($crate::serial::Value::Str(stringify!($variant).to_string())),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+    (@from $payload:ident, $name:ident, $variant:ident) => {
+        match $payload {
+            None | Some($crate::serial::Value::Null) => Ok($name::$variant),
+            Some(other) => Err($crate::serial::DecodeError::mismatch("unit variant", other)),
+        }
+    };
+
+    //* Struct variants
+    (@to $self:ident, $name:ident, $variant:ident { $($field:ident : $fty:ty),* }) => {
+        match $self {
+            $name::$variant { $($field),* } => Some($crate::serial::Value::variant(
+                stringify!($variant),
+                $crate::serial::Value::Map(vec![
+                    $( (stringify!($field).to_string(), $crate::serial::Serialize::to_value($field)) ),*
+                ]),
+            )),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+    (@from $payload:ident, $name:ident, $variant:ident { $($field:ident : $fty:ty),* }) => {
+        {
+            fn decode(payload: Option<&$crate::serial::Value>) -> Result<$name, $crate::serial::DecodeError> {
+                let payload = payload.ok_or_else(|| $crate::serial::DecodeError::mismatch("map", &$crate::serial::Value::Null))?;
+                #[allow(unused_variables)]
+                let entries = payload.as_map()?;
+                Ok($name::$variant {
+                    $( $field: $crate::serial::decode_field(entries, stringify!($variant), stringify!($field))? ),*
+                })
+            }
+            decode($payload)
+        }
+    };
+
+    //* Tuple variants need a binding per field, so the types are paired with names from a fixed pool
+    (@to $self:ident, $name:ident, $variant:ident ( $($tty:ty),* )) => {
+        $crate::__serial_variant!(@to_tuple $self, $name, $variant, [], [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11], $($tty),*)
+    };
+    (@to_tuple $self:ident, $name:ident, $variant:ident, [$($bound:ident)*], [$next:ident $($free:ident)*], $tty:ty $(, $rest:ty)*) => {
+        $crate::__serial_variant!(@to_tuple $self, $name, $variant, [$($bound)* $next], [$($free)*], $($rest),*)
+    };
+    (@to_tuple $self:ident, $name:ident, $variant:ident, [$($bound:ident)*], [$($free:ident)*], ) => {
+        match $self {
+            $name::$variant($($bound),*) => Some($crate::serial::Value::variant(
+                stringify!($variant),
+                $crate::serial::Value::Seq(vec![$($crate::serial::Serialize::to_value($bound)),*]),
+            )),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+
+    (@from $payload:ident, $name:ident, $variant:ident ( $($tty:ty),* )) => {
+        {
+            fn decode(payload: Option<&$crate::serial::Value>) -> Result<$name, $crate::serial::DecodeError> {
+                let payload = payload.ok_or_else(|| $crate::serial::DecodeError::mismatch("sequence", &$crate::serial::Value::Null))?;
+                let items = payload.as_seq()?;
+                let expected = [$(stringify!($tty)),*].len();
+                if items.len() != expected {
+                    return Err($crate::serial::DecodeError::new($crate::serial::DecodeErrorKind::WrongLength {
+                        expected,
+                        found: items.len(),
+                    }));
+                }
+                //* the length was checked above, so every field finds its item
+                #[allow(unused_mut, unused_variables)]
+                let mut items = items.iter().enumerate();
+                Ok($name::$variant($(
+                    {
+                        let (index, item) = items.next().unwrap();
+                        <$tty as $crate::serial::Deserialize>::from_value(item)
+                            .map_err(|err| err.at($crate::serial::PathSegment::Index(index)))?
+                    }
+                ),*))
+            }
+            decode($payload)
+        }
+    };
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+//* Lifecycles are often modeled as an enum of states plus a big 'match' over (state, event)
+//* 'state_machine!' declares the states, the events and the allowed transitions in one place:
+//*
+//*     state_machine! {
+//*         #[derive(Debug)]
+//*         pub enum Door { Open, Closed, Locked(u32) }
+//*
+//*         #[derive(Debug)]
+//*         pub enum DoorEvent { Push, Pull, Lock(u32), Unlock(u32) }
+//*
+//*         transitions {
+//*             Open + Push => Closed,
+//*             Closed + Pull => Open,
+//*             Closed + Lock(code) => Locked(code),
+//*             Locked(code) + Unlock(key) if code == key => Closed,
+//*         }
+//*     }
+//*
+//* A transition reads '<state pattern> + <event pattern> [if <guard>] => <new state>'
+//* The bindings of both patterns can be used by the guard and the new state
+//* For both enums the macro generates name(), NAMES and for the states:
+//*     transition(self, event)     the new state or InvalidTransition, which hands state and event back
+//*     TRANSITIONS                 (from, event, to) names of every declared transition
+//*     allowed_events(&self)       names of the events declared for the current state, guards are not evaluated
+//*     to_dot()                    the graph in Graphviz DOT format, render it with 'dot -Tsvg'
+
+//* Returned when an event isn't allowed in the current state
+//* Both are handed back so that the caller can carry on with the old state
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidTransition<S, E> {
+    pub state: S,
+    pub event: E,
+    pub state_name: &'static str,
+    pub event_name: &'static str,
+}
+
+impl<S, E> fmt::Display for InvalidTransition<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "event {} is not allowed in state {}", self.event_name, self.state_name)
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> std::error::Error for InvalidTransition<S, E> {}
+
+//* Renders the transition graph, used by the generated to_dot()
+//* The first state is the initial one, states without outgoing transitions are drawn as final states
+pub fn to_dot(name: &str, states: &[&str], transitions: &[(&str, &str, &str)]) -> String {
+    let mut dot = format!("digraph {} {{\n    rankdir=LR;\n    node [shape=circle];\n", name);
+    if let Some(initial) = states.first() {
+        dot.push_str("    __start [shape=point];\n");
+        dot.push_str(&format!("    __start -> \"{}\";\n", initial));
+    }
+    for state in states {
+        let is_final = !transitions.iter().any(|(from, _, _)| from == state);
+        let shape = if is_final { "doublecircle" } else { "circle" };
+        dot.push_str(&format!("    \"{}\" [shape={}];\n", state, shape));
+    }
+    for (from, event, to) in transitions {
+        dot.push_str(&format!("    \"{}\" -> \"{}\" [label=\"{}\"];\n", from, to, event));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[macro_export]
+macro_rules! state_machine {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $state:ident {
+            $( $(#[$smeta:meta])* $svariant:ident $( ( $($sfields:tt)* ) )? $( { $($snamed:tt)* } )? ),* $(,)?
+        }
+
+        $(#[$emeta:meta])*
+        $evis:vis enum $event:ident {
+            $( $(#[$evmeta:meta])* $evariant:ident $( ( $($efields:tt)* ) )? $( { $($enamed:tt)* } )? ),* $(,)?
+        }
+
+        transitions {
+            $(
+                $from:ident $( ( $($fpat:tt)* ) )? $( { $($fnamed:tt)* } )?
+                + $on:ident $( ( $($epat:tt)* ) )? $( { $($enamedpat:tt)* } )?
+                $( if $guard:expr )?
+                => $to:ident $( ( $($targs:tt)* ) )? $( { $($tnamed:tt)* } )?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $state {
+            $( $(#[$smeta])* $svariant $( ( $($sfields)* ) )? $( { $($snamed)* } )? ),*
+        }
+
+        $(#[$emeta])*
+        $evis enum $event {
+            $( $(#[$evmeta])* $evariant $( ( $($efields)* ) )? $( { $($enamed)* } )? ),*
+        }
+
+        #[allow(dead_code)]
+        impl $state {
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($svariant)),*];
+            pub const TRANSITIONS: &'static [(&'static str, &'static str, &'static str)] =
+                &[$( (stringify!($from), stringify!($on), stringify!($to)) ),*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( $state::$svariant { .. } => stringify!($svariant), )*
+                }
+            }
+
+            pub fn transition(self, event: $event) -> Result<$state, $crate::state_machine::InvalidTransition<$state, $event>> {
+                match (self, event) {
+                    $(
+                        (
+                            $state::$from $( ( $($fpat)* ) )? $( { $($fnamed)* } )?,
+                            $event::$on $( ( $($epat)* ) )? $( { $($enamedpat)* } )?
+                        ) $( if $guard )? => Ok($state::$to $( ( $($targs)* ) )? $( { $($tnamed)* } )?),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    (state, event) => Err($crate::state_machine::InvalidTransition {
+                        state_name: state.name(),
+                        event_name: event.name(),
+                        state,
+                        event,
+                    }),
+                }
+            }
+
+            pub fn allowed_events(&self) -> Vec<&'static str> {
+                let name = self.name();
+                let mut events = Vec::new();
+                for (from, event, _) in Self::TRANSITIONS {
+                    if *from == name && !events.contains(event) {
+                        events.push(*event);
+                    }
+                }
+                events
+            }
+
+            pub fn to_dot() -> String {
+                $crate::state_machine::to_dot(stringify!($state), Self::NAMES, Self::TRANSITIONS)
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $event {
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($evariant)),*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( $event::$evariant { .. } => stringify!($evariant), )*
+                }
+            }
+        }
+    };
+}
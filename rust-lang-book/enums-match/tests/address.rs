@@ -0,0 +1,120 @@
+use enums_patterns::address::{Address, AddressError, Country, HouseNumber};
+
+fn parse(text: &str) -> Address {
+    text.parse().unwrap_or_else(|err| panic!("{:?}: {}", text, err))
+}
+
+#[test]
+fn house_numbers() {
+    assert_eq!("42".parse(), Ok(HouseNumber::new(42)));
+    for text in &["42a", "42 A", "42-a", " 42A "] {
+        assert_eq!(text.parse(), Ok(HouseNumber { value: 42, suffix: String::from("a") }), "{:?}", text);
+    }
+    for text in &["", "a42", "42ab", "42 1", "-42"] {
+        assert!(text.parse::<HouseNumber>().is_err(), "{:?}", text);
+    }
+    assert_eq!("42-".parse(), Ok(HouseNumber::new(42)));
+    assert_eq!("42ab".parse::<HouseNumber>(), Err(AddressError::InvalidNumber(String::from("42ab"))));
+}
+
+#[test]
+fn number_before_or_after_the_street() {
+    let us = parse("42 Washington Street, Atlanta, GA 30303, USA");
+    assert_eq!((us.street.as_str(), us.number.value, us.city.as_str()), ("Washington Street", 42, "Atlanta"));
+
+    let de = parse("hauptstr. 5 A\n10115 Berlin\nDeutschland");
+    assert_eq!((de.street.as_str(), de.number.to_string(), de.city.as_str()), ("Hauptstraße", "5a".into(), "Berlin"));
+    assert_eq!(de.postal_code.as_deref(), Some("10115"));
+    assert_eq!(de.country, Country::Germany);
+
+    let fr = parse("12 rue de Rivoli; 75001 Paris; France");
+    assert_eq!((fr.street.as_str(), fr.city.as_str()), ("rue de Rivoli", "Paris"));
+}
+
+#[test]
+fn street_abbreviations_are_expanded() {
+    for (text, street) in &[
+        ("1 main st, Dayton", "Main Street"),
+        ("1 Elm Rd., Dayton", "Elm Road"),
+        ("1 fifth ave, New York", "Fifth Avenue"),
+        ("1 sunset blvd, Los Angeles", "Sunset Boulevard"),
+        ("Bahnhofstr. 3, Zürich, CH", "Bahnhofstraße"),
+    ] {
+        assert_eq!(parse(text).street, *street, "{:?}", text);
+    }
+}
+
+#[test]
+fn zip_codes_make_an_address_american() {
+    //* a region and a five digit ZIP code, with or without the +4 extension
+    assert_eq!(parse("42 Washington Street, Atlanta, GA 30303").country, Country::UnitedStates);
+    let zip4 = parse("7 Main Street, Dayton, OH 45402-1234");
+    assert_eq!((zip4.country, zip4.postal_code.as_deref()), (Country::UnitedStates, Some("45402-1234")));
+
+    //* without a region five digits could just as well be German
+    assert_eq!(parse("Hauptstraße 5, 10115 Berlin").country, Country::Unknown);
+    assert_eq!(parse("42 Washington Street, Atlanta, GA 3030").country, Country::Unknown);
+    assert_eq!(parse("42 Washington Street, Atlanta, GA 303030").country, Country::Unknown);
+    //* a named country wins
+    assert_eq!(parse("Hauptstraße 5, 10115 Berlin, Brandenburg, Germany").country, Country::Germany);
+}
+
+#[test]
+fn uk_postcodes() {
+    for postcode in &["NW1 6XE", "SW1A 1AA", "M1 1AE", "B33 8TH", "CR2 6XH", "DN55 1PT", "W1A 0AX", "ec1a 1bb"] {
+        let address = parse(&format!("221b Baker Street, London {}", postcode));
+        assert_eq!(address.country, Country::UnitedKingdom, "{}", postcode);
+        assert_eq!(address.postal_code.as_deref(), Some(postcode.to_uppercase().as_str()));
+        assert_eq!(address.city, "London");
+    }
+    for not_a_postcode in &["NW1 XE6", "1W1 6XE", "NWW1 6XE", "NW1 6X", "NW16XE"] {
+        let address = parse(&format!("221b Baker Street, London {}", not_a_postcode));
+        assert_eq!(address.country, Country::Unknown, "{}", not_a_postcode);
+    }
+    let london = parse("221b baker st, London NW1 6XE");
+    assert_eq!(london.lines(), ["221B Baker Street", "London NW1 6XE", "UNITED KINGDOM"]);
+}
+
+#[test]
+fn errors() {
+    assert_eq!("".parse::<Address>(), Err(AddressError::Empty));
+    assert_eq!(" , \n ".parse::<Address>(), Err(AddressError::Empty));
+    let missing_number = AddressError::MissingNumber(String::from("Washington Street"));
+    assert_eq!("Washington Street, Atlanta".parse::<Address>(), Err(missing_number));
+    assert_eq!("42 Washington Street".parse::<Address>(), Err(AddressError::MissingCity));
+    assert_eq!("42 Washington Street, 30303".parse::<Address>(), Err(AddressError::MissingCity));
+}
+
+#[test]
+fn display_follows_the_country() {
+    let berlin = parse("hauptstr. 5 A\n10115 Berlin\nDeutschland");
+    assert_eq!(berlin.to_string(), "Hauptstraße 5a, 10115 Berlin, Germany");
+    let atlanta = Address::new("Washington Street", HouseNumber::new(42), "Atlanta", Country::UnitedStates)
+        .with_region("GA")
+        .with_postal_code("30303");
+    assert_eq!(atlanta.to_string(), "42 Washington Street, Atlanta, GA 30303, United States");
+    assert_eq!(atlanta.lines(), ["42 Washington Street", "Atlanta, GA 30303", "UNITED STATES"]);
+}
+
+#[test]
+fn display_round_trip() {
+    for text in &[
+        "42 Washington Street, Atlanta, GA 30303, USA",
+        "hauptstr. 5 A\n10115 Berlin\nDeutschland",
+        "221b baker st, London NW1 6XE",
+        "Bahnhofstr. 3, 8001 Zürich, Schweiz",
+        "12 rue de Rivoli, 75001 Paris, France",
+        "Damrak 1, 1012 LG Amsterdam, NL",
+        "7 Main Street, Springfield",
+    ] {
+        let address = parse(text);
+        assert_eq!(parse(&address.to_string()), address, "{:?} printed as {:?}", text, address.to_string());
+    }
+
+    //* an unknown country writes the code before the city, a ZIP code there doesn't make it the US
+    let unknown = Address::new("Main Street", HouseNumber::new(5), "Springfield", Country::Unknown)
+        .with_postal_code("12345")
+        .with_region("Oregon");
+    assert_eq!(unknown.to_string(), "5 Main Street, 12345 Springfield, Oregon");
+    assert_eq!(parse(&unknown.to_string()), unknown);
+}
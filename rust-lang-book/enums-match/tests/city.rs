@@ -0,0 +1,123 @@
+use std::fs;
+
+use enums_patterns::building::{Building, BuildingKind};
+use enums_patterns::city::{City, CityError};
+
+fn school(city: &str, name: &str) -> Building {
+    Building::School(name.to_string(), format!("1 Main Street, {}", city).parse().unwrap())
+}
+
+fn police(city: &str, district: i32) -> Building {
+    Building::PoliceStation(format!("{} Police Plaza, {}", district.abs(), city).parse().unwrap(), district)
+}
+
+fn house(address: &str) -> Building {
+    Building::House(address.parse().unwrap())
+}
+
+fn buildings(city: &City) -> Vec<Building> {
+    city.iter().map(|(_, building)| building.clone()).collect()
+}
+
+fn registry() -> City {
+    let mut city = City::new();
+    city.add(school("Dayton", "Dayton School"));
+    city.add(house("42 Washington Street, Atlanta, GA 30303, USA"));
+    city.add(police("Atlanta", 2));
+    city.add(police("Atlanta", 3));
+    city.add(school("atlanta", "Grady High"));
+    city
+}
+
+#[test]
+fn queries() {
+    let city = registry();
+    assert_eq!(city.len(), 5);
+    assert!(!city.is_empty());
+    assert_eq!(city.in_city("ATLANTA").count(), 4);
+    assert_eq!(city.in_city("Springfield").count(), 0);
+    assert_eq!(city.of_kind(BuildingKind::School).map(|(_, b)| b.city()).collect::<Vec<_>>(), ["Dayton", "atlanta"]);
+    assert_eq!(city.in_district("atlanta", 3).map(|(_, b)| b).collect::<Vec<_>>(), [&police("Atlanta", 3)]);
+    assert_eq!(city.in_district("Dayton", 2).count(), 0);
+    //* "atlanta" is the same city as "Atlanta", like for in_city()
+    assert_eq!(city.cities(), ["Atlanta", "Dayton"]);
+
+    let counts = city.count_by_kind();
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), [
+        (BuildingKind::School, 2),
+        (BuildingKind::House, 1),
+        (BuildingKind::PoliceStation, 2)
+    ]);
+}
+
+#[test]
+fn ids_stay_valid_until_demolished() {
+    let mut city = registry();
+    let ids: Vec<_> = city.iter().map(|(id, _)| id).collect();
+    assert_eq!(city.demolish(ids[1]), Some(house("42 Washington Street, Atlanta, GA 30303, USA")));
+    assert_eq!(city.demolish(ids[1]), None);
+    assert_eq!(city.get(ids[1]), None);
+    assert_eq!(city.get(ids[2]), Some(&police("Atlanta", 2)));
+
+    //* ids are never handed out twice
+    let new = city.add(school("Dayton", "Another School"));
+    assert!(!ids.contains(&new));
+    assert_eq!(city.iter().last(), Some((new, &school("Dayton", "Another School"))));
+}
+
+#[test]
+fn text_round_trip_escapes_separators() {
+    let mut city = City::new();
+    city.add(school("Back\\slash", "A | B School"));
+    city.add(police("Pipe|Town", -1));
+    city.add(school("Dayton", "Trailing \\"));
+    let text = city.to_string();
+    assert_eq!(
+        text,
+        "school | A \\| B School | 1 Main Street, Back\\\\slash\n\
+         police | 1 Police Plaza, Pipe\\|Town | -1\n\
+         school | Trailing \\\\ | 1 Main Street, Dayton\n"
+    );
+
+    let parsed: City = text.parse().unwrap();
+    assert_eq!(buildings(&parsed), buildings(&city));
+    assert_eq!(parsed.to_string(), text);
+}
+
+#[test]
+fn parsing_skips_comments_and_reports_lines() {
+    let city: City = "# buildings\n\n  SCHOOL|Dayton School|1 main st, Dayton  \npolice | 2 Police Plaza, Atlanta | 2\n"
+        .parse()
+        .unwrap();
+    assert_eq!(buildings(&city), [school("Dayton", "Dayton School"), police("Atlanta", 2)]);
+
+    let error = |text: &str| match text.parse::<City>() {
+        Err(CityError::Parse { line, message }) => (line, message),
+        other => panic!("{:?}", other),
+    };
+    let text = "school | a | 1 Main Street, Dayton\n\npolice | 2 Police Plaza, Atlanta | two";
+    assert_eq!(error(text), (3, "district 'two' is not a number".to_string()));
+    assert_eq!(error("school | Dayton School"), (1, "a school needs 3 fields, found 2".to_string()));
+    let message = "no house number found in 'Main Street'".to_string();
+    assert_eq!(error("school | Dayton School | Main Street, Dayton"), (1, message));
+    assert_eq!(error("police | 2 Police Plaza | 2"), (1, "the address has no city".to_string()));
+    assert_eq!(error("bank | Atlanta"), (1, "unknown building type 'bank'".to_string()));
+    assert_eq!(error("house | "), (1, "the address is empty".to_string()));
+}
+
+#[test]
+fn load_and_save() {
+    let dir = std::env::temp_dir().join(format!("enums-patterns-city-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("city.txt");
+
+    let city = registry();
+    city.save(&path).unwrap();
+    let loaded = City::load(&path).unwrap();
+    assert_eq!(buildings(&loaded), buildings(&city));
+
+    let missing = City::load(dir.join("missing.txt")).unwrap_err();
+    assert!(matches!(missing, CityError::Io(_)), "{:?}", missing);
+    assert!(missing.to_string().starts_with("could not access the city file: "));
+    fs::remove_dir_all(&dir).unwrap();
+}
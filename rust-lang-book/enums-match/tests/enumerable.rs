@@ -0,0 +1,89 @@
+use std::convert::TryFrom;
+
+use enums_patterns::enumerable;
+use enums_patterns::enumerable::{names_match, InvalidDiscriminant, ParseEnumError};
+
+enumerable! {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Building { School, Bank, PoliceStation }
+}
+
+enumerable! {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Level { Low = 1, Medium, High = 10, Max = 255, }
+}
+
+#[test]
+fn all_names_and_count_follow_declaration_order() {
+    assert_eq!(Building::ALL, [Building::School, Building::Bank, Building::PoliceStation]);
+    assert_eq!(Building::NAMES, ["School", "Bank", "PoliceStation"]);
+    assert_eq!(Building::count(), 3);
+    assert_eq!(Building::iter().collect::<Vec<_>>(), Building::ALL);
+    for (building, name) in Building::iter().zip(Building::NAMES.iter()) {
+        assert_eq!(building.name(), *name);
+        assert_eq!(building.to_string(), *name);
+    }
+
+    assert_eq!(Level::NAMES, ["Low", "Medium", "High", "Max"]);
+    assert_eq!(Level::iter().map(|level| level as u8).collect::<Vec<_>>(), [1, 2, 10, 255]);
+    //* Display pads like a str
+    assert_eq!(format!("[{:>8}]", Level::Max), "[     Max]");
+}
+
+#[test]
+fn from_str_ignores_case_and_separators() {
+    for text in &["PoliceStation", "policestation", "POLICESTATION", "police-station", "Police_Station",
+        "police station", "  pOlIcE-sTaTiOn\t", "p-o-l-i-c-e_station"]
+    {
+        assert_eq!(text.parse(), Ok(Building::PoliceStation), "{:?}", text);
+    }
+    assert_eq!("bank".parse(), Ok(Building::Bank));
+    assert_eq!("SCHOOL".parse(), Ok(Building::School));
+    for building in Building::iter() {
+        assert_eq!(building.to_string().parse(), Ok(building));
+        assert_eq!(building.to_string().to_lowercase().parse(), Ok(building));
+    }
+
+    assert!(names_match("Police-Station", "policestation"));
+    assert!(!names_match("police", "PoliceStation"));
+    assert!(!names_match("banks", "Bank"));
+}
+
+#[test]
+fn from_str_errors_list_the_variants() {
+    for text in &["", "   ", "church", "police", "banks", "School Bank"] {
+        let err = text.parse::<Building>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseEnumError { enum_name: "Building", input: text.to_string(), expected: &Building::NAMES },
+            "{:?}",
+            text
+        );
+    }
+    assert_eq!(
+        "church".parse::<Building>().unwrap_err().to_string(),
+        "'church' is not a valid Building, expected one of: School, Bank, PoliceStation"
+    );
+}
+
+#[test]
+fn try_from_u8_respects_explicit_discriminants() {
+    assert_eq!(Building::try_from(0), Ok(Building::School));
+    assert_eq!(Building::try_from(2), Ok(Building::PoliceStation));
+    assert_eq!(Level::try_from(1), Ok(Level::Low));
+    assert_eq!(Level::try_from(2), Ok(Level::Medium));
+    assert_eq!(Level::try_from(10), Ok(Level::High));
+    assert_eq!(Level::try_from(255), Ok(Level::Max));
+    for level in Level::iter() {
+        assert_eq!(Level::try_from(level as u8), Ok(level));
+    }
+
+    let valid = [1, 2, 10, 255];
+    for value in (0..=255u8).filter(|value| !valid.contains(value)) {
+        assert_eq!(Level::try_from(value), Err(InvalidDiscriminant { enum_name: "Level", value }));
+    }
+    for value in 3..=255u8 {
+        assert!(Building::try_from(value).is_err(), "{}", value);
+    }
+    assert_eq!(Level::try_from(0).unwrap_err().to_string(), "0 is not a discriminant of Level");
+}
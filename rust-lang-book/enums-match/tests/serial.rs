@@ -0,0 +1,224 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+
+use enums_patterns::address::Address;
+use enums_patterns::building::{Building, BuildingKind};
+use enums_patterns::serial::{self, DecodeErrorKind, Deserialize, Serialize, Value, MAX_DEPTH};
+use enums_patterns::serializable;
+
+fn round_trip<T: Serialize + Deserialize + PartialEq + Debug>(value: T) {
+    let json = serial::to_json(&value);
+    assert_eq!(serial::from_json::<T>(&json).as_ref(), Ok(&value), "{}", json);
+    let pretty = serial::to_json_pretty(&value);
+    assert_eq!(serial::from_json::<T>(&pretty).as_ref(), Ok(&value), "{}", pretty);
+    let bytes = serial::to_binary(&value);
+    assert_eq!(serial::from_binary::<T>(&bytes), Ok(value));
+}
+
+serializable! {
+    #[derive(Debug, PartialEq)]
+    enum SimpleBuilding { School, Bank, PoliceStation }
+}
+
+serializable! {
+    #[derive(Debug, PartialEq)]
+    enum ChapterBuilding {
+        School(String, String),
+        House(String, String, i32),
+        PoliceStation(String, i32),
+    }
+}
+
+serializable! {
+    #[derive(Debug, PartialEq)]
+    enum T { Int(i32), Float(f32), Text(String) }
+}
+
+serializable! {
+    #[derive(Debug, PartialEq)]
+    enum Shape {
+        Point,
+        Circle { x: f64, y: f64, radius: f64 },
+        Polygon(Vec<(f64, f64)>),
+    }
+}
+
+serializable! {
+    #[derive(Debug, PartialEq)]
+    struct Tree {
+        name: String,
+        children: Vec<Tree>,
+        note: Option<String>,
+    }
+}
+
+#[test]
+fn chapter_enums_round_trip() {
+    round_trip(SimpleBuilding::School);
+    round_trip(SimpleBuilding::Bank);
+    round_trip(SimpleBuilding::PoliceStation);
+    round_trip(ChapterBuilding::School(String::from("Dayton"), String::from("Dayton School")));
+    round_trip(ChapterBuilding::House(String::from("Atlanta"), String::from("Washington Street"), 42));
+    round_trip(ChapterBuilding::PoliceStation(String::from("Atlanta"), -2));
+    round_trip(vec![T::Int(-7), T::Float(2.5), T::Text(String::from("\"quoted\" \u{1F4A5}\n\t\u{1}"))]);
+    round_trip(Shape::Point);
+    round_trip(Shape::Circle { x: 1.0, y: -2.5, radius: 0.1 });
+    round_trip(Shape::Polygon(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]));
+    round_trip(Shape::Polygon(Vec::new()));
+}
+
+#[test]
+fn std_types_round_trip() {
+    round_trip(Some(100));
+    round_trip(None::<i32>);
+    round_trip(Ok::<u8, String>(7));
+    round_trip(Err::<u8, String>(String::from("failed")));
+    round_trip((i64::MIN, u64::MAX));
+    round_trip((f64::MAX, f64::MIN_POSITIVE));
+    round_trip(vec![0.1f64, -0.0, 1e300, 23.0]);
+    round_trip('\u{10FFFF}');
+    round_trip(String::new());
+    round_trip(Box::new(-1i8));
+    round_trip(BTreeMap::from([(String::from("a"), 1u8), (String::from("\""), 2)]));
+    round_trip(HashMap::from([(String::from("x"), vec![true, false])]));
+}
+
+#[test]
+fn library_types_round_trip() {
+    let address = |text: &str| text.parse::<Address>().unwrap();
+    let main_street = address("1 Main Street, Dayton, OH 45402, USA");
+    round_trip(Building::School(String::from("Dayton School"), main_street));
+    round_trip(Building::House(address("42 Washington Street, Atlanta, GA 30303, USA")));
+    round_trip(Building::PoliceStation(address("Hauptstr. 5a, 10115 Berlin, Germany"), 2));
+    round_trip(BuildingKind::PoliceStation);
+}
+
+#[test]
+fn nested_structs_round_trip() {
+    let mut tree = Tree { name: String::from("leaf"), children: Vec::new(), note: None };
+    //* every level is a map and a sequence
+    for depth in 0..MAX_DEPTH / 2 - 1 {
+        tree = Tree { name: depth.to_string(), children: vec![tree], note: Some(String::from("node")) };
+    }
+    round_trip(tree);
+}
+
+#[test]
+fn formats() {
+    assert_eq!(serial::to_json(&SimpleBuilding::Bank), r#""Bank""#);
+    assert_eq!(serial::to_json(&T::Int(3)), r#"{"Int": [3]}"#);
+    let circle = Shape::Circle { x: 1.0, y: 2.0, radius: 3.0 };
+    assert_eq!(serial::to_json(&circle), r#"{"Circle": {"x": 1.0, "y": 2.0, "radius": 3.0}}"#);
+    assert_eq!(serial::to_binary(&SimpleBuilding::Bank), b"\x06\x04Bank");
+    assert_eq!(serial::to_json(&f64::NAN), "null");
+}
+
+fn syntax_error(json: &str) -> String {
+    match serial::parse_json(json) {
+        Err(err) => match err.kind {
+            DecodeErrorKind::Syntax { message, .. } => message,
+            other => panic!("{:?}: {:?}", json, other),
+        },
+        Ok(value) => panic!("{:?} was accepted as {:?}", json, value),
+    }
+}
+
+#[test]
+fn json_numbers() {
+    assert_eq!(serial::parse_json("0"), Ok(Value::UInt(0)));
+    assert_eq!(serial::parse_json("-0"), Ok(Value::Int(0)));
+    assert_eq!(serial::parse_json("10"), Ok(Value::UInt(10)));
+    assert_eq!(serial::parse_json("-12"), Ok(Value::Int(-12)));
+    assert_eq!(serial::parse_json("0.5"), Ok(Value::Float(0.5)));
+    assert_eq!(serial::parse_json("-1.5e3"), Ok(Value::Float(-1500.0)));
+    assert_eq!(serial::parse_json("1E+2"), Ok(Value::Float(100.0)));
+    assert_eq!(serial::parse_json("2e-1"), Ok(Value::Float(0.2)));
+
+    assert_eq!(syntax_error("01"), "invalid number '01', leading zeros are not allowed");
+    assert_eq!(syntax_error("-007"), "invalid number '-007', leading zeros are not allowed");
+    assert_eq!(syntax_error("[00]"), "invalid number '00', leading zeros are not allowed");
+    assert_eq!(syntax_error("-"), "invalid number '-', expected a digit");
+    assert_eq!(syntax_error("--1"), "invalid number '-', expected a digit");
+    assert_eq!(syntax_error("1."), "invalid number '1.', expected a digit after '.'");
+    assert_eq!(syntax_error("1.e5"), "invalid number '1.', expected a digit after '.'");
+    assert_eq!(syntax_error("1e"), "invalid number '1e', expected a digit in the exponent");
+    assert_eq!(syntax_error("1e+"), "invalid number '1e+', expected a digit in the exponent");
+    assert_eq!(syntax_error("0x10"), "invalid number '0x', unexpected character");
+    assert_eq!(syntax_error("1.5.3"), "invalid number '1.5.', unexpected character");
+    assert_eq!(syntax_error("1-2"), "invalid number '1-', unexpected character");
+    assert_eq!(syntax_error("+1"), "expected a JSON value");
+    assert_eq!(syntax_error(".5"), "expected a JSON value");
+    assert_eq!(syntax_error("18446744073709551616"), "invalid number '18446744073709551616'");
+}
+
+#[test]
+fn rejected_json() {
+    assert_eq!(syntax_error("[1 2]"), "expected ',' or ']'");
+    assert_eq!(syntax_error("{\"a\" 1}"), "expected ':'");
+    assert_eq!(syntax_error("{1: 2}"), "expected a string key");
+    assert_eq!(syntax_error("[1,]"), "expected a JSON value");
+    assert_eq!(syntax_error("nul"), "expected 'null'");
+    assert_eq!(syntax_error("\"\\x\""), "invalid escape sequence");
+    assert_eq!(syntax_error("\"\\ud800\""), "expected a low surrogate");
+    assert_eq!(syntax_error("\"tab\there\""), "control character in string");
+    assert_eq!(syntax_error("1 2"), "unexpected characters after the value");
+    for truncated in &["", "[1, 2", "{\"a\": ", "\"open"] {
+        let err = serial::parse_json(truncated).unwrap_err();
+        assert_eq!(err.kind, DecodeErrorKind::UnexpectedEof, "{:?}", truncated);
+    }
+}
+
+#[test]
+fn deep_nesting_is_an_error_not_a_stack_overflow() {
+    let too_deep = DecodeErrorKind::TooDeep { limit: MAX_DEPTH };
+
+    let ok = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+    assert!(serial::parse_json(&ok).is_ok());
+    let deeper = format!("{}{}", "[".repeat(MAX_DEPTH + 1), "]".repeat(MAX_DEPTH + 1));
+    assert_eq!(serial::parse_json(&deeper).unwrap_err().kind, too_deep);
+    assert_eq!(serial::parse_json(&"[".repeat(200_000)).unwrap_err().kind, too_deep);
+    assert_eq!(serial::parse_json(&"{\"a\": ".repeat(200_000)).unwrap_err().kind, too_deep);
+
+    //* the binary format: a sequence of one element, over and over
+    let ok: Vec<u8> = [7, 1].repeat(MAX_DEPTH - 1).into_iter().chain([7, 0]).collect();
+    assert!(serial::parse_binary(&ok).is_ok());
+    let deeper: Vec<u8> = [7, 1].repeat(MAX_DEPTH).into_iter().chain([7, 0]).collect();
+    assert_eq!(serial::parse_binary(&deeper).unwrap_err().kind, too_deep);
+    let maps: Vec<u8> = [8, 1, 0].repeat(200_000);
+    assert_eq!(serial::parse_binary(&maps).unwrap_err().kind, too_deep);
+    assert_eq!(serial::from_binary::<Vec<Vec<u8>>>(&[7, 1].repeat(200_000)).unwrap_err().kind, too_deep);
+    let message = serial::parse_json(&"[".repeat(MAX_DEPTH + 1)).unwrap_err().to_string();
+    assert_eq!(message, format!("values are nested deeper than {} levels", MAX_DEPTH));
+}
+
+#[test]
+fn binary_errors() {
+    assert_eq!(serial::parse_binary(&[9]).unwrap_err().kind, DecodeErrorKind::InvalidTag(9));
+    assert_eq!(serial::parse_binary(&[6, 5, b'a']).unwrap_err().kind, DecodeErrorKind::UnexpectedEof);
+    assert_eq!(serial::parse_binary(&[6, 1, 0xff]).unwrap_err().kind, DecodeErrorKind::InvalidUtf8);
+    assert_eq!(serial::parse_binary(&[0, 0]).unwrap_err().kind, DecodeErrorKind::TrailingData(1));
+    //* a huge length doesn't allocate before it is checked against the input
+    let huge = [7, 0xff, 0xff, 0xff, 0xff, 0x0f];
+    assert_eq!(serial::parse_binary(&huge).unwrap_err().kind, DecodeErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn decoding_errors_name_the_type_and_the_path() {
+    let err = serial::from_json::<ChapterBuilding>(r#"{"Church": ["Dayton"]}"#).unwrap_err();
+    assert!(matches!(err.kind, DecodeErrorKind::UnknownVariant { enum_name: "ChapterBuilding", .. }));
+    let expected = "unknown variant `Church` of ChapterBuilding, expected one of: School, House, PoliceStation";
+    assert_eq!(err.to_string(), expected);
+
+    let err = serial::from_json::<Shape>(r#"{"Circle": {"x": 1.0, "y": 2.0}}"#).unwrap_err();
+    assert_eq!(err.kind, DecodeErrorKind::MissingField { type_name: "Circle", field: "radius" });
+    assert_eq!(err.to_string(), "at Circle.radius: missing field `radius` in Circle");
+
+    let err = serial::from_json::<Building>(r#"{"House": [{"street": "Main Street", "number": {"value": -1}}]}"#);
+    assert_eq!(err.unwrap_err().to_string(), "at House[0].number.value: -1 does not fit into u32");
+
+    let house = Building::House("42 Washington Street, Atlanta, GA 30303, USA".parse().unwrap());
+    let bytes = serial::to_binary(&house);
+    for len in 0..bytes.len() {
+        assert_eq!(serial::from_binary::<Building>(&bytes[..len]).unwrap_err().kind, DecodeErrorKind::UnexpectedEof);
+    }
+}
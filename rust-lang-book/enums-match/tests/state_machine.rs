@@ -0,0 +1,113 @@
+use enums_patterns::state_machine;
+use enums_patterns::state_machine::InvalidTransition;
+
+state_machine! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Door { Open, Closed, Locked(u32) }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DoorEvent { Push, Pull, Lock(u32), Unlock(u32) }
+
+    transitions {
+        Open + Push => Closed,
+        Closed + Pull => Open,
+        Closed + Lock(code) => Locked(code),
+        Locked(code) + Unlock(key) if code == key => Closed,
+    }
+}
+
+state_machine! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Construction { Planned, Building { progress: u8 }, Done }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Work { Start, Progress(u8) }
+
+    transitions {
+        Planned + Start => Building { progress: 0 },
+        Building { progress } + Progress(p) if progress.saturating_add(p) < 100 => Building { progress: progress + p },
+        Building { .. } + Progress(_) => Done,
+    }
+}
+
+fn invalid(state: Door, event: DoorEvent) -> InvalidTransition<Door, DoorEvent> {
+    let (state_name, event_name) = (state.name(), event.name());
+    InvalidTransition { state, event, state_name, event_name }
+}
+
+#[test]
+fn declared_transitions_are_taken() {
+    assert_eq!(Door::Open.transition(DoorEvent::Push), Ok(Door::Closed));
+    assert_eq!(Door::Closed.transition(DoorEvent::Pull), Ok(Door::Open));
+    assert_eq!(Door::Closed.transition(DoorEvent::Lock(1234)), Ok(Door::Locked(1234)));
+    assert_eq!(Door::Locked(1234).transition(DoorEvent::Unlock(1234)), Ok(Door::Closed));
+
+    //* the first matching transition wins, later ones only apply when the guard fails
+    let mut state = Construction::Planned;
+    for (event, expected) in [
+        (Work::Start, Construction::Building { progress: 0 }),
+        (Work::Progress(40), Construction::Building { progress: 40 }),
+        (Work::Progress(59), Construction::Building { progress: 99 }),
+        (Work::Progress(1), Construction::Done),
+    ] {
+        state = state.transition(event).unwrap();
+        assert_eq!(state, expected);
+    }
+    let almost = Construction::Building { progress: 99 };
+    assert_eq!(almost.transition(Work::Progress(u8::MAX)), Ok(Construction::Done));
+}
+
+#[test]
+fn forbidden_events_hand_state_and_event_back() {
+    assert_eq!(Door::Open.transition(DoorEvent::Pull), Err(invalid(Door::Open, DoorEvent::Pull)));
+    assert_eq!(Door::Open.transition(DoorEvent::Lock(1)), Err(invalid(Door::Open, DoorEvent::Lock(1))));
+    assert_eq!(Door::Locked(7).transition(DoorEvent::Pull), Err(invalid(Door::Locked(7), DoorEvent::Pull)));
+
+    //* a failing guard counts as a forbidden event
+    let err = Door::Locked(1234).transition(DoorEvent::Unlock(4321)).unwrap_err();
+    assert_eq!(err, invalid(Door::Locked(1234), DoorEvent::Unlock(4321)));
+    assert_eq!(err.to_string(), "event Unlock is not allowed in state Locked");
+
+    //* the caller carries on with the state it got back
+    assert_eq!(err.state.transition(DoorEvent::Unlock(1234)), Ok(Door::Closed));
+
+    //* final states reject everything
+    for event in [Work::Start, Work::Progress(0)] {
+        let err = Construction::Done.transition(event.clone()).unwrap_err();
+        assert_eq!((err.state, err.event), (Construction::Done, event));
+    }
+}
+
+#[test]
+fn names_and_allowed_events() {
+    assert_eq!(Door::NAMES, ["Open", "Closed", "Locked"]);
+    assert_eq!(DoorEvent::NAMES, ["Push", "Pull", "Lock", "Unlock"]);
+    assert_eq!(Door::Locked(1).name(), "Locked");
+    assert_eq!(Construction::Building { progress: 3 }.name(), "Building");
+    assert_eq!(Door::TRANSITIONS, [
+        ("Open", "Push", "Closed"),
+        ("Closed", "Pull", "Open"),
+        ("Closed", "Lock", "Locked"),
+        ("Locked", "Unlock", "Closed")
+    ]);
+
+    assert_eq!(Door::Closed.allowed_events(), ["Pull", "Lock"]);
+    //* guards are not evaluated
+    assert_eq!(Door::Locked(1).allowed_events(), ["Unlock"]);
+    //* events with several transitions are listed once
+    assert_eq!(Construction::Building { progress: 0 }.allowed_events(), ["Progress"]);
+    assert!(Construction::Done.allowed_events().is_empty());
+}
+
+#[test]
+fn dot_output() {
+    assert_eq!(
+        Construction::to_dot(),
+        "digraph Construction {\n    rankdir=LR;\n    node [shape=circle];\n    __start [shape=point];\n    \
+         __start -> \"Planned\";\n    \"Planned\" [shape=circle];\n    \"Building\" [shape=circle];\n    \
+         \"Done\" [shape=doublecircle];\n    \"Planned\" -> \"Building\" [label=\"Start\"];\n    \
+         \"Building\" -> \"Building\" [label=\"Progress\"];\n    \"Building\" -> \"Done\" [label=\"Progress\"];\n}\n"
+    );
+    let empty = state_machine::to_dot("Empty", &[], &[]);
+    assert_eq!(empty, "digraph Empty {\n    rankdir=LR;\n    node [shape=circle];\n}\n");
+}
@@ -0,0 +1,397 @@
+use std::collections::btree_map::{self, BTreeMap};
+use std::env;
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Context, Error, Kind, Result};
+
+//* A loader for configuration files in a small subset of INI and TOML
+//*     # comments start with '#' or ';'
+//*     name = "ferris"                 keys before the first section have no section
+//*
+//*     [server]
+//*     host = localhost                bare values run until the end of the line or a comment
+//*     port = 8080                     server.port
+//*     motd = "Hello,\n\"world\""      quoted strings know \" \\ \n \r \t
+//*
+//* A key is looked up as "section.key" and can come from three places, the first one wins
+//*     (1) an environment variable, PREFIX_SECTION_KEY in upper case, e.g. APP_SERVER_PORT for server.port
+//*     (2) the file
+//*     (3) the defaults given to the Loader
+//* An environment variable only overrides keys that the file or the defaults know about
+//*
+//* Everything is stored as text and converted by the typed getters with FromStr: get::<u16>("server.port")
+//* Errors say where the offending text came from, for the file that is its path, line and column:
+//*     app.toml:3:8: expected '=' after the key (parse error)
+//* A missing file is Kind::NotFound like in the chapter's match on ErrorKind::NotFound, a bad file Kind::Parse,
+//* the ParseError with the location can be taken out of the Error with downcast_ref::<ParseError>()
+
+#[derive(Debug, Clone, Default)]
+pub struct Loader {
+    defaults: Vec<(String, String)>,
+    env_prefix: Option<String>,
+}
+
+impl Loader {
+    pub fn new() -> Loader {
+        Loader::default()
+    }
+
+    //* The value for 'key' if neither the file nor the environment have one
+    pub fn with_default(mut self, key: &str, value: impl ToString) -> Loader {
+        self.defaults.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    //* Lets PREFIX_SECTION_KEY environment variables override the values
+    pub fn env_prefix(mut self, prefix: &str) -> Loader {
+        self.env_prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Config> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).with_context(|| format!("reading config file {}", path.display()))?;
+        self.parse(path, &text)
+    }
+
+    //* Like load(), but a missing file is treated like an empty one
+    pub fn load_optional(&self, path: impl AsRef<Path>) -> Result<Config> {
+        match self.load(&path) {
+            Err(err) if err.kind() == Kind::NotFound => self.parse(path, ""),
+            result => result,
+        }
+    }
+
+    //* Reads the configuration from 'text', 'path' is only used in error messages
+    pub fn parse(&self, path: impl AsRef<Path>, text: &str) -> Result<Config> {
+        let path = path.as_ref();
+        let mut config = Config { entries: BTreeMap::new(), sections: Vec::new() };
+
+        for (key, value) in &self.defaults {
+            config.entries.insert(key.clone(), Entry { value: value.clone(), origin: Origin::Default });
+        }
+
+        let mut section = String::new();
+        let mut from_file: BTreeMap<String, usize> = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let error = |column: usize, message: String| {
+                let origin = Origin::File { path: path.to_path_buf(), line: i + 1, column };
+                Error::new(Kind::Parse, ParseError { origin, message })
+            };
+            match parse_line(line).map_err(|(column, message)| error(column, message))? {
+                Line::Blank => {}
+                Line::Section(name) => {
+                    if !config.sections.contains(&name) {
+                        config.sections.push(name.clone());
+                    }
+                    section = name;
+                }
+                Line::Pair { key, key_column, value, value_column } => {
+                    let key = if section.is_empty() { key } else { format!("{}.{}", section, key) };
+                    if let Some(first) = from_file.insert(key.clone(), i + 1) {
+                        let message = format!("duplicate key {}, it was first set on line {}", key, first);
+                        return Err(error(key_column, message));
+                    }
+                    let origin = Origin::File { path: path.to_path_buf(), line: i + 1, column: value_column };
+                    config.entries.insert(key, Entry { value, origin });
+                }
+            }
+        }
+
+        if let Some(prefix) = &self.env_prefix {
+            for (key, entry) in &mut config.entries {
+                let var = env_var_name(prefix, key);
+                if let Ok(value) = env::var(&var) {
+                    *entry = Entry { value, origin: Origin::Env(var) };
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+//* "server.port" with prefix "APP" is APP_SERVER_PORT
+pub fn env_var_name(prefix: &str, key: &str) -> String {
+    let key: String = key.chars().map(|c| if c == '.' || c == '-' { '_' } else { c.to_ascii_uppercase() }).collect();
+    format!("{}_{}", prefix, key)
+}
+
+//* Where a value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    //* line and column count from 1, the column in characters
+    File { path: PathBuf, line: usize, column: usize },
+    Env(String),
+    Default,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::File { path, line, column } => write!(f, "{}:{}:{}", path.display(), line, column),
+            Origin::Env(var) => write!(f, "environment variable {}", var),
+            Origin::Default => write!(f, "default value"),
+        }
+    }
+}
+
+//* A syntax error in the file or a value that doesn't convert to the requested type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub origin: Origin,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.origin, self.message)
+    }
+}
+
+impl StdError for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    value: String,
+    origin: Origin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    entries: BTreeMap<String, Entry>,
+    //* in the order they first appear in the file
+    sections: Vec<String>,
+}
+
+impl Config {
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    //* Kind::NotFound if the key isn't set
+    pub fn get_str(&self, key: &str) -> Result<&str> {
+        self.entry(key).map(|entry| entry.value.as_str())
+    }
+
+    //* Converts the value with FromStr, a value that doesn't convert is a ParseError at the value
+    //* Quotes don't matter, port = "8080" is an integer as well, like every environment variable is text
+    pub fn get<T>(&self, key: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let entry = self.entry(key)?;
+        entry.value.parse().map_err(|err| {
+            let message = format!("invalid value {:?} for {}: {}", entry.value, key, err);
+            Error::new(Kind::Parse, ParseError { origin: entry.origin.clone(), message })
+        })
+    }
+
+    //* None if the key isn't set, but still an error if it doesn't convert
+    pub fn get_opt<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if self.contains(key) {
+            self.get(key).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn get_or<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.get_opt(key).map(|value| value.unwrap_or(default))
+    }
+
+    pub fn origin(&self, key: &str) -> Option<&Origin> {
+        self.entries.get(key).map(|entry| &entry.origin)
+    }
+
+    //* All keys with their section in front, sorted
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(String::as_str)
+    }
+
+    //* The keys and values of one section, without the section in front
+    //* "" is the part before the first section
+    pub fn section<'a>(&'a self, name: &str) -> Section<'a> {
+        let prefix = if name.is_empty() { String::new() } else { format!("{}.", name) };
+        Section { entries: self.entries.range(prefix.clone()..), prefix }
+    }
+
+    fn entry(&self, key: &str) -> Result<&Entry> {
+        self.entries.get(key).ok_or_else(|| Error::new(Kind::NotFound, format!("{} is not set", key)))
+    }
+}
+
+//* Iterator returned by Config::section()
+pub struct Section<'a> {
+    entries: btree_map::Range<'a, String, Entry>,
+    prefix: String,
+}
+
+impl<'a> Iterator for Section<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, entry) in &mut self.entries {
+            let key = key.strip_prefix(&self.prefix)?;
+            //* keys have no dots, so anything with a dot belongs to a nested section
+            if !key.contains('.') {
+                return Some((key, entry.value.as_str()));
+            }
+        }
+        None
+    }
+}
+
+enum Line {
+    Blank,
+    Section(String),
+    Pair { key: String, key_column: usize, value: String, value_column: usize },
+}
+
+//* A line as characters, errors are (column, message)
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Cursor {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn column(&self) -> usize {
+        self.pos + 1
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+            self.pos += 1;
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    //* Only spaces or a comment may follow
+    fn expect_end(&mut self, what: &str) -> std::result::Result<(), (usize, String)> {
+        self.skip_spaces();
+        match self.peek() {
+            None | Some('#') | Some(';') => Ok(()),
+            Some(c) => Err((self.column(), format!("unexpected {:?} after {}", c, what))),
+        }
+    }
+}
+
+fn is_comment(c: char) -> bool {
+    c == '#' || c == ';'
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+fn parse_line(line: &str) -> std::result::Result<Line, (usize, String)> {
+    let mut cursor = Cursor { chars: line.chars().collect(), pos: 0 };
+    cursor.skip_spaces();
+
+    match cursor.peek() {
+        None => Ok(Line::Blank),
+        Some(c) if is_comment(c) => Ok(Line::Blank),
+        Some('[') => {
+            cursor.pos += 1;
+            cursor.skip_spaces();
+            let start = cursor.column();
+            let name = cursor.take_while(|c| is_key_char(c) || c == '.');
+            if name.is_empty() || name.starts_with('.') || name.ends_with('.') || name.contains("..") {
+                return Err((start, "expected a section name like [server] or [server.tls]".to_string()));
+            }
+            cursor.skip_spaces();
+            if cursor.peek() != Some(']') {
+                return Err((cursor.column(), "expected ']' after the section name".to_string()));
+            }
+            cursor.pos += 1;
+            cursor.expect_end("the section header")?;
+            Ok(Line::Section(name))
+        }
+        Some(_) => {
+            let key_column = cursor.column();
+            let key = cursor.take_while(is_key_char);
+            if key.is_empty() {
+                return Err((key_column, "expected a key made of letters, digits, '_' and '-'".to_string()));
+            }
+            cursor.skip_spaces();
+            if cursor.peek() != Some('=') {
+                return Err((cursor.column(), "expected '=' after the key".to_string()));
+            }
+            cursor.pos += 1;
+            cursor.skip_spaces();
+            let value_column = cursor.column();
+            let value = if cursor.peek() == Some('"') {
+                let value = parse_quoted(&mut cursor)?;
+                cursor.expect_end("the string")?;
+                value
+            } else {
+                cursor.take_while(|c| !is_comment(c)).trim_end().to_string()
+            };
+            if value.is_empty() && value_column == cursor.column() {
+                return Err((value_column, format!("missing value for {}", key)));
+            }
+            Ok(Line::Pair { key, key_column, value, value_column })
+        }
+    }
+}
+
+fn parse_quoted(cursor: &mut Cursor) -> std::result::Result<String, (usize, String)> {
+    let start = cursor.column();
+    cursor.pos += 1;
+    let mut value = String::new();
+    loop {
+        match cursor.peek() {
+            None => return Err((start, "unterminated string".to_string())),
+            Some('"') => {
+                cursor.pos += 1;
+                return Ok(value);
+            }
+            Some('\\') => {
+                let column = cursor.column();
+                cursor.pos += 1;
+                value.push(match cursor.peek() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some(c) => return Err((column, format!("unknown escape sequence \\{}", c))),
+                    None => return Err((start, "unterminated string".to_string())),
+                });
+                cursor.pos += 1;
+            }
+            Some(c) => {
+                value.push(c);
+                cursor.pos += 1;
+            }
+        }
+    }
+}
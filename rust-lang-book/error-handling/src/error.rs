@@ -0,0 +1,316 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+//* One error type for the whole package, instead of a Box<dyn Error> that can only be printed
+//*     Error       what went wrong (a Kind to match on), why (its sources) and where (a backtrace)
+//*     Result<T>   short for Result<T, Error>
+//*     Context     adds what we were doing to an error: File::open(path).context("opening hello.txt")?
+//*     Report      prints an error with all of its causes, main returns it so a failure is readable
+//*
+//* Each .context() puts another error on top of the chain, so the report reads from the outside in:
+//*     reading the username (not found)
+//*
+//*     Caused by:
+//*         0: opening hello.txt
+//*         1: No such file or directory (os error 2)
+//*
+//* The backtrace is captured where the first Error is created, if RUST_BACKTRACE=1 (or RUST_LIB_BACKTRACE=1) is set
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+//* What kind of failure it was, the io ones mirror io::ErrorKind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    NotFound,
+    PermissionDenied,
+    AlreadyExists,
+    InvalidInput,
+    InvalidData,
+    UnexpectedEof,
+    Interrupted,
+    WouldBlock,
+    TimedOut,
+    //* any other io::ErrorKind
+    Io,
+    //* text that couldn't be parsed into a value
+    Parse,
+    Other,
+}
+
+impl Kind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Kind::NotFound => "not found",
+            Kind::PermissionDenied => "permission denied",
+            Kind::AlreadyExists => "already exists",
+            Kind::InvalidInput => "invalid input",
+            Kind::InvalidData => "invalid data",
+            Kind::UnexpectedEof => "unexpected end of file",
+            Kind::Interrupted => "interrupted",
+            Kind::WouldBlock => "would block",
+            Kind::TimedOut => "timed out",
+            Kind::Io => "i/o error",
+            Kind::Parse => "parse error",
+            Kind::Other => "other error",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<io::ErrorKind> for Kind {
+    fn from(kind: io::ErrorKind) -> Kind {
+        match kind {
+            io::ErrorKind::NotFound => Kind::NotFound,
+            io::ErrorKind::PermissionDenied => Kind::PermissionDenied,
+            io::ErrorKind::AlreadyExists => Kind::AlreadyExists,
+            io::ErrorKind::InvalidInput => Kind::InvalidInput,
+            io::ErrorKind::InvalidData => Kind::InvalidData,
+            io::ErrorKind::UnexpectedEof => Kind::UnexpectedEof,
+            io::ErrorKind::Interrupted => Kind::Interrupted,
+            io::ErrorKind::WouldBlock => Kind::WouldBlock,
+            io::ErrorKind::TimedOut => Kind::TimedOut,
+            _ => Kind::Io,
+        }
+    }
+}
+
+//* Boxed, so a Result<T> is not much bigger than a T
+pub struct Error {
+    inner: Box<Inner>,
+}
+
+struct Inner {
+    kind: Kind,
+    repr: Repr,
+    //* only the outermost error of a chain holds the captured backtrace, context() moves it up
+    backtrace: Backtrace,
+}
+
+enum Repr {
+    //* Transparent: displays as the wrapped error and continues with its sources
+    Wrapped(Box<dyn StdError + Send + Sync>),
+    //* What we were doing when 'source' happened, inherits its kind
+    Context { context: String, source: Error },
+}
+
+impl Error {
+    //* Anything that converts into a Box<dyn Error> works as the cause, a &str or a String included
+    //*     Error::new(Kind::InvalidInput, "the username must not be empty")
+    pub fn new<E>(kind: Kind, error: E) -> Error
+    where
+        E: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        Error { inner: Box::new(Inner { kind, repr: Repr::Wrapped(error.into()), backtrace: Backtrace::capture() }) }
+    }
+
+    pub fn kind(&self) -> Kind {
+        self.inner.kind
+    }
+
+    //* Puts 'context' on top of this error, the kind stays the same
+    pub fn context<C: fmt::Display>(mut self, context: C) -> Error {
+        let backtrace = mem::replace(&mut self.inner.backtrace, Backtrace::disabled());
+        let kind = self.inner.kind;
+        let repr = Repr::Context { context: context.to_string(), source: self };
+        Error { inner: Box::new(Inner { kind, repr, backtrace }) }
+    }
+
+    //* This error followed by its source, the source's source and so on down to the root cause
+    pub fn sources(&self) -> Sources<'_> {
+        Sources { next: Some(self.as_dyn()) }
+    }
+
+    //* The innermost error, the one that started it all
+    pub fn root_cause(&self) -> &(dyn StdError + 'static) {
+        self.sources().last().expect("the chain starts with the error itself")
+    }
+
+    //* The first error in the chain that is an E, e.g. to get at the io::Error behind a context
+    pub fn downcast_ref<E: StdError + 'static>(&self) -> Option<&E> {
+        self.sources().find_map(|error| error.downcast_ref::<E>())
+    }
+
+    //* Has something in it only if backtraces were enabled when the error was created, see backtrace().status()
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.inner.backtrace
+    }
+
+    //* A wrapped error stands in for the Error around it, so downcasting along the chain finds it
+    fn as_dyn(&self) -> &(dyn StdError + 'static) {
+        match &self.inner.repr {
+            Repr::Wrapped(error) => &**error,
+            Repr::Context { .. } => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.inner.repr {
+            Repr::Wrapped(error) => fmt::Display::fmt(error, f),
+            Repr::Context { context, .. } => f.write_str(context),
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Error");
+        debug.field("kind", &self.inner.kind);
+        match &self.inner.repr {
+            Repr::Wrapped(error) => debug.field("error", error),
+            Repr::Context { context, source } => debug.field("context", context).field("source", source),
+        };
+        debug.finish()
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner.repr {
+            Repr::Wrapped(error) => error.source(),
+            Repr::Context { source, .. } => Some(source.as_dyn()),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::new(error.kind().into(), error)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Error {
+        Error::new(Kind::Parse, error)
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(error: ParseFloatError) -> Error {
+        Error::new(Kind::Parse, error)
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(error: Utf8Error) -> Error {
+        Error::new(Kind::InvalidData, error)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(error: FromUtf8Error) -> Error {
+        Error::new(Kind::InvalidData, error)
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(error: fmt::Error) -> Error {
+        Error::new(Kind::Other, error)
+    }
+}
+
+//* Iterator returned by Error::sources()
+pub struct Sources<'a> {
+    next: Option<&'a (dyn StdError + 'static)>,
+}
+
+impl<'a> Iterator for Sources<'a> {
+    type Item = &'a (dyn StdError + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+//* .context() and .with_context() for any Result whose error converts into an Error, and for Option
+//* with_context() only builds the message if there is an error, use it for format!()
+pub trait Context<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for std::result::Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|error| error.into().context(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T> {
+        self.map_err(|error| error.into().context(context()))
+    }
+}
+
+//* None becomes an error of Kind::Other that only consists of the context
+impl<T> Context<T> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.ok_or_else(|| Error::new(Kind::Other, context.to_string()))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T> {
+        self.ok_or_else(|| Error::new(Kind::Other, context().to_string()))
+    }
+}
+
+//* An Error printed over several lines: the error and its kind, then every cause numbered from the outside in
+//* Debug adds the backtrace, if one was captured
+//* main can return Result<(), Report>, Rust prints the Debug format of the error when main fails
+pub struct Report {
+    error: Error,
+}
+
+impl Report {
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
+
+impl<E: Into<Error>> From<E> for Report {
+    fn from(error: E) -> Report {
+        Report { error: error.into() }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.error, self.error.kind())?;
+        let mut causes = self.error.sources().skip(1).peekable();
+        if causes.peek().is_some() {
+            write!(f, "\n\nCaused by:")?;
+        }
+        for (i, cause) in causes.enumerate() {
+            //* causes that span several lines stay lined up under the first one
+            let cause = cause.to_string().replace('\n', "\n       ");
+            write!(f, "\n{:>5}: {}", i, cause)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)?;
+        let backtrace = self.error.backtrace();
+        if backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n\nStack backtrace:\n{}", backtrace)?;
+        }
+        Ok(())
+    }
+}
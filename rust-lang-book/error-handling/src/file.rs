@@ -0,0 +1,223 @@
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+use crate::error::{Context, Error, Kind, Result};
+
+//* Safer versions of the chapter's File::create("hello.txt")
+//*     write_atomic()      replaces a file as a whole: readers see the old or the new contents, never half of it
+//*     create_new()        fails with Kind::AlreadyExists instead of truncating what is there
+//*     open_or_create()    opens a file or creates it with the given permissions, without racing another creator
+//*     FileLock            an advisory lock, it only keeps out others that lock the same file as well
+//*
+//* Every error keeps the kind of the io::Error behind it and says which file and which step it was about
+
+//* How hard to make sure that the data reached the disk before returning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fsync {
+    //* leave it to the operating system, a crash may lose the new contents
+    Never,
+    //* the file's contents, not its metadata
+    Data,
+    //* the file's contents and metadata, and the directory so that the rename survives a crash too
+    All,
+}
+
+//* Writes 'contents' to a temporary file next to 'path' and renames it to 'path'
+//* The rename is atomic, an existing file keeps its permissions, the temporary file is removed on failure
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>, fsync: Fsync) -> Result<()> {
+    let path = path.as_ref();
+    let (temp_path, mut temp) = create_temp(path)?;
+
+    let written = (|| -> Result<()> {
+        let temp_name = temp_path.display();
+        temp.write_all(contents.as_ref()).with_context(|| format!("writing {}", temp_name))?;
+        if let Ok(metadata) = fs::metadata(path) {
+            temp.set_permissions(metadata.permissions())
+                .with_context(|| format!("copying the permissions of {} to {}", path.display(), temp_name))?;
+        }
+        //* after the permissions, they are metadata that Fsync::All has to get to the disk as well
+        match fsync {
+            Fsync::Never => {}
+            Fsync::Data => temp.sync_data().with_context(|| format!("syncing {}", temp_name))?,
+            Fsync::All => temp.sync_all().with_context(|| format!("syncing {}", temp_name))?,
+        }
+        fs::rename(&temp_path, path).with_context(|| format!("renaming {} to {}", temp_name, path.display()))
+    })();
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    written?;
+
+    if fsync == Fsync::All {
+        sync_dir(parent(path))?;
+    }
+    Ok(())
+}
+
+//* Creates 'path' for writing, Kind::AlreadyExists if there is a file already, which stays as it is
+pub fn create_new(path: impl AsRef<Path>) -> Result<File> {
+    let path = path.as_ref();
+    OpenOptions::new().write(true).create_new(true).open(path).with_context(|| format!("creating {}", path.display()))
+}
+
+//* Whether open_or_create() found the file or made it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opened {
+    Existing,
+    Created,
+}
+
+//* Opens 'path' for reading and writing without truncating it, or creates it if it doesn't exist
+//* A new file gets the Unix permissions 'mode' (minus the umask), they are ignored on other systems
+//* If another process creates the file in between, its file is opened instead of overwritten
+//* A symlink whose target doesn't exist is Kind::NotFound, the target isn't created through it
+pub fn open_or_create(path: impl AsRef<Path>, mode: u32) -> Result<(File, Opened)> {
+    let path = path.as_ref();
+    loop {
+        match OpenOptions::new().read(true).write(true).open(path) {
+            Ok(file) => return Ok((file, Opened::Existing)),
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                return Err(err).with_context(|| format!("opening {}", path.display()));
+            }
+            Err(_) => {}
+        }
+
+        let mut options = OpenOptions::new();
+        options.read(true).write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(mode);
+        #[cfg(not(unix))]
+        let _ = mode;
+        match options.open(path) {
+            Ok(file) => return Ok((file, Opened::Created)),
+            //* opening follows a symlink, creating doesn't: a dangling one would send this round forever
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists && dangling_symlink(path) => {
+                let message = format!("{} is a symbolic link to a file that doesn't exist", path.display());
+                return Err(Error::new(Kind::NotFound, message));
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err).with_context(|| format!("creating {}", path.display())),
+        }
+    }
+}
+
+fn dangling_symlink(path: &Path) -> bool {
+    let is_symlink = fs::symlink_metadata(path).map(|metadata| metadata.file_type().is_symlink()).unwrap_or(false);
+    is_symlink && fs::metadata(path).map_err(|err| err.kind()).err() == Some(io::ErrorKind::NotFound)
+}
+
+//* An advisory lock on a file, held until the FileLock is dropped
+//* Exclusive locks keep out every other lock, shared locks only exclusive ones
+//* The file is opened with open_or_create(), so locking a file that doesn't exist yet creates it
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl FileLock {
+    //* Waits until the lock is free
+    pub fn exclusive(path: impl AsRef<Path>) -> Result<FileLock> {
+        FileLock::lock(path.as_ref(), "an exclusive", File::lock)
+    }
+
+    pub fn shared(path: impl AsRef<Path>) -> Result<FileLock> {
+        FileLock::lock(path.as_ref(), "a shared", File::lock_shared)
+    }
+
+    //* None if someone else holds a lock that is in the way
+    pub fn try_exclusive(path: impl AsRef<Path>) -> Result<Option<FileLock>> {
+        FileLock::try_lock(path.as_ref(), "an exclusive", File::try_lock)
+    }
+
+    pub fn try_shared(path: impl AsRef<Path>) -> Result<Option<FileLock>> {
+        FileLock::try_lock(path.as_ref(), "a shared", File::try_lock_shared)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    pub fn file_mut(&mut self) -> &mut File {
+        &mut self.file
+    }
+
+    fn lock(path: &Path, which: &str, lock: fn(&File) -> io::Result<()>) -> Result<FileLock> {
+        let (file, _) = open_or_create(path, 0o666)?;
+        lock(&file).with_context(|| format!("taking {} lock on {}", which, path.display()))?;
+        Ok(FileLock { file, path: path.to_path_buf() })
+    }
+
+    fn try_lock(
+        path: &Path,
+        which: &str,
+        try_lock: fn(&File) -> std::result::Result<(), TryLockError>,
+    ) -> Result<Option<FileLock>> {
+        let (file, _) = open_or_create(path, 0o666)?;
+        match try_lock(&file) {
+            Ok(()) => Ok(Some(FileLock { file, path: path.to_path_buf() })),
+            Err(TryLockError::WouldBlock) => Ok(None),
+            Err(TryLockError::Error(err)) => {
+                Err(err).with_context(|| format!("taking {} lock on {}", which, path.display()))
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        //* closing the file would release it as well
+        let _ = self.file.unlock();
+    }
+}
+
+//* The temporary file is hidden and named after the target, the process and a counter so that
+//* concurrent writers, in this process or another one, never share one
+fn create_temp(path: &Path) -> Result<(PathBuf, File)> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let name = path.file_name().ok_or_else(|| {
+        Error::new(Kind::InvalidInput, format!("{} does not name a file", path.display()))
+    })?;
+    loop {
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let temp_name = format!(".{}.{}.{}.tmp", name.to_string_lossy(), process::id(), count);
+        let temp_path = parent(path).join(temp_name);
+        match OpenOptions::new().write(true).create_new(true).open(&temp_path) {
+            Ok(file) => return Ok((temp_path, file)),
+            //* left over from a process with the same id that crashed
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => {
+                return Err(err).with_context(|| format!("creating a temporary file next to {}", path.display()));
+            }
+        }
+    }
+}
+
+fn parent(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+//* A rename is only durable once the directory that holds the names is synced
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<()> {
+    File::open(dir).and_then(|dir| dir.sync_all()).with_context(|| format!("syncing the directory {}", dir.display()))
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod config;
+pub mod error;
+pub mod file;
+pub mod retry;
+pub mod username;
@@ -1,7 +1,14 @@
 use std::fs::File;
-use std::io::{self, Error, ErrorKind, Read};
+use std::io::ErrorKind;
+use std::time::Duration;
 
-fn main() {
+use error_handling::config::Loader;
+use error_handling::error::{Context, Kind, Report};
+use error_handling::file::{create_new, open_or_create, write_atomic, FileLock, Fsync};
+use error_handling::retry::{Classify, Fallback, ManualClock, Policy};
+use error_handling::username::{read_username, read_username_from_file};
+
+fn main() -> Result<(), Report> {
 
     //* panic! - unrecoverable error that causes the program to abort 
     {
@@ -67,45 +74,113 @@ fn main() {
         let f = File::open("hello.txt").expect("File does not exist.");
 
     }
-}
 
-//* When a client cares about the error thrown, a function can 'propogate' the error to him/her
-fn read_username_from_file() -> Result<String, Error> {
-    let f = File::open("hello.txt");
+    //* Functions that may fail return a Result and leave it to the caller what to do about an error
+    //* read_username_from_file() and its variants are in src/username.rs, they return error::Result
+    //* error::Error has a kind to match on, like io::Error, and can say what we were doing when it happened
+    {
+        if let Err(err) = read_username_from_file("no-such-file.txt") {
+            println!("{:?}: {}", err.kind(), err); // NotFound: No such file or directory (os error 2)
+        }
+
+        //* context() puts a message on top of the error, sources() walks down the chain again
+        let err = read_username("no-such-file.txt").context("looking up the username").unwrap_err();
+        for (depth, source) in err.sources().enumerate() {
+            println!("{}: {}", depth, source);
+        }
+        println!("the io::Error is still there: {}", err.downcast_ref::<std::io::Error>().is_some());
+
+        //* Report prints the error with all of its causes
+        println!("Error: {}", Report::from(err));
+    }
 
-    let mut f = match f {
-        Ok(file) => file,
-        Err(e) => return Err(e),
-    };
+    //* The nested match on error.kind() from above as a reusable chain of alternatives, see src/retry.rs
+    //* Each step only runs if the ones before failed, or_on() only for errors of one kind, anything else is returned
+    {
+        let (_, step) = Fallback::new("opening hello.txt", || File::open("hello.txt"))
+            .or_on(Kind::NotFound, "creating hello.txt", || File::create("hello.txt"))
+            .run_named()?;
+        println!("hello.txt after {}", step);
+
+        //* Errors that may go away by themselves, like Interrupted or TimedOut, are worth another try
+        //* The ManualClock only pretends to wait
+        let clock = ManualClock::new();
+        let policy = Policy::exponential(Duration::from_millis(10), 2.0).max_attempts(4);
+        let answer = policy.retry_with(&clock, |attempt| match attempt {
+            1 | 2 => Err(std::io::Error::from(ErrorKind::TimedOut)),
+            _ => Ok(42),
+        })?;
+        println!("{} after waiting {:?}", answer, clock.sleeps()); // 42 after waiting [10ms, 20ms]
+
+        //* NotFound is permanent, waiting won't make the file appear
+        if let Err(err) = policy.retry_with(&clock, |_| File::open("no-such-file.txt")) {
+            println!("{:?} without another sleep: {}", err.class(), err);
+        }
+    }
 
-    let mut s = String::new();
+    //* File::create("hello.txt") truncates a file that someone else just created, src/file.rs has safer ways
+    //*     create_new()        only creates, an existing file is an AlreadyExists error
+    //*     open_or_create()    opens without truncating, creates with the given permissions otherwise
+    //*     write_atomic()      replaces a file in one go by renaming a temporary file over it
+    //*     FileLock            an advisory lock on a file
+    {
+        let path = std::env::temp_dir().join(format!("error-handling-{}.txt", std::process::id()));
+        write_atomic(&path, "ferris\n", Fsync::Data)?;
+        println!("{} wrote {}", read_username(&path)?, path.display());
 
-    match f.read_to_string(&mut s) {
-        Ok(_) => Ok(s),
-        Err(e) => Err(e),
-    }
-}
+        if let Err(err) = create_new(&path) {
+            println!("{:?}: {}", err.kind(), err); // AlreadyExists: creating /tmp/error-handling-....txt
+        }
 
-//* The example is even shorter when using the ?-operator after a call that returns a Result
-//* It behaves in the same way like the previous example, returning Ok(file) on success and Err(e) on error
-fn _read_username_from_file() -> Result<String, Error> {
-    let mut f = File::open("hello.txt")?;
+        let lock = FileLock::exclusive(&path)?;
+        println!("locked twice: {}", FileLock::try_exclusive(&path)?.is_some()); // locked twice: false
+        drop(lock);
 
-    let mut s = String::new();
-    f.read_to_string(&mut s)?;
+        let (_, opened) = open_or_create(&path, 0o644)?;
+        println!("{:?}", opened); // Existing
+        std::fs::remove_file(&path)?;
+    }
 
-    Ok(s)
-}
+    //* Instead of reading a whole file into a String, config::Loader reads settings from an INI/TOML-like file
+    //* Keys are looked up as "section.key" and converted with FromStr, see src/config.rs
+    {
+        let text = "[server]\nhost = localhost\nport = 8080\n";
+        let config = Loader::new().with_default("server.timeout", 30).parse("server.toml", text)?;
+        let host = config.get_str("server.host")?;
+        let port: u16 = config.get("server.port")?;
+        let timeout: u32 = config.get("server.timeout")?;
+        println!("{}:{} with a timeout of {}s", host, port, timeout); // localhost:8080 with a timeout of 30s
+
+        //* Errors are about a place in the file ...
+        if let Err(err) = Loader::new().parse("server.toml", "[server]\nport 8080\n") {
+            println!("{:?}: {}", err.kind(), err); // Parse: server.toml:2:6: expected '=' after the key
+        }
+
+        //* ... or about a file that isn't there, like ErrorKind::NotFound above
+        if let Err(err) = Loader::new().load("no-such-file.toml") {
+            println!("{:?}: {}", err.kind(), err);
+        }
+    }
 
-//* These method can also be chained
-fn __read_username_from_file() -> Result<String, Error> {
-    let mut s = String::new();
+    //* You can even use the ?-operator in the main function
+    //* You need to add a Result as the return type, e.g. Result<(), Box<dyn Error>>
+    //* If main returns an Err, Rust prints it with {:?}, for a Report that's the multi-line report from above
+    //*     Error: reading the username (not found)
+    //*
+    //*     Caused by:
+    //*         0: reading hello.txt
+    //*         1: No such file or directory (os error 2)
+    //* HELLO_GREETING=Hi overrides the greeting in hello.toml, the file doesn't have to exist
+    let config = Loader::new().with_default("greeting", "Hello").env_prefix("HELLO").load_optional("hello.toml")?;
+    let greeting: String = config.get("greeting")?;
+
+    let username = read_username("hello.txt").context("reading the username");
+    match username {
+        Ok(name) => println!("{}, {}!", greeting, name),
+        //* hello.txt is empty unless you write a name into it, that's fine for this example
+        Err(err) if err.kind() == Kind::InvalidData => println!("No username yet: {}", err.root_cause()),
+        Err(err) => return Err(err.into()),
+    }
 
-    File::open("hello.txt")?.read_to_string(&mut s)?;
-    
-    Ok(s)
+    Ok(())
 }
-
-//* You can even use the ?-operator in the main function
-//* You need to add the return type as ...
-//*     -> Result<(), Box<dyn Error>>
@@ -0,0 +1,352 @@
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Kind, Result};
+
+//* Instead of panicking on the first error, try again or try something else
+//*     Policy      how often and how long to wait in between: fixed or exponential backoff, with jitter
+//*     Classify    whether an error is worth another try, Interrupted or TimedOut are, NotFound is not
+//*     Fallback    a chain of alternatives like the chapter's match: open the file, else create it, else a default
+//*     Clock       where the time comes from and how to wait, tests use a ManualClock instead of sleeping
+//*
+//*     let file = Policy::exponential(Duration::from_millis(10), 2.0).max_attempts(5).retry(|_| File::open(path))?;
+
+//* Whether trying again can help
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Retryable,
+    Permanent,
+}
+
+pub trait Classify {
+    fn class(&self) -> Class;
+}
+
+//* Only errors that are about timing or a busy/unreachable peer are retryable,
+//* missing files or permissions won't change by waiting
+impl Classify for io::ErrorKind {
+    fn class(&self) -> Class {
+        match self {
+            io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::AddrInUse
+            | io::ErrorKind::BrokenPipe => Class::Retryable,
+            _ => Class::Permanent,
+        }
+    }
+}
+
+impl Classify for io::Error {
+    fn class(&self) -> Class {
+        self.kind().class()
+    }
+}
+
+//* The io::Error somewhere in the chain decides, without one it goes by the Kind
+impl Classify for Error {
+    fn class(&self) -> Class {
+        match self.downcast_ref::<io::Error>() {
+            Some(error) => error.class(),
+            None => match self.kind() {
+                Kind::Interrupted | Kind::WouldBlock | Kind::TimedOut => Class::Retryable,
+                _ => Class::Permanent,
+            },
+        }
+    }
+}
+
+//* Where retry() gets the current time from and how it waits
+pub trait Clock {
+    fn now(&self) -> Instant;
+
+    fn sleep(&self, duration: Duration);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+//* A clock that only moves when it is told to or slept on, and remembers every sleep
+//* Clones share the same time
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    start: Instant,
+    elapsed: Rc<Cell<Duration>>,
+    sleeps: Rc<RefCell<Vec<Duration>>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock { start: Instant::now(), elapsed: Rc::new(Cell::new(Duration::ZERO)), sleeps: Rc::default() }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed.set(self.elapsed.get() + duration);
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed.get()
+    }
+
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.borrow().clone()
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> ManualClock {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.sleeps.borrow_mut().push(duration);
+        self.advance(duration);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Backoff {
+    Fixed(Duration),
+    Exponential { initial: Duration, factor: f64 },
+}
+
+//* How often to try and how long to wait before the next try
+//* Built like Policy::fixed(Duration::from_millis(100)).max_attempts(5).jitter(0.5)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Policy {
+    backoff: Backoff,
+    //* including the first one
+    max_attempts: u32,
+    max_delay: Duration,
+    //* the fraction of a delay that is random, 0.0 waits exactly the delay, 1.0 anything between 0 and the delay
+    jitter: f64,
+    //* give up if waiting for the next attempt would take longer than this since the first attempt
+    timeout: Option<Duration>,
+    seed: Option<u64>,
+}
+
+impl Policy {
+    //* Waits 'delay' between attempts, 3 attempts unless told otherwise
+    pub fn fixed(delay: Duration) -> Policy {
+        Policy::new(Backoff::Fixed(delay))
+    }
+
+    //* Waits 'initial' after the first attempt and 'factor' times longer after each one after that
+    pub fn exponential(initial: Duration, factor: f64) -> Policy {
+        assert!(factor >= 1.0, "the backoff factor must be at least 1, not {}", factor);
+        Policy::new(Backoff::Exponential { initial, factor })
+    }
+
+    fn new(backoff: Backoff) -> Policy {
+        Policy { backoff, max_attempts: 3, max_delay: Duration::MAX, jitter: 0.0, timeout: None, seed: None }
+    }
+
+    pub fn max_attempts(mut self, attempts: u32) -> Policy {
+        assert!(attempts > 0, "a policy needs at least one attempt");
+        self.max_attempts = attempts;
+        self
+    }
+
+    //* No delay gets longer than 'max', jitter is applied after capping
+    pub fn max_delay(mut self, max: Duration) -> Policy {
+        self.max_delay = max;
+        self
+    }
+
+    //* Randomizes a 'fraction' of each delay, so that many clients that failed together don't retry together
+    pub fn jitter(mut self, fraction: f64) -> Policy {
+        assert!((0.0..=1.0).contains(&fraction), "jitter must be between 0 and 1, not {}", fraction);
+        self.jitter = fraction;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Policy {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    //* Makes the jitter the same on every run, otherwise every retry() starts from a random seed
+    pub fn seed(mut self, seed: u64) -> Policy {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    //* The delay after the given failed attempt (counting from 1) before jitter
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, factor } => {
+                let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+                Duration::try_from_secs_f64(initial.as_secs_f64() * factor.powi(exponent)).unwrap_or(Duration::MAX)
+            }
+        };
+        delay.min(self.max_delay)
+    }
+
+    //* Runs 'op' until it succeeds, fails with a permanent error or the policy gives up, then returns the last error
+    //* 'op' gets the number of the attempt, starting at 1
+    pub fn retry<T, E, F>(&self, op: F) -> std::result::Result<T, E>
+    where
+        E: Classify,
+        F: FnMut(u32) -> std::result::Result<T, E>,
+    {
+        self.retry_with(&SystemClock, op)
+    }
+
+    pub fn retry_with<T, E, F, C>(&self, clock: &C, mut op: F) -> std::result::Result<T, E>
+    where
+        E: Classify,
+        F: FnMut(u32) -> std::result::Result<T, E>,
+        C: Clock + ?Sized,
+    {
+        let start = clock.now();
+        let mut rng = XorShift::new(self.seed);
+        let mut attempt = 1;
+        loop {
+            let err = match op(attempt) {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            if attempt >= self.max_attempts || err.class() == Class::Permanent {
+                return Err(err);
+            }
+            let delay = self.delay(attempt);
+            let delay = Duration::try_from_secs_f64(delay.as_secs_f64() * (1.0 - self.jitter * rng.next_f64()))
+                .map_or(delay, |jittered| jittered.min(delay));
+            if let Some(timeout) = self.timeout {
+                if clock.now().duration_since(start).checked_add(delay).is_none_or(|total| total > timeout) {
+                    return Err(err);
+                }
+            }
+            clock.sleep(delay);
+            attempt += 1;
+        }
+    }
+}
+
+//* Enough randomness for jitter without a crate
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: Option<u64>) -> XorShift {
+        let seed = seed.unwrap_or_else(|| RandomState::new().build_hasher().finish());
+        //* xorshift gets stuck at 0
+        XorShift(seed | 1)
+    }
+
+    //* In [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+//* Alternatives tried one after the other until one works, like the chapter's nested match
+//*     Fallback::new("opening hello.txt", || File::open("hello.txt"))
+//*         .or_on(Kind::NotFound, "creating hello.txt", || File::create("hello.txt"))
+//*         .run()?
+//* A step only runs if the steps before it failed and, for or_on(), the last error is of the given kind
+//* If no step works the last error is returned, with the name of its step as context
+pub struct Fallback<'a, T> {
+    steps: Vec<Step<'a, T>>,
+}
+
+struct Step<'a, T> {
+    name: String,
+    kind: Option<Kind>,
+    run: Box<dyn FnOnce() -> Result<T> + 'a>,
+}
+
+impl<'a, T: 'a> Fallback<'a, T> {
+    pub fn new<E, F>(name: &str, first: F) -> Fallback<'a, T>
+    where
+        E: Into<Error>,
+        F: FnOnce() -> std::result::Result<T, E> + 'a,
+    {
+        Fallback { steps: Vec::new() }.step(name, None, first)
+    }
+
+    //* Tried after any error
+    pub fn or<E, F>(self, name: &str, next: F) -> Fallback<'a, T>
+    where
+        E: Into<Error>,
+        F: FnOnce() -> std::result::Result<T, E> + 'a,
+    {
+        self.step(name, None, next)
+    }
+
+    //* Tried only if the last error is of 'kind', skipped otherwise
+    pub fn or_on<E, F>(self, kind: Kind, name: &str, next: F) -> Fallback<'a, T>
+    where
+        E: Into<Error>,
+        F: FnOnce() -> std::result::Result<T, E> + 'a,
+    {
+        self.step(name, Some(kind), next)
+    }
+
+    //* The last resort that always works
+    pub fn or_value(self, name: &str, value: T) -> Fallback<'a, T> {
+        self.step(name, None, move || Ok::<T, Error>(value))
+    }
+
+    fn step<E, F>(mut self, name: &str, kind: Option<Kind>, run: F) -> Fallback<'a, T>
+    where
+        E: Into<Error>,
+        F: FnOnce() -> std::result::Result<T, E> + 'a,
+    {
+        self.steps.push(Step { name: name.to_string(), kind, run: Box::new(move || run().map_err(Into::into)) });
+        self
+    }
+
+    pub fn run(self) -> Result<T> {
+        self.run_named().map(|(value, _)| value)
+    }
+
+    //* Also returns the name of the step that worked
+    pub fn run_named(self) -> Result<(T, String)> {
+        let mut last: Option<Error> = None;
+        for step in self.steps {
+            if let (Some(kind), Some(err)) = (step.kind, &last) {
+                if err.kind() != kind {
+                    continue;
+                }
+            }
+            match (step.run)() {
+                Ok(value) => return Ok((value, step.name)),
+                Err(err) => last = Some(err.context(step.name)),
+            }
+        }
+        Err(last.expect("a fallback chain starts with a step"))
+    }
+}
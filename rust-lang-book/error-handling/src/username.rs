@@ -0,0 +1,60 @@
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use crate::error::{Context, Error, Kind, Result};
+
+//* The ways of reading a username from a file that main.rs used to end with
+//* They return our error::Result, the io::Error of File::open() and read_to_string() is converted into an Error
+
+//* When a client cares about the error thrown, a function can 'propogate' the error to him/her
+pub fn read_username_from_file(path: impl AsRef<Path>) -> Result<String> {
+    let f = File::open(path);
+
+    let mut f = match f {
+        Ok(file) => file,
+        Err(e) => return Err(Error::from(e)),
+    };
+
+    let mut s = String::new();
+
+    match f.read_to_string(&mut s) {
+        Ok(_) => Ok(s),
+        Err(e) => Err(Error::from(e)),
+    }
+}
+
+//* The example is even shorter when using the ?-operator after a call that returns a Result
+//* It behaves in the same way like the previous example, returning Ok(file) on success and Err(e) on error
+//* On top of that ? calls From::from on the error, that's what turns the io::Error into an Error
+pub fn read_username_from_file_short(path: impl AsRef<Path>) -> Result<String> {
+    let mut f = File::open(path)?;
+
+    let mut s = String::new();
+    f.read_to_string(&mut s)?;
+
+    Ok(s)
+}
+
+//* These method can also be chained
+pub fn read_username_from_file_chained(path: impl AsRef<Path>) -> Result<String> {
+    let mut s = String::new();
+
+    File::open(path)?.read_to_string(&mut s)?;
+
+    Ok(s)
+}
+
+//* fs::read_to_string() does all of the above in one go
+//* The context says which file it was, io::Error alone only says "No such file or directory"
+//* A username has to be a single non-empty line, anything else is Kind::InvalidData
+pub fn read_username(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let s = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+
+    match s.trim_end_matches(&['\r', '\n'][..]) {
+        "" => Err(Error::new(Kind::InvalidData, format!("{} is empty", path.display()))),
+        name if name.contains('\n') => Err(Error::new(Kind::InvalidData, format!("{} has several lines", path.display()))),
+        name => Ok(name.to_string()),
+    }
+}
@@ -0,0 +1,155 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use error_handling::config::{env_var_name, Config, Loader, Origin, ParseError};
+use error_handling::error::{Error, Kind};
+
+mod common;
+use common::TempDir;
+
+const APP: &str = "\
+# settings for the example app
+name = \"ferris\"
+
+[server]
+host = localhost   ; a bare value
+port = 8080
+motd = \"Hello,\\n\\\"world\\\"\"  # a quoted one
+
+[server.tls]
+enabled = true
+";
+
+fn parse(text: &str) -> Config {
+    Loader::new().parse("app.toml", text).unwrap()
+}
+
+fn parse_error(text: &str) -> (Error, ParseError) {
+    let err = Loader::new().parse("app.toml", text).unwrap_err();
+    let parse_error = err.downcast_ref::<ParseError>().expect("a ParseError").clone();
+    (err, parse_error)
+}
+
+fn at(line: usize, column: usize) -> Origin {
+    Origin::File { path: PathBuf::from("app.toml"), line, column }
+}
+
+#[test]
+fn reads_sections_and_typed_values() {
+    let config = parse(APP);
+    assert_eq!(config.get_str("name").unwrap(), "ferris");
+    assert_eq!(config.get_str("server.host").unwrap(), "localhost");
+    assert_eq!(config.get::<u16>("server.port").unwrap(), 8080);
+    assert_eq!(config.get::<String>("server.motd").unwrap(), "Hello,\n\"world\"");
+    assert!(config.get::<bool>("server.tls.enabled").unwrap());
+
+    assert_eq!(config.sections().collect::<Vec<_>>(), vec!["server", "server.tls"]);
+    assert_eq!(
+        config.section("server").collect::<Vec<_>>(),
+        vec![("host", "localhost"), ("motd", "Hello,\n\"world\""), ("port", "8080")]
+    );
+    assert_eq!(config.section("").collect::<Vec<_>>(), vec![("name", "ferris")]);
+    assert_eq!(config.keys().count(), 5);
+    assert_eq!(config.origin("server.port"), Some(&at(6, 8)));
+}
+
+#[test]
+fn missing_keys_are_not_found_and_bad_values_are_parse_errors() {
+    let config = parse(APP);
+    let err = config.get::<u16>("server.timeout").unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(config.get_opt::<u16>("server.timeout").unwrap(), None);
+    assert_eq!(config.get_or("server.timeout", 30u16).unwrap(), 30);
+
+    let err = config.get::<u16>("server.host").unwrap_err();
+    assert_eq!(err.kind(), Kind::Parse);
+    let parse_error = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!(parse_error.origin, at(5, 8));
+    assert!(err.to_string().starts_with("app.toml:5:8: invalid value \"localhost\" for server.host"));
+    assert_eq!(config.get_or("server.host", 0u16).unwrap_err().kind(), Kind::Parse);
+}
+
+#[test]
+fn syntax_errors_have_line_and_column() {
+    let cases = [
+        ("[server\n", 1, 8, "expected ']' after the section name"),
+        ("[]\n", 1, 2, "expected a section name like [server] or [server.tls]"),
+        ("[a] b\n", 1, 5, "unexpected 'b' after the section header"),
+        ("a = 1\n\n  port 8080\n", 3, 8, "expected '=' after the key"),
+        ("= 1\n", 1, 1, "expected a key made of letters, digits, '_' and '-'"),
+        ("port =   # nothing\n", 1, 10, "missing value for port"),
+        ("motd = \"Hello\n", 1, 8, "unterminated string"),
+        ("motd = \"a\\qb\"\n", 1, 10, "unknown escape sequence \\q"),
+        ("motd = \"a\" b\n", 1, 12, "unexpected 'b' after the string"),
+        ("[s]\nport = 1\nport = 2\n", 3, 1, "duplicate key s.port, it was first set on line 2"),
+    ];
+    for &(text, line, column, message) in &cases {
+        let (err, parse_error) = parse_error(text);
+        assert_eq!(err.kind(), Kind::Parse, "{:?}", text);
+        assert_eq!(parse_error.origin, at(line, column), "{:?}", text);
+        assert_eq!(parse_error.message, message, "{:?}", text);
+        assert_eq!(err.to_string(), format!("app.toml:{}:{}: {}", line, column, message));
+    }
+
+    //* columns count characters, not bytes
+    let (_, parse_error) = parse_error("motd = \"größe\" 2\n");
+    assert_eq!(parse_error.origin, at(1, 16));
+}
+
+#[test]
+fn empty_quoted_values_and_reopened_sections_are_fine() {
+    let config = parse("[a]\nx = \"\"\n[b]\ny = 1\n[a]\nz = 2\n");
+    assert_eq!(config.get_str("a.x").unwrap(), "");
+    assert_eq!(config.section("a").count(), 2);
+    assert_eq!(config.sections().collect::<Vec<_>>(), vec!["a", "b"]);
+}
+
+#[test]
+fn environment_overrides_file_overrides_defaults() {
+    let prefix = "ERROR_HANDLING_TEST_OVERRIDES";
+    assert_eq!(env_var_name(prefix, "server.max-connections"), format!("{}_SERVER_MAX_CONNECTIONS", prefix));
+    env::set_var(env_var_name(prefix, "server.port"), "9090");
+    env::set_var(env_var_name(prefix, "server.workers"), "8");
+    env::set_var(env_var_name(prefix, "server.unknown"), "ignored");
+
+    let config = Loader::new()
+        .with_default("server.port", 80)
+        .with_default("server.workers", 1)
+        .with_default("server.timeout", 30)
+        .env_prefix(prefix)
+        .parse("app.toml", APP)
+        .unwrap();
+    assert_eq!(config.get::<u16>("server.port").unwrap(), 9090);
+    assert_eq!(config.origin("server.port"), Some(&Origin::Env(format!("{}_SERVER_PORT", prefix))));
+    assert_eq!(config.get::<u32>("server.workers").unwrap(), 8);
+    assert_eq!(config.get::<u32>("server.timeout").unwrap(), 30);
+    assert_eq!(config.origin("server.timeout"), Some(&Origin::Default));
+    assert_eq!(config.get_str("server.host").unwrap(), "localhost");
+    assert!(!config.contains("server.unknown"));
+
+    env::set_var(env_var_name(prefix, "server.port"), "ninety");
+    let config = Loader::new().env_prefix(prefix).parse("app.toml", APP).unwrap();
+    let err = config.get::<u16>("server.port").unwrap_err();
+    assert!(err.to_string().starts_with(&format!("environment variable {}_SERVER_PORT: invalid value", prefix)));
+}
+
+#[test]
+fn missing_file_is_not_found() {
+    let dir = TempDir::new("config");
+    let path = dir.path("app.toml");
+
+    let loader = Loader::new().with_default("name", "nobody");
+    let err = loader.load(&path).unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(err.to_string(), format!("reading config file {}", path.display()));
+    assert_eq!(loader.load_optional(&path).unwrap().get_str("name").unwrap(), "nobody");
+
+    fs::write(&path, APP).unwrap();
+    assert_eq!(loader.load(&path).unwrap().get_str("name").unwrap(), "ferris");
+
+    fs::write(&path, "name: ferris\n").unwrap();
+    let err = loader.load_optional(&path).unwrap_err();
+    assert_eq!(err.kind(), Kind::Parse);
+    assert_eq!(err.to_string(), format!("{}:1:5: expected '=' after the key", path.display()));
+}
@@ -0,0 +1,131 @@
+use std::io;
+use std::path::PathBuf;
+
+use error_handling::error::{Context, Error, Kind, Report, Result};
+use error_handling::username::{
+    read_username, read_username_from_file, read_username_from_file_chained, read_username_from_file_short,
+};
+
+mod common;
+use common::TempDir;
+
+type Variant = fn(PathBuf) -> Result<String>;
+
+fn variants() -> Vec<(&'static str, Variant)> {
+    vec![
+        ("read_username_from_file", |path| read_username_from_file(path)),
+        ("read_username_from_file_short", |path| read_username_from_file_short(path)),
+        ("read_username_from_file_chained", |path| read_username_from_file_chained(path)),
+    ]
+}
+
+#[test]
+fn variants_read_the_whole_file() {
+    let dir = TempDir::new("variants-read");
+    let path = dir.file("hello.txt", "ferris\n");
+    for (name, read) in variants() {
+        assert_eq!(read(path.clone()).unwrap(), "ferris\n", "{}", name);
+    }
+    assert_eq!(read_username(&path).unwrap(), "ferris");
+}
+
+#[test]
+fn variants_turn_io_errors_into_errors() {
+    let dir = TempDir::new("variants-missing");
+    let missing = dir.0.join("missing.txt");
+    for (name, read) in variants() {
+        let err = read(missing.clone()).unwrap_err();
+        assert_eq!(err.kind(), Kind::NotFound, "{}", name);
+        assert_eq!(err.sources().count(), 1, "{}", name);
+        assert_eq!(err.downcast_ref::<io::Error>().map(io::Error::kind), Some(io::ErrorKind::NotFound));
+        assert_eq!(err.to_string(), err.root_cause().to_string());
+    }
+
+    //* a directory can be opened but not read
+    for (name, read) in variants() {
+        let err = read(dir.0.clone()).unwrap_err();
+        let io_kind = err.downcast_ref::<io::Error>().unwrap().kind();
+        assert_eq!(err.kind(), Kind::from(io_kind), "{}", name);
+    }
+
+    let invalid = dir.file("invalid.txt", [0xff, 0xfe]);
+    for (name, read) in variants() {
+        assert_eq!(read(invalid.clone()).unwrap_err().kind(), Kind::InvalidData, "{}", name);
+    }
+}
+
+#[test]
+fn read_username_says_which_file_it_was() {
+    let dir = TempDir::new("read-username");
+    let missing = dir.0.join("missing.txt");
+    let err = read_username(&missing).unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(err.to_string(), format!("reading {}", missing.display()));
+
+    let empty = dir.file("empty.txt", "\n");
+    let err = read_username(&empty).unwrap_err();
+    assert_eq!(err.kind(), Kind::InvalidData);
+    assert_eq!(err.sources().count(), 1);
+
+    let two_lines = dir.file("two-lines.txt", "ferris\ncorro\n");
+    assert_eq!(read_username(&two_lines).unwrap_err().kind(), Kind::InvalidData);
+}
+
+#[test]
+fn context_is_chained_from_the_outside_in() {
+    let dir = TempDir::new("context");
+    let missing = dir.0.join("missing.txt");
+    let err = read_username(&missing).context("reading the username").context("logging in").unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+
+    let messages: Vec<String> = err.sources().map(|source| source.to_string()).collect();
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[0], "logging in");
+    assert_eq!(messages[1], "reading the username");
+    assert_eq!(messages[2], format!("reading {}", missing.display()));
+    assert_eq!(messages[3], err.root_cause().to_string());
+    assert!(err.root_cause().downcast_ref::<io::Error>().is_some());
+    assert!(err.downcast_ref::<Error>().is_some());
+
+    //* with_context() builds its message only when there is an error
+    let ok: Result<u32> = "42".parse::<u32>().with_context(|| -> String { panic!("not called") });
+    assert_eq!(ok.unwrap(), 42);
+    let err = "forty-two".parse::<u32>().with_context(|| format!("parsing {:?}", "forty-two")).unwrap_err();
+    assert_eq!(err.kind(), Kind::Parse);
+    assert_eq!(err.to_string(), "parsing \"forty-two\"");
+
+    let err = None::<u32>.context("no user with id 7").unwrap_err();
+    assert_eq!(err.kind(), Kind::Other);
+    assert_eq!(err.sources().count(), 1);
+}
+
+#[test]
+fn report_lists_every_cause() {
+    let err = Error::new(Kind::InvalidData, "bad header\nexpected 'P6'")
+        .context("decoding picture.ppm")
+        .context("loading the profile picture");
+    let report = Report::from(err);
+    assert_eq!(
+        report.to_string(),
+        "loading the profile picture (invalid data)\n\
+         \n\
+         Caused by:\n    \
+             0: decoding picture.ppm\n    \
+             1: bad header\n       \
+                expected 'P6'"
+    );
+    assert!(format!("{:?}", report).starts_with(&report.to_string()));
+
+    let report = Report::from(io::Error::new(io::ErrorKind::TimedOut, "no answer"));
+    assert_eq!(report.to_string(), "no answer (timed out)");
+    assert_eq!(report.into_error().kind(), Kind::TimedOut);
+}
+
+#[test]
+fn backtrace_stays_with_the_outermost_error() {
+    let err = Error::new(Kind::Other, "inner");
+    let status = err.backtrace().status();
+    let err = err.context("outer");
+    assert_eq!(err.backtrace().status(), status);
+    assert_eq!(Report::from(err).error().to_string(), "outer");
+}
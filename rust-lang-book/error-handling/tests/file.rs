@@ -0,0 +1,214 @@
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use error_handling::error::Kind;
+use error_handling::file::{create_new, open_or_create, write_atomic, FileLock, Fsync, Opened};
+
+mod common;
+use common::TempDir;
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn write_atomic_creates_and_replaces() {
+    let dir = TempDir::new("atomic");
+    let path = dir.path("hello.txt");
+    for &fsync in &[Fsync::Never, Fsync::Data, Fsync::All] {
+        write_atomic(&path, format!("{:?}\n", fsync), fsync).unwrap();
+        assert_eq!(read(&path), format!("{:?}\n", fsync));
+    }
+    assert_eq!(dir.names(), vec!["hello.txt"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn write_atomic_keeps_the_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new("permissions");
+    let path = dir.path("secret.txt");
+    fs::write(&path, "old").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+    for &fsync in &[Fsync::Never, Fsync::Data, Fsync::All] {
+        write_atomic(&path, format!("{:?}", fsync), fsync).unwrap();
+        assert_eq!(read(&path), format!("{:?}", fsync));
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn write_atomic_errors_name_the_file() {
+    let dir = TempDir::new("atomic-errors");
+    let missing = dir.path("missing").join("hello.txt");
+    let err = write_atomic(&missing, "hi", Fsync::Never).unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(err.to_string(), format!("creating a temporary file next to {}", missing.display()));
+
+    //* renaming a file over a directory fails, the temporary file is cleaned up
+    fs::create_dir(dir.path("taken")).unwrap();
+    fs::write(dir.path("taken").join("inside"), "").unwrap();
+    let err = write_atomic(dir.path("taken"), "hi", Fsync::Never).unwrap_err();
+    assert!(err.to_string().starts_with("renaming "), "{}", err);
+    assert_eq!(dir.names(), vec!["taken"]);
+
+    let err = write_atomic(dir.0.join(".."), "hi", Fsync::Never).unwrap_err();
+    assert_eq!(err.kind(), Kind::InvalidInput);
+}
+
+#[test]
+fn create_new_never_truncates() {
+    let dir = TempDir::new("create-new");
+    let path = dir.path("hello.txt");
+    create_new(&path).unwrap().write_all(b"first").unwrap();
+    let err = create_new(&path).unwrap_err();
+    assert_eq!(err.kind(), Kind::AlreadyExists);
+    assert_eq!(err.to_string(), format!("creating {}", path.display()));
+    assert_eq!(read(&path), "first");
+}
+
+#[test]
+fn open_or_create_keeps_existing_contents() {
+    let dir = TempDir::new("open-or-create");
+    let path = dir.path("hello.txt");
+    let (mut file, opened) = open_or_create(&path, 0o600).unwrap();
+    assert_eq!(opened, Opened::Created);
+    file.write_all(b"ferris").unwrap();
+
+    let (mut file, opened) = open_or_create(&path, 0o600).unwrap();
+    assert_eq!(opened, Opened::Existing);
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    assert_eq!(contents, "ferris");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    let err = open_or_create(dir.path("missing").join("hello.txt"), 0o644).unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert!(err.to_string().starts_with("creating "));
+}
+
+#[cfg(unix)]
+#[test]
+fn open_or_create_stops_at_a_dangling_symlink() {
+    let dir = TempDir::new("dangling-symlink");
+    let link = dir.path("link.txt");
+    std::os::unix::fs::symlink(dir.path("target.txt"), &link).unwrap();
+    let err = open_or_create(&link, 0o644).unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(err.to_string(), format!("{} is a symbolic link to a file that doesn't exist", link.display()));
+    assert_eq!(dir.names(), vec!["link.txt"]);
+
+    //* once the target exists, the link is followed
+    fs::write(dir.path("target.txt"), "ferris").unwrap();
+    let (_, opened) = open_or_create(&link, 0o644).unwrap();
+    assert_eq!(opened, Opened::Existing);
+}
+
+#[test]
+fn concurrent_open_or_create_creates_once() {
+    let dir = TempDir::new("create-race");
+    let path = Arc::new(dir.path("hello.txt"));
+    let barrier = Arc::new(Barrier::new(16));
+    let handles: Vec<_> = (0..16)
+        .map(|_| {
+            let (path, barrier) = (path.clone(), barrier.clone());
+            thread::spawn(move || {
+                barrier.wait();
+                open_or_create(&*path, 0o644).unwrap().1
+            })
+        })
+        .collect();
+    let opened: Vec<Opened> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    assert_eq!(opened.iter().filter(|&&opened| opened == Opened::Created).count(), 1);
+}
+
+#[test]
+fn concurrent_writers_never_leave_a_partial_file() {
+    let dir = TempDir::new("writers");
+    let path = Arc::new(dir.path("hello.txt"));
+    //* big enough that a plain fs::write would be seen half done
+    let versions: Arc<Vec<String>> = Arc::new((0..4).map(|i| i.to_string().repeat(256 * 1024)).collect());
+    write_atomic(&*path, &versions[0], Fsync::Never).unwrap();
+
+    let writers: Vec<_> = (0..4)
+        .map(|i| {
+            let (path, versions) = (path.clone(), versions.clone());
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    write_atomic(&*path, &versions[i], Fsync::Never).unwrap();
+                }
+            })
+        })
+        .collect();
+    let reader = {
+        let (path, versions) = (path.clone(), versions.clone());
+        thread::spawn(move || {
+            for _ in 0..50 {
+                let contents = fs::read_to_string(&*path).unwrap();
+                assert!(versions.contains(&contents), "read {} bytes that are no complete version", contents.len());
+            }
+        })
+    };
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    reader.join().unwrap();
+    assert!(versions.contains(&read(&path)));
+    assert_eq!(dir.names(), vec!["hello.txt"]);
+}
+
+#[test]
+fn exclusive_locks_serialize_writers() {
+    let dir = TempDir::new("lock-counter");
+    let path = Arc::new(dir.path("counter.txt"));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let path = path.clone();
+            thread::spawn(move || {
+                for _ in 0..25 {
+                    let mut lock = FileLock::exclusive(&*path).unwrap();
+                    let file = lock.file_mut();
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents).unwrap();
+                    let count: u32 = if contents.is_empty() { 0 } else { contents.parse().unwrap() };
+                    file.set_len(0).unwrap();
+                    file.seek(SeekFrom::Start(0)).unwrap();
+                    write!(file, "{}", count + 1).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(read(&path), "200");
+}
+
+#[test]
+fn try_lock_sees_other_locks() {
+    let dir = TempDir::new("try-lock");
+    let path = dir.path("hello.lock");
+
+    let shared = FileLock::shared(&path).unwrap();
+    assert_eq!(shared.path(), path.as_path());
+    let also_shared = FileLock::try_shared(&path).unwrap();
+    assert!(also_shared.is_some());
+    assert!(FileLock::try_exclusive(&path).unwrap().is_none());
+
+    drop(shared);
+    drop(also_shared);
+    let exclusive = FileLock::try_exclusive(&path).unwrap().expect("nobody holds a lock anymore");
+    assert!(FileLock::try_shared(&path).unwrap().is_none());
+    assert!(FileLock::try_exclusive(&path).unwrap().is_none());
+    drop(exclusive);
+    assert!(FileLock::try_shared(&path).unwrap().is_some());
+}
@@ -0,0 +1,185 @@
+use std::cell::Cell;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind};
+use std::time::Duration;
+
+use error_handling::error::{Context, Error, Kind};
+use error_handling::retry::{Class, Classify, Fallback, ManualClock, Policy};
+
+mod common;
+use common::TempDir;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+//* Fails with 'kind' until the given attempt
+fn flaky(kind: ErrorKind, succeeds_at: u32) -> impl FnMut(u32) -> io::Result<u32> {
+    move |attempt| if attempt < succeeds_at { Err(io::Error::from(kind)) } else { Ok(attempt) }
+}
+
+#[test]
+fn io_errors_are_classified() {
+    for &kind in &[ErrorKind::Interrupted, ErrorKind::WouldBlock, ErrorKind::TimedOut, ErrorKind::ConnectionReset] {
+        assert_eq!(kind.class(), Class::Retryable, "{:?}", kind);
+    }
+    for &kind in &[ErrorKind::NotFound, ErrorKind::PermissionDenied, ErrorKind::InvalidData, ErrorKind::Other] {
+        assert_eq!(kind.class(), Class::Permanent, "{:?}", kind);
+    }
+
+    //* for an Error the io::Error behind the context counts
+    let err = Err::<(), _>(io::Error::from(ErrorKind::ConnectionRefused)).context("connecting").unwrap_err();
+    assert_eq!(err.kind(), Kind::Io);
+    assert_eq!(err.class(), Class::Retryable);
+    assert_eq!(Error::new(Kind::TimedOut, "no answer").class(), Class::Retryable);
+    assert_eq!(Error::new(Kind::Parse, "bad number").class(), Class::Permanent);
+}
+
+#[test]
+fn fixed_policy_retries_until_success() {
+    let clock = ManualClock::new();
+    let policy = Policy::fixed(ms(100)).max_attempts(5);
+    assert_eq!(policy.retry_with(&clock, flaky(ErrorKind::Interrupted, 4)).unwrap(), 4);
+    assert_eq!(clock.sleeps(), vec![ms(100); 3]);
+    assert_eq!(clock.elapsed(), ms(300));
+}
+
+#[test]
+fn gives_up_after_max_attempts_with_the_last_error() {
+    let clock = ManualClock::new();
+    let attempts = Cell::new(0);
+    let result: io::Result<()> = Policy::fixed(ms(10)).retry_with(&clock, |attempt| {
+        attempts.set(attempt);
+        Err(io::Error::new(ErrorKind::TimedOut, format!("attempt {}", attempt)))
+    });
+    assert_eq!(result.unwrap_err().to_string(), "attempt 3");
+    assert_eq!(attempts.get(), 3);
+    assert_eq!(clock.sleeps().len(), 2);
+}
+
+#[test]
+fn permanent_errors_are_not_retried() {
+    let clock = ManualClock::new();
+    let err = Policy::fixed(ms(10)).max_attempts(10).retry_with(&clock, flaky(ErrorKind::NotFound, 5)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert!(clock.sleeps().is_empty());
+}
+
+#[test]
+fn exponential_backoff_is_capped() {
+    let policy = Policy::exponential(ms(10), 2.0).max_attempts(8).max_delay(ms(200));
+    let delays: Vec<Duration> = (1..8).map(|attempt| policy.delay(attempt)).collect();
+    assert_eq!(delays, vec![ms(10), ms(20), ms(40), ms(80), ms(160), ms(200), ms(200)]);
+
+    let clock = ManualClock::new();
+    assert_eq!(policy.retry_with(&clock, flaky(ErrorKind::WouldBlock, 8)).unwrap(), 8);
+    assert_eq!(clock.sleeps(), delays);
+
+    //* a huge exponent doesn't overflow
+    assert_eq!(Policy::exponential(ms(1), 10.0).delay(u32::MAX), Duration::MAX);
+}
+
+#[test]
+fn jitter_stays_within_the_delay_and_is_reproducible_with_a_seed() {
+    let policy = Policy::exponential(ms(100), 2.0).max_attempts(6).jitter(0.5).seed(42);
+    let run = || {
+        let clock = ManualClock::new();
+        policy.retry_with(&clock, flaky(ErrorKind::Interrupted, 6)).unwrap();
+        clock.sleeps()
+    };
+    let sleeps = run();
+    assert_eq!(sleeps, run());
+    for (i, sleep) in sleeps.iter().enumerate() {
+        let delay = policy.delay(i as u32 + 1);
+        assert!(*sleep <= delay && *sleep >= delay / 2, "{:?} for a delay of {:?}", sleep, delay);
+    }
+    assert!(sleeps.iter().enumerate().any(|(i, sleep)| *sleep != policy.delay(i as u32 + 1)));
+
+    let full = Policy::fixed(ms(100)).max_attempts(50).jitter(1.0);
+    let clock = ManualClock::new();
+    full.retry_with(&clock, flaky(ErrorKind::Interrupted, 50)).unwrap();
+    assert!(clock.sleeps().iter().all(|sleep| *sleep <= ms(100)));
+    assert!(clock.sleeps().iter().any(|sleep| *sleep < ms(50)));
+}
+
+#[test]
+fn timeout_stops_before_sleeping_past_it() {
+    let clock = ManualClock::new();
+    let policy = Policy::exponential(ms(100), 2.0).max_attempts(100).timeout(ms(1000));
+    let err = policy.retry_with(&clock, flaky(ErrorKind::TimedOut, 100)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+    //* 100 + 200 + 400 = 700, another 800 would end at 1500
+    assert_eq!(clock.sleeps(), vec![ms(100), ms(200), ms(400)]);
+
+    //* time spent in the operation counts as well
+    let clock = ManualClock::new();
+    let slow = |_| -> io::Result<()> {
+        clock.advance(ms(600));
+        Err(ErrorKind::Interrupted.into())
+    };
+    assert!(Policy::fixed(ms(100)).max_attempts(10).timeout(ms(1000)).retry_with(&clock, slow).is_err());
+    assert_eq!(clock.sleeps(), vec![ms(100)]);
+}
+
+#[test]
+#[should_panic(expected = "a policy needs at least one attempt")]
+fn zero_attempts_panics() {
+    Policy::fixed(ms(1)).max_attempts(0);
+}
+
+#[test]
+fn fallback_opens_else_creates() {
+    let dir = TempDir::new("open-create");
+    let path = dir.0.join("hello.txt");
+    let open_or_create = || {
+        Fallback::new("opening hello.txt", || File::open(&path))
+            .or_on(Kind::NotFound, "creating hello.txt", || File::create(&path))
+            .run_named()
+            .map(|(_, step)| step)
+    };
+    assert_eq!(open_or_create().unwrap(), "creating hello.txt");
+    assert!(path.exists());
+    assert_eq!(open_or_create().unwrap(), "opening hello.txt");
+}
+
+#[test]
+fn fallback_skips_steps_for_other_kinds_and_ends_with_a_default() {
+    let dir = TempDir::new("default");
+    //* a directory can be opened, but not read as a username
+    let read = |path: &std::path::Path| fs::read_to_string(path);
+    let created = Cell::new(false);
+
+    let username = Fallback::new("reading the username", || read(&dir.0))
+        .or_on(Kind::NotFound, "creating the username file", || {
+            created.set(true);
+            Ok::<_, Error>(String::new())
+        })
+        .or_value("using the default username", "ferris".to_string())
+        .run_named()
+        .unwrap();
+    assert_eq!(username, ("ferris".to_string(), "using the default username".to_string()));
+    assert!(!created.get());
+
+    //* no step works, the last error comes back with its step as context
+    let missing = dir.0.join("missing").join("hello.txt");
+    let err = Fallback::new("opening hello.txt", || File::open(&missing))
+        .or_on(Kind::NotFound, "creating hello.txt", || File::create(&missing))
+        .or_on(Kind::PermissionDenied, "opening it read-only", || File::open(&missing))
+        .run()
+        .unwrap_err();
+    assert_eq!(err.kind(), Kind::NotFound);
+    assert_eq!(err.to_string(), "creating hello.txt");
+    assert_eq!(err.sources().count(), 2);
+}
+
+#[test]
+fn retry_inside_a_fallback() {
+    let clock = ManualClock::new();
+    let policy = Policy::fixed(ms(50));
+    let value = Fallback::new("asking the server", || policy.retry_with(&clock, flaky(ErrorKind::ConnectionReset, 9)))
+        .or("asking the cache", || "42".parse::<u32>())
+        .run()
+        .unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(clock.sleeps(), vec![ms(50), ms(50)]);
+}
@@ -1,11 +1,16 @@
 use std::io;
 use rand::Rng;
 use std::cmp::Ordering;
+use structs::ansi::Style;
+use structs::color::Rgb;
 
 fn main() {
     let (min, max) : (i32, i32) = (1, 100);
     
-    println!("Guess the number!");
+    let hint = Style::new().fg(Rgb(255, 165, 0));
+    let error = Style::new().fg(Rgb(255, 0, 0));
+
+    println!("{}", Style::new().bold().paint("Guess the number!"));
 
     let secret_number = rand::thread_rng().gen_range(min, max + 1);
 
@@ -21,7 +26,7 @@ fn main() {
         let guess: i32 = match guess.trim().parse() {
             Ok(num) => num,
             Err(_) => {
-                println!("Please type a number between {} and {}.", min, max);
+                println!("{}", error.paint(format!("Please type a number between {} and {}.", min, max)));
                 continue;
             }
         };
@@ -29,10 +34,10 @@ fn main() {
         println!("You guessed: {}", guess);
 
         match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
+            Ordering::Less => println!("{}", hint.paint("Too small!")),
+            Ordering::Greater => println!("{}", hint.paint("Too big!")),
             Ordering::Equal => {
-                println!("You win!");
+                println!("{}", Style::new().bold().fg(Rgb(0, 205, 0)).paint("You win!"));
                 break;
             }
         }
@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Field, Fields, GenericArgument, PathArguments, Result, Type};
+
+//* How a single field is treated by the generated builder
+enum Kind<'a> {
+    Required,
+    Optional(&'a Type), // Option<T>, the setter takes the inner T
+    Defaulted,          // #[builder(default)]
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Builder can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Builder can only be derived for structs",
+            ))
+        }
+    };
+
+    let kinds = fields.iter().map(field_kind).collect::<Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let builder = format_ident!("{}Builder", name);
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+
+    let storage = fields.iter().zip(&kinds).map(|(f, kind)| {
+        let ident = &f.ident;
+        let ty = &f.ty;
+        match kind {
+            Kind::Optional(_) => quote! { #ident: #ty },
+            _ => quote! { #ident: ::std::option::Option<#ty> },
+        }
+    });
+
+    let setters = fields.iter().zip(&kinds).map(|(f, kind)| {
+        let ident = &f.ident;
+        let ty = &f.ty;
+        match kind {
+            Kind::Optional(inner) => quote! {
+                pub fn #ident(mut self, value: impl ::std::convert::Into<#inner>) -> Self {
+                    self.#ident = ::std::option::Option::Some(value.into());
+                    self
+                }
+            },
+            _ => quote! {
+                pub fn #ident(mut self, value: impl ::std::convert::Into<#ty>) -> Self {
+                    self.#ident = ::std::option::Option::Some(value.into());
+                    self
+                }
+            },
+        }
+    });
+
+    let checks = fields.iter().zip(&kinds).map(|(f, kind)| {
+        let ident = &f.ident;
+        match kind {
+            Kind::Required => {
+                let message = format!("missing field `{}`", ident.as_ref().unwrap());
+                quote! {
+                    let #ident = self.#ident.ok_or_else(|| ::std::string::String::from(#message))?;
+                }
+            }
+            Kind::Optional(_) => quote! { let #ident = self.#ident; },
+            Kind::Defaulted => quote! { let #ident = self.#ident.unwrap_or_default(); },
+        }
+    });
+
+    Ok(quote! {
+        #vis struct #builder #impl_generics #where_clause {
+            #(#storage,)*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn builder() -> #builder #ty_generics {
+                #builder {
+                    #(#idents: ::std::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(#setters)*
+
+            pub fn build(self) -> ::std::result::Result<#name #ty_generics, ::std::string::String> {
+                #(#checks)*
+                ::std::result::Result::Ok(#name { #(#idents,)* })
+            }
+        }
+    })
+}
+
+fn field_kind(field: &Field) -> Result<Kind<'_>> {
+    let mut defaulted = false;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                defaulted = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown builder attribute, expected `default`"))
+            }
+        })?;
+    }
+
+    match option_inner(&field.ty) {
+        Some(_) if defaulted => Err(Error::new_spanned(
+            &field.ty,
+            "#[builder(default)] is redundant on an Option field",
+        )),
+        Some(inner) => Ok(Kind::Optional(inner)),
+        None if defaulted => Ok(Kind::Defaulted),
+        None => Ok(Kind::Required),
+    }
+}
+
+//* Recognizes Option<T> (also spelled std::option::Option<T>) and returns T
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(p) if p.qself.is_none() => &p.path,
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
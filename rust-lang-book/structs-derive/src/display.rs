@@ -0,0 +1,146 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Member, Result};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Display can only be derived for structs",
+            ))
+        }
+    };
+
+    let template = template_attribute(input)?;
+    let (format, used) = rewrite_template(&template, fields)?;
+
+    let args = used.iter().map(|member| {
+        let name = argument_name(member);
+        quote! { #name = &self.#member }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, #format #(, #args)*)
+            }
+        }
+    })
+}
+
+//* Exactly one #[display("...")] with a string literal is required
+fn template_attribute(input: &DeriveInput) -> Result<LitStr> {
+    let mut template = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("display")) {
+        if template.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate #[display(...)] attribute"));
+        }
+        let lit: LitStr = attr.parse_args().map_err(|_| {
+            Error::new_spanned(attr, "expected a format string, e.g. #[display(\"{field}\")]")
+        })?;
+        template = Some(lit);
+    }
+    template.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "#[derive(Display)] requires a #[display(\"...\")] attribute",
+        )
+    })
+}
+
+//* Tuple fields cannot be named format arguments, so {0} is rewritten to {__field0}
+fn argument_name(member: &Member) -> syn::Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(index) => format_ident!("__field{}", index.index),
+    }
+}
+
+//* Walks the template, checks every placeholder against the struct's fields
+//* and returns the rewritten template together with the fields that it uses
+fn rewrite_template(template: &LitStr, fields: &Fields) -> Result<(LitStr, Vec<Member>)> {
+    let source = template.value();
+    let mut out = String::with_capacity(source.len());
+    let mut used: Vec<Member> = Vec::new();
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if !closed {
+                    return Err(Error::new_spanned(template, "unclosed '{' in display template"));
+                }
+
+                let (name, spec) = match placeholder.find(':') {
+                    Some(i) => placeholder.split_at(i),
+                    None => (placeholder.as_str(), ""),
+                };
+                let member = resolve_field(name.trim(), fields, template)?;
+                out.push('{');
+                out.push_str(&argument_name(&member).to_string());
+                out.push_str(spec);
+                out.push('}');
+                if !used.contains(&member) {
+                    used.push(member);
+                }
+            }
+            '}' => {
+                return Err(Error::new_spanned(template, "unmatched '}' in display template, use '}}'"))
+            }
+            _ => out.push(c),
+        }
+    }
+
+    Ok((LitStr::new(&out, template.span()), used))
+}
+
+fn resolve_field(name: &str, fields: &Fields, template: &LitStr) -> Result<Member> {
+    if name.is_empty() {
+        return Err(Error::new_spanned(
+            template,
+            "positional '{}' is not supported, name the field inside the braces",
+        ));
+    }
+
+    match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .filter_map(|f| f.ident.as_ref())
+            .find(|ident| *ident == name)
+            .map(|ident| Member::Named(ident.clone())),
+        Fields::Unnamed(unnamed) => name
+            .parse::<usize>()
+            .ok()
+            .filter(|i| *i < unnamed.unnamed.len())
+            .map(|i| Member::Unnamed(i.into())),
+        Fields::Unit => None,
+    }
+    .ok_or_else(|| {
+        Error::new_spanned(
+            template,
+            format!("unknown field `{}` in display template", name),
+        )
+    })
+}
@@ -0,0 +1,112 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Field, Fields, Ident, LitStr, Member, Result};
+
+//* Settings collected from the #[getter(...)] attributes of one field
+struct Options {
+    skip: bool,
+    copy: bool,
+    rename: Option<Ident>,
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Getters can only be derived for structs",
+            ))
+        }
+    };
+    if let Fields::Unit = fields {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Getters cannot be derived for a unit struct, it has no fields",
+        ));
+    }
+
+    let mut getters = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let options = field_options(field)?;
+        if options.skip {
+            continue;
+        }
+
+        //* Tuple fields have no name we could use, so they need an explicit rename
+        let (member, getter) = match (&field.ident, options.rename) {
+            (_, Some(rename)) => (member(field, index), rename),
+            (Some(ident), None) => (member(field, index), ident.clone()),
+            (None, None) => {
+                return Err(Error::new_spanned(
+                    field,
+                    "tuple fields need a name, add #[getter(rename = \"...\")]",
+                ))
+            }
+        };
+
+        //* The Copy bound only matters for generic fields like the T in Rgb<T>, other types are checked right away
+        let ty = &field.ty;
+        getters.push(if options.copy {
+            quote! {
+                pub fn #getter(&self) -> #ty
+                where
+                    #ty: ::std::marker::Copy,
+                {
+                    self.#member
+                }
+            }
+        } else {
+            quote! {
+                pub fn #getter(&self) -> &#ty {
+                    &self.#member
+                }
+            }
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    })
+}
+
+fn member(field: &Field, index: usize) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    }
+}
+
+fn field_options(field: &Field) -> Result<Options> {
+    let mut options = Options { skip: false, copy: false, rename: None };
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("getter")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("copy") {
+                options.copy = true;
+            } else if meta.path.is_ident("rename") {
+                let name: LitStr = meta.value()?.parse()?;
+                options.rename = Some(name.parse().map_err(|_| {
+                    Error::new_spanned(&name, "rename must be a valid identifier")
+                })?);
+            } else {
+                return Err(meta.error("unknown getter attribute, expected `skip`, `copy` or `rename`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    if options.skip && (options.copy || options.rename.is_some()) {
+        return Err(Error::new_spanned(
+            field,
+            "#[getter(skip)] cannot be combined with other getter options",
+        ));
+    }
+    Ok(options)
+}
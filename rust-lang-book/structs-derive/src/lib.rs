@@ -0,0 +1,41 @@
+//* A procedural macro crate has to be declared with 'proc-macro = true' in its Cargo.toml
+//* It may only export macros, the helpers live in the private modules below
+//* Each derive receives the token stream of the annotated struct and returns the generated impl
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod builder;
+mod display;
+mod getters;
+
+//* #[derive(Display)] together with #[display("{title} by {author}")] implements std::fmt::Display
+//* Named fields are referred to by name, tuple fields by index, e.g. #[display("({0}, {1})")]
+#[proc_macro_derive(Display, attributes(display))]
+pub fn derive_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    display::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+//* #[derive(Builder)] generates a <Name>Builder with one setter per field and a checked build()
+//* Option fields and fields marked #[builder(default)] may be left out
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    builder::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+//* #[derive(Getters)] generates a borrowing accessor per field
+//* Fields can be tuned with #[getter(skip)], #[getter(copy)] and #[getter(rename = "name")]
+#[proc_macro_derive(Getters, attributes(getter))]
+pub fn derive_getters(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    getters::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,7 @@
+//* Every misuse listed at the end of the derive demo in ../structs/src/main.rs must fail to compile
+//* with the message in the matching tests/ui/*.stderr, regenerate them with TRYBUILD=overwrite
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
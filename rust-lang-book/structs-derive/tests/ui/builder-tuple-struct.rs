@@ -0,0 +1,6 @@
+use structs_derive::Builder;
+
+#[derive(Builder)]
+struct Tuple(i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use structs_derive::Builder;
+
+#[derive(Builder)]
+struct Bad {
+    #[builder(fallback)]
+    a: i32,
+}
+
+fn main() {}
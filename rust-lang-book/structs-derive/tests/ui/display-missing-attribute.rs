@@ -0,0 +1,8 @@
+use structs_derive::Display;
+
+#[derive(Display)]
+struct Missing {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use structs_derive::Display;
+
+#[derive(Display)]
+#[display(42)]
+struct NotAString {
+    a: i32,
+}
+
+fn main() {}
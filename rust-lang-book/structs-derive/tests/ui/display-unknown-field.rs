@@ -0,0 +1,9 @@
+use structs_derive::Display;
+
+#[derive(Display)]
+#[display("{b}")]
+struct Unknown {
+    a: i32,
+}
+
+fn main() {}
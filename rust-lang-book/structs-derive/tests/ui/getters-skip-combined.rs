@@ -0,0 +1,9 @@
+use structs_derive::Getters;
+
+#[derive(Getters)]
+struct Both {
+    #[getter(skip, copy)]
+    a: i32,
+}
+
+fn main() {}
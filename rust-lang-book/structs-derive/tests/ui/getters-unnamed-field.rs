@@ -0,0 +1,6 @@
+use structs_derive::Getters;
+
+#[derive(Getters)]
+struct Unnamed(i32);
+
+fn main() {}
@@ -0,0 +1,361 @@
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+use crate::color::{Color, Rgb};
+
+//* How many colours the terminal understands, ordered from least to most capable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorLevel {
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorLevel {
+    //* Decides the level from the usual environment variables:
+    //*     NO_COLOR (any non-empty value) or TERM=dumb disable escapes entirely
+    //*     COLORTERM=truecolor|24bit enables 24 bit colours
+    //*     TERM=*256color* enables the 256 colour palette
+    //*     any other TERM falls back to the 16 basic colours
+    pub fn from_env(term: Option<&str>, colorterm: Option<&str>, no_color: Option<&str>) -> ColorLevel {
+        if no_color.is_some_and(|v| !v.is_empty()) {
+            return ColorLevel::None;
+        }
+        let term = term.unwrap_or("");
+        if term == "dumb" {
+            return ColorLevel::None;
+        }
+        match colorterm.map(|c| c.to_ascii_lowercase()) {
+            Some(ref c) if c == "truecolor" || c == "24bit" => return ColorLevel::TrueColor,
+            _ => {}
+        }
+        if term.contains("256color") {
+            ColorLevel::Ansi256
+        } else if term.is_empty() {
+            ColorLevel::None
+        } else {
+            ColorLevel::Ansi16
+        }
+    }
+
+    //* Reads the environment of this process, output that isn't a terminal gets no escapes
+    pub fn detect() -> ColorLevel {
+        if !io::stdout().is_terminal() {
+            return ColorLevel::None;
+        }
+        let term = env::var("TERM").ok();
+        let colorterm = env::var("COLORTERM").ok();
+        let no_color = env::var("NO_COLOR").ok();
+        ColorLevel::from_env(term.as_deref(), colorterm.as_deref(), no_color.as_deref())
+    }
+
+    //* The detected level, computed once per process
+    pub fn current() -> ColorLevel {
+        static LEVEL: OnceLock<ColorLevel> = OnceLock::new();
+        *LEVEL.get_or_init(ColorLevel::detect)
+    }
+}
+
+//* The xterm default palette for the 16 basic colours, in ANSI order
+const ANSI16_PALETTE: [Color; 16] = [
+    Rgb(0, 0, 0),
+    Rgb(205, 0, 0),
+    Rgb(0, 205, 0),
+    Rgb(205, 205, 0),
+    Rgb(0, 0, 238),
+    Rgb(205, 0, 205),
+    Rgb(0, 205, 205),
+    Rgb(229, 229, 229),
+    Rgb(127, 127, 127),
+    Rgb(255, 0, 0),
+    Rgb(0, 255, 0),
+    Rgb(255, 255, 0),
+    Rgb(92, 92, 255),
+    Rgb(255, 0, 255),
+    Rgb(0, 255, 255),
+    Rgb(255, 255, 255),
+];
+
+//* Channel intensities of the 6x6x6 colour cube in the 256 colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: Color, b: Color) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+//* Index 0..16 of the closest basic colour
+pub fn to_ansi16(color: Color) -> u8 {
+    (0..16u8)
+        .min_by_key(|i| distance(color, ANSI16_PALETTE[*i as usize]))
+        .unwrap_or(0)
+}
+
+//* Index 16..256 of the closest colour from the colour cube or the grayscale ramp
+pub fn to_ansi256(color: Color) -> u8 {
+    let nearest_level = |c: u8| {
+        (0..6usize)
+            .min_by_key(|i| (i32::from(CUBE_LEVELS[*i]) - i32::from(c)).abs())
+            .unwrap_or(0)
+    };
+    let (r, g, b) = (nearest_level(color.0), nearest_level(color.1), nearest_level(color.2));
+    let cube = Rgb(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let cube_index = 16 + 36 * r as u8 + 6 * g as u8 + b as u8;
+
+    let average = (u32::from(color.0) + u32::from(color.1) + u32::from(color.2)) / 3;
+    let gray_step = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * gray_step;
+    let gray = Rgb(gray_level, gray_level, gray_level);
+
+    if distance(color, gray) < distance(color, cube) {
+        232 + gray_step
+    } else {
+        cube_index
+    }
+}
+
+//* SGR parameters selecting the colour as foreground (base 30) or background (base 40)
+fn color_params(color: Color, level: ColorLevel, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    match level {
+        ColorLevel::None => None,
+        ColorLevel::Ansi16 => {
+            let index = to_ansi16(color);
+            let code = if index < 8 { base + index } else { base + 60 + index - 8 };
+            Some(code.to_string())
+        }
+        ColorLevel::Ansi256 => Some(format!("{};5;{}", base + 8, to_ansi256(color))),
+        ColorLevel::TrueColor => Some(format!("{};2;{};{};{}", base + 8, color.0, color.1, color.2)),
+    }
+}
+
+//* A composable set of text attributes, e.g. Style::new().bold().fg(Rgb(255, 0, 0))
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    pub fn fg(mut self, color: Color) -> Style {
+        self.fg = Some(color);
+        self
+    }
+    pub fn bg(mut self, color: Color) -> Style {
+        self.bg = Some(color);
+        self
+    }
+    pub fn bold(mut self) -> Style {
+        self.bold = true;
+        self
+    }
+    pub fn dim(mut self) -> Style {
+        self.dim = true;
+        self
+    }
+    pub fn italic(mut self) -> Style {
+        self.italic = true;
+        self
+    }
+    pub fn underline(mut self) -> Style {
+        self.underline = true;
+        self
+    }
+
+    pub fn is_plain(&self) -> bool {
+        *self == Style::default()
+    }
+
+    pub fn paint(self, text: impl Into<String>) -> Styled {
+        Styled { text: text.into(), style: self }
+    }
+
+    //* The escape sequence that switches the terminal to this style, empty if nothing is to be done
+    pub fn prefix(&self, level: ColorLevel) -> String {
+        if level == ColorLevel::None {
+            return String::new();
+        }
+
+        let mut params: Vec<String> = Vec::new();
+        if self.bold {
+            params.push("1".to_string());
+        }
+        if self.dim {
+            params.push("2".to_string());
+        }
+        if self.italic {
+            params.push("3".to_string());
+        }
+        if self.underline {
+            params.push("4".to_string());
+        }
+        params.extend(self.fg.and_then(|c| color_params(c, level, false)));
+        params.extend(self.bg.and_then(|c| color_params(c, level, true)));
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", params.join(";"))
+        }
+    }
+}
+
+//* Text bundled with its style, rendered with escapes suited for the terminal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Styled {
+    text: String,
+    style: Style,
+}
+
+impl Styled {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    //* Styles can be layered on top of an already painted text
+    pub fn restyle(mut self, f: impl FnOnce(Style) -> Style) -> Styled {
+        self.style = f(self.style);
+        self
+    }
+
+    pub fn render(&self, level: ColorLevel) -> String {
+        let prefix = self.style.prefix(level);
+        if prefix.is_empty() {
+            self.text.clone()
+        } else {
+            format!("{}{}\x1b[0m", prefix, self.text)
+        }
+    }
+
+    //* Columns occupied by the text on screen, escapes don't count
+    pub fn width(&self) -> usize {
+        display_width(&self.text)
+    }
+}
+
+//* Renders for the detected ColorLevel
+//* Width and alignment ({:<10}, {:^8}, ...) are applied to the visible text, not the escapes
+impl fmt::Display for Styled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rendered = self.render(ColorLevel::current());
+        let padding = f.width().map_or(0, |w| w.saturating_sub(self.width()));
+        let (left, right) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+        let fill = f.fill().to_string();
+        write!(f, "{}{}{}", fill.repeat(left), rendered, fill.repeat(right))
+    }
+}
+
+//* Removes CSI (ESC [ ... final), OSC (ESC ] ... BEL or ESC \) and two-character escape sequences
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+//* Number of terminal columns needed to show the string, ignoring escape codes
+pub fn display_width(s: &str) -> usize {
+    strip_ansi(s).chars().map(char_width).sum()
+}
+
+//* Zero for control and combining characters, two for wide East Asian characters and emoji
+pub fn char_width(c: char) -> usize {
+    let cp = c as u32;
+    if cp < 0x20 || (0x7f..0xa0).contains(&cp) {
+        return 0;
+    }
+    if ZERO_WIDTH.iter().any(|(lo, hi)| (*lo..=*hi).contains(&cp)) {
+        return 0;
+    }
+    if WIDE.iter().any(|(lo, hi)| (*lo..=*hi).contains(&cp)) {
+        return 2;
+    }
+    1
+}
+
+//* Combining marks and format characters of the scripts we print
+const ZERO_WIDTH: [(u32, u32); 22] = [
+    (0x0300, 0x036f), // combining diacritical marks
+    (0x0483, 0x0489), // cyrillic
+    (0x0591, 0x05bd), // hebrew points
+    (0x05bf, 0x05bf),
+    (0x05c1, 0x05c2),
+    (0x05c4, 0x05c5),
+    (0x05c7, 0x05c7),
+    (0x0610, 0x061a), // arabic
+    (0x064b, 0x065f),
+    (0x0670, 0x0670),
+    (0x0900, 0x0902), // devanagari
+    (0x093a, 0x093a),
+    (0x093c, 0x093c),
+    (0x0941, 0x0948),
+    (0x094d, 0x094d),
+    (0x0951, 0x0957),
+    (0x0962, 0x0963),
+    (0x1ab0, 0x1aff), // combining marks extended
+    (0x200b, 0x200f), // zero width space, joiners and direction marks
+    (0x20d0, 0x20ff), // combining marks for symbols
+    (0xfe00, 0xfe0f), // variation selectors
+    (0xfe20, 0xfe2f), // combining half marks
+];
+
+const WIDE: [(u32, u32); 13] = [
+    (0x1100, 0x115f),   // hangul jamo
+    (0x2e80, 0x303e),   // cjk radicals, punctuation
+    (0x3041, 0x33ff),   // hiragana, katakana, cjk symbols
+    (0x3400, 0x4dbf),   // cjk extension a
+    (0x4e00, 0x9fff),   // cjk unified ideographs
+    (0xa000, 0xa4cf),   // yi
+    (0xac00, 0xd7a3),   // hangul syllables
+    (0xf900, 0xfaff),   // cjk compatibility ideographs
+    (0xfe30, 0xfe4f),   // cjk compatibility forms
+    (0xff00, 0xff60),   // fullwidth forms
+    (0xffe0, 0xffe6),
+    (0x1f300, 0x1faff), // emoji and pictographs
+    (0x20000, 0x3fffd), // cjk extensions b and beyond
+];
@@ -0,0 +1,265 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use structs_derive::Display;
+
+//* Everything that can go wrong while building or parsing a Book
+#[derive(Debug, PartialEq)]
+pub enum BookError {
+    MissingTitle,
+    MissingAuthor,
+    MissingYear,
+    EmptyTitle,
+    EmptyAuthor,
+    YearInFuture { year: u16, current: u16 },
+    InvalidYear(String),
+    InvalidIsbn(String),
+    InvalidEdition,
+    MalformedBanner(String),
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BookError::MissingTitle => write!(f, "a book needs a title"),
+            BookError::MissingAuthor => write!(f, "a book needs an author"),
+            BookError::MissingYear => write!(f, "a book needs a year"),
+            BookError::EmptyTitle => write!(f, "the title must not be empty"),
+            BookError::EmptyAuthor => write!(f, "the author must not be empty"),
+            BookError::YearInFuture { year, current } => {
+                write!(f, "year {} lies in the future (current year is {})", year, current)
+            }
+            BookError::InvalidYear(year) => write!(f, "'{}' is not a valid year", year),
+            BookError::InvalidIsbn(isbn) => write!(f, "'{}' is not a valid ISBN", isbn),
+            BookError::InvalidEdition => write!(f, "editions start counting at 1"),
+            BookError::MalformedBanner(banner) => {
+                write!(f, "'{}' does not look like '<title> by <author>, <year>'", banner)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {}
+
+//* Display prints the banner through structs-derive, so "{}" and get_banner() always agree
+#[derive(Debug, Clone, PartialEq, Display)]
+#[display("{title} by {author}, {year}")]
+pub struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    subtitle: Option<String>,
+    isbn: Option<String>,
+    edition: Option<u8>,
+    language: Option<String>,
+}
+
+impl Book {
+    //* Entry point for constructing a book, the fields are only accepted after validation
+    pub fn builder() -> BookBuilder {
+        BookBuilder::default()
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+    pub fn subtitle(&self) -> Option<&str> {
+        self.subtitle.as_deref()
+    }
+    pub fn isbn(&self) -> Option<&str> {
+        self.isbn.as_deref()
+    }
+    pub fn edition(&self) -> Option<u8> {
+        self.edition
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn get_banner(&self) -> String {
+        self.to_string()
+    }
+    pub fn has_title(&self, title: &str) -> bool {
+        self.title == title
+    }
+}
+
+//* Parses the banner format back into a validated book
+//* The year is split off at the last ", " and the author at the last " by ",
+//* so titles like "Stand by Me" survive the round trip
+impl FromStr for Book {
+    type Err = BookError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || BookError::MalformedBanner(s.to_string());
+        let (rest, year) = s.rsplit_once(", ").ok_or_else(malformed)?;
+        let (title, author) = rest.rsplit_once(" by ").ok_or_else(malformed)?;
+        let year: u16 = year
+            .trim()
+            .parse()
+            .map_err(|_| BookError::InvalidYear(year.to_string()))?;
+
+        Book::builder().title(title).author(author).year(year).build()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BookBuilder {
+    title: Option<String>,
+    author: Option<String>,
+    year: Option<u16>,
+    subtitle: Option<String>,
+    isbn: Option<String>,
+    edition: Option<u8>,
+    language: Option<String>,
+    current_year: Option<u16>,
+}
+
+impl BookBuilder {
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+    pub fn year(mut self, year: u16) -> Self {
+        self.year = Some(year);
+        self
+    }
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+    pub fn isbn(mut self, isbn: impl Into<String>) -> Self {
+        self.isbn = Some(isbn.into());
+        self
+    }
+    pub fn edition(mut self, edition: u8) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    //* Overrides the year used for the "not in the future" rule, defaults to the system clock
+    pub fn current_year(mut self, current_year: u16) -> Self {
+        self.current_year = Some(current_year);
+        self
+    }
+
+    pub fn build(self) -> Result<Book, BookError> {
+        let title = self.title.ok_or(BookError::MissingTitle)?;
+        let author = self.author.ok_or(BookError::MissingAuthor)?;
+        let year = self.year.ok_or(BookError::MissingYear)?;
+
+        if title.trim().is_empty() {
+            return Err(BookError::EmptyTitle);
+        }
+        if author.trim().is_empty() {
+            return Err(BookError::EmptyAuthor);
+        }
+
+        let current = self.current_year.unwrap_or_else(current_year);
+        if year > current {
+            return Err(BookError::YearInFuture { year, current });
+        }
+
+        let isbn = match self.isbn {
+            Some(isbn) => Some(normalize_isbn(&isbn).ok_or(BookError::InvalidIsbn(isbn))?),
+            None => None,
+        };
+
+        if self.edition == Some(0) {
+            return Err(BookError::InvalidEdition);
+        }
+
+        Ok(Book {
+            title,
+            author,
+            year,
+            subtitle: self.subtitle.filter(|s| !s.trim().is_empty()),
+            isbn,
+            edition: self.edition,
+            language: self.language.filter(|l| !l.trim().is_empty()),
+        })
+    }
+}
+
+//* Strips hyphens and spaces and verifies the ISBN-10 or ISBN-13 check digit
+pub fn normalize_isbn(isbn: &str) -> Option<String> {
+    let digits: String = isbn
+        .chars()
+        .filter(|c| *c != '-' && *c != ' ')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let valid = match digits.len() {
+        10 => is_valid_isbn10(&digits),
+        13 => is_valid_isbn13(&digits),
+        _ => false,
+    };
+
+    if valid {
+        Some(digits)
+    } else {
+        None
+    }
+}
+
+fn is_valid_isbn10(digits: &str) -> bool {
+    let mut sum = 0;
+    for (i, c) in digits.chars().enumerate() {
+        let value = match c {
+            'X' if i == 9 => 10,
+            '0'..='9' => c.to_digit(10).unwrap(),
+            _ => return false,
+        };
+        sum += value * (10 - i as u32);
+    }
+    sum % 11 == 0
+}
+
+fn is_valid_isbn13(digits: &str) -> bool {
+    let mut sum = 0;
+    for (i, c) in digits.chars().enumerate() {
+        let value = match c.to_digit(10) {
+            Some(value) => value,
+            None => return false,
+        };
+        sum += if i % 2 == 0 { value } else { value * 3 };
+    }
+    sum % 10 == 0
+}
+
+//* Derives the current (UTC) year from the system clock without pulling in a date crate
+fn current_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    year_from_days((secs / 86_400) as i64)
+}
+
+//* Civil-from-days conversion for the proleptic Gregorian calendar
+fn year_from_days(days: i64) -> u16 {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    year as u16
+}
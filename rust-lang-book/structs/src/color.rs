@@ -0,0 +1,621 @@
+use std::fmt;
+use std::str::FromStr;
+
+use structs_derive::Getters;
+
+//* An sRGB colour as a tuple struct, generic over the channel type
+//* Rgb<u8> stores the familiar 0..=255 channels, Rgb<f32> normalized channels in 0.0..=1.0
+//* red(), green() and blue() are generated by structs-derive for every channel type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Getters)]
+pub struct Rgb<T = u8>(
+    #[getter(copy, rename = "red")] pub T,
+    #[getter(copy, rename = "green")] pub T,
+    #[getter(copy, rename = "blue")] pub T,
+);
+
+//* The chapter's Color(i32, i32, i32) with channels that cannot go negative
+pub type Color = Rgb<u8>;
+
+//* sRGB with an alpha channel, 0 is fully transparent and 255 fully opaque
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgba<T = u8>(pub T, pub T, pub T, pub T);
+
+//* Hue in degrees [0, 360), saturation and lightness in [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+//* Hue in degrees [0, 360), saturation and value in [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+//* sRGB without the gamma curve, the space in which light actually adds up
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearRgb(pub f32, pub f32, pub f32);
+
+//* CIE L*a*b* relative to the D65 white point
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseColorError {
+    Empty,
+    InvalidHex(String),
+    InvalidFunction(String),
+    ChannelOutOfRange(String),
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "cannot parse a colour from an empty string"),
+            ParseColorError::InvalidHex(s) => write!(f, "'{}' is not a valid hex colour", s),
+            ParseColorError::InvalidFunction(s) => write!(f, "'{}' is not a valid rgb()/rgba() colour", s),
+            ParseColorError::ChannelOutOfRange(s) => write!(f, "channel '{}' is out of range", s),
+            ParseColorError::UnknownName(s) => write!(f, "'{}' is not a known colour name", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+//* WCAG 2.x conformance levels for text contrast
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    Fail,
+    AaLarge,
+    Aa,
+    Aaa,
+}
+
+impl Rgb<u8> {
+    pub const BLACK: Rgb<u8> = Rgb(0, 0, 0);
+    pub const WHITE: Rgb<u8> = Rgb(255, 255, 255);
+
+    pub fn with_alpha(self, alpha: u8) -> Rgba<u8> {
+        Rgba(self.0, self.1, self.2, alpha)
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    //* Interpolates between two colours in linear light, t = 0.0 yields self and t = 1.0 yields other
+    pub fn mix(self, other: Rgb<u8>, t: f32) -> Rgb<u8> {
+        let t = t.clamp(0.0, 1.0);
+        let a = LinearRgb::from(self);
+        let b = LinearRgb::from(other);
+        Rgb::from(LinearRgb(
+            a.0 + (b.0 - a.0) * t,
+            a.1 + (b.1 - a.1) * t,
+            a.2 + (b.2 - a.2) * t,
+        ))
+    }
+
+    //* Relative luminance as defined by WCAG, 0.0 for black and 1.0 for white
+    pub fn relative_luminance(&self) -> f32 {
+        let LinearRgb(r, g, b) = LinearRgb::from(*self);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    //* WCAG contrast ratio between 1.0 (no contrast) and 21.0 (black on white)
+    pub fn contrast_ratio(&self, other: &Rgb<u8>) -> f32 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    //* Highest WCAG level reached by this colour as text on the given background
+    pub fn wcag_level(&self, background: &Rgb<u8>, large_text: bool) -> WcagLevel {
+        let ratio = self.contrast_ratio(background);
+        let (aaa, aa) = if large_text { (4.5, 3.0) } else { (7.0, 4.5) };
+        if ratio >= aaa {
+            WcagLevel::Aaa
+        } else if ratio >= aa {
+            WcagLevel::Aa
+        } else if ratio >= 3.0 {
+            WcagLevel::AaLarge
+        } else {
+            WcagLevel::Fail
+        }
+    }
+
+    //* Looks up a CSS named colour, case-insensitive
+    pub fn from_name(name: &str) -> Option<Rgb<u8>> {
+        let name = name.trim().to_ascii_lowercase();
+        NAMED_COLORS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, hex)| Rgb((hex >> 16) as u8, (hex >> 8) as u8, *hex as u8))
+    }
+
+    //* Reverse lookup of the CSS name for an exact colour
+    pub fn name(&self) -> Option<&'static str> {
+        let hex = (u32::from(self.0) << 16) | (u32::from(self.1) << 8) | u32::from(self.2);
+        NAMED_COLORS.iter().find(|(_, h)| *h == hex).map(|(n, _)| *n)
+    }
+}
+
+impl Rgba<u8> {
+    pub fn rgb(&self) -> Rgb<u8> {
+        Rgb(self.0, self.1, self.2)
+    }
+    pub fn alpha(&self) -> u8 {
+        self.3
+    }
+
+    //* Porter-Duff 'source over' compositing of self on top of the backdrop, computed in linear light
+    pub fn over(self, backdrop: Rgba<u8>) -> Rgba<u8> {
+        let sa = f32::from(self.3) / 255.0;
+        let da = f32::from(backdrop.3) / 255.0;
+        let out_a = sa + da * (1.0 - sa);
+        if out_a <= 0.0 {
+            return Rgba(0, 0, 0, 0);
+        }
+
+        let s = LinearRgb::from(self.rgb());
+        let d = LinearRgb::from(backdrop.rgb());
+        let channel = |s: f32, d: f32| (s * sa + d * da * (1.0 - sa)) / out_a;
+        let rgb = Rgb::from(LinearRgb(channel(s.0, d.0), channel(s.1, d.1), channel(s.2, d.2)));
+        rgb.with_alpha(to_u8(out_a))
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl fmt::Display for Rgb<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl fmt::Display for Rgba<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+//* Accepts "#rgb", "#rrggbb", "rgb(r, g, b)" and CSS colour names such as "rebeccapurple"
+impl FromStr for Rgb<u8> {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        let lower = s.to_ascii_lowercase();
+
+        if let Some(hex) = lower.strip_prefix('#') {
+            return match parse_hex(hex) {
+                Some(Rgba(r, g, b, 255)) if hex.len() == 3 || hex.len() == 6 => Ok(Rgb(r, g, b)),
+                _ => Err(ParseColorError::InvalidHex(s.to_string())),
+            };
+        }
+        if lower.starts_with("rgb(") {
+            let channels = parse_function(s, &lower, "rgb", 3)?;
+            return Ok(Rgb(channels[0], channels[1], channels[2]));
+        }
+
+        Rgb::from_name(&lower).ok_or_else(|| ParseColorError::UnknownName(s.to_string()))
+    }
+}
+
+//* Additionally accepts "#rgba", "#rrggbbaa" and "rgba(r, g, b, a)" with alpha in [0, 1]
+impl FromStr for Rgba<u8> {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_ascii_lowercase();
+
+        if let Some(hex) = lower.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError::InvalidHex(s.to_string()));
+        }
+        if lower.starts_with("rgba(") {
+            let channels = parse_function(s, &lower, "rgba", 4)?;
+            return Ok(Rgba(channels[0], channels[1], channels[2], channels[3]));
+        }
+        if lower == "transparent" {
+            return Ok(Rgba(0, 0, 0, 0));
+        }
+
+        s.parse::<Rgb<u8>>().map(|rgb| rgb.with_alpha(255))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba<u8>> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok();
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    match hex.len() {
+        3 | 4 => {
+            let short = |i: usize| digit(i).map(|d| d * 17);
+            let alpha = if hex.len() == 4 { short(3)? } else { 255 };
+            Some(Rgba(short(0)?, short(1)?, short(2)?, alpha))
+        }
+        6 | 8 => {
+            let alpha = if hex.len() == 8 { pair(6)? } else { 255 };
+            Some(Rgba(pair(0)?, pair(2)?, pair(4)?, alpha))
+        }
+        _ => None,
+    }
+}
+
+//* Parses the comma separated arguments of rgb(...)/rgba(...), the 4th argument is a float alpha
+fn parse_function(original: &str, lower: &str, name: &str, arity: usize) -> Result<Vec<u8>, ParseColorError> {
+    let invalid = || ParseColorError::InvalidFunction(original.to_string());
+    let inner = lower
+        .strip_prefix(name)
+        .and_then(|rest| rest.trim_start().strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(invalid)?;
+
+    let args: Vec<&str> = inner.split(',').map(|a| a.trim()).collect();
+    if args.len() != arity {
+        return Err(invalid());
+    }
+
+    let mut channels = Vec::with_capacity(arity);
+    for (i, arg) in args.iter().enumerate() {
+        let channel = if i == 3 {
+            let alpha: f32 = arg.parse().map_err(|_| invalid())?;
+            if !(0.0..=1.0).contains(&alpha) {
+                return Err(ParseColorError::ChannelOutOfRange(arg.to_string()));
+            }
+            to_u8(alpha)
+        } else if let Some(percent) = arg.strip_suffix('%') {
+            let percent: f32 = percent.trim().parse().map_err(|_| invalid())?;
+            if !(0.0..=100.0).contains(&percent) {
+                return Err(ParseColorError::ChannelOutOfRange(arg.to_string()));
+            }
+            to_u8(percent / 100.0)
+        } else {
+            let value: i64 = arg.parse().map_err(|_| invalid())?;
+            if !(0..=255).contains(&value) {
+                return Err(ParseColorError::ChannelOutOfRange(arg.to_string()));
+            }
+            value as u8
+        };
+        channels.push(channel);
+    }
+    Ok(channels)
+}
+
+fn to_u8(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl From<Rgb<u8>> for Rgb<f32> {
+    fn from(c: Rgb<u8>) -> Self {
+        Rgb(f32::from(c.0) / 255.0, f32::from(c.1) / 255.0, f32::from(c.2) / 255.0)
+    }
+}
+
+impl From<Rgb<f32>> for Rgb<u8> {
+    fn from(c: Rgb<f32>) -> Self {
+        Rgb(to_u8(c.0), to_u8(c.1), to_u8(c.2))
+    }
+}
+
+impl From<Rgb<u8>> for LinearRgb {
+    fn from(c: Rgb<u8>) -> Self {
+        let Rgb(r, g, b) = Rgb::<f32>::from(c);
+        LinearRgb(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b))
+    }
+}
+
+impl From<LinearRgb> for Rgb<u8> {
+    fn from(c: LinearRgb) -> Self {
+        Rgb::from(Rgb(linear_to_srgb(c.0), linear_to_srgb(c.1), linear_to_srgb(c.2)))
+    }
+}
+
+//* Hue, and the max/min channel of a normalized colour, shared by the HSL and HSV conversions
+fn hue_and_extrema(c: Rgb<f32>) -> (f32, f32, f32) {
+    let Rgb(r, g, b) = c;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (hue, max, min)
+}
+
+//* Shared inverse for HSL/HSV: places chroma and the second largest component by hue sector
+fn from_chroma(h: f32, chroma: f32, m: f32) -> Rgb<u8> {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Rgb::from(Rgb(r + m, g + m, b + m))
+}
+
+impl From<Rgb<u8>> for Hsl {
+    fn from(c: Rgb<u8>) -> Self {
+        let (h, max, min) = hue_and_extrema(Rgb::<f32>::from(c));
+        let l = (max + min) / 2.0;
+        let s = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * l - 1.0).abs()) };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Hsl> for Rgb<u8> {
+    fn from(c: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * c.l - 1.0).abs()) * c.s;
+        from_chroma(c.h, chroma, c.l - chroma / 2.0)
+    }
+}
+
+impl From<Rgb<u8>> for Hsv {
+    fn from(c: Rgb<u8>) -> Self {
+        let (h, max, min) = hue_and_extrema(Rgb::<f32>::from(c));
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Hsv> for Rgb<u8> {
+    fn from(c: Hsv) -> Self {
+        let chroma = c.v * c.s;
+        from_chroma(c.h, chroma, c.v - chroma)
+    }
+}
+
+//* D65 reference white in XYZ
+const WHITE_X: f32 = 0.950_47;
+const WHITE_Y: f32 = 1.0;
+const WHITE_Z: f32 = 1.088_83;
+
+impl From<Rgb<u8>> for Lab {
+    fn from(c: Rgb<u8>) -> Self {
+        let LinearRgb(r, g, b) = LinearRgb::from(c);
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+        let f = |t: f32| {
+            if t > 216.0 / 24_389.0 {
+                t.cbrt()
+            } else {
+                (24_389.0 / 27.0 * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / WHITE_X), f(y / WHITE_Y), f(z / WHITE_Z));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl From<Lab> for Rgb<u8> {
+    fn from(c: Lab) -> Self {
+        let fy = (c.l + 16.0) / 116.0;
+        let fx = fy + c.a / 500.0;
+        let fz = fy - c.b / 200.0;
+
+        let f_inv = |t: f32| {
+            if t.powi(3) > 216.0 / 24_389.0 {
+                t.powi(3)
+            } else {
+                (116.0 * t - 16.0) * 27.0 / 24_389.0
+            }
+        };
+        let (x, y, z) = (f_inv(fx) * WHITE_X, f_inv(fy) * WHITE_Y, f_inv(fz) * WHITE_Z);
+
+        Rgb::from(LinearRgb(
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+        ))
+    }
+}
+
+impl Lab {
+    //* CIE76 colour difference, roughly 2.3 is the smallest difference the eye notices
+    pub fn delta_e(&self, other: &Lab) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+}
+
+//* The CSS Color Module Level 4 named colours as 0xRRGGBB
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
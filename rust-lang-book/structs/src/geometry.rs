@@ -0,0 +1,731 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//* The arithmetic every coordinate type has to support
+//* Integers and floats both qualify, which lets us build exact integer grids and continuous shapes alike
+pub trait Number:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    //* Lossless for floats and integers up to 2^53, larger i64 values get rounded
+    fn to_f64(self) -> f64;
+    //* The exact value for integer types, None for floats
+    fn to_i64(self) -> Option<i64>;
+    //* None if the result doesn't fit, floats never overflow (they become infinite)
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+//* Floating point coordinates additionally support lengths, angles and normalization
+pub trait Float: Number {
+    const EPSILON: Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn abs(self) -> Self;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_number {
+    (integer: $($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self { 0 }
+                fn one() -> Self { 1 }
+                fn to_f64(self) -> f64 { self as f64 }
+                fn to_i64(self) -> Option<i64> { Some(self as i64) }
+                fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+                fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+                fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+            }
+        )*
+    };
+    (float: $($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self { 0.0 }
+                fn one() -> Self { 1.0 }
+                fn to_f64(self) -> f64 { self as f64 }
+                fn to_i64(self) -> Option<i64> { None }
+                fn checked_add(self, other: Self) -> Option<Self> { Some(self + other) }
+                fn checked_sub(self, other: Self) -> Option<Self> { Some(self - other) }
+                fn checked_mul(self, other: Self) -> Option<Self> { Some(self * other) }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const EPSILON: Self = $t::EPSILON;
+                fn sqrt(self) -> Self { $t::sqrt(self) }
+                fn sin(self) -> Self { $t::sin(self) }
+                fn cos(self) -> Self { $t::cos(self) }
+                fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+                fn abs(self) -> Self { $t::abs(self) }
+                fn from_f64(value: f64) -> Self { value as $t }
+            }
+        )*
+    };
+}
+
+impl_number!(integer: i32, i64);
+impl_number!(float: f32, f64);
+impl_float!(f32, f64);
+
+//* Compares two floats with a tolerance relative to their magnitude
+pub fn approx_eq<T: Float>(a: T, b: T, tolerance: T) -> bool {
+    let diff = (a - b).abs();
+    let scale = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    diff <= tolerance || diff <= tolerance * scale
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Number> Vec2<T> {
+    pub fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+    pub fn zero() -> Vec2<T> {
+        Vec2::new(T::zero(), T::zero())
+    }
+    pub fn dot(self, other: Vec2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+    //* The z component of the 3D cross product, positive if other lies counter-clockwise of self
+    pub fn cross(self, other: Vec2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+    //* Rotated by 90 degrees counter-clockwise
+    pub fn perpendicular(self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+impl<T: Float> Vec2<T> {
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+    pub fn normalize(self) -> Option<Vec2<T>> {
+        let length = self.length();
+        if length <= T::EPSILON {
+            None
+        } else {
+            Some(self / length)
+        }
+    }
+    //* Counter-clockwise rotation by an angle in radians
+    pub fn rotate(self, angle: T) -> Vec2<T> {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Vec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+    //* Signed angle in radians from self to other
+    pub fn angle_to(self, other: Vec2<T>) -> T {
+        self.cross(other).atan2(self.dot(other))
+    }
+}
+
+impl<T: Number> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+    pub fn zero() -> Vec3<T> {
+        Vec3::new(T::zero(), T::zero(), T::zero())
+    }
+    pub fn dot(self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    pub fn cross(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+    pub fn normalize(self) -> Option<Vec3<T>> {
+        let length = self.length();
+        if length <= T::EPSILON {
+            None
+        } else {
+            Some(self / length)
+        }
+    }
+    //* Rotation around an arbitrary axis by an angle in radians (Rodrigues' formula)
+    pub fn rotate_around(self, axis: Vec3<T>, angle: T) -> Vec3<T> {
+        let k = match axis.normalize() {
+            Some(k) => k,
+            None => return self,
+        };
+        let (sin, cos) = (angle.sin(), angle.cos());
+        self * cos + k.cross(self) * sin + k * (k.dot(self) * (T::one() - cos))
+    }
+}
+
+//* Component-wise operators, written once for both vector types
+macro_rules! impl_vector_ops {
+    ($name:ident { $($field:ident),* }) => {
+        impl<T: Number> Add for $name<T> {
+            type Output = $name<T>;
+            fn add(self, rhs: $name<T>) -> $name<T> {
+                $name { $($field: self.$field + rhs.$field),* }
+            }
+        }
+        impl<T: Number> Sub for $name<T> {
+            type Output = $name<T>;
+            fn sub(self, rhs: $name<T>) -> $name<T> {
+                $name { $($field: self.$field - rhs.$field),* }
+            }
+        }
+        impl<T: Number> Mul<T> for $name<T> {
+            type Output = $name<T>;
+            fn mul(self, rhs: T) -> $name<T> {
+                $name { $($field: self.$field * rhs),* }
+            }
+        }
+        impl<T: Number> Div<T> for $name<T> {
+            type Output = $name<T>;
+            fn div(self, rhs: T) -> $name<T> {
+                $name { $($field: self.$field / rhs),* }
+            }
+        }
+        impl<T: Number> Neg for $name<T> {
+            type Output = $name<T>;
+            fn neg(self) -> $name<T> {
+                $name { $($field: -self.$field),* }
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2 { x, y });
+impl_vector_ops!(Vec3 { x, y, z });
+
+//* The Point<T, S> from the generics chapter, the coordinates may still have different types
+//* Geometry is only available when both coordinates share a numeric type
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point<T, S = T> {
+    pub x: T,
+    pub y: S,
+}
+
+impl<T, S> Point<T, S> {
+    pub fn new(x: T, y: S) -> Point<T, S> {
+        Point { x, y }
+    }
+    pub fn as_tuple(&self) -> (&T, &S) {
+        (&self.x, &self.y)
+    }
+}
+
+impl<T: Number> Point<T, T> {
+    pub fn origin() -> Point<T, T> {
+        Point::new(T::zero(), T::zero())
+    }
+    pub fn to_vec(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+    pub fn distance_squared(self, other: Point<T, T>) -> T {
+        (other - self).length_squared()
+    }
+}
+
+impl<T: Float> Point<T, T> {
+    pub fn distance(self, other: Point<T, T>) -> T {
+        (other - self).length()
+    }
+    //* Generalizes the chapter's Point<f32, f32>::distance_from_origin to every float type
+    pub fn distance_from_origin(self) -> T {
+        self.to_vec().length()
+    }
+    pub fn lerp(self, other: Point<T, T>, t: T) -> Point<T, T> {
+        self + (other - self) * t
+    }
+    pub fn rotate_around(self, center: Point<T, T>, angle: T) -> Point<T, T> {
+        center + (self - center).rotate(angle)
+    }
+}
+
+impl<T: Number> Sub for Point<T, T> {
+    type Output = Vec2<T>;
+    fn sub(self, rhs: Point<T, T>) -> Vec2<T> {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Number> Add<Vec2<T>> for Point<T, T> {
+    type Output = Point<T, T>;
+    fn add(self, rhs: Vec2<T>) -> Point<T, T> {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Number> Sub<Vec2<T>> for Point<T, T> {
+    type Output = Point<T, T>;
+    fn sub(self, rhs: Vec2<T>) -> Point<T, T> {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+//* Which side of the directed line a -> b the point c lies on
+//* Integer coordinates are decided exactly in 128 bit arithmetic, i64 beyond 2^53 would not survive to_f64()
+//* For floats the naive determinant is only trusted if it clearly exceeds its rounding error,
+//* otherwise the sign is recomputed exactly with floating point expansions (after Shewchuk)
+pub fn orient2d<T: Number>(a: Point<T, T>, b: Point<T, T>, c: Point<T, T>) -> Orientation {
+    let integers = [a.x, a.y, b.x, b.y, c.x, c.y].map(Number::to_i64);
+    if let [Some(ax), Some(ay), Some(bx), Some(by), Some(cx), Some(cy)] = integers {
+        return orient2d_integer(ax, ay, bx, by, cx, cy);
+    }
+
+    let (ax, ay) = (a.x.to_f64(), a.y.to_f64());
+    let (bx, by) = (b.x.to_f64(), b.y.to_f64());
+    let (cx, cy) = (c.x.to_f64(), c.y.to_f64());
+
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let det = left - right;
+    let error_bound = (3.0 + 16.0 * f64::EPSILON) * f64::EPSILON * (left.abs() + right.abs());
+
+    let sign = if det.abs() > error_bound {
+        det
+    } else {
+        orient2d_exact(ax, ay, bx, by, cx, cy)
+    };
+
+    if sign > 0.0 {
+        Orientation::CounterClockwise
+    } else if sign < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+//* (ax - cx) * (by - cy) compared to (ay - cy) * (bx - cx)
+//* The differences of two i64 fit an i128 and their magnitudes are below 2^64,
+//* so the magnitude of each product is below 2^128 and fits an u128
+fn orient2d_integer(ax: i64, ay: i64, bx: i64, by: i64, cx: i64, cy: i64) -> Orientation {
+    let product = |a: i64, b: i64, c: i64, d: i64| {
+        let (x, y) = (a as i128 - b as i128, c as i128 - d as i128);
+        let negative = (x < 0) != (y < 0) && x != 0 && y != 0;
+        (negative, x.unsigned_abs() * y.unsigned_abs())
+    };
+    let left = product(ax, cx, by, cy);
+    let right = product(ay, cy, bx, cx);
+    //* positive products sort above negative ones, among negative ones the larger magnitude is smaller
+    let key = |(negative, magnitude): (bool, u128)| {
+        (!negative, if negative { u128::MAX - magnitude } else { magnitude })
+    };
+    match key(left).cmp(&key(right)) {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less => Orientation::Clockwise,
+        Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+//* a*b as an exact sum of two floats
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+//* Adds a float to a nonoverlapping expansion without any rounding error
+fn grow_expansion(expansion: &mut Vec<f64>, value: f64) {
+    let mut q = value;
+    for component in expansion.iter_mut() {
+        let sum = q + *component;
+        let virtual_b = sum - q;
+        let error = (q - (sum - virtual_b)) + (*component - virtual_b);
+        *component = error;
+        q = sum;
+    }
+    expansion.push(q);
+}
+
+//* det = ax*by - ay*bx + bx*cy - by*cx + cx*ay - cy*ax, summed exactly
+//* The sign of an expansion is the sign of its most significant nonzero component
+fn orient2d_exact(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64) -> f64 {
+    let terms = [
+        two_product(ax, by),
+        two_product(-ay, bx),
+        two_product(bx, cy),
+        two_product(-by, cx),
+        two_product(cx, ay),
+        two_product(-cy, ax),
+    ];
+
+    let mut expansion = Vec::with_capacity(12);
+    for (product, error) in terms.iter() {
+        grow_expansion(&mut expansion, *error);
+        grow_expansion(&mut expansion, *product);
+    }
+    expansion.iter().rev().find(|c| **c != 0.0).copied().unwrap_or(0.0)
+}
+
+//* An infinite line through a point along a direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line<T> {
+    pub origin: Point<T, T>,
+    pub direction: Vec2<T>,
+}
+
+impl<T: Number> Line<T> {
+    pub fn through(a: Point<T, T>, b: Point<T, T>) -> Line<T> {
+        Line { origin: a, direction: b - a }
+    }
+    pub fn side(&self, p: Point<T, T>) -> Orientation {
+        orient2d(self.origin, self.origin + self.direction, p)
+    }
+}
+
+impl<T: Float> Line<T> {
+    pub fn distance_to(&self, p: Point<T, T>) -> T {
+        self.direction.cross(p - self.origin).abs() / self.direction.length()
+    }
+
+    //* None for parallel (or identical) lines
+    pub fn intersection(&self, other: &Line<T>) -> Option<Point<T, T>> {
+        let denominator = self.direction.cross(other.direction);
+        if denominator.abs() <= T::EPSILON * self.direction.length() * other.direction.length() {
+            return None;
+        }
+        let t = (other.origin - self.origin).cross(other.direction) / denominator;
+        Some(self.origin + self.direction * t)
+    }
+
+    pub fn project(&self, p: Point<T, T>) -> Point<T, T> {
+        let t = (p - self.origin).dot(self.direction) / self.direction.length_squared();
+        self.origin + self.direction * t
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<T> {
+    pub start: Point<T, T>,
+    pub end: Point<T, T>,
+}
+
+impl<T: Number> Segment<T> {
+    pub fn new(start: Point<T, T>, end: Point<T, T>) -> Segment<T> {
+        Segment { start, end }
+    }
+
+    pub fn bounding_box(&self) -> Aabb<T> {
+        Aabb::from_points(&[self.start, self.end]).unwrap()
+    }
+
+    //* True if p lies exactly on the segment, endpoints included
+    pub fn contains(&self, p: Point<T, T>) -> bool {
+        orient2d(self.start, self.end, p) == Orientation::Collinear && self.bounding_box().contains(p)
+    }
+
+    //* Exact for integer coordinates, touching endpoints and collinear overlaps count as intersecting
+    pub fn intersects(&self, other: &Segment<T>) -> bool {
+        let d1 = orient2d(other.start, other.end, self.start);
+        let d2 = orient2d(other.start, other.end, self.end);
+        let d3 = orient2d(self.start, self.end, other.start);
+        let d4 = orient2d(self.start, self.end, other.end);
+
+        let straddles = |a: Orientation, b: Orientation| {
+            a != Orientation::Collinear && b != Orientation::Collinear && a != b
+        };
+        if straddles(d1, d2) && straddles(d3, d4) {
+            return true;
+        }
+
+        self.contains(other.start)
+            || self.contains(other.end)
+            || other.contains(self.start)
+            || other.contains(self.end)
+    }
+}
+
+impl<T: Float> Segment<T> {
+    pub fn length(&self) -> T {
+        self.start.distance(self.end)
+    }
+    pub fn midpoint(&self) -> Point<T, T> {
+        self.start.lerp(self.end, T::from_f64(0.5))
+    }
+
+    pub fn closest_point(&self, p: Point<T, T>) -> Point<T, T> {
+        let direction = self.end - self.start;
+        let length_squared = direction.length_squared();
+        if length_squared <= T::EPSILON {
+            return self.start;
+        }
+        let t = (p - self.start).dot(direction) / length_squared;
+        let t = if t < T::zero() {
+            T::zero()
+        } else if t > T::one() {
+            T::one()
+        } else {
+            t
+        };
+        self.start + direction * t
+    }
+
+    pub fn distance_to(&self, p: Point<T, T>) -> T {
+        self.closest_point(p).distance(p)
+    }
+
+    pub fn intersection(&self, other: &Segment<T>) -> Option<Point<T, T>> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Line::through(self.start, self.end)
+            .intersection(&Line::through(other.start, other.end))
+            .or_else(|| {
+                //* collinear overlap, report the first shared endpoint
+                [other.start, other.end, self.start, self.end]
+                    .iter()
+                    .copied()
+                    .find(|p| self.contains(*p) && other.contains(*p))
+            })
+    }
+}
+
+//* A simple polygon given by its vertices, the closing edge back to the first vertex is implicit
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point<T, T>>,
+}
+
+impl<T: Number> Polygon<T> {
+    //* Needs at least three vertices to enclose an area
+    pub fn new(vertices: Vec<Point<T, T>>) -> Option<Polygon<T>> {
+        if vertices.len() < 3 {
+            None
+        } else {
+            Some(Polygon { vertices })
+        }
+    }
+
+    pub fn vertices(&self) -> &[Point<T, T>] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| Segment::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    //* Twice the signed area (shoelace formula), stays exact for integer coordinates
+    //* Panics if it doesn't fit the integer type, see checked_signed_area_doubled()
+    pub fn signed_area_doubled(&self) -> T {
+        self.checked_signed_area_doubled().expect("the doubled area of the polygon overflows its coordinate type")
+    }
+
+    //* None if an intermediate product or sum overflows, which takes coordinates beyond 2^31 for i64
+    pub fn checked_signed_area_doubled(&self) -> Option<T> {
+        self.edges().try_fold(T::zero(), |sum, e| {
+            let cross = e.start.x.checked_mul(e.end.y)?.checked_sub(e.start.y.checked_mul(e.end.x)?)?;
+            sum.checked_add(cross)
+        })
+    }
+
+    //* Follows the sign of the area, or, if that overflows, the turn at the lowest vertex which
+    //* for a simple polygon always goes the same way as the whole polygon
+    pub fn orientation(&self) -> Orientation {
+        let doubled = match self.checked_signed_area_doubled() {
+            Some(doubled) => doubled,
+            None => return self.turn_at_lowest_vertex(),
+        };
+        match doubled.partial_cmp(&T::zero()) {
+            Some(Ordering::Greater) => Orientation::CounterClockwise,
+            Some(Ordering::Less) => Orientation::Clockwise,
+            _ => Orientation::Collinear,
+        }
+    }
+
+    fn turn_at_lowest_vertex(&self) -> Orientation {
+        let n = self.vertices.len();
+        let lower = |p: &Point<T, T>, q: &Point<T, T>| {
+            p.y.partial_cmp(&q.y).unwrap_or(Ordering::Equal).then(p.x.partial_cmp(&q.x).unwrap_or(Ordering::Equal))
+        };
+        let lowest = (0..n).min_by(|&i, &j| lower(&self.vertices[i], &self.vertices[j])).unwrap();
+        orient2d(self.vertices[(lowest + n - 1) % n], self.vertices[lowest], self.vertices[(lowest + 1) % n])
+    }
+
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut turn = None;
+        for i in 0..n {
+            let o = orient2d(self.vertices[i], self.vertices[(i + 1) % n], self.vertices[(i + 2) % n]);
+            if o == Orientation::Collinear {
+                continue;
+            }
+            match turn {
+                None => turn = Some(o),
+                Some(t) if t != o => return false,
+                _ => {}
+            }
+        }
+        true
+    }
+
+    //* Winding number test, points on the boundary count as inside
+    pub fn contains(&self, p: Point<T, T>) -> bool {
+        let mut winding = 0;
+        for edge in self.edges() {
+            if edge.contains(p) {
+                return true;
+            }
+            if edge.start.y <= p.y {
+                if edge.end.y > p.y && orient2d(edge.start, edge.end, p) == Orientation::CounterClockwise {
+                    winding += 1;
+                }
+            } else if edge.end.y <= p.y && orient2d(edge.start, edge.end, p) == Orientation::Clockwise {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+
+    pub fn bounding_box(&self) -> Aabb<T> {
+        Aabb::from_points(&self.vertices).unwrap()
+    }
+}
+
+impl<T: Float> Polygon<T> {
+    pub fn area(&self) -> T {
+        (self.signed_area_doubled() / T::from_f64(2.0)).abs()
+    }
+
+    pub fn perimeter(&self) -> T {
+        self.edges().fold(T::zero(), |sum, e| sum + e.length())
+    }
+
+    //* Centroid of the enclosed area, falls back to the vertex average for degenerate polygons
+    pub fn centroid(&self) -> Point<T, T> {
+        let doubled = self.signed_area_doubled();
+        if doubled.abs() <= T::EPSILON {
+            let n = T::from_f64(self.vertices.len() as f64);
+            let sum = self.vertices.iter().fold(Vec2::zero(), |sum, p| sum + p.to_vec());
+            return Point::origin() + sum / n;
+        }
+
+        let (mut cx, mut cy) = (T::zero(), T::zero());
+        for e in self.edges() {
+            let cross = e.start.to_vec().cross(e.end.to_vec());
+            cx = cx + (e.start.x + e.end.x) * cross;
+            cy = cy + (e.start.y + e.end.y) * cross;
+        }
+        let factor = T::from_f64(3.0) * doubled;
+        Point::new(cx / factor, cy / factor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<T> {
+    pub min: Point<T, T>,
+    pub max: Point<T, T>,
+}
+
+impl<T: Number> Aabb<T> {
+    //* The corners may be given in any order
+    pub fn new(a: Point<T, T>, b: Point<T, T>) -> Aabb<T> {
+        let (min_x, max_x) = if a.x < b.x { (a.x, b.x) } else { (b.x, a.x) };
+        let (min_y, max_y) = if a.y < b.y { (a.y, b.y) } else { (b.y, a.y) };
+        Aabb { min: Point::new(min_x, min_y), max: Point::new(max_x, max_y) }
+    }
+
+    pub fn from_points(points: &[Point<T, T>]) -> Option<Aabb<T>> {
+        let first = *points.first()?;
+        Some(points.iter().fold(Aabb::new(first, first), |bb, p| bb.expand(*p)))
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn expand(self, p: Point<T, T>) -> Aabb<T> {
+        let pick = |a: T, b: T, smaller: bool| if (a < b) == smaller { a } else { b };
+        Aabb {
+            min: Point::new(pick(self.min.x, p.x, true), pick(self.min.y, p.y, true)),
+            max: Point::new(pick(self.max.x, p.x, false), pick(self.max.y, p.y, false)),
+        }
+    }
+
+    pub fn union(self, other: Aabb<T>) -> Aabb<T> {
+        self.expand(other.min).expand(other.max)
+    }
+
+    //* Boundary inclusive
+    pub fn contains(&self, p: Point<T, T>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    pub fn intersects(&self, other: &Aabb<T>) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    pub fn intersection(&self, other: &Aabb<T>) -> Option<Aabb<T>> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let max = |a: T, b: T| if a > b { a } else { b };
+        let min = |a: T, b: T| if a < b { a } else { b };
+        Some(Aabb {
+            min: Point::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
+            max: Point::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+        })
+    }
+}
+
+impl<T: Float> Aabb<T> {
+    pub fn center(&self) -> Point<T, T> {
+        self.min.lerp(self.max, T::from_f64(0.5))
+    }
+}
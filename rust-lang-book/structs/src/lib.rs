@@ -0,0 +1,4 @@
+pub mod book;
+pub mod color;
+pub mod ansi;
+pub mod geometry;
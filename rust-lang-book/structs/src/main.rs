@@ -1,5 +1,4 @@
 
-
 fn main() {
     //* Structs are composite object structures that encapsulate related data
     //* To declare a struct you associate its name with a list of variable declarations
@@ -120,8 +119,216 @@ fn main() {
             }
         }
         let _moby_sick = Book::new(String::from("Moby Sick!"), String::from("Henry Dude"), 2030);
+    }
+
+    //* Book::new above happily accepts a book from the future
+    //* The Book in src/book.rs hides its fields and is only created through a 'builder'
+    //* The builder validates the fields in 'build' and reports problems as a typed BookError
+    {
+        use structs::book::{Book, BookError};
+
+        let moby_dick = Book::builder()
+            .title("Moby-Dick")
+            .subtitle("or, The Whale")
+            .author("Herman Melville")
+            .year(1851)
+            .isbn("978-0-14-243724-7")
+            .edition(1)
+            .language("en")
+            .build()
+            .expect("Moby-Dick is a valid book");
+        println!("{}", moby_dick); // Display prints the same banner as get_banner()
+
+        let harry_flotter = Book::builder()
+            .title("Harry Flotter")
+            .author("J.K. Rowling")
+            .year(2030)
+            .current_year(2020)
+            .build();
+        if let Err(err) = harry_flotter {
+            println!("Could not build Harry Flotter: {}", err); // a BookError::YearInFuture
+        }
+
+        let bad_isbn = Book::builder().title("Moby-Dick").author("Herman Melville").year(1851).isbn("978-0-14-243724-8").build();
+        if let Err(BookError::InvalidIsbn(isbn)) = bad_isbn {
+            println!("{} has a wrong check digit", isbn);
+        }
+
+        //* Implementing FromStr lets us parse the banner back into a book via str::parse
+        let parsed: Book = moby_dick.get_banner().parse().unwrap();
+        println!("parsed {:?}, has title Moby-Dick: {}", parsed.get_banner(), parsed.has_title("Moby-Dick"));
+
+        if let Err(err) = "Untitled, 1999".parse::<Book>() {
+            println!("Could not parse book: {}", err);
+        }
+    }
 
+    //* The tuple struct Color from above is grown into a colour module in src/color.rs
+    //* Rgb<T> is generic over its channels, the chapter's Color is now Rgb<u8>
+    //* Conversions between colour spaces are expressed through the From trait
+    {
+        use structs::color::{Color, Hsl, Lab, Rgb, Rgba, WcagLevel};
+
+        let red: Color = Rgb(255, 0, 0);
+        let purple: Color = "rebeccapurple".parse().unwrap();
+        println!("red is {}, rebeccapurple is {}", red, purple);
+
+        //* Parsing accepts hex, rgb(...) and CSS names, Display prints hex again
+        println!("rgb(102, 51, 153) parses to {}", "rgb(102, 51, 153)".parse::<Color>().unwrap());
+        println!("rgba(255, 0, 0, 0.5) is {:?}", "rgba(255, 0, 0, 0.5)".parse::<Rgba>());
+        if let Err(err) = "#12345".parse::<Color>() {
+            println!("Could not parse colour: {}", err);
+        }
+
+        //* Every colour survives the round trip through the other colour spaces, tests/color.rs checks that
+        println!("rebeccapurple as {:?} and {:?}", Hsl::from(purple), Lab::from(purple));
+
+        //* Blending and WCAG contrast
+        let half_red = red.with_alpha(128).over(Color::WHITE.with_alpha(255));
+        println!("half transparent red on white: {}", half_red);
+        println!("red mixed with blue: {}", red.mix(Rgb(0, 0, 255), 0.5));
+        println!("black on white has a contrast of {:.1}:1", Color::BLACK.contrast_ratio(&Color::WHITE));
+        let level = red.wcag_level(&Color::WHITE, false);
+        println!("red on white is {:?}, fine for normal text: {}", level, level >= WcagLevel::Aa);
+    }
 
+    //* Colours are only useful if we can show them, src/ansi.rs turns a Color into terminal escapes
+    //* A Style composes attributes and paints text into a Styled value that implements Display
+    {
+        use structs::ansi::{display_width, strip_ansi, to_ansi16, to_ansi256, ColorLevel, Style};
+        use structs::color::Rgb;
+
+        let warning = Style::new().bold().underline().fg(Rgb(255, 165, 0)).paint("Careful!");
+        println!("{}", warning); // escapes depend on TERM, COLORTERM and NO_COLOR
+
+        //* The same style degrades gracefully on less capable terminals
+        for level in [ColorLevel::TrueColor, ColorLevel::Ansi256, ColorLevel::Ansi16, ColorLevel::None] {
+            println!("{:?}: {:?}", level, warning.render(level));
+        }
+        let (red, grey) = (to_ansi16(Rgb(255, 0, 0)), to_ansi256(Rgb(128, 128, 128)));
+        println!("red is colour {} of 16, grey colour {} of 256", red, grey);
+        //* ColorLevel::detect() reads TERM, COLORTERM and NO_COLOR, from_env takes them as arguments
+        println!("xterm-256color supports {:?}", ColorLevel::from_env(Some("xterm-256color"), None, None));
+
+        //* Escapes take up bytes but no columns, wide characters take up two columns
+        let rendered = warning.render(ColorLevel::TrueColor);
+        println!("{:?} takes {} bytes and {} columns", strip_ansi(&rendered), rendered.len(), display_width(&rendered));
+        println!("こんにちは takes {} columns, שָׁלוֹם takes {}", display_width("こんにちは"), display_width("שָׁלוֹם"));
+        println!("[{:^12}]", Style::new().fg(Rgb(0, 128, 0)).paint("centered"));
+    }
+
+    //* Earlier we could only derive Debug, printing with {} needed a handwritten Display
+    //* The structs-derive crate provides our own derive macros, see ../structs-derive
+    //*     #[derive(Display)] with a #[display("...")] template referring to the fields
+    //*     #[derive(Builder)] generating <Name>Builder with setters and build()
+    //*     #[derive(Getters)] generating accessors for private fields
+    {
+        use structs_derive::{Builder, Display, Getters};
+
+        #[derive(Debug, Display, Getters)]
+        #[display("Struct({_field1}, {_field2}, {_field3:?})")]
+        struct Struct {
+            #[getter(copy, rename = "field1")]
+            _field1: i32,
+            #[getter(copy, rename = "field2")]
+            _field2: bool,
+            #[getter(rename = "field3")]
+            _field3: String
+        }
+
+        let my_struct = Struct { _field1: 1, _field2: false, _field3: String::from("s") };
+        println!("{}", my_struct); // finally works
+        println!("field1 {}, field2 {}, field3 {:?}", my_struct.field1(), my_struct.field2(), my_struct.field3());
+
+        #[derive(Debug, Display, Builder, Getters)]
+        #[display("{title} by {author}, {year}")]
+        struct Book {
+            title: String,
+            author: String,
+            #[getter(copy)]
+            year: u16,
+            #[getter(skip)]
+            subtitle: Option<String>,
+            #[builder(default)]
+            #[getter(copy)]
+            edition: u8
+        }
+
+        let book = Book::builder().title("Moby-Dick").author("Herman Melville").year(1851u16).build().unwrap();
+        println!("{} (edition {}, subtitle {:?})", book, book.edition(), book.subtitle);
+        if let Err(err) = Book::builder().title("Moby-Dick").build() {
+            println!("Could not build book: {}", err);
+        }
+
+        //* Tuple structs refer to their fields by index and need names for their getters
+        //* The real structs::color::Rgb gets red(), green() and blue() that way, and structs::book::Book its Display
+        let red = structs::color::Rgb(255, 0, 0);
+        println!("red has red {}, green {} and blue {}", red.red(), red.green(), red.blue());
+
+        //* Mistakes in the attributes are reported at compile time, ../structs-derive/tests/ui checks each of these:
+        // ! #[derive(Display)] struct Missing { a: i32 }                    // requires a #[display("...")] attribute
+        // ! #[derive(Display)] #[display("{b}")] struct Unknown { a: i32 }  // unknown field `b` in display template
+        // ! #[derive(Display)] #[display(42)] struct NotAString { a: i32 }  // expected a format string
+        // ! #[derive(Builder)] struct Tuple(i32);                           // only structs with named fields
+        // ! #[derive(Builder)] struct Bad { #[builder(fallback)] a: i32 }   // unknown builder attribute
+        // ! #[derive(Getters)] struct Unnamed(i32);                         // tuple fields need a name
+        // ! #[derive(Getters)] struct Both { #[getter(skip, copy)] a: i32 } // skip cannot be combined
+    }
+
+    //* The generics chapter introduced Point<T, S> with as_tuple and distance_from_origin for Point<f32, f32>
+    //* src/geometry.rs grows it into 2D/3D geometry over the 'Number' and 'Float' traits
+    //* Integer coordinates get the exact operations, float coordinates additionally lengths and angles
+    {
+        use std::f64::consts::PI;
+        use structs::geometry::{approx_eq, orient2d, Aabb, Line, Point, Polygon, Segment, Vec2, Vec3};
+
+        //* Mixed coordinate types still work like in the generics chapter
+        let mixed: Point<f32, i32> = Point::new(0.0, 0);
+        println!("mixed point {:?}", mixed.as_tuple());
+        println!("(3, 4) is {} away from the origin", Point::new(3.0f32, 4.0).distance_from_origin());
+
+        //* Vectors
+        let a = Vec2::new(1.0, 0.0);
+        let b = Vec2::new(0.0, 1.0);
+        println!("a · b = {}, a × b = {}, a rotated by 90° = {:?}", a.dot(b), a.cross(b), a.rotate(PI / 2.0));
+        println!("x × y = {:?}", Vec3::new(1, 0, 0).cross(Vec3::new(0, 1, 0)));
+        let around_z = Vec3::new(1.0, 0.0, 0.0).rotate_around(Vec3::new(0.0, 0.0, 2.0), PI / 2.0);
+        println!("x rotated by 90° around z = {:?}, up to rounding: {}", around_z, approx_eq(around_z.y, 1.0, 1e-12));
+
+        //* Lines and segments
+        let diagonal = Line::through(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        let anti = Line::through(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+        println!("the diagonals cross at {:?}", diagonal.intersection(&anti));
+        println!("(0, 2) is {} away from the diagonal", diagonal.distance_to(Point::new(0.0, 2.0)));
+
+        let s1 = Segment::new(Point::new(0, 0), Point::new(4, 4));
+        let s2 = Segment::new(Point::new(0, 4), Point::new(4, 0));
+        let s3 = Segment::new(Point::new(5, 5), Point::new(6, 6));
+        println!("s1 intersects s2: {}, s1 intersects s3: {}", s1.intersects(&s2), s1.intersects(&s3));
+
+        //* Robust predicates: the naive determinant rounds to 0.0 for this nearly collinear triple
+        let p = Point::new(0.5, 0.5 + f64::EPSILON / 2.0);
+        let q = Point::new(12.0, 12.0);
+        let r = Point::new(24.0, 24.0);
+        let naive = (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x);
+        println!("naive determinant {}, orient2d {:?}", naive, orient2d(p, q, r));
+        println!("(0, 0), (1, 1), (2, 2) are {:?}", orient2d(Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)));
+
+        //* Polygons and bounding boxes
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0), Point::new(1.0, 2.0), Point::new(0.0, 2.0),
+        ]).unwrap();
+        println!("the L shape has area {}, is convex: {}", l_shape.area(), l_shape.is_convex());
+        for point in [Point::new(0.5, 1.5), Point::new(1.5, 1.5), Point::new(2.0, 0.5)] {
+            println!("  contains {:?}: {}", point, l_shape.contains(point)); // points on the boundary are inside
+        }
+        println!("  centroid {:?}", l_shape.centroid());
 
+        let bb = l_shape.bounding_box();
+        let other = Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        println!("bounding box {:?} centered at {:?}", bb, bb.center());
+        println!("overlap with {:?}: {:?}", other, bb.intersection(&other).map(|i| i.area()));
+        println!("union area: {}", bb.union(other).area());
     }
 }
\ No newline at end of file
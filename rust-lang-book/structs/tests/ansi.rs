@@ -0,0 +1,137 @@
+use structs::ansi::{char_width, display_width, strip_ansi, to_ansi16, to_ansi256, ColorLevel, Style};
+use structs::color::Rgb;
+
+#[test]
+fn strip_csi_sequences() {
+    assert_eq!(strip_ansi("\x1b[1;31mred\x1b[0m"), "red");
+    assert_eq!(strip_ansi("\x1b[38;2;1;2;3mtrue\x1b[39m colour"), "true colour");
+    //* cursor movement and erase use other final bytes
+    assert_eq!(strip_ansi("a\x1b[2Kb\x1b[10Ac"), "abc");
+    assert_eq!(strip_ansi("plain text"), "plain text");
+    assert_eq!(strip_ansi(""), "");
+}
+
+#[test]
+fn strip_osc_sequences() {
+    //* a hyperlink, terminated by BEL and by ESC \
+    assert_eq!(strip_ansi("\x1b]8;;https://rust-lang.org\x07Rust\x1b]8;;\x07"), "Rust");
+    assert_eq!(strip_ansi("\x1b]0;title\x1b\\after"), "after");
+    //* an ESC inside an OSC that isn't followed by \ doesn't end it
+    assert_eq!(strip_ansi("\x1b]0;a\x1bb\x07c"), "c");
+}
+
+#[test]
+fn strip_two_character_and_unterminated_sequences() {
+    assert_eq!(strip_ansi("\x1bcreset"), "reset");
+    assert_eq!(strip_ansi("trailing\x1b"), "trailing");
+    assert_eq!(strip_ansi("cut off\x1b[31"), "cut off");
+    assert_eq!(strip_ansi("cut off\x1b]0;title"), "cut off");
+    //* non-ASCII text around escapes is kept
+    assert_eq!(strip_ansi("\x1b[1mこんにちは\x1b[0m Grüße"), "こんにちは Grüße");
+}
+
+#[test]
+fn display_width_ignores_escapes() {
+    assert_eq!(display_width("hello"), 5);
+    assert_eq!(display_width("\x1b[1;31mhello\x1b[0m"), 5);
+    assert_eq!(display_width("こんにちは"), 10);
+    assert_eq!(display_width("안녕하세요"), 10);
+    assert_eq!(display_width("Dobry\u{301} den"), 9);
+    assert_eq!(display_width("नमस्ते"), 4);
+    assert_eq!(display_width("שָׁלוֹם"), 4);
+    assert_eq!(display_width("🦀 Ferris"), 9);
+    assert_eq!(display_width("tab\tand\nnewline"), 13);
+}
+
+#[test]
+fn zero_width_table_boundaries() {
+    let ranges = [
+        (0x0300, 0x036f),
+        (0x0483, 0x0489),
+        (0x0591, 0x05bd),
+        (0x0610, 0x061a),
+        (0x064b, 0x065f),
+        (0x0900, 0x0902),
+        (0x0941, 0x0948),
+        (0x1ab0, 0x1aff),
+        (0x200b, 0x200f),
+        (0x20d0, 0x20ff),
+        (0xfe00, 0xfe0f),
+        (0xfe20, 0xfe2f),
+    ];
+    for &(lo, hi) in &ranges {
+        for cp in [lo, (lo + hi) / 2, hi] {
+            assert_eq!(char_width(char::from_u32(cp).unwrap()), 0, "U+{:04X}", cp);
+        }
+        //* the neighbours are visible again, U+FE30 right after the half marks is even wide
+        assert_ne!(char_width(char::from_u32(lo - 1).unwrap()), 0, "U+{:04X}", lo - 1);
+        assert_ne!(char_width(char::from_u32(hi + 1).unwrap()), 0, "U+{:04X}", hi + 1);
+    }
+    //* control characters
+    for c in ['\0', '\x1b', '\x7f', '\u{9f}'] {
+        assert_eq!(char_width(c), 0, "{:?}", c);
+    }
+    assert_eq!(char_width(' '), 1);
+    assert_eq!(char_width('\u{a0}'), 1);
+}
+
+#[test]
+fn wide_table_boundaries() {
+    let ranges = [
+        (0x1100, 0x115f),
+        (0x3400, 0x4dbf),
+        (0x4e00, 0x9fff),
+        (0xa000, 0xa4cf),
+        (0xac00, 0xd7a3),
+        (0xf900, 0xfaff),
+        (0xfe30, 0xfe4f),
+        (0xff00, 0xff60),
+        (0xffe0, 0xffe6),
+        (0x1f300, 0x1faff),
+        (0x20000, 0x3fffd),
+    ];
+    for &(lo, hi) in &ranges {
+        for cp in [lo, (lo + hi) / 2, hi] {
+            assert_eq!(char_width(char::from_u32(cp).unwrap()), 2, "U+{:04X}", cp);
+        }
+    }
+    for cp in [0x10ff, 0x1160, 0xa4d0, 0xd7a4, 0xff61, 0xffe7, 0x1f2ff, 0x1fb00, 0x3fffe] {
+        assert_eq!(char_width(char::from_u32(cp).unwrap()), 1, "U+{:04X}", cp);
+    }
+}
+
+#[test]
+fn color_levels_from_the_environment() {
+    assert_eq!(ColorLevel::from_env(Some("xterm-256color"), Some("truecolor"), Some("1")), ColorLevel::None);
+    assert_eq!(ColorLevel::from_env(Some("xterm-256color"), Some("truecolor"), Some("")), ColorLevel::TrueColor);
+    assert_eq!(ColorLevel::from_env(Some("dumb"), Some("24bit"), None), ColorLevel::None);
+    assert_eq!(ColorLevel::from_env(Some("xterm-256color"), None, None), ColorLevel::Ansi256);
+    assert_eq!(ColorLevel::from_env(Some("xterm"), None, None), ColorLevel::Ansi16);
+    assert_eq!(ColorLevel::from_env(None, None, None), ColorLevel::None);
+    assert_eq!(ColorLevel::from_env(Some("xterm-256color"), Some("truecolor"), None), ColorLevel::TrueColor);
+    assert_eq!(ColorLevel::from_env(Some("xterm-256color"), None, Some("1")), ColorLevel::None);
+    assert_eq!(ColorLevel::from_env(Some("dumb"), None, None), ColorLevel::None);
+}
+
+#[test]
+fn styles_render_per_level() {
+    let styled = Style::new().bold().fg(Rgb(255, 0, 0)).paint("hi");
+    assert_eq!(styled.render(ColorLevel::None), "hi");
+    assert_eq!(styled.render(ColorLevel::Ansi16), "\x1b[1;91mhi\x1b[0m");
+    assert_eq!(styled.render(ColorLevel::Ansi256), "\x1b[1;38;5;196mhi\x1b[0m");
+    assert_eq!(styled.render(ColorLevel::TrueColor), "\x1b[1;38;2;255;0;0mhi\x1b[0m");
+    assert_eq!(strip_ansi(&styled.render(ColorLevel::TrueColor)), "hi");
+    assert_eq!(Style::new().paint("plain").render(ColorLevel::TrueColor), "plain");
+    assert_eq!((to_ansi16(Rgb(0, 0, 0)), to_ansi256(Rgb(255, 255, 255))), (0, 231));
+}
+
+#[test]
+fn attributes_combine_with_every_level() {
+    let warning = Style::new().bold().underline().fg(Rgb(255, 165, 0)).paint("Careful!");
+    assert_eq!(warning.render(ColorLevel::TrueColor), "\x1b[1;4;38;2;255;165;0mCareful!\x1b[0m");
+    assert_eq!(warning.render(ColorLevel::Ansi256), "\x1b[1;4;38;5;214mCareful!\x1b[0m");
+    assert_eq!(warning.render(ColorLevel::Ansi16), "\x1b[1;4;33mCareful!\x1b[0m");
+    assert_eq!(warning.render(ColorLevel::None), "Careful!");
+    assert_eq!(display_width(&warning.render(ColorLevel::TrueColor)), 8);
+    assert_eq!((to_ansi16(Rgb(255, 0, 0)), to_ansi256(Rgb(128, 128, 128))), (9, 244));
+}
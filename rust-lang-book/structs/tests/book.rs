@@ -0,0 +1,84 @@
+use structs::book::{normalize_isbn, Book, BookBuilder, BookError};
+
+fn builder() -> BookBuilder {
+    Book::builder().title("The Rust Programming Language").author("Steve Klabnik").year(2018).current_year(2024)
+}
+
+#[test]
+fn builds_a_valid_book() {
+    let book = builder().subtitle("  ").isbn("978-1-59327-828-1").edition(2).language("en").build().unwrap();
+    assert_eq!(book.title(), "The Rust Programming Language");
+    assert_eq!(book.subtitle(), None);
+    assert_eq!(book.isbn(), Some("9781593278281"));
+    assert_eq!(book.edition(), Some(2));
+    assert_eq!(book.language(), Some("en"));
+}
+
+#[test]
+fn missing_fields() {
+    assert_eq!(Book::builder().author("a").year(2000).build(), Err(BookError::MissingTitle));
+    assert_eq!(Book::builder().title("t").year(2000).build(), Err(BookError::MissingAuthor));
+    assert_eq!(Book::builder().title("t").author("a").build(), Err(BookError::MissingYear));
+}
+
+#[test]
+fn empty_fields() {
+    assert_eq!(builder().title(" \t").build(), Err(BookError::EmptyTitle));
+    assert_eq!(builder().author("").build(), Err(BookError::EmptyAuthor));
+}
+
+#[test]
+fn year_in_the_future() {
+    assert_eq!(builder().year(2025).build(), Err(BookError::YearInFuture { year: 2025, current: 2024 }));
+    assert!(builder().year(2024).build().is_ok());
+    //* without current_year() the system clock decides
+    assert!(matches!(
+        Book::builder().title("t").author("a").year(u16::MAX).build(),
+        Err(BookError::YearInFuture { year: u16::MAX, .. })
+    ));
+}
+
+#[test]
+fn isbn10_checksum() {
+    assert_eq!(normalize_isbn("0-306-40615-2"), Some("0306406152".to_string()));
+    assert_eq!(normalize_isbn("0 8044 2957 x"), Some("080442957X".to_string()));
+    assert_eq!(normalize_isbn("0-306-40615-3"), None);
+    //* X is only allowed as the check digit
+    assert_eq!(normalize_isbn("X306406152"), None);
+    assert_eq!(builder().isbn("0-306-40615-3").build(), Err(BookError::InvalidIsbn("0-306-40615-3".to_string())));
+}
+
+#[test]
+fn isbn13_checksum() {
+    assert_eq!(normalize_isbn("978-0-306-40615-7"), Some("9780306406157".to_string()));
+    assert_eq!(normalize_isbn("978-0-306-40615-8"), None);
+    assert_eq!(normalize_isbn("978030640615X"), None);
+    assert_eq!(normalize_isbn("12345"), None);
+    let err = builder().isbn("978-0-306-40615-8").build();
+    assert_eq!(err, Err(BookError::InvalidIsbn("978-0-306-40615-8".to_string())));
+}
+
+#[test]
+fn edition_starts_at_one() {
+    assert_eq!(builder().edition(0).build(), Err(BookError::InvalidEdition));
+    assert_eq!(builder().edition(1).build().unwrap().edition(), Some(1));
+}
+
+#[test]
+fn banner_round_trip() {
+    let book = Book::builder().title("Stand by Me").author("Stephen King").year(1982).build().unwrap();
+    assert_eq!(book.to_string(), book.get_banner());
+    let parsed = book.to_string().parse::<Book>().unwrap();
+    assert!(parsed.has_title("Stand by Me") && !parsed.has_title("Stand By Me"));
+    assert_eq!(parsed, book);
+}
+
+#[test]
+fn malformed_banners() {
+    for banner in &["no year here", "Title, 1999", ""] {
+        assert_eq!(banner.parse::<Book>(), Err(BookError::MalformedBanner(banner.to_string())));
+    }
+    assert_eq!("Title by Author, soon".parse::<Book>(), Err(BookError::InvalidYear("soon".to_string())));
+    assert_eq!("Title by Author, 99999".parse::<Book>(), Err(BookError::InvalidYear("99999".to_string())));
+    assert_eq!(" by Author, 1999".parse::<Book>(), Err(BookError::EmptyTitle));
+}
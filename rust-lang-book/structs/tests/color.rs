@@ -0,0 +1,87 @@
+use structs::color::{Color, Hsl, Hsv, Lab, LinearRgb, ParseColorError, Rgb, Rgba, WcagLevel};
+
+//* Every 15th value per channel, 4913 colours including black, white and the primaries
+fn grid() -> impl Iterator<Item = Color> {
+    let steps = || (0..=255).step_by(15);
+    steps().flat_map(move |r| steps().flat_map(move |g| steps().map(move |b| Rgb(r, g, b))))
+}
+
+#[test]
+fn hsl_round_trip() {
+    for color in grid() {
+        assert_eq!(Rgb::from(Hsl::from(color)), color);
+    }
+}
+
+#[test]
+fn hsv_round_trip() {
+    for color in grid() {
+        assert_eq!(Rgb::from(Hsv::from(color)), color);
+    }
+}
+
+#[test]
+fn linear_rgb_round_trip() {
+    for color in grid() {
+        assert_eq!(Rgb::from(LinearRgb::from(color)), color);
+    }
+}
+
+#[test]
+fn lab_round_trip() {
+    for color in grid() {
+        assert_eq!(Rgb::from(Lab::from(color)), color);
+    }
+}
+
+#[test]
+fn float_channels_round_trip() {
+    for color in grid() {
+        assert_eq!(Color::from(Rgb::<f32>::from(color)), color);
+    }
+}
+
+#[test]
+fn display_round_trip() {
+    for color in grid() {
+        assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+        let translucent = color.with_alpha(color.red());
+        assert_eq!(translucent.to_string().parse::<Rgba>(), Ok(translucent));
+    }
+}
+
+#[test]
+fn parsing() {
+    let purple = Rgb(102, 51, 153);
+    assert_eq!("#663399".parse::<Color>(), Ok(purple));
+    assert_eq!("rgb(102, 51, 153)".parse::<Color>(), Ok(purple));
+    assert_eq!("rebeccapurple".parse::<Color>(), Ok(purple));
+    assert_eq!(purple.name(), Some("rebeccapurple"));
+    assert_eq!("".parse::<Color>(), Err(ParseColorError::Empty));
+    assert!(matches!("#12345".parse::<Color>(), Err(ParseColorError::InvalidHex(_))));
+    assert!(matches!("rgb(256, 0, 0)".parse::<Color>(), Err(ParseColorError::ChannelOutOfRange(_))));
+    assert!(matches!("notacolour".parse::<Color>(), Err(ParseColorError::UnknownName(_))));
+    assert_eq!("rgba(255, 0, 0, 0.5)".parse::<Rgba>(), Ok(Rgba(255, 0, 0, 128)));
+}
+
+#[test]
+fn contrast_and_wcag_levels() {
+    let red = Rgb(255, 0, 0);
+    assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 0.01);
+    assert_eq!(Color::WHITE.contrast_ratio(&Color::WHITE), 1.0);
+    assert_eq!(red.contrast_ratio(&Color::WHITE), Color::WHITE.contrast_ratio(&red));
+    assert_eq!(Color::BLACK.wcag_level(&Color::WHITE, false), WcagLevel::Aaa);
+    //* about 4:1, enough for large text only
+    assert_eq!(red.wcag_level(&Color::WHITE, false), WcagLevel::AaLarge);
+    assert_eq!(red.wcag_level(&Color::WHITE, true), WcagLevel::Aa);
+    assert_eq!(Color::WHITE.wcag_level(&Color::WHITE, true), WcagLevel::Fail);
+}
+
+#[test]
+fn blending() {
+    let red = Rgb(255, 0, 0);
+    assert_eq!(red.with_alpha(255).over(Color::WHITE.with_alpha(255)), Rgba(255, 0, 0, 255));
+    assert_eq!(red.with_alpha(0).over(Color::WHITE.with_alpha(255)), Rgba(255, 255, 255, 255));
+    assert_eq!(red.with_alpha(0).over(Color::WHITE.with_alpha(0)), Rgba(0, 0, 0, 0));
+    assert_eq!((red.mix(Color::BLACK, 0.0), red.mix(Color::BLACK, 1.0)), (red, Color::BLACK));
+}
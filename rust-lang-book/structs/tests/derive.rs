@@ -0,0 +1,70 @@
+use structs::book::Book;
+use structs::color::{Color, Rgb};
+use structs_derive::{Builder, Display, Getters};
+
+//* The derives of structs-derive on the types of this crate rather than on copies made for the demo
+
+#[test]
+fn book_display_is_derived_from_the_banner_template() {
+    let book = Book::builder().title("Moby-Dick").author("Herman Melville").year(1851).build().unwrap();
+    assert_eq!(book.to_string(), "Moby-Dick by Herman Melville, 1851");
+    assert_eq!(book.get_banner(), book.to_string());
+    assert_eq!(format!("[{}]", book), "[Moby-Dick by Herman Melville, 1851]");
+    assert_eq!(book.to_string().parse::<Book>(), Ok(book));
+}
+
+#[test]
+fn color_getters_are_derived_for_every_channel_type() {
+    let coral: Color = Rgb(255, 127, 80);
+    assert_eq!((coral.red(), coral.green(), coral.blue()), (255, 127, 80));
+
+    let normalized = Rgb::<f32>::from(coral);
+    assert_eq!(normalized.red(), 1.0);
+    assert_eq!(normalized.blue(), 80.0 / 255.0);
+
+    //* getters of a non-Copy channel type can't be called, but the type itself still works
+    let names = Rgb(String::from("r"), String::from("g"), String::from("b"));
+    assert_eq!(names.0, "r");
+}
+
+#[derive(Debug, Display, Getters)]
+#[display("Struct({_field1}, {_field2}, {_field3:?})")]
+struct Struct {
+    #[getter(copy, rename = "field1")]
+    _field1: i32,
+    #[getter(copy, rename = "field2")]
+    _field2: bool,
+    #[getter(rename = "field3")]
+    _field3: String,
+}
+
+#[derive(Debug, Display, Builder, Getters)]
+#[display("{title} by {author}, {year}")]
+struct Novel {
+    title: String,
+    author: String,
+    #[getter(copy)]
+    year: u16,
+    #[getter(skip)]
+    subtitle: Option<String>,
+    #[builder(default)]
+    #[getter(copy)]
+    edition: u8,
+}
+
+#[test]
+fn display_templates_format_the_fields() {
+    let my_struct = Struct { _field1: 1, _field2: false, _field3: String::from("s") };
+    assert_eq!(my_struct.to_string(), "Struct(1, false, \"s\")");
+    assert_eq!((my_struct.field1(), my_struct.field2(), my_struct.field3().as_str()), (1, false, "s"));
+}
+
+#[test]
+fn builder_fills_defaults_and_reports_missing_fields() {
+    let novel = Novel::builder().title("Moby-Dick").author("Herman Melville").year(1851u16).build().unwrap();
+    assert_eq!(novel.to_string(), "Moby-Dick by Herman Melville, 1851");
+    assert_eq!((novel.title().as_str(), novel.author().as_str()), ("Moby-Dick", "Herman Melville"));
+    assert_eq!((novel.year(), novel.edition()), (1851, 0));
+    assert!(novel.subtitle.is_none());
+    assert_eq!(Novel::builder().title("Moby-Dick").build().unwrap_err(), "missing field `author`");
+}
@@ -0,0 +1,195 @@
+use std::f64::consts::PI;
+
+use structs::geometry::{approx_eq, orient2d, Aabb, Line, Orientation, Point, Polygon, Segment, Vec2, Vec3};
+
+fn polygon<T: structs::geometry::Number>(vertices: &[(T, T)]) -> Polygon<T> {
+    Polygon::new(vertices.iter().map(|&(x, y)| Point::new(x, y)).collect()).unwrap()
+}
+
+#[test]
+fn orient2d_basic_cases() {
+    let (a, b) = (Point::new(0, 0), Point::new(4, 0));
+    assert_eq!(orient2d(a, b, Point::new(2, 1)), Orientation::CounterClockwise);
+    assert_eq!(orient2d(a, b, Point::new(2, -1)), Orientation::Clockwise);
+    assert_eq!(orient2d(a, b, Point::new(9, 0)), Orientation::Collinear);
+    assert_eq!(orient2d(a, a, Point::new(2, 1)), Orientation::Collinear);
+}
+
+//* Points a few ulps around the line y = x, the classic example where the naive determinant gets the sign wrong
+//* Scaled by 2^53 the same points are integers, for which orient2d is exact, so both must agree
+#[test]
+fn orient2d_near_degenerate_floats_agree_with_exact_integers() {
+    let ulp = 0.5f64.powi(53);
+    let scale = 2f64.powi(53);
+    let (q, r) = (Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+    let (qi, ri) = (Point::new(12i64 << 53, 12i64 << 53), Point::new(24i64 << 53, 24i64 << 53));
+
+    let mut seen = [0; 3];
+    for i in 0..64 {
+        for j in 0..64 {
+            let p = Point::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+            let pi = Point::new((p.x * scale) as i64, (p.y * scale) as i64);
+            let expected = orient2d(pi, qi, ri);
+            assert_eq!(orient2d(p, q, r), expected, "i = {}, j = {}", i, j);
+            seen[expected as usize] += 1;
+        }
+    }
+    assert!(seen.iter().all(|&count| count > 0), "{:?}", seen);
+}
+
+#[test]
+fn orient2d_is_exact_for_i64_beyond_2_pow_53() {
+    let big = 1i64 << 62;
+    //* det = 2^62 (2^62 - 2) - (2^62 - 1)^2 = -1, as f64 all three points would look collinear
+    let (a, b, c) = (Point::new(0, 0), Point::new(big, big - 1), Point::new(big - 1, big - 2));
+    assert_eq!(orient2d(a, b, c), Orientation::Clockwise);
+    assert_eq!(orient2d(a, c, b), Orientation::CounterClockwise);
+    assert_eq!(orient2d(a, b, Point::new(-big, 1 - big)), Orientation::Collinear);
+
+    let (min, max) = (Point::new(i64::MIN, i64::MIN), Point::new(i64::MAX, i64::MAX));
+    assert_eq!(orient2d(min, max, Point::new(0, 0)), Orientation::Collinear);
+    assert_eq!(orient2d(min, max, Point::new(0, 1)), Orientation::CounterClockwise);
+    assert_eq!(orient2d(min, max, Point::new(1, 0)), Orientation::Clockwise);
+    assert_eq!(orient2d(Point::new(i64::MAX, i64::MIN), min, max), Orientation::Clockwise);
+}
+
+#[test]
+fn polygon_contains() {
+    //* an L shape, its notch at (1..2, 1..2) is outside
+    let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+    let clockwise = polygon(&[(0, 4), (2, 4), (2, 2), (4, 2), (4, 0), (0, 0)]);
+    for shape in &[&l_shape, &clockwise] {
+        for &inside in &[(1, 1), (3, 1), (1, 3), (0, 0), (4, 1), (2, 3), (3, 2), (2, 2)] {
+            assert!(shape.contains(Point::new(inside.0, inside.1)), "{:?}", inside);
+        }
+        for &outside in &[(3, 3), (5, 1), (-1, 0), (1, 5), (4, 4), (5, 0)] {
+            assert!(!shape.contains(Point::new(outside.0, outside.1)), "{:?}", outside);
+        }
+    }
+
+    //* a convex polygon contains exactly the points that are left of (or on) every edge
+    let hexagon = polygon(&[(2, 0), (6, 0), (8, 3), (6, 6), (2, 6), (0, 3)]);
+    for x in -1..10 {
+        for y in -1..8 {
+            let p = Point::new(x, y);
+            let left_of_all = hexagon.edges().all(|e| orient2d(e.start, e.end, p) != Orientation::Clockwise);
+            assert_eq!(hexagon.contains(p), left_of_all, "{:?}", p);
+        }
+    }
+}
+
+#[test]
+fn polygon_centroid() {
+    let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    assert_eq!(square.centroid(), Point::new(1.0, 1.0));
+
+    //* the centroid of a triangle is the average of its corners
+    let triangle = polygon(&[(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)]);
+    let c = triangle.centroid();
+    assert!(approx_eq(c.x, 2.0, 1e-12) && approx_eq(c.y, 1.0, 1e-12), "{:?}", c);
+
+    //* the L shape is two rectangles: 8 * (2, 1) + 4 * (1, 3) over 12
+    let l_shape = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]);
+    let c = l_shape.centroid();
+    assert!(approx_eq(c.x, 5.0 / 3.0, 1e-12) && approx_eq(c.y, 5.0 / 3.0, 1e-12), "{:?}", c);
+
+    //* neither the direction nor a translation far from the origin changes it
+    let reversed = polygon(&[(0.0, 4.0), (2.0, 4.0), (2.0, 2.0), (4.0, 2.0), (4.0, 0.0), (0.0, 0.0)]);
+    assert_eq!(reversed.centroid(), c);
+    let moved = polygon(&[(1e6, 1e6), (1e6 + 4.0, 1e6), (1e6 + 4.0, 1e6 + 2.0), (1e6, 1e6 + 2.0)]);
+    let c = moved.centroid();
+    assert!(approx_eq(c.x, 1e6 + 2.0, 1e-12) && approx_eq(c.y, 1e6 + 1.0, 1e-12), "{:?}", c);
+
+    //* without an area it falls back to the average of the vertices
+    let flat = polygon(&[(0.0, 0.0), (1.0, 1.0), (5.0, 5.0)]);
+    assert_eq!(flat.centroid(), Point::new(2.0, 2.0));
+}
+
+#[test]
+fn signed_area_doubled_overflow_is_detected() {
+    let small = polygon(&[(0i64, 0), (4, 0), (4, 2)]);
+    assert_eq!(small.checked_signed_area_doubled(), Some(8));
+    assert_eq!(small.signed_area_doubled(), 8);
+
+    let big = 1i64 << 40;
+    let huge = polygon(&[(0, 0), (big, 0), (big, big), (0, big)]);
+    assert_eq!(huge.checked_signed_area_doubled(), None);
+    //* the orientation doesn't need the area
+    assert_eq!(huge.orientation(), Orientation::CounterClockwise);
+    let reversed = polygon(&[(0, big), (big, big), (big, 0), (0, 0)]);
+    assert_eq!(reversed.orientation(), Orientation::Clockwise);
+    assert!(huge.contains(Point::new(big / 2, big - 1)));
+    assert_eq!(polygon(&[(0, 0), (i32::MAX, 0), (0, 2)]).checked_signed_area_doubled(), None);
+}
+
+#[test]
+#[should_panic(expected = "overflows its coordinate type")]
+fn signed_area_doubled_panics_on_overflow() {
+    let big = 1i64 << 40;
+    polygon(&[(0, 0), (big, 0), (big, big)]).signed_area_doubled();
+}
+
+#[test]
+fn points_keep_mixed_coordinate_types() {
+    let mixed: Point<f32, i32> = Point::new(0.0, 0);
+    assert_eq!(mixed.as_tuple(), (&0.0, &0));
+    assert_eq!(Point::new(3.0f32, 4.0).distance_from_origin(), 5.0);
+}
+
+#[test]
+fn vector_products_and_rotations() {
+    let (x, y) = (Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+    assert_eq!((x.dot(y), x.cross(y), y.cross(x)), (0.0, 1.0, -1.0));
+    let rotated = x.rotate(PI / 2.0);
+    assert!(approx_eq(rotated.x, 0.0, 1e-12) && approx_eq(rotated.y, 1.0, 1e-12), "{:?}", rotated);
+
+    assert_eq!(Vec3::new(1, 0, 0).cross(Vec3::new(0, 1, 0)), Vec3::new(0, 0, 1));
+    //* the length of the axis doesn't matter
+    let around_z = Vec3::new(1.0, 0.0, 0.0).rotate_around(Vec3::new(0.0, 0.0, 2.0), PI / 2.0);
+    assert!(approx_eq(around_z.x, 0.0, 1e-12) && approx_eq(around_z.y, 1.0, 1e-12), "{:?}", around_z);
+    assert_eq!(around_z.z, 0.0);
+}
+
+#[test]
+fn lines_and_segments() {
+    let diagonal = Line::through(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+    let anti = Line::through(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+    assert_eq!(diagonal.intersection(&anti), Some(Point::new(1.0, 1.0)));
+    assert!(approx_eq(diagonal.distance_to(Point::new(0.0, 2.0)), 2f64.sqrt(), 1e-12));
+    let parallel = Line::through(Point::new(0.0, 1.0), Point::new(1.0, 2.0));
+    assert_eq!(diagonal.intersection(&parallel), None);
+
+    let s1 = Segment::new(Point::new(0, 0), Point::new(4, 4));
+    assert!(s1.intersects(&Segment::new(Point::new(0, 4), Point::new(4, 0))));
+    //* on the same line but past the end
+    assert!(!s1.intersects(&Segment::new(Point::new(5, 5), Point::new(6, 6))));
+    assert!(s1.intersects(&Segment::new(Point::new(4, 4), Point::new(6, 6))));
+}
+
+#[test]
+fn orient2d_rounds_nothing_away_for_floats() {
+    //* the naive determinant of this nearly collinear triple rounds to 0.0
+    let (p, q, r) = (Point::new(0.5, 0.5 + f64::EPSILON / 2.0), Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+    assert_eq!((q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x), 0.0);
+    assert_eq!(orient2d(p, q, r), Orientation::CounterClockwise);
+    assert_eq!(orient2d(Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)), Orientation::Collinear);
+}
+
+#[test]
+fn polygon_area_convexity_and_bounding_box() {
+    let l_shape = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    assert_eq!(l_shape.area(), 3.0);
+    assert!(!l_shape.is_convex());
+    assert!(polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]).is_convex());
+    let centroid = l_shape.centroid();
+    assert!(approx_eq(centroid.x, 5.0 / 6.0, 1e-12) && approx_eq(centroid.y, 5.0 / 6.0, 1e-12), "{:?}", centroid);
+
+    //* the corners may be given in any order
+    let bb = l_shape.bounding_box();
+    assert_eq!(bb, Aabb::new(Point::new(2.0, 2.0), Point::new(0.0, 0.0)));
+    assert_eq!(bb.center(), Point::new(1.0, 1.0));
+    let other = Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+    assert_eq!(bb.intersection(&other).map(|i| i.area()), Some(1.0));
+    assert_eq!(bb.union(other).area(), 9.0);
+    assert_eq!(bb.intersection(&Aabb::new(Point::new(5.0, 5.0), Point::new(6.0, 6.0))), None);
+}